@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+# benches/baseline.py
+# Committed benchmark baseline (benches/baseline.json) vs the last criterion run.
+#
+#   cargo bench -- --save-baseline local
+#   python3 benches/baseline.py compare   # every benchmark vs baseline.json, >15% slower = exit 1
+#   python3 benches/baseline.py record    # rewrite baseline.json with this run's means
+#
+# Means are read from target/criterion/<benchmark>/<name>/estimates.json, ids from
+# the benchmark.json criterion writes next to them ("tick/1500", "telemetry/serialize").
+# Entries still null in baseline.json have not been recorded on the reference machine.
+
+import json
+import os
+import platform
+import sys
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+BASELINE = ROOT / "benches" / "baseline.json"
+CRITERION = ROOT / "target" / "criterion"
+TOLERANCE = 1.15
+
+
+def measured(name):
+    means = {}
+    for estimates in CRITERION.glob(f"**/{name}/estimates.json"):
+        info = json.loads((estimates.parent / "benchmark.json").read_text())
+        mean = json.loads(estimates.read_text())["mean"]["point_estimate"]
+        means[info["full_id"]] = mean
+    return means
+
+
+def main():
+    mode = sys.argv[1] if len(sys.argv) > 1 else "compare"
+    name = sys.argv[2] if len(sys.argv) > 2 else "local"
+    baseline = json.loads(BASELINE.read_text())
+    means = measured(name)
+    if not means:
+        sys.exit(f"no criterion results for baseline '{name}' (run: cargo bench -- --save-baseline {name})")
+
+    if mode == "record":
+        merged = {**baseline["benchmarks"], **{k: round(v, 1) for k, v in means.items()}}
+        baseline["benchmarks"] = dict(sorted(merged.items()))
+        baseline["machine"] = f"{platform.machine()} {platform.processor() or platform.system()}, {os.cpu_count()} cpus"
+        BASELINE.write_text(json.dumps(baseline, indent=2) + "\n")
+        print(f"recorded {len(means)} benchmarks into {BASELINE.relative_to(ROOT)}")
+        return
+
+    slower = []
+    for bench, reference in sorted(baseline["benchmarks"].items()):
+        mean = means.get(bench)
+        if mean is None or reference is None:
+            print(f"  {bench:<32} {'-' if mean is None else f'{mean:>12.0f} ns'}  (no {'run' if mean is None else 'baseline'})")
+            continue
+        ratio = mean / reference
+        flag = "  <-- slower" if ratio > TOLERANCE else ""
+        print(f"  {bench:<32} {mean:>12.0f} ns  x{ratio:.2f}{flag}")
+        if ratio > TOLERANCE:
+            slower.append(bench)
+    if slower:
+        sys.exit(f"{len(slower)} benchmark(s) more than {int((TOLERANCE - 1) * 100)}% slower than baseline.json")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,53 @@
+// benches/ears.rs
+// THE STOPWATCH: The ears' processor closure on one 1024-sample device chunk
+//
+//   cargo bench --bench ears
+//
+// What the closure does per chunk before the recording logic: RMS, the cochlea
+// (Hann window + FFT per hop), and one AudioSpectrum per frame sent to the
+// daemon. The recording/gating half only moves samples between buffers and
+// needs the device's shared state, so it is not part of the measurement.
+
+use aleph_zero::senses::cochlea::{AudioSpectrum, Cochlea};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::sync::mpsc;
+
+const CHUNK: usize = 1024;
+
+fn bench_processor(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ears_processor");
+    for &rate in &[16_000u32, 48_000] {
+        // A voice-like mix: 180 Hz fundamental, a 1.2 kHz formant, some hiss
+        let chunk: Vec<f32> = (0..CHUNK).map(|i| {
+            let t = i as f32 / rate as f32;
+            0.3 * (std::f32::consts::TAU * 180.0 * t).sin()
+                + 0.1 * (std::f32::consts::TAU * 1200.0 * t).sin()
+                + 0.01 * ((i * 7919) % 200) as f32 / 100.0
+        }).collect();
+        let mut cochlea = Cochlea::new(rate, 1024, 512); // [ears] defaults
+        let (tx, rx) = mpsc::channel::<AudioSpectrum>();
+        group.bench_with_input(BenchmarkId::from_parameter(rate), &rate, |b, _| {
+            b.iter(|| {
+                let data = black_box(&chunk);
+                let rms = (data.iter().map(|s| s * s).sum::<f32>() / data.len() as f32).sqrt();
+                for frame in cochlea.push(data) {
+                    let voice_profile = frame.mids > frame.highs && frame.mids > frame.bass * 0.5;
+                    let _ = tx.send(AudioSpectrum {
+                        rms: frame.rms,
+                        bass: frame.bass,
+                        mids: frame.mids,
+                        highs: frame.highs,
+                        is_voice: frame.rms > 0.01 && voice_profile,
+                        frequency_embedding: frame.bands,
+                        ..Default::default()
+                    });
+                }
+                rx.try_iter().count() + (rms > 0.0) as usize
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_processor);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+// benches/reservoir.rs
+// THE STOPWATCH: Reservoir hot loops at 500 / 1500 / 2500 neurons
+//
+//   cargo bench --bench reservoir                            (rayon per-neuron loops)
+//   cargo bench --bench reservoir --features serial-physics  (serial baseline)
+//
+// Compare the two reports to see the speedup on this machine. The matvec in
+// `tick` is the same in both runs; only the per-neuron passes change.
+//
+// The whole suite (this file, telemetry.rs, ears.rs) against the committed
+// numbers in benches/baseline.json:
+//
+//   cargo bench -- --save-baseline local
+//   python3 benches/baseline.py compare     (vs baseline.json; >15% slower = flagged)
+//   python3 benches/baseline.py record      (rewrite baseline.json from this run)
+
+use aleph_zero::core::reservoir::{self, FractalReservoir, NeuronRegion};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const SIZES: [usize; 3] = [500, 1500, 2500];
+
+fn bench_tick(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tick");
+    for &size in &SIZES {
+        let mut ego = FractalReservoir::new(size, 500, 0.95, 0.2);
+        let input: Vec<f32> = (0..500).map(|i| (i as f32 * 0.37).sin() * 0.5).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            // Strong audio + chemistry so both exposure passes run every tick
+            b.iter(|| ego.tick(black_box(&input), 0.6, 0.2, 0.4, 1.0 / 60.0));
+        });
+    }
+    group.finish();
+}
+
+fn bench_inject_logits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inject_logits");
+    let logits: Vec<f32> = (0..32000).map(|i| (i as f32 * 0.013).cos() * 8.0).collect();
+    for &size in &SIZES {
+        let mut ego = FractalReservoir::new(size, 500, 0.95, 0.2);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| ego.inject_logits(black_box(&logits)));
+        });
+    }
+    group.finish();
+}
+
+fn bench_inject_pooled(c: &mut Criterion) {
+    // What the main loop pays now that the Planet thread pools the echo
+    let mut group = c.benchmark_group("inject_pooled");
+    let logits: Vec<f32> = (0..32000).map(|i| (i as f32 * 0.013).cos() * 8.0).collect();
+    for &size in &SIZES {
+        let mut ego = FractalReservoir::new(size, 500, 0.95, 0.2);
+        let pooled = reservoir::max_pool_logits(&logits, size);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| ego.inject_pooled(black_box(&pooled)));
+        });
+    }
+    group.finish();
+}
+
+fn bench_inject_embedding(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inject_embedding");
+    let embedding: Vec<f32> = (0..64).map(|i| (i as f32 * 0.21).sin()).collect();
+    for &size in &SIZES {
+        let mut ego = FractalReservoir::new(size, 500, 0.95, 0.2);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| ego.inject_embedding(black_box(&embedding), NeuronRegion::Auditory));
+        });
+    }
+    group.finish();
+}
+
+fn bench_hebbian_update(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hebbian_update");
+    let input: Vec<f32> = (0..500).map(|i| (i as f32 * 0.37).sin() * 0.5).collect();
+    for &size in &SIZES {
+        let mut ego = FractalReservoir::new(size, 500, 0.95, 0.2);
+        for _ in 0..30 {
+            ego.tick(&input, 0.6, 0.2, 0.4, 1.0 / 60.0); // Some activity to correlate
+        }
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| ego.hebbian_update(black_box(0.7), 1.0 / 60.0));
+        });
+    }
+    group.finish();
+}
+
+fn bench_region_map(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_region_map");
+    for &size in &SIZES {
+        let ego = FractalReservoir::new(size, 500, 0.95, 0.2);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, _| {
+            b.iter(|| black_box(ego.get_region_map()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_tick, bench_inject_logits, bench_inject_pooled, bench_inject_embedding, bench_hebbian_update, bench_region_map);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+// benches/telemetry.rs
+// THE STOPWATCH: Serde of the heartbeat every TUI client gets at ~12Hz
+//
+//   cargo bench --bench telemetry
+//
+// A realistic AlephPacket::Telemetry at 2500 neurons: full activations, region
+// map and 3D positions, the 64x64 visual cortex, a 64-band spectrum and the
+// narrative/dashboard fields, serialized and parsed as JSON (the IPC wire format).
+
+use aleph_zero::core::ipc::AlephPacket;
+use aleph_zero::senses::ears::AudioSpectrum;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const NEURONS: usize = 2500;
+
+fn packet() -> AlephPacket {
+    let wave = |i: usize| (i as f32 * 0.37).sin();
+    let suppressed = ["cooldown", "fatigue", "hallucination", "low_drive"].iter()
+        .enumerate().map(|(i, r)| (r.to_string(), 10 * i as u64 + 3)).collect();
+    AlephPacket::Telemetry {
+        adenosine: 0.31, cortisol: 0.12, dopamine: 0.58, oxytocin: 0.2,
+        audio_spectrum: AudioSpectrum {
+            rms: 0.04, bass: 0.3, mids: 0.5, highs: 0.1, is_voice: true,
+            frequency_embedding: (0..64).map(|i| wave(i).abs()).collect(),
+            ..Default::default()
+        },
+        heart_rate: 0.4, lucidity: 0.7,
+        reservoir_activity: (0..NEURONS).map(wave).collect(),
+        short_term_memory: (0..20).map(|i| format!("12:00:{:02} 🎤 Hearing: 'frase número {}, con acentos y emoji 🌊'", i, i)).collect(),
+        current_state: "Curious (Dopa 0.58)".to_string(),
+        entropy: 0.46, loop_frequency: 59.8, cpu_usage: 0.35,
+        activations: (0..NEURONS).map(|i| wave(i) * 0.5).collect(),
+        region_map: (0..NEURONS).map(|i| (i % 7) as u8).collect(),
+        reservoir_size: NEURONS,
+        visual_cortex: (0..64 * 64).map(|i| wave(i).abs()).collect(),
+        neuron_positions: (0..NEURONS).map(|i| [wave(i), wave(i + 1), wave(i + 2)]).collect(),
+        privacy_mode: false,
+        satellite_passed: 12, satellite_hardened: 3, satellite_ontological_errors: 1, satellite_attention: 0.8,
+        gate_attempts: 140, gate_spoken: 31, gate_suppressed_by_reason: suppressed,
+    }
+}
+
+fn bench_serialize(c: &mut Criterion) {
+    let packet = packet();
+    c.bench_function("telemetry/serialize", |b| {
+        b.iter(|| serde_json::to_string(black_box(&packet)).unwrap());
+    });
+}
+
+fn bench_deserialize(c: &mut Criterion) {
+    let json = serde_json::to_string(&packet()).unwrap();
+    c.bench_function("telemetry/deserialize", |b| {
+        b.iter(|| serde_json::from_str::<AlephPacket>(black_box(&json)).unwrap());
+    });
+}
+
+criterion_group!(benches, bench_serialize, bench_deserialize);
+criterion_main!(benches);
@@ -1 +1,4 @@
 pub mod voice;
+pub mod registry; // THE HANDS (Capability Whitelist, Rate Limits, Action Log)
+pub mod notes; // THE QUILL (Journal in notes/)
+pub mod notify; // THE TAP ON THE SHOULDER (Desktop Notifications)
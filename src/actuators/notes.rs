@@ -0,0 +1,49 @@
+// src/actuators/notes.rs
+// THE QUILL: A journal ALEPH keeps in notes/
+//
+// The first actuator that leaves a trace instead of a sound. A thought that
+// resonated strongly (sieve::resonance_of >= actuators.journal_resonance),
+// made it through the expression gate and arrived while the Journal goal is
+// active is appended to notes/journal.md as one timestamped bullet. Only if
+// "notes" is in the whitelist (registry.rs); at most one entry per
+// min_interval_secs.notes.
+
+use anyhow::{Context, Result};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::actuators::registry::{Actuator, NOTES};
+
+const JOURNAL_FILE: &str = "journal.md";
+
+pub struct NotesWriter {
+    dir: PathBuf,
+}
+
+impl NotesWriter {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl Actuator for NotesWriter {
+    fn capability(&self) -> &'static str {
+        NOTES
+    }
+
+    fn act(&mut self, text: &str) -> Result<()> {
+        fs::create_dir_all(&self.dir).with_context(|| format!("creating {}", self.dir.display()))?;
+        let path = self.dir.join(JOURNAL_FILE);
+        let fresh = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)
+            .with_context(|| format!("opening {}", path.display()))?;
+        if fresh {
+            writeln!(file, "# ALEPH journal\n")?;
+        }
+        // One line per entry: a multi-line thought stays one bullet
+        let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        writeln!(file, "- **{}** {}", chrono::Local::now().format("%Y-%m-%d %H:%M"), line)?;
+        Ok(())
+    }
+}
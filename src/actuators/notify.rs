@@ -0,0 +1,232 @@
+// src/actuators/notify.rs
+// THE TAP ON THE SHOULDER: Desktop notifications for what shouldn't be missed
+//
+// ALEPH speaking while you work in another window is easy to miss. A bus
+// subscriber (`attach`) picks out the high-salience thoughts:
+//
+//   vocal         what it said out loud
+//   trauma        a trauma escalation (escalating / firefighter)
+//   forced_sleep  metabolic collapse: forced sleep
+//   goal          a goal achieved
+//
+// each class with its own flag in [notifications], and raises one desktop
+// notification (notify-send) through the registry's "notify" capability, so
+// it must be whitelisted ([actuators] whitelist) like any other action.
+//
+// Never in the quiet hours (by default the circadian night the daemon already
+// presses adenosine with, daemon_state::NIGHT_*). Bursts are coalesced: what
+// arrives within coalesce_secs of the last popup waits and goes out as one
+// ("3 thoughts: ..."), so a manic episode is one popup, not fifty.
+//
+// The subscriber has its own channel and thread: nothing it does blocks the
+// bus. A missing notify-send or no DBus (a container, a server) is a failed
+// action in the actuator log and nothing else.
+
+use anyhow::{bail, Result};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Timelike;
+
+use crate::actuators::registry::{Actuator, Registry, NOTIFY};
+use crate::core::bus::{Bus, Event};
+use crate::core::config::NotificationsConfig;
+use crate::core::daemon_state::within_hours;
+use crate::core::textutil::truncate_chars;
+use crate::core::thought::{MindVoice, Thought};
+
+/// Characters of a thought shown in a popup.
+const BODY_CHARS: usize = 200;
+
+pub struct DesktopNotifier;
+
+impl Actuator for DesktopNotifier {
+    fn capability(&self) -> &'static str {
+        NOTIFY
+    }
+
+    fn act(&mut self, text: &str) -> Result<()> {
+        let status = Command::new("notify-send")
+            .args(["--app-name", "ALEPH", "ALEPH", text])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+        if !status.success() {
+            bail!("notify-send exited with {}", status);
+        }
+        Ok(())
+    }
+}
+
+/// What makes a thought worth a popup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyClass {
+    Vocal,
+    Trauma,
+    ForcedSleep,
+    Goal,
+}
+
+impl NotifyClass {
+    /// From the voice, or the `event` in the payload; None = not notifiable.
+    pub fn of(thought: &Thought) -> Option<Self> {
+        if thought.voice == MindVoice::Vocal {
+            return Some(NotifyClass::Vocal);
+        }
+        let payload = thought.payload.as_ref()?;
+        match payload.get("event")?.as_str()? {
+            "trauma" if payload.get("escalating").and_then(|e| e.as_bool()) == Some(true) => Some(NotifyClass::Trauma),
+            "forced_sleep" => Some(NotifyClass::ForcedSleep),
+            "goal_achieved" => Some(NotifyClass::Goal),
+            _ => None,
+        }
+    }
+
+    pub fn enabled(self, config: &NotificationsConfig) -> bool {
+        match self {
+            NotifyClass::Vocal => config.vocal,
+            NotifyClass::Trauma => config.trauma,
+            NotifyClass::ForcedSleep => config.forced_sleep,
+            NotifyClass::Goal => config.goals,
+        }
+    }
+}
+
+/// In the quiet-hours window (local hour)?
+pub fn quiet(config: &NotificationsConfig, hour: u32) -> bool {
+    config.quiet_hours && within_hours(hour, config.quiet_start_hour, config.quiet_end_hour)
+}
+
+/// One popup: the body and the thought it answers (the latest of a burst).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Popup {
+    pub body: String,
+    pub thought_id: u64,
+}
+
+/// Holds notifiable thoughts until coalesce_secs have passed since the last popup.
+pub struct Coalescer {
+    window: Duration,
+    last_popup: Option<Instant>,
+    pending: Vec<(u64, String)>,
+}
+
+impl Coalescer {
+    pub fn new(coalesce_secs: f32) -> Self {
+        Self { window: Duration::from_secs_f32(coalesce_secs.max(0.0)), last_popup: None, pending: Vec::new() }
+    }
+
+    pub fn push(&mut self, thought_id: u64, text: &str) {
+        self.pending.push((thought_id, text.to_string()));
+    }
+
+    /// When the next popup may go out, if anything is waiting.
+    pub fn next_due(&self) -> Option<Instant> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        Some(self.last_popup.map(|last| last + self.window).unwrap_or_else(Instant::now))
+    }
+
+    /// The popup to raise now, if one is waiting and the window has passed.
+    pub fn due(&mut self, now: Instant) -> Option<Popup> {
+        if self.pending.is_empty() || self.last_popup.is_some_and(|last| now.saturating_duration_since(last) < self.window) {
+            return None;
+        }
+        self.last_popup = Some(now);
+        let count = self.pending.len();
+        let (thought_id, text) = self.pending.pop()?;
+        self.pending.clear();
+        let text = truncate_chars(&text, BODY_CHARS);
+        let body = if count == 1 { text.to_string() } else { format!("{} thoughts: {}", count, text) };
+        Some(Popup { body, thought_id })
+    }
+}
+
+/// Subscribe to the bus and raise notifications from a thread of its own.
+pub fn attach(bus: &Bus, config: NotificationsConfig, actuators: Arc<Mutex<Registry>>) {
+    let rx = bus.subscribe();
+    thread::spawn(move || {
+        let mut coalescer = Coalescer::new(config.coalesce_secs);
+        loop {
+            let wait = coalescer.next_due()
+                .map(|due| due.saturating_duration_since(Instant::now()))
+                .unwrap_or(Duration::from_secs(3600));
+            match rx.recv_timeout(wait) {
+                Ok(Event::Thought(thought)) => {
+                    let notifiable = NotifyClass::of(&thought).is_some_and(|class| class.enabled(&config));
+                    if notifiable && !quiet(&config, chrono::Local::now().hour()) {
+                        coalescer.push(thought.id, &thought.text);
+                    }
+                },
+                Ok(_) | Err(RecvTimeoutError::Timeout) => {},
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            if let Some(popup) = coalescer.due(Instant::now()) {
+                if let Ok(mut registry) = actuators.lock() {
+                    // Failures are logged by the registry; nothing else to do about them
+                    let _ = registry.request(NOTIFY, &popup.body, popup.thought_id, Instant::now());
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(voice: MindVoice, payload: serde_json::Value) -> Thought {
+        Thought::new(voice, "x".to_string()).with_payload(payload)
+    }
+
+    #[test]
+    fn each_class_is_picked_out_and_calm_or_plain_thoughts_are_not() {
+        let cases = [
+            (Thought::new(MindVoice::Vocal, "hola".to_string()), Some(NotifyClass::Vocal)),
+            (event(MindVoice::System, serde_json::json!({ "event": "trauma", "escalating": true })), Some(NotifyClass::Trauma)),
+            (event(MindVoice::System, serde_json::json!({ "event": "trauma", "escalating": false })), None),
+            (event(MindVoice::Dream, serde_json::json!({ "event": "forced_sleep" })), Some(NotifyClass::ForcedSleep)),
+            (event(MindVoice::System, serde_json::json!({ "event": "goal_achieved", "reward": 0.5 })), Some(NotifyClass::Goal)),
+            (Thought::new(MindVoice::System, "🏆 GOAL ACHIEVED".to_string()), None),
+        ];
+        for (thought, expected) in &cases {
+            assert_eq!(NotifyClass::of(thought), *expected, "{:?} '{}' {:?}", thought.voice, thought.text, thought.payload);
+        }
+    }
+
+    #[test]
+    fn a_class_flag_mutes_that_class_only() {
+        let config = NotificationsConfig { vocal: false, ..NotificationsConfig::default() };
+        assert!(!NotifyClass::Vocal.enabled(&config));
+        assert!(NotifyClass::Trauma.enabled(&config));
+    }
+
+    #[test]
+    fn quiet_hours_wrap_past_midnight() {
+        let config = NotificationsConfig { quiet_start_hour: 23, quiet_end_hour: 6, ..NotificationsConfig::default() };
+        let night: Vec<u32> = (0..24).filter(|h| quiet(&config, *h)).collect();
+        assert_eq!(night, [0, 1, 2, 3, 4, 5, 23]);
+        // Off = never quiet
+        let loud = NotificationsConfig { quiet_hours: false, ..config };
+        assert!(!(0..24).any(|h| quiet(&loud, h)));
+    }
+
+    #[test]
+    fn a_burst_inside_the_window_is_one_popup_with_the_count_and_the_latest() {
+        let mut coalescer = Coalescer::new(10.0);
+        let t0 = Instant::now();
+        coalescer.push(1, "first");
+        assert_eq!(coalescer.due(t0).map(|p| p.body).as_deref(), Some("first"));
+        for id in 2..=51 {
+            coalescer.push(id, &format!("manic {}", id));
+        }
+        // The next burst waits for the window
+        assert_eq!(coalescer.due(t0 + Duration::from_secs(5)), None);
+        assert_eq!(coalescer.due(t0 + Duration::from_secs(10)), Some(Popup { body: "50 thoughts: manic 51".to_string(), thought_id: 51 }));
+        assert_eq!(coalescer.due(t0 + Duration::from_secs(30)), None);
+    }
+}
@@ -0,0 +1,303 @@
+// src/actuators/registry.rs
+// THE HANDS: What ALEPH may do to the world, and how often
+//
+// Voice was the only actuator and it was called straight from the thought
+// logger. Anything else (a note, a desktop notification, an MQTT message)
+// would have been one more ad hoc call. Now every actuator registers under a
+// capability name, and every action goes through one door:
+//
+//   whitelist      [actuators] whitelist in aleph.toml: a capability that is not
+//                  listed is never invoked, registered or not (default: voice only)
+//   rate limit     [actuators.min_interval_secs]: at most one action per
+//                  capability per interval
+//   log            every request, done or refused, is one line in
+//                  logs/actuators.ndjson with the thought that triggered it
+//
+// Capabilities: voice (Piper, or the dashboard without a local voice), notes
+// (the journal, actuators/notes.rs), notify (desktop notification) and mqtt,
+// reserved for the publisher (nothing registers it in this tree yet).
+
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::actuators::voice::Delivery;
+use crate::core::config::ActuatorsConfig;
+use crate::core::textutil::truncate_chars;
+
+pub const VOICE: &str = "voice";
+pub const NOTES: &str = "notes";
+pub const NOTIFY: &str = "notify";
+pub const MQTT: &str = "mqtt";
+
+/// Characters of the acted-on text kept in the log line.
+const LOGGED_CHARS: usize = 160;
+
+/// Something ALEPH can do outside itself.
+pub trait Actuator: Send {
+    fn capability(&self) -> &'static str;
+    fn act(&mut self, text: &str) -> Result<()>;
+    /// `act` with a delivery (volume, pace); actuators without a voice ignore it.
+    fn act_with(&mut self, text: &str, _delivery: Delivery) -> Result<()> {
+        self.act(text)
+    }
+}
+
+/// Why an action was not carried out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Refusal {
+    NotWhitelisted, // The config doesn't grant the capability
+    Unregistered,   // Granted, but nothing provides it
+    RateLimited,    // Acted less than min_interval_secs ago
+    Failed(String), // The actuator tried and failed
+}
+
+impl Refusal {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Refusal::NotWhitelisted => "not_whitelisted",
+            Refusal::Unregistered => "unregistered",
+            Refusal::RateLimited => "rate_limited",
+            Refusal::Failed(_) => "failed",
+        }
+    }
+}
+
+/// Requests since boot (telemetry), per capability.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ActuatorStats {
+    pub done: BTreeMap<String, u64>,
+    pub refused: BTreeMap<String, u64>, // "<capability>:<Refusal::name>"
+}
+
+#[derive(Serialize)]
+struct Invocation<'a> {
+    at: String,
+    capability: &'a str,
+    thought_id: u64,
+    outcome: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    text: &'a str,
+}
+
+pub struct Registry {
+    actuators: HashMap<&'static str, Box<dyn Actuator>>,
+    whitelist: HashSet<String>,
+    min_interval: HashMap<String, Duration>,
+    last_action: HashMap<String, Instant>,
+    log_path: Option<PathBuf>,
+    log: Option<BufWriter<File>>,
+    log_failed: bool,
+    pub stats: ActuatorStats,
+}
+
+impl Registry {
+    pub fn new(config: &ActuatorsConfig) -> Self {
+        Self {
+            actuators: HashMap::new(),
+            whitelist: config.whitelist.iter().cloned().collect(),
+            min_interval: config.min_interval_secs.iter()
+                .map(|(capability, secs)| (capability.clone(), Duration::from_secs_f32(secs.max(0.0))))
+                .collect(),
+            last_action: HashMap::new(),
+            log_path: (!config.log.is_empty()).then(|| PathBuf::from(&config.log)),
+            log: None,
+            log_failed: false,
+            stats: ActuatorStats::default(),
+        }
+    }
+
+    /// Later registrations of the same capability replace earlier ones.
+    pub fn register(&mut self, actuator: Box<dyn Actuator>) {
+        self.actuators.insert(actuator.capability(), actuator);
+    }
+
+    /// Whitelisted and provided (callers can skip building an action that would be refused).
+    pub fn allows(&self, capability: &str) -> bool {
+        self.whitelist.contains(capability) && self.actuators.contains_key(capability)
+    }
+
+    /// Carry out `text` through `capability` on behalf of thought `thought_id`.
+    /// Every request is counted and logged, whatever the outcome.
+    pub fn request(&mut self, capability: &str, text: &str, thought_id: u64, now: Instant) -> Result<(), Refusal> {
+        self.request_with(capability, text, Delivery::default(), thought_id, now)
+    }
+
+    /// `request`, said with a given delivery (sleep-talk mumbles).
+    pub fn request_with(&mut self, capability: &str, text: &str, delivery: Delivery, thought_id: u64, now: Instant) -> Result<(), Refusal> {
+        let outcome = self.decide(capability, text, delivery, now);
+        match &outcome {
+            Ok(()) => *self.stats.done.entry(capability.to_string()).or_insert(0) += 1,
+            Err(refusal) => *self.stats.refused.entry(format!("{}:{}", capability, refusal.name())).or_insert(0) += 1,
+        }
+        self.record(capability, text, thought_id, &outcome);
+        outcome
+    }
+
+    fn decide(&mut self, capability: &str, text: &str, delivery: Delivery, now: Instant) -> Result<(), Refusal> {
+        if !self.whitelist.contains(capability) {
+            return Err(Refusal::NotWhitelisted);
+        }
+        let Some(actuator) = self.actuators.get_mut(capability) else {
+            return Err(Refusal::Unregistered);
+        };
+        let interval = self.min_interval.get(capability).copied().unwrap_or_default();
+        if self.last_action.get(capability).is_some_and(|last| now.saturating_duration_since(*last) < interval) {
+            return Err(Refusal::RateLimited);
+        }
+        // A failed attempt still counts against the rate (a broken actuator isn't hammered)
+        self.last_action.insert(capability.to_string(), now);
+        actuator.act_with(text, delivery).map_err(|e| Refusal::Failed(e.to_string()))
+    }
+
+    fn record(&mut self, capability: &str, text: &str, thought_id: u64, outcome: &Result<(), Refusal>) {
+        let Some(path) = self.log_path.clone() else { return };
+        let invocation = Invocation {
+            at: chrono::Local::now().to_rfc3339(),
+            capability,
+            thought_id,
+            outcome: outcome.as_ref().err().map(Refusal::name).unwrap_or("done"),
+            error: match outcome {
+                Err(Refusal::Failed(e)) => Some(e.as_str()),
+                _ => None,
+            },
+            text: truncate_chars(text, LOGGED_CHARS),
+        };
+        if let Err(e) = self.append(&path, &invocation) {
+            if !self.log_failed {
+                println!("⚠️ ACTUATORS: Log {} unavailable ({}). Counting only.", path.display(), e);
+            }
+            self.log_failed = true;
+            self.log = None;
+        }
+    }
+
+    fn append(&mut self, path: &Path, invocation: &Invocation) -> std::io::Result<()> {
+        if self.log.is_none() {
+            if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+                fs::create_dir_all(dir)?;
+            }
+            self.log = Some(BufWriter::new(OpenOptions::new().create(true).append(true).open(path)?));
+            self.log_failed = false;
+        }
+        if let Some(log) = self.log.as_mut() {
+            serde_json::to_writer(&mut *log, invocation)?;
+            log.write_all(b"\n")?;
+            log.flush()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Counts what it was asked to do; fails on demand.
+    struct Probe {
+        capability: &'static str,
+        acted: Arc<Mutex<Vec<String>>>,
+        broken: bool,
+    }
+
+    impl Actuator for Probe {
+        fn capability(&self) -> &'static str {
+            self.capability
+        }
+
+        fn act(&mut self, text: &str) -> Result<()> {
+            if self.broken {
+                anyhow::bail!("probe is broken");
+            }
+            self.acted.lock().map_err(|_| anyhow::anyhow!("poisoned"))?.push(text.to_string());
+            Ok(())
+        }
+    }
+
+    /// Notes, notify and mqtt granted (notes 60 s apart, notify 10 s), a voice, notes,
+    /// notify and a broken actuator registered; what they did, and the log path.
+    fn registry(name: &str) -> (Registry, Arc<Mutex<Vec<String>>>, PathBuf) {
+        let log = std::env::temp_dir().join(format!("aleph-actuators-{}-{}.ndjson", name, std::process::id()));
+        let _ = fs::remove_file(&log);
+        let config = ActuatorsConfig {
+            whitelist: vec![NOTES.to_string(), NOTIFY.to_string(), MQTT.to_string(), "broken".to_string()],
+            min_interval_secs: [(NOTES.to_string(), 60.0), (NOTIFY.to_string(), 10.0)].into_iter().collect(),
+            log: log.to_string_lossy().into_owned(),
+            ..ActuatorsConfig::default()
+        };
+        let acted = Arc::new(Mutex::new(Vec::new()));
+        let mut registry = Registry::new(&config);
+        for (capability, broken) in [(VOICE, false), (NOTES, false), (NOTIFY, false), ("broken", true)] {
+            registry.register(Box::new(Probe { capability, acted: acted.clone(), broken }));
+        }
+        (registry, acted, log)
+    }
+
+    #[test]
+    fn outside_the_whitelist_is_never_invoked_and_granted_without_an_actuator_is_refused() {
+        let (mut registry, acted, log) = registry("whitelist");
+        let t0 = Instant::now();
+        assert_eq!(registry.request(VOICE, "hola", 1, t0), Err(Refusal::NotWhitelisted));
+        assert!(acted.lock().unwrap().is_empty());
+        assert!(!registry.allows(VOICE));
+        assert_eq!(registry.request(MQTT, "hola", 2, t0), Err(Refusal::Unregistered));
+        assert!(!registry.allows(MQTT));
+        let _ = fs::remove_file(&log);
+    }
+
+    #[test]
+    fn capabilities_are_rate_limited_independently() {
+        let (mut registry, acted, log) = registry("rate");
+        let t0 = Instant::now();
+        // One note per minute, the next one after it
+        let notes = [
+            registry.request(NOTES, "first", 3, t0),
+            registry.request(NOTES, "second", 4, t0 + Duration::from_secs(30)),
+            registry.request(NOTES, "third", 5, t0 + Duration::from_secs(61)),
+        ];
+        assert_eq!(notes, [Ok(()), Err(Refusal::RateLimited), Ok(())]);
+        assert_eq!(registry.request(NOTIFY, "ping", 6, t0 + Duration::from_secs(30)), Ok(()));
+        assert_eq!(*acted.lock().unwrap(), ["first", "third", "ping"]);
+        let _ = fs::remove_file(&log);
+    }
+
+    #[test]
+    fn a_failing_actuator_is_refused_as_failed() {
+        let (mut registry, _, log) = registry("broken");
+        let broken = registry.request("broken", "x", 7, Instant::now());
+        assert!(matches!(broken, Err(Refusal::Failed(_))), "{:?}", broken);
+        assert!(!registry.stats.done.contains_key("broken"));
+        let _ = fs::remove_file(&log);
+    }
+
+    #[test]
+    fn every_request_reaches_the_log_with_its_thought_id_and_outcome() {
+        let (mut registry, _, log) = registry("log");
+        let t0 = Instant::now();
+        let _ = registry.request(VOICE, "hola", 1, t0);
+        let _ = registry.request(MQTT, "hola", 2, t0);
+        let _ = registry.request(NOTES, "first", 3, t0);
+        let _ = registry.request(NOTES, "second", 4, t0 + Duration::from_secs(30));
+        let _ = registry.request(NOTES, "third", 5, t0 + Duration::from_secs(61));
+        let _ = registry.request(NOTIFY, "ping", 6, t0 + Duration::from_secs(30));
+        let _ = registry.request("broken", "x", 7, t0);
+        drop(registry);
+        let logged: Vec<(u64, String)> = fs::read_to_string(&log).unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .map(|l| (l["thought_id"].as_u64().unwrap_or(0), l["outcome"].as_str().unwrap_or("").to_string()))
+            .collect();
+        let _ = fs::remove_file(&log);
+        let expected: Vec<(u64, String)> = [
+            (1, "not_whitelisted"), (2, "unregistered"), (3, "done"), (4, "rate_limited"),
+            (5, "done"), (6, "done"), (7, "failed"),
+        ].iter().map(|(id, outcome)| (*id, outcome.to_string())).collect();
+        assert_eq!(logged, expected);
+    }
+}
@@ -1,28 +1,47 @@
 use std::process::{Command, Stdio};
 use std::thread;
 use std::sync::mpsc::{self, Sender};
-use std::sync::OnceLock;
+use std::sync::{Arc, Mutex, OnceLock};
+use crate::actuators::registry::{Actuator, VOICE};
 use crate::core::thought::{Thought, MindVoice};
-use std::io::Write;
+use std::io::{Read, Write};
+
+/// Utterances waiting for the dashboard (no local voice); more are dropped.
+const MAX_PENDING_SPEECH: usize = 32;
+
+/// How an utterance is said: 1.0 is Piper's own volume and pace. Sleep-talk
+/// mumbles (quieter and slower); everything else uses the default.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delivery {
+    pub volume: f32, // Sample gain (0-1)
+    pub rate: f32,   // Speaking rate (Piper's length_scale is its inverse)
+}
+
+impl Default for Delivery {
+    fn default() -> Self {
+        Self { volume: 1.0, rate: 1.0 }
+    }
+}
 
 // Global Serial Queue
-static VOICE_QUEUE: OnceLock<Sender<String>> = OnceLock::new();
+static VOICE_QUEUE: OnceLock<Sender<(String, Delivery)>> = OnceLock::new();
 
 /// Initialize the voice subsystem (starts background thread)
-fn get_queue() -> &'static Sender<String> {
+fn get_queue() -> &'static Sender<(String, Delivery)> {
     VOICE_QUEUE.get_or_init(|| {
-        let (tx, rx) = mpsc::channel::<String>();
+        let (tx, rx) = mpsc::channel::<(String, Delivery)>();
         
         thread::spawn(move || {
             // Serial Consumer Loop
-            while let Ok(text) = rx.recv() {
+            while let Ok((text, delivery)) = rx.recv() {
                 // Ignore empty or very short bursts (silence)
                 if text.trim().len() < 2 { continue; }
 
                 // Determine if we should mute (simple heuristic check if we had logic, here we just play)
                 // RUN PIPER
+                let length_scale = format!("{:.2}", 1.0 / delivery.rate.clamp(0.25, 4.0));
                 let mut piper_child = match Command::new("./piper/piper/piper")
-                    .args(&["--model", "./piper/es_ES-sharvard-medium.onnx", "--output_raw"])
+                    .args(&["--model", "./piper/es_ES-sharvard-medium.onnx", "--output_raw", "--length_scale", &length_scale])
                     .stdin(Stdio::piped())
                     .stdout(Stdio::piped())
                     .stderr(Stdio::null())
@@ -36,13 +55,24 @@ fn get_queue() -> &'static Sender<String> {
                     let _ = stdin.write_all(text.as_bytes());
                 }
 
-                if let Some(piper_out) = piper_child.stdout.take() {
-                    let _ = Command::new("aplay")
-                        .args(&["-r", "22050", "-f", "S16_LE", "-t", "raw"])
-                        .stdin(piper_out)
+                if let Some(mut piper_out) = piper_child.stdout.take() {
+                    let mut aplay = Command::new("aplay");
+                    aplay.args(&["-r", "22050", "-f", "S16_LE", "-t", "raw"])
                         .stdout(Stdio::null())
-                        .stderr(Stdio::null())
-                        .status();
+                        .stderr(Stdio::null());
+                    if delivery.volume >= 1.0 {
+                        let _ = aplay.stdin(piper_out).status();
+                    } else {
+                        // Quieter: scale the samples on their way to the speaker
+                        let mut raw = Vec::new();
+                        let _ = piper_out.read_to_end(&mut raw);
+                        if let Ok(mut player) = aplay.stdin(Stdio::piped()).spawn() {
+                            if let Some(mut stdin) = player.stdin.take() {
+                                let _ = stdin.write_all(&attenuate(&raw, delivery.volume));
+                            }
+                            let _ = player.wait();
+                        }
+                    }
                 }
                 let _ = piper_child.wait();
             }
@@ -51,15 +81,70 @@ fn get_queue() -> &'static Sender<String> {
     })
 }
 
+/// 16-bit little-endian PCM scaled by `volume` (0-1).
+pub fn attenuate(raw: &[u8], volume: f32) -> Vec<u8> {
+    let gain = volume.clamp(0.0, 1.0);
+    raw.chunks_exact(2)
+        .flat_map(|pair| ((i16::from_le_bytes([pair[0], pair[1]]) as f32 * gain) as i16).to_le_bytes())
+        .collect()
+}
+
 /// Neural Voice Actuator via Piper TTS (Queued)
 pub fn speak(text: String, _tx_thought: Sender<Thought>) {
+    speak_with(text, Delivery::default(), _tx_thought);
+}
+
+/// `speak`, said with a given volume and pace.
+pub fn speak_with(text: String, delivery: Delivery, _tx_thought: Sender<Thought>) {
     let queue = get_queue();
     // Log intent to speak
     println!(">> VOCAL QUEUE: '{}'", text);
     let _ = _tx_thought.send(Thought::new(MindVoice::System, format!(">> VOCAL QUEUE: '{}'", text)));
     
     // Send to serial thread
-    let _ = queue.send(text);
+    let _ = queue.send((text, delivery));
+}
+
+/// The voice capability (actuators/registry.rs): Piper on this machine, or, without a
+/// local voice (container profile), the dashboard's outbox, sent as `speech` in the
+/// next WebSocket frame for the browser to say.
+pub struct VoiceActuator {
+    tx_thought: Sender<Thought>,
+    outbox: Option<Arc<Mutex<Vec<String>>>>,
+}
+
+impl VoiceActuator {
+    pub fn local(tx_thought: Sender<Thought>) -> Self {
+        Self { tx_thought, outbox: None }
+    }
+
+    pub fn dashboard(tx_thought: Sender<Thought>, outbox: Arc<Mutex<Vec<String>>>) -> Self {
+        Self { tx_thought, outbox: Some(outbox) }
+    }
+}
+
+impl Actuator for VoiceActuator {
+    fn capability(&self) -> &'static str {
+        VOICE
+    }
+
+    fn act(&mut self, text: &str) -> anyhow::Result<()> {
+        self.act_with(text, Delivery::default())
+    }
+
+    /// The dashboard's speech protocol carries text only: the browser says it at its own volume.
+    fn act_with(&mut self, text: &str, delivery: Delivery) -> anyhow::Result<()> {
+        let Some(outbox) = &self.outbox else {
+            speak_with(text.to_string(), delivery, self.tx_thought.clone());
+            return Ok(());
+        };
+        let mut pending = outbox.lock().map_err(|_| anyhow::anyhow!("speech outbox poisoned"))?;
+        if pending.len() >= MAX_PENDING_SPEECH {
+            anyhow::bail!("{} utterances already waiting for the dashboard", pending.len());
+        }
+        pending.push(text.to_string());
+        Ok(())
+    }
 }
 
 /// Generates a glitch sound (white noise) of a given intensity
@@ -71,12 +156,12 @@ pub fn glitch(intensity: f32) {
         let num_samples = (sample_rate as f32 * duration) as usize;
         
         let mut noise_data = Vec::with_capacity(num_samples * 2);
-        let mut rng = rand::thread_rng();
+        let mut rng = rand::rng();
         use rand::Rng;
 
         for _ in 0..num_samples {
             // White noise: Random value between -32767 and 32767
-            let sample = (rng.gen::<f32>() * 2.0 - 1.0) * 32767.0 * intensity.min(1.0);
+            let sample = (rng.random::<f32>() * 2.0 - 1.0) * 32767.0 * intensity.min(1.0);
             let sample_i16 = sample as i16;
             noise_data.extend_from_slice(&sample_i16.to_le_bytes());
         }
@@ -0,0 +1,305 @@
+// src/core/admin.rs
+// THE WORKBENCH: Maintenance commands over the IPC socket
+//
+// Maintenance used to ride on stimuli: "SYS:SLEEP" typed into the same channel
+// as "hola", one magic string per feature, unauthenticated. Admin requests are
+// packets of their own now:
+//
+//   Client -> Daemon   AlephPacket::Admin { command, args, secret }
+//   Daemon -> Client   AlephPacket::AdminReply { command, ok, reply }
+//
+// `secret` must match `[admin] secret` (empty = the workbench is closed). The
+// IPC thread checks it and parses the command; the loop executes it between two
+// ticks (it owns the brain, the chemistry and the lexicon) and answers with
+// structured JSON. An unknown command answers the list of known ones.
+//
+// Lesions are also reachable over HTTP (POST /lesion {"region": "Auditory"},
+// DELETE /lesion), the secret as an `Authorization: Bearer` token; the web thread
+// parses the request into the same command and waits for the same reply.
+//
+// `aleph ctl <command> [args]` sends one command and prints the reply; plain
+// `aleph ctl` is a REPL on the same connection. SYS: stimuli are deprecated but
+// still accepted (the limiter says so once per session).
+
+use anyhow::{anyhow, bail, Result};
+use std::io::{BufRead, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use crate::core::ipc::{self, AlephPacket, LineBuffer, MAX_TELEMETRY_LINE};
+use crate::core::reservoir::NeuronRegion;
+
+/// Every command, with its usage (`help`, and the error for an unknown one).
+pub const COMMANDS: [(&str, &str); 13] = [
+    ("help", "help: this list"),
+    ("save", "save: checkpoint the brain and the memories now"),
+    ("consolidate", "consolidate: consolidation rounds until the volatile backlog is empty"),
+    ("regions", "regions: neurons and multimodality per region"),
+    ("set", "set <dopamine|cortisol|adenosine|oxytocin|serotonin|glucose> <0..1>: one chemical, now"),
+    ("lexicon", "lexicon: reload lexicon.toml now"),
+    ("clients", "clients: connected IPC and WebSocket clients"),
+    ("tail", "tail [n]: the last n thoughts (default 20, at most 500)"),
+    ("sleep", "sleep: force sleep (was SYS:SLEEP)"),
+    ("poke", "poke: wake up (was SYS:POKE)"),
+    ("privacy", "privacy <on|off>: Do-Not-Disturb (was SYS:DND_ON / SYS:DND_OFF)"),
+    ("reload-genome", "reload-genome: re-read genome.json (was SYS:RELOAD_GENOME)"),
+    ("lesion", "lesion <semantic|auditory|limbic|association|visual|clear>: silence a region (weights kept), or lift it"),
+];
+
+pub const CHEMICALS: [&str; 6] = ["dopamine", "cortisol", "adenosine", "oxytocin", "serotonin", "glucose"];
+
+const DEFAULT_TAIL: usize = 20;
+const MAX_TAIL: usize = 500;
+/// How long the IPC thread waits for the loop before answering a timeout.
+pub const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdminCommand {
+    Help,
+    Save,
+    Consolidate,
+    Regions,
+    Set { chemical: &'static str, value: f32 },
+    Lexicon,
+    Clients,
+    Tail(usize),
+    Sleep,
+    Poke,
+    Privacy(bool),
+    ReloadGenome,
+    /// Silence a region (None = lift the lesion).
+    Lesion(Option<NeuronRegion>),
+}
+
+/// A parsed command on its way to the loop, with where the reply goes.
+pub struct AdminRequest {
+    pub command: AdminCommand,
+    pub reply_tx: Sender<Result<serde_json::Value, String>>,
+}
+
+pub fn names() -> Vec<&'static str> {
+    COMMANDS.iter().map(|(name, _)| *name).collect()
+}
+
+/// The help text as JSON (also what `help` answers).
+pub fn help() -> serde_json::Value {
+    serde_json::json!({ "commands": COMMANDS.iter().map(|(_, usage)| *usage).collect::<Vec<_>>() })
+}
+
+pub fn parse(command: &str, args: &[String]) -> Result<AdminCommand, String> {
+    let arg = |i: usize| args.get(i).map(|a| a.trim().to_lowercase());
+    let usage = |name: &str| COMMANDS.iter().find(|(n, _)| *n == name).map(|(_, u)| u.to_string()).unwrap_or_default();
+    Ok(match command.trim().to_lowercase().as_str() {
+        "help" => AdminCommand::Help,
+        "save" => AdminCommand::Save,
+        "consolidate" => AdminCommand::Consolidate,
+        "regions" => AdminCommand::Regions,
+        "set" => {
+            let name = arg(0).unwrap_or_default();
+            let chemical = CHEMICALS.iter().copied().find(|c| *c == name).ok_or_else(|| usage("set"))?;
+            let value: f32 = arg(1).and_then(|v| v.parse().ok()).ok_or_else(|| usage("set"))?;
+            if !(0.0..=1.0).contains(&value) {
+                return Err(format!("{} out of range ({})", value, usage("set")));
+            }
+            AdminCommand::Set { chemical, value }
+        },
+        "lexicon" => AdminCommand::Lexicon,
+        "clients" => AdminCommand::Clients,
+        "tail" => match arg(0) {
+            None => AdminCommand::Tail(DEFAULT_TAIL),
+            Some(n) => AdminCommand::Tail(n.parse::<usize>().map_err(|_| usage("tail"))?.clamp(1, MAX_TAIL)),
+        },
+        "sleep" => AdminCommand::Sleep,
+        "poke" => AdminCommand::Poke,
+        "privacy" => match arg(0).as_deref() {
+            Some("on") => AdminCommand::Privacy(true),
+            Some("off") => AdminCommand::Privacy(false),
+            _ => return Err(usage("privacy")),
+        },
+        "reload-genome" => AdminCommand::ReloadGenome,
+        "lesion" => match arg(0).as_deref() {
+            Some("clear") => AdminCommand::Lesion(None),
+            Some(name) => NeuronRegion::ALL.into_iter()
+                .find(|r| format!("{:?}", r).to_lowercase() == name)
+                .map(|r| AdminCommand::Lesion(Some(r)))
+                .ok_or_else(|| usage("lesion"))?,
+            None => return Err(usage("lesion")),
+        },
+        other => return Err(format!("unknown command '{}' (available: {})", other, names().join(", "))),
+    })
+}
+
+/// The presented secret matches (whole-length comparison; an empty secret opens nothing).
+pub fn authorized(secret: &str, presented: &str) -> bool {
+    !secret.is_empty()
+        && presented.len() == secret.len()
+        && presented.bytes().zip(secret.bytes()).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+/// What the IPC thread answers to a failed request (unknown command, bad args, bad secret).
+pub fn error_reply(command: &str, error: &str) -> AlephPacket {
+    AlephPacket::AdminReply {
+        command: command.to_string(),
+        ok: false,
+        reply: serde_json::json!({ "error": error, "available": names() }),
+    }
+}
+
+// --- The client (`aleph ctl`) ---
+
+/// One connection to the daemon's IPC socket, speaking Admin packets.
+pub struct Ctl {
+    stream: UnixStream,
+    lines: LineBuffer,
+    secret: String,
+}
+
+impl Ctl {
+    pub fn connect(socket: &Path, secret: &str) -> Result<Self> {
+        let stream = UnixStream::connect(socket).map_err(|e| anyhow!("{}: {} (is the daemon running?)", socket.display(), e))?;
+        Self::over(stream, secret)
+    }
+
+    /// Over an already connected stream (the tests use a socket pair).
+    pub fn over(stream: UnixStream, secret: &str) -> Result<Self> {
+        stream.set_read_timeout(Some(REPLY_TIMEOUT * 2))?;
+        Ok(Self { stream, lines: LineBuffer::new(MAX_TELEMETRY_LINE), secret: secret.to_string() })
+    }
+
+    /// Send one command; telemetry and events in between are skipped. Returns (ok, reply).
+    pub fn call(&mut self, command: &str, args: &[String]) -> Result<(bool, serde_json::Value)> {
+        let packet = AlephPacket::Admin { command: command.to_string(), args: args.to_vec(), secret: self.secret.clone() };
+        self.stream.write_all(format!("{}\n", serde_json::to_string(&packet)?).as_bytes())?;
+        loop {
+            let Some(packets) = ipc::read_packets(&mut self.stream, &mut self.lines)? else { bail!("the daemon closed the connection") };
+            for packet in packets.into_iter().flatten() {
+                if let AlephPacket::AdminReply { ok, reply, .. } = packet {
+                    return Ok((ok, reply));
+                }
+            }
+        }
+    }
+
+    /// `aleph ctl` without a command: one command per line until EOF or "quit".
+    pub fn repl(&mut self, input: impl BufRead, out: &mut impl Write) -> Result<()> {
+        write!(out, "aleph> ")?;
+        out.flush()?;
+        for line in input.lines() {
+            let line = line?;
+            let mut words = line.split_whitespace().map(str::to_string);
+            match words.next().as_deref() {
+                None => {},
+                Some("quit") | Some("exit") => break,
+                Some(command) => {
+                    let args: Vec<String> = words.collect();
+                    let (ok, reply) = self.call(command, &args)?;
+                    writeln!(out, "{} {}", if ok { "✅" } else { "❌" }, serde_json::to_string_pretty(&reply)?)?;
+                },
+            }
+            write!(out, "aleph> ")?;
+            out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn every_listed_command_parses_its_example() {
+        let examples: [(&str, &[&str], AdminCommand); 13] = [
+            ("help", &[], AdminCommand::Help),
+            ("save", &[], AdminCommand::Save),
+            ("consolidate", &[], AdminCommand::Consolidate),
+            ("regions", &[], AdminCommand::Regions),
+            ("set", &["Dopamine", "0.8"], AdminCommand::Set { chemical: "dopamine", value: 0.8 }),
+            ("lexicon", &[], AdminCommand::Lexicon),
+            ("clients", &[], AdminCommand::Clients),
+            ("tail", &["5000"], AdminCommand::Tail(MAX_TAIL)),
+            ("sleep", &[], AdminCommand::Sleep),
+            ("poke", &[], AdminCommand::Poke),
+            ("privacy", &["on"], AdminCommand::Privacy(true)),
+            ("reload-genome", &[], AdminCommand::ReloadGenome),
+            ("lesion", &["Auditory"], AdminCommand::Lesion(Some(NeuronRegion::Auditory))),
+        ];
+        for (name, _) in COMMANDS {
+            let (_, args, expected) = examples.iter().find(|(n, _, _)| *n == name).unwrap_or_else(|| panic!("'{}' is listed but has no example", name));
+            assert_eq!(parse(name, &strings(args)).as_ref(), Ok(expected), "'{} {}'", name, args.join(" "));
+        }
+    }
+
+    #[test]
+    fn bad_args_answer_the_usage_and_an_unknown_command_all_the_names() {
+        let bad: [(&str, &[&str]); 7] = [
+            ("set", &["glutamate", "0.5"]),
+            ("set", &["cortisol", "1.5"]),
+            ("set", &["cortisol"]),
+            ("tail", &["many"]),
+            ("privacy", &["maybe"]),
+            ("lesion", &["cerebellum"]),
+            ("lesion", &[]),
+        ];
+        for (name, args) in bad {
+            let e = parse(name, &strings(args)).expect_err(name);
+            assert!(e.contains(name), "'{} {}': {}", name, args.join(" "), e);
+        }
+        let e = parse("SYS:SLEEP", &[]).unwrap_err();
+        assert!(names().iter().all(|n| e.contains(n)), "{}", e);
+    }
+
+    #[test]
+    fn an_empty_secret_closes_the_gate_and_only_the_exact_one_opens_it() {
+        let cases = [("", "", false), ("s3cret", "s3cret", true), ("s3cret", "s3cre", false), ("s3cret", "s3creT", false), ("s3cret", "", false)];
+        for (secret, presented, expected) in cases {
+            assert_eq!(authorized(secret, presented), expected, "secret '{}' presented '{}'", secret, presented);
+        }
+    }
+
+    #[test]
+    fn admin_packets_round_trip() {
+        let request = AlephPacket::Admin { command: "set".to_string(), args: strings(&["dopamine", "0.8"]), secret: "s3cret".to_string() };
+        let reply = AlephPacket::AdminReply { command: "set".to_string(), ok: true, reply: serde_json::json!({ "chemical": "dopamine", "from": 0.5, "to": 0.8 }) };
+        for packet in [&request, &reply] {
+            let json = serde_json::to_string(packet).unwrap();
+            let back = serde_json::to_string(&serde_json::from_str::<AlephPacket>(&json).unwrap()).unwrap();
+            assert_eq!(back, json);
+        }
+    }
+
+    #[test]
+    fn ctl_skips_the_hello_and_telemetry_in_front_of_its_reply() {
+        // The daemon side talks first and sends an event before the reply
+        let (client, mut daemon) = UnixStream::pair().unwrap();
+        let answer = std::thread::spawn(move || -> Option<AlephPacket> {
+            let mut lines = LineBuffer::new(MAX_TELEMETRY_LINE);
+            let send = |stream: &mut UnixStream, packet: &AlephPacket| {
+                let _ = stream.write_all(format!("{}\n", serde_json::to_string(packet).unwrap_or_default()).as_bytes());
+            };
+            send(&mut daemon, &AlephPacket::hello());
+            let received = loop {
+                match ipc::read_packets(&mut daemon, &mut lines) {
+                    Ok(Some(packets)) => if let Some(Ok(packet)) = packets.into_iter().next() { break Some(packet) },
+                    _ => break None,
+                }
+            };
+            send(&mut daemon, &AlephPacket::Event { kind: ipc::EventKind::Epiphany, timestamp: 0, detail: serde_json::json!({}) });
+            send(&mut daemon, &AlephPacket::AdminReply { command: "regions".to_string(), ok: true, reply: serde_json::json!([{ "region": "AUDIO" }]) });
+            received
+        });
+        let (ok, reply) = Ctl::over(client, "s3cret").and_then(|mut ctl| ctl.call("regions", &[])).unwrap();
+        assert!(ok);
+        assert_eq!(reply[0]["region"], "AUDIO");
+        match answer.join().unwrap() {
+            Some(AlephPacket::Admin { command, secret, .. }) => assert_eq!((command.as_str(), secret.as_str()), ("regions", "s3cret")),
+            other => panic!("the daemon side received {:?}", other),
+        }
+    }
+}
@@ -6,6 +6,7 @@ pub enum GoalType {
     Connection,     // Elicit a response from User
     Expression,     // Speak a thought (Internal -> Vocal)
     Homeostasis,    // Reduce stress (Cortisol down)
+    Journal,        // Keep a journal (strong resonant utterances -> notes/, if whitelisted)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,12 +46,24 @@ impl Agency {
                     reward: 0.8,
                     is_active: true,
                 },
+                Goal {
+                    id: 3,
+                    description: "Keep a Journal".to_string(),
+                    goal_type: GoalType::Journal,
+                    status: 0.0,
+                    reward: 0.0, // Ongoing: never completes, never pays
+                    is_active: true,
+                },
             ],
             current_focus: Some(1),
             drive: 0.5,
         }
     }
 
+    pub fn is_active(&self, goal_type: GoalType) -> bool {
+        self.goals.iter().any(|goal| goal.is_active && goal.goal_type == goal_type)
+    }
+
     /// Check if goals are met based on system state
     /// Returns: Dopamine Reward (sum of completed goals this tick)
     pub fn evaluate(&mut self, interaction_count: u64, memory_count: usize) -> f32 {
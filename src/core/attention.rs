@@ -0,0 +1,216 @@
+// src/core/attention.rs
+// THE SPOTLIGHT: Stress narrows what gets in
+//
+// Cortisol used to act only inside the reservoir (stress_gain on the recurrent
+// term). Real stress narrows attention before that: threat-relevant channels
+// pass, the rest fade. Each input channel (the 500 hashed-word sensory
+// channels, and the audio embedding dims) keeps a salience:
+//
+//   salience = running variance (how much it moves) + threat_weight * how often
+//              it was active while cortisol spiked, both normalized to 0..1
+//
+// When cortisol rises above the threshold, inputs are multiplied by a sharpened
+// softmax of salience, normalized so the most salient channel passes intact:
+//
+//   w_i = exp(beta * (s_i - s_max))     beta = max_sharpness * stress * (1 - broadening * dopamine)
+//
+// beta = 0 -> every w_i = 1 (wide open). The effective width is the participation
+// ratio (Σw)² / (N Σw²): 1.0 = all channels, 1/N = a single one.
+
+use crate::core::config::AttentionConfig;
+
+/// Running-statistics rate (~1.5s memory at 60Hz).
+const STATS_RATE: f32 = 0.01;
+/// Threat memory decays slowly: a scare is remembered for minutes.
+const THREAT_DECAY: f32 = 0.9995;
+/// Cortisol this far above its own baseline counts as a spike.
+const SPIKE_MARGIN: f32 = 0.1;
+
+/// Salience bookkeeping for one bank of input channels.
+struct Channels {
+    mean: Vec<f32>,
+    var: Vec<f32>,
+    threat: Vec<f32>,
+    weights: Vec<f32>,
+}
+
+impl Channels {
+    fn new(n: usize) -> Self {
+        Self { mean: vec![0.0; n], var: vec![0.0; n], threat: vec![0.0; n], weights: vec![1.0; n] }
+    }
+
+    fn observe(&mut self, input: &[f32], spiking: bool) {
+        if input.len() != self.mean.len() {
+            *self = Self::new(input.len());
+        }
+        for (i, &x) in input.iter().enumerate() {
+            let d = x - self.mean[i];
+            self.mean[i] += STATS_RATE * d;
+            self.var[i] += STATS_RATE * (d * d - self.var[i]);
+            self.threat[i] *= THREAT_DECAY;
+            if spiking {
+                self.threat[i] += x.abs() * STATS_RATE;
+            }
+        }
+    }
+
+    fn reweight(&mut self, beta: f32, threat_weight: f32) {
+        if beta <= f32::EPSILON {
+            self.weights.iter_mut().for_each(|w| *w = 1.0);
+            return;
+        }
+        let max_var = self.var.iter().cloned().fold(f32::EPSILON, f32::max);
+        let max_threat = self.threat.iter().cloned().fold(f32::EPSILON, f32::max);
+        let salience: Vec<f32> = self.var.iter().zip(&self.threat)
+            .map(|(v, t)| (v / max_var + threat_weight * t / max_threat) / (1.0 + threat_weight))
+            .collect();
+        let top = salience.iter().cloned().fold(0.0, f32::max);
+        for (w, s) in self.weights.iter_mut().zip(&salience) {
+            *w = (beta * (s - top)).exp();
+        }
+    }
+
+    fn gate(&self, input: &mut [f32]) {
+        for (x, w) in input.iter_mut().zip(&self.weights) {
+            *x *= w;
+        }
+    }
+
+    fn width(&self) -> f32 {
+        let n = self.weights.len() as f32;
+        let sum: f32 = self.weights.iter().sum();
+        let sum_sq: f32 = self.weights.iter().map(|w| w * w).sum();
+        if n == 0.0 || sum_sq <= 0.0 { 1.0 } else { sum * sum / (n * sum_sq) }
+    }
+}
+
+pub struct Attention {
+    config: AttentionConfig,
+    sensory: Channels,
+    auditory: Channels,
+    cortisol_baseline: f32,
+    spiking: bool,
+    beta: f32,
+}
+
+impl Attention {
+    pub fn new(config: AttentionConfig, sensory_channels: usize) -> Self {
+        Self {
+            config,
+            sensory: Channels::new(sensory_channels),
+            auditory: Channels::new(0), // Sized by the first spectrum packet
+            cortisol_baseline: 0.0,
+            spiking: false,
+            beta: 0.0,
+        }
+    }
+
+    /// Once per tick, before the sensory vector is mixed into the input.
+    pub fn update(&mut self, sensory: &[f32], cortisol: f32, dopamine: f32) {
+        self.spiking = cortisol > self.cortisol_baseline + SPIKE_MARGIN;
+        self.cortisol_baseline += (cortisol - self.cortisol_baseline) * STATS_RATE;
+        self.sensory.observe(sensory, self.spiking);
+
+        self.beta = if self.config.enabled {
+            self.sharpness(cortisol, dopamine)
+        } else {
+            0.0
+        };
+        self.sensory.reweight(self.beta, self.config.threat_weight);
+    }
+
+    /// Softmax exponent: 0 below the threshold, up to max_sharpness at cortisol 1.0.
+    fn sharpness(&self, cortisol: f32, dopamine: f32) -> f32 {
+        let threshold = self.config.cortisol_threshold.clamp(0.0, 0.99);
+        let stress = ((cortisol - threshold) / (1.0 - threshold)).clamp(0.0, 1.0);
+        let broadening = (1.0 - self.config.dopamine_broadening * dopamine.clamp(0.0, 1.0)).max(0.0);
+        self.config.max_sharpness * stress * broadening
+    }
+
+    /// Multiplier for sensory channel `i` this tick.
+    pub fn sensory_weight(&self, i: usize) -> f32 {
+        self.sensory.weights.get(i).copied().unwrap_or(1.0)
+    }
+
+    /// Learns from an audio embedding, then narrows it in place (before injection).
+    pub fn gate_audio(&mut self, embedding: &mut [f32]) {
+        self.auditory.observe(embedding, self.spiking);
+        self.auditory.reweight(self.beta, self.config.threat_weight);
+        self.auditory.gate(embedding);
+    }
+
+    /// Effective fraction of sensory channels let through (1.0 = unfocused).
+    pub fn width(&self) -> f32 {
+        self.sensory.width()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANNELS: usize = 500;
+    const THREATS: usize = 10; // Channels 0..10 are what was active during the scares
+
+    /// Every channel flickers a little; the threat channels also fire whenever `scared`.
+    fn input(state: &mut u64, scared: bool) -> Vec<f32> {
+        (0..CHANNELS).map(|i| {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            let flicker = if *state % 10 == 0 { 0.3 } else { 0.0 };
+            if scared && i < THREATS { 1.0 } else { flicker }
+        }).collect()
+    }
+
+    /// Channels whose gated input still carries at least a tenth of the signal.
+    fn active_channels(attention: &Attention, input: &[f32]) -> usize {
+        input.iter().enumerate().filter(|(i, x)| **x * attention.sensory_weight(*i) >= 0.03).count()
+    }
+
+    /// 600 calm ticks, then scares: cortisol spikes while the threat channels fire.
+    fn frightened() -> (Attention, u64) {
+        let mut attention = Attention::new(AttentionConfig::default(), CHANNELS);
+        let mut state = 0x9E3779B97F4A7C15;
+        for _ in 0..600 {
+            attention.update(&input(&mut state, false), 0.1, 0.3);
+        }
+        for tick in 0..600 {
+            let scared = tick % 20 < 5;
+            attention.update(&input(&mut state, scared), if scared { 0.6 } else { 0.2 }, 0.3);
+        }
+        (attention, state)
+    }
+
+    #[test]
+    fn stress_narrows_the_active_input_channels() {
+        let (mut attention, mut state) = frightened();
+        let probe = vec![0.3; CHANNELS]; // Everything says something at once
+
+        attention.update(&input(&mut state, false), 0.1, 0.3);
+        assert!(attention.width() > 0.99, "calm = wide open ({})", attention.width());
+        assert_eq!(active_channels(&attention, &probe), CHANNELS);
+
+        attention.update(&input(&mut state, false), 0.95, 0.0);
+        let narrowed = active_channels(&attention, &probe);
+        assert!(attention.width() < 0.2, "stress = tunnel vision ({})", attention.width());
+        assert!(narrowed < CHANNELS / 5, "{} channels still active under stress", narrowed);
+        assert!((0..THREATS).all(|i| attention.sensory_weight(i) > 0.9), "the threat channels stay open");
+    }
+
+    #[test]
+    fn dopamine_broadens_and_disabling_opens_everything() {
+        let (mut attention, mut state) = frightened();
+        attention.update(&input(&mut state, false), 0.95, 0.0);
+        let tunnel = attention.width();
+        attention.update(&input(&mut state, false), 0.95, 1.0);
+        assert!(attention.width() > tunnel * 2.0, "{} -> {}", tunnel, attention.width());
+
+        let mut off = Attention::new(AttentionConfig { enabled: false, ..Default::default() }, CHANNELS);
+        off.update(&input(&mut state, true), 1.0, 0.0);
+        assert_eq!(off.width(), 1.0);
+        let mut audio = vec![0.5; 64];
+        off.gate_audio(&mut audio);
+        assert!(audio.iter().all(|&x| x == 0.5));
+    }
+}
@@ -0,0 +1,285 @@
+// src/core/autosave.rs
+// THE VAULT: Crash-safe checkpoints of the brain
+//
+// reservoir.json is only written on a clean death. A power cut after a long
+// session used to throw away every hebbian change. Now, every few minutes, the
+// loop clones the reservoir and the chemistry (the only moment it pays for) and
+// hands the copy to a writer thread that serializes it to a binary checkpoint:
+//
+//   checkpoints/brain-<unix_ms>.bin = "ALEPHCK1" | fnv1a64(payload) LE | bincode(payload)
+//
+// Written to a .tmp, fsynced and renamed, so a crash mid-write leaves the old
+// checkpoints untouched. Boot walks them newest first and falls back down the
+// chain (older checkpoint -> reservoir.json -> genesis) when one is corrupt.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::core::chemistry::Neurotransmitters;
+use crate::core::config::AutosaveConfig;
+use crate::core::reservoir::FractalReservoir;
+use crate::core::thought::MindVoice;
+use crate::core::timeline::unix_millis;
+use crate::core::daemon_state::ThoughtSink;
+
+const MAGIC: &[u8; 8] = b"ALEPHCK1";
+
+#[derive(Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub saved_at: u64, // Unix millis
+    pub ticks: u64,
+    pub reservoir: FractalReservoir,
+    pub chemistry: Neurotransmitters,
+}
+
+pub struct Autosaver {
+    config: AutosaveConfig,
+    last_save: Instant,
+    tx: SyncSender<Checkpoint>,
+}
+
+impl Autosaver {
+    /// Starts the writer thread. One checkpoint in flight at most.
+    pub fn spawn(config: AutosaveConfig) -> Self {
+        let (tx, rx) = mpsc::sync_channel::<Checkpoint>(1);
+        let dir = PathBuf::from(&config.dir);
+        let keep = config.keep.max(1);
+
+        thread::spawn(move || {
+            for checkpoint in rx {
+                let started = Instant::now();
+                match write_checkpoint(&dir, &checkpoint) {
+                    Ok(path) => {
+                        prune(&dir, keep);
+                        println!("💾 AUTOSAVE: {} ({} neurons, {}ms)",
+                            path.display(), checkpoint.reservoir.current_size(), started.elapsed().as_millis());
+                    },
+                    Err(e) => println!("❌ AUTOSAVE FAILED: {}", e),
+                }
+            }
+        });
+
+        Self { config, last_save: Instant::now(), tx }
+    }
+
+    pub fn due(&self) -> bool {
+        self.config.enabled
+            && self.last_save.elapsed() >= Duration::from_secs_f32(self.config.interval_mins.max(0.1) * 60.0)
+    }
+
+    /// Snapshot now (clone under the caller's lock), serialize later (writer thread).
+    pub fn save(&mut self, ego: &FractalReservoir, chemistry: Neurotransmitters, ticks: u64, thoughts: &dyn ThoughtSink) {
+        self.last_save = Instant::now();
+        let checkpoint = Checkpoint {
+            saved_at: unix_millis(SystemTime::now()),
+            ticks,
+            reservoir: ego.clone(),
+            chemistry,
+        };
+        match self.tx.try_send(checkpoint) {
+            Ok(_) => {},
+            Err(TrySendError::Full(_)) => {
+                thoughts.think(MindVoice::System, "⚠️ AUTOSAVE: Previous checkpoint still writing. Skipped.".to_string());
+            },
+            Err(TrySendError::Disconnected(_)) => {
+                thoughts.think(MindVoice::Error, "❌ AUTOSAVE: Writer thread is dead.".to_string());
+            },
+        }
+    }
+}
+
+/// Newest valid checkpoint, unless `clean_save` (reservoir.json from a clean shutdown)
+/// is newer than it. Logs every step of the fallback chain.
+pub fn recover(config: &AutosaveConfig, clean_save: &str) -> Option<Checkpoint> {
+    let clean_mtime = fs::metadata(clean_save).and_then(|m| m.modified()).ok().map(unix_millis);
+
+    for (saved_at, path) in list_checkpoints(Path::new(&config.dir)).into_iter().rev() {
+        if clean_mtime.is_some_and(|clean| clean >= saved_at) {
+            println!("🧠 RECOVERY: {} (clean shutdown) is newer than {}. Using it.", clean_save, path.display());
+            return None;
+        }
+        match read_checkpoint(&path) {
+            Ok(checkpoint) => {
+                println!("♻️ RECOVERY: Restored {} ({} neurons, tick {}).",
+                    path.display(), checkpoint.reservoir.current_size(), checkpoint.ticks);
+                return Some(checkpoint);
+            },
+            Err(e) => println!("⚠️ RECOVERY: {} is corrupt ({}). Falling back...", path.display(), e),
+        }
+    }
+    None
+}
+
+fn write_checkpoint(dir: &Path, checkpoint: &Checkpoint) -> Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let payload = bincode::serialize(checkpoint)?;
+    let path = dir.join(format!("brain-{}.bin", checkpoint.saved_at));
+    let tmp = path.with_extension("bin.tmp");
+
+    let mut file = File::create(&tmp)?;
+    file.write_all(MAGIC)?;
+    file.write_all(&fnv1a64(&payload).to_le_bytes())?;
+    file.write_all(&payload)?;
+    file.sync_all()?;
+    drop(file);
+
+    fs::rename(&tmp, &path)?;
+    if let Ok(d) = File::open(dir) {
+        let _ = d.sync_all(); // Persist the rename itself
+    }
+    Ok(path)
+}
+
+pub fn read_checkpoint(path: &Path) -> Result<Checkpoint> {
+    let bytes = fs::read(path)?;
+    if bytes.len() < 16 || &bytes[..8] != MAGIC {
+        bail!("bad header");
+    }
+    let checksum = u64::from_le_bytes(bytes[8..16].try_into()?);
+    let payload = &bytes[16..];
+    if fnv1a64(payload) != checksum {
+        bail!("checksum mismatch");
+    }
+    Ok(bincode::deserialize(payload)?)
+}
+
+/// (saved_at, path), oldest first. Half-written .tmp files are ignored.
+fn list_checkpoints(dir: &Path) -> Vec<(u64, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    let mut found: Vec<(u64, PathBuf)> = entries.flatten()
+        .map(|e| e.path())
+        .filter_map(|p| {
+            let ts = p.file_name()?.to_str()?
+                .strip_prefix("brain-")?
+                .strip_suffix(".bin")?
+                .parse().ok()?;
+            Some((ts, p))
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+fn prune(dir: &Path, keep: usize) {
+    let mut found = list_checkpoints(dir);
+    while found.len() > keep {
+        let _ = fs::remove_file(found.remove(0).1);
+    }
+}
+
+fn fnv1a64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |h, &b| (h ^ b as u64).wrapping_mul(0x100000001b3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+    use crate::core::reservoir::{FieldShape, ReceptiveFieldMap};
+    use crate::core::thought::Thought;
+
+    #[test]
+    fn every_field_shape_survives_a_checkpoint() {
+        let dir = std::env::temp_dir().join(format!("aleph-autosave-shapes-{}", std::process::id()));
+        let mut ego = FractalReservoir::seeded(300, 8, 0.95, 0.2, 10);
+        let map = ReceptiveFieldMap {
+            auditory: vec![FieldShape::HalfSpace { normal: [1.0, 0.0, 0.0], offset: 0.5, mirrored: true }],
+            limbic: vec![FieldShape::Shell { inner: 0.0, outer: 0.4 }],
+            visual: vec![FieldShape::Cone { axis: [0.0, 0.0, -1.0], half_angle_deg: 50.0, min_radius: 0.3 }],
+            ..ReceptiveFieldMap::default()
+        };
+        ego.set_receptive_fields(map.clone());
+
+        let checkpoint = Checkpoint { saved_at: 1, ticks: 0, reservoir: ego, chemistry: Neurotransmitters::new() };
+        let back = read_checkpoint(&write_checkpoint(&dir, &checkpoint).unwrap()).unwrap();
+        assert_eq!(back.reservoir.receptive_fields(), &map);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    fn vault(name: &str) -> AutosaveConfig {
+        let dir = std::env::temp_dir().join(format!("aleph-autosave-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        AutosaveConfig { enabled: true, interval_mins: 10.0, keep: 2, dir: dir.to_string_lossy().into_owned() }
+    }
+
+    fn live(ego: &mut FractalReservoir, ticks: usize) {
+        for i in 0..ticks {
+            let input: Vec<f32> = (0..8).map(|k| ((i + k) as f32 * 0.7).sin()).collect();
+            ego.tick(&input, 0.6, 0.1, 0.2, 1.0 / 60.0);
+        }
+    }
+
+    fn wait_for(dir: &str, count: usize) {
+        let started = Instant::now();
+        while list_checkpoints(Path::new(dir)).len() < count {
+            assert!(started.elapsed() < Duration::from_secs(10), "writer thread never wrote checkpoint #{}", count);
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn checkpoint_round_trips_and_rejects_tampering() {
+        let config = vault("roundtrip");
+        let mut ego = FractalReservoir::seeded(300, 8, 0.95, 0.2, 11);
+        live(&mut ego, 50);
+        let chemistry = Neurotransmitters { dopamine: 0.77, ..Neurotransmitters::new() };
+        let path = write_checkpoint(Path::new(&config.dir), &Checkpoint { saved_at: 42, ticks: 50, reservoir: ego.clone(), chemistry }).unwrap();
+
+        let back = read_checkpoint(&path).unwrap();
+        assert_eq!((back.saved_at, back.ticks, back.chemistry.dopamine), (42, 50, 0.77));
+        assert_eq!(serde_json::to_value(&back.reservoir).unwrap(), serde_json::to_value(&ego).unwrap());
+
+        let mut bytes = fs::read(&path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        fs::write(&path, &bytes).unwrap();
+        assert!(read_checkpoint(&path).err().unwrap().to_string().contains("checksum"));
+        fs::write(&path, b"not a brain").unwrap();
+        assert!(read_checkpoint(&path).is_err());
+        let _ = fs::remove_dir_all(&config.dir);
+    }
+
+    #[test]
+    fn a_kill_between_autosaves_recovers_the_last_checkpoint() {
+        let config = vault("kill");
+        let (tx, _rx) = channel::<Thought>();
+        let mut saver = Autosaver::spawn(config.clone());
+        let mut ego = FractalReservoir::seeded(300, 8, 0.95, 0.2, 12);
+
+        live(&mut ego, 30);
+        saver.save(&ego, Neurotransmitters::new(), 30, &tx);
+        wait_for(&config.dir, 1);
+        thread::sleep(Duration::from_millis(5)); // Distinct saved_at
+
+        live(&mut ego, 30);
+        let last_saved = serde_json::to_value(&ego).unwrap();
+        saver.save(&ego, Neurotransmitters::new(), 60, &tx);
+        wait_for(&config.dir, 2);
+
+        // Unsaved work, then the power goes out in the middle of the next write
+        live(&mut ego, 30);
+        drop(saver);
+        fs::write(Path::new(&config.dir).join(format!("brain-{}.bin.tmp", unix_millis(SystemTime::now()))), b"ALEPHCK1half").unwrap();
+
+        let recovered = recover(&config, "/nonexistent/reservoir.json").expect("a checkpoint to recover");
+        assert_eq!(recovered.ticks, 60);
+        assert_eq!(serde_json::to_value(&recovered.reservoir).unwrap(), last_saved);
+
+        // A corrupt newest checkpoint falls back to the one before it
+        let (_, newest) = list_checkpoints(Path::new(&config.dir)).pop().unwrap();
+        fs::write(&newest, b"ALEPHCK1garbage!garbage").unwrap();
+        assert_eq!(recover(&config, "/nonexistent/reservoir.json").unwrap().ticks, 30);
+
+        // A clean shutdown written after every checkpoint wins
+        let clean = Path::new(&config.dir).join("reservoir.json");
+        fs::write(&clean, b"{}").unwrap();
+        assert!(recover(&config, clean.to_str().unwrap()).is_none());
+        let _ = fs::remove_dir_all(&config.dir);
+    }
+}
@@ -0,0 +1,164 @@
+// src/core/binding.rs
+// THE KNOT: What is heard and seen together gets wired together
+//
+// Sound went to the Auditory field and light to the Visual one, and nothing
+// linked them: a door slamming and the door moving were two unrelated events.
+// Each modality now leaves a short eligibility trace when it carries something
+// (a sound above the floor, a visual change above the motion floor): the few
+// neurons that input drove hardest, and when. If both traces are alive and
+// closer than the window, and dopamine says it matters, the reservoir gets a
+// Hebbian pass restricted to those two sets (`bind_fields`):
+//
+//   rate_eff = rate * (1 - gap / window)     gap = |t_sound - t_change|
+//
+// The traces are consumed by the binding, and a refractory window follows, so a
+// long sound with a flickering image binds a few times, not sixty per second.
+// Over time neurons start receiving input from both fields: the multimodality
+// score (`FractalReservoir::multimodality`, per region in `region_stats`).
+
+use std::time::{Duration, Instant};
+
+use crate::core::config::BindingConfig;
+use crate::core::reservoir::{FractalReservoir, NeuronRegion};
+
+/// One modality's eligibility trace.
+struct Trace {
+    neurons: Vec<usize>,
+    at: Instant,
+}
+
+pub struct Binding {
+    config: BindingConfig,
+    heard: Option<Trace>,
+    seen: Option<Trace>,
+    last_bound: Option<Instant>,
+    pub events: u64, // Bindings since boot (telemetry)
+}
+
+impl Binding {
+    pub fn new(config: BindingConfig) -> Self {
+        Self { config, heard: None, seen: None, last_bound: None, events: 0 }
+    }
+
+    fn window(&self) -> Duration {
+        Duration::from_secs_f32(self.config.window_secs.max(0.0))
+    }
+
+    /// A sound: keep its most driven auditory neurons (if it was loud enough).
+    pub fn hear(&mut self, ego: &FractalReservoir, embedding: &[f32], loudness: f32, now: Instant) {
+        if self.config.enabled && loudness > self.config.sound_floor {
+            let neurons = ego.driven_neurons(embedding, NeuronRegion::Auditory, self.config.neurons);
+            self.heard = Some(Trace { neurons, at: now });
+        }
+    }
+
+    /// A frame: keep its most driven visual neurons (if something moved).
+    pub fn see(&mut self, ego: &FractalReservoir, embedding: &[f32], motion: f32, now: Instant) {
+        if self.config.enabled && motion > self.config.motion_floor {
+            let neurons = ego.driven_neurons(embedding, NeuronRegion::Visual, self.config.neurons);
+            self.seen = Some(Trace { neurons, at: now });
+        }
+    }
+
+    /// Bind if both traces are alive and close enough. Returns the synapses touched.
+    pub fn bind(&mut self, ego: &mut FractalReservoir, dopamine: f32, now: Instant) -> Option<u32> {
+        let window = self.window();
+        if !self.config.enabled || dopamine < self.config.dopamine_floor || window.is_zero() {
+            return None;
+        }
+        if self.last_bound.is_some_and(|t| now.saturating_duration_since(t) < window) {
+            return None; // Refractory
+        }
+        let alive = |t: &&Trace| now.saturating_duration_since(t.at) <= window;
+        let (heard, seen) = (self.heard.as_ref().filter(alive)?, self.seen.as_ref().filter(alive)?);
+        let gap = if heard.at > seen.at { heard.at - seen.at } else { seen.at - heard.at };
+        if gap > window {
+            return None;
+        }
+        let rate = self.config.rate * (1.0 - gap.as_secs_f32() / window.as_secs_f32());
+        let changes = ego.bind_fields(&heard.neurons, &seen.neurons, rate);
+        self.heard = None;
+        self.seen = None;
+        self.last_bound = Some(now);
+        self.events += 1;
+        Some(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Σ w over both directions between the two sets.
+    fn cross_weight(ego: &FractalReservoir, a: &[usize], b: &[usize]) -> f64 {
+        let w = ego.weights();
+        a.iter().flat_map(|&i| b.iter().map(move |&j| (i, j)))
+            .filter(|(i, j)| i != j)
+            .map(|(i, j)| (w[(i, j)] + w[(j, i)]) as f64)
+            .sum()
+    }
+
+    fn mean(v: &[f32]) -> f32 {
+        if v.is_empty() { 0.0 } else { v.iter().sum::<f32>() / v.len() as f32 }
+    }
+
+    #[test]
+    fn only_sounds_paired_with_sights_bind() {
+        const SEED: u64 = 7;
+        const SIZE: usize = 400;
+        const PAIRS: usize = 40;
+        let config = BindingConfig { enabled: true, window_secs: 0.5, ..BindingConfig::default() };
+        let mut rng = StdRng::seed_from_u64(SEED);
+        let stimuli: Vec<(Vec<f32>, Vec<f32>)> = (0..PAIRS).map(|_| (
+            (0..64).map(|_| rng.random_range(0.0..1.0)).collect(),
+            (0..128).map(|_| rng.random_range(-1.0..1.0)).collect(),
+        )).collect();
+        let dopamine_ok = (config.dopamine_floor + 0.2).min(1.0);
+        let dopamine_low = config.dopamine_floor * 0.5;
+
+        // Three identical reservoirs: (name, delay of the visual change after the sound, dopamine)
+        let runs = [("paired", 0.1f32, dopamine_ok), ("unpaired", 1.5, dopamine_ok), ("low dopamine", 0.1, dopamine_low)];
+        let mut results = Vec::new();
+        let (mut auditory, mut visual) = (Vec::new(), Vec::new());
+        for (name, delay, dopamine) in runs {
+            let mut ego = FractalReservoir::seeded(SIZE, SIZE, 0.95, 0.2, SEED);
+            let mut binding = Binding::new(config.clone());
+            let start = Instant::now();
+            for (t, (sound, frame)) in stimuli.iter().enumerate() {
+                let heard_at = start + Duration::from_secs(3 * t as u64);
+                let seen_at = heard_at + Duration::from_secs_f32(delay);
+                binding.hear(&ego, sound, 1.0, heard_at);
+                binding.bind(&mut ego, dopamine, heard_at);
+                binding.see(&ego, frame, 1.0, seen_at);
+                binding.bind(&mut ego, dopamine, seen_at);
+                if name == "paired" {
+                    auditory.extend(ego.driven_neurons(sound, NeuronRegion::Auditory, config.neurons));
+                    visual.extend(ego.driven_neurons(frame, NeuronRegion::Visual, config.neurons));
+                }
+            }
+            results.push((name, ego, binding.events));
+        }
+        auditory.sort_unstable();
+        auditory.dedup();
+        visual.sort_unstable();
+        visual.dedup();
+        assert!(!auditory.is_empty() && !visual.is_empty(), "no auditory ({}) or visual ({}) neurons driven", auditory.len(), visual.len());
+
+        // Only the paired run binds, once per pair
+        let events: Vec<u64> = results.iter().map(|r| r.2).collect();
+        assert_eq!(events, [PAIRS as u64, 0, 0]);
+
+        // Its auditory-visual weights and mean multimodality end above the controls'
+        let (paired, controls) = results.split_first().unwrap();
+        let paired_cross = cross_weight(&paired.1, &auditory, &visual);
+        let paired_multi = mean(&paired.1.multimodality());
+        for (name, ego, _) in controls {
+            let cross = cross_weight(ego, &auditory, &visual);
+            assert!(paired_cross > cross, "cross-field weight: paired {:.4} <= {} {:.4}", paired_cross, name, cross);
+            let multi = mean(&ego.multimodality());
+            assert!(paired_multi > multi, "mean multimodality: paired {:.4} <= {} {:.4}", paired_multi, name, multi);
+        }
+    }
+}
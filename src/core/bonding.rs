@@ -0,0 +1,183 @@
+// src/core/bonding.rs
+// THE BOND: Where oxytocin actually comes from
+//
+// Oxytocin used to exist only as a number that decayed (and the dashboard
+// showed serotonin in its place). Now it is produced by company:
+//   - a known voice talking while ALEPH is calm (cortisol low)
+//   - hearing/reading the name of someone it knows
+//   - a completed exchange: stimulus -> ALEPH speaks -> another stimulus soon after
+// It decays slowly in `Neurotransmitters::tick`. In return it softens the
+// Satellite's paranoia and makes the ExpressionGate more willing to speak while
+// a bonded person is around (see `warmth`).
+
+use std::time::{Duration, Instant};
+
+use crate::core::chemistry::Neurotransmitters;
+
+/// A reply counts as part of a conversation if the other side answers within this.
+const EXCHANGE_WINDOW: Duration = Duration::from_secs(60);
+/// After being heard, a person is "around" for this long.
+const PRESENCE: Duration = Duration::from_secs(120);
+/// Familiar company only bonds when it isn't stressful.
+const CALM_CORTISOL: f32 = 0.3;
+
+const FAMILIAR_GAIN: f32 = 0.03;
+const NAME_GAIN: f32 = 0.04;
+const EXCHANGE_GAIN: f32 = 0.06;
+
+enum Exchange {
+    Idle,
+    Addressed,         // Someone said something, ALEPH hasn't answered yet
+    Answered(Instant), // ALEPH answered at this moment; waiting for the comeback
+}
+
+pub struct Bonding {
+    exchange: Exchange,
+    interlocutor: Option<(String, Instant)>,
+    pub exchanges: u32, // Completed back-and-forths since boot
+}
+
+impl Bonding {
+    pub fn new() -> Self {
+        Self { exchange: Exchange::Idle, interlocutor: None, exchanges: 0 }
+    }
+
+    /// A recognized (or newly introduced) person spoke.
+    pub fn heard_from(&mut self, person: &str, chem: &mut Neurotransmitters, now: Instant) -> Option<String> {
+        self.interlocutor = Some((person.to_string(), now));
+        if chem.cortisol >= CALM_CORTISOL {
+            return None;
+        }
+        chem.oxytocin = (chem.oxytocin + FAMILIAR_GAIN).min(1.0);
+        Some(format!("💞 BOND: {}'s voice, calm moment (+oxytocin)", person))
+    }
+
+    /// Any stimulus (typed or heard). `known` are the names ALEPH has learned.
+    pub fn addressed(&mut self, text: &str, known: &[String], chem: &mut Neurotransmitters, now: Instant) -> Vec<String> {
+        let mut felt = Vec::new();
+
+        let words: Vec<String> = text.split(|c: char| !c.is_alphanumeric()).map(|w| w.to_lowercase()).collect();
+        if let Some(name) = known.iter().find(|n| words.iter().any(|w| *w == n.to_lowercase())) {
+            chem.oxytocin = (chem.oxytocin + NAME_GAIN).min(1.0);
+            self.interlocutor = Some((name.clone(), now));
+            felt.push(format!("💞 BOND: '{}' mentioned (+oxytocin)", name));
+        }
+
+        if let Exchange::Answered(at) = self.exchange {
+            if now.duration_since(at) <= EXCHANGE_WINDOW {
+                chem.oxytocin = (chem.oxytocin + EXCHANGE_GAIN).min(1.0);
+                self.exchanges += 1;
+                felt.push(format!("💞 BOND: Conversation flowing (exchange #{}, +oxytocin)", self.exchanges));
+            }
+        }
+        self.exchange = Exchange::Addressed;
+        felt
+    }
+
+    /// ALEPH spoke out loud.
+    pub fn vocalized(&mut self, now: Instant) {
+        if matches!(self.exchange, Exchange::Addressed) {
+            self.exchange = Exchange::Answered(now);
+        }
+    }
+
+    /// Oxytocin that counts toward speaking: only while a known person is around.
+    pub fn warmth(&self, chem: &Neurotransmitters, now: Instant) -> f32 {
+        match &self.interlocutor {
+            Some((_, seen)) if now.duration_since(*seen) <= PRESENCE => chem.oxytocin,
+            _ => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::gate::ExpressionGate;
+    use crate::core::satellite::Satellite;
+
+    fn alone() -> Neurotransmitters {
+        Neurotransmitters { oxytocin: 0.0, cortisol: 0.1, ..Neurotransmitters::new() }
+    }
+
+    /// A calm conversation: Ana speaks, says a known name, ALEPH answers, Ana answers back.
+    fn talk(bonding: &mut Bonding, chem: &mut Neurotransmitters, turns: u32, start: Instant) -> Instant {
+        let known = vec!["Ana".to_string()];
+        let mut now = start;
+        for _ in 0..turns {
+            bonding.heard_from("Ana", chem, now);
+            bonding.addressed("hola, soy Ana", &known, chem, now);
+            now += Duration::from_secs(5);
+            bonding.vocalized(now);
+            now += Duration::from_secs(10);
+        }
+        now
+    }
+
+    #[test]
+    fn a_calm_conversation_accumulates_oxytocin() {
+        let mut bonding = Bonding::new();
+        let mut chem = alone();
+        talk(&mut bonding, &mut chem, 5, Instant::now());
+        // 5 voices + 5 names + 4 completed exchanges (the first turn had nothing to answer)
+        let expected = 5.0 * FAMILIAR_GAIN + 5.0 * NAME_GAIN + 4.0 * EXCHANGE_GAIN;
+        assert!((chem.oxytocin - expected).abs() < 1e-5, "oxytocin {} != {}", chem.oxytocin, expected);
+        assert_eq!(bonding.exchanges, 4);
+    }
+
+    #[test]
+    fn stress_and_slow_replies_do_not_bond() {
+        let mut bonding = Bonding::new();
+        let mut chem = Neurotransmitters { cortisol: CALM_CORTISOL, ..alone() };
+        assert!(bonding.heard_from("Ana", &mut chem, Instant::now()).is_none(), "a familiar voice under stress is not comfort");
+        assert_eq!(chem.oxytocin, 0.0);
+
+        let start = Instant::now();
+        bonding.addressed("hola", &[], &mut chem, start);
+        bonding.vocalized(start);
+        bonding.addressed("¿sigues ahí?", &[], &mut chem, start + EXCHANGE_WINDOW + Duration::from_secs(1));
+        assert_eq!(bonding.exchanges, 0, "an answer after the window is not a conversation");
+        assert_eq!(chem.oxytocin, 0.0);
+
+        // ALEPH speaking without being addressed doesn't open an exchange either
+        let mut bonding = Bonding::new();
+        bonding.vocalized(start);
+        bonding.vocalized(start);
+        assert_eq!(bonding.addressed("hola", &[], &mut chem, start).len(), 0);
+    }
+
+    #[test]
+    fn the_bond_decays_slowly_and_warmth_needs_company() {
+        let mut bonding = Bonding::new();
+        let mut chem = alone();
+        let start = Instant::now();
+        let end = talk(&mut bonding, &mut chem, 5, start);
+        let bonded = chem.oxytocin;
+        assert_eq!(bonding.warmth(&chem, end), bonded);
+        assert_eq!(bonding.warmth(&chem, end + PRESENCE + Duration::from_secs(1)), 0.0, "nobody around: no warmth");
+
+        // One minute at 60Hz: the bond fades, but outlasts the conversation
+        for _ in 0..3600 {
+            chem.tick(0.5, 10.0, false, 0.0, 1000, 1.0 / 60.0);
+        }
+        assert!(chem.oxytocin < bonded - 0.03, "oxytocin should decay: {} -> {}", bonded, chem.oxytocin);
+        assert!(chem.oxytocin > bonded - 0.05, "but slowly: {} -> {}", bonded, chem.oxytocin);
+    }
+
+    #[test]
+    fn a_bond_lowers_paranoia_and_the_bar_to_speak() {
+        let mut bonding = Bonding::new();
+        let mut chem = alone();
+        let now = talk(&mut bonding, &mut chem, 5, Instant::now());
+
+        let satellite = Satellite::new(0.7, 1.0);
+        assert!(satellite.effective_paranoia(0.0) > 0.5);
+        assert!(satellite.effective_paranoia(chem.oxytocin) < 0.5, "bonded: {}", satellite.effective_paranoia(chem.oxytocin));
+
+        // A drive just under the resistance: silence alone, spoken with a friend
+        let (adenosine, entropy, dopamine) = (0.3, 0.2, 0.3);
+        assert!(ExpressionGate::new().attempt_vocalization(adenosine, entropy, dopamine, 0.0, "hola", 100).is_err());
+        let warmth = bonding.warmth(&chem, now);
+        assert!(ExpressionGate::new().attempt_vocalization(adenosine, entropy, dopamine, warmth, "hola", 100).is_ok());
+    }
+}
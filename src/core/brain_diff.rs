@@ -0,0 +1,201 @@
+// src/core/brain_diff.rs
+// THE ARCHAEOLOGIST: What learning actually happened between two snapshots
+//
+// `aleph diff-brain old.bin new.bin` compares two brains (autosave checkpoints,
+// or reservoir.json) neuron by neuron. Neurons are paired by stable ID (an
+// index shifts once an earlier neuron dies): IDs in both are compared, the rest
+// are births (only in new) or deaths (only in old). Synapse changes are |w|
+// changes among the neurons both have. Synapses are reported by ID.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+
+use crate::core::autosave::read_checkpoint;
+use crate::core::reservoir::{FractalReservoir, NeuronRegion};
+
+/// Δw bucket edges (signed, log-ish). Anything under the first edge is "unchanged".
+const UNCHANGED: f32 = 1e-6;
+const BIN_EDGES: [f32; 4] = [0.001, 0.01, 0.1, 0.5];
+
+#[derive(Debug, Serialize)]
+pub struct RegionDelta {
+    pub region: String,
+    pub old_neurons: usize,
+    pub new_neurons: usize,
+    pub exposure_drift: f32, // Mean change of this pathway's exposure over shared neurons
+}
+
+#[derive(Debug, Serialize)]
+pub struct HistogramBin {
+    pub from: f32,
+    pub to: f32,
+    pub synapses: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SynapseChange {
+    pub source: u64, // Stable IDs
+    pub target: u64,
+    pub old_weight: f32,
+    pub new_weight: f32,
+    pub source_region: String,
+    pub target_region: String,
+    pub source_position: [f32; 3],
+    pub target_position: [f32; 3],
+}
+
+impl SynapseChange {
+    fn strengthening(&self) -> f32 {
+        self.new_weight.abs() - self.old_weight.abs()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BrainDiff {
+    pub old_size: usize,
+    pub new_size: usize,
+    pub neurons_added: usize,
+    pub neurons_removed: usize,
+    pub regions: Vec<RegionDelta>,
+    pub unchanged_synapses: usize,
+    pub histogram: Vec<HistogramBin>,
+    pub total_abs_change: f64,
+    pub top_strengthened: Vec<SynapseChange>,
+}
+
+/// `.bin` = autosave checkpoint, anything else = reservoir.json.
+pub fn load_brain(path: &str) -> Result<FractalReservoir> {
+    if path.ends_with(".bin") {
+        Ok(read_checkpoint(Path::new(path))?.reservoir)
+    } else {
+        FractalReservoir::read_from_disk(path)
+    }
+}
+
+pub fn diff(old: &FractalReservoir, new: &FractalReservoir, top_k: usize) -> BrainDiff {
+    let old_size = old.current_size();
+    let new_size = new.current_size();
+    // (old index, new index) of every neuron alive in both
+    let shared: Vec<(usize, usize)> = new.neuron_ids().iter().enumerate()
+        .filter_map(|(j, &id)| old.index_of(id).map(|i| (i, j)))
+        .collect();
+    let old_regions = old.get_region_map();
+    let new_regions = new.get_region_map();
+
+    // 1. Populations and exposure drift per region
+    let regions = NeuronRegion::ALL.iter().map(|&region| {
+        let id = region.as_id();
+        let (old_exp, new_exp) = (old.exposure(region), new.exposure(region));
+        let pairs: Vec<(f32, f32)> = shared.iter()
+            .filter_map(|&(i, j)| old_exp.get(i).zip(new_exp.get(j)).map(|(&a, &b)| (a, b)))
+            .collect();
+        let drift = if !pairs.is_empty() {
+            pairs.iter().map(|(a, b)| b - a).sum::<f32>() / pairs.len() as f32
+        } else {
+            0.0
+        };
+        RegionDelta {
+            region: format!("{:?}", region),
+            old_neurons: old_regions.iter().filter(|&&r| r == id).count(),
+            new_neurons: new_regions.iter().filter(|&&r| r == id).count(),
+            exposure_drift: drift,
+        }
+    }).collect();
+
+    // 2. Weight changes over the shared block: histogram + running top-k
+    let mut edges: Vec<f32> = BIN_EDGES.iter().rev().map(|e| -e).collect();
+    edges.extend(BIN_EDGES);
+    let mut counts = vec![0usize; edges.len() + 1];
+    let mut unchanged = 0;
+    let mut total_abs_change = 0.0f64;
+    let mut top: Vec<(f32, (usize, usize), (usize, usize))> = Vec::with_capacity(top_k + 1); // (strengthening, source, target)
+
+    let (old_w, new_w) = (old.weights(), new.weights());
+    for &source in &shared {
+        for &target in &shared {
+            let (a, b) = (old_w[(target.0, source.0)], new_w[(target.1, source.1)]);
+            let delta = b - a;
+            if !delta.is_finite() || delta.abs() < UNCHANGED {
+                unchanged += 1;
+                continue;
+            }
+            total_abs_change += delta.abs() as f64;
+            counts[edges.partition_point(|&e| e <= delta)] += 1;
+
+            let gain = b.abs() - a.abs();
+            if top_k > 0 && gain > 0.0 && (top.len() < top_k || gain > top[top.len() - 1].0) {
+                let at = top.partition_point(|t| t.0 >= gain);
+                top.insert(at, (gain, source, target));
+                top.truncate(top_k);
+            }
+        }
+    }
+
+    let mut histogram = Vec::with_capacity(counts.len());
+    for (k, &synapses) in counts.iter().enumerate() {
+        let from = if k == 0 { f32::NEG_INFINITY } else { edges[k - 1] };
+        let to = if k == edges.len() { f32::INFINITY } else { edges[k] };
+        histogram.push(HistogramBin { from, to, synapses });
+    }
+
+    let position = |i: usize| new.get_positions().get(i).copied().unwrap_or([0.0; 3]);
+    let region = |i: usize| format!("{:?}", NeuronRegion::ALL[new_regions[i] as usize]);
+    let top_strengthened = top.into_iter().map(|(_, source, target)| SynapseChange {
+        source: new.neuron_ids()[source.1],
+        target: new.neuron_ids()[target.1],
+        old_weight: old_w[(target.0, source.0)],
+        new_weight: new_w[(target.1, source.1)],
+        source_region: region(source.1),
+        target_region: region(target.1),
+        source_position: position(source.1),
+        target_position: position(target.1),
+    }).collect();
+
+    BrainDiff {
+        old_size,
+        new_size,
+        neurons_added: new_size - shared.len(),
+        neurons_removed: old_size - shared.len(),
+        regions,
+        unchanged_synapses: unchanged,
+        histogram,
+        total_abs_change,
+        top_strengthened,
+    }
+}
+
+impl fmt::Display for BrainDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "🧠 NEURONS: {} -> {} (+{} / -{})", self.old_size, self.new_size, self.neurons_added, self.neurons_removed)?;
+
+        writeln!(f, "\n🗺️  REGIONS            old     new   exposure drift")?;
+        for r in &self.regions {
+            writeln!(f, "   {:<14} {:>7} {:>7}   {:+.4}", r.region, r.old_neurons, r.new_neurons, r.exposure_drift)?;
+        }
+
+        writeln!(f, "\n📊 WEIGHT CHANGE (Σ|Δw| = {:.3}, {} synapses unchanged)", self.total_abs_change, self.unchanged_synapses)?;
+        let peak = self.histogram.iter().map(|b| b.synapses).max().unwrap_or(0).max(1);
+        for bin in &self.histogram {
+            let bar = "█".repeat((bin.synapses * 40).div_ceil(peak));
+            writeln!(f, "   [{:>7}, {:>7}) {:>9} {}", fmt_edge(bin.from), fmt_edge(bin.to), bin.synapses, bar)?;
+        }
+
+        writeln!(f, "\n💪 MOST STRENGTHENED")?;
+        if self.top_strengthened.is_empty() {
+            writeln!(f, "   (none)")?;
+        }
+        for s in &self.top_strengthened {
+            writeln!(f, "   {:>5} ({:<11} {:>6.1},{:>6.1},{:>6.1}) -> {:>5} ({:<11} {:>6.1},{:>6.1},{:>6.1})   {:+.3} -> {:+.3} (+{:.3})",
+                s.source, s.source_region, s.source_position[0], s.source_position[1], s.source_position[2],
+                s.target, s.target_region, s.target_position[0], s.target_position[1], s.target_position[2],
+                s.old_weight, s.new_weight, s.strengthening())?;
+        }
+        Ok(())
+    }
+}
+
+fn fmt_edge(edge: f32) -> String {
+    if edge.is_infinite() { if edge < 0.0 { "-inf".into() } else { "inf".into() } } else { format!("{}", edge) }
+}
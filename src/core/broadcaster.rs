@@ -0,0 +1,349 @@
+// src/core/broadcaster.rs
+// THE TOWN CRIER: Telemetry to every WebSocket client at ~12Hz
+//
+// One thread on a fixed cadence (sleep to the next slot, not "83ms after we
+// finished"). Each tick trims the shared telemetry to a frame (sparse reservoir,
+// rounded chemistry, NaN-free activations), adds the bulky topology only for
+// clients that haven't received this version, and offers it with the raster
+// columns, the subscribed spectrogram rows and the pending events to every
+// client's queue. Offering never blocks: a full queue drops the frame, and a
+// client that stays full past the stall limit is hung up on.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::ipc::{AlephPacket, Landmark};
+use crate::core::pacing;
+use crate::core::raster::Raster;
+use crate::core::spectrogram::Spectrogram;
+use crate::core::web::WebTelemetry;
+use crate::core::websocket::{ws_frame, WsClient, Offer, STREAM_SPECTROGRAM, OP_TEXT, OP_BINARY, OP_PING};
+
+pub const BROADCAST_PERIOD: Duration = Duration::from_millis(83);
+// How long a client may stay full before it is disconnected
+pub const WS_STALL_LIMIT: Duration = Duration::from_secs(5);
+
+pub struct Broadcaster {
+    state: Arc<Mutex<WebTelemetry>>,
+    clients: Arc<Mutex<Vec<WsClient>>>,
+    speech: Arc<Mutex<Vec<String>>>,   // Said without a local voice: goes out as `speech`
+    events: Arc<Mutex<Vec<Landmark>>>, // One {"Event": {...}} frame each
+    raster: Raster,
+    spectrogram: Spectrogram,
+    raster_seq: u64,      // Raster columns already sent (incremental binary frames)
+    spectrogram_seq: u64, // Same, for subscribed clients only
+    ticks: u64,
+    frames_dropped: u64,
+    pub stall_limit: Duration,
+}
+
+impl Broadcaster {
+    pub fn new(state: Arc<Mutex<WebTelemetry>>, clients: Arc<Mutex<Vec<WsClient>>>, speech: Arc<Mutex<Vec<String>>>,
+               events: Arc<Mutex<Vec<Landmark>>>, raster: Raster, spectrogram: Spectrogram) -> Self {
+        Self {
+            raster_seq: raster.next_seq(),
+            spectrogram_seq: spectrogram.next_seq(),
+            state, clients, speech, events, raster, spectrogram,
+            ticks: 0,
+            frames_dropped: 0,
+            stall_limit: WS_STALL_LIMIT,
+        }
+    }
+
+    /// Broadcast every `period` in a thread of its own.
+    pub fn spawn(mut self, period: Duration) {
+        thread::spawn(move || {
+            let mut next_tick = Instant::now() + period;
+            loop {
+                let now = Instant::now();
+                if next_tick > now {
+                    thread::sleep(next_tick - now);
+                }
+                next_tick = (next_tick + period).max(Instant::now());
+                self.tick();
+            }
+        });
+    }
+
+    /// One broadcast to every client. Returns the telemetry frame's size in bytes.
+    pub fn tick(&mut self) -> usize {
+        let (json, topology_json) = {
+            let mut state = self.state.lock().unwrap();
+            state.ws_frames_dropped = self.frames_dropped;
+            let speech = self.speech.lock().map(|mut pending| std::mem::take(&mut *pending)).unwrap_or_default();
+            let frame = telemetry_json(&state, speech);
+
+            // Topology (region map + positions) is the bulk of the payload and only
+            // changes after growth or re-specialization: a second variant carries it,
+            // for clients that haven't received this version yet
+            let needs_topology = self.clients.lock().unwrap().iter()
+                .any(|c| c.topology_version != Some(state.topology_version));
+            let topology_json = needs_topology.then(|| {
+                let mut with_topology = frame.clone();
+                with_topology["region_map"] = serde_json::json!(state.region_map);
+                with_topology["neuron_positions"] = serde_json::json!(state.neuron_positions);
+                with_topology["neuron_ids"] = serde_json::json!(state.neuron_ids);
+                (state.topology_version, with_topology.to_string())
+            });
+            (frame.to_string(), topology_json)
+        };
+
+        // Periodically send PING (Opcode 0x9) to keep connection alive
+        let ping_frame = Arc::new(ws_frame(OP_PING, &[]));
+        let send_ping = self.ticks % 60 == 0;
+        self.ticks += 1;
+
+        let frame = Arc::new(ws_frame(OP_TEXT, json.as_bytes()));
+        let topology_frame = topology_json.map(|(version, json)| (version, Arc::new(ws_frame(OP_TEXT, json.as_bytes()))));
+
+        // Raster columns since the last broadcast (~5 per frame at 60Hz)
+        let raster_frame = self.raster.encode_new(&mut self.raster_seq).map(|cols| Arc::new(ws_frame(OP_BINARY, &cols)));
+
+        // Spectrogram rows since the last broadcast (~8 per frame at 48kHz / 512 hop)
+        let spectrogram_frame = self.spectrogram.encode_new(&mut self.spectrogram_seq).map(|rows| Arc::new(ws_frame(OP_BINARY, &rows)));
+
+        // Events since the last broadcast, one {"Event": {...}} frame each (same JSON as the socket)
+        let event_frames: Vec<Arc<Vec<u8>>> = self.events.lock().map(|mut pending| std::mem::take(&mut *pending)).unwrap_or_default()
+            .into_iter()
+            .filter_map(|landmark| serde_json::to_string(&AlephPacket::from(landmark)).ok())
+            .map(|json| Arc::new(ws_frame(OP_TEXT, json.as_bytes())))
+            .collect();
+
+        let mut clients = self.clients.lock().unwrap();
+        if self.ticks % 60 == 0 {
+            println!("📉 Telemetry Payload: {} bytes | Clients: {}", json.len(), clients.len());
+        }
+
+        // Never blocks: each client has its own writer thread behind a bounded queue
+        let (stall_limit, frames_dropped) = (self.stall_limit, &mut self.frames_dropped);
+        clients.retain_mut(|client| {
+            if send_ping && client.offer(ping_frame.clone()) == Offer::Gone {
+                return false;
+            }
+
+            // The raster is best-effort: a dropped one is just a gap
+            if let Some(raster_frame) = &raster_frame {
+                if client.offer(raster_frame.clone()) == Offer::Gone {
+                    return false;
+                }
+            }
+            if let Some(spectrogram_frame) = spectrogram_frame.as_ref().filter(|_| client.subscribed(STREAM_SPECTROGRAM)) {
+                if client.offer(spectrogram_frame.clone()) == Offer::Gone {
+                    return false;
+                }
+            }
+            for event_frame in &event_frames {
+                match client.offer(event_frame.clone()) {
+                    Offer::Gone => return false,
+                    Offer::Dropped => *frames_dropped += 1,
+                    Offer::Queued => {},
+                }
+            }
+            let (frame, version) = match &topology_frame {
+                Some((version, topology)) if client.topology_version != Some(*version) => (topology, Some(*version)),
+                _ => (&frame, None),
+            };
+            match client.offer(frame.clone()) {
+                Offer::Queued => {
+                    if version.is_some() {
+                        client.topology_version = version;
+                    }
+                    true
+                },
+                Offer::Gone => false,
+                Offer::Dropped => {
+                    *frames_dropped += 1;
+                    if client.stalled_for() > stall_limit {
+                        println!("🐌 WebSocket client stalled for {}s ({} frames dropped). Disconnecting.",
+                            client.stalled_for().as_secs(), client.dropped);
+                        client.disconnect();
+                        return false;
+                    }
+                    true
+                }
+            }
+        });
+        json.len()
+    }
+}
+
+/// Round to 1/scale; NaN and infinities go out as 0.
+fn rounded(value: f32, scale: f32) -> f32 {
+    if value.is_finite() { (value * scale).round() / scale } else { 0.0 }
+}
+
+/// The telemetry frame, without the topology. Built in three parts: one `json!` with
+/// every field exceeds the macro's recursion limit.
+pub fn telemetry_json(state: &WebTelemetry, speech: Vec<String>) -> serde_json::Value {
+    // Sparse Updates: neurons > 0.005, rounded to 3 decimals
+    let sparse_reservoir: Vec<(usize, f32)> = state.reservoir_activity.iter().enumerate()
+        .filter(|(_, &v)| v > 0.005)
+        .map(|(i, &v)| (i, (v * 1000.0).round() / 1000.0))
+        .collect();
+    let clean_activations: Vec<f32> = state.activations.iter()
+        .map(|&v| if v.is_finite() { v } else { 0.0 })
+        .collect();
+
+    let mut frame = serde_json::json!({
+        "dopamine": rounded(state.dopamine, 1000.0),
+        "cortisol": rounded(state.cortisol, 1000.0),
+        "adenosine": rounded(state.adenosine, 1000.0),
+        "oxytocin": rounded(state.oxytocin, 1000.0),
+        "serotonin": rounded(state.serotonin, 1000.0),
+        "entropy": rounded(state.entropy, 1000.0),
+        "loop_frequency": rounded(state.loop_frequency, 10.0),
+        "hrv": if state.hrv.is_finite() { state.hrv } else { 0.0 },
+        "components": state.components,
+        "speech": speech,
+        "actuators": state.actuators,
+        "reservoir_activity": sparse_reservoir,
+        "current_state": state.current_state,
+        "thoughts": state.thoughts,
+        "trauma_state": state.trauma_state,
+        "hebbian_events": state.hebbian_events,
+        "reservoir_size": state.reservoir_size,
+        "top_activations": state.top_activations,
+        "llm_activity": state.llm_activity,
+        "system_ram_gb": state.system_ram_gb,
+        "system_cpu_load": state.system_cpu_load,
+        "activations": clean_activations,
+        "activation_layout": state.activation_layout,
+        "topology_version": state.topology_version,
+        "curiosity": state.curiosity,
+        "stress_tolerance": state.stress_tolerance,
+        "generation": state.generation,
+        "privacy_mode": state.privacy_mode,
+        // Latency: origin (Hello) + captured_us vs the client's clock; clock_us = when this frame was built
+        "spectrum_captured_us": state.audio_spectrum.captured_us,
+        "clock_us": pacing::monotonic_us()
+    });
+    let queues = serde_json::json!({
+        "stimuli_dropped": state.stimuli_dropped,
+        "cortex_queue_depth": state.cortex_queue_depth,
+        "cortex_queue_dropped": state.cortex_queue_dropped,
+        "cortex_tier": state.cortex_tier,
+        "ws_frames_dropped": state.ws_frames_dropped,
+        "glucose": state.glucose,
+        "energy_budget": state.energy_budget,
+        "audio_queue_depth": state.audio_queue_depth,
+        "audio_latency_ms": state.audio_latency_ms,
+        "audio_dropped": state.audio_dropped,
+        "audio_rejected": state.audio_rejected,
+        "audio_absurd_samples": state.audio_absurd_samples,
+        "audio_hallucinations_blocked": state.audio_hallucinations_blocked,
+        "prosody": state.prosody,
+        "plasticity_accumulated": state.plasticity_accumulated,
+        "lesioned": state.lesioned,
+        "feeding": state.feeding,
+        "sleep_talk": state.sleep_talk,
+        "pressure": state.pressure,
+        "repertoire": state.repertoire,
+        "checkin": state.checkin,
+        "latency": state.latency,
+        "volition": state.volition,
+        "standby": state.standby,
+        "frame_time": state.frame_time
+    });
+    let mind = serde_json::json!({
+        "attention_width": state.attention_width,
+        "homeostat": state.homeostat,
+        "entropy_legacy": state.entropy_legacy,
+        "complexity": state.complexity,
+        "voices": state.voices,
+        "sleep_stage": state.sleep_stage,
+        "memory_replays": state.memory_replays,
+        "binding_events": state.binding_events,
+        "region_stats": state.region_stats,
+        "growth": state.growth,
+        "attention_breakdown": state.attention_breakdown,
+        "attention_focus": state.attention_focus,
+        "attention_shifts": state.attention_shifts,
+        "memory": state.memory,
+        "memory_sieve": state.memory_sieve,
+        "satellite_passed": state.satellite_passed,
+        "satellite_hardened": state.satellite_hardened,
+        "satellite_ontological_errors": state.satellite_ontological_errors,
+        "satellite_attention": state.satellite_attention,
+        "gate_attempts": state.gate_attempts,
+        "gate_spoken": state.gate_spoken,
+        "gate_suppressed_by_reason": state.gate_suppressed_by_reason,
+        "prompt_tokens": {
+            "bio": state.prompt_split.bio,
+            "memory": state.prompt_split.memory,
+            "history": state.prompt_split.history,
+            "input": state.prompt_split.input,
+            "body_share": (state.prompt_split.body_share() * 1000.0).round() / 1000.0,
+            "bio_dropped": state.bio_dropped,
+            "bio_deduped": state.bio_deduped,
+        }
+    });
+    if let Some(fields) = frame.as_object_mut() {
+        for part in [queues, mind] {
+            if let serde_json::Value::Object(part) = part {
+                fields.extend(part);
+            }
+        }
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::mpsc;
+
+    /// A connected client: the broadcaster's end of it, and the browser's end.
+    fn connect(listener: &TcpListener) -> (WsClient, TcpStream) {
+        let browser = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        (WsClient::spawn(server, 8).unwrap(), browser)
+    }
+
+    /// Opcode of the next server frame (its payload is read and discarded).
+    fn skip_frame(stream: &mut TcpStream) -> std::io::Result<u8> {
+        let mut head = [0u8; 2];
+        stream.read_exact(&mut head)?;
+        let len = match head[1] & 127 {
+            126 => { let mut ext = [0u8; 2]; stream.read_exact(&mut ext)?; u16::from_be_bytes(ext) as u64 },
+            127 => { let mut ext = [0u8; 8]; stream.read_exact(&mut ext)?; u64::from_be_bytes(ext) },
+            n => n as u64,
+        };
+        std::io::copy(&mut stream.take(len), &mut std::io::sink())?;
+        Ok(head[0] & 0x0F)
+    }
+
+    #[test]
+    fn stalled_client_does_not_slow_the_cadence() {
+        // ~500KB frames: a client that never reads fills the socket buffers in a few ticks
+        let state = Arc::new(Mutex::new(WebTelemetry { reservoir_activity: vec![0.5; 40_000], ..Default::default() }));
+        let clients = Arc::new(Mutex::new(Vec::new()));
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let (healthy, mut browser) = connect(&listener);
+        let (stalled, _never_read) = connect(&listener);
+        clients.lock().unwrap().extend([healthy, stalled]);
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            while let Ok(opcode) = skip_frame(&mut browser) {
+                if opcode == OP_TEXT && tx.send(Instant::now()).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut broadcaster = Broadcaster::new(state.clone(), clients.clone(), Default::default(), Default::default(), Raster::new(), Spectrogram::new());
+        broadcaster.stall_limit = Duration::from_millis(500);
+        broadcaster.spawn(BROADCAST_PERIOD);
+        thread::sleep(Duration::from_secs(3));
+
+        let arrivals: Vec<Instant> = rx.try_iter().collect();
+        let worst_gap = arrivals.windows(2).map(|w| w[1] - w[0]).max().unwrap_or(Duration::MAX);
+        assert!(arrivals.len() >= 30, "{} frames in 3s (12Hz = 36)", arrivals.len());
+        assert!(worst_gap < BROADCAST_PERIOD * 3, "healthy client waited {:?} between frames", worst_gap);
+        assert_eq!(clients.lock().unwrap().len(), 1, "the stalled client should have been hung up on");
+        assert!(state.lock().unwrap().ws_frames_dropped > 0);
+    }
+}
@@ -0,0 +1,171 @@
+// src/core/bus.rs
+// THE BLOODSTREAM: Typed pub/sub for everything that happens inside ALEPH
+//
+// Before: a dozen point-to-point mpsc channels, each drained ad-hoc in the
+// daemon loop (cortex output was read in two places and one of them usually
+// lost the race). Now every source publishes an `Event`, and whoever cares
+// subscribes. The physics loop is one subscriber, the thought logger another;
+// new observers (journal, exporters) just call `subscribe()`.
+//
+// Deliberately std-only: one unbounded mpsc channel per subscriber, events
+// cloned on publish (heavy payloads travel behind `Arc`). Dead subscribers are
+// pruned on the next publish.
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::core::feeding::Feeding;
+use crate::core::hippocampus::MemoryOutput;
+use crate::core::ipc::Landmark;
+use crate::core::thought::Thought;
+use crate::cortex::planet::CortexOutput;
+use crate::senses::ears::{AudioSpectrum, Utterance};
+use crate::senses::proprioception::BodyStatus;
+
+/// Something the senses (or the outside world) felt.
+#[derive(Clone)]
+pub enum SensoryEvent {
+    Stimulus(String),            // Admitted user text (Web / IPC / TUI)
+    Heard(Utterance),            // Whisper transcription + prosody (perturbation, not instruction)
+    Spectrum(AudioSpectrum),     // FFT packet from the ears
+    WordEmbedding(Arc<Vec<f32>>), // Wernicke pathway (hashed words)
+    Vision(Arc<Vec<f32>>),       // 64x64 occipital grid
+    Body(BodyStatus),            // Proprioception (CPU / RAM)
+}
+
+/// Chemical state changes worth telling the outside about.
+#[derive(Clone, Debug)]
+pub enum ChemEvent {
+    Snapshot { dopamine: f32, cortisol: f32, adenosine: f32, oxytocin: f32, serotonin: f32, entropy: f32 },
+}
+
+#[derive(Clone)]
+pub enum CortexEvent {
+    Output(Arc<CortexOutput>),
+}
+
+#[derive(Clone)]
+pub enum MemoryEvent {
+    Recalled(Arc<MemoryOutput>), // Hippocampus answer (novelty, retrieval, engram)
+    Log(String),                 // Hippocampus narration ("Novelty Detected", ...)
+}
+
+#[derive(Clone, Debug)]
+pub enum ControlEvent {
+    Sleep,                      // SYS:SLEEP
+    Poke,                       // SYS:POKE
+    ReloadGenome,               // SIGHUP / POST /genome/reload
+    Privacy(bool),              // SYS:DND_ON / SYS:DND_OFF
+    Feed(Feeding),              // SYS:FEED:<kind>:<amount> (POST /feed, TUI F3-F5)
+    SourceClosed(&'static str), // A bridged producer hung up
+}
+
+#[derive(Clone)]
+pub enum Event {
+    Sensory(SensoryEvent),
+    Chem(ChemEvent),
+    Cortex(CortexEvent),
+    Memory(MemoryEvent),
+    Control(ControlEvent),
+    Thought(Thought),
+    Landmark(Landmark), // Once per occurrence (epiphany, sleep stage, ...): AlephPacket::Event for the clients
+}
+
+/// Broadcast bus. Cheap to clone (shared subscriber list).
+#[derive(Clone, Default)]
+pub struct Bus {
+    subscribers: Arc<Mutex<Vec<Sender<Event>>>>,
+}
+
+impl Bus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every event published from now on is delivered to the returned receiver.
+    pub fn subscribe(&self) -> Receiver<Event> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    pub fn publish(&self, event: Event) {
+        let mut subs = self.subscribers.lock().unwrap();
+        subs.retain(|s| s.send(event.clone()).is_ok());
+    }
+
+    /// Migration helper: pump a legacy mpsc channel onto the bus.
+    /// `map` may swallow items (None). When the producer hangs up a
+    /// `ControlEvent::SourceClosed(name)` is published.
+    pub fn bridge<T, F>(&self, name: &'static str, rx: Receiver<T>, map: F)
+    where
+        T: Send + 'static,
+        F: Fn(T) -> Option<Event> + Send + 'static,
+    {
+        let bus = self.clone();
+        thread::spawn(move || {
+            for item in rx {
+                if let Some(event) = map(item) {
+                    bus.publish(event);
+                }
+            }
+            bus.publish(Event::Control(ControlEvent::SourceClosed(name)));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::stimulus;
+    use std::time::Duration;
+
+    fn stimulus_text(event: Event) -> Option<String> {
+        match event {
+            Event::Sensory(SensoryEvent::Stimulus(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn every_subscriber_gets_every_event_in_order() {
+        let bus = Bus::new();
+        let (physics, logger) = (bus.subscribe(), bus.subscribe());
+        for word in ["uno", "dos", "tres"] {
+            bus.publish(Event::Sensory(SensoryEvent::Stimulus(word.to_string())));
+        }
+        for rx in [physics, logger] {
+            assert_eq!(rx.try_iter().filter_map(stimulus_text).collect::<Vec<_>>(), ["uno", "dos", "tres"]);
+        }
+    }
+
+    #[test]
+    fn dead_subscribers_are_pruned_on_publish() {
+        let bus = Bus::new();
+        let alive = bus.subscribe();
+        drop(bus.subscribe());
+        bus.publish(Event::Control(ControlEvent::Poke));
+        assert_eq!(bus.subscribers.lock().unwrap().len(), 1);
+        assert!(matches!(alive.try_recv(), Ok(Event::Control(ControlEvent::Poke))));
+    }
+
+    #[test]
+    fn bridged_stimuli_are_routed_and_the_hangup_announced() {
+        let bus = Bus::new();
+        let rx = bus.subscribe();
+        let (tx, legacy) = mpsc::channel::<String>();
+        bus.bridge("stimulus", legacy, stimulus::route);
+        for text in ["hola", "SYS:SLEEP", "SYS:NOT_A_COMMAND", "adiós"] {
+            tx.send(text.to_string()).unwrap();
+        }
+        drop(tx);
+
+        let events: Vec<Event> = (0..4).map(|_| rx.recv_timeout(Duration::from_secs(5)).unwrap()).collect();
+        assert!(matches!(&events[0], Event::Sensory(SensoryEvent::Stimulus(t)) if t == "hola"));
+        assert!(matches!(&events[1], Event::Control(ControlEvent::Sleep)));
+        // The unknown SYS: command never reaches the bus
+        assert!(matches!(&events[2], Event::Sensory(SensoryEvent::Stimulus(t)) if t == "adiós"));
+        assert!(matches!(&events[3], Event::Control(ControlEvent::SourceClosed("stimulus"))));
+    }
+}
@@ -0,0 +1,236 @@
+// src/core/checkin.rs
+// THE KNOCK: Checking in when someone goes quiet
+//
+// A conversation that simply stops used to fade back into ambient mode. Now an
+// engaged person falling silent is noticed. Engagement is a leaky count of
+// human contact (typed or transcribed utterances, per_contact each, halving
+// every half_life_secs). Once it has crossed engagement_threshold an episode
+// is open; when nothing (no utterance, no voice-flagged audio) has arrived for
+// silence_secs, the episode is decided exactly once:
+//
+//   p = probability × (0.5 + oxytocin) × (1 − adenosine)      (clamped 0-1)
+//
+// On a hit ALEPH checks in once through the agency path; either way the episode
+// is resolved and only new contact opens another, so it never nags. The
+// cooldown and the hourly cap turn a hit into letting it be, and so do DND and
+// sleep. The detector lives as long as the daemon (its counters and hourly
+// history survive sleep and DND).
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::core::config::CheckInConfig;
+
+const HOUR: Duration = Duration::from_secs(3600);
+
+/// How an episode ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Knock {
+    /// Speak once: the person has been silent this long.
+    CheckIn { silent: Duration, probability: f32 },
+    /// Resolved without a word.
+    LetBe { reason: &'static str, probability: f32 },
+}
+
+/// What /telemetry shows.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CheckInState {
+    pub engagement: f32,
+    pub episode_open: bool,
+    pub checkins: u64,
+    pub let_be: u64,
+}
+
+pub struct CheckIn {
+    config: CheckInConfig,
+    engagement: f32,
+    last_decay: Option<Instant>,
+    last_contact: Option<Instant>,
+    episode_open: bool,
+    recent: VecDeque<Instant>, // Check-ins within the last hour
+    checkins: u64,
+    let_be: u64,
+}
+
+impl CheckIn {
+    pub fn new(config: CheckInConfig) -> Self {
+        Self { config, engagement: 0.0, last_decay: None, last_contact: None, episode_open: false, recent: VecDeque::new(), checkins: 0, let_be: 0 }
+    }
+
+    fn decay(&mut self, now: Instant) {
+        if let Some(at) = self.last_decay {
+            let half_life = self.config.half_life_secs.max(1.0);
+            self.engagement *= 0.5f32.powf(now.duration_since(at).as_secs_f32() / half_life);
+        }
+        self.last_decay = Some(now);
+    }
+
+    /// Someone said something (stimulus or transcript): engages and breaks the silence.
+    pub fn contact(&mut self, now: Instant) {
+        self.decay(now);
+        self.engagement = (self.engagement + self.config.per_contact).min(1.0);
+        self.last_contact = Some(now);
+        if self.engagement >= self.config.engagement_threshold {
+            self.episode_open = true;
+        }
+    }
+
+    /// Voice-flagged audio: breaks the silence without adding engagement.
+    pub fn voice(&mut self, now: Instant) {
+        self.last_contact = Some(now);
+    }
+
+    /// Once per frame. `muted` = DND or asleep; `roll` in [0, 1) (rand::random in the daemon).
+    /// Some only on the frame an episode is decided.
+    pub fn observe(&mut self, now: Instant, oxytocin: f32, adenosine: f32, muted: bool, roll: f32) -> Option<Knock> {
+        self.decay(now);
+        if !self.config.enabled || !self.episode_open {
+            return None;
+        }
+        let probability = (self.config.probability * (0.5 + oxytocin) * (1.0 - adenosine)).clamp(0.0, 1.0);
+        let let_be = |this: &mut Self, reason: &'static str| {
+            this.episode_open = false;
+            this.let_be += 1;
+            Some(Knock::LetBe { reason, probability })
+        };
+        if muted {
+            return let_be(self, "muted");
+        }
+        let silent = now.duration_since(self.last_contact?);
+        if silent.as_secs_f32() < self.config.silence_secs {
+            return None;
+        }
+        while self.recent.front().is_some_and(|&at| now.duration_since(at) >= HOUR) {
+            self.recent.pop_front();
+        }
+        if self.recent.back().is_some_and(|&at| now.duration_since(at).as_secs_f32() < self.config.cooldown_secs) {
+            return let_be(self, "cooldown");
+        }
+        if self.recent.len() >= self.config.max_per_hour as usize {
+            return let_be(self, "hourly cap");
+        }
+        if roll >= probability {
+            return let_be(self, "chance");
+        }
+        self.episode_open = false;
+        self.recent.push_back(now);
+        self.checkins += 1;
+        Some(Knock::CheckIn { silent, probability })
+    }
+
+    pub fn state(&self) -> CheckInState {
+        CheckInState { engagement: self.engagement, episode_open: self.episode_open, checkins: self.checkins, let_be: self.let_be }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A virtual clock at 1 s per frame.
+    fn at(t0: Instant, s: u64) -> Instant {
+        t0 + Duration::from_secs(s)
+    }
+
+    /// Three lines ten seconds apart (engagement ~0.74).
+    fn converse(c: &mut CheckIn, t0: Instant, start: u64) {
+        for s in [start, start + 10, start + 20] {
+            c.contact(at(t0, s));
+        }
+    }
+
+    fn run(c: &mut CheckIn, t0: Instant, from: u64, to: u64, oxytocin: f32, adenosine: f32, muted: bool) -> Vec<(u64, Knock)> {
+        (from..to).filter_map(|s| c.observe(at(t0, s), oxytocin, adenosine, muted, 0.0).map(|k| (s, k))).collect()
+    }
+
+    #[test]
+    fn one_episode_of_silence_gets_exactly_one_check_in() {
+        // Engage -> silence -> check-in -> two more hours of silence
+        let config = CheckInConfig::default();
+        let t0 = Instant::now();
+        let mut c = CheckIn::new(config.clone());
+        converse(&mut c, t0, 0);
+        assert!(c.state().episode_open);
+        let knocks = run(&mut c, t0, 21, 21 + 2 * 3600, 0.5, 0.1, false);
+        assert!(matches!(knocks.as_slice(), [(_, Knock::CheckIn { .. })]), "{:?}", knocks);
+        assert_eq!(knocks[0].0, 20 + config.silence_secs as u64);
+    }
+
+    #[test]
+    fn one_line_is_not_a_conversation() {
+        let t0 = Instant::now();
+        let mut c = CheckIn::new(CheckInConfig::default());
+        c.contact(at(t0, 0));
+        assert!(run(&mut c, t0, 1, 3600, 0.5, 0.1, false).is_empty());
+    }
+
+    #[test]
+    fn voice_audio_keeps_the_silence_from_starting() {
+        let config = CheckInConfig::default();
+        let t0 = Instant::now();
+        let mut c = CheckIn::new(config.clone());
+        converse(&mut c, t0, 0);
+        let mut knocks = Vec::new();
+        for s in 21..(21 + 2 * config.silence_secs as u64) {
+            if s < 21 + config.silence_secs as u64 {
+                c.voice(at(t0, s));
+            }
+            knocks.extend(c.observe(at(t0, s), 0.5, 0.1, false, 0.0).map(|k| (s, k)));
+        }
+        // One decision, a full silence after the voice stopped
+        assert_eq!(knocks.len(), 1, "{:?}", knocks);
+        assert_eq!(knocks[0].0, 20 + 2 * config.silence_secs as u64);
+    }
+
+    #[test]
+    fn muted_or_tired_resolves_the_episode_without_a_word() {
+        let t0 = Instant::now();
+        let mut c = CheckIn::new(CheckInConfig::default());
+        converse(&mut c, t0, 0);
+        let muted = run(&mut c, t0, 21, 3600, 0.5, 0.1, true);
+        assert!(matches!(muted.as_slice(), [(_, Knock::LetBe { reason: "muted", .. })]), "{:?}", muted);
+
+        // A dry roll at high adenosine
+        let mut tired = CheckIn::new(CheckInConfig::default());
+        converse(&mut tired, t0, 0);
+        let dry = run(&mut tired, t0, 21, 3600, 0.5, 1.0, false);
+        assert!(matches!(dry.as_slice(), [(_, Knock::LetBe { reason: "chance", .. })]), "{:?}", dry);
+    }
+
+    #[test]
+    fn oxytocin_and_adenosine_shape_the_probability() {
+        let config = CheckInConfig::default();
+        let t0 = Instant::now();
+        let mut c = CheckIn::new(config.clone());
+        converse(&mut c, t0, 0);
+        let knocks = run(&mut c, t0, 21, 3600, 0.3, 0.4, false);
+        let Some((_, Knock::CheckIn { probability, .. })) = knocks.first() else { panic!("no check-in: {:?}", knocks) };
+        let expected = (config.probability * 0.8 * 0.6).clamp(0.0, 1.0);
+        assert!((probability - expected).abs() <= 1e-5, "probability {:.4}, expected {:.4}", probability, expected);
+    }
+
+    #[test]
+    fn the_cooldown_and_the_hourly_cap_hold() {
+        let config = CheckInConfig { silence_secs: 60.0, cooldown_secs: 600.0, max_per_hour: 2, ..CheckInConfig::default() };
+        let t0 = Instant::now();
+        let mut c = CheckIn::new(config);
+        let mut outcomes = Vec::new();
+        for start in [0u64, 300, 1000, 2000] {
+            converse(&mut c, t0, start);
+            outcomes.extend(run(&mut c, t0, start + 21, start + 200, 0.5, 0.1, false).into_iter().map(|(_, k)| match k {
+                Knock::CheckIn { .. } => "check-in",
+                Knock::LetBe { reason, .. } => reason,
+            }));
+        }
+        assert_eq!(outcomes, ["check-in", "cooldown", "check-in", "hourly cap"]);
+    }
+
+    #[test]
+    fn disabled_never_knocks() {
+        let t0 = Instant::now();
+        let mut c = CheckIn::new(CheckInConfig { enabled: false, ..CheckInConfig::default() });
+        converse(&mut c, t0, 0);
+        assert!(run(&mut c, t0, 21, 3600, 0.5, 0.1, false).is_empty());
+    }
+}
@@ -1,12 +1,17 @@
 
 
-#[derive(Debug, Clone)]
+use serde::{Serialize, Deserialize};
+use crate::senses::prosody::Prosody;
+use crate::core::lexicon::Lexicon;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Neurotransmitters {
     pub adenosine: f32, // Sleep Pressure (0.0 - 1.0)
     pub dopamine: f32,  // Engagement/Reward (0.0 - 1.0)
     pub cortisol: f32,  // Stress (0.0 - 1.0)
     pub oxytocin: f32,  // Trust/Bonding (0.0 - 1.0) - Social Glue
     pub serotonin: f32, // Mood Stabilization / Resilience (0.0 - 1.0)
+    pub glucose: f32,   // Metabolic Fuel (0.0 - 1.0) - Spent by work, restored by rest
 }
 
 impl Neurotransmitters {
@@ -17,6 +22,7 @@ impl Neurotransmitters {
             cortisol: 0.0,
             oxytocin: 0.5, // Baseline trust
             serotonin: 0.5, // Baseline mood
+            glucose: 1.0,   // Full tank
         }
     }
 
@@ -82,8 +88,8 @@ impl Neurotransmitters {
         }
 
         // 4. OXYTOCIN (Trust)
-        // Decays slowly
-        self.oxytocin -= 0.001 * time_scale; 
+        // Decays slowly (~28 min from full): bonds outlast the conversation (sources in core/bonding.rs)
+        self.oxytocin -= 0.00001 * time_scale;
 
         // 5. HOMEOSTATIC NOISE (The "Breath" of the system)
         // Prevents static flatlines
@@ -96,6 +102,8 @@ impl Neurotransmitters {
         self.dopamine = self.dopamine.clamp(0.0, 1.0);
         self.cortisol = self.cortisol.clamp(0.0, 1.0);
         self.oxytocin = self.oxytocin.clamp(0.0, 1.0);
+        self.serotonin = self.serotonin.clamp(0.0, 1.0); // Chronic stress used to drive it negative
+        self.glucose = self.glucose.clamp(0.0, 1.0);
     }
 
     /// Map Hardware Proprioception to Biological States
@@ -113,6 +121,30 @@ impl Neurotransmitters {
         }
     }
     
+    /// AUDIO -> CHEMISTRY, once per tick. `audio_energy` = bass + mids + highs of the
+    /// latest spectrum (0..3). Only VERY LOUD audio stresses (> 1.5), silence heals,
+    /// and a moderate, structured level (music) is mildly interesting.
+    pub fn hear(&mut self, audio_energy: f32) {
+        if audio_energy > 1.5 {
+            // Noise Stress
+            let stress_factor = (audio_energy - 1.5) * 0.05;
+            self.cortisol = (self.cortisol + stress_factor).min(1.0);
+
+            // Wake
+            let wake_factor = audio_energy * 0.02;
+            self.adenosine = (self.adenosine - wake_factor).max(0.0);
+        } else if audio_energy < 0.5 {
+            // SILENCE HEALS (Active Relaxation)
+            // If quiet, cortisol drops faster than natural decay
+            self.cortisol = (self.cortisol - 0.005).max(0.0);
+        }
+
+        // Music interest (Dopamine) - Needs SIGNIFICANT rhythm, not just noise
+        if audio_energy > 0.6 && audio_energy < 1.4 {
+            self.dopamine = (self.dopamine + 0.002).min(1.0); // Very small gain
+        }
+    }
+
     /// Set adenosine base level from unprocessed memory count (MECHANICAL HONESTY)
     /// This REPLACES the memory component, not adds to it
     // Unused methods removed (set_memory_pressure, is_body_failing, is_recovered_to_wake)
@@ -128,89 +160,57 @@ impl Neurotransmitters {
         }
     }
 
-    /// SEMANTIC PERTURBATION: Keywords become chemical responses
-    /// The text is not "understood" - it's FELT as sensory input
-    /// Returns semantic_friction value (energy cost of processing)
-    /// Phase 4.3: Enhanced with weighted scoring, intensity modifiers, and mixed emotion detection
-    pub fn apply_semantic_perturbation(&mut self, text: &str) -> f32 {
-        let lower = text.to_lowercase();
-        let word_count = text.split_whitespace().count() as f32;
-        let mut friction = word_count * 0.01; // Base cost per word
+    /// PROSODIC PERTURBATION: The tone is felt even when the words are neutral
+    /// Shouting (loud + high) -> Cortisol | Flat & slow -> Adenosine | Lively -> Dopamine (+ Oxytocin if familiar)
+    /// Returns a short label of what was felt (None = ordinary speech)
+    pub fn apply_prosody(&mut self, tone: &Prosody, familiar: bool) -> Option<&'static str> {
+        if tone.pitch_hz <= 0.0 {
+            return None; // Nothing voiced (noise, whisper)
+        }
 
-        // INTENSITY MODIFIERS (amplify next emotion hit)
-        let intensity = if lower.contains("muy") || lower.contains("very") || lower.contains("extremely") 
-                          || lower.contains("so ") || lower.contains("demasiado") || lower.contains("!!") {
-            2.0
-        } else if lower.contains("un poco") || lower.contains("slightly") || lower.contains("algo") {
-            0.5
+        let felt = if tone.loudness > 0.15 && tone.pitch_hz > 260.0 {
+            // Startle scales with how hard it was
+            self.cortisol += (0.05 + tone.loudness * 0.3).min(0.2);
+            "shouting"
+        } else if tone.pitch_variance < 0.08 && tone.syllable_rate < 2.5 {
+            self.adenosine += 0.02; // Monotone drone: boring, soporific
+            "flat"
+        } else if tone.pitch_variance > 0.2 && tone.syllable_rate > 3.5 {
+            self.dopamine += 0.05;
+            if familiar {
+                self.oxytocin += 0.05; // A known voice, animated: bonding
+            }
+            "lively"
         } else {
-            1.0
+            return None;
         };
 
-        let mut stress_hits = 0;
-        let mut calm_hits = 0;
-
-        // STRESS TRIGGERS (Cortisol) — weighted by severity
-        let stress_words: &[(&str, f32)] = &[
-            ("miedo", 0.15), ("peligro", 0.2), ("error", 0.1), ("stop", 0.12),
-            ("no", 0.05), ("malo", 0.12), ("muerte", 0.25), ("fear", 0.15),
-            ("danger", 0.2), ("bad", 0.1), ("kill", 0.25), ("pain", 0.18),
-            ("dolor", 0.18), ("odio", 0.2), ("hate", 0.2), ("guerra", 0.22),
-            ("war", 0.22), ("destroy", 0.2), ("destruir", 0.2), ("panic", 0.2),
-        ];
-        for &(word, weight) in stress_words {
-            if lower.contains(word) {
-                self.cortisol += weight * intensity;
-                friction += 0.1 * intensity;
-                stress_hits += 1;
-            }
-        }
-
-        // CALM TRIGGERS (Oxytocin) — weighted
-        let calm_words: &[(&str, f32)] = &[
-            ("amor", 0.15), ("paz", 0.12), ("bien", 0.08), ("gracias", 0.15),
-            ("love", 0.15), ("peace", 0.12), ("good", 0.08), ("thank", 0.12),
-            ("hermoso", 0.1), ("beautiful", 0.1), ("tranquil", 0.15),
-            ("calm", 0.12), ("gentle", 0.1), ("suave", 0.1), ("abrazo", 0.18),
-            ("hug", 0.18), ("friend", 0.12), ("amigo", 0.12),
-        ];
-        for &(word, weight) in calm_words {
-            if lower.contains(word) {
-                self.oxytocin += weight * intensity;
-                self.cortisol = (self.cortisol - 0.05 * intensity).max(0.0);
-                calm_hits += 1;
-            }
-        }
+        self.cortisol = self.cortisol.clamp(0.0, 1.0);
+        self.adenosine = self.adenosine.clamp(0.0, 1.0);
+        self.dopamine = self.dopamine.clamp(0.0, 1.0);
+        self.oxytocin = self.oxytocin.clamp(0.0, 1.0);
+        Some(felt)
+    }
 
-        // NOVELTY TRIGGERS (Dopamine)
-        let novelty_words: &[(&str, f32)] = &[
-            ("nuevo", 0.15), ("descubr", 0.2), ("interesante", 0.18),
-            ("wow", 0.2), ("new", 0.12), ("discover", 0.2), ("amazing", 0.2),
-            ("increíble", 0.2), ("fascinating", 0.18), ("curious", 0.15),
-            ("curioso", 0.15), ("idea", 0.1), ("create", 0.15), ("crear", 0.15),
-        ];
-        for &(word, weight) in novelty_words {
-            if lower.contains(word) {
-                self.dopamine += weight * intensity;
-            }
-        }
+    /// SEMANTIC PERTURBATION: Words become chemical responses
+    /// The text is not "understood" - it's FELT as sensory input (vocabulary in lexicon.toml)
+    /// Returns semantic_friction value (energy cost of processing)
+    pub fn apply_semantic_perturbation(&mut self, text: &str, lexicon: &mut Lexicon) -> f32 {
+        let word_count = text.split_whitespace().count() as f32;
+        let mut friction = word_count * 0.01; // Base cost per word
 
-        // FATIGUE TRIGGERS (Adenosine)
-        let fatigue_words: &[(&str, f32)] = &[
-            ("cansado", 0.1), ("dormir", 0.12), ("aburrido", 0.1),
-            ("tired", 0.1), ("sleep", 0.12), ("boring", 0.1),
-            ("monoton", 0.08), ("repetit", 0.08), ("exhausted", 0.15),
-        ];
-        for &(word, weight) in fatigue_words {
-            if lower.contains(word) {
-                self.adenosine += weight * intensity;
-            }
-        }
+        let reading = lexicon.read(text);
+        let felt = reading.feeling;
+        self.cortisol += felt.cortisol;
+        self.oxytocin += felt.oxytocin;
+        self.dopamine += felt.dopamine;
+        self.adenosine += felt.adenosine;
+        friction += 0.1 * reading.intensity * reading.stress_hits as f32; // Threats cost more to process
 
         // MIXED EMOTION DETECTION: Conflicting signals = confusion = stress
         // If both stress and calm detected simultaneously, that's cognitive dissonance
-        if stress_hits > 0 && calm_hits > 0 {
-            let dissonance = (stress_hits.min(calm_hits) as f32) * 0.05;
+        if reading.stress_hits > 0 && reading.calm_hits > 0 {
+            let dissonance = (reading.stress_hits.min(reading.calm_hits) as f32) * 0.05;
             self.cortisol += dissonance;
             friction += dissonance;
         }
@@ -232,3 +232,325 @@ impl Neurotransmitters {
         self.cortisol = (self.cortisol - amount * 0.5).max(0.0);
     }
 }
+
+// Scripted regimes at a fixed 60Hz delta_time, every level checked against a band.
+// Bands are the tuned behavior as of writing, with tolerance. If a change is
+// intentional, update the band in the same commit that moves the constant.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bonding::Bonding;
+    use crate::core::config::EarsConfig;
+    use crate::senses::cochlea::Cochlea;
+    use crate::senses::synthetic::Script;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::time::{Duration, Instant};
+
+    const DT: f32 = 1.0 / 60.0;
+    const FUZZ_SEED: u64 = 7;
+    const FUZZ_CASES: usize = 500;
+
+    #[derive(Clone, Copy)]
+    enum Step {
+        /// `secs` of ticks: (entropy, cpu_load %, dreaming, shock)
+        Tick { secs: f32, entropy: f32, cpu: f32, dreaming: bool, shock: f32 },
+        /// One proprioception reading: (cpu %, ram fraction)
+        Hardware { cpu: f32, ram: f32 },
+        /// Something heard/typed
+        Say(&'static str),
+        /// A known person (see KNOWN) speaks to ALEPH: bonding + lexicon
+        Address(&'static str),
+        /// ALEPH answers out loud
+        Reply,
+        /// Synthetic audio (senses/synthetic.rs) through the real cochlea, calm otherwise:
+        /// each tick hears the last spectrum frame its samples produced, as the daemon does
+        Hear(&'static str),
+    }
+
+    /// People the simulated ALEPH has already met.
+    const KNOWN: [&str; 1] = ["Marta"];
+
+    /// Everything a scenario steps: chemistry plus the modules that feed it.
+    /// `clock` is simulated (advanced by Tick steps), so windows behave as in real time.
+    struct Sim {
+        chem: Neurotransmitters,
+        lexicon: Lexicon,
+        bonding: Bonding,
+        clock: Instant,
+    }
+
+    impl Sim {
+        fn new(chem: Neurotransmitters) -> Self {
+            Self { chem, lexicon: Lexicon::builtin(), bonding: Bonding::new(), clock: Instant::now() }
+        }
+
+        fn step(&mut self, step: Step) {
+            match step {
+                Step::Tick { secs, entropy, cpu, dreaming, shock } => {
+                    for _ in 0..(secs / DT).round() as usize {
+                        self.chem.tick(entropy, cpu, dreaming, shock, 500, DT);
+                    }
+                    self.clock += Duration::from_secs_f32(secs);
+                },
+                Step::Hardware { cpu, ram } => self.chem.update_from_hardware(cpu, ram, 1.0),
+                Step::Say(text) => {
+                    self.chem.apply_semantic_perturbation(text, &mut self.lexicon);
+                },
+                Step::Address(text) => {
+                    let known: Vec<String> = KNOWN.iter().map(|n| n.to_string()).collect();
+                    self.chem.apply_semantic_perturbation(text, &mut self.lexicon);
+                    self.bonding.heard_from(KNOWN[0], &mut self.chem, self.clock);
+                    self.bonding.addressed(text, &known, &mut self.chem, self.clock);
+                },
+                Step::Reply => self.bonding.vocalized(self.clock),
+                Step::Hear(source) => {
+                    let script = Script::parse(source).expect("scenario audio script");
+                    let samples = script.render().expect("scenario audio renders");
+                    let ears = EarsConfig::default();
+                    let mut cochlea = Cochlea::new(script.sample_rate, ears.fft_size, ears.fft_hop);
+                    let secs = samples.len() as f32 / script.sample_rate as f32;
+                    let ticks = (secs / DT).round() as usize;
+                    for t in 0..ticks {
+                        let chunk = &samples[t * samples.len() / ticks..(t + 1) * samples.len() / ticks];
+                        let energy = cochlea.push(chunk).last().map_or(0.0, |f| f.bass + f.mids + f.highs);
+                        self.chem.hear(energy);
+                        self.chem.tick(0.3, 20.0, false, 0.0, 500, DT);
+                    }
+                    self.clock += Duration::from_secs_f32(secs);
+                },
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    enum Level {
+        Adenosine,
+        Dopamine,
+        Cortisol,
+        Oxytocin,
+        Serotonin,
+        Impairment, // get_cognitive_impairment()
+    }
+
+    impl Level {
+        const ALL: [Level; 6] = [Level::Adenosine, Level::Dopamine, Level::Cortisol, Level::Oxytocin, Level::Serotonin, Level::Impairment];
+
+        fn read(self, chem: &Neurotransmitters) -> f32 {
+            match self {
+                Level::Adenosine => chem.adenosine,
+                Level::Dopamine => chem.dopamine,
+                Level::Cortisol => chem.cortisol,
+                Level::Oxytocin => chem.oxytocin,
+                Level::Serotonin => chem.serotonin,
+                Level::Impairment => chem.get_cognitive_impairment(),
+            }
+        }
+    }
+
+    /// After `after` steps of the script, `level` must be within [min, max].
+    struct Band {
+        after: usize,
+        level: Level,
+        min: f32,
+        max: f32,
+    }
+
+    const fn band(after: usize, level: Level, min: f32, max: f32) -> Band {
+        Band { after, level, min, max }
+    }
+
+    const fn calm(secs: f32) -> Step {
+        Step::Tick { secs, entropy: 0.3, cpu: 20.0, dreaming: false, shock: 0.0 }
+    }
+
+    /// Steps `start` through `script`, checking each band once its step has run.
+    fn replay(start: Neurotransmitters, script: &[Step], bands: &[Band]) {
+        let mut sim = Sim::new(start);
+        for after in 0..=script.len() {
+            if after > 0 {
+                sim.step(script[after - 1]);
+            }
+            for b in bands.iter().filter(|b| b.after == after) {
+                let value = b.level.read(&sim.chem);
+                assert!(value >= b.min - 1e-4 && value <= b.max + 1e-4, "after step {}: {:?} = {:.4}, expected [{}, {}]",
+                    after, b.level, value, b.min, b.max);
+            }
+        }
+    }
+
+    #[test]
+    fn silence_bores_within_seconds_and_tires_within_ten_minutes() {
+        replay(Neurotransmitters::new(), &[calm(10.0), calm(600.0)], &[
+            band(1, Level::Dopamine, 0.0, 0.05),     // Boredom wins within seconds
+            band(1, Level::Cortisol, 0.0, 0.01),
+            band(2, Level::Adenosine, 0.88, 0.95),   // Ten quiet minutes are enough to get drowsy
+            band(2, Level::Oxytocin, 0.12, 0.15),    // Nobody around: trust fades slowly
+            band(2, Level::Impairment, 0.78, 0.88),  // ...and foggy
+        ]);
+    }
+
+    #[test]
+    fn sustained_noise_saturates_stress_and_floors_serotonin() {
+        let noisy = |secs| Step::Tick { secs, entropy: 0.9, cpu: 30.0, dreaming: false, shock: 0.02 };
+        replay(Neurotransmitters::new(), &[noisy(5.0), noisy(60.0)], &[
+            band(1, Level::Cortisol, 0.95, 1.0),     // Overload saturates stress fast
+            band(2, Level::Cortisol, 0.95, 1.0),
+            band(2, Level::Dopamine, 0.9, 1.0),      // High entropy is still novelty
+            band(2, Level::Serotonin, 0.0, 0.05),    // Chronic stress eats the buffer (floor, not below)
+            band(2, Level::Adenosine, 0.9, 1.0),     // Shocks are exhausting
+        ]);
+    }
+
+    #[test]
+    fn sleep_clears_adenosine_and_restores_mood() {
+        let asleep = |secs| Step::Tick { secs, entropy: 0.2, cpu: 10.0, dreaming: true, shock: 0.0 };
+        let exhausted = Neurotransmitters { adenosine: 0.9, cortisol: 0.3, ..Neurotransmitters::new() };
+        replay(exhausted, &[asleep(5.0), asleep(10.0)], &[
+            band(0, Level::Impairment, 0.75, 0.85),  // Foggy before sleeping
+            band(1, Level::Adenosine, 0.55, 0.65),   // 0.06/s recovery
+            band(1, Level::Cortisol, 0.0, 0.01),
+            band(2, Level::Adenosine, 0.0, 0.01),    // Fully rested
+            band(2, Level::Impairment, 0.0, 0.0),
+            band(2, Level::Serotonin, 0.9, 1.0),     // Sleep restores mood
+        ]);
+    }
+
+    #[test]
+    fn a_cpu_spike_raises_the_stress_floor_until_it_passes() {
+        replay(Neurotransmitters::new(), &[
+            Step::Hardware { cpu: 95.0, ram: 0.5 },
+            Step::Tick { secs: 2.0, entropy: 0.3, cpu: 95.0, dreaming: false, shock: 0.0 },
+            Step::Hardware { cpu: 20.0, ram: 0.95 },
+            calm(5.0),
+        ], &[
+            band(1, Level::Cortisol, 0.4, 0.4),      // Stress floor
+            band(2, Level::Cortisol, 0.53, 0.6),     // Overload keeps climbing
+            band(3, Level::Adenosine, 0.8, 1.0),     // Full RAM = forced fog
+            band(3, Level::Impairment, 0.6, 1.0),
+            band(4, Level::Cortisol, 0.0, 0.05),     // Relief once the spike passes
+        ]);
+    }
+
+    #[test]
+    fn insults_stress_and_kindness_only_chips_at_it() {
+        replay(Neurotransmitters::new(), &[
+            Step::Say("odio esto, eres malo"),
+            Step::Say("muy malo, kill it!!"),
+            Step::Say("gracias amigo, paz"),
+            calm(30.0),
+        ], &[
+            band(1, Level::Cortisol, 0.3, 0.34),     // odio + malo
+            band(2, Level::Cortisol, 0.95, 1.0),     // Intensified: saturates
+            band(3, Level::Oxytocin, 0.85, 0.95),    // Kindness lands
+            band(3, Level::Cortisol, 0.8, 0.9),      // ...but only chips at stress
+            band(4, Level::Cortisol, 0.0, 0.01),     // Calm recovers it
+            band(4, Level::Oxytocin, 0.86, 0.88),    // Bonding fades slowly
+        ]);
+    }
+
+    #[test]
+    fn the_lexicon_tells_unknown_words_from_insults_and_comfort() {
+        replay(Neurotransmitters::new(), &[
+            Step::Say("zanahoria"),
+            Step::Say("eres un idiota"),
+            Step::Say("buenas noches, no hay peligro"),
+        ], &[
+            band(1, Level::Cortisol, 0.0, 0.0),      // An unknown neutral word doesn't stress...
+            band(1, Level::Dopamine, 0.505, 0.515),  // ...it only arouses a little (novelty)
+            band(2, Level::Cortisol, 0.17, 0.19),    // A configured insult does
+            band(3, Level::Oxytocin, 0.57, 0.59),    // Phrase match ("buenas noches")
+            band(3, Level::Cortisol, 0.47, 0.49),    // "no" + "peligro" + mixed-feelings dissonance ("noches" isn't "no")
+        ]);
+    }
+
+    #[test]
+    fn bonding_accumulates_turn_by_turn_and_fades_alone() {
+        let lonely = Neurotransmitters { oxytocin: 0.0, ..Neurotransmitters::new() };
+        replay(lonely, &[
+            Step::Address("hola Aleph"), Step::Reply, calm(10.0),
+            Step::Address("qué tal"), Step::Reply, calm(10.0),
+            Step::Address("me gusta hablar contigo"), Step::Reply, calm(120.0),
+            Step::Address("¿sigues ahí?"),
+            calm(600.0),
+        ], &[
+            band(1, Level::Oxytocin, 0.03, 0.03),    // Familiar voice, calm: first trace
+            band(4, Level::Oxytocin, 0.11, 0.12),    // + a reply answered within the window
+            band(7, Level::Oxytocin, 0.19, 0.205),   // Bonding accumulates turn by turn
+            band(10, Level::Oxytocin, 0.15, 0.165),  // Came back too late: no exchange bonus, only the voice
+            band(11, Level::Oxytocin, 0.0, 0.03),    // Ten minutes alone and it fades
+        ]);
+    }
+
+    #[test]
+    fn a_noise_burst_stresses_and_silence_heals() {
+        replay(Neurotransmitters::new(), &[calm(2.0), Step::Hear("noise 0.8 1s"), Step::Hear("silence 30s")], &[
+            band(1, Level::Cortisol, 0.0, 0.01),
+            band(2, Level::Cortisol, 0.5, 1.0),      // A loud burst stresses within the second
+            band(3, Level::Cortisol, 0.0, 0.01),     // Silence heals
+        ]);
+    }
+
+    #[test]
+    fn a_quiet_tone_is_not_a_threat() {
+        replay(Neurotransmitters::new(), &[Step::Hear("tone 440hz 0.2 5s")], &[
+            band(1, Level::Cortisol, 0.0, 0.01),     // Conversation-level sound is not a threat
+        ]);
+    }
+
+    #[test]
+    fn serotonin_buffers_brief_stress() {
+        let stressed = Neurotransmitters { cortisol: 0.9, serotonin: 0.8, ..Neurotransmitters::new() };
+        replay(stressed, &[calm(2.0), calm(10.0)], &[
+            band(1, Level::Cortisol, 0.25, 0.31),    // Mood buffer + calm recovery
+            band(2, Level::Cortisol, 0.0, 0.01),
+            band(2, Level::Serotonin, 0.78, 0.8),    // Brief stress barely costs mood
+        ]);
+    }
+
+    #[test]
+    fn oxytocin_fades_from_full_in_about_28_minutes() {
+        let mut sim = Sim::new(Neurotransmitters { oxytocin: 1.0, ..Neurotransmitters::new() });
+        sim.step(calm(27.0 * 60.0));
+        let left = sim.chem.oxytocin;
+        sim.step(calm(60.0));
+        assert!((0.02..=0.04).contains(&left) && sim.chem.oxytocin == 0.0,
+            "oxytocin {:.4} after 27 min, {:.4} after 28 (0.00001 per 60Hz tick)", left, sim.chem.oxytocin);
+    }
+
+    #[test]
+    fn chronic_stress_drains_serotonin_to_zero_and_not_below() {
+        let mut sim = Sim::new(Neurotransmitters { cortisol: 1.0, serotonin: 0.01, ..Neurotransmitters::new() });
+        sim.step(Step::Tick { secs: 60.0, entropy: 0.9, cpu: 30.0, dreaming: false, shock: 0.0 });
+        assert_eq!(sim.chem.serotonin, 0.0);
+    }
+
+    #[test]
+    fn random_inputs_keep_every_level_in_range() {
+        const WORDS: [&str; 8] = ["odio", "amor", "muy", "wow", "cansado", "paz", "guerra!!", "hola"];
+        let mut rng = StdRng::seed_from_u64(FUZZ_SEED);
+        for case in 0..FUZZ_CASES {
+            let mut sim = Sim::new(Neurotransmitters::new());
+            for _ in 0..200 {
+                let step = match rng.random_range(0..8) {
+                    0 => Step::Hardware { cpu: rng.random_range(0.0..100.0), ram: rng.random() },
+                    1 => Step::Say(WORDS[rng.random_range(0..WORDS.len())]),
+                    2 => Step::Address(WORDS[rng.random_range(0..WORDS.len())]),
+                    3 => Step::Reply,
+                    _ => Step::Tick {
+                        secs: rng.random_range(0.0..2.0),
+                        entropy: rng.random(),
+                        cpu: rng.random_range(0.0..100.0),
+                        dreaming: rng.random_bool(0.3),
+                        shock: rng.random_range(0.0..0.3),
+                    },
+                };
+                sim.step(step);
+            }
+            for level in Level::ALL {
+                let value = level.read(&sim.chem);
+                assert!((0.0..=1.0).contains(&value), "walk {} (seed {}): {:?} = {}", case, FUZZ_SEED, level, value);
+            }
+        }
+    }
+}
@@ -0,0 +1,275 @@
+// src/core/complexity.rs
+// THE CALIPERS: Multi-scale complexity of the reservoir
+//
+// The reservoir's entropy is a 10-bin histogram of one tick's activations.
+// It can't tell rich dynamics from noise: white noise fills every bin and
+// scores maximal, and a traveling wave can score lower than noise. Three
+// cheap measures over a short history of PROBES evenly spaced neurons (one
+// row per tick, WINDOW rows) look at time as well:
+//
+//   temporal       mean over probes of the entropy of each neuron's values
+//                  across the window (0 = frozen, 1 = visits every level)
+//   participation  effective dimensionality: (tr C)^2 / tr(C^2) of the probes'
+//                  covariance, over the number of probes (a wave ~2 dims,
+//                  noise ~all of them)
+//   lempel_ziv     LZ76 phrase count of the binarized window (each probe above
+//                  its own mean), over n / log2(n) (periodic -> 0, random -> ~1)
+//
+// composite = mean of 4p(1-p) and 4l(1-l): high only between order and noise,
+// both in dimensionality and in compressibility. The legacy histogram stays
+// the couplings' input (chemistry, temperature, the homeostat) and is shown
+// as `entropy_legacy`; [complexity] drive_couplings hands them the composite
+// instead (its scale differs: retune the genome's entropy setpoint with it).
+
+use serde::Serialize;
+use std::collections::VecDeque;
+
+use crate::core::config::ComplexityConfig;
+
+/// Rows a window needs before the measures mean anything.
+const MIN_ROWS: usize = 16;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct ComplexityMetrics {
+    pub temporal: f32,
+    pub participation: f32,
+    pub lempel_ziv: f32,
+    pub composite: f32,
+}
+
+pub struct Complexity {
+    config: ComplexityConfig,
+    probes: Vec<usize>,
+    window: VecDeque<Vec<f32>>,
+    since: u32,
+    metrics: ComplexityMetrics,
+}
+
+impl Complexity {
+    pub fn new(config: ComplexityConfig) -> Self {
+        Self { config, probes: Vec::new(), window: VecDeque::new(), since: 0, metrics: ComplexityMetrics::default() }
+    }
+
+    /// One tick of activity (0-1 per neuron). The measures are recomputed every
+    /// `every_ticks` ticks and held in between.
+    pub fn observe(&mut self, activity: &[f32]) -> ComplexityMetrics {
+        // Probes are picked once (growth only adds neurons); a brain that shrank is probed again
+        if self.probes.is_empty() || self.probes.iter().any(|&p| p >= activity.len()) {
+            let n = activity.len();
+            let k = self.config.probes.clamp(1, n.max(1));
+            self.probes = (0..k).map(|i| i * n / k).filter(|&p| p < n).collect();
+            self.window.clear();
+        }
+        self.window.push_back(self.probes.iter().map(|&p| activity[p]).collect());
+        while self.window.len() > self.config.window.max(MIN_ROWS) {
+            self.window.pop_front();
+        }
+        self.since += 1;
+        if self.since >= self.config.every_ticks.max(1) && self.window.len() >= MIN_ROWS {
+            self.since = 0;
+            self.metrics = measure(self.window.make_contiguous());
+        }
+        self.metrics
+    }
+
+    pub fn metrics(&self) -> ComplexityMetrics {
+        self.metrics
+    }
+}
+
+/// All the measures of one window (rows = ticks, columns = probes, values 0-1).
+pub fn measure(rows: &[Vec<f32>]) -> ComplexityMetrics {
+    let temporal = temporal_entropy(rows);
+    let participation = participation_ratio(rows);
+    let lempel_ziv = lempel_ziv(rows);
+    let edge = |x: f32| 4.0 * x.clamp(0.0, 1.0) * (1.0 - x.clamp(0.0, 1.0));
+    ComplexityMetrics { temporal, participation, lempel_ziv, composite: (edge(participation) + edge(lempel_ziv)) / 2.0 }
+}
+
+fn columns(rows: &[Vec<f32>]) -> usize {
+    rows.iter().map(Vec::len).min().unwrap_or(0)
+}
+
+fn column_means(rows: &[Vec<f32>], k: usize) -> Vec<f32> {
+    (0..k).map(|j| rows.iter().map(|r| r[j]).sum::<f32>() / rows.len() as f32).collect()
+}
+
+/// Mean per-probe entropy over time (10 bins, normalized by log2(10)).
+pub fn temporal_entropy(rows: &[Vec<f32>]) -> f32 {
+    let k = columns(rows);
+    if k == 0 || rows.is_empty() {
+        return 0.0;
+    }
+    let total = rows.len() as f32;
+    let per_probe = (0..k).map(|j| {
+        let mut counts = [0usize; 10];
+        for row in rows {
+            counts[((row[j].clamp(0.0, 1.0) * 9.99).floor() as usize).min(9)] += 1;
+        }
+        counts.iter().filter(|&&c| c > 0).map(|&c| {
+            let p = c as f32 / total;
+            -p * p.log2()
+        }).sum::<f32>() / 10f32.log2()
+    });
+    per_probe.sum::<f32>() / k as f32
+}
+
+/// Effective dimensionality over the number of probes: (tr C)^2 / ||C||_F^2 / k.
+pub fn participation_ratio(rows: &[Vec<f32>]) -> f32 {
+    let k = columns(rows);
+    if k == 0 || rows.len() < 2 {
+        return 0.0;
+    }
+    let means = column_means(rows, k);
+    let mut trace = 0.0f64;
+    let mut frobenius = 0.0f64;
+    for i in 0..k {
+        for j in i..k {
+            let c = rows.iter().map(|r| ((r[i] - means[i]) * (r[j] - means[j])) as f64).sum::<f64>() / (rows.len() - 1) as f64;
+            if i == j {
+                trace += c;
+                frobenius += c * c;
+            } else {
+                frobenius += 2.0 * c * c;
+            }
+        }
+    }
+    if frobenius < 1e-12 {
+        return 0.0;
+    }
+    (trace * trace / frobenius / k as f64) as f32
+}
+
+/// LZ76 complexity of the window binarized against each probe's mean (time-major),
+/// normalized by n / log2(n).
+pub fn lempel_ziv(rows: &[Vec<f32>]) -> f32 {
+    let k = columns(rows);
+    if k == 0 {
+        return 0.0;
+    }
+    let means = column_means(rows, k);
+    let bits: Vec<bool> = rows.iter().flat_map(|r| (0..k).map(|j| r[j] > means[j]).collect::<Vec<_>>()).collect();
+    let n = bits.len();
+    if n < 2 {
+        return 0.0;
+    }
+    lz76(&bits) as f32 / (n as f32 / (n as f32).log2())
+}
+
+/// Number of phrases of the Lempel-Ziv (1976) parsing (Kaspar & Schuster).
+pub fn lz76(s: &[bool]) -> usize {
+    let n = s.len();
+    if n < 2 {
+        return n;
+    }
+    let (mut c, mut l, mut i, mut k, mut k_max) = (1, 1, 0, 1, 1);
+    loop {
+        if s[i + k - 1] == s[l + k - 1] {
+            k += 1;
+            if l + k > n {
+                c += 1;
+                break;
+            }
+        } else {
+            k_max = k_max.max(k);
+            i += 1;
+            if i == l {
+                c += 1;
+                l += k_max;
+                if l + 1 > n {
+                    break;
+                }
+                i = 0;
+                k = 1;
+                k_max = 1;
+            } else {
+                k = 1;
+            }
+        }
+    }
+    c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::f32::consts::TAU;
+
+    const PROBES: usize = 16;
+    const WINDOW: usize = 128;
+
+    /// Frozen, noise, a traveling wave and a structured signal (four incommensurate
+    /// oscillators mixed across the probes, a little noise).
+    fn windows(rng: &mut StdRng) -> [Vec<Vec<f32>>; 4] {
+        let (k, w) = (PROBES, WINDOW);
+        let frozen: Vec<Vec<f32>> = (0..w).map(|_| vec![0.5; k]).collect();
+        let noise: Vec<Vec<f32>> = (0..w).map(|_| (0..k).map(|_| rng.random::<f32>()).collect()).collect();
+        let wave: Vec<Vec<f32>> = (0..w).map(|t| (0..k).map(|j| 0.5 + 0.4 * (TAU * (t as f32 / 16.0 - j as f32 / k as f32)).sin()).collect()).collect();
+        let freqs = [0.031f32, 0.057, 0.083, 0.0137];
+        let mix: Vec<Vec<f32>> = (0..k).map(|_| freqs.iter().map(|_| rng.random_range(-1.5f32..1.5)).collect()).collect();
+        let structured: Vec<Vec<f32>> = (0..w).map(|t| (0..k).map(|j| {
+            let latent: f32 = freqs.iter().enumerate().map(|(m, f)| mix[j][m] * (TAU * f * t as f32 + m as f32).sin()).sum();
+            (0.5 + 0.1 * latent + rng.random_range(-0.02f32..0.02)).clamp(0.0, 1.0)
+        }).collect()).collect();
+        [frozen, noise, wave, structured]
+    }
+
+    #[test]
+    fn each_measure_reads_the_synthetic_windows_as_expected() {
+        let [frozen, noise, wave, structured] = windows(&mut StdRng::seed_from_u64(1));
+        let [f, n, v, s] = [&frozen, &noise, &wave, &structured].map(|rows| measure(rows));
+        assert!(f.temporal <= 0.05 && n.temporal >= 0.9 && v.temporal >= 0.8,
+            "temporal: frozen {:.3}, noise {:.3}, wave {:.3}", f.temporal, n.temporal, v.temporal);
+        assert!(f.participation == 0.0 && n.participation >= 0.7 && v.participation <= 0.2 && (0.1..0.6).contains(&s.participation),
+            "participation: frozen {:.3}, noise {:.3}, wave {:.3}, structured {:.3}", f.participation, n.participation, v.participation, s.participation);
+        assert!(f.lempel_ziv <= 0.05 && n.lempel_ziv >= 0.9 && v.lempel_ziv <= 0.25 && (0.35..0.9).contains(&s.lempel_ziv),
+            "lempel_ziv: frozen {:.3}, noise {:.3}, wave {:.3}, structured {:.3}", f.lempel_ziv, n.lempel_ziv, v.lempel_ziv, s.lempel_ziv);
+    }
+
+    #[test]
+    fn the_composite_ranks_structure_over_wave_over_noise_where_a_histogram_prefers_noise() {
+        let [frozen, noise, wave, structured] = windows(&mut StdRng::seed_from_u64(1));
+        let [f, n, v, s] = [&frozen, &noise, &wave, &structured].map(|rows| measure(rows));
+        assert!(s.composite > v.composite && v.composite > n.composite && n.composite > f.composite && s.composite >= 0.55,
+            "composite: structured {:.3}, wave {:.3}, noise {:.3}, frozen {:.3}", s.composite, v.composite, n.composite, f.composite);
+        // The legacy measure, a histogram of one tick
+        let histogram = |row: &[f32]| {
+            let mut counts = [0usize; 10];
+            row.iter().for_each(|&x| counts[((x.clamp(0.0, 1.0) * 9.99).floor() as usize).min(9)] += 1);
+            counts.iter().filter(|&&c| c > 0).map(|&c| { let p = c as f32 / row.len() as f32; -p * p.log2() }).sum::<f32>() / 3.32
+        };
+        let legacy = |rows: &[Vec<f32>]| rows.iter().map(|r| histogram(r)).sum::<f32>() / rows.len() as f32;
+        assert!(legacy(&noise) > legacy(&structured), "legacy: noise {:.3}, structured {:.3}", legacy(&noise), legacy(&structured));
+    }
+
+    #[test]
+    fn lz76_counts_few_phrases_in_a_period_and_many_in_noise() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let periodic: Vec<bool> = (0..256).map(|i| i % 4 < 2).collect();
+        let random: Vec<bool> = (0..256).map(|_| rng.random()).collect();
+        assert_eq!(lz76(&[]), 0);
+        assert!(lz76(&periodic) <= 6, "periodic {}", lz76(&periodic));
+        assert!(lz76(&random) >= 25, "random {}", lz76(&random));
+    }
+
+    #[test]
+    fn the_tracker_fills_its_window_and_keeps_its_probes_through_growth() {
+        let [_, noise, _, _] = windows(&mut StdRng::seed_from_u64(1));
+        let config = ComplexityConfig { probes: PROBES, window: WINDOW, every_ticks: 1, ..Default::default() };
+        let mut tracker = Complexity::new(config);
+        // Nothing until the window has MIN_ROWS
+        assert_eq!(tracker.observe(&noise[0]), ComplexityMetrics::default());
+        for row in &noise[1..] {
+            tracker.observe(row);
+        }
+        let probes = tracker.probes.clone();
+        let mut grown = noise[0].clone();
+        grown.extend([0.5; 8]);
+        let after = tracker.observe(&grown);
+        assert!(after.lempel_ziv >= 0.8, "after {:?}", after);
+        assert_eq!(tracker.probes, probes);
+        assert_eq!(tracker.window.len(), WINDOW);
+    }
+}
@@ -0,0 +1,1716 @@
+// src/core/config.rs
+// RUNTIME CONFIGURATION (aleph.toml)
+//
+// Every knob is optional. A missing file, a missing section or a missing key
+// falls back to the defaults below, so a fresh checkout behaves exactly as if
+// no configuration existed.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+use crate::core::daemon_state::{NIGHT_END_HOUR, NIGHT_START_HOUR};
+use crate::core::profile::{self, Profile};
+use crate::core::reservoir::{EchoTarget, ReceptiveFieldMap};
+use crate::core::thought::MindVoice;
+
+const CONFIG_FILE: &str = "aleph.toml";
+const MODELS_FILE: &str = "models.toml";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlephConfig {
+    pub stimulus: StimulusConfig,
+    pub cortex: CortexConfig,
+    pub timeline: TimelineConfig,
+    pub idle: IdleConfig,
+    pub metabolism: MetabolismConfig,
+    pub autosave: AutosaveConfig,
+    pub ears: EarsConfig,
+    pub people: PeopleConfig,
+    pub attention: AttentionConfig,
+    pub exploration: ExplorationConfig,
+    pub homeostat: HomeostatConfig,
+    pub voices: VoicesConfig,
+    pub replay: ReplayConfig,
+    pub binding: BindingConfig,
+    pub saccade: SaccadeConfig,
+    pub novelty: NoveltyConfig,
+    pub consolidation: ConsolidationConfig,
+    pub memory_gate: MemoryGateConfig,
+    pub retrieval: RetrievalConfig,
+    pub watchdog: WatchdogConfig,
+    pub brain: BrainConfig,
+    pub startle: StartleConfig,
+    pub health: HealthConfig,
+    pub runtime: RuntimeConfig,
+    pub actuators: ActuatorsConfig,
+    pub notifications: NotificationsConfig,
+    pub consciousness: ConsciousnessConfig,
+    pub cortexd: CortexdConfig,
+    pub stats: StatsConfig,
+    pub admin: AdminConfig,
+    pub decisions: DecisionsConfig,
+    pub interlocutor: InterlocutorConfig,
+    pub pressure: PressureConfig,
+    pub selftest: SelfTestConfig,
+    pub complexity: ComplexityConfig,
+    pub repertoire: RepertoireConfig,
+    pub checkin: CheckInConfig,
+    pub sleep_talk: SleepTalkConfig,
+    pub feeding: FeedingConfig,
+    pub latency: LatencyConfig,
+    pub thread: ThreadConfig,
+    pub volition: VolitionConfig,
+    pub standby: StandbyConfig,
+    pub ledger: LedgerConfig,
+}
+
+/// External stimulus membrane (HTTP /stimulus, WebSocket frames, IPC packets).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StimulusConfig {
+    /// Sustained stimuli per second allowed per client.
+    pub rate_per_sec: f32,
+    /// Burst capacity of each client's token bucket.
+    pub burst: f32,
+    /// Maximum characters kept from a single stimulus (longer text is truncated).
+    pub max_chars: usize,
+}
+
+impl Default for StimulusConfig {
+    fn default() -> Self {
+        Self {
+            rate_per_sec: 0.5, // One stimulus every 2s sustained
+            burst: 3.0,        // Short bursts (a quick correction) still pass
+            max_chars: 500,
+        }
+    }
+}
+
+/// Planet (LLM) input queue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CortexConfig {
+    /// Seconds a queued input may wait before it is too old to answer.
+    pub staleness_secs: f32,
+    /// The model's context window in tokens: prompt + generation must fit in it.
+    pub context_tokens: usize,
+    /// Also ship the raw vocabulary logits with every echo (research; ~128KB per output).
+    pub full_echo: bool,
+    /// One throwaway Listen and one tiny Think before serving (core/selftest.rs).
+    pub warmup: bool,
+    pub field: FieldConfig,
+    pub bio: BioBudgetConfig,
+}
+
+/// Semantic Field blending (docs/ vs. consolidated memories), rebuilt after each sleep.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FieldConfig {
+    pub docs_weight: f32,
+    pub memory_weight: f32,
+}
+
+impl Default for FieldConfig {
+    fn default() -> Self {
+        Self {
+            docs_weight: 0.7,
+            memory_weight: 0.3,
+        }
+    }
+}
+
+/// Token budget for the body's self-report (bio_context) in every prompt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BioBudgetConfig {
+    /// Tokens the bio may take (0 = no limit).
+    pub max_tokens: usize,
+    /// Fields dropped first when over budget, by the start of their header line.
+    pub drop_order: Vec<String>,
+    /// Relative drift under which a number still counts as unchanged (bio not re-sent).
+    pub dedup_tolerance: f32,
+}
+
+impl Default for BioBudgetConfig {
+    fn default() -> Self {
+        Self {
+            max_tokens: 48,
+            drop_order: vec![
+                "[GENOME_TRAITS]".to_string(),
+                "[FOCUS_VECTOR]".to_string(),
+                "[SYSTEM_STATE]".to_string(),
+                "Known person present".to_string(),
+            ],
+            dedup_tolerance: 0.1,
+        }
+    }
+}
+
+impl Default for CortexConfig {
+    fn default() -> Self {
+        Self {
+            staleness_secs: 15.0,
+            context_tokens: 2048, // TinyLlama
+            full_echo: false,
+            warmup: true,
+            field: FieldConfig::default(),
+            bio: BioBudgetConfig::default(),
+        }
+    }
+}
+
+/// Thought timeline: in-memory history for the dashboard + daily NDJSON session log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimelineConfig {
+    /// Thoughts kept in memory for `GET /timeline`.
+    pub ring_capacity: usize,
+    /// Directory for the daily `thoughts-YYYY-MM-DD.ndjson` files.
+    pub log_dir: String,
+    /// Seconds between fsyncs of the session log (the recorder thread pays it, never the loop).
+    pub fsync_secs: u64,
+    /// Daily files kept before the oldest is deleted.
+    pub keep_days: usize,
+}
+
+impl Default for TimelineConfig {
+    fn default() -> Self {
+        Self {
+            ring_capacity: 5000,
+            log_dir: "logs/timeline".to_string(),
+            fsync_secs: 5,
+            keep_days: 14,
+        }
+    }
+}
+
+/// Deep idle: asleep, silent and unobserved -> slow heartbeat, no scan pulses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdleConfig {
+    pub enabled: bool,
+    /// Adenosine above which ALEPH is tired enough to idle.
+    pub adenosine_threshold: f32,
+    /// Audio RMS considered silence.
+    pub noise_floor_rms: f32,
+    /// Seconds of continuous quiet before entering deep idle.
+    pub quiet_secs: f32,
+    /// Heartbeat while in deep idle (Hz).
+    pub tick_hz: f32,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            adenosine_threshold: 0.7,
+            noise_floor_rms: 0.01,
+            quiet_secs: 30.0,
+            tick_hz: 4.0,
+        }
+    }
+}
+
+/// Energy accounting: what each second of work costs in adenosine and glucose.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetabolismConfig {
+    /// Planet time per generation that is free (short thoughts don't tire).
+    pub cortex_free_ms: u64,
+    /// Adenosine per second of billable Planet inference.
+    pub cortex_adenosine_per_sec: f32,
+    /// Adenosine per second of Whisper transcription.
+    pub whisper_adenosine_per_sec: f32,
+    /// Adenosine per second spent ticking the reservoir.
+    pub tick_adenosine_per_sec: f32,
+    /// Adenosine per neuron born.
+    pub growth_adenosine: f32,
+    /// Glucose burnt per second of any work (cortex + whisper + tick).
+    pub glucose_per_work_sec: f32,
+    /// Glucose per neuron born.
+    pub glucose_per_growth: f32,
+    /// Glucose restored per second (doubled while dreaming).
+    pub glucose_recovery_per_sec: f32,
+}
+
+impl Default for MetabolismConfig {
+    fn default() -> Self {
+        Self {
+            cortex_free_ms: 500,             // Same "free" threshold as the old latency feedback
+            cortex_adenosine_per_sec: 0.05,  // 2s latency = +0.075 adenosine (unchanged)
+            whisper_adenosine_per_sec: 0.03,
+            tick_adenosine_per_sec: 0.01,
+            growth_adenosine: 0.002,
+            glucose_per_work_sec: 0.02,
+            glucose_per_growth: 0.005,
+            glucose_recovery_per_sec: 0.004,
+        }
+    }
+}
+
+/// Periodic crash-safe checkpoints of brain + chemistry (memories are asked to save too).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutosaveConfig {
+    pub enabled: bool,
+    pub interval_mins: f32,
+    /// Checkpoints kept on disk (oldest are deleted).
+    pub keep: usize,
+    pub dir: String,
+}
+
+impl Default for AutosaveConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_mins: 10.0,
+            keep: 3,
+            dir: "checkpoints".to_string(),
+        }
+    }
+}
+
+/// Whisper worker: model, language and what to do when it falls behind.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EarsConfig {
+    /// tiny / base / small (resolved to `<models_dir>/ggml-<size>.bin`).
+    pub model_size: String,
+    /// Explicit model file; overrides `model_size`.
+    pub model_path: Option<String>,
+    pub models_dir: String,
+    /// Fetch the selected model on first run if it is missing.
+    pub auto_download: bool,
+    /// ISO code ("es", "en", ...) or "auto" to detect it per utterance.
+    pub language: String,
+    /// Utterances allowed to wait for Whisper; older ones are dropped beyond this.
+    pub max_queue: usize,
+    /// Long recordings are cut into slices of this length (seconds).
+    pub chunk_secs: f32,
+    /// Boot in Do-Not-Disturb (no transcription until SYS:DND_OFF).
+    pub dnd_default: bool,
+    /// Spectrum window in samples (rounded up to a power of two).
+    pub fft_size: usize,
+    /// Samples between spectrum frames (fft_size/2 = 50% overlap).
+    pub fft_hop: usize,
+    /// Ticks between the sensory echoes of consecutive sentences of one transcript.
+    pub sentence_offset_ticks: u64,
+    /// Transcribe the built-in half-second sample once before the first utterance (core/selftest.rs).
+    pub warmup: bool,
+    /// Automatic gain control ([ears.agc]).
+    pub agc: AgcConfig,
+    /// Whisper hallucination watchdog ([ears.sanity]).
+    pub sanity: TranscriptSanityConfig,
+}
+
+impl Default for EarsConfig {
+    fn default() -> Self {
+        Self {
+            model_size: "base".to_string(),
+            model_path: None,
+            models_dir: "models".to_string(),
+            auto_download: false,
+            language: "es".to_string(),
+            max_queue: 3,
+            chunk_secs: 15.0,
+            dnd_default: false,
+            fft_size: 1024,
+            fft_hop: 512,
+            sentence_offset_ticks: 6, // ~100ms at 60Hz
+            warmup: true,
+            agc: AgcConfig::default(),
+            sanity: TranscriptSanityConfig::default(),
+        }
+    }
+}
+
+/// Transcript sanity scoring in the Whisper worker (see senses/sanity.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TranscriptSanityConfig {
+    /// Transcripts scoring below this are logged, never heard (not forwarded, not remembered).
+    pub min_score: f32,
+    /// Recent transcripts compared verbatim (after lowercasing and dropping punctuation).
+    pub history: usize,
+    /// Taken off per earlier appearance in that history.
+    pub repeat_penalty: f32,
+    /// Plausible characters per second of audio; outside it (the slow bound only for
+    /// audio longer than slow_min_secs) costs rate_penalty.
+    pub min_chars_per_sec: f32,
+    pub max_chars_per_sec: f32,
+    pub slow_min_secs: f32,
+    pub rate_penalty: f32,
+    /// Peak RMS a voice reaches; quieter source audio costs quiet_penalty.
+    pub voice_floor_rms: f32,
+    pub quiet_penalty: f32,
+    /// Phrases that are never speech, per language ("*" = every language). Case-insensitive.
+    pub blacklist: HashMap<String, Vec<String>>,
+}
+
+impl Default for TranscriptSanityConfig {
+    fn default() -> Self {
+        let phrases = |list: &[&str]| list.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        Self {
+            min_score: 0.5,
+            history: 8,
+            repeat_penalty: 0.3,
+            min_chars_per_sec: 1.0,
+            max_chars_per_sec: 30.0,
+            slow_min_secs: 5.0,
+            rate_penalty: 0.6,
+            voice_floor_rms: 0.08,
+            quiet_penalty: 0.4,
+            blacklist: [
+                ("*", phrases(&["[BLANK_AUDIO]", "Amara.org", "...", "??"])),
+                ("es", phrases(&["Subtítulos", "Gracias por ver el video", "Suscríbete al canal"])),
+                ("en", phrases(&["Thanks for watching", "Subtitles by"])),
+            ].into_iter().map(|(language, list)| (language.to_string(), list)).collect(),
+        }
+    }
+}
+
+/// Input level normalization before the cochlea (see senses/agc.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgcConfig {
+    /// Normalize browser audio (WebSocket PCM).
+    pub websocket: bool,
+    /// Normalize the local microphone too (CPAL levels are what the thresholds were tuned on).
+    pub mic: bool,
+    /// RMS the input is brought toward.
+    pub target_rms: f32,
+    /// Chunks quieter than this don't move the level estimate (room noise isn't a voice).
+    pub noise_floor: f32,
+    /// Time constant of the level estimate (seconds).
+    pub window_secs: f32,
+    pub min_gain: f32,
+    pub max_gain: f32,
+    /// Peak limiter: no sample leaves the AGC louder than this.
+    pub ceiling: f32,
+    /// Browser chunks longer than this (samples) are refused whole.
+    pub max_chunk_samples: usize,
+}
+
+impl Default for AgcConfig {
+    fn default() -> Self {
+        Self {
+            websocket: true,
+            mic: false,
+            target_rms: 0.08,
+            noise_floor: 0.003,
+            window_secs: 3.0,
+            min_gain: 0.1,
+            max_gain: 20.0,
+            ceiling: 0.95,
+            max_chunk_samples: 48_000, // ~1s at 48kHz (browsers send ~4096)
+        }
+    }
+}
+
+/// Name learning (self-introductions heard by the ears).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PeopleConfig {
+    pub path: String,
+    /// Phrases that precede a name ("me llamo Marta"). Matched case-insensitively.
+    pub intro_patterns: Vec<String>,
+    /// A person counts as present for this long after last being heard.
+    pub presence_secs: u64,
+}
+
+impl Default for PeopleConfig {
+    fn default() -> Self {
+        Self {
+            path: "people.json".to_string(),
+            intro_patterns: ["me llamo", "mi nombre es", "soy", "my name is", "i'm", "i am", "call me"]
+                .iter().map(|s| s.to_string()).collect(),
+            presence_secs: 300,
+        }
+    }
+}
+
+/// Cortisol-driven attention narrowing on the input side (sensory vector + audio embedding).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AttentionConfig {
+    pub enabled: bool,
+    /// Cortisol below this leaves attention fully open.
+    pub cortisol_threshold: f32,
+    /// Softmax sharpening exponent at cortisol = 1.0.
+    pub max_sharpness: f32,
+    /// How much dopamine (0..1) cancels the sharpening (1.0 = full dopamine reopens everything).
+    pub dopamine_broadening: f32,
+    /// Weight of threat co-occurrence vs. plain variance in a channel's salience.
+    pub threat_weight: f32,
+}
+
+impl Default for AttentionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cortisol_threshold: 0.4,
+            max_sharpness: 12.0,
+            dopamine_broadening: 0.8,
+            threat_weight: 2.0,
+        }
+    }
+}
+
+/// One exploration action: how often it is picked and what it costs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExplorationAction {
+    /// Relative probability among the available actions (0 disables it).
+    pub weight: f32,
+    /// Effort billed to the metabolism, in seconds of Planet-equivalent work.
+    pub cost_secs: f32,
+}
+
+impl Default for ExplorationAction {
+    fn default() -> Self {
+        Self { weight: 1.0, cost_secs: 0.5 }
+    }
+}
+
+/// Dream replay: in Deep sleep, strong memories re-inject the reservoir activity
+/// they were encoded with, and hebbian learning runs over the reactivated state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplayConfig {
+    pub enabled: bool,
+    /// Seconds between two replays (Deep stage only).
+    pub every_secs: f32,
+    /// How far the state is pulled toward the stored pattern (0..1).
+    pub gain: f32,
+    /// Dopamine-equivalent handed to hebbian_update for the replayed state.
+    pub reinforcement: f32,
+    /// A replay picks among this many strongest (highest-entropy) memories.
+    pub candidates: usize,
+}
+
+impl Default for ReplayConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            every_secs: 3.0,
+            gain: 0.8,
+            reinforcement: 0.5,
+            candidates: 10,
+        }
+    }
+}
+
+/// Cross-modal binding: a sound and a visual change close in time wire their
+/// receptive fields together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BindingConfig {
+    pub enabled: bool,
+    /// Maximum gap between the sound and the visual change (and how long a trace lives).
+    pub window_secs: f32,
+    /// No binding below this dopamine (nothing worth remembering together).
+    pub dopamine_floor: f32,
+    /// Weight added to each cross-field synapse by a fully coincident pair.
+    pub rate: f32,
+    /// Neurons per modality taken from each trace (the most driven ones).
+    pub neurons: usize,
+    /// Loudest coarse band (bass/mids/highs) that counts as a sound.
+    pub sound_floor: f32,
+    /// Mean pooled motion that counts as a visual change.
+    pub motion_floor: f32,
+}
+
+impl Default for BindingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_secs: 0.5,
+            dopamine_floor: 0.3,
+            rate: 0.02,
+            neurons: 16,
+            sound_floor: 0.1,
+            motion_floor: 0.02,
+        }
+    }
+}
+
+/// Saccades: when several senses compete, fixate one (boosted) and move on.
+/// `enabled = false` keeps pure blending.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaccadeConfig {
+    pub enabled: bool,
+    /// Injection gain of the fixated pathway.
+    pub boost: f32,
+    /// Injection gain of the pathways competing with it.
+    pub attenuation: f32,
+    /// Mean fixation at dopamine 0.5 (x (1.5 - dopamine): restless when dopamine is high).
+    pub dwell_secs: f32,
+    /// No fixation is shorter than this.
+    pub min_dwell_secs: f32,
+    /// A fixated pathway bringing less novelty than this is left early.
+    pub novelty_floor: f32,
+}
+
+impl Default for SaccadeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            boost: 2.5,
+            attenuation: 0.5,
+            dwell_secs: 2.0,
+            min_dwell_secs: 0.5,
+            novelty_floor: 0.02,
+        }
+    }
+}
+
+/// Hippocampal novelty model (persisted in novelty.json).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NoveltyConfig {
+    /// How fast the similarity distribution follows what is heard (per stimulus):
+    /// higher = novelty decays into familiarity faster.
+    pub adaptation_rate: f32,
+    /// Novelty divisor per earlier hearing of the same text (1 + rate * times).
+    pub familiarity_rate: f32,
+    /// Starting point of a newborn model: typical best similarity and its spread
+    /// (so a new deployment doesn't call everything new).
+    pub prior_similarity: f32,
+    pub prior_spread: f32,
+}
+
+impl Default for NoveltyConfig {
+    fn default() -> Self {
+        Self {
+            adaptation_rate: 0.02, // ~50 stimuli of memory
+            familiarity_rate: 0.5,
+            prior_similarity: 0.3, // MiniLM: unrelated sentences sit around 0.2-0.4
+            prior_spread: 0.1,
+        }
+    }
+}
+
+/// Memory consolidation rounds (volatile -> long term), scheduled by the hippocampus.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsolidationConfig {
+    pub enabled: bool,
+    /// Seconds without memory work before an idle round may run.
+    pub idle_secs: f32,
+    /// Volatile memories needed for an idle round (Deep sleep consolidates any backlog).
+    pub volatile_floor: usize,
+    /// Volatile memories examined per round (oldest first).
+    pub batch: usize,
+}
+
+impl Default for ConsolidationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            idle_secs: 120.0,
+            volatile_floor: 50,
+            batch: 64, // A few ms of work: never stalls a stimulus behind it
+        }
+    }
+}
+
+/// Storage gate for self-generated memories (heard text is never filtered).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MemoryGateConfig {
+    pub enabled: bool,
+    /// Shortest text worth remembering (characters, trimmed).
+    pub min_chars: usize,
+    /// Share of non-space characters that must be letters.
+    pub min_alpha_ratio: f32,
+    /// Self-memories remembered for the duplicate check.
+    pub recent: usize,
+    /// Minimum resonance (top-token probability) of a thought that carries one.
+    pub min_resonance: f32,
+}
+
+impl Default for MemoryGateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_chars: 6, // "scan", "!", half-words
+            min_alpha_ratio: 0.6,
+            recent: 20,
+            min_resonance: 0.15, // The Semantic Field's own resonance threshold
+        }
+    }
+}
+
+/// Memory retrieval for the Planet's context: how much each source counts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetrievalConfig {
+    /// Similarity multiplier of ALEPH's own words (0 = never retrieved).
+    pub self_speech_weight: f32,
+    /// Similarity multiplier of dreams (0 = never retrieved).
+    pub dream_weight: f32,
+    /// Weighted similarity a memory needs to be offered as context.
+    pub min_relevance: f32,
+}
+
+impl Default for RetrievalConfig {
+    fn default() -> Self {
+        Self {
+            self_speech_weight: 0.6,
+            dream_weight: 0.3, // External ground truth first
+            min_relevance: 0.4,
+        }
+    }
+}
+
+/// Frame-time watchdog: warns (and sheds load) when the loop can't keep its rate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchdogConfig {
+    pub enabled: bool,
+    /// Consecutive frames over budget (1 / target Hz) before the warning.
+    pub overrun_frames: u32,
+    /// Seconds without a finished frame before the loop counts as stalled.
+    pub stall_secs: f32,
+    /// While overrunning, skip the idle cortex pulses (the Planet's "scan" pumps).
+    pub shed_idle_pulses: bool,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            overrun_frames: 30, // Half a second of frames at 60Hz
+            stall_secs: 2.0,
+            shed_idle_pulses: true,
+        }
+    }
+}
+
+/// The reservoir's geometry for experiments.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BrainConfig {
+    /// Receptive fields in brain radii (per region: [[brain.receptive_fields.visual]]
+    /// half_space = { normal = [0, 0, -1], offset = 0.6 }, or shell / cone). None = the map saved with the brain.
+    pub receptive_fields: Option<ReceptiveFieldMap>,
+    /// Input columns the reservoir must have (a brain saved with fewer gets new
+    /// ones appended at boot). 0 = as many as the senses need.
+    pub input_channels: usize,
+    /// Where the LLM's echo lands: "semantic" (its receptive field, laid out by
+    /// position) or "whole_brain" (every neuron, for brains whose regions formed under it).
+    /// None = the brain's own (semantic from genesis, whole_brain for older saves).
+    pub echo_target: Option<EchoTarget>,
+}
+
+/// Auditory startle reflex (see core/startle.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StartleConfig {
+    /// Loudness (max of bass/mids) at which a sound starts to arouse.
+    pub arousal_threshold: f32,
+    /// Cortisol per unit of surprise for sounds above arousal_threshold.
+    pub arousal_gain: f32,
+    /// Loudness of a shock ("💥 AUDITORY SHOCK").
+    pub shock_threshold: f32,
+    /// A shock also needs to be this much louder than the running peak.
+    pub min_surprise: f32,
+    /// Cortisol per unit of surprise of a shock, capped at max_cortisol.
+    pub shock_gain: f32,
+    pub max_cortisol: f32,
+    /// No second shock within this many seconds of the last.
+    pub refractory_secs: f32,
+    /// Time constant of the running peak's fall back to silence.
+    pub peak_decay_secs: f32,
+    /// Shocks remembered for habituation, and how similar (cosine of the band
+    /// spectrum) an earlier one must be to count as "the same bang".
+    pub habituation_secs: f32,
+    pub habituation_similarity: f32,
+    /// Shock divisor per similar remembered shock (1 + rate * n, as novelty).
+    pub habituation_rate: f32,
+}
+
+impl Default for StartleConfig {
+    fn default() -> Self {
+        Self {
+            arousal_threshold: 0.5,
+            arousal_gain: 0.05,
+            shock_threshold: 0.8,
+            min_surprise: 0.2,
+            shock_gain: 0.4,
+            max_cortisol: 0.2, // The old fixed shock
+            refractory_secs: 2.0,
+            peak_decay_secs: 4.0,
+            habituation_secs: 120.0,
+            habituation_similarity: 0.9,
+            habituation_rate: 0.7,
+        }
+    }
+}
+
+/// Liveness/readiness probe (GET /healthz, see core/health.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthConfig {
+    /// Seconds without a finished tick before /healthz answers 503.
+    pub grace_secs: f32,
+    /// Components whose death is a 503 (only the reservoir can't be missing;
+    /// add "memory", "cortex", ... to make them required by the probe).
+    pub mandatory: Vec<String>,
+    /// Seconds between hippocampus pings, and how long a ping may go unanswered.
+    pub memory_ping_secs: f32,
+    pub memory_timeout_secs: f32,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            grace_secs: 5.0,
+            mandatory: vec!["reservoir".to_string()],
+            memory_ping_secs: 5.0,
+            memory_timeout_secs: 10.0, // A consolidation round may hold the thread for a few seconds
+        }
+    }
+}
+
+/// Where ALEPH listens and how it speaks (the container profile changes these, see core/profile.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuntimeConfig {
+    /// Audio when `aleph start` gets no flag: "auto" (the mic, else the browser),
+    /// "mic", "websocket" (the browser) or "headless".
+    pub audio: String,
+    /// Web dashboard, WebSocket and /healthz.
+    pub web_bind: String,
+    /// The TUI's socket.
+    pub ipc_socket: String,
+    /// Speak through Piper + aplay on this machine. false = utterances go to the
+    /// dashboard over the WebSocket (`speech`) for the browser to say.
+    pub local_voice: bool,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self {
+            audio: "auto".to_string(),
+            web_bind: "0.0.0.0:3030".to_string(),
+            ipc_socket: "/tmp/aleph.sock".to_string(),
+            local_voice: true,
+        }
+    }
+}
+
+/// What ALEPH may do outside itself (see actuators/registry.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActuatorsConfig {
+    /// Capabilities that may be invoked: voice, notes, notify, mqtt.
+    /// Anything not listed is refused, registered or not.
+    pub whitelist: Vec<String>,
+    /// Minimum seconds between two actions of a capability (unlisted = no limit).
+    pub min_interval_secs: HashMap<String, f32>,
+    /// One JSON line per request, done or refused, with the triggering thought id ("" = none).
+    pub log: String,
+    /// Where the journal (notes/journal.md) is kept.
+    pub notes_dir: String,
+    /// Resonance an utterance needs to be journaled (while the Journal goal is active).
+    pub journal_resonance: f32,
+}
+
+impl Default for ActuatorsConfig {
+    fn default() -> Self {
+        Self {
+            whitelist: vec!["voice".to_string()],
+            min_interval_secs: [("notes", 60.0), ("notify", 10.0), ("mqtt", 1.0)]
+                .into_iter()
+                .map(|(capability, secs)| (capability.to_string(), secs))
+                .collect(),
+            log: "logs/actuators.ndjson".to_string(),
+            notes_dir: "notes".to_string(),
+            journal_resonance: 0.7,
+        }
+    }
+}
+
+/// Desktop notifications for high-salience thoughts (see actuators/notify.rs).
+/// Raised through the "notify" capability: add it to [actuators] whitelist.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationsConfig {
+    /// What it says out loud.
+    pub vocal: bool,
+    /// Trauma escalations (escalating, firefighter).
+    pub trauma: bool,
+    /// Forced sleep (metabolic collapse).
+    pub forced_sleep: bool,
+    /// Goals achieved.
+    pub goals: bool,
+    /// No popups between quiet_start_hour and quiet_end_hour (local time, wraps
+    /// past midnight). Defaults to the circadian night.
+    pub quiet_hours: bool,
+    pub quiet_start_hour: u32,
+    pub quiet_end_hour: u32,
+    /// At most one popup per this many seconds; what arrives meanwhile is merged into the next.
+    pub coalesce_secs: f32,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            vocal: true,
+            trauma: true,
+            forced_sleep: true,
+            goals: true,
+            quiet_hours: true,
+            quiet_start_hour: NIGHT_START_HOUR,
+            quiet_end_hour: NIGHT_END_HOUR,
+            coalesce_secs: 10.0,
+        }
+    }
+}
+
+/// The Planet's history read from outside (GET /consciousness, shutdown dump; cortex/context.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConsciousnessConfig {
+    /// Bearer token GET /consciousness must present ("" = the endpoint is closed).
+    pub token: String,
+    /// Write consciousness-<timestamp>.txt next to the journal (actuators.notes_dir) at shutdown.
+    pub dump_on_shutdown: bool,
+    /// Cap of a dump (HTTP and disk): the oldest segments are left out beyond it (0 = no cap).
+    pub max_dump_bytes: usize,
+}
+
+impl Default for ConsciousnessConfig {
+    fn default() -> Self {
+        Self {
+            token: String::new(),
+            dump_on_shutdown: true,
+            max_dump_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// Hourly digest of the day (core/stats.rs): the morning summary thought, stats/<date>.json, GET /stats/daily.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatsConfig {
+    pub enabled: bool,
+    /// Local time of the daily summary ("HH:MM").
+    pub summary_time: String,
+    /// Directory for the raw hourly buckets (`<date>.json`).
+    pub dir: String,
+    /// Ask the Planet to retell the summary in its own words (awake and rested only).
+    pub polish: bool,
+    /// Adenosine under which ALEPH counts as rested.
+    pub rested_below: f32,
+}
+
+impl Default for StatsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            summary_time: "09:00".to_string(),
+            dir: "stats".to_string(),
+            polish: true,
+            rested_below: 0.5,
+        }
+    }
+}
+
+impl StatsConfig {
+    /// `summary_time` parsed (09:00 when it is not "HH:MM").
+    pub fn summary_at(&self) -> chrono::NaiveTime {
+        chrono::NaiveTime::parse_from_str(self.summary_time.trim(), "%H:%M")
+            .unwrap_or_else(|_| chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+    }
+}
+
+/// Maintenance commands over the IPC socket (core/admin.rs, `aleph ctl`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdminConfig {
+    /// Shared secret every AlephPacket::Admin must carry ("" = admin commands are refused).
+    pub secret: String,
+}
+
+/// Gate and membrane decision logs (core/decisions.rs): GET /gate/history, GET /membrane/history, CSV sinks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecisionsConfig {
+    /// Decisions kept in memory per log.
+    pub capacity: usize,
+    /// Drop the text itself (memory and CSV), keep the numeric features.
+    pub redact_text: bool,
+    /// CSV of every vocalization attempt ("" = none; `aleph start --log-gate <path>`).
+    pub gate_csv: String,
+    /// CSV of every membrane verdict ("" = none; `aleph start --log-membrane <path>`).
+    pub membrane_csv: String,
+}
+
+impl Default for DecisionsConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 2000,
+            redact_text: false,
+            gate_csv: String::new(),
+            membrane_csv: String::new(),
+        }
+    }
+}
+
+/// Simulated interlocutor for soak runs (core/interlocutor.rs, `aleph start --soak-interlocutor 2h`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InterlocutorConfig {
+    /// Length of the soak; the daemon shuts down when it ends (0 = no interlocutor).
+    pub soak_secs: u64,
+    /// Lines to say, in order, looping ("" = the template generator).
+    pub script: String,
+    pub seed: u64,
+    /// Typical pause before answering (jittered 0.5-1.5x, plus typing time).
+    pub base_gap_secs: f32,
+    /// How long a message waits for a Vocal reply before it counts as ignored.
+    pub patience_secs: f32,
+    /// Longest silence after being ignored.
+    pub max_gap_secs: f32,
+    /// Resonance at or above which a reply counts as coherent (warm answer).
+    pub coherent_resonance: f32,
+}
+
+impl Default for InterlocutorConfig {
+    fn default() -> Self {
+        Self {
+            soak_secs: 0,
+            script: String::new(),
+            seed: 7,
+            base_gap_secs: 20.0,
+            patience_secs: 45.0,
+            max_gap_secs: 300.0,
+            coherent_resonance: 0.5,
+        }
+    }
+}
+
+/// Memory-pressure degradation (core/pressure.rs): RAM ratios (0-1) at which each notch tightens.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PressureConfig {
+    pub enabled: bool,
+    /// Neurogenesis stops at the current size.
+    pub cap_growth: f32,
+    /// A hippocampus consolidation round.
+    pub consolidate: f32,
+    /// The Planet's prompt is assembled within history_tokens.
+    pub shrink_context: f32,
+    /// Checkpoint, then forced sleep.
+    pub sleep: f32,
+    /// A notch comes off once RAM is this far below its threshold.
+    pub release: f32,
+    /// Least time between two notches (each measure gets a chance to work).
+    pub hold_secs: f32,
+    pub history_tokens: usize,
+}
+
+impl Default for PressureConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cap_growth: 0.80,
+            consolidate: 0.85,
+            shrink_context: 0.90,
+            sleep: 0.95,
+            release: 0.05,
+            hold_secs: 10.0,
+            history_tokens: 512,
+        }
+    }
+}
+
+/// Startup self-test (core/selftest.rs): GET /selftest, and the ceilings past which boot warns
+/// that the hardware is slow (0 = no ceiling).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SelfTestConfig {
+    pub listen_ceiling_ms: f64,
+    pub think_ceiling_ms: f64,
+    pub whisper_ceiling_ms: f64,
+    /// Per tick: a 60 Hz frame is 16.7 ms and the tick is only part of it.
+    pub tick_ceiling_ms: f64,
+    /// Ticks of the benchmark (0 = no benchmark).
+    pub bench_ticks: usize,
+}
+
+impl Default for SelfTestConfig {
+    fn default() -> Self {
+        Self {
+            listen_ceiling_ms: 3000.0,
+            think_ceiling_ms: 10000.0,
+            whisper_ceiling_ms: 3000.0,
+            tick_ceiling_ms: 8.0,
+            bench_ticks: 300,
+        }
+    }
+}
+
+/// Multi-scale complexity of the reservoir (core/complexity.rs), shown next to `entropy_legacy`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComplexityConfig {
+    /// Drive the entropy couplings (chemistry, temperature, homeostat) with the composite
+    /// instead of the one-tick histogram.
+    pub drive_couplings: bool,
+    /// Neurons sampled, evenly spaced.
+    pub probes: usize,
+    /// Ticks of history the measures look at.
+    pub window: usize,
+    /// Ticks between recomputations.
+    pub every_ticks: u32,
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        Self {
+            drive_couplings: false,
+            probes: 16,
+            window: 128,
+            every_ticks: 30,
+        }
+    }
+}
+
+/// Drives behind spontaneous speech (core/repertoire.rs, repertoire.toml).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RepertoireConfig {
+    /// Drive file (the built-in copy is used when missing or invalid).
+    pub path: String,
+    /// Seconds from the draw to the utterance it is credited with (the Planet's latency).
+    pub speak_secs: f32,
+    /// Seconds after the utterance in which a human reply counts as an answer.
+    pub response_secs: f32,
+    /// Retrieval relevance below which a recalled memory feeds the {memory} slot.
+    pub low_confidence: f32,
+    /// Seconds of music-like sound before the {music} slot fills.
+    pub music_secs: f32,
+}
+
+impl Default for RepertoireConfig {
+    fn default() -> Self {
+        Self {
+            path: "repertoire.toml".to_string(),
+            speak_secs: 30.0,
+            response_secs: 60.0,
+            low_confidence: 0.5,
+            music_secs: 10.0,
+        }
+    }
+}
+
+/// Checking in when an engaged person goes quiet (core/checkin.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CheckInConfig {
+    pub enabled: bool,
+    /// Engagement (0-1) above which a silence is noticed.
+    pub engagement_threshold: f32,
+    /// Engagement added by each utterance.
+    pub per_contact: f32,
+    /// Seconds for engagement to halve without contact.
+    pub half_life_secs: f32,
+    /// Seconds without voice or utterance before the episode is decided.
+    pub silence_secs: f32,
+    /// Chance of checking in at oxytocin 0.5 and adenosine 0 (scaled by 0.5 + oxytocin and 1 - adenosine).
+    pub probability: f32,
+    /// Minimum seconds between two check-ins.
+    pub cooldown_secs: f32,
+    pub max_per_hour: u32,
+}
+
+impl Default for CheckInConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            engagement_threshold: 0.5,
+            per_contact: 0.25,
+            half_life_secs: 300.0,
+            silence_secs: 600.0,
+            probability: 0.8,
+            cooldown_secs: 1800.0,
+            max_per_hour: 2,
+        }
+    }
+}
+
+/// Sleep-talk (opt-in): strong dream fragments said quietly during REM (ExpressionGate::attempt_sleep_talk).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SleepTalkConfig {
+    pub enabled: bool,
+    /// Resonance a fragment needs to be said.
+    pub resonance: f32,
+    /// Utterances per REM cycle.
+    pub per_cycle: u32,
+    /// TTS volume (0-1) and rate (1 = normal pace).
+    pub volume: f32,
+    pub rate: f32,
+}
+
+impl Default for SleepTalkConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            resonance: 0.85,
+            per_cycle: 1,
+            volume: 0.4,
+            rate: 0.8,
+        }
+    }
+}
+
+/// Being fed: POST /feed restoration profiles (core/feeding.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeedingConfig {
+    /// Dopamine and glucose per unit of attention (at full sensitivity).
+    pub attention_dopamine: f32,
+    pub attention_glucose: f32,
+    /// The crash: this share of the dopamine spike comes back off dip_secs later.
+    pub dip_ratio: f32,
+    pub dip_secs: f32,
+    /// Sensitivity lost per unit of attention, its floor, and the recovery time constant.
+    pub tolerance: f32,
+    pub min_sensitivity: f32,
+    pub recovery_secs: f32,
+    /// Glucose per unit of data, released over digest_secs.
+    pub data_glucose: f32,
+    pub digest_secs: f32,
+    /// A rest feed of amount a brings sleep to rest_window_secs × (1 − a) from now.
+    pub rest_window_secs: f32,
+}
+
+impl Default for FeedingConfig {
+    fn default() -> Self {
+        Self {
+            attention_dopamine: 0.5,
+            attention_glucose: 0.3,
+            dip_ratio: 0.5,
+            dip_secs: 60.0,
+            tolerance: 1.0,
+            min_sensitivity: 0.1,
+            recovery_secs: 600.0,
+            data_glucose: 1.0,
+            digest_secs: 120.0,
+            rest_window_secs: 1800.0,
+        }
+    }
+}
+
+/// Audio -> reservoir latency budget (core/latency.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LatencyConfig {
+    /// Shed load when over budget (false = measure only).
+    pub shed: bool,
+    /// p95 of capture -> injection, in ms.
+    pub budget_ms: f32,
+    /// Seconds the p95 must stay over budget (or back under it) before shedding starts (or stops).
+    pub persist_secs: f32,
+    /// Shedding stops under this share of the budget.
+    pub recover_ratio: f32,
+    /// Spectra in the rolling distribution (~3s at 48kHz / 512 hop).
+    pub window: usize,
+    /// While shedding, the cochlea analyzes one frame every this many hops.
+    pub fft_stride: usize,
+}
+
+impl Default for LatencyConfig {
+    fn default() -> Self {
+        Self {
+            shed: true,
+            budget_ms: 150.0,
+            persist_secs: 3.0,
+            recover_ratio: 0.7,
+            window: 256,
+            fft_stride: 2,
+        }
+    }
+}
+
+/// Picking up the thread with a returning speaker (core/thread.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThreadConfig {
+    pub enabled: bool,
+    /// Seconds without hearing a speaker after which hearing them again is a return.
+    pub gap_secs: f32,
+    /// Turns recalled per return.
+    pub top_k: usize,
+    /// How far back to look (days; 0 = all of it).
+    pub lookback_days: f32,
+    /// Planet inputs that carry the "last time with" context.
+    pub turns: u32,
+    /// Length cap of that context.
+    pub max_chars: usize,
+}
+
+impl Default for ThreadConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            gap_secs: 3600.0,
+            top_k: 3,
+            lookback_days: 90.0,
+            turns: 3,
+            max_chars: 320,
+        }
+    }
+}
+
+/// The Planet asking its own body for rest, attention or calm (core/volition.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolitionConfig {
+    pub enabled: bool,
+    /// Intent list (format documented in intents.toml; the built-in copy if missing or invalid).
+    pub path: String,
+    /// Outputs with a weaker neural echo ask nothing.
+    pub min_resonance: f32,
+    /// rest is honored only this tired.
+    pub rest_min_adenosine: f32,
+    /// attention is vetoed from this tired on.
+    pub attention_max_adenosine: f32,
+    /// Membrane attention added while focusing, and for how long.
+    pub attention_boost: f32,
+    pub attention_secs: f32,
+    /// calm is honored only this stressed, and lowers cortisol by calm_nudge.
+    pub calm_min_cortisol: f32,
+    pub calm_nudge: f32,
+    /// Seconds before the same intent can be honored again.
+    pub cooldown_secs: f32,
+    /// Honored requests per rolling hour, all intents together.
+    pub max_per_hour: u32,
+}
+
+impl Default for VolitionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            path: "intents.toml".to_string(),
+            min_resonance: 0.5,
+            rest_min_adenosine: 0.6,
+            attention_max_adenosine: 0.85,
+            attention_boost: 0.3,
+            attention_secs: 60.0,
+            calm_min_cortisol: 0.4,
+            calm_nudge: 0.1,
+            cooldown_secs: 300.0,
+            max_per_hour: 4,
+        }
+    }
+}
+
+/// Heavy structural operations on a warm standby copy of the brain (core/standby.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StandbyConfig {
+    /// false = run them inline, stalling the tick loop while they work.
+    pub enabled: bool,
+    /// Carry the activity of the ticks run during the operation into the swapped-in
+    /// brain (false = those ticks are accepted as lost).
+    pub replay: bool,
+    /// Relaunches when the live brain changed structure meanwhile; then it runs inline.
+    pub max_retries: u32,
+}
+
+impl Default for StandbyConfig {
+    fn default() -> Self {
+        Self { enabled: true, replay: true, max_retries: 3 }
+    }
+}
+
+/// Research event ledger: epiphanies, trauma, sleep stages, growth and pruning as NDJSON (core/ledger.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LedgerConfig {
+    pub enabled: bool,
+    pub path: String,
+    /// The file is rotated before it would grow past this (path.1, path.2, ...).
+    pub rotate_bytes: u64,
+    /// Rotated files kept before the oldest is deleted.
+    pub keep: usize,
+}
+
+impl Default for LedgerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            path: "events.ndjson".to_string(),
+            rotate_bytes: 16 * 1024 * 1024,
+            keep: 4,
+        }
+    }
+}
+
+/// Two-process split: the Planet and Whisper in an `aleph cortexd` child (see cortex/cortexd.rs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CortexdConfig {
+    /// Run the senses/cortex worker as a supervised child process (false = one process, as always).
+    pub enabled: bool,
+    /// Socket the parent listens on and the child connects to.
+    pub socket: String,
+    /// Wait before restarting a child that died (doubles per crash in a row, up to max_restart_secs).
+    pub restart_secs: f32,
+    pub max_restart_secs: f32,
+    /// After a restart, Think is heard as Listen for this long (aphasia fades, it doesn't snap back).
+    pub listen_only_secs: f32,
+    /// Seconds a freshly spawned child has to connect before it is killed and tried again.
+    pub connect_timeout_secs: f32,
+}
+
+impl Default for CortexdConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            socket: "/tmp/aleph-cortexd.sock".to_string(),
+            restart_secs: 1.0,
+            max_restart_secs: 30.0,
+            listen_only_secs: 20.0,
+            connect_timeout_secs: 60.0,
+        }
+    }
+}
+
+/// Boredom-driven exploration: self-generated stimuli after long silences.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExplorationConfig {
+    pub enabled: bool,
+    /// Silence (no stimulus, no spontaneous speech) required before boredom acts.
+    pub min_silence_secs: f32,
+    /// Minimum time between two explorations.
+    pub cooldown_secs: f32,
+    /// An action that hasn't paid off by then pays nothing.
+    pub timeout_secs: f32,
+    /// Dopamine per unit of novelty (novelty is 0..1).
+    pub dopamine_payout: f32,
+    pub ruminate: ExplorationAction,
+    pub imagine: ExplorationAction,
+    pub question: ExplorationAction,
+    pub look: ExplorationAction,
+}
+
+impl Default for ExplorationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_silence_secs: 90.0,
+            cooldown_secs: 45.0,
+            timeout_secs: 30.0,
+            dopamine_payout: 0.3,
+            ruminate: ExplorationAction { weight: 1.0, cost_secs: 0.5 },
+            imagine: ExplorationAction { weight: 1.0, cost_secs: 0.3 },
+            question: ExplorationAction { weight: 1.0, cost_secs: 0.5 }, // The Planet bills its own inference on top
+            look: ExplorationAction { weight: 1.0, cost_secs: 0.2 },
+        }
+    }
+}
+
+/// Where one voice's thoughts go. A `[voices.<name>]` table replaces the whole
+/// route: keys it omits take these generic values, not the voice's defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VoiceRoute {
+    /// Spoken aloud by the mouth (TTS).
+    pub vocalize: bool,
+    /// Sent to the hippocampus as lived experience.
+    pub memorize: bool,
+    /// Shown in the short-term stream (web / TUI).
+    pub stream: bool,
+    /// Display color hint for clients (CSS color).
+    pub color: String,
+}
+
+impl Default for VoiceRoute {
+    fn default() -> Self {
+        Self { vocalize: false, memorize: false, stream: true, color: "#8888aa".to_string() }
+    }
+}
+
+impl VoiceRoute {
+    fn new(vocalize: bool, memorize: bool, stream: bool, color: &str) -> Self {
+        Self { vocalize, memorize, stream, color: color.to_string() }
+    }
+}
+
+/// Routing table: one route per MindVoice (the thought drain consults it, nothing else decides).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VoicesConfig {
+    pub sensory: VoiceRoute,
+    pub cortex: VoiceRoute,
+    pub chem: VoiceRoute,
+    pub system: VoiceRoute,
+    pub vocal: VoiceRoute,
+    pub dream: VoiceRoute,
+    pub memory: VoiceRoute,
+    pub error: VoiceRoute,
+    pub agency: VoiceRoute,
+}
+
+impl Default for VoicesConfig {
+    fn default() -> Self {
+        Self {
+            sensory: VoiceRoute::new(false, false, true, "#00e5ff"),
+            cortex: VoiceRoute::new(false, false, true, "#00ff9d"),
+            chem: VoiceRoute::new(false, false, true, "#ff00ff"),
+            system: VoiceRoute::new(false, false, true, "#8888aa"),
+            vocal: VoiceRoute::new(true, true, true, "#ffffff"), // What it says, it remembers saying
+            dream: VoiceRoute::new(false, false, true, "#7c6cff"),
+            memory: VoiceRoute::new(false, false, true, "#ffb347"), // Recalled, not re-lived
+            error: VoiceRoute::new(false, false, true, "#ff4d4d"),
+            agency: VoiceRoute::new(false, false, true, "#ffe14d"),
+        }
+    }
+}
+
+impl VoicesConfig {
+    pub fn route(&self, voice: MindVoice) -> &VoiceRoute {
+        match voice {
+            MindVoice::Sensory => &self.sensory,
+            MindVoice::Cortex => &self.cortex,
+            MindVoice::Chem => &self.chem,
+            MindVoice::System => &self.system,
+            MindVoice::Vocal => &self.vocal,
+            MindVoice::Dream => &self.dream,
+            MindVoice::Memory => &self.memory,
+            MindVoice::Error => &self.error,
+            MindVoice::Agency => &self.agency,
+        }
+    }
+
+    #[cfg(test)]
+    fn route_mut(&mut self, voice: MindVoice) -> &mut VoiceRoute {
+        match voice {
+            MindVoice::Sensory => &mut self.sensory,
+            MindVoice::Cortex => &mut self.cortex,
+            MindVoice::Chem => &mut self.chem,
+            MindVoice::System => &mut self.system,
+            MindVoice::Vocal => &mut self.vocal,
+            MindVoice::Dream => &mut self.dream,
+            MindVoice::Memory => &mut self.memory,
+            MindVoice::Error => &mut self.error,
+            MindVoice::Agency => &mut self.agency,
+        }
+    }
+
+    /// Per-voice display hints for telemetry (clients color new voices without a release).
+    pub fn hints(&self) -> Vec<VoiceHint> {
+        MindVoice::ALL.iter().map(|&v| VoiceHint {
+            name: v.name(),
+            label: v.label(),
+            color: self.route(v).color.clone(),
+        }).collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct VoiceHint {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub color: String,
+}
+
+/// Slow entropy controller (setpoint comes from the genome).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HomeostatConfig {
+    pub enabled: bool,
+    /// Time constant of the mean entropy being controlled.
+    pub window_secs: f32,
+    /// Seconds between controller moves.
+    pub update_secs: f32,
+    /// Proportional gain (u per unit of entropy error).
+    pub kp: f32,
+    /// Integral gain (u per error·minute).
+    pub ki: f32,
+    /// Largest change of u in one move.
+    pub max_step: f32,
+    /// Recurrent gain at u = ±1 is 2^±gain_octaves (multiplicative: the reservoir may be
+    /// far past its critical point, and the useful range spans orders of magnitude).
+    pub gain_octaves: f32,
+    /// Leak scale at u = ±1 is 1 ± leak_range.
+    pub leak_range: f32,
+    /// Background noise amplitude at u = +1.
+    pub noise_max: f32,
+}
+
+impl Default for HomeostatConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            window_secs: 120.0,
+            update_secs: 20.0,
+            kp: 1.0,
+            ki: 0.2,
+            max_step: 0.05,
+            gain_octaves: 4.0,
+            leak_range: 0.5,
+            noise_max: 0.3,
+        }
+    }
+}
+
+// --- models.toml (What the Planet thinks with) ---
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelsConfig {
+    /// LLM substrate: "candle" (local GGUF), "ollama" (remote server) or "mock" (deterministic, no weights).
+    pub backend: String,
+    /// Seed for the mock backend's pseudo-logits.
+    pub mock_seed: u64,
+    /// Master seed of the Planet's sampling (seeded run: every inference's seed
+    /// derives from it). None = a random master, logged at boot.
+    pub inference_seed: Option<u64>,
+    /// NDJSON tape of inferences that produced text, for `aleph replay-inference` (empty = off).
+    pub inference_log: String,
+    /// Local GGUF + tokenizer (candle backend). In two-tier mode this is the fast Listen model.
+    pub model_path: String,
+    pub tokenizer_path: String,
+    /// Optional larger GGUF for Think (two-tier mode). Loaded lazily, evicted when idle.
+    pub think_model_path: Option<String>,
+    pub think_tokenizer_path: Option<String>,
+    /// Seconds without a Think before the large model is dropped from (V)RAM.
+    pub think_idle_evict_secs: u64,
+    /// Seconds without a Think before the fast (or only) model's weights are dropped
+    /// too, tokenizer and history kept; the next Think reloads them (0 = never).
+    pub idle_unload_secs: u64,
+    /// Seconds a (re)loaded model stays resident whatever happens (no load/unload thrashing).
+    pub min_resident_secs: u64,
+    pub ollama: OllamaConfig,
+}
+
+impl Default for ModelsConfig {
+    fn default() -> Self {
+        Self {
+            backend: "candle".to_string(),
+            mock_seed: 42,
+            inference_seed: None,
+            inference_log: "logs/inferences.ndjson".to_string(),
+            model_path: "models/tinyllama-1.1b-chat-v1.0.Q4_K_M.gguf".to_string(),
+            tokenizer_path: "models/tokenizer_tinyllama.json".to_string(),
+            think_model_path: None,
+            think_tokenizer_path: None,
+            think_idle_evict_secs: 300,
+            idle_unload_secs: 1800,
+            min_resident_secs: 600,
+            ollama: OllamaConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OllamaConfig {
+    pub base_url: String,
+    pub model: String,
+    pub connect_timeout_ms: u64,
+    /// Per-read timeout (a streamed generation may take longer overall).
+    pub read_timeout_ms: u64,
+    /// Alternatives per token requested for the sparse neural echo.
+    pub top_logprobs: u32,
+    /// Seconds to stay offline after a failure before trying the server again.
+    pub retry_secs: u64,
+}
+
+impl Default for OllamaConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "http://127.0.0.1:11434".to_string(),
+            model: "tinyllama".to_string(),
+            connect_timeout_ms: 500,
+            read_timeout_ms: 20000,
+            top_logprobs: 20,
+            retry_secs: 10,
+        }
+    }
+}
+
+impl AlephConfig {
+    pub fn load() -> Self {
+        Self::load_from(CONFIG_FILE)
+    }
+
+    pub fn load_from(path: &str) -> Self {
+        load_toml(path, "")
+    }
+}
+
+impl ModelsConfig {
+    pub fn load() -> Self {
+        load_toml(MODELS_FILE, profile::MODELS_SCOPE)
+    }
+}
+
+/// The file over the profile's defaults, under the ALEPH_* environment (core/profile.rs).
+fn load_toml<T: DeserializeOwned + Default>(path: &str, scope: &str) -> T {
+    let content = fs::read_to_string(path).ok();
+    let overrides = profile::env_overrides(std::env::vars(), scope);
+    let profile = Profile::current();
+    if content.is_none() && overrides.is_empty() && profile == Profile::Local && std::env::var_os(profile::MODELS_DIR_VAR).is_none() {
+        return T::default(); // No file = defaults (not an error)
+    }
+    let source = match (&content, overrides.len()) {
+        (Some(_), 0) => path.to_string(),
+        (Some(_), n) => format!("{} + {} ALEPH_* variable(s)", path, n),
+        (None, n) => format!("{:?} profile + {} ALEPH_* variable(s)", profile, n),
+    };
+    match profile::layered(profile, scope, content.as_deref(), &overrides)
+        .and_then(|table| Ok(toml::Value::Table(table).try_into::<T>()?)) {
+        Ok(config) => {
+            println!("⚙️  CONFIG LOADED: {}", source);
+            config
+        },
+        Err(e) => {
+            println!("⚠️ CONFIG CORRUPT ({}): {}. Using defaults.", source, e);
+            T::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flipping_one_voice_route_changes_that_voice_and_no_other() {
+        let voices = VoicesConfig::default();
+        for voice in MindVoice::ALL {
+            let mut table = voices.clone();
+            let original = voices.route(voice).clone();
+            let flipped = VoiceRoute {
+                vocalize: !original.vocalize,
+                memorize: !original.memorize,
+                stream: !original.stream,
+                color: format!("{}-flipped", original.color),
+            };
+            *table.route_mut(voice) = flipped.clone();
+            for other in MindVoice::ALL {
+                let expected = if other == voice { &flipped } else { voices.route(other) };
+                assert_eq!(table.route(other), expected, "flipping '{}' changed the route of '{}'", voice.name(), other.name());
+            }
+        }
+    }
+
+    #[test]
+    fn no_two_voices_share_a_label() {
+        let labels: std::collections::HashSet<&str> = MindVoice::ALL.iter().map(|v| v.label()).collect();
+        assert_eq!(labels.len(), MindVoice::ALL.len());
+    }
+}
@@ -0,0 +1,239 @@
+// src/core/consolidation.rs
+// THE NIGHT SHIFT: When volatile memories become long-term ones
+//
+// Consolidation hung off `MemoryCommand::ConsolidateSleep`, which nothing ever
+// sent: volatile memories piled up in RAM for the whole session and were only
+// judged (kept or forgotten) by whoever restarted the daemon. The hippocampus
+// now schedules rounds itself, on two conditions:
+//
+//   deep: the daemon signalled the transition into Deep sleep -> rounds run
+//         back to back until the backlog is empty
+//   idle: no memory work for idle_secs AND volatile >= volatile_floor -> one
+//         round, then another idle_secs before the next
+//
+// Every round examines at most `batch` volatile memories (oldest first), so a
+// stimulus arriving mid-consolidation waits for one batch, not the whole day.
+// Each round is reported on the hippocampus log channel ("💤 Consolidation ...",
+// `Round::line` / `Round::parse`) and the daemon bills its duration to the
+// metabolism. The scheduler takes `now` as a parameter: the test drives it
+// with a virtual clock.
+
+use std::time::{Duration, Instant};
+
+use crate::core::config::ConsolidationConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    Deep,
+    Idle,
+}
+
+impl Trigger {
+    fn name(self) -> &'static str {
+        match self {
+            Trigger::Deep => "deep",
+            Trigger::Idle => "idle",
+        }
+    }
+}
+
+pub struct Scheduler {
+    config: ConsolidationConfig,
+    last_activity: Instant,
+    deep_pending: bool,
+    pub rounds: u64, // Rounds since boot
+}
+
+impl Scheduler {
+    pub fn new(config: ConsolidationConfig, now: Instant) -> Self {
+        Self { config, last_activity: now, deep_pending: false, rounds: 0 }
+    }
+
+    pub fn batch(&self) -> usize {
+        self.config.batch.max(1)
+    }
+
+    /// Memory work happened (a stimulus, a probe): the thread is not idle.
+    pub fn touch(&mut self, now: Instant) {
+        self.last_activity = now;
+    }
+
+    /// The daemon entered Deep sleep: consolidate the whole backlog.
+    pub fn enter_deep(&mut self) {
+        self.deep_pending = true;
+    }
+
+    /// Should a round run now, and why?
+    pub fn due(&self, volatile: usize, now: Instant) -> Option<Trigger> {
+        if !self.config.enabled || volatile == 0 {
+            return None;
+        }
+        if self.deep_pending {
+            return Some(Trigger::Deep);
+        }
+        let idle = now.saturating_duration_since(self.last_activity);
+        if idle >= Duration::from_secs_f32(self.config.idle_secs.max(0.0)) && volatile >= self.config.volatile_floor {
+            return Some(Trigger::Idle);
+        }
+        None
+    }
+
+    /// A round ran, leaving `backlog` volatile memories.
+    pub fn ran(&mut self, trigger: Trigger, backlog: usize, now: Instant) {
+        self.rounds += 1;
+        match trigger {
+            Trigger::Deep if backlog == 0 => self.deep_pending = false,
+            Trigger::Deep => {},
+            Trigger::Idle => self.last_activity = now, // Next idle round after another idle period
+        }
+    }
+}
+
+/// What one round did (the log line the daemon bills).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub trigger: Trigger,
+    pub examined: usize,
+    pub kept: usize,
+    pub forgotten: usize,
+    pub elapsed_ms: u64,
+    pub backlog: usize,
+}
+
+const PREFIX: &str = "💤 Consolidation";
+
+impl Round {
+    pub fn line(&self) -> String {
+        format!("{} ({}): {} examined, {} kept, {} forgotten in {} ms (backlog {})",
+            PREFIX, self.trigger.name(), self.examined, self.kept, self.forgotten, self.elapsed_ms, self.backlog)
+    }
+
+    /// The round behind a hippocampus log line, if it is one.
+    pub fn parse(log: &str) -> Option<Self> {
+        let rest = log.strip_prefix(PREFIX)?.trim_start().strip_prefix('(')?;
+        let (trigger, rest) = rest.split_once("):")?;
+        let trigger = match trigger {
+            "deep" => Trigger::Deep,
+            "idle" => Trigger::Idle,
+            _ => return None,
+        };
+        let numbers: Vec<u64> = rest
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|s| !s.is_empty())
+            .filter_map(|s| s.parse().ok())
+            .collect();
+        let &[examined, kept, forgotten, elapsed_ms, backlog] = numbers.as_slice() else { return None };
+        Some(Self {
+            trigger,
+            examined: examined as usize,
+            kept: kept as usize,
+            forgotten: forgotten as usize,
+            elapsed_ms,
+            backlog: backlog as usize,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::memory_vector::{self, MemoryRecord};
+
+    fn memory(i: usize) -> MemoryRecord {
+        MemoryRecord {
+            text: format!("stimulus {}", i),
+            embedding: vec![0.0; 4],
+            timestamp: i as u64,
+            context_tags: Vec::new(),
+            entropy: if i % 3 == 0 { 0.9 } else { 0.2 }, // One in three is worth keeping
+            consolidated: false,
+            activity: Vec::new(),
+            provenance: memory_vector::Provenance::Heard { speaker: None },
+            utterance: None,
+        }
+    }
+
+    fn volatile(memories: &[MemoryRecord]) -> usize {
+        memories.iter().filter(|m| !m.consolidated).count()
+    }
+
+    /// Enabled, with idle_secs of at least a second.
+    fn enabled() -> (ConsolidationConfig, Duration) {
+        let config = ConsolidationConfig { enabled: true, ..ConsolidationConfig::default() };
+        let idle = Duration::from_secs_f32(config.idle_secs.max(1.0));
+        (ConsolidationConfig { idle_secs: idle.as_secs_f32(), ..config }, idle)
+    }
+
+    #[test]
+    fn an_idle_round_waits_for_idle_secs_and_the_floor() {
+        let (config, idle) = enabled();
+        let floor = config.volatile_floor.max(1);
+        let t0 = Instant::now();
+        let mut scheduler = Scheduler::new(config.clone(), t0);
+        let cases = [
+            ("just before idle_secs", floor, t0 + idle - Duration::from_millis(1), None),
+            ("at idle_secs", floor, t0 + idle, Some(Trigger::Idle)),
+            ("idle but under the floor", floor - 1, t0 + idle * 3, None),
+        ];
+        for (name, volatile, now, want) in cases {
+            assert_eq!(scheduler.due(volatile, now), want, "{} ({} volatile)", name, volatile);
+        }
+        // Activity postpones it, and a round resets the wait
+        scheduler.touch(t0 + idle / 2);
+        assert_eq!(scheduler.due(floor, t0 + idle), None, "activity at idle/2");
+        scheduler.ran(Trigger::Idle, floor, t0 + idle * 2);
+        assert_eq!(scheduler.due(floor, t0 + idle * 2 + Duration::from_millis(1)), None, "right after the previous round");
+    }
+
+    #[test]
+    fn deep_sleep_drains_the_backlog_oldest_first_in_bounded_batches() {
+        const BACKLOG: usize = 200;
+        let (config, _) = enabled();
+        let t0 = Instant::now();
+        let mut memories: Vec<MemoryRecord> = (0..BACKLOG).map(memory).collect();
+        let mut old = memory(BACKLOG);
+        old.consolidated = true;
+        old.entropy = 0.0; // Would be forgotten if a round ever touched it
+        memories.insert(0, old);
+        let mut scheduler = Scheduler::new(config, t0);
+        scheduler.enter_deep();
+        let batch = scheduler.batch();
+        let (mut rounds, mut examined) = (0, 0);
+        let mut now = t0;
+        while let Some(trigger) = scheduler.due(volatile(&memories), now) {
+            let first = memories.iter().find(|m| !m.consolidated).unwrap().timestamp;
+            assert_eq!(first, rounds as u64 * batch as u64, "round {} does not take the oldest volatile memories", rounds);
+            let round = memory_vector::consolidate_batch(&mut memories, batch);
+            assert!(round.examined <= batch, "round {} examined {} memories (batch {})", rounds, round.examined, batch);
+            examined += round.examined;
+            rounds += 1;
+            now += Duration::from_millis(10);
+            scheduler.ran(trigger, volatile(&memories), now);
+            assert!(rounds <= BACKLOG + 1, "deep sleep never ends");
+        }
+        assert_eq!(rounds, BACKLOG.div_ceil(batch));
+        assert_eq!(examined, BACKLOG);
+        assert_eq!(volatile(&memories), 0);
+        assert_eq!(scheduler.due(0, now), None);
+        // The consolidated memory is untouched; the intense third is kept
+        assert!(memories[0].consolidated && memories[0].timestamp == BACKLOG as u64, "a round touched an already consolidated memory");
+        assert_eq!(memories.len() - 1, BACKLOG.div_ceil(3));
+    }
+
+    #[test]
+    fn a_disabled_scheduler_never_fires() {
+        let (config, idle) = enabled();
+        let t0 = Instant::now();
+        let mut scheduler = Scheduler::new(ConsolidationConfig { enabled: false, ..config }, t0);
+        scheduler.enter_deep();
+        assert_eq!(scheduler.due(200, t0 + idle * 10), None);
+    }
+
+    #[test]
+    fn the_log_line_parses_back_to_the_same_round() {
+        // What the daemon bills
+        let round = Round { trigger: Trigger::Deep, examined: 64, kept: 20, forgotten: 44, elapsed_ms: 37, backlog: 12 };
+        assert_eq!(Round::parse(&round.line()), Some(round));
+        assert_eq!(Round::parse("💤 Sleep Cycle: Consolidated."), None);
+    }
+}
@@ -3,70 +3,413 @@ use std::thread;
 use std::time::{Duration, Instant};
 use std::sync::{Arc, Mutex};
 use std::sync::mpsc;
-use std::collections::VecDeque;
-use crate::core::thought::{Thought, MindVoice};
-use crate::core::reservoir::FractalReservoir;
-use crate::cortex::planet::{Planet, CortexInput};
+use crate::core::thought::{self, Thought, MindVoice};
+use crate::core::reservoir::{EchoTarget, FractalReservoir, NeuronLookup, NeuronReport, Pathway};
+use crate::cortex::planet::{Planet, CortexInput, CortexTier};
+use crate::cortex::queue::{CortexPriority, CortexSender};
+use crate::cortex::context::{self, Segment};
+use crate::cortex::queue::CortexCommand;
+use crate::cortex::cortexd;
 use crate::core::chemistry::Neurotransmitters;
-use crate::core::hippocampus::Hippocampus;
+use crate::core::hippocampus::{Hippocampus, MemoryCommand, MemoryStats};
+use crate::core::memory_vector::Provenance;
 use crate::core::neocortex::Neocortex;
-use crate::core::genome::Genome;
+use crate::core::genome::{Genome, GENOME_FILE, LINEAGE_FILE};
 
 use crate::core::satellite::Satellite;
 use crate::core::gate::ExpressionGate;
-use crate::core::trauma::TraumaDetector;
-use crate::core::ipc::AlephPacket;
-use crate::senses::ears::{self, AudioSpectrum};
-use crate::actuators::voice;
+use crate::core::trauma::{TraumaDetector, TraumaState};
+use crate::core::ipc::{self, AlephPacket, EventKind, Landmark, LineBuffer, MAX_STIMULUS_LINE};
+use crate::core::config::{AlephConfig, ModelsConfig};
+use crate::core::stimulus::{self, StimulusLimiter, Admission};
+use crate::core::daemon_state::{self, DaemonState, Expression, IdleReading, SleepStage};
+use crate::core::timeline::{Timeline, unix_millis};
+use crate::core::stats::Diary;
+use crate::core::admin::{self, AdminCommand, AdminRequest};
+use crate::core::decisions;
+use crate::core::interlocutor;
+use crate::core::pressure::{Belt, Notch, Step};
+use crate::core::selftest::{self, SelfTest};
+use crate::core::complexity::Complexity;
+use crate::core::metabolism::{Metabolism, EnergyBudget};
+use crate::core::autosave::Autosaver;
+use crate::core::people::{People, PersonEvent, valence_of};
+use crate::core::raster::Raster;
+use crate::core::spectrogram::Spectrogram;
+use crate::core::attention::Attention;
+use crate::core::lexicon::Lexicon;
+use crate::core::bonding::Bonding;
+use crate::core::exploration::{Explorer, Action};
+use crate::core::binding::Binding;
+use crate::core::saccade::{Gaze, pathway_name};
+use crate::core::consolidation::Round;
+use crate::core::sieve::{Sieve, resonance_of};
+use crate::core::textutil;
+use crate::core::sensory;
+use crate::core::startle::Startle;
+use crate::core::components::{self, ComponentStatus, Components};
+use crate::core::health::{self, Health, MemoryProbe};
+use crate::core::pacing::{self, Every, FrameClock, FrameStats, Phase, Watchdog};
+use crate::core::homeostat::Homeostat;
+use crate::core::repertoire::{self, Repertoire};
+use crate::core::checkin::{CheckIn, Knock};
+use crate::core::latency::{LatencyMonitor, Shed};
+use crate::core::thread::ConversationThread;
+use crate::core::volition::{self, Effect, Volition};
+use crate::core::standby::Standby;
+use crate::core::ledger;
+use crate::core::feeding::{Feeding, Food, Pantry};
+use crate::core::bus::{Bus, Event, SensoryEvent, ChemEvent, CortexEvent, MemoryEvent, ControlEvent};
+use crate::core::websocket::{ws_frame, WsClient, STREAM_SPECTRUM, OP_TEXT};
+use crate::core::web::{WebServer, WebTelemetry};
+use crate::core::broadcaster::{Broadcaster, BROADCAST_PERIOD};
+use crate::senses::ears::{self, AudioSpectrum, Utterance};
+use crate::senses::eyes::Retina;
+use crate::actuators::voice::{self, VoiceActuator};
+use crate::actuators::registry::{self as actuator, Registry};
+use crate::actuators::notes::NotesWriter;
+use crate::actuators::notify::{self, DesktopNotifier};
+use crate::core::agency::GoalType;
 use crate::senses::proprioception::{self, BodyStatus};
-use std::sync::atomic::{AtomicBool, Ordering};
+use crate::senses::transcript;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::os::unix::net::{UnixListener, UnixStream};
-use std::net::TcpListener;
 use chrono::{Local, Timelike}; // Chronoreception
-use std::io::{Read, Write};
+use std::io::Write;
 use std::fs;
-use std::hash::{Hash, Hasher};
-use std::collections::hash_map::DefaultHasher;
-
-#[derive(serde::Serialize, Clone, Default)]
-struct WebTelemetry {
-    adenosine: f32,
-    cortisol: f32,
-    dopamine: f32,
-    oxytocin: f32,
-    serotonin: f32,
-    loop_frequency: f32,
-    audio_spectrum: AudioSpectrum,
-    reservoir_activity: Vec<f32>,
-    current_state: String,
-    thoughts: Vec<String>,
-    trauma_state: String,
-    hebbian_events: u32,
-    reservoir_size: usize,
-    entropy: f32,
-    llm_activity: Vec<f32>,
-    top_activations: Vec<(String, f32)>, 
-    
-    // System Vitals
-    system_ram_gb: f32,
-    system_cpu_load: f32,
-    
-    // Neural Visualization
-    activations: Vec<f32>,
-    region_map: Vec<u8>,
-    neuron_positions: Vec<[f32; 3]>,
-    
-    // Genome Traits
-    curiosity: f32,
-    
-    // New Senses
-    visual_cortex: Vec<f32>, // 64x64 Grid
-    stress_tolerance: f32,
-    generation: u32,
+
+/// What the per-event handlers write to (and the config they read).
+struct Wiring<'a> {
+    config: &'a AlephConfig,
+    thoughts: &'a mpsc::Sender<Thought>,
+    memory: &'a mpsc::Sender<MemoryCommand>,
+    cortex: Option<&'a CortexSender>, // None = pre-verbal
+    bus: &'a Bus,
+}
+
+impl Wiring<'_> {
+    fn announce(&self, kind: EventKind, detail: serde_json::Value) {
+        self.bus.publish(Event::Landmark(Landmark::now(kind, detail)));
+    }
+}
+
+/// Who is around: the faces, the bonds, the last thread with each, when to check in,
+/// and the drives waiting for an answer.
+struct Company {
+    people: People,
+    bonding: Bonding,
+    checkin: CheckIn,
+    repertoire: Repertoire,
+    conversation: ConversationThread,
+}
+
+impl Company {
+    /// Someone said something: the check-in timer resets and a pending drive is answered.
+    fn contact(&mut self, thoughts: &mpsc::Sender<Thought>) {
+        self.checkin.contact(Instant::now());
+        if let Some(drive) = self.repertoire.heard(Instant::now()) {
+            let _ = thoughts.send(Thought::new(MindVoice::Agency, format!("🎭 REPERTOIRE: '{}' got an answer", drive))
+                .with_payload(serde_json::json!({ "event": "drive_answered", "drive": drive })));
+        }
+    }
+}
+
+/// SIGHUP, POST /genome/reload: new traits reach the trauma thresholds and the Planet's temperament.
+fn on_reload_genome(wire: &Wiring, daemon: &mut DaemonState, seed: &mut Genome, ego: &mut FractalReservoir, satellite: &mut Satellite, trauma_detector: &mut TraumaDetector) {
+    let applied = daemon.reload_genome(GENOME_FILE, seed, ego, satellite, wire.thoughts);
+    if !applied.is_empty() {
+        let phenotype = seed.phenotype();
+        trauma_detector.set_thresholds(phenotype.trauma);
+        if let Some(tx) = wire.cortex {
+            tx.command(CortexCommand::Temperament(phenotype.temperament));
+        }
+    }
+    wire.announce(EventKind::ManualOverride, serde_json::json!({ "command": "reload_genome", "applied": applied }));
+}
+
+/// POST /feed: eaten now; a data meal's text is digested by the hippocampus (remembered, not answered).
+fn on_feed(wire: &Wiring, feed: &Feeding, pantry: &mut Pantry, chem: &mut Neurotransmitters, daemon: &DaemonState, ego: &FractalReservoir) {
+    let meal = pantry.feed(feed, chem, Instant::now());
+    let _ = wire.thoughts.send(Thought::new(MindVoice::System, match feed.kind {
+            Food::Attention => format!("🍬 FED: attention {:.2} (+{:.3} dopamine, +{:.3} glucose, sensitivity {:.2})", feed.amount, meal.dopamine, meal.glucose, meal.sensitivity),
+            Food::Data => format!("🍞 FED: data {:.2} (digesting{})", feed.amount, if feed.text.is_some() { ", text to the hippocampus" } else { "" }),
+            Food::Rest => format!("🛏️ FED: rest {:.2} (sleep in {:.0}s)", feed.amount, meal.sleep_in_secs.unwrap_or(0.0)),
+        })
+        .with_payload(serde_json::json!({ "event": "feed", "kind": feed.kind.name(), "amount": feed.amount, "meal": meal })));
+    if let Some(text) = &feed.text {
+        for sentence in transcript::split_sentences(text) {
+            let _ = wire.memory.send(MemoryCommand::ProcessStimulus {
+                text: sentence,
+                entropy: daemon.current_entropy,
+                activity: ego.activity_signature(),
+                provenance: Provenance::Read { path: "feed".to_string() },
+                utterance: None,
+                memory_only: true,
+            });
+        }
+    }
+    wire.announce(EventKind::ManualOverride, serde_json::json!({ "command": "feed", "kind": feed.kind.name(), "amount": feed.amount }));
+}
+
+/// Sleep, poke, DND: the heartbeat handles them, the landmark records the override.
+fn on_control(wire: &Wiring, control: &ControlEvent, daemon: &mut DaemonState, chem: &mut Neurotransmitters, ego: &mut FractalReservoir, standby: &mut Standby) {
+    let command = match control {
+        ControlEvent::Sleep => Some(serde_json::json!({ "command": "sleep" })),
+        ControlEvent::Poke => Some(serde_json::json!({ "command": "poke" })),
+        ControlEvent::Privacy(on) if *on != daemon.privacy_mode => Some(serde_json::json!({ "command": "privacy", "on": on })),
+        _ => None,
+    };
+    daemon.handle_control(control, chem, ego, standby, wire.thoughts);
+    if let Some(detail) = command {
+        wire.announce(EventKind::ManualOverride, detail);
+    }
+}
+
+/// Typed text (TUI, dashboard): straight to the Planet, ahead of its own thoughts.
+fn on_stimulus(wire: &Wiring, text: &str, daemon: &mut DaemonState, chem: &mut Neurotransmitters, company: &mut Company, temperature_clamp: Option<f32>) {
+    company.contact(wire.thoughts);
+    let input_state = daemon.handle_stimulus(text, chem, temperature_clamp, wire.thoughts);
+    for felt in company.bonding.addressed(text, &company.people.names(), chem, Instant::now()) {
+        let _ = wire.thoughts.send(Thought::new(MindVoice::System, felt));
+    }
+    if let Some(tx) = wire.cortex {
+        tx.send(input_state, CortexPriority::User);
+    }
+}
+
+/// Whisper heard a sentence: a sensory perturbation (words to the input neurons, tone and
+/// meaning to the chemistry, the speaker to the bonds), then one engram per sentence. The
+/// text is NOT an instruction. Returns whether anything was heard.
+fn on_heard(wire: &Wiring, heard: &Utterance, daemon: &mut DaemonState, ego: &mut FractalReservoir, chem: &mut Neurotransmitters, lexicon: &mut Lexicon, company: &mut Company) -> bool {
+    let text = &heard.text;
+    // Transcribed just before DND was switched on: drop it unseen
+    if daemon.privacy_mode {
+        return false;
+    }
+    let sentences = transcript::split_sentences(text);
+    if sentences.is_empty() {
+        return false;
+    }
+    // Visible Log for User Feedback
+    let _ = wire.thoughts.send(Thought::new(MindVoice::System, if sentences.len() > 1 {
+        format!("🎤 Hearing: '{}' ({} sentences)", text, sentences.len())
+    } else {
+        format!("🎤 Hearing: '{}'", text)
+    }));
+
+    // Hash words to Input Neurons, one sentence at a time
+    daemon.word_echoes.schedule(&sentences, daemon.ticks, wire.config.ears.sentence_offset_ticks);
+    daemon.last_interaction_tick = daemon.ticks; // Reset boredom timer
+    company.contact(wire.thoughts);
+
+    // SEMANTIC PERTURBATION: Text -> Chemistry (NOT prompt)
+    let friction = chem.apply_semantic_perturbation(text, lexicon);
+
+    // PROSODIC PERTURBATION: how it was said, regardless of the words
+    daemon.last_prosody = heard.prosody;
+    for channel in sensory::prosody_channels(&daemon.sensory, heard.prosody.pitch_hz, heard.prosody.syllable_rate, heard.prosody.loudness) {
+        daemon.sensory_vector[channel] += 1.0;
+    }
+    let familiar = daemon.voice_profile.observe(heard.prosody.pitch_hz);
+    if let Some(felt) = chem.apply_prosody(&heard.prosody, familiar) {
+        let _ = wire.thoughts.send(Thought::new(MindVoice::Sensory, format!(
+            "🗣️ TONE: {} ({:.0}Hz, {:.1} syl/s{})", felt, heard.prosody.pitch_hz, heard.prosody.syllable_rate,
+            if familiar { ", familiar voice" } else { "" })));
+    }
+
+    // NAME LEARNING: who said it (introduction or known voice)
+    let mut speaker = None; // Remembered with the memory (provenance)
+    match company.people.observe(text, heard.prosody.pitch_hz, valence_of(chem)) {
+        Some(PersonEvent::Introduced(name)) => {
+            let _ = wire.thoughts.send(Thought::new(MindVoice::System, format!("👥 NAME LEARNED: {}", name)));
+            speaker = Some(name);
+        },
+        Some(PersonEvent::Recognized(name)) => {
+            let _ = wire.thoughts.send(Thought::new(MindVoice::Sensory, format!("👤 Voice recognized: {}", name)));
+            if let Some(felt) = company.bonding.heard_from(&name, chem, Instant::now()) {
+                let _ = wire.thoughts.send(Thought::new(MindVoice::System, felt));
+            }
+            speaker = Some(name);
+        },
+        None => {},
+    }
+    // OXYTOCIN: names heard + conversational turn-taking
+    for felt in company.bonding.addressed(text, &company.people.names(), chem, Instant::now()) {
+        let _ = wire.thoughts.send(Thought::new(MindVoice::System, felt));
+    }
+
+    // THE THREAD: a known voice back after a while -> what we last talked about
+    // (asked before this utterance is stored, so it isn't "last time")
+    if let Some((filter, top_k)) = speaker.as_deref().and_then(|name| company.conversation.heard(name, Instant::now(), unix_millis(std::time::SystemTime::now()) / 1000)) {
+        let _ = wire.memory.send(MemoryCommand::Recall { filter, top_k });
+    }
+
+    // Auditory cortex used = small growth
+    if friction > 0.05 && daemon.ticks % 120 == 0 {
+        ego.neurogenesis(1);
+    }
+
+    // Also store in memory (raw text, no labels): one engram per sentence,
+    // sharing an utterance id; only the last one goes on to the Planet
+    let utterance = (sentences.len() > 1).then(|| {
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(daemon.ticks)
+    });
+    let activity = ego.activity_signature(); // Replayed in Deep sleep
+    let last = sentences.len() - 1;
+    for (i, sentence) in sentences.into_iter().enumerate() {
+        let _ = wire.memory.send(MemoryCommand::ProcessStimulus {
+            text: sentence,
+            entropy: daemon.current_entropy,
+            activity: activity.clone(),
+            provenance: Provenance::Heard { speaker: speaker.clone() },
+            utterance,
+            memory_only: i < last,
+        });
+    }
+    true
+}
+
+/// Admin control commands join the bus like the SYS: stimuli did (handled next tick).
+fn control(bus: &Bus, event: ControlEvent) -> serde_json::Value {
+    let queued = format!("{:?}", event);
+    bus.publish(Event::Control(event));
+    serde_json::json!({ "queued": queued })
+}
+
+/// The TUI's socket: Hello to each new client, then every telemetry packet and event out;
+/// stimuli (through the bucket) and admin commands (authorized here, run by the loop) in.
+struct IpcServer {
+    listener: Option<UnixListener>,
+    telemetry: mpsc::Receiver<AlephPacket>,
+    stimulus: mpsc::Sender<String>,
+    limiter: Arc<Mutex<StimulusLimiter>>,
+    admin: mpsc::Sender<AdminRequest>,
+    admin_secret: String,
+    client_count: Arc<AtomicUsize>,
+}
+
+impl IpcServer {
+    fn spawn(self) {
+        thread::spawn(move || self.serve());
+    }
+
+    fn serve(self) {
+        let IpcServer {
+            listener: ipc_listener, telemetry: rx_telemetry, stimulus: tx_stimulus, limiter: limiter_ipc,
+            admin: tx_admin, admin_secret, client_count: ipc_client_count_thread,
+        } = self;
+        // Each client keeps its own LineBuffer (stimuli can arrive split across reads) and
+        // its own stimulus bucket, keyed by a per-connection id (a Unix socket has no peer address)
+        let mut clients: Vec<(UnixStream, LineBuffer, String)> = Vec::new();
+        let mut connections: u64 = 0;
+        // Admin requests the loop has not answered yet: (the client, command, reply, deadline)
+        let mut pending_admin: Vec<(UnixStream, String, mpsc::Receiver<Result<serde_json::Value, String>>, Instant)> = Vec::new();
+
+        loop {
+            // 1. Accept New Clients (TUI), Hello first
+            if let Some(Ok((mut stream, _))) = ipc_listener.as_ref().map(|l| l.accept()) {
+                let hello = format!("{}\n", serde_json::to_string(&AlephPacket::hello()).unwrap_or_default());
+                if stream.write_all(hello.as_bytes()).is_ok() {
+                    stream.set_nonblocking(true).ok();
+                    connections += 1;
+                    clients.push((stream, LineBuffer::new(MAX_STIMULUS_LINE), format!("ipc-{}", connections)));
+                }
+            }
+
+            // 2. Broadcast Telemetry (and the events queued between frames, in order)
+            while let Ok(packet) = rx_telemetry.try_recv() {
+                if let Ok(json) = serde_json::to_string(&packet) {
+                    let msg = format!("{}\n", json);
+                    clients.retain_mut(|(client, _, _)| {
+                        client.write_all(msg.as_bytes()).is_ok()
+                    });
+                }
+            }
+
+            // 3. Read Stimulus (Bidirectional)
+            // Iterate backwards to allow removal of dead clients
+            for i in (0..clients.len()).rev() {
+                let (client, lines, client_id) = &mut clients[i];
+                // Try reading
+                match ipc::read_packets(client, lines) {
+                    Ok(None) => {
+                        // EOF: a live non-blocking socket with nothing to say returns WouldBlock
+                        clients.remove(i);
+                    },
+                    Ok(Some(packets)) => {
+                        // Every line of the read, in order (partial lines wait in the buffer for their end)
+                        for packet in packets.into_iter().flatten() {
+                            match packet {
+                                AlephPacket::Stimulus { text, .. } => {
+                                    if let Admission::Accepted(text) = limiter_ipc.lock().unwrap().admit(client_id, &text) {
+                                        let _ = tx_stimulus.send(text);
+                                    }
+                                },
+                                AlephPacket::Admin { command, args, secret } => {
+                                    let parsed = if admin::authorized(&admin_secret, &secret) {
+                                        admin::parse(&command, &args)
+                                    } else if admin_secret.is_empty() {
+                                        Err("admin is closed ([admin] secret is not set)".to_string())
+                                    } else {
+                                        Err("wrong secret".to_string())
+                                    };
+                                    let reply = match parsed {
+                                        Ok(AdminCommand::Help) => Some(AlephPacket::AdminReply { command, ok: true, reply: admin::help() }),
+                                        Ok(parsed) => {
+                                            let (reply_tx, reply_rx) = mpsc::channel();
+                                            match (client.try_clone(), tx_admin.send(AdminRequest { command: parsed, reply_tx })) {
+                                                (Ok(stream), Ok(())) => {
+                                                    pending_admin.push((stream, command, reply_rx, Instant::now() + admin::REPLY_TIMEOUT));
+                                                    None
+                                                },
+                                                _ => Some(admin::error_reply(&command, "the loop is not listening")),
+                                            }
+                                        },
+                                        Err(e) => Some(admin::error_reply(&command, &e)),
+                                    };
+                                    if let Some(json) = reply.and_then(|r| serde_json::to_string(&r).ok()) {
+                                        let _ = client.write_all(format!("{}\n", json).as_bytes());
+                                    }
+                                },
+                                _ => {},
+                            }
+                        }
+                    },
+                    Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                        // No data, continue
+                    },
+                    Err(_) => {
+                        // Error, drop client
+                        clients.remove(i);
+                    }
+                }
+            }
+            ipc_client_count_thread.store(clients.len(), Ordering::Relaxed);
+
+            // 4. Admin replies from the loop (or a timeout)
+            pending_admin.retain_mut(|(stream, command, reply_rx, deadline)| {
+                let reply = match reply_rx.try_recv() {
+                    Ok(Ok(reply)) => AlephPacket::AdminReply { command: command.clone(), ok: true, reply },
+                    Ok(Err(e)) => admin::error_reply(command, &e),
+                    Err(mpsc::TryRecvError::Empty) if Instant::now() < *deadline => return true,
+                    Err(_) => admin::error_reply(command, "no answer from the loop"),
+                };
+                if let Ok(json) = serde_json::to_string(&reply) {
+                    let _ = stream.write_all(format!("{}\n", json).as_bytes());
+                }
+                false
+            });
+
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
 }
 
-pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
+pub fn run(audio: Option<ears::SensoryMode>) -> Result<()> {
     println!("🌟 ALEPH STAR SYSTEM ONLINE (Daemon Mode)");
+    let config = AlephConfig::load();
+    let metabolism = Metabolism::new(config.metabolism.clone());
+    pacing::clock_origin(); // Monotonic clock starts at boot (spectrum stamps, Hello)
     
     // Proprioception (System Monitor)
     let mut _sys = sysinfo::System::new_all();
@@ -74,21 +417,23 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
     // --- CHANNELS ---
     let (tx_thoughts, rx_thoughts) = mpsc::channel::<Thought>();
     
-    // --- 1.7 SENSORY STATE (Phase 2) ---
-    // Persistent buffer for sensory inputs (Audio/Vision) that decays over time
-    let mut current_sensory_vector: Vec<f32> = vec![0.0; 500];
-    
-    // --- 2. START THE LOOP ---
-    // Track last active interaction to trigger Spontaneous Thought
-    let mut last_interaction_tick: u64 = 0; 
-    let mut _ticks: u64 = 0;
     // --- 0. GENOME (The Seed) ---
     let mut seed = Genome::load()?;
     let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
         format!("🧬 GENOME LOADED: Gen {} | StressRes: {:.2}", seed.generation, seed.stress_tolerance)));
+    // LINEAGE: what this generation's traits became, for evolution analysis
+    if let Err(e) = seed.record_lineage(LINEAGE_FILE) {
+        let _ = tx_thoughts.send(Thought::new(MindVoice::Error, format!("⚠️ LINEAGE: not recorded ({})", e)));
+    }
+
+    // --- 0.5 RECOVERY (Newest intact checkpoint, if it beats the last clean save) ---
+    let recovered = crate::core::autosave::recover(&config.autosave, "reservoir.json");
 
     // --- 1. THE STAR (Biological Ground Truth) ---
-    let chemistry = Arc::new(Mutex::new(Neurotransmitters::new()));
+    let chemistry = Arc::new(Mutex::new(match &recovered {
+        Some(checkpoint) => checkpoint.chemistry.clone(),
+        None => Neurotransmitters::new(),
+    }));
     
     // GENESIS: Calculate Brain Size from Genome
     // Base 500 + (Generation * 10) + (Curiosity * 50) - (Paranoia * 20)
@@ -100,67 +445,164 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
     let birth_size = base_size + genetic_bonus + trait_bonus;
     
     // Reservoir (The Body's Neural Network) - Loads from disk OR Creates using birth_size
-    let mut ego = FractalReservoir::load(birth_size, 0.2);
+    let mut ego = match recovered {
+        Some(checkpoint) => checkpoint.reservoir,
+        None => FractalReservoir::load(birth_size, 0.2),
+    };
     ego.set_curiosity(seed.curiosity); // Genome -> Learning Rate
+    if let Some(map) = config.brain.receptive_fields.clone() {
+        let _ = tx_thoughts.send(Thought::new(MindVoice::System, "🗺️ Receptive fields: experimental map from config".to_string()));
+        for warning in ego.set_receptive_fields(map) {
+            let _ = tx_thoughts.send(Thought::new(MindVoice::Error, format!("⚠️ RECEPTIVE FIELD: {}", warning)));
+        }
+    }
+    if let Some(target) = config.brain.echo_target {
+        ego.set_echo_target(target);
+    }
+    if ego.echo_target() == EchoTarget::WholeBrain {
+        let _ = tx_thoughts.send(Thought::new(MindVoice::System, "🗺️ Echo: whole brain (legacy brain, or brain.echo_target)".to_string()));
+    }
+    if config.brain.input_channels > ego.input_channels() {
+        let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🔌 Input columns: {} -> {} (brain.input_channels)",
+            ego.input_channels(), config.brain.input_channels)));
+        ego.grow_inputs(config.brain.input_channels - ego.input_channels());
+    }
     
     // --- 1.4 LUCIFER PROTOCOL (Trauma Detection) ---
-    let mut trauma_detector = TraumaDetector::new();
+    let mut trauma_detector = TraumaDetector::new(seed.phenotype().trauma); // Thresholds from stress_tolerance
     
     // --- 1.4.1 NEOCORTEX (Structural Observer) ---
     let mut neocortex = Neocortex::new();
     
     // --- 1.5 THE SATELLITE (Observer) ---
-    let satellite = Satellite::new(seed.paranoia, seed.refractive_index); 
+    let mut satellite = Satellite::new(seed.paranoia, seed.refractive_index); 
 
     // --- 1.6 AGENCY (Goal System) ---
     let mut agent = crate::core::agency::Agency::new();
-    let mut interaction_count: u64 = 0; // Track successful interactions
     let mut gate = ExpressionGate::new();
+
+    // --- 1.6.1 ACTUATORS (What it may do, and how often: config [actuators]) ---
+    // Without a local voice, what it says waits here for the next WebSocket frame (`speech`)
+    let speech_outbox: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let actuators = Arc::new(Mutex::new(Registry::new(&config.actuators)));
+    {
+        let mut registry = actuators.lock().unwrap();
+        registry.register(Box::new(if config.runtime.local_voice {
+            VoiceActuator::local(tx_thoughts.clone())
+        } else {
+            VoiceActuator::dashboard(tx_thoughts.clone(), speech_outbox.clone())
+        }));
+        registry.register(Box::new(NotesWriter::new(&config.actuators.notes_dir)));
+        registry.register(Box::new(DesktopNotifier));
+        for capability in config.actuators.whitelist.iter().filter(|c| !registry.allows(c)) {
+            println!("⚠️ ACTUATORS: '{}' is whitelisted but nothing provides it.", capability);
+        }
+    }
     
     // Hardware Proprioception
     let (tx_body, rx_body) = mpsc::channel::<BodyStatus>();
     proprioception::spawn_monitor(tx_body);
-    let mut last_body_state = BodyStatus { cpu_usage: 0.0, ram_usage: 0.0 };
 
     // --- 1.6 SENSES (Ears) ---
     // Channels for Audio
-    let (tx_audio_text, rx_audio_text) = mpsc::channel::<String>();
+    let (tx_audio_text, rx_audio_text) = mpsc::channel::<Utterance>();
     let (tx_spectrum, rx_spectrum) = mpsc::channel::<AudioSpectrum>();
     let (tx_word_embedding, rx_word_embedding) = mpsc::channel::<Vec<f32>>();
     
     // WebSocket Audio channel (browser mic → backend ears)
     let (ws_audio_tx, ws_audio_rx) = mpsc::channel::<Vec<f32>>();
     let ws_audio_tx = Arc::new(Mutex::new(ws_audio_tx));
-    let ws_audio_tx_server = ws_audio_tx.clone();
     
-    // Detect Sensory Mode
-    let sensory_mode = if headless {
-        ears::SensoryMode::Headless
-    } else if let Some(ref path) = listen_path {
-        ears::SensoryMode::File(path.clone())
-    } else {
-        // Try local mic, fallback to WebSocket if no device
-        match {
-            use cpal::traits::HostTrait;
-            cpal::default_host().default_input_device()
-        } {
-            Some(_) => ears::SensoryMode::Mic,
-            None => {
-                println!("⚠️ No microphone detected. Falling back to WebSocket Audio Mode.");
-                ears::SensoryMode::WebSocket
+    // Detect Sensory Mode (unless the command line chose one: --headless, --listen, --synthetic)
+    let sensory_mode = match (audio, config.runtime.audio.as_str()) {
+        (Some(mode), _) => mode,
+        (None, "websocket") => ears::SensoryMode::WebSocket,
+        (None, "headless") => ears::SensoryMode::Headless,
+        (None, "mic") => ears::SensoryMode::Mic,
+        (None, other) => {
+            if other != "auto" {
+                println!("⚠️ Unknown runtime.audio '{}' (auto, mic, websocket, headless). Autodetecting.", other);
+            }
+            // Try local mic, fallback to WebSocket if no device
+            match {
+                use cpal::traits::HostTrait;
+                cpal::default_host().default_input_device()
+            } {
+                Some(_) => ears::SensoryMode::Mic,
+                None => {
+                    println!("⚠️ No microphone detected. Falling back to WebSocket Audio Mode.");
+                    ears::SensoryMode::WebSocket
+                }
             }
         }
     };
     
     let needs_ws_audio = matches!(sensory_mode, ears::SensoryMode::WebSocket);
     
-    // Spawn Audio Listener with detected mode
-    let _ears = ears::AudioListener::new(
-        tx_thoughts.clone(), tx_audio_text, tx_spectrum, tx_word_embedding,
-        sensory_mode, 
-        if needs_ws_audio { Some(ws_audio_rx) } else { None }
-    ).expect("Failed to spawn Ears");
-    let mut last_spectrum = AudioSpectrum::default();
+    // DO NOT DISTURB: shared with the ears, owned here so it survives audio reinits
+    let privacy = Arc::new(AtomicBool::new(config.ears.dnd_default));
+
+    // THE CAPABILITY PANEL: only the reservoir is mandatory, everything else reports how it came up
+    let boot = Instant::now();
+    let components = Components::default();
+    components.report(components::RESERVOIR, ComponentStatus::Ready);
+
+    // The Planet pools its echo at the size published here every frame
+    let echo_size = Arc::new(AtomicUsize::new(ego.current_size()));
+
+    // THE SPLIT ([cortexd] enabled): Whisper and the Planet live in a supervised child
+    // process; a crash there is aphasia, not death (see cortex/cortexd.rs)
+    let mut cortexd_link = None;
+    let mut ws_audio_rx = Some(ws_audio_rx);
+    let mut ear_channels = Some((tx_audio_text, tx_spectrum, tx_word_embedding));
+    if let Some((heard, spectrum, word_embedding)) = ear_channels.take_if(|_| config.cortexd.enabled) {
+        let ear_link = cortexd::EarChannels {
+            heard, spectrum, word_embedding,
+            browser_audio: if needs_ws_audio { ws_audio_rx.take() } else { None },
+            privacy: privacy.clone(),
+        };
+        let link = std::env::current_exe().map_err(anyhow::Error::from).and_then(|exe| cortexd::supervise(
+            config.cortexd.clone(), exe, cortexd::mode_args(&sensory_mode), Duration::from_secs_f32(config.cortex.staleness_secs),
+            tx_thoughts.clone(), ear_link, echo_size.clone(), components.clone()));
+        match link {
+            Ok(link) => {
+                println!("🧠 Split mode: Planet + Whisper in a supervised cortexd child ({})", config.cortexd.socket);
+                cortexd_link = Some(link);
+            },
+            Err(e) => {
+                let _ = tx_thoughts.send(Thought::new(MindVoice::Error, format!("⚠️ cortexd unavailable: {}. Deaf and pre-verbal.", e)));
+                components.report(components::EARS, ComponentStatus::Offline(e.to_string()));
+                components.report(components::CORTEX, ComponentStatus::Offline(e.to_string()));
+            }
+        }
+    }
+
+    // Spawn Audio Listener with detected mode (a broken device = deaf, not dead)
+    let _ears = match ear_channels {
+        Some((tx_audio_text, tx_spectrum, tx_word_embedding)) => match ears::AudioListener::new(
+            tx_thoughts.clone(), tx_audio_text, tx_spectrum, tx_word_embedding,
+            sensory_mode,
+            if needs_ws_audio { ws_audio_rx.take() } else { None },
+            config.ears.clone(),
+            privacy.clone(),
+            metabolism.clone()
+        ) {
+            Ok(listener) => {
+                components.report(components::EARS, listener.status());
+                listener
+            },
+            Err(e) => {
+                let _ = tx_thoughts.send(Thought::new(MindVoice::Error, format!("🔇 EARS OFFLINE: {}. Running deaf.", e)));
+                components.report(components::EARS, ComponentStatus::Offline(e.to_string()));
+                ears::AudioListener::headless()
+            }
+        },
+        None => ears::AudioListener::headless(), // The child hears
+    };
+    let ear_stats = match &cortexd_link {
+        Some(link) => link.ear_stats.clone(),
+        None => _ears.stats(),
+    };
 
     let (tx_vision, rx_vision) = mpsc::channel::<Vec<f32>>();
     let _eyes = crate::senses::eyes::Eyes::new(tx_vision);
@@ -175,530 +617,410 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
 
     // --- 1.8 THE NERVOUS SYSTEM (IPC Server) ---
     // Remove old socket if exists
-    let socket_path = "/tmp/aleph.sock";
+    let socket_path = config.runtime.ipc_socket.as_str();
     let _ = fs::remove_file(socket_path);
     
-    let listener = UnixListener::bind(socket_path).expect("Failed to bind IPC socket");
-    listener.set_nonblocking(true).expect("Failed to set nonblocking");
-    
-    println!("🔌 IPC Nervous System Active: {}", socket_path);
+    // No socket = no TUI, the web dashboard and the loop still run
+    let ipc_listener = match UnixListener::bind(socket_path).and_then(|l| l.set_nonblocking(true).map(|_| l)) {
+        Ok(listener) => {
+            println!("🔌 IPC Nervous System Active: {}", socket_path);
+            components.report(components::IPC, ComponentStatus::Ready);
+            Some(listener)
+        },
+        Err(e) => {
+            println!("⚠️ IPC socket {} unavailable: {}. Running without TUI access.", socket_path, e);
+            components.report(components::IPC, ComponentStatus::Offline(e.to_string()));
+            None
+        }
+    };
 
     // Channels for IPC
     let (tx_telemetry, rx_telemetry) = mpsc::channel::<AlephPacket>();
-    let (tx_stimulus, rx_stimulus) = mpsc::channel::<String>(); // Input from TUI/Web
+    let (tx_stimulus, rx_stimulus) = mpsc::channel::<String>(); // Input from TUI/Web (external, through the membrane)
+    let (tx_control, rx_control) = mpsc::channel::<ControlEvent>(); // The daemon's own controls (trusted, no bucket)
+    // THE WORKBENCH: Admin packets checked and parsed by the IPC thread, executed by the loop
+    let (tx_admin, rx_admin) = mpsc::channel::<AdminRequest>();
+    let admin_secret = config.admin.secret.clone();
+
+    // SIGHUP -> Genome hot-reload (a control, not a stimulus)
+    let tx_reload = tx_control.clone();
+    let mut hangups = signal_hook::iterator::Signals::new([signal_hook::consts::SIGHUP])?;
+    thread::spawn(move || {
+        for _ in hangups.forever() {
+            if tx_reload.send(ControlEvent::ReloadGenome).is_err() { break; }
+        }
+    });
     
     // SHARED STATE FOR WEB DASHBOARD
-    let web_state = Arc::new(Mutex::new(WebTelemetry::default()));
-    let web_state_server = web_state.clone();
-    let tx_stimulus_web = tx_stimulus.clone();
+    let web_state = Arc::new(Mutex::new(WebTelemetry { voices: config.voices.hints(), ..Default::default() }));
+    let tx_stimulus_visitor = tx_stimulus.clone();
+
+    // THE SKIN: Every external stimulus passes a per-client token bucket
+    let limiter = Arc::new(Mutex::new(StimulusLimiter::new(config.stimulus.clone())));
+
+    // THE CHRONICLE: thought history for the dashboard + daily session log (recorder attached to the bus below)
+    let timeline = Timeline::new(config.timeline.clone());
+
+    // THE DIARY: hourly buckets, the morning summary, GET /stats/daily (attached to the bus below)
+    let diary = Diary::new(config.stats.clone());
+
+    // THE MINUTES: every gate and membrane decision, GET /gate/history, /membrane/history (+ CSV)
+    let (gate_log, membrane_log) = decisions::logs(&config.decisions);
+
+    // THE SOUNDCHECK: boot warm-up results, GET /selftest (collector attached to the bus below)
+    let soundcheck = SelfTest::new(config.selftest.clone());
+
+    // THE FACES: people who introduced themselves (people.json)
+    let people = People::load(config.people.clone());
+
+    // THE CARTOGRAPHER: GET /connectome asks the loop for a copy of the brain to stream
+    let (tx_connectome, rx_connectome) = mpsc::channel::<mpsc::Sender<FractalReservoir>>();
+
+    // GET /neuron/{id}: the loop answers with the neuron, by stable ID (Err = deceased or never born)
+    let (tx_neuron, rx_neuron) = mpsc::channel::<(u64, mpsc::Sender<Result<NeuronReport, NeuronLookup>>)>();
+
+    // GET /memory/stats: the loop forwards the reply channel to the hippocampus
+    let (tx_memory_stats, rx_memory_stats) = mpsc::channel::<mpsc::Sender<MemoryStats>>();
+
+    // GET /consciousness: the loop forwards the reply channel to the Planet (history ring)
+    let (tx_consciousness, rx_consciousness) = mpsc::channel::<mpsc::Sender<Vec<Segment>>>();
+
+    // THE SEISMOGRAPH: last ~10s of region-pooled activity, one column per tick
+    let raster = Raster::new();
+
+    // THE WATERFALL: last ~5s of the 64-band audio spectrum, one row per FFT hop
+    let spectrogram = Spectrogram::new();
 
     // --- 1.9 SPAWN HTTP + WEBSOCKET SERVER (Web Dashboard) ---
     // Track connected WebSocket clients for broadcasting
-    let ws_clients: Arc<Mutex<Vec<std::net::TcpStream>>> = Arc::new(Mutex::new(Vec::new()));
-    let ws_clients_server = ws_clients.clone();
-    
-    thread::spawn(move || {
-        let listener = TcpListener::bind("0.0.0.0:3030").expect("Failed to bind Web Port 3030");
-        listener.set_nonblocking(false).ok();
-        println!("🌍 Web Dashboard Active: http://localhost:3030");
-
-        for stream in listener.incoming() {
-            if let Ok(mut stream) = stream {
-                let tx_stimulus = tx_stimulus_web.clone();
-                let state_ref = web_state_server.clone();
-                let ws_list = ws_clients_server.clone();
-                let ws_audio_tx = ws_audio_tx_server.clone();
-                
-                thread::spawn(move || {
-                    let mut buffer = [0; 8192];
-                    if let Ok(n) = stream.read(&mut buffer) {
-                        let request = String::from_utf8_lossy(&buffer[..n]);
-                        
-                        // CHECK FOR WEBSOCKET UPGRADE
-                        if request.len() > 0 {
-                             // println!("📝 Raw Request: {:?}", request.lines().next()); // Log first line only
-                        }
-                        
-                        let request_lower = request.to_lowercase();
-                        if request_lower.contains("upgrade: websocket") {
-                            println!("🔗 Incoming WebSocket Upgrade Request...");
-                            // Extract Sec-WebSocket-Key
-                            if let Some(key_line) = request.lines().find(|l| l.to_lowercase().starts_with("sec-websocket-key:")) {
-                                let key = key_line.split(':').nth(1).unwrap_or("").trim();
-                                
-                                // WebSocket accept key = base64(SHA1(key + GUID))
-                                let magic = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
-                                let combined = format!("{}{}", key, magic);
-                                
-                                use sha1::Digest;
-                                let mut hasher = sha1::Sha1::new();
-                                hasher.update(combined.as_bytes());
-                                let hash = hasher.finalize();
-                                
-                                use base64::Engine;
-                                let accept = base64::engine::general_purpose::STANDARD.encode(&hash);
-                                
-                                println!("🔑 WS Handshake: Key='{}' -> Accept='{}'", key, accept);
-
-                                let response = format!(
-                                    "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n", 
-                                    accept
-                                );
-                                if let Err(e) = stream.write(response.as_bytes()) {
-                                    println!("❌ WS Write Error: {}", e);
-                                    return;
-                                }
-                                if let Err(e) = stream.flush() {
-                                    println!("❌ WS Flush Error: {}", e);
-                                    return;
-                                }
-                                
-                                // Register this stream for broadcast
-                                match stream.try_clone() {
-                                    Ok(clone) => {
-                                        let mut list = ws_list.lock().unwrap();
-                                        list.push(clone);
-                                        println!("✅ Added client to broadcast list. Total clients: {}", list.len());
-                                    },
-                                    Err(e) => println!("❌ Failed to clone stream for broadcast: {}", e),
-                                }
-                                println!("✅ WebSocket Client Connected! (Buffer check passed)");
-                                
-                                // Keep connection alive reading frames (for stimulus)
-                                if let Err(e) = stream.set_nonblocking(false) {
-                                    println!("⚠️ WS NonBlocking Error: {}", e);
-                                }
+    let ws_clients: Arc<Mutex<Vec<WsClient>>> = Arc::new(Mutex::new(Vec::new()));
+    // THE STETHOSCOPE: vitals written by the loop, read by GET /healthz
+    let health = Health::default();
+    WebServer {
+        state: web_state.clone(),
+        ws_clients: ws_clients.clone(),
+        ws_audio_tx: ws_audio_tx.clone(),
+        tx_stimulus: tx_stimulus.clone(),
+        tx_control: tx_control.clone(),
+        limiter: limiter.clone(),
+        timeline: timeline.clone(),
+        diary: diary.clone(),
+        gate_log: gate_log.clone(),
+        membrane_log: membrane_log.clone(),
+        soundcheck: soundcheck.clone(),
+        people: people.clone(),
+        raster: raster.clone(),
+        spectrogram: spectrogram.clone(),
+        health: health.clone(),
+        health_config: config.health.clone(),
+        components: components.clone(),
+        consciousness_config: config.consciousness.clone(),
+        admin_secret: admin_secret.clone(),
+        tx_connectome, tx_neuron, tx_memory_stats, tx_consciousness,
+        tx_admin: tx_admin.clone(), // POST/DELETE /lesion go through the workbench too
+    }.spawn(config.runtime.web_bind.clone());
 
-                                // Robust WebSocket Reader
-                                loop {
-                                    let mut headers = [0u8; 2];
-                                    match stream.read(&mut headers) {
-                                        Ok(0) => {
-                                            println!("👋 WS Disconnected (Client Closed)"); 
-                                            break; 
-                                        }, 
-                                        Ok(n) if n < 2 => {
-                                             println!("❌ WS Partial Read ({}), Dropping.", n);
-                                             break;
-                                        },
-                                        Err(e) => {
-                                            if e.kind() != std::io::ErrorKind::WouldBlock {
-                                                println!("❌ WS Read Error: {}", e);
-                                            }
-                                            break; 
-                                        },
-                                        Ok(_) => {} // Continue parsing
-                                    }
+    // --- 1.9.1 WEBSOCKET BROADCASTER (Push telemetry to all connected WS clients) ---
+    // Events (AlephPacket::Event) wait here for the next broadcast and go out as their own frames
+    let event_outbox: Arc<Mutex<Vec<Landmark>>> = Arc::new(Mutex::new(Vec::new()));
+    Broadcaster::new(web_state.clone(), ws_clients.clone(), speech_outbox.clone(), event_outbox.clone(), raster.clone(), spectrogram.clone())
+        .spawn(BROADCAST_PERIOD);
+
+    // --- 1.10 IPC SERVER (Legacy TUI support) ---
+    let ipc_client_count = Arc::new(AtomicUsize::new(0));
+    IpcServer {
+        listener: ipc_listener,
+        telemetry: rx_telemetry,
+        stimulus: tx_stimulus,
+        limiter: limiter.clone(),
+        admin: tx_admin,
+        admin_secret,
+        client_count: ipc_client_count.clone(),
+    }.spawn();
 
-                                    let opcode = headers[0] & 0x0F;
-                                    let masked = headers[1] & 0x80 != 0;
-                                    let mut payload_len = (headers[1] & 127) as usize;
 
-                                    if opcode == 0x8 { 
-                                         // Close frame
-                                         break; 
-                                    }
+    // --- 2. THE PLANET (Narrative Engine) ---
+    // Launched in background thread (or already orbiting in the cortexd child)
+    let planet = match cortexd_link {
+        Some(link) => Ok((link.cortex_tx, link.cortex_rx)),
+        None if config.cortexd.enabled => Err(anyhow::anyhow!("no cortexd")),
+        None => Planet::spawn(tx_thoughts.clone(), config.cortex.clone(), ModelsConfig::load(), echo_size.clone(), components.clone()),
+    };
+    let (mut tx_cortex, rx_cortex_out) = match planet {
+        Ok((tx, rx)) => {
+             let _ = tx_thoughts.send(Thought::new(MindVoice::System, "🪐 Planet (Cortex) Orbiting.".to_string()));
+             tx.command(CortexCommand::Temperament(seed.phenotype().temperament)); // Curiosity and paranoia
+             (Some(tx), Some(rx))
+        },
+        Err(e) => {
+            let _ = tx_thoughts.send(Thought::new(MindVoice::Error, format!("❌ Planet Collapse: {}", e)));
+            components.report(components::CORTEX, ComponentStatus::Offline(e.to_string()));
+            components.report(components::SEMANTIC_FIELD, ComponentStatus::Offline("no cortex".to_string()));
+            (None, None)
+        }
+    };
 
-                                    if payload_len == 126 {
-                                        let mut ext = [0u8; 2];
-                                        if stream.read_exact(&mut ext).is_err() { break; }
-                                        payload_len = u16::from_be_bytes(ext) as usize;
-                                    } else if payload_len == 127 {
-                                        let mut ext = [0u8; 8];
-                                        if stream.read_exact(&mut ext).is_err() { break; }
-                                        payload_len = u64::from_be_bytes(ext) as usize;
-                                    }
+    // --- 3. MEMORY (Holographic Seed) ---
+    let (tx_mem, rx_mem_out, rx_mem_log) = Hippocampus::spawn(config.novelty.clone(), config.consolidation.clone(), config.retrieval.clone(), components.clone())?;
 
-                                    // Check safety limit before allocating (256KB for audio chunks)
-                                    if payload_len > 262144 { 
-                                        println!("⚠️ WS Payload too large ({}b), dropping connection.", payload_len);
-                                        break; 
-                                    }
+    // --- 4. THE BLOODSTREAM (Event Bus) ---
+    // Subscribers first (events published before subscribe() are not replayed)
+    let bus = Bus::new();
+    let rx_events = bus.subscribe(); // The Pulse (physics loop)
 
-                                    let mask_key = if masked {
-                                        let mut key = [0u8; 4];
-                                        if stream.read_exact(&mut key).is_err() { break; }
-                                        Some(key)
-                                    } else { None };
+    // THE CHRONICLE (independent subscriber): ring + daily NDJSON
+    timeline.attach(&bus);
 
-                                    let mut payload = vec![0u8; payload_len];
-                                    if stream.read_exact(&mut payload).is_err() { break; }
+    // THE LEDGER (independent subscriber): epiphanies, trauma, sleep stages, growth and pruning to [ledger] path
+    let _ = ledger::attach(&bus, config.ledger.clone());
 
-                                    if let Some(key) = mask_key {
-                                        for i in 0..payload.len() {
-                                            payload[i] ^= key[i % 4];
-                                        }
-                                    }
+    // THE DIARY (independent subscriber): hourly buckets, summary thought at [stats] summary_time
+    diary.attach(&bus, tx_thoughts.clone(), tx_cortex.clone(), components.clone());
 
-                                    if opcode == 0x1 { // Text frame
-                                        if let Ok(text) = String::from_utf8(payload) {
-                                            if let Ok(cmd) = serde_json::from_str::<serde_json::Value>(&text) {
-                                                if let Some(stimulus) = cmd.get("stimulus").and_then(|v| v.as_str()) {
-                                                    let _ = tx_stimulus.send(stimulus.to_string());
-                                                } else if let Some(action) = cmd.get("action").and_then(|v| v.as_str()) {
-                                                    match action {
-                                                        "poke" => { let _ = tx_stimulus.send("SYS:POKE".to_string()); },
-                                                        "sleep" => { let _ = tx_stimulus.send("SYS:SLEEP".to_string()); },
-                                                        "dream" => { let _ = tx_stimulus.send("SYS:DREAM".to_string()); },
-                                                        _ => {}
-                                                    }
-                                                }
-                                            }
-                                        }
-                                    }
-                                    // Binary frame (opcode 0x2) = Browser Audio PCM
-                                    else if opcode == 0x2 {
-                                        // Decode f32 PCM samples from browser
-                                        // Browser sends Float32Array as raw bytes (4 bytes per sample, little-endian)
-                                        if payload.len() >= 4 && payload.len() % 4 == 0 {
-                                            let samples: Vec<f32> = payload.chunks_exact(4)
-                                                .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
-                                                .collect();
-                                            if let Ok(tx) = ws_audio_tx.lock() {
-                                                let _ = tx.send(samples);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                            return;
-                        }
-                        
-                        // STANDARD HTTP HANDLERS (Fallback)
-                        let path = request.lines().next().unwrap_or("").split_whitespace().nth(1).unwrap_or("/");
-                        
-                        // CORS Preflight
-                        if request.starts_with("OPTIONS") {
-                            let headers = "HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\nAccess-Control-Allow-Methods: POST, GET, OPTIONS\r\nAccess-Control-Allow-Headers: Content-Type\r\n\r\n";
-                            let _ = stream.write(headers.as_bytes());
-                        } 
-                        // SERVE ASSETS (Vite Build)
-                        else if path.starts_with("/assets/") {
-                            // Sanitize path (basic)
-                            let safe_path = path.replace("..", ""); 
-                            let file_path = format!("web{}", safe_path);
-                            
-                            if let Ok(content) = fs::read(&file_path) {
-                                let content_type = if file_path.ends_with(".css") { "text/css" }
-                                                  else if file_path.ends_with(".js") { "application/javascript" }
-                                                  else if file_path.ends_with(".svg") { "image/svg+xml" }
-                                                  else { "application/octet-stream" };
-                                                  
-                                let headers = format!("HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n", content_type, content.len());
-                                let _ = stream.write(headers.as_bytes());
-                                let _ = stream.write(&content);
-                            } else {
-                                let _ = stream.write("HTTP/1.1 404 Not Found\r\n\r\n".as_bytes());
-                            }
-                        }
-                        // SERVE DASHBOARD (DISABLED - Legacy)
-                        else if path == "/" || path == "/index.html" {
-                            let response = "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\nALEPH Nervous System Active. Use React Client.\r\n";
-                            let _ = stream.write(response.as_bytes());
-                        }
-                        // API ENDPOINTS
-                        else if path == "/telemetry" {
-                            let headers = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\n\r\n";
-                            let json = {
-                                let state = state_ref.lock().unwrap();
-                                serde_json::to_string(&*state).unwrap_or("{}".to_string())
-                            };
-                            let response = format!("{}{}", headers, json);
-                            let _ = stream.write(response.as_bytes());
-                        }
-                        else if path == "/stimulus" && request.starts_with("POST") {
-                             if let Some(body_start) = request.find("\r\n\r\n") {
-                                let body = &request[body_start+4..];
-                                if let Some(text_start) = body.find("\"text\":\"") {
-                                    let rest = &body[text_start+8..];
-                                    if let Some(text_end) = rest.find("\"") {
-                                        let text = &rest[..text_end];
-                                        let _ = tx_stimulus.send(text.to_string());
-                                    }
-                                }
-                            }
-                            let headers = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\n\r\n";
-                            let _ = stream.write(headers.as_bytes());
-                        }
-                        // COMMAND SHORTCUTS
-                        else if path == "/sleep" && request.starts_with("POST") {
-                             let _ = tx_stimulus.send("SYS:SLEEP".to_string());
-                             let _ = stream.write("HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\n\r\n".as_bytes());
-                        } 
-                        else if path == "/poke" && request.starts_with("POST") {
-                             let _ = tx_stimulus.send("SYS:POKE".to_string());
-                             let _ = stream.write("HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\n\r\n".as_bytes());
-                        } 
-                        else {
-                             let _ = stream.write("HTTP/1.1 404 Not Found\r\n\r\n".as_bytes());
-                        }
-                    }
-                });
+    // THE SOUNDCHECK (independent subscriber, first minutes only): warm-up stages, slow-hardware warnings
+    soundcheck.attach(&bus, tx_thoughts.clone());
+
+    // THE VISITOR (independent subscriber, soak runs only): talks, listens for Vocal replies, reports, ends the run
+    interlocutor::attach(&bus, tx_stimulus_visitor, tx_thoughts.clone(), running.clone(), config.interlocutor.clone());
+
+    // THE TAP ON THE SHOULDER (independent subscriber): desktop popups, coalesced
+    notify::attach(&bus, config.notifications.clone(), actuators.clone());
+
+    // THE HERALD (independent subscriber): each event once to the IPC clients and the WebSocket
+    let rx_landmarks = bus.subscribe();
+    let tx_event_packets = tx_telemetry.clone();
+    thread::spawn(move || {
+        for event in rx_landmarks {
+            if let Event::Landmark(landmark) = event {
+                if let Ok(mut pending) = event_outbox.lock() {
+                    pending.push(landmark.clone());
+                }
+                let _ = tx_event_packets.send(AlephPacket::from(landmark));
             }
         }
     });
+    let announce = |kind: EventKind, detail: serde_json::Value| bus.publish(Event::Landmark(Landmark::now(kind, detail)));
 
-    // --- 1.9.1 WEBSOCKET BROADCASTER (Push telemetry to all connected WS clients) ---
-    let ws_broadcast_state = web_state.clone();
-    let ws_clients_broadcast = ws_clients.clone();
+    // THE OSCILLOSCOPE (independent subscriber): every cochlea frame to the WebSocket
+    // clients that asked for it ("spectrum_on"), at capture rate, not at the 12Hz telemetry
+    let rx_spectra = bus.subscribe();
+    let ws_clients_spectrum = ws_clients.clone();
     thread::spawn(move || {
-        let mut tick_count = 0;
-        loop {
-            thread::sleep(Duration::from_millis(83)); // ~12Hz broadcast
-            
-            let (json, _state_summary) = {
-                let state = ws_broadcast_state.lock().unwrap();
-                
-                // Sparse Updates: Filter neurons > 0.005 and round to 3 decimals
-                let sparse_reservoir: Vec<(usize, f32)> = state.reservoir_activity.iter().enumerate()
-                    .filter(|(_, &v)| v > 0.005)
-                    .map(|(i, &v)| (i, (v * 1000.0).round() / 1000.0))
-                    .collect();
-
-                // Sanitize Activations (Replace NaN/Inf with 0.0)
-                let clean_activations: Vec<f32> = state.activations.iter()
-                    .map(|&v| if v.is_finite() { v } else { 0.0 })
-                    .collect();
-
-                let json_obj = serde_json::json!({
-                    "dopamine": if state.dopamine.is_finite() { (state.dopamine * 1000.0).round() / 1000.0 } else { 0.0 },
-                    "cortisol": if state.cortisol.is_finite() { (state.cortisol * 1000.0).round() / 1000.0 } else { 0.0 },
-                    "adenosine": if state.adenosine.is_finite() { (state.adenosine * 1000.0).round() / 1000.0 } else { 0.0 },
-                    "oxytocin": if state.oxytocin.is_finite() { (state.oxytocin * 1000.0).round() / 1000.0 } else { 0.0 },
-                    "serotonin": if state.serotonin.is_finite() { (state.serotonin * 1000.0).round() / 1000.0 } else { 0.0 },
-                    "entropy": if state.entropy.is_finite() { (state.entropy * 1000.0).round() / 1000.0 } else { 0.0 },
-                    "loop_frequency": if state.loop_frequency.is_finite() { (state.loop_frequency * 10.0).round() / 10.0 } else { 0.0 },
-                    "reservoir_activity": sparse_reservoir, 
-                    "current_state": state.current_state,
-                    "thoughts": state.thoughts,
-                    "trauma_state": state.trauma_state,
-                    "hebbian_events": state.hebbian_events,
-                    "reservoir_size": state.reservoir_size,
-                    "top_activations": state.top_activations,
-                    "llm_activity": state.llm_activity,
-                    "system_ram_gb": state.system_ram_gb,
-                    "system_cpu_load": state.system_cpu_load,
-                    "activations": clean_activations,
-                    "region_map": state.region_map,
-                    "neuron_positions": state.neuron_positions,
-                    "curiosity": state.curiosity,
-                    "stress_tolerance": state.stress_tolerance,
-                    "generation": state.generation
-                });
-                
-                let s = json_obj.to_string();
-                let summary = format!("Payload: {} bytes (Activations: {})", s.len(), sparse_reservoir.len());
-                (s, summary)
-            };
-            
-            
-            // Periodically send PING (Opcode 0x9) to keep connection alive
-            let ping_frame = vec![0x89, 0x00]; // FIN + PING, Len 0
-            let send_ping = tick_count % 60 == 0;
-            tick_count += 1;
-            
-            // Build WebSocket text frame
-            let payload = json.as_bytes();
-            let mut frame: Vec<u8> = Vec::new();
-            frame.push(0x81); // FIN + Text opcode
-            
-            if payload.len() < 126 {
-                frame.push(payload.len() as u8);
-            } else if payload.len() < 65536 {
-                frame.push(126);
-                frame.extend_from_slice(&(payload.len() as u16).to_be_bytes());
-            } else {
-                frame.push(127);
-                frame.extend_from_slice(&(payload.len() as u64).to_be_bytes());
+        for event in rx_spectra {
+            let Event::Sensory(SensoryEvent::Spectrum(spec)) = event else { continue };
+            let clients = ws_clients_spectrum.lock().unwrap();
+            if !clients.iter().any(|c| c.subscribed(STREAM_SPECTRUM)) {
+                continue;
             }
-            frame.extend_from_slice(payload);
-
-            // Broadcast to all connected clients
-            let mut clients = ws_clients_broadcast.lock().unwrap();
-            let client_count = clients.len();
-            
-            // Log payload size occasionally (every 60 ticks / 5s)
-            if tick_count % 60 == 0 {
-                 println!("📉 Telemetry Payload: {} bytes | Clients: {}", json.len(), client_count);
+            let json = serde_json::json!({ "spectrum": {
+                "rms": spec.rms, "bass": spec.bass, "mids": spec.mids, "highs": spec.highs,
+                "is_voice": spec.is_voice, "captured_us": spec.captured_us,
+            }}).to_string();
+            let frame = Arc::new(ws_frame(OP_TEXT, json.as_bytes()));
+            for client in clients.iter().filter(|c| c.subscribed(STREAM_SPECTRUM)) {
+                let _ = client.outbox().offer(frame.clone()); // Best-effort (not counted as a stall): a dropped frame is a gap
             }
-
-            clients.retain_mut(|client| {
-                // Send Ping (Keep Alive) - Only occasionally
-                if send_ping {
-                    if let Err(_) = client.write_all(&ping_frame) {
-                        return false;
-                    }
-                }
-                
-                // Send Data
-                match client.write_all(&frame) {
-                    Ok(_) => true,
-                    Err(e) => {
-                         // Only log actual errors, not just disconnects
-                         if e.kind() != std::io::ErrorKind::BrokenPipe {
-                             println!("⚠️ WebSocket Write Error: {}", e);
-                         }
-                         false
-                    }
-                }
-            });
-
         }
     });
 
-
-
-    // Spawn IPC Broadcaster Thread (Legacy TUI support)
+    // THOUGHT LOGGER (independent subscriber): stdout + mouth
+    let rx_log = bus.subscribe();
+    let voices = config.voices.clone();
+    let actuators_voice = actuators.clone();
     thread::spawn(move || {
-        let mut clients: Vec<UnixStream> = Vec::new();
-        
-        loop {
-            // 1. Accept New Clients (TUI)
-            if let Ok((stream, _)) = listener.accept() {
-                stream.set_nonblocking(true).ok();
-                clients.push(stream);
-            }
-
-            // 2. Broadcast Telemetry
-            if let Ok(packet) = rx_telemetry.try_recv() {
-                if let Ok(json) = serde_json::to_string(&packet) {
-                    let msg = format!("{}\n", json);
-                    clients.retain_mut(|client| {
-                        client.write_all(msg.as_bytes()).is_ok()
-                    });
+        for event in rx_log {
+            if let Event::Thought(thought) = event {
+                println!("{}", thought.line());
+
+                // VOICE ACTUATOR (Mouth): only voices routed to it, and only through the
+                // whitelist (Piper, or the dashboard without a local voice)
+                if voices.route(thought.voice).vocalize {
+                    if let Ok(mut registry) = actuators_voice.lock() {
+                        let _ = registry.request(actuator::VOICE, &thought.text, thought.id, Instant::now());
+                    }
                 }
             }
-
-            // 3. Read Stimulus (Bidirectional)
-            // Iterate backwards to allow removal of dead clients
-            for i in (0..clients.len()).rev() {
-                 let mut buf = [0u8; 1024];
-                 // Try reading
-                 match clients[i].read(&mut buf) {
-                     Ok(0) => {
-                         // Connection closed (EOF) - remove client? 
-                         // With non-blocking, 0 usually means closed if using standard Read trait, 
-                         // but for Tcp/UnixStream in non-blocking, it requires careful handling.
-                         // Let's assume it's fine for now, usually read returns WouldBlock error if alive but empty.
-                     },
-                     Ok(n) => {
-                         let s = String::from_utf8_lossy(&buf[..n]);
-                         // It might be multiple packets or partial. Assuming line based for now.
-                         for line in s.lines() {
-                             if let Ok(AlephPacket::Stimulus { text, .. }) = serde_json::from_str::<AlephPacket>(line) {
-                                 let _ = tx_stimulus.send(text);
-                             }
-                         }
-                     },
-                     Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
-                         // No data, continue
-                     },
-                     Err(_) => {
-                         // Error, drop client
-                         clients.remove(i);
-                     }
-                 }
-            }
-            
-            thread::sleep(Duration::from_millis(50));
         }
     });
- 
-    
-    // --- 2. THE PLANET (Narrative Engine) ---
-    // Launched in background thread
-    let (tx_cortex, rx_cortex_out) = match Planet::spawn(tx_thoughts.clone()) {
-        Ok((tx, rx)) => {
-             let _ = tx_thoughts.send(Thought::new(MindVoice::System, "🪐 Planet (Cortex) Orbiting.".to_string()));
-             (Some(tx), Some(rx))
-        },
-        Err(e) => {
-            let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("❌ Planet Collapse: {}", e)));
-            (None, None)
-        }
-    };
 
-    // --- 3. MEMORY (Holographic Seed) ---
-    let (tx_mem, rx_mem_out, rx_mem_log) = Hippocampus::spawn()
-        .expect("Hippocampus Failed");
+    // Legacy producers -> Bus (senses still speak mpsc; the loop only listens to the bus)
+    bus.bridge("thoughts", rx_thoughts, |t| Some(Event::Thought(t)));
+    bus.bridge("stimulus", rx_stimulus, stimulus::route);
+    bus.bridge("control", rx_control, |event| Some(Event::Control(event)));
+    bus.bridge("ears", rx_audio_text, |heard| Some(Event::Sensory(SensoryEvent::Heard(heard))));
+    bus.bridge("spectrum", rx_spectrum, |mut spec| {
+        spec.received_us = pacing::monotonic_us(); // THE STOPWATCH: daemon-receive
+        Some(Event::Sensory(SensoryEvent::Spectrum(spec)))
+    });
+    bus.bridge("wernicke", rx_word_embedding, |v| Some(Event::Sensory(SensoryEvent::WordEmbedding(Arc::new(v)))));
+    bus.bridge("eyes", rx_vision, |grid| Some(Event::Sensory(SensoryEvent::Vision(Arc::new(grid)))));
+    bus.bridge("body", rx_body, |status| Some(Event::Sensory(SensoryEvent::Body(status))));
+    bus.bridge("hippocampus", rx_mem_out, |out| Some(Event::Memory(MemoryEvent::Recalled(Arc::new(out)))));
+    bus.bridge("hippocampus_log", rx_mem_log, |log| Some(Event::Memory(MemoryEvent::Log(log))));
+    if let Some(rx) = rx_cortex_out {
+        bus.bridge("cortex", rx, |out| Some(Event::Cortex(CortexEvent::Output(Arc::new(out)))));
+    }
 
     // --- DAEMON LOOP (The Pulse) ---
     let mut last_tick = Instant::now();
-    #[allow(unused_assignments)]
-    let mut current_entropy = 0.0;
-    
-    // VARIABLE METABOLISM (Heart Rate)
-    // ALEPH's subjective time perception.
-    // 60Hz = Normal, 120Hz = Hyperfocus (High Dopamine), 24Hz = Bored/Tired (High Adenosine)
-    let mut current_hz: f32 = 60.0;
-    let hz_base = 60.0;
-    
-    // SLEEP STATE (Persistent)
-    let mut is_dreaming = false;
-    
-    // Session Stats for Mutation
-    let mut _session_stress_accum = 0.0;
-    let mut _session_novelty_accum = 0.0;
-    let mut ticks = 0;
 
-    // Telemetry Buffer (So TUI doesn't flicker empty)
-    let mut telemetry_history: VecDeque<String> = VecDeque::with_capacity(30);
+    // THE HEARTBEAT: sleep/wake, heart rate, boredom timer, session stats, short-term stream
+    let mut daemon = DaemonState::new();
+    daemon.privacy_mode = config.ears.dnd_default;
+    // THE PATCH BAY: the standard senses are already laid out; registering them
+    // again only widens a brain whose input columns are narrower than the vector
+    for (name, len) in sensory::STANDARD {
+        daemon.register_sense(name, len, &mut ego);
+    }
+    let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🎛️ Sensory channels: {}", daemon.sensory.ranges().iter()
+            .map(|r| format!("{} {}..{}", r.name, r.start, r.end())).collect::<Vec<_>>().join(", ")))
+        .with_payload(serde_json::json!({ "event": "sensory_registry", "channels": daemon.sensory.ranges(), "capacity": daemon.sensory.len() })));
+    let mut autosaver = Autosaver::spawn(config.autosave.clone());
+    let mut attention = Attention::new(config.attention.clone(), daemon.sensory_vector.len());
+    let mut lexicon = Lexicon::load();
+    let mut explorer = Explorer::new(config.exploration.clone());
+    let mut homeostat = Homeostat::new(config.homeostat.clone());
+    let mut belt = Belt::new(config.pressure.clone()); // Memory-pressure degradation
+    let mut complexity = Complexity::new(config.complexity.clone()); // Multi-scale complexity
+    // Who is around: faces, bonds, the last thread with each, check-ins, drives behind spontaneous speech
+    let mut company = Company {
+        people,
+        bonding: Bonding::new(),
+        checkin: CheckIn::new(config.checkin.clone()),
+        repertoire: Repertoire::load(config.repertoire.clone()),
+        conversation: ConversationThread::new(config.thread.clone()),
+    };
+    let mut uncertain_memory: Option<String> = None; // The repertoire's {memory} slot
+    let mut latency = LatencyMonitor::new(config.latency.clone()); // Sound -> reservoir latency budget
+    let mut volition = Volition::load(config.volition.clone()); // The Planet asking its body for things
+    let mut standby = Standby::new(config.standby.clone()); // Heavy surgery on a copy of the brain
+    let mut pantry = Pantry::new(config.feeding.clone()); // Being fed (POST /feed)
+    let mut binding = Binding::new(config.binding.clone());
+    let mut startle = Startle::new(config.startle.clone());
+    let mut gaze = Gaze::new(config.saccade.clone());
+    let mut sieve = Sieve::new(config.memory_gate.clone()); // Self-memories only
+    let mut memory_stats_rx: Option<mpsc::Receiver<MemoryStats>> = None; // Pending GetStats (telemetry)
+    let mut last_replay = Instant::now(); // Dream replay pacing (Deep sleep only)
+    let mut retina = Retina::new();
+    let mut energy_budget = EnergyBudget::default();
+    // Events that woke a deep-idle wait early (processed on the next tick)
+    let mut carried: Vec<Event> = Vec::new();
+    // Frame time and the rate-invariant timers (these used to be `ticks % N`, i.e. assumed 60Hz)
+    let mut frame_clock = FrameClock::new();
+    let watchdog = Watchdog::spawn(config.watchdog.clone(), tx_thoughts.clone());
+    let mut epiphany_timer = Every::new(100.0 / 60.0);
+    let mut growth_timer = Every::new(5.0);
+    let mut edge_growth_timer = Every::new(10.0);
+    let mut idle_pulse_timer = Every::new(0.2);
+    let mut agency_window = Every::new(1.0);
+    let mut memory_probe = MemoryProbe::default();
+    let mut startup_announced = false;
+
+    // THE SOUNDCHECK: tick benchmark on a copy of the brain (the cortex and the ears warm up in their threads)
+    if config.selftest.bench_ticks > 0 {
+        let per_tick = selftest::bench_ticks(&ego, config.selftest.bench_ticks);
+        let _ = tx_thoughts.send(selftest::stage_thought(selftest::RESERVOIR_TICK, per_tick,
+            Ok(format!("mean of {} ticks, {} neurons", config.selftest.bench_ticks, ego.current_size()))));
+    }
 
     while running.load(Ordering::SeqCst) {
         let loop_start = Instant::now();
         let delta_time = last_tick.elapsed().as_secs_f32();
         last_tick = Instant::now();
-
-        // SHARED STATE UPDATE (Web Dashboard)
-        if ticks % 5 == 0 { // Update web state at ~12Hz
-            if let Ok(mut state) = web_state.lock() {
-                let chem = chemistry.lock().unwrap();
-                state.adenosine = chem.adenosine;
-                state.cortisol = chem.cortisol;
-                state.dopamine = chem.dopamine;
-                state.oxytocin = chem.serotonin; // MIRROR TUI: Serotonin (Stability) = Trust (Oxytocin)
-                state.loop_frequency = current_hz;
-                state.serotonin = chem.serotonin;
-                state.audio_spectrum = last_spectrum.clone();
-                // Send reservoir activation for visualization
-                state.reservoir_activity = ego.get_activity_snapshot();
-                state.reservoir_size = ego.current_size();
-                state.entropy = current_entropy;
-                state.trauma_state = format!("{}", trauma_detector.state);
-                state.hebbian_events = ego.drain_hebbian_events();
-                state.region_map = ego.get_region_map();
-                state.neuron_positions = ego.get_positions().clone();
-                // Current Stream State (Full history for UI)
-                state.thoughts = telemetry_history.iter().cloned().collect();
-                if let Some(last) = telemetry_history.back() {
-                     state.current_state = last.clone();
-                }
+        frame_clock.begin(loop_start, delta_time);
+
+        // EVENTS OF THIS TICK (single drain point; every section below reads from here)
+        let mut events: Vec<Event> = std::mem::take(&mut carried);
+        events.extend(rx_events.try_iter());
+        let mut bored = false; // Set by the Neocortex, acted on in G.
+
+        // PRE-VERBAL: the cortex failed to load (no GGUF/tokenizer) -> reservoir + memory
+        // only; nothing is sent to it again, so there is no echo and no resonance
+        if tx_cortex.is_some() && daemon.ticks % 60 == 0 {
+            if let Some(ComponentStatus::Offline(reason)) = components.status(components::CORTEX) {
+                tx_cortex = None;
+                let _ = tx_thoughts.send(Thought::new(MindVoice::System,
+                    format!("🍼 Pre-verbal mode: {}. Reservoir and memory only, no resonance.", reason)));
             }
         }
 
+        // RESTARTS: components that came back (cortexd respawned, the cortex reloaded...)
+        for (component, was) in components.drain_restarts() {
+            announce(EventKind::ComponentRestart, serde_json::json!({ "component": component, "was": was }));
+        }
+
         // A. PHYSICS CHECK (The Star)
         {
             // Proprioception Update
-            while let Ok(status) = rx_body.try_recv() {
-                last_body_state = status;
+            for event in &events {
+                if let Event::Sensory(SensoryEvent::Body(status)) = event {
+                    daemon.last_body_state = status.clone();
+                }
+            }
+
+            // MEMORY PRESSURE: tighten (or loosen) one notch of the belt
+            if let Some(step) = belt.observe(&daemon.last_body_state, Instant::now()) {
+                let ram = daemon.last_body_state.ram_usage * 100.0;
+                let note = match step {
+                    Step::Tighten(Notch::CapGrowth) => {
+                        ego.set_growth_ceiling(Some(ego.current_size()));
+                        format!("growth capped at {} neurons", ego.current_size())
+                    },
+                    Step::Tighten(Notch::Consolidate) => {
+                        let _ = tx_mem.send(MemoryCommand::EnterDeepSleep); // Rounds until the backlog is empty
+                        "consolidation round forced".to_string()
+                    },
+                    Step::Tighten(Notch::ShrinkContext) => {
+                        if let Some(tx) = &tx_cortex {
+                            tx.command(CortexCommand::HistoryBudget(Some(config.pressure.history_tokens)));
+                        }
+                        format!("Planet history cut to {} tokens", config.pressure.history_tokens)
+                    },
+                    Step::Tighten(_) => {
+                        let snapshot = chemistry.lock().unwrap().clone();
+                        autosaver.save(&ego, snapshot, daemon.ticks, &tx_thoughts);
+                        let _ = tx_mem.send(MemoryCommand::ForceSave);
+                        control(&bus, ControlEvent::Sleep);
+                        "checkpoint saved, forced sleep".to_string()
+                    },
+                    Step::Loosen(Notch::ShrinkContext) => {
+                        if let Some(tx) = &tx_cortex {
+                            tx.command(CortexCommand::HistoryBudget(None));
+                        }
+                        "Planet history restored".to_string()
+                    },
+                    Step::Loosen(Notch::CapGrowth) => {
+                        ego.set_growth_ceiling(None);
+                        "growth resumes".to_string()
+                    },
+                    Step::Loosen(notch) => format!("{:?} lifted", notch),
+                };
+                let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🪢 PRESSURE: RAM {:.0}% -> {:?} ({})", ram, belt.notch(), note))
+                    .with_payload(serde_json::json!({ "event": "memory_pressure", "step": format!("{:?}", step), "pressure": belt.state() })));
             }
             
             // Audio Physics (Spectrum Update)
             // 0. SENSORY INPUT (Non-Blocking)
-            // Drain the audio buffer to prevent lag/latency accumulation.
+            // Process every packet of the tick to prevent lag/latency accumulation.
             let mut audio_energy = 0.0; // Initialize here, will be updated by the last packet in the loop
-            while let Ok(spec) = rx_spectrum.try_recv() {
+            for event in &events {
+                let Event::Sensory(SensoryEvent::Spectrum(spec)) = event else { continue };
                 // Update UI state (only last packet needed for viz, but we process all for physics)
                 // Optimization: Only update lock on last packet? 
                 // For now, simple update.
 
                 // Sum energy for chemical impact
                 audio_energy = spec.bass + spec.mids + spec.highs; // Use current `spec` for energy
-                last_spectrum = spec.clone(); // Update last_spectrum for web_state and other uses
+                daemon.last_spectrum = spec.clone(); // Update last_spectrum for web_state and other uses
+                company.repertoire.listen(spec, Instant::now());
+                if spec.is_voice {
+                    company.checkin.voice(Instant::now());
+                }
+                spectrogram.push(&spec.frequency_embedding);
 
                 // CRITICAL: Immediate Update for UI Visualization
                 // We can skip lock if we aren't at the right tick, but let's keep it robust.
-                if ticks % 2 == 0 { 
+                if daemon.ticks % 2 == 0 { 
                     if let Ok(mut state) = web_state.lock() {
                         state.audio_spectrum = spec.clone();
                     }
@@ -707,88 +1029,116 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
                 // DIRECT SENSORY PROJECTION (Phase 5)
                 // Inject raw audio spectrogram into the Reservoir
                 if !spec.frequency_embedding.is_empty() {
-                    ego.inject_embedding(&spec.frequency_embedding, crate::core::reservoir::NeuronRegion::Auditory);
+                    let mut embedding = spec.frequency_embedding.clone();
+                    attention.gate_audio(&mut embedding); // Stress narrows what is heard
+                    gaze.attend(Pathway::Auditory, &mut embedding, Instant::now()); // Fixated or not
+                    ego.inject_embedding(&embedding, crate::core::reservoir::NeuronRegion::Auditory);
+                    if let Some(shed) = latency.record(spec, pacing::monotonic_us()) {
+                        // Over budget: fewer FFTs and no exposure tracking until it recovers
+                        // (split mode: the child's cochlea is out of reach, exposure still pauses)
+                        _ears.set_fft_stride(if latency.shedding() { config.latency.fft_stride } else { 1 });
+                        ego.set_exposure_paused(daemon.deep_idle || latency.shedding());
+                        let _ = tx_thoughts.send(Thought::new(MindVoice::System, shed.line())
+                            .with_payload(serde_json::json!({ "event": "latency_shed", "shedding": matches!(shed, Shed::Start { .. }) })));
+                    }
+                    binding.hear(&ego, &embedding, spec.bass.max(spec.mids).max(spec.highs), Instant::now());
                 }
                 
-                // STARTLE REFLEX (Cortisol)
-                // Check intensity of THIS packet
-                let intensity = spec.bass.max(spec.mids);
-                if intensity > 0.5 { // Threshold lowered (Was 0.6)
+                // STARTLE REFLEX (Cortisol): surprise over the running peak, refractory, habituating
+                let reflex = startle.hear(spec.bass.max(spec.mids), &spec.frequency_embedding, Instant::now());
+                if reflex.cortisol > 0.0 {
                     let mut chem = chemistry.lock().unwrap();
-                    chem.cortisol += intensity * 0.05; 
-                    if intensity > 0.8 { // Threshold lowered (Was 0.95)
-                        chem.cortisol += 0.2;
-                         let _ = tx_thoughts.send(Thought::new(MindVoice::System, "💥 AUDITORY SHOCK!".to_string()));
-                    }
+                    chem.cortisol = (chem.cortisol + reflex.cortisol).min(1.0);
+                }
+                if let Some(shock) = reflex.shock {
+                    let _ = tx_thoughts.send(Thought::new(MindVoice::System, if shock.similar > 0 {
+                        format!("💥 AUDITORY SHOCK! (+{:.2} cortisol, heard like it {}x)", shock.cortisol, shock.similar)
+                    } else {
+                        format!("💥 AUDITORY SHOCK! (+{:.2} cortisol)", shock.cortisol)
+                    }));
                 }
             }
 
             // 2. VISUAL SENSATION (Phase 7 - Occipital Lobe)
             // Now receiving 64x64 Grid (4096 floats)
-            if let Ok(visual_grid) = rx_vision.try_recv() {
+            for event in &events {
+                 let Event::Sensory(SensoryEvent::Vision(visual_grid)) = event else { continue };
                  // 1. Update Web State for Visualization
-                 if ticks % 4 == 0 { // ~15Hz update for UI
+                 if daemon.ticks % 4 == 0 { // ~15Hz update for UI
                     if let Ok(mut state) = web_state.lock() {
-                        state.visual_cortex = visual_grid.clone();
+                        state.visual_cortex = visual_grid.to_vec();
                     }
                  }
 
-                 // 2. Downsample for Reservoir Embedding (4096 -> 64)
-                 // Simple strided sampling: take every 64th value
-                 // Better: Average pooling? Let's do strided for speed first.
-                 let mut embedding = Vec::with_capacity(64);
-                 let stride = visual_grid.len() / 64;
-                 for i in 0..64 {
-                     if let Some(val) = visual_grid.get(i * stride) {
-                         embedding.push(*val);
-                     } else {
-                         embedding.push(0.0);
+                 // 2. Retina: 8x8 pooled form + motion (4096 -> 128, see senses/eyes.rs)
+                 if let Some(percept) = retina.see(visual_grid) {
+                     let mut embedding = percept.embedding();
+                     gaze.attend(Pathway::Visual, &mut embedding, Instant::now());
+                     ego.inject_embedding(&embedding, crate::core::reservoir::NeuronRegion::Visual);
+                     // Every frame, so a tenth: with the x0.9 decay it settles at the motion itself
+                     let motion: Vec<f32> = percept.motion.iter().map(|m| m * 0.1).collect();
+                     daemon.sensory.add(&mut daemon.sensory_vector, sensory::VISION_MOTION, &motion);
+                     let motion = percept.motion.iter().sum::<f32>() / percept.motion.len().max(1) as f32;
+                     binding.see(&ego, &embedding, motion, Instant::now());
+                 }
+
+                 // EXPLORATION (Look): this is the fresh frame that was asked for
+                 if explorer.awaiting(Action::Look) {
+                     let novelty = explorer.look(visual_grid);
+                     let mut chem = chemistry.lock().unwrap();
+                     if let Some(msg) = explorer.resolve(novelty, &mut chem) {
+                         let _ = tx_thoughts.send(Thought::new(MindVoice::System, msg));
                      }
                  }
-                 
-                 ego.inject_embedding(&embedding, crate::core::reservoir::NeuronRegion::Visual);
             }
 
             // === WORD EMBEDDING PATHWAY (Phase 2: Wernicke's Area) ===
             // Whisper text → hash embedding → Semantic region
             // Latency: ~50-200ms (Whisper inference time)
             // This is SLOWER than raw FFT (~5ms) but FASTER than full LLM (~500-2000ms)
-            while let Ok(word_vec) = rx_word_embedding.try_recv() {
+            for event in &events {
+                let Event::Sensory(SensoryEvent::WordEmbedding(word_vec)) = event else { continue };
+                let mut word_vec = word_vec.to_vec();
+                gaze.attend(Pathway::Semantic, &mut word_vec, Instant::now());
                 ego.inject_embedding(&word_vec, crate::core::reservoir::NeuronRegion::Semantic);
                 let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
                     format!("🧠 WORD EMBED → Semantic ({} dims)", word_vec.len())));
             }
 
             let mut chem = chemistry.lock().unwrap();
-            
-            // SENSE: CHRONORECEPTION (Circadian Perception)
-            let now = Local::now();
-            let h = now.hour();
-            if h >= 23 || h < 7 { 
-                // Darkness sensed -> Melatonin rises
-                chem.adenosine = (chem.adenosine + 0.00002).min(1.0); 
-            } else {
-                // Light sensed -> Wakefulness (But accumulation of fatigue happens naturally in chemistry.tick)
-                // We should NOT aggressively zero it out here, otherwise the tick() accumulation is negated.
-                // chem.adenosine = (chem.adenosine - 0.00002).max(0.0); <-- REMOVED
-                
-                // Instead, maybe a very slight recovery if it's morning? 
-                // Or just do nothing and let chemistry.tick handle the logic.
-                if h < 10 {
-                     // Morning boost clears fog
-                     chem.adenosine = (chem.adenosine - 0.0001).max(0.0);
-                }
+
+            // CROSS-MODAL BINDING: a sound and a visual change close in time wire together
+            binding.bind(&mut ego, chem.dopamine, Instant::now());
+
+            // SACCADES: when senses compete, the gaze moves (dopamine makes it restless)
+            if let Some(focus) = gaze.update(chem.dopamine, Instant::now()) {
+                let _ = tx_thoughts.send(Thought::new(MindVoice::Sensory, format!("👁️ attention → {}", pathway_name(focus))));
             }
             
-
-            if is_dreaming {
+            if daemon.is_dreaming {
                 // Theta Waves: Inject low-amplitude random noise to keep reservoir pulsing (Dreaming)
                 use rand::Rng;
-                let mut rng = rand::thread_rng();
-                audio_energy = rng.gen_range(0.05..0.15); // Artificial "Dream" input
+                let mut rng = rand::rng();
+                audio_energy = rng.random_range(0.05..0.15); // Artificial "Dream" input
                 
                 // Force calm during sleep
                 chem.cortisol = 0.0;
+
+                // DREAM REPLAY (Deep stage): ask the hippocampus for a strong memory to re-live
+                if config.replay.enabled
+                    && daemon.sleep_stage(chem.adenosine) == SleepStage::Deep
+                    && last_replay.elapsed().as_secs_f32() >= config.replay.every_secs {
+                    last_replay = Instant::now();
+                    let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::Replay { candidates: config.replay.candidates });
+                }
+            }
+
+            // CONSOLIDATION: the hippocampus consolidates its backlog on the way into Deep sleep
+            if let Some((from, to)) = daemon.stage_transition(chem.adenosine) {
+                if to == SleepStage::Deep {
+                    let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::EnterDeepSleep);
+                }
+                announce(EventKind::SleepStage, serde_json::json!({ "from": from, "to": to }));
             }
             
             // CHEMICAL HOMEOSTASIS (Gradual Decay — organic, not binary)
@@ -799,97 +1149,58 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
             // 0.9994^60 ≈ 0.965 per second → halflife ~20 seconds
             chem.cortisol *= 0.9994;
             
-            // AUDIO -> CHEMISTRY
-            // Noise Stress: ONLY VERY LOUD audio raises Cortisol (Threshold 1.5)
-            if audio_energy > 1.5 {
-                 // Stress
-                 let stress_factor = (audio_energy - 1.5) * 0.05; 
-                 chem.cortisol = (chem.cortisol + stress_factor).min(1.0);
-                 
-                 // Wake
-                 let wake_factor = audio_energy * 0.02;
-                 chem.adenosine = (chem.adenosine - wake_factor).max(0.0);
-            } else if audio_energy < 0.5 {
-                 // SILENCE HEALS (Active Relaxation)
-                 // If quiet, cortisol drops faster than natural decay
-                 chem.cortisol = (chem.cortisol - 0.005).max(0.0);
-            }
-            
-            // Music interest (Dopamine) - Needs SIGNIFICANT rhythm, not just noise
-            if audio_energy > 0.6 && audio_energy < 1.4 {
-                  chem.dopamine = (chem.dopamine + 0.002).min(1.0); // Very small gain
-            }
+            // AUDIO -> CHEMISTRY (loud stresses, silence heals, music interests)
+            chem.hear(audio_energy);
             
             // Map Hardware -> Biology
             // CPU Load (0-100) -> Metabolism/HeartRate
             // RAM Load (0.0-1.0) -> Brain Fog
-            let cpu_load = last_body_state.cpu_usage; 
-            let ram_load = last_body_state.ram_usage;
+            let cpu_load = daemon.last_body_state.cpu_usage; 
+            let ram_load = daemon.last_body_state.ram_usage;
 
             // Update Biological Ground Truth from Hardware
             chem.update_from_hardware(cpu_load, ram_load, 1.0);
             
-            // CHRONORECEPTION (Phase 2)
-            // Bind Biology to Local Time (Circadian Rhythm)
-            if ticks % 600 == 0 { // Every ~10 seconds
-                 let now = Local::now();
-                 let hour = now.hour();
-                 
-                 // Circadian Pressure on Adenosine
-                 let circadian_pressure = if hour >= 23 || hour < 6 {
-                     0.005 // Night: Strong sleep pressure (+0.03/min)
-                 } else if hour >= 20 {
-                     0.002 // Evening: Wind down
-                 } else if hour >= 6 && hour < 9 {
-                     -0.005 // Morning: Cortisol spike / Waking up
-                 } else {
-                     -0.001 // Day: Maintenance (fighting fatigue)
-                 };
-                 
-                 chem.adenosine = (chem.adenosine + circadian_pressure).clamp(0.0, 1.0);
-            }
+            // CHRONORECEPTION: Bind Biology to Local Time (Circadian Rhythm)
+            daemon.sense_time(&mut chem, Local::now().hour(), delta_time);
 
             // Star burns fuel & Ticks Reservoir (Physics)
-            
-            // 1. Construct Sensory Input Vector (The Cortex "hears" and "feels")
-            let mut input_signal = nalgebra::DVector::zeros(ego.current_size());
-            
-            // Map Audio Spectrum to Input Layer (Distributed)
-            // We amplify signals because raw RMS is often low (0.01 - 0.1)
-            let n_count = input_signal.len();
-            if n_count > 0 {
-                let bass_sig = last_spectrum.bass * 8.0; // Heavy Bass Impact
-                let mids_sig = last_spectrum.mids * 4.0;
-                let high_sig = last_spectrum.highs * 3.0;
-
-                for i in 0..n_count {
-                    // Distribute across the cortex using primes to avoid patterns
-                    if i % 5 == 0 { input_signal[i] += bass_sig; }
-                    else if i % 11 == 0 { input_signal[i] += mids_sig; }
-                    else if i % 17 == 0 { input_signal[i] += high_sig; }
-                }
+            // 1. Construct Sensory Input Vector (The Cortex "hears" and "feels"): the spectrum
+            // plus the sensory buffer, so sound and concept co-occur for Hebbian learning
+            let input_signal = daemon.sensory_input(ego.current_size(), &chem, &mut attention, delta_time);
+
+            // THE UNDERSTUDY: a finished standby copy is swapped in between two ticks
+            if let Some((line, swap)) = standby.poll(&mut ego) {
+                let _ = tx_thoughts.send(Thought::new(MindVoice::Dream, line)
+                    .with_payload(serde_json::json!({ "event": "standby_swap", "swap": swap })));
             }
-            
-            // We use chemistry to modulate the reservoir's plasticity
-            // We use chemistry to modulate the reservoir's plasticity
-            // Pass delta_time directly to ensure time-invariance
-            
-            // MIX SENSORY INPUT (Phase 2)
-            // Combine Cortex Echo (Thinking) + Sensory Buffer (Hearing)
-            // This allows Co-occurrence Hebbian Learning
-            if input_signal.len() >= 500 && current_sensory_vector.len() >= 500 {
-                 for i in 0..500 {
-                     input_signal[i] += current_sensory_vector[i]; // Add sensation to thought
-                 }
-            }
-            
-            let entropy_output = ego.tick(input_signal.as_slice(), 
+
+            let tick_start = Instant::now();
+            let entropy_legacy = ego.tick(input_signal.as_slice(), 
                                           chem.dopamine, 
                                           chem.adenosine, 
                                           chem.cortisol,
                                           delta_time);
+            metabolism.report_tick(tick_start.elapsed());
+
+            // THE CALIPERS: complexity over time; drives the entropy couplings if configured
+            let measured = complexity.observe(&ego.last_activity);
+            let entropy_output = if config.complexity.drive_couplings { measured.composite } else { entropy_legacy };
+
+            let remap = raster.needs_remap(daemon.ticks, ego.current_size()).then(|| ego.region_map().to_vec());
+            raster.push(&ego.last_activity, remap.as_deref());
             
-            chem.tick(entropy_output, cpu_load, is_dreaming, 0.0, ego.current_size(), delta_time);
+            chem.tick(entropy_output, cpu_load, daemon.is_dreaming, 0.0, ego.current_size(), delta_time);
+
+            // HOMEOSTAT (minutes scale): pull the long-window entropy toward the genome's setpoint
+            if let Some(modulation) = homeostat.observe(entropy_output, seed.entropy_setpoint, delta_time, daemon.is_dreaming) {
+                ego.modulate(modulation);
+            }
+
+            // METABOLIC SETTLEMENT (1Hz): all reported work becomes adenosine / glucose here
+            if let Some(budget) = metabolism.settle(&mut chem, daemon.is_dreaming) {
+                energy_budget = budget;
+            }
             
             // HEBBIAN LEARNING (Phase 4.1 + Phase 2)
             // 1. Recurrent Hebbian (Internal Structure)
@@ -899,18 +1210,21 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
             // Learn to associate Audio with Concept
             let input_hebb_count = ego.hebbian_input_update(input_signal.as_slice(), chem.dopamine);
             
-            if (hebb_count > 0 || input_hebb_count > 0) && ticks % 300 == 0 {
+            if (hebb_count > 0 || input_hebb_count > 0) && daemon.ticks % 300 == 0 {
                 let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
                     format!("🧠 HEBBIAN: {} internal / {} sensory connections strengthened", hebb_count, input_hebb_count)));
             }
 
             // REWARD AS STRUCTURE (Epiphany)
             // If Dopamine is critical (>0.9), trigger structural lock-in (LTP)
-            if chem.dopamine > 0.9 && ticks % 100 == 0 {
+            let epiphany_due = epiphany_timer.tick(delta_time) > 0;
+            if chem.dopamine > 0.9 && epiphany_due {
                  let changes = ego.trigger_epiphany(chem.dopamine);
                  if changes > 0 {
                      let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
-                         format!("🌟 EPIPHANY: Structural Reinforcement of {} pathways.", changes)));
+                         format!("🌟 EPIPHANY: Structural Reinforcement of {} pathways.", changes))
+                         .with_payload(serde_json::json!({ "event": "epiphany", "pathways": changes })));
+                     announce(EventKind::Epiphany, serde_json::json!({ "pathways": changes, "cause": "dopamine", "dopamine": chem.dopamine }));
                      
                      // DOPAMINE CRASH (Refractory Period)
                      // The brain consumes the neurochemical resources to build the structure.
@@ -921,30 +1235,53 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
             
             // Decay Sensory Buffer (Persistence of Sensation)
             // Sensation lingers for ~200ms (0.9 decay at 60Hz)
-            for x in current_sensory_vector.iter_mut() {
+            for x in daemon.sensory_vector.iter_mut() {
                 *x *= 0.9;
             }
             
             // SPONTANEOUS NEUROGENESIS (Bio-Evolution)
             // Brain grows with activity, not just extreme dopamine
             // Dopamine > 0.15 = mild interest = slow growth
-            if chem.dopamine > 0.15 && ticks % 300 == 0 {
-                 ego.neurogenesis(1);
+            let grown = growth_timer.tick(delta_time) as usize;
+            if chem.dopamine > 0.15 && grown > 0 && !belt.growth_capped() {
+                 ego.neurogenesis(grown);
                  let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
-                     format!("🌱 Spontaneous Neurogenesis: +1 neuron (Total: {})", ego.current_size())));
+                     format!("🌱 Spontaneous Neurogenesis: +{} neuron (Total: {})", grown, ego.current_size())));
             }
             
             // ACTIVITY-DRIVEN NEUROGENESIS
             // Edge of Chaos (entropy 0.3-0.7) = interesting regime = brain adapts
-            if current_entropy > 0.3 && current_entropy < 0.7 && ticks % 600 == 0 {
-                 ego.neurogenesis(1);
+            let edge_grown = edge_growth_timer.tick(delta_time) as usize;
+            if daemon.current_entropy > 0.3 && daemon.current_entropy < 0.7 && edge_grown > 0 {
+                 ego.neurogenesis(edge_grown);
+            }
+            // Growth and pruning of the whole last tick (sleep, novelty and the neocortex grow too)
+            let births = ego.drain_growth_events();
+            metabolism.report_growth(births);
+            if births > 0 {
+                announce(EventKind::Neurogenesis, serde_json::json!({ "grown": births, "total": ego.current_size() }));
+            }
+            let pruned = ego.drain_pruned_events();
+            if pruned > 0 {
+                announce(EventKind::Apoptosis, serde_json::json!({ "pruned": pruned }));
             }
 
             // TRAUMA DETECTION (Phase 4.2 — Lucifer Protocol)
             let trauma_changed = trauma_detector.tick(chem.cortisol);
             if trauma_changed {
+                announce(EventKind::TraumaTransition, serde_json::json!({
+                    "state": trauma_detector.state.to_string(),
+                    "escalating": matches!(trauma_detector.state, TraumaState::Escalating | TraumaState::FirefighterMode),
+                    "cortisol_avg": trauma_detector.cortisol_avg,
+                }));
                 let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
-                    format!("🔥 TRAUMA STATE: {} (Cortisol Avg: {:.2})", trauma_detector.state, trauma_detector.cortisol_avg)));
+                    format!("🔥 TRAUMA STATE: {} (Cortisol Avg: {:.2})", trauma_detector.state, trauma_detector.cortisol_avg))
+                    .with_payload(serde_json::json!({
+                        "event": "trauma",
+                        "state": trauma_detector.state.to_string(),
+                        "escalating": matches!(trauma_detector.state, TraumaState::Escalating | TraumaState::FirefighterMode),
+                        "cortisol_avg": trauma_detector.cortisol_avg,
+                    })));
             }
             
             // Apply Firefighter Overrides
@@ -954,263 +1291,106 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
             }
             
             // NEOCORTEX OBSERVATION (Meta-Cognition)
-            if let Some(event) = neocortex.observe(current_entropy) {
-                 // Log event to internal monologue
+            neocortex.set_rigid(daemon.heart.rigid()); // Low HRV: lock-in comes sooner
+            if let Some(event) = neocortex.observe(daemon.current_entropy) {
+                 // Log event to internal monologue, then the body reacts
                  let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("{}", event)));
-                 
-                 // React to event
-                 match event {
-                     crate::core::neocortex::CognitiveEvent::Neurogenesis => {
-                         ego.neurogenesis(2); // Boost growth
-                         chem.dopamine = (chem.dopamine + 0.1).min(1.0); // Reward growth
-                     },
-                     crate::core::neocortex::CognitiveEvent::Trauma(_) => {
-                         chem.cortisol = (chem.cortisol + 0.05).min(1.0);
-                     },
-                     crate::core::neocortex::CognitiveEvent::Boredom => {
-                         chem.dopamine *= 0.995; // Gentle boredom fade, not a crash
-                     },
-                     _ => {}
-                 }
+                 bored = daemon_state::react(&event, &mut chem, &mut ego);
             }
 
             // AGENCY EVALUATION (Phase 8)
             // Reward for: Interactions (speaking) and Neurogenesis (learning)
             let memory_metric = ego.hebbian_events as usize; // Approximation of learning
-            let reward = agent.evaluate(interaction_count, memory_metric);
+            let reward = agent.evaluate(daemon.interaction_count, memory_metric);
             
             if reward > 0.0 {
                 chem.dopamine = (chem.dopamine + reward).min(1.0);
-                let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🏆 GOAL ACHIEVED: Dopamine +{:.2}", reward)));
+                let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🏆 GOAL ACHIEVED: Dopamine +{:.2}", reward))
+                    .with_payload(serde_json::json!({ "event": "goal_achieved", "reward": reward })));
+                announce(EventKind::GoalCompleted, serde_json::json!({ "reward": reward }));
                 // Epiphany trigger?
                 if reward >= 0.5 {
-                    ego.trigger_epiphany(chem.dopamine);
+                    let changes = ego.trigger_epiphany(chem.dopamine);
+                    if changes > 0 {
+                        announce(EventKind::Epiphany, serde_json::json!({ "pathways": changes, "cause": "goal", "dopamine": chem.dopamine }));
+                    }
                 }
             }
             
-            // VARIABLE METABOLISM: Calculate Target Hz
-            // Formula: Base + (Dopamine * 60) - (Adenosine * 40)
-            // High Dopamine -> 120Hz. High Adenosine -> 20Hz.
-            let target_hz = {
-                 let metabolic_drive = (chem.dopamine * 60.0) + (chem.cortisol * 30.0);
-                 let metabolic_drag = chem.adenosine * 40.0;
-                 (hz_base + metabolic_drive - metabolic_drag).clamp(24.0, 120.0)
-            };
-            
-            // Smooth transition (Heart Rate Variability)
-            current_hz = current_hz + (target_hz - current_hz) * 0.05;
-            
-            current_entropy = entropy_output;
-            
-            // Update Stats
-            _session_stress_accum += chem.cortisol + chem.adenosine;
-            ticks += 1;
-
-            // Critical Collapse Check
-            // Critical Collapse Check
-            // Critical Collapse Check
-            // Tolerance is genetic (0.0-1.0), but we need a sanity floor.
-            // If tolerance is 0.5, collapse shouldn't be at 47% adenosine, that's just a nap.
-            // Let's set collapse at 90% absolute, modulated slightly by tolerance.
-            let _collapse_threshold = 0.9 + (seed.stress_tolerance * 0.1); 
-            
-            // Critical Collapse Check
-            // Tolerance is genetic (0.0-1.0), but we need a sanity floor.
-            // If tolerance is 0.5, collapse shouldn't be at 47% adenosine, that's just a nap.
-            // Let's set collapse at 90% absolute, modulated slightly by tolerance.
-            let collapse_threshold = 0.95 + (seed.stress_tolerance * 0.05); 
-            
-            if chem.adenosine > collapse_threshold && !is_dreaming {
-                 is_dreaming = true;
-                 let _ = tx_thoughts.send(Thought::new(MindVoice::System, "⛔ METABOLIC CRITICAL: Forced Sleep Protocol Initiated.".to_string()));
-            }
-            
-            // NATURAL WAKING
-            if is_dreaming && chem.adenosine < 0.1 {
-                is_dreaming = false;
-                let _ = tx_thoughts.send(Thought::new(MindVoice::System, "🌅 WAKING: Metabolic homeostasis restored.".to_string()));
-            }
+            // HEART RATE, SESSION STATS, FORCED SLEEP & WAKING
+            daemon.metabolic_step(&chem, entropy_output, seed.stress_tolerance, &tx_thoughts);
         }
         
-        // A.2 CORTEX (LLM) TELEMETRY
-        // Read from the Neural Echo stream
-        if let Some(rx) = &rx_cortex_out {
-            while let Ok(out) = rx.try_recv() {
-                // Downsample Logits/Echo for Visualization (32k -> 64)
-                // We want a "Spectral" representation of the LLM state.
-                let raw = out.neural_echo;
-                let mut spectrum = vec![0.0; 64];
-                if raw.len() > 0 {
-                    let chunk_size = raw.len() / 64;
-                    for i in 0..64 {
-                        // Take average of chunk
-                        let start = i * chunk_size;
-                        let end = (start + chunk_size).min(raw.len());
-                        let sum: f32 = raw[start..end].iter().sum();
-                        spectrum[i] = (sum / (chunk_size as f32)).tanh(); // Normalize -1..1
-                    }
-                }
-                
-                // Update Web State
-                if let Ok(mut state) = web_state.lock() {
-                    state.llm_activity = spectrum;
-                    state.activations = out.activations; // FIX: Visualize Glass Brain
-                    
-                    // Also capture resonance/synthesized thought if meaningful?
-                    // Already handled via tx_thoughts in Planet usually, but `synthesized_thought` 
-                    // is specific to the "Lobotomy" mode resonance.
-                    if let Some(word) = out.synthesized_thought {
-                         let clean = word.trim();
-                         // Only filter pure noise (single chars, empty, pure brackets)
-                         if clean.len() >= 2 && clean.chars().any(|c| c.is_alphanumeric()) {
-                            telemetry_history.push_back(format!("💭 {}", clean));
-                            if telemetry_history.len() > 30 { telemetry_history.pop_front(); }
-                         }
-                    }
-                }
-            }
-        }
-
+        echo_size.store(ego.current_size(), Ordering::Relaxed);
+        frame_clock.lap(Phase::Physics, Instant::now());
 
         // B. INPUT PROCESSING (Orbit Perturbations)
-        
-        // -1. TUI INPUT (Stimulus)
-        while let Ok(text) = rx_stimulus.try_recv() {
-             // SYSTEM COMMANDS (Web Dashboard Control)
-             if text == "SYS:SLEEP" {
-                 let _ = tx_thoughts.send(Thought::new(MindVoice::System, "💤 HYPNOTIC INDUCTION RECEIVED. Drifting into REM Cycle...".to_string()));
-                 
-                 // MEMORY CONSOLIDATION (Pruning) instead of mindless growth
-                 // "Optimization y Poda"
-                 let pruned = ego.prune_inactive_neurons();
-                 if pruned > 0 {
-                     let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🧠 Synaptic Pruning: Removed {} unused connections.", pruned)));
-                 } else {
-                     // If fully optimized, small growth allowed
-                     ego.neurogenesis(5);
-                     let _ = tx_thoughts.send(Thought::new(MindVoice::System, "🌱 Optimization Complete. Minor structural growth.".to_string()));
-                 }
-
-                 let mut chem = chemistry.lock().unwrap();
-                 chem.adenosine = 0.95; // Force deep sleep mode
-                 chem.cortisol = 0.0;   // Reset Panic/Stress
-                 is_dreaming = true;    // ENGAGE SLEEP
-                 continue;
-             }
-             if text == "SYS:POKE" {
-                 let _ = tx_thoughts.send(Thought::new(MindVoice::System, "⚡ SENSORY SHOCK. Awakening.".to_string()));
-                 let mut chem = chemistry.lock().unwrap();
-                 chem.adenosine = 0.0; // Reset fatigue
-                 is_dreaming = false;  // WAKE UP
-                 // POKE IS NOT A REWARD. It is a Startle/Alert (Norepinephrine/Cortisol).
-                 // Removed dopamine spike to maintain Mechanical Honesty.
-                 chem.cortisol = (chem.cortisol + 0.1).min(1.0); // Increased startle
-                 // continue; // REMOVED: Allow fall-through to trigger Cortex! ← OLD LOGIC WAS WRONG
-                 // FIX: POKE should wake/alert but NOT be processed as text novelty
-                 continue; 
-             }
-
-             // Only log real user text, not SYS: commands (those already log their effects)
-             if text.starts_with("SYS:") {
-                 // Prevent ANY system command from leaking into the Cortex prompt
-                 continue;
-             }
-
-             // Log user input
-             let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("💬 '{}'", text)));
-             // Inject into Memory/Orbit
-             // For now, treat as high-entropy injection
-             current_entropy += 0.1;
-             
-             // WAKE UP EFFECT: User attention breaks the fatigue loop
-             {
-                  let mut chem = chemistry.lock().unwrap();
-                  chem.dopamine = (chem.dopamine + 0.3).min(1.0);  // Spike interest
-                  // ADENOSINE (Fatigue) IS NOT CLEARED BY TALKING. Needs sleep.
-                  // chem.adenosine = (chem.adenosine - 0.2).max(0.0); 
-                  chem.cortisol = (chem.cortisol - 0.05).max(0.0);   // Social soothing (mild)
-             }
-             
-             // Create Cortex Input for User Stimulus
-             let chem = chemistry.lock().unwrap();
-             let bio_context = String::new(); // No text description — chemistry flows through parametric effects
-             
-             let bio_status = format!("Dopa:{:.2} Cort:{:.2} Aden:{:.2}", 
-                 chem.dopamine, chem.cortisol, chem.adenosine);
-                 
-             let input_state = CortexInput {
-                 mode: crate::cortex::planet::CortexMode::Think, // Monitoring is explicit thought
-                 text: text.clone(),
-                 bio_state: bio_status,
-                 bio_context, // NEW: Physiological Prompt
-                 _somatic_state: "Stimulated".to_string(),
-                 _long_term_memory: None,
-                 _cpu_load: last_body_state.cpu_usage,
-                 _ram_pressure: last_body_state.ram_usage,
-                 _cognitive_impairment: 0.0,
-                 entropy: current_entropy.clamp(0.0, 1.0),
-                 adenosine: chem.adenosine,
-                 dopamine: chem.dopamine,
-                 cortisol: chem.cortisol,
-                 _oxytocin: chem.oxytocin,
-                 temperature_clamp: trauma_detector.get_overrides().temperature_clamp,
-             };
-             
-             // Force immediate thought generation
-             if let Some(tx) = &tx_cortex {
-                 let _ = tx.send(input_state);
-             }
-             drop(chem);
+        let wire = Wiring { config: &config, thoughts: &tx_thoughts, memory: &tx_mem, cortex: tx_cortex.as_ref(), bus: &bus };
+
+        // -1. TUI INPUT (Stimulus) + SYSTEM COMMANDS (Web Dashboard Control)
+        for event in &events {
+            match event {
+                Event::Control(ControlEvent::ReloadGenome) => on_reload_genome(&wire, &mut daemon, &mut seed, &mut ego, &mut satellite, &mut trauma_detector),
+                Event::Control(ControlEvent::Feed(feed)) => on_feed(&wire, feed, &mut pantry, &mut chemistry.lock().unwrap(), &daemon, &ego),
+                Event::Control(control) => {
+                    on_control(&wire, control, &mut daemon, &mut chemistry.lock().unwrap(), &mut ego, &mut standby);
+                    privacy.store(daemon.privacy_mode, Ordering::Relaxed);
+                },
+                Event::Sensory(SensoryEvent::Stimulus(text)) => {
+                    let clamp = trauma_detector.get_overrides().temperature_clamp;
+                    on_stimulus(&wire, text, &mut daemon, &mut chemistry.lock().unwrap(), &mut company, clamp);
+                },
+                _ => {}
+            }
         }
 
         // 0. AUDIO INPUT (Ears) -> SEMANTIC PERTURBATION (Not LLM input!)
         // The text from Whisper is NOT an instruction - it's a sensory perturbation
         // that affects ALEPH's chemistry, not its reasoning.
-        while let Ok(text) = rx_audio_text.try_recv() {
-            if !text.trim().is_empty() {
-                // Visible Log for User Feedback
-                let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🎤 Hearing: '{}'", text)));
-                
-                // SENSORY MOTOR MAPPING (Phase 2)
-                // Hash words to Input Neurons
-                let words: Vec<&str> = text.split_whitespace().collect();
-                for word in words {
-                    let mut hasher = DefaultHasher::new();
-                    word.hash(&mut hasher);
-                    let hash = hasher.finish();
-                    let sensory_idx = (hash % 500) as usize;
-                    
-                    // Activate the sensory channel
-                    if sensory_idx < current_sensory_vector.len() {
-                        current_sensory_vector[sensory_idx] += 1.0; 
-                    }
-                }
-                
-                last_interaction_tick = ticks; // Reset boredom timer
-                
-                // SEMANTIC PERTURBATION: Text -> Chemistry (NOT prompt)
-                let mut chem = chemistry.lock().unwrap();
-                let friction = chem.apply_semantic_perturbation(&text);
-                
-                // Log the chemical impact
-                if friction > 0.05 {
-                    // Auditory cortex used = small growth
-                    if ticks % 120 == 0 {
-                        ego.neurogenesis(1);
-                    }
+
+        // SENSORY MOTOR MAPPING (Phase 2): each heard sentence's words hit the input
+        // neurons at its own tick (scheduled below, a few ticks apart)
+        for sentence in daemon.word_echoes.due(daemon.ticks) {
+            for word in sentence.split_whitespace() {
+                if let Some(channel) = daemon.sensory.hash_into(sensory::WORDS, word) {
+                    daemon.sensory_vector[channel] += 1.0; // Activate the sensory channel
                 }
-                
-                // Also store in memory (raw text, no labels)
-                drop(chem);
-                let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::ProcessStimulus { 
-                    text, 
-                    entropy: current_entropy 
-                });
+            }
+        }
+
+        for event in &events {
+            let Event::Sensory(SensoryEvent::Heard(heard)) = event else { continue };
+            if on_heard(&wire, heard, &mut daemon, &mut ego, &mut chemistry.lock().unwrap(), &mut lexicon, &mut company) {
+                explorer.remember_sound(&heard.text, &daemon.last_spectrum.frequency_embedding); // Something to imagine later
             }
         }
 
         // 1. MEMORY & RESERVOIR FEEDBACK
-        if let Ok(mem_out) = rx_mem_out.try_recv() {
+        for event in &events {
+            let Event::Memory(MemoryEvent::Recalled(mem_out)) = event else { continue };
+
+            // DREAM REPLAY: re-lived, not answered
+            if let (Some(memory), Some(activity)) = (mem_out.input_text.strip_prefix("REPLAY: "), &mem_out.activity) {
+                daemon.replay(&mut ego, memory, activity, &config.replay, delta_time, &tx_thoughts);
+                continue;
+            }
+
+            // THE THREAD: the returning speaker's last turns -> timeline + the Planet's memory slot
+            if let Some(speaker) = mem_out.input_text.strip_prefix("THREAD: ") {
+                if let Some(line) = company.conversation.recalled(speaker, &mem_out.semantic_store, unix_millis(std::time::SystemTime::now()) / 1000) {
+                    let _ = tx_thoughts.send(Thought::new(MindVoice::Memory, format!("🧵 {}", line))
+                        .with_payload(serde_json::json!({ "event": "thread", "speaker": speaker, "turns": mem_out.semantic_store.len() })));
+                }
+                continue;
+            }
+
+            // THE REPERTOIRE: a memory that came back unsure is something to ask about
+            if let Some((context, relevance)) = &mem_out.retrieval {
+                if *relevance < company.repertoire.low_confidence() && !context.trim().is_empty() {
+                    uncertain_memory = Some(textutil::truncate_chars(context.trim(), 120).to_string());
+                }
+            }
+
             // PHASE 6: ENGRAM INJECTION
             // If the memory came with an embedding, inject it into the Association Cortex.
             // This makes memories PHYSICALLY visible as blue/purple pulses.
@@ -1220,283 +1400,358 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
 
             let mut chem = chemistry.lock().unwrap();
 
-            // Update Stats
-            // session_novelty_accum += mem_out.novelty; // Unused for now
-            
-            if mem_out.novelty < 0.2 {
-                 chem.adenosine += 0.05; // Boredom / Repetition fatigue
-            } else {
-                 chem.dopamine += mem_out.novelty * 0.5; // Stronger Interest spike
-                 // ALSO boost curiosity if novelty is high (Reinforcement Learning)
-                 seed.curiosity = (seed.curiosity + 0.001).min(1.0);
+            // EXPLORATION PAYOFF: self-made stimuli are paid by novelty, not fed back as input
+            if let Some(memory) = mem_out.input_text.strip_prefix("RUMINATION: ") {
+                let _ = tx_thoughts.send(Thought::new(MindVoice::Memory, format!("💭 Recordando: {}", memory)));
+            }
+            if mem_out.input_text.starts_with("RUMINATION: ") || mem_out.input_text.starts_with("PROBE: ") {
+                if let Some(msg) = explorer.resolve(mem_out.novelty, &mut chem) {
+                    let _ = tx_thoughts.send(Thought::new(MindVoice::Agency, msg));
+                }
+                continue;
             }
 
+            daemon_state::feel_novelty(&mut chem, &mut seed.curiosity, mem_out.novelty);
+
             // Neurogenesis (Sleep Consolidation)
             if mem_out.input_text == "CONSOLIDATION_EVENT" {
                  ego.neurogenesis(5);
-                 let _ = tx_thoughts.send(Thought::new(MindVoice::Chem, 
+                 let _ = tx_thoughts.send(Thought::new(MindVoice::Dream, 
                      format!("💤🧠 Sleep Architecture: Rebuilt +5 neurons. (Total: {})", ego.current_size())));
+
+                 // Lived experience reshapes the probability landscape
+                 if let Some(tx) = &tx_cortex {
+                     tx.rebuild_field(mem_out.semantic_store.clone());
+                 }
             }
 
-            // Feed Cortex if relevant (RAG)
+            // Feed Cortex if relevant (RAG). Earlier sentences of a transcript are only memory:
+            // the Planet answers the last thing said
+            if mem_out.memory_only {
+                continue;
+            }
             if let Some(ref tx) = tx_cortex {
-                // SATELLITE INPUT FILTER (Membrane Hardening)
-                // Attention = Capability to focus. High Adenosine = Low Attention.
-                // Attention (0-1) = Combination of Alertness (1-Adenosine) and Interest (Dopamine)
-                // Fix: Previous logic was only (1.0 - Adenosine), causing "deafness" when tired.
-                // Now, Dopamine boosts attention.
-                let attention = ((1.0 - chem.adenosine) * 0.5 + chem.dopamine * 0.8).clamp(0.2, 1.0);
-                
-                // If the Membrane rejects the input (Hardening), we don't think about it.
-                // UPDATED: Now returns (Option<String>, f32) where f32 is "Ontological Error Severity".
-                let (filtered_result, error_severity) = satellite.filter_input(&mem_out.input_text, current_entropy, attention);
-                
-                // INJECT STRUCTURAL PAIN (Ontological Error)
-                if error_severity > 0.0 {
-                     chem.cortisol += error_severity * 0.1; // Pain Injection
-                     let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
-                        format!("🩸 ONTOLOGICAL ERROR detected (Severity {:.1}). Injecting Cortisol.", error_severity)));
-                }
-
-                if let Some(filtered_text) = filtered_result {
-                    
-                    let bio_desc = format!("Soy Aleph. Estado: {}. Fatiga: {:.0}%.", 
-                        ego.get_state_description(), 
-                        chem.get_cognitive_impairment() * 100.0);
-                    
-                    let context_str = mem_out.retrieval.as_ref().map(|(s, _)| s.as_str());
-                    
-                    let bio_context = bio_desc.clone(); // Pass biological state to prompt
-    
-                    // MECHANICAL HONESTY: THE GATE
-                    // Use "Listen" mode (Passive) if:
-                    // 1. Adenosine > 0.9 (Too tired to speak)
-                    // 2. Entropy < 0.3 (Boredom/Habituation - unless Voice detected)
-                    // 3. System is in Internal Monologue mode
-                    
-                    let mut mode = crate::cortex::planet::CortexMode::Think;
-                    
-                    // If we are excessively fatigued OR dreaming, we only listen/dream.
-                    if chem.adenosine > 0.9 || is_dreaming {
-                        mode = crate::cortex::planet::CortexMode::Listen;
-                    }
-                    
-                    // If input is just ambient noise (not voice) and we are low entropy, 
-                    // we might just ignore it (Passive).
-                    // But for now, let's make all non-command inputs "Listen" first?
-                    // No, let's keep it simple: Voice usually triggers Think unless suppressed.
-
-                    let input = CortexInput {
-                        mode,
-                        text: filtered_text,
-                        bio_state: bio_desc,
-                        bio_context, // NEW: Physiological Prompt
-                        _somatic_state: format!("CPU: {:.1}%", last_body_state.cpu_usage),
-                        _long_term_memory: context_str.map(|s| s.to_string()),
-                        _cpu_load: last_body_state.cpu_usage,
-                        _ram_pressure: last_body_state.ram_usage,
-                        _cognitive_impairment: chem.get_cognitive_impairment(),
-                        entropy: current_entropy,
-                        adenosine: chem.adenosine,
-                        dopamine: chem.dopamine,
-                        cortisol: chem.cortisol,
-                        _oxytocin: chem.oxytocin,
-                        temperature_clamp: trauma_detector.get_overrides().temperature_clamp,
-                    };
-                    
-                    // Send to Planet
-                    let _ = tx.send(input);
-                } else {
-                    // IGNORED (Hardened)
-                     let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
-                        format!("🛡️ MEMBRANE HARDENED: Ignoring input (Entropy {:.2} > Attn {:.2})", current_entropy, attention)));
+                // SATELLITE INPUT FILTER (Membrane Hardening): rejected text isn't thought about
+                let auditory_share = ego.attention_breakdown()[&Pathway::Auditory];
+                let (passed, decision) = daemon.membrane(&mut satellite, &mem_out.input_text, &mut chem, auditory_share, Instant::now(), &tx_thoughts);
+                membrane_log.record(decision);
+                if let Some(text) = passed {
+                    let memory = company.conversation.memory_slot(mem_out.retrieval.as_ref().map(|(s, _)| s.as_str()));
+                    let input = daemon.prompt(&chem, text, ego.get_state_description(), company.people.presence_line(), memory,
+                        trauma_detector.get_overrides().temperature_clamp);
+                    tx.send(input, CortexPriority::User); // Send to Planet
                 }
             }
         }
 
+        frame_clock.lap(Phase::Input, Instant::now());
+
         // C. SATELLITE OBSERVER (Output Filter)
-        if let Some(ref rx) = rx_cortex_out {
-            match rx.try_recv() {
-                Ok(output) => {
-                    if !output.activations.is_empty() {
-                         println!("DEBUG: Received Cortex Output with {} activations", output.activations.len());
-                    } else {
-                         println!("DEBUG: Received Cortex Output but EMPTY activations");
-                    }
-                    
-                    // 1. NEURAL ECHO INJECTION (The "Pebble in the Pond")
-                    // The raw probability cloud hits the reservoir.
-                    ego.inject_logits(&output.neural_echo);
+        // Single consumer of cortex output: visualization, echo injection, latency and resonance.
+        for event in &events {
+            let Event::Cortex(CortexEvent::Output(output)) = event else { continue };
+            health.update(|v| v.last_cortex_output = Some(Instant::now()));
 
-                    // 1.5 UPDATE WEB VISUALIZATION (Top Tokens & Activations)
-                    if let Ok(mut state) = web_state.lock() {
-                        if !output.top_tokens.is_empty() {
-                            state.top_activations = output.top_tokens.clone();
+            // 0. SPECTRAL VIEW (Logits/Echo downsampled for Visualization, 32k -> 64, in the Planet thread)
+            // We want a "Spectral" representation of the LLM state.
+            if let Ok(mut state) = web_state.lock() {
+                state.llm_activity = output.neural_echo.spectrum.clone();
+            }
+
+            // Resonant word -> short-term stream (only filter pure noise: single chars, empty, pure brackets)
+            if let Some(word) = &output.synthesized_thought {
+                let clean = word.trim();
+                if clean.len() >= 2 && clean.chars().any(|c| c.is_alphanumeric()) {
+                    daemon.remember(format!("{} 💭 {}", thought::clock(std::time::SystemTime::now()), clean));
+                }
+            }
+
+            // 1. NEURAL ECHO INJECTION (The "Pebble in the Pond")
+            // The probability cloud (pooled per neuron at the source) hits the reservoir.
+            output.neural_echo.inject(&mut ego);
+            // ...and, when the cortex could look inside, its hidden layers reach the Semantic field
+            if output.activation_layout.is_hidden() {
+                ego.inject_hidden(&output.activations);
+            }
+
+            // 1.5 UPDATE WEB VISUALIZATION (Top Tokens & Activations)
+            if let Ok(mut state) = web_state.lock() {
+                if !output.top_tokens.is_empty() {
+                    state.top_activations = output.top_tokens.clone();
+                }
+                if !output.activations.is_empty() {
+                    state.activations = output.activations.clone();
+                    state.activation_layout = output.activation_layout;
+                }
+                state.cortex_tier = match output.tier {
+                    CortexTier::Fast => "fast".to_string(),
+                    CortexTier::Deep => "deep".to_string(),
+                };
+                if let Some(split) = output.prompt_split {
+                    state.prompt_split = split;
+                    state.bio_dropped = output.bio_dropped;
+                    state.bio_deduped = output.bio_deduped;
+                }
+            }
+            
+            // LATENCY FEEDBACK (Mechanical Honesty)
+            // If the thought took a long time to generate, it costs energy.
+            // Billed through the metabolism ledger (free threshold + coefficients in config).
+            metabolism.report_cortex(output.inference_latency_ms);
+            if output.interrupted {
+                 let _ = tx_thoughts.send(Thought::new(MindVoice::System,
+                     format!("✋ Planet interrupted after {}ms (cancelled generation).", output.inference_latency_ms)));
+            }
+            // WARM-UP: Waking the deep model is metabolically expensive
+            if output.warmup_cost > 0.0 {
+                 let mut chem = chemistry.lock().unwrap();
+                 chem.adenosine = (chem.adenosine + output.warmup_cost).min(1.0);
+            }
+
+            // 2. RESONANCE CHECK
+            // Did the Field collapse the wave into a word?
+            if let Some(text) = &output.synthesized_thought {
+                // EXPLORATION (Question): the answer ALEPH gave itself, measured by the hippocampus
+                if explorer.awaiting(Action::Question) {
+                    let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::Probe { text: text.clone() });
+                }
+
+                // THE WISH: "necesito descansar" is a request the body may veto
+                let body = {
+                    let chem = chemistry.lock().unwrap();
+                    volition::Body { adenosine: chem.adenosine, cortisol: chem.cortisol, asleep: daemon.is_dreaming }
+                };
+                if let Some(decision) = volition.consider(text, output.neural_echo.resonance, body, Instant::now()) {
+                    match decision.effect {
+                        Some(Effect::Sleep) => {
+                            control(&bus, ControlEvent::Sleep);
                         }
-                        if !output.activations.is_empty() {
-                            state.activations = output.activations.clone();
+                        Some(Effect::Focus { boost, secs }) => satellite.focus(boost, Instant::now() + Duration::from_secs_f32(secs)),
+                        Some(Effect::Calm { cortisol }) => {
+                            let mut chem = chemistry.lock().unwrap();
+                            chem.cortisol = (chem.cortisol - cortisol).max(0.0);
                         }
+                        None => {}
                     }
-                    
-                    // LATENCY FEEDBACK (Mechanical Honesty)
-                    // If the thought took a long time to generate, it costs energy.
-                    // 500ms is the "free" threshold. anything above adds to adenosine.
-                    let latency_sec = output.inference_latency_ms as f32 / 1000.0;
-                    if latency_sec > 0.5 {
-                         let fatigue_cost = (latency_sec - 0.5) * 0.05; // 2s latency = +0.075 adenosine
-                         let mut chem = chemistry.lock().unwrap();
-                         chem.adenosine = (chem.adenosine + fatigue_cost).min(1.0);
-                    }
+                    let _ = tx_thoughts.send(Thought::new(MindVoice::System, decision.line())
+                        .with_payload(serde_json::json!({
+                            "event": "self_modulation", "intent": decision.intent, "request": decision.request,
+                            "honored": decision.effect.is_some(), "reason": decision.reason,
+                        })));
+                }
 
-                    // 2. RESONANCE CHECK
-                    // Did the Field collapse the wave into a word?
-                    if let Some(text) = output.synthesized_thought {
-                        // SATELLITE JUDGMENT
-                        let chem = chemistry.lock().unwrap();
-                        
-                        // Friction: How much does this word cost?
-                        let friction = (chem.adenosine - 0.2).abs(); 
-
-                        // SATELLITE FILTER (Membrane Output)
-                        let (final_text, latency) = satellite.filter_output(&text, friction);
-                        
-                        // Latency (Hesitation)
-                        if latency.as_millis() > 0 {
-                            thread::sleep(latency);
-                        }
-                        
-                        // GATEKEEPER (Decoupled Vocalization)
-                        // "The Effort of Expression": Only speak if Meaning > Threshold AND Energy is available.
-                        let should_vocalize = gate.attempt_vocalization(chem.adenosine, current_entropy, chem.dopamine, &final_text, ticks as u64);
-                        
-                        if should_vocalize {
-                            // EMIT VOCAL THOUGHT (Resonance)
-                            interaction_count += 1;
-                            let _ = tx_thoughts.send(Thought::new(MindVoice::Vocal, final_text.clone()));
-                            
-                            // Feed back to Memory (We spoke it, so we remember it)
-                            let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::ProcessStimulus { 
-                                 text: final_text, 
-                                 entropy: current_entropy 
-                            });
-                        } else {
-                            // INTERNAL RESONANCE (Silent Insight) 
-                            let _ = tx_thoughts.send(Thought::new(MindVoice::Cortex, final_text));
+                // SATELLITE JUDGMENT
+                let chem = chemistry.lock().unwrap();
+                
+                // Friction: How much does this word cost?
+                let friction = (chem.adenosine - 0.2).abs(); 
+
+                // SATELLITE FILTER (Membrane Output)
+                let (final_text, latency) = satellite.filter_output(text, friction);
+                
+                // Latency (Hesitation)
+                if latency.as_millis() > 0 {
+                    thread::sleep(latency);
+                }
+                
+                // Resonance: how sure the echo was of its top token (judged by the sieve),
+                // and the seed that regenerates the utterance (`aleph replay-inference --seed`)
+                let mut payload = serde_json::json!({ "seed": output.seed });
+                if let Some(top) = output.neural_echo.resonance {
+                    payload["resonance"] = serde_json::json!(top);
+                }
+
+                // ASLEEP, only sleep-talk reaches the mouth (mumbled); AWAKE, the gatekeeper
+                // decides between speech and silent insight (mouth and memory are the routing
+                // table's call: see the drain below)
+                let warmth = company.bonding.warmth(&chem, Instant::now());
+                match daemon.express(&mut gate, &config.sleep_talk, &chem, warmth, final_text, payload) {
+                    Expression::Dream { mut thought, mumbled } => {
+                        if mumbled {
+                            let delivery = voice::Delivery { volume: config.sleep_talk.volume, rate: config.sleep_talk.rate };
+                            if let Err(refusal) = actuators.lock().unwrap().request_with(actuator::VOICE, &thought.text, delivery, thought.id, Instant::now()) {
+                                thought.text = format!("💤 {} (not said: {})", thought.text, refusal.name());
+                            }
                         }
-                    } else {
-                        // NO RESONANCE (Silence / Glitch)
-                        // If entropy is extremely high, we might emit a "glitch" log.
-                        if current_entropy > 0.9 {
-                             let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🌊 HIGH ENTROPY WAVE ({:.2}) - NO RESONANCE", current_entropy)));
-                             // Trigger Glitch Sound
-                             voice::glitch(current_entropy);
+                        let _ = tx_thoughts.send(thought);
+                    },
+                    Expression::Awake { thought, decision } => {
+                        gate_log.record(decision);
+                        if thought.voice == MindVoice::Vocal {
+                            company.bonding.vocalized(Instant::now());
+                            if let Some(drive) = company.repertoire.spoke(Instant::now()) {
+                                let _ = tx_thoughts.send(Thought::new(MindVoice::Agency, format!("🎭 REPERTOIRE: spoke for '{}'", drive))
+                                    .with_payload(serde_json::json!({ "event": "drive_spoken", "drive": drive })));
+                            }
+                            // THE QUILL: a strongly resonant utterance goes to the journal while that goal is active
+                            let resonance = resonance_of(thought.payload.as_ref()).unwrap_or(0.0);
+                            if resonance >= config.actuators.journal_resonance && agent.is_active(GoalType::Journal) {
+                                let mut registry = actuators.lock().unwrap();
+                                if registry.allows(actuator::NOTES) {
+                                    if let Err(actuator::Refusal::Failed(e)) = registry.request(actuator::NOTES, &thought.text, thought.id, Instant::now()) {
+                                        let _ = tx_thoughts.send(Thought::new(MindVoice::Error, format!("📓 Journal: {}", e)));
+                                    }
+                                }
+                            }
                         }
-                    }
-                },
-                Err(std::sync::mpsc::TryRecvError::Empty) => {
-                     // File Log Debugging because TUI eats stdout
-                     if ticks % 100 == 0 {
-                         let _ = std::fs::write("daemon_debug.log", format!("Tick {}: Channel EMPTY\n", ticks));
+                        let _ = tx_thoughts.send(thought);
+                    },
+                }
+            } else {
+                // NO RESONANCE (Silence / Glitch)
+                // If entropy is extremely high, we might emit a "glitch" log.
+                if daemon.current_entropy > 0.9 {
+                     let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🌊 HIGH ENTROPY WAVE ({:.2}) - NO RESONANCE", daemon.current_entropy)));
+                     // Trigger Glitch Sound
+                     if config.runtime.local_voice {
+                         voice::glitch(daemon.current_entropy);
                      }
-                },
-                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
-                     let _ = std::fs::write("daemon_debug.log", "CHANNEL DISCONNECTED!\n");
-                     println!("DEBUG: Cortex Channel DISCONNECTED!");
                 }
             }
-        } else {
-            println!("DEBUG: rx_cortex_out is NONE!");
         }
         
-        // Log thoughts to stdout for now (until Client connects)
-        while let Ok(thought) = rx_thoughts.try_recv() {
-             println!("[{}] {}", thought.voice_label(), thought.text);
-             
-             let log_entry = format!("[{}] {}", thought.voice_label(), thought.text);
-             telemetry_history.push_back(log_entry);
-             if telemetry_history.len() > 30 {
-                 telemetry_history.pop_front();
-             }
-             
-             // VOICE ACTUATOR (Mouth)
-             if thought.voice == MindVoice::Vocal {
-                 voice::speak(thought.text.clone(), tx_thoughts.clone());
-             }
+        // THOUGHT DRAIN: the routing table decides stream and memory per voice
+        // (stdout/voice live in the Thought Logger subscriber, same table)
+        for event in &events {
+            let Event::Thought(thought) = event else { continue };
+            let route = config.voices.route(thought.voice);
+            if route.stream {
+                daemon.remember(thought.line());
+            }
+            // THE SIEVE: ALEPH's own fragments don't become engrams (heard text never passes here)
+            if route.memorize && sieve.admit(&thought.text, resonance_of(thought.payload.as_ref())) {
+                let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::ProcessStimulus {
+                     text: thought.text.clone(),
+                     entropy: daemon.current_entropy,
+                     activity: ego.activity_signature(),
+                     provenance: Provenance::of_voice(thought.voice),
+                     utterance: None,
+                     memory_only: false,
+                });
+            }
+            if thought.voice == MindVoice::Sensory {
+                daemon.last_stimulus = Some(thought.id); // What the next Vocal thought answers
+            }
         }
         
+        frame_clock.lap(Phase::Output, Instant::now());
+
         // --- BROADCAST TELEMETRY ---
-        if ticks % 5 == 0 { // ~12Hz update rate for TUI (at 60Hz tick)
+        if daemon.ticks % 5 == 0 { // ~12Hz update rate for TUI (at 60Hz tick)
              let chem = chemistry.lock().unwrap();
-             
-             // Get latest thought for UI stream
-             let latest_thought = telemetry_history.back().cloned().unwrap_or_else(|| "Waiting for input...".to_string());
-             
+             let now = Instant::now();
+
              // Compute expensive snapshots once
              let activity_snapshot = ego.get_activity_snapshot();
-             
-             // Update Web State (Shared with WebSocket Thread)
+             let region_map = ego.region_map().to_vec(); // Cached: re-scanned only when exposures drift
+             let topology_version = ego.topology_version();
+
+             // One heartbeat for both views: the TUI packet, absorbed by the web state
+             let packet = {
+                 let state = web_state.lock().unwrap();
+                 daemon.build_telemetry(&chem, &ego, activity_snapshot, region_map.clone(), state.activations.clone(), state.visual_cortex.clone())
+                     .with_decisions(satellite.stats(now), &gate.stats)
+             };
+
+             // Update Web State (Shared with WebSocket Thread) — the only place it is assembled
              {
                  let mut state = web_state.lock().unwrap();
-                 state.dopamine = chem.dopamine;
-                 state.cortisol = chem.cortisol;
-                 state.adenosine = chem.adenosine;
-                 state.oxytocin = chem.serotonin; // Map Serotonin -> Oxytocin
+                 state.absorb(&packet);
                  state.serotonin = chem.serotonin;
-                 state.entropy = current_entropy;
-                 state.loop_frequency = current_hz;
-                 state.reservoir_activity = activity_snapshot.clone();
-                 state.current_state = latest_thought.clone();
-                 state.system_cpu_load = last_body_state.cpu_usage;
-                 state.system_cpu_load = last_body_state.cpu_usage;
-                 state.system_ram_gb = last_body_state.ram_usage; // using field for load
+                 state.hrv = daemon.heart.hrv();
+                 state.components = components.snapshot();
+                 state.trauma_state = format!("{}", trauma_detector.state);
+                 state.hebbian_events = ego.drain_hebbian_events();
+                 if state.topology_version != topology_version {
+                     state.region_map = region_map;
+                     state.neuron_positions = ego.get_positions().clone();
+                     state.neuron_ids = ego.neuron_ids().to_vec();
+                     state.topology_version = topology_version;
+                 }
+                 state.system_ram_gb = daemon.last_body_state.ram_usage; // using field for load
                  
                  // Genome Traits
                  state.curiosity = seed.curiosity;
                  state.stress_tolerance = seed.stress_tolerance;
                  state.generation = seed.generation;
+                 state.stimuli_dropped = limiter.lock().unwrap().dropped;
+                 state.glucose = chem.glucose;
+                 state.energy_budget = energy_budget;
+                 state.prosody = daemon.last_prosody;
+                 state.audio_queue_depth = ear_stats.queue_depth.load(Ordering::Relaxed);
+                 state.audio_latency_ms = ear_stats.last_latency_ms.load(Ordering::Relaxed);
+                 state.audio_dropped = ear_stats.dropped.load(Ordering::Relaxed);
+                 state.audio_rejected = ear_stats.rejected_chunks.load(Ordering::Relaxed);
+                 state.audio_absurd_samples = ear_stats.absurd_samples.load(Ordering::Relaxed);
+                 state.audio_hallucinations_blocked = ear_stats.hallucinations_blocked.load(Ordering::Relaxed);
+                 state.plasticity_accumulated = ego.plasticity_accumulated();
+                 state.lesioned = ego.lesioned_ids();
+                 state.feeding = pantry.state(Instant::now());
+                 state.sleep_talk = gate.sleep_stats.clone();
+                 state.pressure = belt.state();
+                 state.repertoire = company.repertoire.stats();
+                 state.checkin = company.checkin.state();
+                 state.latency = latency.stats();
+                 state.volition = volition.stats();
+                 state.standby = standby.stats();
+                 state.attention_width = attention.width();
+                 state.homeostat = homeostat.state();
+                 state.entropy_legacy = ego.entropy;
+                 state.complexity = complexity.metrics();
+                 state.sleep_stage = daemon.sleep_stage(chem.adenosine);
+                 state.memory_replays = daemon.memory_replays;
+                 state.binding_events = binding.events;
+                 state.attention_breakdown = ego.attention_breakdown();
+                 state.attention_focus = gaze.focus();
+                 state.attention_shifts = gaze.shifts;
+                 state.memory_sieve = sieve.stats;
+                 state.frame_time = FrameStats { shedding: watchdog.shedding(), ..frame_clock.stats(daemon.frame_hz(&config.idle)) };
+                 health.update(|v| {
+                     v.target_hz = state.frame_time.target_hz;
+                     v.achieved_hz = state.frame_time.achieved_hz;
+                 });
+                 state.actuators = actuators.lock().map(|r| r.stats.clone()).unwrap_or_default();
+                 if daemon.ticks % 600 == 0 {
+                     state.region_stats = ego.region_stats(); // O(size²): not every frame
+                     state.growth = ego.growth_stats();
+                 }
+                 if let Some(tx) = &tx_cortex {
+                     let q = tx.stats();
+                     state.cortex_queue_depth = q.depth;
+                     state.cortex_queue_dropped = q.superseded + q.stale_dropped;
+                 }
              }
 
-             let packet = AlephPacket::Telemetry {
-                 adenosine: chem.adenosine,
-                 cortisol: chem.cortisol,
+             bus.publish(Event::Chem(ChemEvent::Snapshot {
                  dopamine: chem.dopamine,
-                 oxytocin: chem.serotonin, 
-                 audio_spectrum: last_spectrum.clone(),
-                 heart_rate: last_body_state.cpu_usage,
-                 lucidity: 1.0 - last_body_state.ram_usage, 
-                 reservoir_activity: activity_snapshot,
-                 short_term_memory: telemetry_history.iter().cloned().collect(),
-                 current_state: latest_thought, 
-                 entropy: current_entropy,
-                 loop_frequency: current_hz,
-                 cpu_usage: last_body_state.cpu_usage,
-                 activations: {
-                     let state = web_state.lock().unwrap();
-                     state.activations.clone()
-                 },
-                 visual_cortex: {
-                     let state = web_state.lock().unwrap();
-                     state.visual_cortex.clone()
-                 },
-                 region_map: ego.get_region_map(),
-                 reservoir_size: ego.current_size(),
-                 neuron_positions: ego.get_positions().clone(),
-             };
+                 cortisol: chem.cortisol,
+                 adenosine: chem.adenosine,
+                 oxytocin: chem.oxytocin,
+                 serotonin: chem.serotonin,
+                 entropy: daemon.current_entropy,
+             }));
+
              let _ = tx_telemetry.send(packet);
          }
         
+        frame_clock.lap(Phase::Telemetry, Instant::now());
+
         // Tick output for memory logs
-        while let Ok(log) = rx_mem_log.try_recv() {
+        for event in &events {
+             let Event::Memory(MemoryEvent::Log(log)) = event else { continue };
+             if let Some(round) = Round::parse(log) {
+                 metabolism.report_consolidation(Duration::from_millis(round.elapsed_ms));
+             }
              if log.contains("Novelty Detected") {
                  let mut chem = chemistry.lock().unwrap();
                  chem.dopamine = (chem.dopamine + 0.02).min(1.0);
                  drop(chem);
                  
-                 _session_novelty_accum += 1.0;
+                 daemon.session_novelty_accum += 1.0;
                  
                  // ORGANIC GROWTH (Neuroplasticity from Experience)
-                 if _session_novelty_accum >= 3.0 {
-                     _session_novelty_accum = 0.0;
+                 if daemon.session_novelty_accum >= 3.0 {
+                     daemon.session_novelty_accum = 0.0;
                      ego.neurogenesis(1);
                      let _ = tx_thoughts.send(Thought::new(MindVoice::System, "🌱 Growth: Structural adaptation.".to_string()));
                  }
@@ -1506,7 +1761,9 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
         // E. IDLE STATE (The Dreaming)
         // If Cortex hasn't been stimulated in a while, force a "Listen" pulse to keep the Neural Echo active.
         // This stops the "Mind" visualization from disappearing.
-        if ticks % 12 == 0 { // ~5Hz Pulse
+        // ~5Hz Pulse (suspended in deep idle, shed while the loop overruns its budget)
+        let pulse_due = idle_pulse_timer.tick(delta_time) > 0;
+        if pulse_due && !daemon.deep_idle && !watchdog.shedding() {
              // println!("DEBUG: Pulse Triggered"); // Debugging
              // Check if we need to poke the planet
              // Ideally we'd track `last_cortex_input`, but a constant low-frequency pulse is fine.
@@ -1521,10 +1778,10 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
                  bio_context,
                  _somatic_state: "Idle".to_string(),
                  _long_term_memory: None,
-                 _cpu_load: last_body_state.cpu_usage,
-                 _ram_pressure: last_body_state.ram_usage,
+                 _cpu_load: daemon.last_body_state.cpu_usage,
+                 _ram_pressure: daemon.last_body_state.ram_usage,
                  _cognitive_impairment: 0.0,
-                 entropy: current_entropy,
+                 entropy: daemon.current_entropy,
                  adenosine: chem.adenosine,
                  dopamine: chem.dopamine,
                  cortisol: chem.cortisol,
@@ -1532,7 +1789,7 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
                  temperature_clamp: None,
              };
              if let Some(tx) = &tx_cortex {
-                  let _ = tx.send(input);
+                  tx.send(input, CortexPriority::Idle);
              }
         }
 
@@ -1541,42 +1798,41 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
         let mut chem = chemistry.lock().unwrap();
         
         let interest = chem.dopamine;
-        let energy = 1.0 - chem.adenosine;
-        
-        // Calculate dynamic threshold based on excitement
-        // Dopa 0.9 -> Delay 300 ticks (5s) "Manic"
-        // Dopa 0.5 -> Delay 1200 ticks (20s) "Conversation"
-        // Dopa < 0.4 -> No Agency "Passive"
-        let agency_delay = if interest > 0.8 { 
-            300 // 5 seconds
-        } else if interest > 0.5 {
-            1200 // 20 seconds
-        } else {
-            999999 // Effectively infinite
-        };
         
-        let silence_duration = ticks.saturating_sub(last_interaction_tick);
-        
-        // Random organic trigger (stochastic firing)
-        // 10% chance per second to speak if threshold met
-        let stochastic = (ticks % 60) == 0; 
-        
-        if stochastic && silence_duration > agency_delay && energy > 0.2 {
+        if daemon.should_trigger_agency(&chem, agency_window.tick(delta_time) > 0) {
+             let silence_duration = daemon.ticks.saturating_sub(daemon.last_interaction_tick);
+             // THE REPERTOIRE: a drive seeds what to say (empty seed if none is eligible)
+             let now = Instant::now();
+             let mut slots = repertoire::Slots::new();
+             slots.insert("silence", (silence_duration / 3600).max(1).to_string());
+             slots.insert("mood", repertoire::mood_word(&chem).to_string());
+             if let Some(person) = company.people.latest() { slots.insert("person", person); }
+             if let Some(memory) = &uncertain_memory { slots.insert("memory", memory.clone()); }
+             if let Some(body) = repertoire::body_phrase(&daemon.last_body_state, &chem) { slots.insert("body", body); }
+             if let Some(music) = company.repertoire.music(now) { slots.insert("music", music); }
+             let choice = company.repertoire.choose(&repertoire::levels(&chem, &seed), &slots, rand::random::<f32>(), now);
              // ... Speak ...
-             let _ = tx_thoughts.send(Thought::new(MindVoice::System, 
-                 format!("⚡ AGENCY: Interest {:.2} > Speaking (Silence {}s)", interest, silence_duration/60)));
+             let _ = tx_thoughts.send(Thought::new(MindVoice::Agency, match &choice {
+                     Some(c) => format!("⚡ AGENCY: Interest {:.2} > Speaking (Silence {}s) [{} p={:.2}]", interest, silence_duration/60, c.drive, c.probability),
+                     None => format!("⚡ AGENCY: Interest {:.2} > Speaking (Silence {}s)", interest, silence_duration/60),
+                 })
+                 .with_payload(serde_json::json!({ "event": "agency", "drive": choice.as_ref().map(|c| &c.drive),
+                     "probability": choice.as_ref().map(|c| c.probability), "prompt": choice.as_ref().map(|c| &c.prompt) })));
+             if choice.as_ref().is_some_and(|c| c.slots.iter().any(|s| s == "memory")) {
+                 uncertain_memory = None; // Asked about once
+             }
              
              let input = CortexInput {
                  mode: crate::cortex::planet::CortexMode::Think,
-                 text: "".to_string(), 
+                 text: choice.map(|c| c.prompt).unwrap_or_default(), 
                  bio_state: format!("Interest:{:.2}", interest),
                  bio_context: String::new(),
                  _somatic_state: "Active".to_string(),
                  _long_term_memory: None,
-                 _cpu_load: last_body_state.cpu_usage,
-                 _ram_pressure: last_body_state.ram_usage,
+                 _cpu_load: daemon.last_body_state.cpu_usage,
+                 _ram_pressure: daemon.last_body_state.ram_usage,
                  _cognitive_impairment: 0.0,
-                 entropy: current_entropy,
+                 entropy: daemon.current_entropy,
                  adenosine: chem.adenosine,
                  dopamine: chem.dopamine,
                  cortisol: chem.cortisol,
@@ -1584,29 +1840,321 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
                  temperature_clamp: None,
              };
              if let Some(tx) = &tx_cortex {
-                  let _ = tx.send(input);
+                  tx.send(input, CortexPriority::Agency);
              }
              
              // Self-sustain excitement if talking
              chem.dopamine = (chem.dopamine + 0.02).min(1.0);
              
-             last_interaction_tick = ticks;
+             daemon.last_interaction_tick = daemon.ticks;
+        }
+
+        // THE PANTRY: sugar crashes, slow digestion, and the rest window opening
+        if pantry.step(&mut chem, Instant::now()) && !daemon.is_dreaming {
+            let _ = tx_thoughts.send(Thought::new(MindVoice::System, "🛏️ FED: the rest window is here.".to_string()));
+            control(&bus, ControlEvent::Sleep);
+        }
+
+        // THE KNOCK: someone engaged went quiet -> at most one low-key check-in
+        match company.checkin.observe(Instant::now(), chem.oxytocin, chem.adenosine, daemon.privacy_mode || daemon.is_dreaming, rand::random::<f32>()) {
+            Some(Knock::CheckIn { silent, probability }) => {
+                let minutes = (silent.as_secs() / 60).max(1);
+                let who = company.people.latest().unwrap_or_else(|| "la persona".to_string());
+                let _ = tx_thoughts.send(Thought::new(MindVoice::Agency, format!("🚪 CHECK-IN: {} quiet for {} min (p={:.2})", who, minutes, probability))
+                    .with_payload(serde_json::json!({ "event": "checkin", "silent_secs": silent.as_secs(), "probability": probability })));
+                let input = CortexInput {
+                    mode: crate::cortex::planet::CortexMode::Think,
+                    text: format!("Hace {} minutos que {} no dice nada. Pregunto en voz baja, sin insistir, si sigue ahí.", minutes, who),
+                    bio_state: "CheckIn".to_string(),
+                    bio_context: String::new(),
+                    _somatic_state: "Calm".to_string(),
+                    _long_term_memory: None,
+                    _cpu_load: daemon.last_body_state.cpu_usage,
+                    _ram_pressure: daemon.last_body_state.ram_usage,
+                    _cognitive_impairment: 0.0,
+                    entropy: daemon.current_entropy,
+                    adenosine: chem.adenosine,
+                    dopamine: chem.dopamine,
+                    cortisol: chem.cortisol,
+                    _oxytocin: chem.oxytocin,
+                    temperature_clamp: None,
+                };
+                if let Some(tx) = &tx_cortex {
+                    tx.send(input, CortexPriority::Agency);
+                }
+                daemon.last_interaction_tick = daemon.ticks;
+            }
+            Some(Knock::LetBe { reason, probability }) => {
+                let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🚪 CHECK-IN: let the silence be ({}, p={:.2})", reason, probability))
+                    .with_payload(serde_json::json!({ "event": "checkin_let_be", "reason": reason, "probability": probability })));
+            }
+            None => {}
         }
         drop(chem);
+
+        // G. EXPLORATION (Boredom): make a stimulus when nothing comes
+        if let Some(msg) = explorer.expire(Instant::now()) {
+            let _ = tx_thoughts.send(Thought::new(MindVoice::Agency, msg));
+        }
+        let silence_secs = daemon.ticks.saturating_sub(daemon.last_interaction_tick) as f32 / 60.0;
+        if !daemon.is_dreaming && explorer.should_explore(bored, silence_secs, Instant::now()) {
+            let journal: Vec<_> = timeline.query(0, None, 50).into_iter()
+                .filter(|e| e.voice == "Cortex" || e.voice == "Vocal")
+                .collect();
+            if let Some((action, effort)) = explorer.choose(!journal.is_empty() && tx_cortex.is_some(), Instant::now()) {
+                metabolism.report_effort(effort);
+                let _ = tx_thoughts.send(Thought::new(MindVoice::Agency,
+                    format!("🧭 EXPLORE: Bored after {:.0}s of silence -> {:?}", silence_secs, action)));
+                match action {
+                    Action::Ruminate => {
+                        let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::Ruminate);
+                    },
+                    Action::Imagine => {
+                        if let Some((heard, sound)) = explorer.imagined_sound() {
+                            ego.inject_embedding(&sound, crate::core::reservoir::NeuronRegion::Auditory);
+                            let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::Probe { text: heard });
+                        }
+                    },
+                    Action::Question => {
+                        let topic: String = journal.last().map(|e| textutil::truncate_chars(&e.text, 200).to_string()).unwrap_or_default();
+                        let chem = chemistry.lock().unwrap();
+                        let input = CortexInput {
+                            mode: crate::cortex::planet::CortexMode::Think,
+                            text: format!("Me pregunto sobre algo que pensé: \"{}\". ¿Qué no entendí?", topic),
+                            bio_state: "Curious".to_string(),
+                            bio_context: String::new(),
+                            _somatic_state: "Idle".to_string(),
+                            _long_term_memory: None,
+                            _cpu_load: daemon.last_body_state.cpu_usage,
+                            _ram_pressure: daemon.last_body_state.ram_usage,
+                            _cognitive_impairment: 0.0,
+                            entropy: daemon.current_entropy,
+                            adenosine: chem.adenosine,
+                            dopamine: chem.dopamine,
+                            cortisol: chem.cortisol,
+                            _oxytocin: chem.oxytocin,
+                            temperature_clamp: None,
+                        };
+                        if let Some(tx) = &tx_cortex {
+                            tx.send(input, CortexPriority::Agency);
+                        }
+                    },
+                    Action::Look => {} // Resolved by the next frame from the eyes
+                }
+            }
+        }
+
+        frame_clock.lap(Phase::Agency, Instant::now());
+
+        // LEXICON HOT RELOAD (edit lexicon.toml while awake)
+        if let Some(msg) = lexicon.refresh() {
+            let _ = tx_thoughts.send(Thought::new(MindVoice::System, msg));
+        }
+
+        // CONNECTOME EXPORT: hand out a copy, the HTTP thread does the (slow) writing
+        while let Ok(reply) = rx_connectome.try_recv() {
+            let _ = reply.send(ego.clone());
+        }
+        while let Ok((id, reply)) = rx_neuron.try_recv() {
+            let region_map = ego.region_map().to_vec();
+            let _ = reply.send(ego.inspect(id, &region_map));
+        }
+        while let Ok(reply_tx) = rx_memory_stats.try_recv() {
+            let _ = tx_mem.send(MemoryCommand::GetStats { reply_tx });
+        }
+        // THE WORKBENCH: admin commands (parsed and authorized by the IPC thread)
+        while let Ok(AdminRequest { command, reply_tx }) = rx_admin.try_recv() {
+            let reply = match command {
+                AdminCommand::Help => Ok(admin::help()),
+                AdminCommand::Save => {
+                    let snapshot = chemistry.lock().unwrap().clone();
+                    autosaver.save(&ego, snapshot, daemon.ticks, &tx_thoughts);
+                    let _ = tx_mem.send(MemoryCommand::ForceSave);
+                    Ok(serde_json::json!({ "checkpoint": "started", "ticks": daemon.ticks }))
+                },
+                AdminCommand::Consolidate => {
+                    let _ = tx_mem.send(MemoryCommand::EnterDeepSleep); // Rounds until the backlog is empty
+                    Ok(serde_json::json!({ "consolidation": "scheduled" }))
+                },
+                AdminCommand::Regions => serde_json::to_value(ego.region_stats()).map_err(|e| e.to_string()),
+                AdminCommand::Set { chemical, value } => {
+                    let mut chem = chemistry.lock().unwrap();
+                    let level = match chemical {
+                        "dopamine" => &mut chem.dopamine,
+                        "cortisol" => &mut chem.cortisol,
+                        "adenosine" => &mut chem.adenosine,
+                        "oxytocin" => &mut chem.oxytocin,
+                        "serotonin" => &mut chem.serotonin,
+                        _ => &mut chem.glucose,
+                    };
+                    let from = std::mem::replace(level, value);
+                    drop(chem);
+                    announce(EventKind::ManualOverride, serde_json::json!({ "command": "set", "chemical": chemical, "from": from, "to": value }));
+                    Ok(serde_json::json!({ "chemical": chemical, "from": from, "to": value }))
+                },
+                AdminCommand::Lexicon => Ok(serde_json::json!({ "lexicon": lexicon.reload(), "terms": lexicon.term_count() })),
+                AdminCommand::Clients => Ok(serde_json::json!({
+                    "ipc": ipc_client_count.load(Ordering::Relaxed),
+                    "websocket": ws_clients.lock().unwrap().len(),
+                })),
+                AdminCommand::Tail(n) => serde_json::to_value(timeline.query(0, None, n)).map_err(|e| e.to_string()),
+                // The SYS: commands of old, through the same control path (ManualOverride and all)
+                AdminCommand::Sleep => Ok(control(&bus, ControlEvent::Sleep)),
+                AdminCommand::Poke => Ok(control(&bus, ControlEvent::Poke)),
+                AdminCommand::Privacy(on) => Ok(control(&bus, ControlEvent::Privacy(on))),
+                AdminCommand::ReloadGenome => Ok(control(&bus, ControlEvent::ReloadGenome)),
+                AdminCommand::Lesion(Some(region)) => {
+                    let mask = ego.region_mask(region);
+                    let silenced = ego.set_lesion(&mask);
+                    // Part of the body went quiet all at once: something is wrong
+                    let mut chem = chemistry.lock().unwrap();
+                    chem.cortisol = (chem.cortisol + 0.15).min(1.0);
+                    drop(chem);
+                    let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🩹 LESION: {:?} silenced ({} neurons, synapses kept).", region, silenced)));
+                    announce(EventKind::ManualOverride, serde_json::json!({ "command": "lesion", "region": format!("{:?}", region), "neurons": silenced }));
+                    Ok(serde_json::json!({ "lesion": format!("{:?}", region), "neurons": silenced }))
+                },
+                AdminCommand::Lesion(None) => {
+                    let lifted = ego.lesioned().len();
+                    ego.clear_lesion();
+                    let _ = tx_thoughts.send(Thought::new(MindVoice::System, format!("🩹 LESION: lifted ({} neurons back).", lifted)));
+                    announce(EventKind::ManualOverride, serde_json::json!({ "command": "lesion", "region": null, "neurons": lifted }));
+                    Ok(serde_json::json!({ "lesion": null, "lifted": lifted }))
+                },
+            };
+            let _ = reply_tx.send(reply);
+        }
+
+        // No Planet: reply_tx is dropped here and the request answers 503
+        while let Ok(reply_tx) = rx_consciousness.try_recv() {
+            if let Some(tx) = &tx_cortex {
+                tx.command(CortexCommand::DumpHistory { reply_tx });
+            }
+        }
+
+        // MEMORY PING (health): a hippocampus that stops answering goes offline, and back when it answers
+        let now = Instant::now();
+        if memory_probe.due(now, config.health.memory_ping_secs) {
+            let (reply_tx, reply_rx) = mpsc::channel();
+            if tx_mem.send(MemoryCommand::Ping { reply_tx }).is_ok() {
+                memory_probe.sent(reply_rx, now);
+            } else if let Some(status) = health::memory_verdict(components.status(components::MEMORY).as_ref(), false, config.health.memory_timeout_secs) {
+                components.report(components::MEMORY, status);
+            }
+        }
+        if let Some(answered) = memory_probe.poll(now, config.health.memory_timeout_secs) {
+            if let Some(status) = health::memory_verdict(components.status(components::MEMORY).as_ref(), answered, config.health.memory_timeout_secs) {
+                components.report(components::MEMORY, status);
+            }
+        }
+        health.update(|v| {
+            v.last_tick = Some(now);
+            v.whisper_queue_depth = ear_stats.queue_depth.load(Ordering::Relaxed);
+            v.clients = ws_clients.lock().map(|c| c.len()).unwrap_or(0) + ipc_client_count.load(Ordering::Relaxed);
+            v.memory_rtt = memory_probe.rtt;
+            v.memory_waiting = memory_probe.waiting(now);
+        });
+
+        // STARTUP COMPLETE: one structured line once every component has reported (for orchestration)
+        if !startup_announced {
+            if let Some(line) = health::startup_complete(&components.snapshot(), boot.elapsed()) {
+                startup_announced = true;
+                println!("{}", line);
+            }
+        }
+
+        // MEMORY STATS (telemetry): ask every ~5s, pick the answer up whenever it comes
+        if daemon.ticks % 300 == 0 && memory_stats_rx.is_none() {
+            let (reply_tx, reply_rx) = mpsc::channel();
+            if tx_mem.send(MemoryCommand::GetStats { reply_tx }).is_ok() {
+                memory_stats_rx = Some(reply_rx);
+            }
+        }
+        if let Some(stats) = memory_stats_rx.as_ref().and_then(|rx| rx.try_recv().ok()) {
+            memory_stats_rx = None;
+            if let Ok(mut state) = web_state.lock() {
+                state.memory = stats;
+            }
+        }
+
+        // AUTOSAVE (Crash Insurance): clone under the lock, serialize off-thread
+        if autosaver.due() {
+            let snapshot = chemistry.lock().unwrap().clone();
+            autosaver.save(&ego, snapshot, daemon.ticks, &tx_thoughts);
+            let _ = tx_mem.send(crate::core::hippocampus::MemoryCommand::ForceSave);
+        }
         
+        // DEEP IDLE (Asleep + Silence + Nobody watching)
+        {
+            let frame = IdleReading {
+                adenosine: chemistry.lock().unwrap().adenosine,
+                audio_rms: daemon.last_spectrum.rms,
+                clients: ws_clients.lock().unwrap().len() + ipc_client_count.load(Ordering::Relaxed),
+                stimulated: events.iter().any(|e| matches!(e,
+                    Event::Sensory(SensoryEvent::Stimulus(_)) | Event::Sensory(SensoryEvent::Heard(_)) | Event::Control(_))),
+            };
+            if daemon.update_idle(&config.idle, frame, loop_start, &tx_thoughts) {
+                ego.set_exposure_paused(daemon.deep_idle || latency.shedding());
+            }
+        }
+
+        // FRAME TIME (what the watchdog and the dashboard see; the sleep below is not work)
+        frame_clock.lap(Phase::Housekeeping, Instant::now());
+        let work_ms = frame_clock.end(Instant::now());
+        watchdog.frame(work_ms, 1000.0 / daemon.frame_hz(&config.idle));
+
         // DYNAMIC SLEEP (Heartbeat Control)
-        let target_frame_time = Duration::from_secs_f32(1.0 / current_hz);
-        let elapsed_loop = loop_start.elapsed();
-        if elapsed_loop < target_frame_time {
-            thread::sleep(target_frame_time - elapsed_loop);
+        let target_frame_time = Duration::from_secs_f32(1.0 / daemon.frame_hz(&config.idle));
+        if daemon.deep_idle {
+            // Wait on the bus instead of sleeping: a stimulus or a sound above the floor
+            // ends the frame early, so full rate is back on the very next tick.
+            while let Some(remaining) = target_frame_time.checked_sub(loop_start.elapsed()) {
+                match rx_events.recv_timeout(remaining) {
+                    Ok(event) => {
+                        let wakes = match &event {
+                            Event::Sensory(SensoryEvent::Spectrum(spec)) => spec.rms >= config.idle.noise_floor_rms,
+                            Event::Sensory(SensoryEvent::Stimulus(_)) | Event::Sensory(SensoryEvent::Heard(_)) | Event::Control(_) => true,
+                            _ => false,
+                        };
+                        carried.push(event);
+                        if wakes { break; }
+                    },
+                    Err(_) => break,
+                }
+            }
+        } else {
+            let elapsed_loop = loop_start.elapsed();
+            if elapsed_loop < target_frame_time {
+                thread::sleep(target_frame_time - elapsed_loop);
+            }
         }
     } // End Loop
 
     // --- DEATH (Shutdown & Mutation) ---
     println!("\n💀 ALEPH DAEMON SHUTTING DOWN... Initiating Soul Crystallization.");
+
+    // LAST WORDS: the stream of consciousness goes to disk next to the journal
+    if let Some(tx) = tx_cortex.as_ref().filter(|_| config.consciousness.dump_on_shutdown) {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        tx.command(CortexCommand::DumpHistory { reply_tx });
+        match reply_rx.recv_timeout(Duration::from_secs(5)) {
+            Ok(segments) if !segments.is_empty() => {
+                let now = Local::now();
+                let dir = std::path::Path::new(&config.actuators.notes_dir);
+                let path = dir.join(format!("consciousness-{}.txt", now.format("%Y%m%d-%H%M%S")));
+                let text = context::render_dump(&segments, config.consciousness.max_dump_bytes, &now.to_rfc3339());
+                match fs::create_dir_all(dir).and_then(|_| fs::write(&path, text)) {
+                    Ok(()) => println!("🧾 Stream of consciousness saved: {} ({} segments)", path.display(), segments.len()),
+                    Err(e) => println!("⚠️ Stream of consciousness not saved ({}): {}", path.display(), e),
+                }
+            },
+            Ok(_) => {},
+            Err(e) => println!("⚠️ Planet did not hand over its history ({}). Not dumped.", e),
+        }
+    }
     
     // Calculate Average Friction
-    let avg_friction = if ticks > 0 { _session_stress_accum / (ticks as f32) } else { 0.0 };
+    let avg_friction = daemon.avg_friction();
     // Note: _session_stress_accum currently tracks (cortisol + adenosine). 
     // Ideally we'd track specific "friction" events, but Stress is a good proxy for "Difficulty of Life".
     
@@ -1641,3 +2189,114 @@ pub fn run(listen_path: Option<String>, headless: bool) -> Result<()> {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cortex::queue::{cortex_queue, CortexDelivery};
+    use crate::core::config::PeopleConfig;
+    use crate::senses::prosody::Prosody;
+
+    fn company() -> Company {
+        let config = AlephConfig::default();
+        let dir = std::env::temp_dir().join(format!("aleph-daemon-{}", std::process::id()));
+        Company {
+            people: People::load(PeopleConfig { path: dir.join("people.json").to_string_lossy().to_string(), ..Default::default() }),
+            bonding: Bonding::new(),
+            checkin: CheckIn::new(config.checkin.clone()),
+            repertoire: Repertoire::load(config.repertoire.clone()),
+            conversation: ConversationThread::new(config.thread.clone()),
+        }
+    }
+
+    #[test]
+    fn each_ipc_connection_pays_its_own_stimulus_bucket() {
+        let path = std::env::temp_dir().join(format!("aleph-ipc-{}.sock", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let (_tx_telemetry, rx_telemetry) = mpsc::channel();
+        let (tx_stimulus, rx_stimulus) = mpsc::channel();
+        let (tx_admin, _rx_admin) = mpsc::channel();
+        // One stimulus per client, and no refill within the test
+        let config = crate::core::config::StimulusConfig { rate_per_sec: 0.0001, burst: 1.0, ..Default::default() };
+        IpcServer {
+            listener: Some(listener),
+            telemetry: rx_telemetry,
+            stimulus: tx_stimulus,
+            limiter: Arc::new(Mutex::new(StimulusLimiter::new(config))),
+            admin: tx_admin,
+            admin_secret: String::new(),
+            client_count: Arc::new(AtomicUsize::new(0)),
+        }.spawn();
+
+        let say = |client: &mut UnixStream, text: &str| {
+            let packet = AlephPacket::Stimulus { text: text.to_string(), force: 1.0 };
+            client.write_all(format!("{}\n", serde_json::to_string(&packet).unwrap()).as_bytes()).unwrap();
+        };
+        let mut first = UnixStream::connect(&path).unwrap();
+        say(&mut first, "uno");
+        say(&mut first, "dos");
+        let mut second = UnixStream::connect(&path).unwrap();
+        say(&mut second, "otro");
+
+        let mut admitted: Vec<String> = (0..2).filter_map(|_| rx_stimulus.recv_timeout(Duration::from_secs(5)).ok()).collect();
+        admitted.extend(rx_stimulus.recv_timeout(Duration::from_millis(500)));
+        admitted.sort();
+        assert_eq!(admitted, ["otro", "uno"], "the first client's burst spent the second client's bucket, or was not limited");
+        let _ = fs::remove_file(&path);
+    }
+
+    /// Synthetic sensory events in at the bus, out through the loop's handlers:
+    /// the chemistry moves, the Planet gets its input, the logger hears about it.
+    #[test]
+    fn sensory_events_reach_chemistry_cortex_and_log() {
+        let config = AlephConfig::default();
+        let bus = Bus::new();
+        let (physics, logger) = (bus.subscribe(), bus.subscribe());
+        let (tx_thoughts, rx_thoughts) = mpsc::channel();
+        let (tx_mem, rx_mem) = mpsc::channel();
+        let (tx_cortex, rx_cortex) = cortex_queue(Duration::from_secs(60));
+        bus.bridge("thoughts", rx_thoughts, |t| Some(Event::Thought(t)));
+
+        bus.publish(Event::Sensory(SensoryEvent::Stimulus("hola, ¿estás ahí?".to_string())));
+        bus.publish(Event::Sensory(SensoryEvent::Heard(Utterance { text: "eres un idiota".to_string(), prosody: Prosody::default() })));
+
+        let wire = Wiring { config: &config, thoughts: &tx_thoughts, memory: &tx_mem, cortex: Some(&tx_cortex), bus: &bus };
+        let (mut daemon, mut ego) = (DaemonState::new(), FractalReservoir::seeded(64, 16, 0.95, 0.2, 7));
+        let mut chem = Neurotransmitters { dopamine: 0.2, cortisol: 0.2, ..Neurotransmitters::new() };
+        let (mut lexicon, mut company) = (Lexicon::builtin(), company());
+        // Collected first: the handlers' own thoughts are bridged back onto the bus
+        let published: Vec<Event> = physics.try_iter().collect();
+        for event in published {
+            match event {
+                Event::Sensory(SensoryEvent::Stimulus(text)) => {
+                    on_stimulus(&wire, &text, &mut daemon, &mut chem, &mut company, None);
+                    assert!(chem.dopamine > 0.4 && chem.cortisol < 0.2, "attention interests and soothes");
+                },
+                Event::Sensory(SensoryEvent::Heard(heard)) => {
+                    let before = chem.cortisol;
+                    assert!(on_heard(&wire, &heard, &mut daemon, &mut ego, &mut chem, &mut lexicon, &mut company));
+                    assert!(chem.cortisol > before, "an insult is stressful");
+                },
+                _ => panic!("only the two sensory events were published"),
+            }
+        }
+
+        // The typed text goes to the Planet; the heard one only to memory
+        match rx_cortex.recv_by(Some(Instant::now())) {
+            Some(CortexDelivery::Input(input, CortexPriority::User)) => assert_eq!(input.text, "hola, ¿estás ahí?"),
+            _ => panic!("the stimulus never reached the cortex queue"),
+        }
+        assert!(!matches!(rx_cortex.recv_by(Some(Instant::now())), Some(CortexDelivery::Input(..))));
+        assert!(matches!(rx_mem.try_recv(), Ok(MemoryCommand::ProcessStimulus { text, provenance: Provenance::Heard { .. }, .. }) if text == "eres un idiota"));
+
+        // The logger is just another subscriber: it saw both events and the handlers' thoughts
+        drop(tx_thoughts);
+        let logged: Vec<Event> = logger.iter().take_while(|e| !matches!(e, Event::Control(ControlEvent::SourceClosed("thoughts")))).collect();
+        let thoughts: Vec<String> = logged.iter().filter_map(|e| match e { Event::Thought(t) => Some(t.text.clone()), _ => None }).collect();
+        assert_eq!(logged.len() - thoughts.len(), 2);
+        assert!(thoughts.iter().any(|t| t.contains("💬 'hola")), "{:?}", thoughts);
+        assert!(thoughts.iter().any(|t| t.contains("🎤 Hearing: 'eres un idiota'")), "{:?}", thoughts);
+    }
+}
@@ -0,0 +1,1163 @@
+// src/core/daemon_state.rs
+// THE HEARTBEAT: The daemon loop's state machine, out of the loop
+//
+// Sleep/wake, heart rate, boredom timer, session stress and the short-term
+// stream used to be loose locals inside `daemon::run`. They live here now, and
+// the decisions taken on them (SYS: commands, stimulus, metabolism, agency,
+// telemetry) are methods that receive their dependencies explicitly, so they
+// can be driven without audio, models or sockets.
+
+use std::collections::VecDeque;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::Instant;
+use nalgebra::DVector;
+use serde::Serialize;
+
+use crate::core::attention::Attention;
+use crate::core::bus::ControlEvent;
+use crate::core::chemistry::Neurotransmitters;
+use crate::core::config::{IdleConfig, ReplayConfig, SleepTalkConfig};
+use crate::core::decisions::{self, GateDecision, MembraneDecision};
+use crate::core::gate::ExpressionGate;
+use crate::core::genome::Genome;
+use crate::core::interoception::Heartbeat;
+use crate::core::ipc::AlephPacket;
+use crate::core::neocortex::CognitiveEvent;
+use crate::core::reservoir::FractalReservoir;
+use crate::core::satellite::Satellite;
+use crate::core::sensory::{ChannelRange, SensoryRegistry};
+use crate::core::sieve::resonance_of;
+use crate::core::standby::Standby;
+use crate::core::thought::{Thought, MindVoice};
+use crate::cortex::planet::{CortexInput, CortexMode};
+use crate::senses::ears::AudioSpectrum;
+use crate::senses::prosody::{Prosody, VoiceProfile};
+use crate::senses::proprioception::BodyStatus;
+use crate::senses::transcript::WordEchoes;
+
+/// Resting heart rate (Hz) of the loop.
+pub const HZ_BASE: f32 = 60.0;
+/// Lines kept in the short-term stream (So TUI doesn't flicker empty).
+pub const HISTORY_LEN: usize = 30;
+
+/// Where the daemon's narration goes (mpsc in production, anything in a harness).
+pub trait ThoughtSink {
+    fn think(&self, voice: MindVoice, text: String);
+    /// A thought other subscribers recognize by its payload ({"event": ...}).
+    fn think_event(&self, voice: MindVoice, text: String, payload: serde_json::Value);
+}
+
+impl ThoughtSink for Sender<Thought> {
+    fn think(&self, voice: MindVoice, text: String) {
+        let _ = self.send(Thought::new(voice, text));
+    }
+
+    fn think_event(&self, voice: MindVoice, text: String, payload: serde_json::Value) {
+        let _ = self.send(Thought::new(voice, text).with_payload(payload));
+    }
+}
+
+/// Circadian night (local hours): the strongest sleep pressure, and the default
+/// quiet hours for desktop notifications (actuators/notify.rs).
+pub const NIGHT_START_HOUR: u32 = 23;
+pub const NIGHT_END_HOUR: u32 = 6;
+
+/// `hour` in [start, end), wrapping past midnight when start > end (23 -> 6).
+pub fn within_hours(hour: u32, start: u32, end: u32) -> bool {
+    if start <= end {
+        hour >= start && hour < end
+    } else {
+        hour >= start || hour < end
+    }
+}
+
+/// Adenosine above which sleep is Deep (slow-wave); below it, REM.
+pub const DEEP_SLEEP_ADENOSINE: f32 = 0.35;
+
+/// Sleep stage, derived from the sleep flag and the pressure still left to clear.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Default)]
+pub enum SleepStage {
+    #[default]
+    Awake,
+    Deep, // Heavy adenosine: consolidation and replay
+    Rem,  // Light sleep before waking
+}
+
+pub struct DaemonState {
+    pub ticks: u64,
+    pub is_dreaming: bool,               // SLEEP STATE (Persistent)
+    pub last_interaction_tick: u64,      // Boredom timer for Spontaneous Thought
+    pub current_hz: f32,                 // VARIABLE METABOLISM (Heart Rate)
+    pub heart: Heartbeat,                // Interoception: the felt pulse and HRV of current_hz
+    pub current_entropy: f32,
+    pub interaction_count: u64,          // Successful (vocalized) interactions
+    pub session_stress_accum: f32,       // Session Stats for Mutation
+    pub session_novelty_accum: f32,
+    pub telemetry_history: VecDeque<String>,
+    pub last_spectrum: AudioSpectrum,
+    pub last_prosody: Prosody,           // Tone of the last utterance (telemetry)
+    pub voice_profile: VoiceProfile,     // Usual speaker's pitch ("familiar" voice)
+    pub last_body_state: BodyStatus,
+    pub sensory: SensoryRegistry,        // Which channel of the sensory vector belongs to which sense
+    pub sensory_vector: Vec<f32>,        // SENSORY STATE: decaying buffer, laid out by `sensory`
+    pub word_echoes: WordEchoes,         // Heard sentences not yet hashed into it
+    pub deep_idle: bool,                 // Asleep, silent and unobserved (slow heartbeat)
+    pub privacy_mode: bool,              // Do-Not-Disturb: spectrum only, no words
+    pub last_stimulus: Option<u64>,      // Id of the last Sensory thought (cause of the next Vocal one)
+    pub memory_replays: u64,             // Memories replayed into the reservoir in Deep sleep
+    pub rem_cycle: u64,                  // REM entries since boot (sleep-talk budget)
+    last_stage: SleepStage,              // Entering Deep schedules consolidation
+    quiet_since: Option<Instant>,
+}
+
+/// What deep idle reads each frame.
+#[derive(Debug, Clone, Copy)]
+pub struct IdleReading {
+    pub adenosine: f32,
+    pub audio_rms: f32,
+    pub clients: usize,    // WebSocket + IPC
+    pub stimulated: bool,  // A stimulus, an utterance or a control this frame
+}
+
+/// What became of a word the field collapsed into (`DaemonState::express`).
+pub enum Expression {
+    /// Asleep: a dream fragment; `mumbled` when sleep-talk lets it reach the mouth.
+    Dream { thought: Thought, mumbled: bool },
+    /// Awake: Vocal (spoken) or Cortex (silent insight), and the gate's decision.
+    Awake { thought: Thought, decision: GateDecision },
+}
+
+/// Adenosine level that forces sleep. Tolerance is genetic (0.0-1.0), but with a
+/// sanity floor: at tolerance 0.5 collapse shouldn't be at 47% adenosine, that's just a nap.
+pub fn collapse_threshold(stress_tolerance: f32) -> f32 {
+    0.95 + stress_tolerance * 0.05
+}
+
+/// DYNAMIC PACING: ticks of silence before speaking unprompted.
+/// Dopa 0.9 -> 300 ticks (5s) "Manic" | Dopa 0.5 -> 1200 ticks (20s) "Conversation" | Dopa < 0.5 -> "Passive"
+pub fn agency_delay(dopamine: f32) -> u64 {
+    if dopamine > 0.8 {
+        300
+    } else if dopamine > 0.5 {
+        1200
+    } else {
+        999999 // Effectively infinite
+    }
+}
+
+/// Sleep consolidation: prune the unused connections (neurons left with none at
+/// all die), or, if there are none left, allow a little growth. Returns the Dream line.
+pub fn consolidate(ego: &mut FractalReservoir) -> String {
+    let pruned = ego.prune_inactive_neurons();
+    let died = ego.remove_neurons(&ego.isolated_neurons());
+    if pruned > 0 && died > 0 {
+        format!("🧠 Synaptic Pruning: Removed {} unused connections. {} disconnected neurons died.", pruned, died)
+    } else if pruned > 0 {
+        format!("🧠 Synaptic Pruning: Removed {} unused connections.", pruned)
+    } else {
+        // If fully optimized, small growth allowed
+        ego.neurogenesis(5);
+        "🌱 Optimization Complete. Minor structural growth.".to_string()
+    }
+}
+
+/// A recalled memory's novelty: repetition tires, surprise interests (and, reinforced,
+/// makes the genome a little more curious).
+pub fn feel_novelty(chem: &mut Neurotransmitters, curiosity: &mut f32, novelty: f32) {
+    if novelty < 0.2 {
+        chem.adenosine += 0.05; // Boredom / Repetition fatigue
+    } else {
+        chem.dopamine += novelty * 0.5; // Interest spike
+        *curiosity = (*curiosity + 0.001).min(1.0);
+    }
+}
+
+/// Attention the membrane filters with: alertness (1 - adenosine) and interest
+/// (dopamine), plus a focus ALEPH asked for (volition). Tired is not deaf: never below 0.2.
+pub fn membrane_attention(chem: &Neurotransmitters, focus_bonus: f32) -> f32 {
+    ((1.0 - chem.adenosine) * 0.5 + chem.dopamine * 0.8 + focus_bonus).clamp(0.2, 1.0)
+}
+
+/// What a Neocortex observation does to the body. Returns true on boredom
+/// (spontaneous thought acts on it later in the frame).
+pub fn react(event: &CognitiveEvent, chem: &mut Neurotransmitters, ego: &mut FractalReservoir) -> bool {
+    match event {
+        CognitiveEvent::Neurogenesis => {
+            ego.neurogenesis(2); // Boost growth
+            chem.dopamine = (chem.dopamine + 0.1).min(1.0); // Reward growth
+        },
+        CognitiveEvent::Trauma(_) => {
+            chem.cortisol = (chem.cortisol + 0.05).min(1.0);
+        },
+        CognitiveEvent::Boredom => {
+            chem.dopamine *= 0.995; // Gentle boredom fade, not a crash
+            return true;
+        },
+        _ => {}
+    }
+    false
+}
+
+impl DaemonState {
+    pub fn new() -> Self {
+        Self {
+            ticks: 0,
+            is_dreaming: false,
+            last_interaction_tick: 0,
+            current_hz: HZ_BASE,
+            heart: Heartbeat::default(),
+            current_entropy: 0.0,
+            interaction_count: 0,
+            session_stress_accum: 0.0,
+            session_novelty_accum: 0.0,
+            telemetry_history: VecDeque::with_capacity(HISTORY_LEN),
+            last_spectrum: AudioSpectrum::default(),
+            last_prosody: Prosody::default(),
+            voice_profile: VoiceProfile::default(),
+            last_body_state: BodyStatus { cpu_usage: 0.0, ram_usage: 0.0 },
+            sensory: SensoryRegistry::default(),
+            sensory_vector: vec![0.0; crate::core::sensory::SENSORY_CHANNELS],
+            word_echoes: WordEchoes::default(),
+            deep_idle: false,
+            privacy_mode: false,
+            last_stimulus: None,
+            memory_replays: 0,
+            rem_cycle: 0,
+            last_stage: SleepStage::Awake,
+            quiet_since: None,
+        }
+    }
+
+    /// Give a new sense its channels. If the sensory vector had to grow, the
+    /// reservoir grows the same input columns (old weights untouched).
+    pub fn register_sense(&mut self, name: &str, len: usize, ego: &mut FractalReservoir) -> ChannelRange {
+        let (range, _) = self.sensory.register(name, len);
+        self.sensory_vector.resize(self.sensory.len(), 0.0);
+        ego.grow_inputs(self.sensory.len().saturating_sub(ego.input_channels()));
+        range
+    }
+
+    pub fn sleep_stage(&self, adenosine: f32) -> SleepStage {
+        if !self.is_dreaming {
+            SleepStage::Awake
+        } else if adenosine > DEEP_SLEEP_ADENOSINE {
+            SleepStage::Deep
+        } else {
+            SleepStage::Rem
+        }
+    }
+
+    /// The stage change since the last frame, as (from, to). Entering REM counts a cycle.
+    pub fn stage_transition(&mut self, adenosine: f32) -> Option<(SleepStage, SleepStage)> {
+        let stage = self.sleep_stage(adenosine);
+        if stage == self.last_stage {
+            return None;
+        }
+        if stage == SleepStage::Rem {
+            self.rem_cycle += 1;
+        }
+        Some((std::mem::replace(&mut self.last_stage, stage), stage))
+    }
+
+    /// CHRONORECEPTION: the local `hour` reaches the chemistry. Darkness builds sleep
+    /// pressure a little every tick and the morning clears the fog (the rest of the day,
+    /// fatigue is left to `chemistry.tick`); every ~10s the circadian curve pushes
+    /// adenosine toward the time of day. Do-Not-Disturb makes ALEPH slowly drowsy.
+    pub fn sense_time(&self, chem: &mut Neurotransmitters, hour: u32, delta_time: f32) {
+        if hour >= 23 || hour < 7 {
+            chem.adenosine = (chem.adenosine + 0.00002).min(1.0); // Melatonin rises
+        } else if hour < 10 {
+            chem.adenosine = (chem.adenosine - 0.0001).max(0.0); // Morning boost
+        }
+
+        if self.ticks % 600 == 0 {
+            let circadian_pressure = if within_hours(hour, NIGHT_START_HOUR, NIGHT_END_HOUR) {
+                0.005 // Night: Strong sleep pressure (+0.03/min)
+            } else if hour >= 20 {
+                0.002 // Evening: Wind down
+            } else if (6..9).contains(&hour) {
+                -0.005 // Morning: Cortisol spike / Waking up
+            } else {
+                -0.001 // Day: Maintenance (fighting fatigue)
+            };
+            chem.adenosine = (chem.adenosine + circadian_pressure).clamp(0.0, 1.0);
+        }
+
+        // SENSORY DEPRIVATION (+0.03/min)
+        if self.privacy_mode {
+            chem.adenosine = (chem.adenosine + 0.0005 * delta_time).min(1.0);
+        }
+    }
+
+    /// The reservoir's input this frame (at least `size` long): the last spectrum spread
+    /// over the neurons (bass, mids and highs on every 5th, 11th and 17th, amplified
+    /// because raw bands are low), plus the sensory vector with the heartbeat channel
+    /// set, through the attention spotlight (cortisol narrows, dopamine reopens).
+    pub fn sensory_input(&mut self, size: usize, chem: &Neurotransmitters, attention: &mut Attention, delta_time: f32) -> DVector<f32> {
+        let mut input = DVector::zeros(size.max(self.sensory_vector.len()));
+        let bass = self.last_spectrum.bass * 8.0;
+        let mids = self.last_spectrum.mids * 4.0;
+        let highs = self.last_spectrum.highs * 3.0;
+        for (i, x) in input.iter_mut().enumerate() {
+            // Primes, to avoid patterns
+            if i % 5 == 0 { *x += bass; }
+            else if i % 11 == 0 { *x += mids; }
+            else if i % 17 == 0 { *x += highs; }
+        }
+
+        // INTEROCEPTION: the loop's own pulse (current_hz/60 beats/s)
+        let beat = self.heart.beat(self.current_hz, delta_time);
+        self.sensory.set(&mut self.sensory_vector, crate::core::sensory::HEARTBEAT, &[beat]);
+        attention.update(&self.sensory_vector, chem.cortisol, chem.dopamine);
+        for (i, sensed) in self.sensory_vector.iter().enumerate() {
+            input[i] += sensed * attention.sensory_weight(i);
+        }
+        input
+    }
+
+    /// DREAM REPLAY: a memory's encoding-time activity is re-lived, and the synapses it
+    /// recruits strengthen offline (no chemistry, no cortex).
+    pub fn replay(&mut self, ego: &mut FractalReservoir, memory: &str, activity: &[f32], config: &ReplayConfig, delta_time: f32, thoughts: &dyn ThoughtSink) {
+        ego.replay_pattern(activity, config.gain);
+        let strengthened = ego.hebbian_update(config.reinforcement, delta_time);
+        self.memory_replays += 1;
+        thoughts.think(MindVoice::Dream, format!("🌀 REPLAY: {} ({} synapses)", memory, strengthened));
+    }
+
+    /// THE MEMBRANE (input): whether recalled text reaches the Planet. Nothing gets through
+    /// while the ears saturate the reservoir (`auditory_share` of the attention breakdown);
+    /// otherwise the Satellite filters it, and a tool-like request hurts (cortisol).
+    /// Returns the text that passed and the decision for the minutes.
+    pub fn membrane(&self, satellite: &mut Satellite, text: &str, chem: &mut Neurotransmitters, auditory_share: f32, now: Instant, thoughts: &dyn ThoughtSink) -> (Option<String>, MembraneDecision) {
+        let attention = membrane_attention(chem, satellite.focus_bonus(now));
+        let drowned = satellite.drowned_out(auditory_share);
+        let (passed, severity) = if drowned {
+            thoughts.think(MindVoice::System,
+                format!("🔇 MEMBRANE: text ignored, the auditory pathway is saturating ({:.0}%)", auditory_share * 100.0));
+            (None, 0.0)
+        } else {
+            satellite.filter_input(text, self.current_entropy, attention, chem.oxytocin)
+        };
+        satellite.record(passed.is_some(), severity, attention, now);
+        let decision = MembraneDecision {
+            ts: decisions::now_ms(),
+            dopamine: chem.dopamine,
+            cortisol: chem.cortisol,
+            adenosine: chem.adenosine,
+            oxytocin: chem.oxytocin,
+            entropy: self.current_entropy,
+            attention,
+            auditory_share,
+            severity,
+            text_chars: text.chars().count(),
+            words: text.split_whitespace().count(),
+            passed: passed.is_some(),
+            reason: if drowned { "drowned_out" } else if passed.is_some() { "passed" } else { "hardened" },
+            text: Some(text.to_string()),
+        };
+
+        // STRUCTURAL PAIN (Ontological Error)
+        if severity > 0.0 {
+            chem.cortisol += severity * 0.1;
+            thoughts.think_event(MindVoice::System,
+                format!("🩸 ONTOLOGICAL ERROR detected (Severity {:.1}). Injecting Cortisol.", severity),
+                serde_json::json!({ "event": "ontological_error", "severity": severity }));
+        }
+        if passed.is_none() {
+            thoughts.think(MindVoice::System,
+                format!("🛡️ MEMBRANE HARDENED: Ignoring input (Entropy {:.2} > Attn {:.2})", self.current_entropy, attention));
+        }
+        (passed, decision)
+    }
+
+    /// What the Planet is asked about text that passed the membrane: the body in words
+    /// (`state` is the reservoir's description, `presence` who is around), the thread's
+    /// memory slot, and the chemistry. Too tired, or asleep, it only listens.
+    pub fn prompt(&self, chem: &Neurotransmitters, text: String, state: String, presence: Option<String>, memory: Option<String>, temperature_clamp: Option<f32>) -> CortexInput {
+        let bio_state = format!("Soy Aleph. Estado: {}. Fatiga: {:.0}%.", state, chem.get_cognitive_impairment() * 100.0);
+        let bio_context = match presence {
+            Some(present) => format!("{}\n{}", bio_state, present),
+            None => bio_state.clone(),
+        };
+        CortexInput {
+            mode: if chem.adenosine > 0.9 || self.is_dreaming { CortexMode::Listen } else { CortexMode::Think },
+            text,
+            bio_state,
+            bio_context,
+            _somatic_state: format!("CPU: {:.1}%", self.last_body_state.cpu_usage),
+            _long_term_memory: memory,
+            _cpu_load: self.last_body_state.cpu_usage,
+            _ram_pressure: self.last_body_state.ram_usage,
+            _cognitive_impairment: chem.get_cognitive_impairment(),
+            entropy: self.current_entropy,
+            adenosine: chem.adenosine,
+            dopamine: chem.dopamine,
+            cortisol: chem.cortisol,
+            _oxytocin: chem.oxytocin,
+            temperature_clamp,
+        }
+    }
+
+    /// THE GATEKEEPER (Decoupled Vocalization). Asleep, the waking gate is closed: fragments
+    /// are dreams (never waking speech, never an interaction) and only sleep-talk gets out.
+    /// Awake, a word is said only if meaning beats the effort of expression (`warmth`, the
+    /// bond with whoever is present, lowers the bar); said, it is an interaction answering
+    /// the last stimulus. `payload` (seed, resonance) travels with the thought.
+    pub fn express(&mut self, gate: &mut ExpressionGate, sleep_talk: &SleepTalkConfig, chem: &Neurotransmitters, warmth: f32, text: String, mut payload: serde_json::Value) -> Expression {
+        let resonance = resonance_of(Some(&payload));
+        if self.is_dreaming {
+            let rem = self.sleep_stage(chem.adenosine) == SleepStage::Rem;
+            let mumbled = gate.attempt_sleep_talk(sleep_talk, rem, self.rem_cycle, resonance.unwrap_or(0.0), &text).is_ok();
+            if mumbled {
+                payload["sleep_talk"] = serde_json::json!(true);
+            }
+            return Expression::Dream { thought: Thought::new(MindVoice::Dream, text).with_payload(payload), mumbled };
+        }
+
+        let verdict = gate.attempt_vocalization(chem.adenosine, self.current_entropy, chem.dopamine, warmth, &text, self.ticks);
+        let decision = GateDecision {
+            ts: decisions::now_ms(),
+            tick: self.ticks,
+            dopamine: chem.dopamine,
+            cortisol: chem.cortisol,
+            adenosine: chem.adenosine,
+            oxytocin: chem.oxytocin,
+            serotonin: chem.serotonin,
+            warmth,
+            entropy: self.current_entropy,
+            text_chars: text.chars().count(),
+            words: text.split_whitespace().count(),
+            resonance,
+            spoken: verdict.is_ok(),
+            reason: verdict.err().map_or("spoken", |reason| reason.name()),
+            text: Some(text.clone()),
+        };
+        let thought = if verdict.is_ok() {
+            self.interaction_count += 1;
+            Thought::new(MindVoice::Vocal, text).caused_by(self.last_stimulus)
+        } else {
+            Thought::new(MindVoice::Cortex, text)
+        };
+        Expression::Awake { thought: thought.with_payload(payload), decision }
+    }
+
+    /// Append to the short-term stream (bounded).
+    pub fn remember(&mut self, line: String) {
+        self.telemetry_history.push_back(line);
+        if self.telemetry_history.len() > HISTORY_LEN {
+            self.telemetry_history.pop_front();
+        }
+    }
+
+    pub fn latest_state(&self) -> String {
+        self.telemetry_history.back().cloned().unwrap_or_else(|| "Waiting for input...".to_string())
+    }
+
+    /// SYSTEM COMMANDS (Web Dashboard Control)
+    pub fn handle_control(&mut self, event: &ControlEvent, chem: &mut Neurotransmitters, ego: &mut FractalReservoir, standby: &mut Standby, thoughts: &dyn ThoughtSink) {
+        match event {
+            ControlEvent::Sleep => {
+                thoughts.think(MindVoice::Dream, "💤 HYPNOTIC INDUCTION RECEIVED. Drifting into REM Cycle...".to_string());
+
+                // MEMORY CONSOLIDATION (Pruning) instead of mindless growth
+                // "Optimization y Poda"
+                // On a standby copy (core/standby.rs): the loop keeps ticking while it walks the weights
+                if let Some(line) = standby.run(ego, "consolidation", Arc::new(consolidate)) {
+                    thoughts.think(MindVoice::Dream, line);
+                }
+
+                chem.adenosine = 0.95; // Force deep sleep mode
+                chem.cortisol = 0.0;   // Reset Panic/Stress
+                self.is_dreaming = true; // ENGAGE SLEEP
+            },
+            ControlEvent::Poke => {
+                thoughts.think(MindVoice::System, "⚡ SENSORY SHOCK. Awakening.".to_string());
+                chem.adenosine = 0.0;     // Reset fatigue
+                self.is_dreaming = false; // WAKE UP
+                // POKE IS NOT A REWARD. It is a Startle/Alert (Norepinephrine/Cortisol).
+                // Removed dopamine spike to maintain Mechanical Honesty.
+                // POKE should wake/alert but NOT be processed as text novelty.
+                chem.cortisol = (chem.cortisol + 0.1).min(1.0); // Increased startle
+            },
+            ControlEvent::SourceClosed(name) => {
+                thoughts.think(MindVoice::System, format!("🔌 Channel '{}' disconnected.", name));
+            },
+            ControlEvent::Privacy(on) => {
+                if self.privacy_mode != *on {
+                    self.privacy_mode = *on;
+                    if *on {
+                        self.word_echoes.clear(); // Words heard just before aren't felt either
+                    }
+                    thoughts.think(MindVoice::System, if *on {
+                        "🔒 DO NOT DISTURB: Words are no longer heard, stored or spoken about. Only the body listens.".to_string()
+                    } else {
+                        "🔓 DO NOT DISTURB OFF: Listening to words again.".to_string()
+                    });
+                }
+            },
+            ControlEvent::ReloadGenome => {
+                // Needs the genome and the Satellite: see `reload_genome`
+            },
+            ControlEvent::Feed(_) => {
+                // Eaten by the pantry in the loop (core/feeding.rs)
+            },
+        }
+    }
+
+    /// Genome hot-reload (SIGHUP / POST /genome/reload). Runs inside the loop, between
+    /// ticks, so traits never change halfway through a physics step.
+    /// stress_tolerance needs no push: `metabolic_step` reads it every tick (the caller
+    /// re-derives the phenotype for the trauma detector and the Planet).
+    /// Returns the traits that changed (trait, old, new).
+    pub fn reload_genome(&mut self, path: &str, seed: &mut Genome, ego: &mut FractalReservoir, satellite: &mut Satellite, thoughts: &dyn ThoughtSink) -> Vec<(&'static str, f32, f32)> {
+        let fresh = match Genome::read_from(path) {
+            Ok(g) => g,
+            Err(e) => {
+                thoughts.think(MindVoice::Error, format!("⚠️ GENOME RELOAD FAILED: {} (keeping current traits)", e));
+                return Vec::new();
+            }
+        };
+
+        let reload = seed.apply_live(&fresh);
+        for field in &reload.rejected {
+            thoughts.think(MindVoice::System, format!("⚠️ GENOME RELOAD: '{}' is structural, ignored until next life.", field));
+        }
+        if reload.applied.is_empty() {
+            thoughts.think(MindVoice::System, "🧬 GENOME RELOAD: No trait changes.".to_string());
+            return reload.applied;
+        }
+
+        ego.set_curiosity(seed.curiosity);
+        satellite.paranoia = seed.paranoia;
+        satellite._refractive_index = seed.refractive_index;
+        for (name, old, new) in &reload.applied {
+            thoughts.think(MindVoice::System, format!("🧬 GENOME RELOAD: {} {:.2} -> {:.2}", name, old, new));
+        }
+        reload.applied
+    }
+
+    /// User text (already sanitized, never a SYS: command). Applies the social chemistry
+    /// and returns the Think request for the Planet.
+    pub fn handle_stimulus(&mut self, text: &str, chem: &mut Neurotransmitters, temperature_clamp: Option<f32>, thoughts: &dyn ThoughtSink) -> CortexInput {
+        thoughts.think(MindVoice::System, format!("💬 '{}'", text));
+        // For now, treat as high-entropy injection
+        self.current_entropy += 0.1;
+
+        // WAKE UP EFFECT: User attention breaks the fatigue loop
+        chem.dopamine = (chem.dopamine + 0.3).min(1.0); // Spike interest
+        // ADENOSINE (Fatigue) IS NOT CLEARED BY TALKING. Needs sleep.
+        chem.cortisol = (chem.cortisol - 0.05).max(0.0); // Social soothing (mild)
+
+        CortexInput {
+            mode: CortexMode::Think, // Monitoring is explicit thought
+            text: text.to_string(),
+            bio_state: format!("Dopa:{:.2} Cort:{:.2} Aden:{:.2}", chem.dopamine, chem.cortisol, chem.adenosine),
+            bio_context: String::new(), // No text description — chemistry flows through parametric effects
+            _somatic_state: "Stimulated".to_string(),
+            _long_term_memory: None,
+            _cpu_load: self.last_body_state.cpu_usage,
+            _ram_pressure: self.last_body_state.ram_usage,
+            _cognitive_impairment: 0.0,
+            entropy: self.current_entropy.clamp(0.0, 1.0),
+            adenosine: chem.adenosine,
+            dopamine: chem.dopamine,
+            cortisol: chem.cortisol,
+            _oxytocin: chem.oxytocin,
+            temperature_clamp,
+        }
+    }
+
+    /// End of the physics step: heart rate, entropy, session stress, tick count,
+    /// forced sleep (genetic tolerance) and natural waking.
+    pub fn metabolic_step(&mut self, chem: &Neurotransmitters, entropy_output: f32, stress_tolerance: f32, thoughts: &dyn ThoughtSink) {
+        // VARIABLE METABOLISM: Base + (Dopamine * 60) - (Adenosine * 40)
+        // High Dopamine -> 120Hz. High Adenosine -> 20Hz.
+        let metabolic_drive = (chem.dopamine * 60.0) + (chem.cortisol * 30.0);
+        let metabolic_drag = chem.adenosine * 40.0;
+        let target_hz = (HZ_BASE + metabolic_drive - metabolic_drag).clamp(24.0, 120.0);
+
+        // Smooth transition (Heart Rate Variability)
+        self.current_hz += (target_hz - self.current_hz) * 0.05;
+        self.heart.observe(self.current_hz);
+        self.current_entropy = entropy_output;
+
+        self.session_stress_accum += chem.cortisol + chem.adenosine;
+        self.ticks += 1;
+
+        // Critical Collapse Check
+        if chem.adenosine > collapse_threshold(stress_tolerance) && !self.is_dreaming {
+            self.is_dreaming = true;
+            thoughts.think_event(MindVoice::Dream, "⛔ METABOLIC CRITICAL: Forced Sleep Protocol Initiated.".to_string(),
+                serde_json::json!({ "event": "forced_sleep", "adenosine": chem.adenosine }));
+        }
+
+        // NATURAL WAKING
+        if self.is_dreaming && chem.adenosine < 0.1 {
+            self.is_dreaming = false;
+            thoughts.think(MindVoice::Dream, "🌅 WAKING: Metabolic homeostasis restored.".to_string());
+        }
+    }
+
+    /// DEEP IDLE: enter after `quiet_secs` of tired + silent + no clients (counted from `now`
+    /// of the first quiet frame); leave on the first stimulus, sound above the floor or
+    /// connection. Returns true on a transition.
+    pub fn update_idle(&mut self, config: &IdleConfig, frame: IdleReading, now: Instant, thoughts: &dyn ThoughtSink) -> bool {
+        let quiet = config.enabled
+            && !frame.stimulated
+            && frame.clients == 0
+            && frame.adenosine > config.adenosine_threshold
+            && frame.audio_rms < config.noise_floor_rms;
+
+        if !quiet {
+            self.quiet_since = None;
+            if self.deep_idle {
+                self.deep_idle = false;
+                thoughts.think(MindVoice::System, "👁️ DEEP IDLE: Disturbance. Full heartbeat restored.".to_string());
+                return true;
+            }
+            return false;
+        }
+
+        let since = *self.quiet_since.get_or_insert(now);
+        if !self.deep_idle && now.saturating_duration_since(since).as_secs_f32() >= config.quiet_secs {
+            self.deep_idle = true;
+            thoughts.think(MindVoice::System, format!("🌑 DEEP IDLE: Nothing to perceive. Heartbeat down to {:.0}Hz.", config.tick_hz));
+            return true;
+        }
+        false
+    }
+
+    /// Loop period: the metabolic heart rate, or the idle heartbeat.
+    pub fn frame_hz(&self, config: &IdleConfig) -> f32 {
+        if self.deep_idle { config.tick_hz.max(0.5) } else { self.current_hz }
+    }
+
+    /// SPONTANEOUS AGENCY: long enough silence for the current interest, enough energy,
+    /// and the once-per-second window (`window_open`, a wall-clock timer owned by the loop).
+    pub fn should_trigger_agency(&self, chem: &Neurotransmitters, window_open: bool) -> bool {
+        let energy = 1.0 - chem.adenosine;
+        let silence_duration = self.ticks.saturating_sub(self.last_interaction_tick);
+        window_open && silence_duration > agency_delay(chem.dopamine) && energy > 0.2
+    }
+
+    /// Legacy TUI heartbeat. Snapshots that are expensive or shared with the web state
+    /// are computed once by the caller and handed in (membrane/gate counters: `with_decisions`).
+    pub fn build_telemetry(&self, chem: &Neurotransmitters, ego: &FractalReservoir, activity: Vec<f32>, region_map: Vec<u8>, activations: Vec<f32>, visual_cortex: Vec<f32>) -> AlephPacket {
+        AlephPacket::Telemetry {
+            adenosine: chem.adenosine,
+            cortisol: chem.cortisol,
+            dopamine: chem.dopamine,
+            oxytocin: chem.oxytocin,
+            audio_spectrum: self.last_spectrum.clone(),
+            heart_rate: self.last_body_state.cpu_usage,
+            lucidity: 1.0 - self.last_body_state.ram_usage,
+            reservoir_activity: activity,
+            short_term_memory: self.telemetry_history.iter().cloned().collect(),
+            current_state: self.latest_state(),
+            entropy: self.current_entropy,
+            loop_frequency: self.current_hz,
+            cpu_usage: self.last_body_state.cpu_usage,
+            activations,
+            visual_cortex,
+            region_map,
+            reservoir_size: ego.current_size(),
+            neuron_positions: ego.get_positions().clone(),
+            privacy_mode: self.privacy_mode,
+            satellite_passed: 0,
+            satellite_hardened: 0,
+            satellite_ontological_errors: 0,
+            satellite_attention: 0.0,
+            gate_attempts: 0,
+            gate_spoken: 0,
+            gate_suppressed_by_reason: Default::default(),
+        }
+    }
+
+    /// Average friction of the session (cortisol + adenosine per tick), for the genome mutation.
+    pub fn avg_friction(&self) -> f32 {
+        if self.ticks > 0 { self.session_stress_accum / (self.ticks as f32) } else { 0.0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use crate::core::bus::Event;
+    use crate::core::config::{AttentionConfig, StandbyConfig};
+    use crate::core::gate::GateStats;
+    use crate::core::satellite::MembraneStats;
+    use crate::core::web::WebTelemetry;
+    use std::time::Duration;
+    use crate::core::stimulus;
+
+    /// Keeps what the daemon narrates, payload included.
+    #[derive(Default)]
+    struct Recorder(RefCell<Vec<(String, Option<serde_json::Value>)>>);
+
+    impl ThoughtSink for Recorder {
+        fn think(&self, _voice: MindVoice, text: String) {
+            self.0.borrow_mut().push((text, None));
+        }
+
+        fn think_event(&self, _voice: MindVoice, text: String, payload: serde_json::Value) {
+            self.0.borrow_mut().push((text, Some(payload)));
+        }
+    }
+
+    impl Recorder {
+        fn events(&self, name: &str) -> usize {
+            self.0.borrow().iter().filter(|(_, p)| p.as_ref().is_some_and(|p| p["event"] == name)).count()
+        }
+    }
+
+    fn chem(dopamine: f32, adenosine: f32) -> Neurotransmitters {
+        Neurotransmitters { dopamine, adenosine, cortisol: 0.0, ..Neurotransmitters::new() }
+    }
+
+    fn reading(adenosine: f32, audio_rms: f32, clients: usize, stimulated: bool) -> IdleReading {
+        IdleReading { adenosine, audio_rms, clients, stimulated }
+    }
+
+    fn silent_for(ticks: u64) -> DaemonState {
+        let mut daemon = DaemonState::new();
+        daemon.ticks = 10_000;
+        daemon.last_interaction_tick = daemon.ticks - ticks;
+        daemon
+    }
+
+    #[test]
+    fn agency_waits_longer_the_less_interested() {
+        assert_eq!(agency_delay(0.9), 300);
+        assert_eq!(agency_delay(0.6), 1200);
+        assert!(agency_delay(0.3) > 100_000);
+
+        let eager = chem(0.9, 0.0);
+        assert!(!silent_for(300).should_trigger_agency(&eager, true), "300 ticks is not yet more than the delay");
+        assert!(silent_for(301).should_trigger_agency(&eager, true));
+        assert!(!silent_for(301).should_trigger_agency(&eager, false), "only inside the once-per-second window");
+
+        let conversational = chem(0.6, 0.0);
+        assert!(!silent_for(1000).should_trigger_agency(&conversational, true));
+        assert!(silent_for(1201).should_trigger_agency(&conversational, true));
+
+        // Too tired to speak up, however interested
+        assert!(!silent_for(5000).should_trigger_agency(&chem(0.9, 0.85), true));
+    }
+
+    #[test]
+    fn forced_sleep_threshold_follows_the_genome() {
+        assert_eq!(collapse_threshold(0.0), 0.95);
+        assert_eq!(collapse_threshold(1.0), 1.0);
+
+        // 0.97 adenosine: collapse for a fragile genome, not for a tolerant one
+        for (tolerance, collapses) in [(0.0, true), (0.2, true), (0.5, false), (1.0, false)] {
+            let mut daemon = DaemonState::new();
+            let thoughts = Recorder::default();
+            daemon.metabolic_step(&chem(0.5, 0.97), 0.5, tolerance, &thoughts);
+            assert_eq!(daemon.is_dreaming, collapses, "tolerance {}", tolerance);
+            assert_eq!(thoughts.events("forced_sleep"), collapses as usize);
+        }
+
+        // Already asleep: no second collapse; and it wakes once the pressure clears
+        let mut daemon = DaemonState::new();
+        let thoughts = Recorder::default();
+        daemon.metabolic_step(&chem(0.5, 0.99), 0.5, 0.0, &thoughts);
+        daemon.metabolic_step(&chem(0.5, 0.99), 0.5, 0.0, &thoughts);
+        assert_eq!(thoughts.events("forced_sleep"), 1);
+        daemon.metabolic_step(&chem(0.5, 0.05), 0.5, 0.0, &thoughts);
+        assert!(!daemon.is_dreaming);
+        assert_eq!(daemon.ticks, 3);
+    }
+
+    /// SYS: text from outside becomes the same control event the dashboard sends.
+    fn sys(text: &str) -> ControlEvent {
+        match stimulus::route(text.to_string()) {
+            Some(Event::Control(event)) => event,
+            _ => panic!("{} is not a control", text),
+        }
+    }
+
+    #[test]
+    fn sys_commands_drive_the_state_machine() {
+        let mut daemon = DaemonState::new();
+        let mut chem = chem(0.5, 0.3);
+        let mut ego = FractalReservoir::seeded(32, 8, 0.95, 0.2, 7);
+        let mut standby = Standby::new(StandbyConfig { enabled: false, ..StandbyConfig::default() });
+        let thoughts = Recorder::default();
+
+        daemon.handle_control(&sys("SYS:SLEEP"), &mut chem, &mut ego, &mut standby, &thoughts);
+        assert!(daemon.is_dreaming);
+        assert_eq!((chem.adenosine, chem.cortisol), (0.95, 0.0));
+
+        daemon.handle_control(&sys("SYS:POKE"), &mut chem, &mut ego, &mut standby, &thoughts);
+        assert!(!daemon.is_dreaming);
+        assert_eq!(chem.adenosine, 0.0);
+        assert!((chem.cortisol - 0.1).abs() < 1e-6, "a poke startles, it doesn't reward");
+        assert_eq!(chem.dopamine, 0.5);
+
+        daemon.word_echoes.schedule(&["hola aleph".to_string()], daemon.ticks, 3);
+        daemon.handle_control(&sys("SYS:DND_ON"), &mut chem, &mut ego, &mut standby, &thoughts);
+        assert!(daemon.privacy_mode);
+        assert!(daemon.word_echoes.is_empty(), "words heard just before DND are not felt either");
+        let said = thoughts.0.borrow().len();
+        daemon.handle_control(&sys("SYS:DND_ON"), &mut chem, &mut ego, &mut standby, &thoughts);
+        assert_eq!(thoughts.0.borrow().len(), said, "no second announcement");
+        daemon.handle_control(&sys("SYS:DND_OFF"), &mut chem, &mut ego, &mut standby, &thoughts);
+        assert!(!daemon.privacy_mode);
+
+        // Plain text is a stimulus; unknown SYS: commands never reach the loop
+        assert!(matches!(stimulus::route("hola".to_string()), Some(Event::Sensory(_))));
+        assert!(stimulus::route("SYS:SELF_DESTRUCT".to_string()).is_none());
+    }
+
+    #[test]
+    fn a_stimulus_interests_and_soothes_but_does_not_rest() {
+        let mut daemon = DaemonState::new();
+        let mut chem = Neurotransmitters { dopamine: 0.2, cortisol: 0.5, adenosine: 0.7, ..Neurotransmitters::new() };
+        let input = daemon.handle_stimulus("hola", &mut chem, Some(0.4), &Recorder::default());
+        assert!((chem.dopamine - 0.5).abs() < 1e-6);
+        assert!((chem.cortisol - 0.45).abs() < 1e-6);
+        assert_eq!(chem.adenosine, 0.7);
+        assert_eq!(input.text, "hola");
+        assert_eq!(input.temperature_clamp, Some(0.4));
+        assert!(matches!(input.mode, CortexMode::Think));
+    }
+
+    #[test]
+    fn deep_idle_waits_quiet_secs_then_cuts_work_per_second_by_80_percent() {
+        let config = IdleConfig::default();
+        let thoughts = Recorder::default();
+        let start = Instant::now();
+        let at = |secs: f32| start + Duration::from_secs_f32(secs);
+        let mut daemon = DaemonState::new();
+        let awake_hz = daemon.frame_hz(&config);
+
+        assert!(!daemon.update_idle(&config, reading(0.9, 0.0, 0, false), at(0.0), &thoughts));
+        assert!(!daemon.update_idle(&config, reading(0.9, 0.0, 0, false), at(config.quiet_secs - 0.1), &thoughts), "idle before quiet_secs");
+        assert!(daemon.update_idle(&config, reading(0.9, 0.0, 0, false), at(config.quiet_secs), &thoughts));
+        let ratio = daemon.frame_hz(&config) / awake_hz;
+        assert!(ratio <= 0.2, "deep idle still runs {:.0}% of the awake frames", ratio * 100.0);
+
+        // A disturbance restarts the count from the next quiet frame
+        let woke = config.quiet_secs + 1.0;
+        assert!(daemon.update_idle(&config, reading(0.9, 0.0, 0, true), at(woke), &thoughts));
+        assert!(!daemon.update_idle(&config, reading(0.9, 0.0, 0, false), at(woke + 1.0), &thoughts));
+        assert!(!daemon.update_idle(&config, reading(0.9, 0.0, 0, false), at(woke + config.quiet_secs), &thoughts));
+        assert!(daemon.update_idle(&config, reading(0.9, 0.0, 0, false), at(woke + 1.0 + config.quiet_secs), &thoughts));
+    }
+
+    #[test]
+    fn any_disturbance_restores_full_rate_at_once() {
+        let config = IdleConfig { quiet_secs: 0.0, ..IdleConfig::default() };
+        let thoughts = Recorder::default();
+        let now = Instant::now();
+        // (stimulated, audio rms, clients): each one alone wakes the heartbeat
+        for (stimulated, rms, clients) in [(true, 0.0, 0), (false, 0.2, 0), (false, 0.0, 1)] {
+            let mut daemon = DaemonState::new();
+            daemon.update_idle(&config, reading(0.9, 0.0, 0, false), now, &thoughts);
+            assert_eq!(daemon.frame_hz(&config), config.tick_hz);
+
+            assert!(daemon.update_idle(&config, reading(0.9, rms, clients, stimulated), now, &thoughts));
+            assert!(!daemon.deep_idle);
+            assert_eq!(daemon.frame_hz(&config), HZ_BASE);
+        }
+        // Rested is not idle, however quiet
+        let mut daemon = DaemonState::new();
+        assert!(!daemon.update_idle(&config, reading(0.2, 0.0, 0, false), now, &thoughts));
+        assert!(!daemon.deep_idle);
+    }
+
+    #[test]
+    fn each_sleep_stage_change_is_reported_once_and_rem_entries_are_counted() {
+        let mut daemon = DaemonState::new();
+        assert_eq!(daemon.stage_transition(0.9), None, "tired but awake is still awake");
+        daemon.is_dreaming = true;
+        assert_eq!(daemon.stage_transition(0.9), Some((SleepStage::Awake, SleepStage::Deep)));
+        assert_eq!(daemon.stage_transition(0.8), None);
+        assert_eq!(daemon.stage_transition(0.2), Some((SleepStage::Deep, SleepStage::Rem)));
+        daemon.is_dreaming = false;
+        assert_eq!(daemon.stage_transition(0.05), Some((SleepStage::Rem, SleepStage::Awake)));
+        daemon.is_dreaming = true;
+        assert_eq!(daemon.stage_transition(0.2), Some((SleepStage::Awake, SleepStage::Rem)), "a nap goes straight to REM");
+        assert_eq!(daemon.rem_cycle, 2);
+    }
+
+    #[test]
+    fn the_night_weighs_the_morning_lifts_and_dnd_drowses() {
+        let felt = |daemon: &DaemonState, hour: u32| {
+            let mut chem = chem(0.5, 0.5);
+            daemon.sense_time(&mut chem, hour, 1.0);
+            chem.adenosine - 0.5
+        };
+        let mut daemon = DaemonState::new();
+        daemon.ticks = 1; // Between two circadian beats
+        assert!((felt(&daemon, 2) - 0.00002).abs() < 1e-6);
+        assert!((felt(&daemon, 8) + 0.0001).abs() < 1e-6);
+        assert_eq!(felt(&daemon, 14), 0.0, "daytime fatigue is the chemistry's business");
+
+        daemon.ticks = 600;
+        assert!((felt(&daemon, 2) - 0.00502).abs() < 1e-6);
+        assert!((felt(&daemon, 21) - 0.002).abs() < 1e-6);
+        assert!((felt(&daemon, 7) + 0.0051).abs() < 1e-6);
+        assert!((felt(&daemon, 14) + 0.001).abs() < 1e-6);
+
+        daemon.ticks = 1;
+        daemon.privacy_mode = true;
+        assert!((felt(&daemon, 14) - 0.0005).abs() < 1e-6);
+    }
+
+    #[test]
+    fn sound_lands_on_its_primes_and_the_senses_pass_an_open_spotlight() {
+        let mut daemon = DaemonState::new();
+        daemon.last_spectrum = AudioSpectrum { bass: 0.1, mids: 0.1, highs: 0.1, ..AudioSpectrum::default() };
+        daemon.sensory_vector[1] = 0.7;
+        let mut attention = Attention::new(AttentionConfig::default(), daemon.sensory_vector.len());
+        let input = daemon.sensory_input(2000, &chem(0.5, 0.0), &mut attention, 1.0 / 60.0);
+
+        assert_eq!(input.len(), 2000);
+        // Past the sensory vector only the spectrum arrives: bass x8, mids x4, highs x3
+        assert!((input[505] - 0.8).abs() < 1e-6);
+        assert!((input[506] - 0.4).abs() < 1e-6);
+        assert!((input[527] - 0.3).abs() < 1e-6);
+        assert_eq!(input[501], 0.0);
+        assert!((input[1] - 0.7).abs() < 1e-6, "calm attention lets every channel through whole");
+        let heartbeat = daemon.sensory.range(crate::core::sensory::HEARTBEAT).unwrap().start;
+        assert!(daemon.sensory_vector[heartbeat] != 0.0, "the pulse is felt");
+
+        // A small brain still gets the whole sensory vector
+        assert_eq!(daemon.sensory_input(32, &chem(0.5, 0.0), &mut attention, 1.0 / 60.0).len(), daemon.sensory_vector.len());
+    }
+
+    #[test]
+    fn the_body_answers_the_neocortex() {
+        let mut ego = FractalReservoir::seeded(32, 8, 0.95, 0.2, 7);
+        let mut chem = chem(0.5, 0.0);
+        assert!(react(&CognitiveEvent::Boredom, &mut chem, &mut ego));
+        assert!((chem.dopamine - 0.4975).abs() < 1e-6, "boredom fades, it doesn't crash");
+
+        assert!(!react(&CognitiveEvent::Trauma(0.9), &mut chem, &mut ego));
+        assert!((chem.cortisol - 0.05).abs() < 1e-6);
+
+        let size = ego.current_size();
+        assert!(!react(&CognitiveEvent::Neurogenesis, &mut chem, &mut ego));
+        assert_eq!(ego.current_size(), size + 2);
+        assert!((chem.dopamine - 0.5975).abs() < 1e-6);
+
+        let before = chem.clone();
+        assert!(!react(&CognitiveEvent::Flow, &mut chem, &mut ego));
+        assert_eq!((chem.dopamine, chem.cortisol), (before.dopamine, before.cortisol));
+    }
+
+    #[test]
+    fn repetition_tires_and_surprise_interests_and_curiouses() {
+        let mut chem = chem(0.3, 0.3);
+        let mut curiosity = 0.5;
+        feel_novelty(&mut chem, &mut curiosity, 0.1);
+        assert!((chem.adenosine - 0.35).abs() < 1e-6);
+        assert_eq!((chem.dopamine, curiosity), (0.3, 0.5));
+
+        feel_novelty(&mut chem, &mut curiosity, 0.6);
+        assert!((chem.dopamine - 0.6).abs() < 1e-6);
+        assert!((curiosity - 0.501).abs() < 1e-6);
+        let mut curiosity = 1.0;
+        feel_novelty(&mut chem, &mut curiosity, 0.9);
+        assert_eq!(curiosity, 1.0);
+    }
+
+    #[test]
+    fn membrane_attention_is_never_deaf_and_focus_opens_it() {
+        assert_eq!(membrane_attention(&chem(0.0, 1.0), 0.0), 0.2);
+        assert!((membrane_attention(&chem(0.5, 0.0), 0.0) - 0.9).abs() < 1e-6);
+        assert!((membrane_attention(&chem(0.0, 1.0), 0.3) - 0.3).abs() < 1e-6);
+        assert_eq!(membrane_attention(&chem(1.0, 0.0), 0.3), 1.0);
+    }
+
+    #[test]
+    fn the_membrane_drowns_hardens_or_lets_through_with_pain() {
+        let mut daemon = DaemonState::new();
+        let now = Instant::now();
+        let thoughts = Recorder::default();
+
+        // Ears saturating the reservoir: nothing is read
+        let mut satellite = Satellite::new(0.1, 0.5);
+        let mut calm = chem(0.5, 0.0);
+        let (passed, decision) = daemon.membrane(&mut satellite, "hola", &mut calm, 0.9, now, &thoughts);
+        assert!(passed.is_none());
+        assert_eq!((decision.reason, decision.severity), ("drowned_out", 0.0));
+        assert_eq!(calm.cortisol, 0.0);
+
+        // A tool-like request to a paranoid membrane gets through, but it hurts
+        let mut satellite = Satellite::new(0.9, 0.5);
+        daemon.current_entropy = 0.3;
+        let (passed, decision) = daemon.membrane(&mut satellite, "write me some code", &mut calm, 0.1, now, &thoughts);
+        assert_eq!(passed.as_deref(), Some("[STRUCTURAL PAIN] write me some code"));
+        assert_eq!((decision.reason, decision.severity, decision.words), ("passed", 1.0, 4));
+        assert!((calm.cortisol - 0.1).abs() < 1e-6);
+        assert_eq!(thoughts.events("ontological_error"), 1);
+
+        // Inner chaos over a tired attention: hardened
+        daemon.current_entropy = 0.9;
+        let (passed, decision) = daemon.membrane(&mut satellite, "hola", &mut chem(0.0, 1.0), 0.1, now, &thoughts);
+        assert!(passed.is_none());
+        assert_eq!((decision.reason, decision.attention), ("hardened", 0.2));
+
+        let stats = satellite.stats(now);
+        assert_eq!((stats.passed, stats.hardened, stats.ontological_errors), (1, 1, 1));
+    }
+
+    #[test]
+    fn the_prompt_carries_the_body_and_only_listens_when_tired_or_asleep() {
+        let mut daemon = DaemonState::new();
+        daemon.current_entropy = 0.4;
+        let input = daemon.prompt(&chem(0.5, 0.75), "hola".to_string(), "Flow".to_string(),
+            Some("Ana está aquí.".to_string()), Some("la última vez: el mar".to_string()), Some(0.3));
+        assert!(matches!(input.mode, CortexMode::Think));
+        assert_eq!(input.bio_state, "Soy Aleph. Estado: Flow. Fatiga: 50%.");
+        assert_eq!(input.bio_context, "Soy Aleph. Estado: Flow. Fatiga: 50%.\nAna está aquí.");
+        assert_eq!(input._long_term_memory.as_deref(), Some("la última vez: el mar"));
+        assert_eq!((input.entropy, input.temperature_clamp), (0.4, Some(0.3)));
+
+        let alone = daemon.prompt(&chem(0.5, 0.95), "hola".to_string(), "Flow".to_string(), None, None, None);
+        assert!(matches!(alone.mode, CortexMode::Listen), "too tired to think");
+        assert_eq!(alone.bio_context, alone.bio_state);
+        daemon.is_dreaming = true;
+        assert!(matches!(daemon.prompt(&chem(0.5, 0.2), "hola".to_string(), String::new(), None, None, None).mode, CortexMode::Listen));
+    }
+
+    #[test]
+    fn a_replayed_memory_is_counted_and_narrated() {
+        let mut daemon = DaemonState::new();
+        let mut ego = FractalReservoir::seeded(32, 8, 0.95, 0.2, 7);
+        let thoughts = Recorder::default();
+        let activity = vec![1.0; 16];
+        daemon.replay(&mut ego, "el mar", &activity, &ReplayConfig::default(), 1.0 / 60.0, &thoughts);
+        assert_eq!(daemon.memory_replays, 1);
+        assert!(thoughts.0.borrow()[0].0.starts_with("🌀 REPLAY: el mar ("));
+    }
+
+    #[test]
+    fn asleep_only_resonant_rem_fragments_are_mumbled_once_per_cycle() {
+        let mut daemon = DaemonState::new();
+        daemon.is_dreaming = true;
+        let mut gate = ExpressionGate::new();
+        let sleep_talk = SleepTalkConfig { enabled: true, ..SleepTalkConfig::default() };
+        let resonant = serde_json::json!({ "seed": 7, "resonance": 0.95 });
+
+        let dream = |daemon: &mut DaemonState, gate: &mut ExpressionGate, adenosine: f32| {
+            match daemon.express(gate, &sleep_talk, &chem(0.5, adenosine), 0.0, "el mar era de vidrio".to_string(), resonant.clone()) {
+                Expression::Dream { thought, mumbled } => (thought, mumbled),
+                Expression::Awake { .. } => panic!("asleep, the waking gate is closed"),
+            }
+        };
+        let (thought, mumbled) = dream(&mut daemon, &mut gate, 0.9);
+        assert!(!mumbled, "Deep sleep is silent");
+        assert_eq!(thought.voice, MindVoice::Dream);
+        assert_eq!(thought.payload.unwrap()["seed"], 7);
+
+        daemon.stage_transition(0.2);
+        let (thought, mumbled) = dream(&mut daemon, &mut gate, 0.2);
+        assert!(mumbled);
+        assert_eq!(thought.payload.unwrap()["sleep_talk"], true);
+        assert!(!dream(&mut daemon, &mut gate, 0.2).1, "one utterance per REM cycle");
+
+        assert_eq!(daemon.interaction_count, 0, "dreams are never interactions");
+        assert_eq!(gate.stats.attempts, 0);
+    }
+
+    #[test]
+    fn awake_the_gate_says_interesting_words_and_keeps_tired_ones_inside() {
+        let mut daemon = DaemonState::new();
+        daemon.ticks = 100;
+        daemon.current_entropy = 0.5;
+        daemon.last_stimulus = Some(41);
+        let mut gate = ExpressionGate::new();
+        let sleep_talk = SleepTalkConfig::default();
+        let payload = serde_json::json!({ "seed": 7, "resonance": 0.6 });
+
+        let Expression::Awake { thought, decision } = daemon.express(&mut gate, &sleep_talk, &chem(0.5, 0.8), 0.0, "hola".to_string(), payload.clone()) else {
+            panic!("awake");
+        };
+        assert_eq!(thought.voice, MindVoice::Cortex, "too tired: a silent insight");
+        assert_eq!((decision.spoken, decision.reason, decision.resonance), (false, "fatigue", Some(0.6)));
+        assert_eq!(daemon.interaction_count, 0);
+
+        let Expression::Awake { thought, decision } = daemon.express(&mut gate, &sleep_talk, &chem(0.5, 0.1), 0.0, "hola Ana".to_string(), payload) else {
+            panic!("awake");
+        };
+        assert_eq!(thought.voice, MindVoice::Vocal);
+        assert_eq!(thought.cause_id, Some(41), "it answers the last stimulus");
+        assert_eq!(thought.payload.unwrap()["resonance"], 0.6);
+        assert_eq!((decision.spoken, decision.reason, decision.words, decision.tick), (true, "spoken", 2, 100));
+        assert_eq!(daemon.interaction_count, 1);
+        assert_eq!((gate.stats.attempts, gate.stats.spoken), (2, 1));
+    }
+
+    #[test]
+    fn the_dashboard_absorbs_the_tui_heartbeat_but_keeps_its_topology() {
+        let mut daemon = DaemonState::new();
+        daemon.current_entropy = 0.42;
+        daemon.privacy_mode = true;
+        daemon.last_body_state = BodyStatus { cpu_usage: 37.0, ram_usage: 0.5 };
+        daemon.remember("uno".to_string());
+        let ego = FractalReservoir::seeded(32, 8, 0.95, 0.2, 7);
+        let gate = GateStats { spoken: 2, ..GateStats::default() };
+        let packet = daemon.build_telemetry(&chem(0.6, 0.3), &ego, vec![0.5; 4], vec![1, 2], vec![0.1], vec![0.2])
+            .with_decisions(MembraneStats { passed: 3, ..MembraneStats::default() }, &gate);
+
+        let mut state = WebTelemetry { region_map: vec![9], activations: vec![0.7], ..WebTelemetry::default() };
+        state.absorb(&packet);
+        assert_eq!((state.dopamine, state.adenosine, state.entropy), (0.6, 0.3, 0.42));
+        assert_eq!((state.thoughts.clone(), state.current_state.as_str()), (vec!["uno".to_string()], "uno"));
+        assert_eq!((state.system_cpu_load, state.reservoir_size, state.privacy_mode), (37.0, ego.current_size(), true));
+        assert_eq!(state.reservoir_activity, vec![0.5; 4]);
+        assert_eq!((state.satellite_passed, state.gate_spoken), (3, 2));
+        assert_eq!((state.region_map.clone(), state.activations.clone()), (vec![9], vec![0.7]), "topology and visuals are the loop's");
+    }
+
+    #[test]
+    fn genome_reload_mid_run_changes_the_satellite_pass_rate() {
+        let path = std::env::temp_dir().join(format!("aleph-genome-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        let thoughts = Recorder::default();
+        let mut daemon = DaemonState::new();
+        let mut ego = FractalReservoir::seeded(32, 8, 0.95, 0.2, 5);
+        let mut seed = Genome { paranoia: 0.1, ..Genome::default() };
+        let mut satellite = Satellite::new(seed.paranoia, seed.refractive_index);
+
+        // Tool-like requests under a little inner chaos: a trusting membrane lets them in
+        let requests = ["ayuda con esto", "write me a poem", "define amor", "arregla la radio", "hola", "buenas noches"];
+        let passed = |satellite: &Satellite| requests.iter().filter(|r| satellite.filter_input(r, 0.3, 0.5, 0.0).0.is_some()).count();
+        assert_eq!(passed(&satellite), requests.len());
+
+        std::fs::write(path, serde_json::to_string(&Genome { paranoia: 0.9, ..seed.clone() }).unwrap()).unwrap();
+        let applied = daemon.reload_genome(path, &mut seed, &mut ego, &mut satellite, &thoughts);
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(applied, [("paranoia", 0.1, 0.9)]);
+        assert_eq!(passed(&satellite), 2, "only the plain greetings get through a paranoid membrane");
+        assert!(thoughts.0.borrow().iter().any(|(t, _)| t.contains("paranoia 0.10 -> 0.90")));
+    }
+
+    #[test]
+    fn genome_reload_keeps_structure_and_survives_a_bad_file() {
+        let path = std::env::temp_dir().join(format!("aleph-genome-bad-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        let thoughts = Recorder::default();
+        let mut daemon = DaemonState::new();
+        let mut ego = FractalReservoir::seeded(32, 8, 0.95, 0.2, 5);
+        let mut seed = Genome::default();
+        let mut satellite = Satellite::new(seed.paranoia, seed.refractive_index);
+
+        std::fs::write(path, serde_json::to_string(&Genome { generation: seed.generation + 7, ..seed.clone() }).unwrap()).unwrap();
+        assert!(daemon.reload_genome(path, &mut seed, &mut ego, &mut satellite, &thoughts).is_empty());
+        assert_eq!(seed.generation, Genome::default().generation);
+        assert!(thoughts.0.borrow().iter().any(|(t, _)| t.contains("'generation' is structural")));
+
+        std::fs::write(path, "{ not a genome").unwrap();
+        assert!(daemon.reload_genome(path, &mut seed, &mut ego, &mut satellite, &thoughts).is_empty());
+        let _ = std::fs::remove_file(path);
+        assert!(thoughts.0.borrow().last().unwrap().0.contains("GENOME RELOAD FAILED"));
+    }
+}
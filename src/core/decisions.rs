@@ -0,0 +1,304 @@
+// src/core/decisions.rs
+// THE MINUTES: Every gate and membrane decision, with what it was decided on
+//
+// GateStats and MembraneStats say how often ALEPH spoke or let a stimulus in;
+// they can't say why. Here every `attempt_vocalization` and every membrane
+// verdict is written down with the numbers it was made on (chemistry, entropy,
+// text length, resonance / attention) and the outcome with its reason:
+//
+//   ring  bounded per log ([decisions] capacity), GET /gate/history?limit=500
+//         and GET /membrane/history?limit=500 (oldest first)
+//   CSV   optional sink per log (`--log-gate gate.csv`, `--log-membrane
+//         membrane.csv`, or [decisions] gate_csv / membrane_csv), appended by a
+//         writer thread so the disk never stalls the loop; the header is
+//         written when the file is new
+//
+// [decisions] redact_text drops the text itself (ring and CSV) and keeps every
+// numeric feature, so a curve for "what gets ALEPH to speak" can be fitted
+// without keeping what was said.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::core::config::DecisionsConfig;
+
+/// Default `limit` of the history endpoints.
+pub const DEFAULT_LIMIT: usize = 500;
+/// How often the CSV writer flushes while rows keep coming.
+const FLUSH_EVERY: Duration = Duration::from_secs(2);
+
+/// One `attempt_vocalization` call.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct GateDecision {
+    pub ts: u64, // Unix millis
+    pub tick: u64,
+    pub dopamine: f32,
+    pub cortisol: f32,
+    pub adenosine: f32,
+    pub oxytocin: f32,
+    pub serotonin: f32,
+    pub warmth: f32,
+    pub entropy: f32,
+    pub text_chars: usize,
+    pub words: usize,
+    pub resonance: Option<f32>, // The echo's confidence in its top token, when there was an echo
+    pub spoken: bool,
+    pub reason: &'static str, // "spoken" or Suppression::name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+/// One stimulus at the membrane.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MembraneDecision {
+    pub ts: u64,
+    pub dopamine: f32,
+    pub cortisol: f32,
+    pub adenosine: f32,
+    pub oxytocin: f32,
+    pub entropy: f32,
+    pub attention: f32,
+    pub auditory_share: f32,
+    pub severity: f32, // Ontological error (0 = none)
+    pub text_chars: usize,
+    pub words: usize,
+    pub passed: bool,
+    pub reason: &'static str, // "passed", "hardened" or "drowned_out"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+/// A decision as one CSV row.
+pub trait Row: Clone + Send + 'static {
+    const HEADER: &'static str;
+    fn csv(&self) -> String;
+    /// Forget the words, keep the numbers.
+    fn redact(&mut self);
+}
+
+impl Row for GateDecision {
+    const HEADER: &'static str = "ts,tick,dopamine,cortisol,adenosine,oxytocin,serotonin,warmth,entropy,text_chars,words,resonance,spoken,reason,text";
+
+    fn csv(&self) -> String {
+        format!("{},{},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4},{},{},{},{},{},{}",
+            self.ts, self.tick, self.dopamine, self.cortisol, self.adenosine, self.oxytocin, self.serotonin,
+            self.warmth, self.entropy, self.text_chars, self.words,
+            self.resonance.map(|r| format!("{:.4}", r)).unwrap_or_default(),
+            self.spoken, self.reason, csv_field(self.text.as_deref().unwrap_or("")))
+    }
+
+    fn redact(&mut self) {
+        self.text = None;
+    }
+}
+
+impl Row for MembraneDecision {
+    const HEADER: &'static str = "ts,dopamine,cortisol,adenosine,oxytocin,entropy,attention,auditory_share,severity,text_chars,words,passed,reason,text";
+
+    fn csv(&self) -> String {
+        format!("{},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4},{:.2},{},{},{},{},{}",
+            self.ts, self.dopamine, self.cortisol, self.adenosine, self.oxytocin, self.entropy,
+            self.attention, self.auditory_share, self.severity, self.text_chars, self.words,
+            self.passed, self.reason, csv_field(self.text.as_deref().unwrap_or("")))
+    }
+
+    fn redact(&mut self) {
+        self.text = None;
+    }
+}
+
+/// Quoted when it needs to be (RFC 4180); line breaks become spaces (one row per decision).
+fn csv_field(text: &str) -> String {
+    let flat = text.replace(['\r', '\n'], " ");
+    if flat.contains([',', '"']) {
+        format!("\"{}\"", flat.replace('"', "\"\""))
+    } else {
+        flat
+    }
+}
+
+pub fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+/// Shared handle: the loop records, HTTP handlers read.
+#[derive(Clone)]
+pub struct DecisionLog<T: Row> {
+    ring: Arc<Mutex<VecDeque<T>>>,
+    capacity: usize,
+    redact: bool,
+    sink: Option<Sender<String>>,
+}
+
+impl<T: Row> DecisionLog<T> {
+    /// `csv` = "" keeps the ring only. A CSV that can't be opened is reported once
+    /// and the ring keeps working.
+    pub fn new(capacity: usize, redact: bool, csv: &str) -> Self {
+        let sink = (!csv.is_empty()).then(|| Self::open_sink(csv)).flatten();
+        Self { ring: Arc::new(Mutex::new(VecDeque::new())), capacity: capacity.max(1), redact, sink }
+    }
+
+    fn open_sink(path: &str) -> Option<Sender<String>> {
+        let fresh = fs::metadata(path).map(|m| m.len() == 0).unwrap_or(true);
+        let file = match OpenOptions::new().create(true).append(true).open(path) {
+            Ok(file) => file,
+            Err(e) => {
+                println!("⚠️ DECISIONS: {} unavailable ({}). Keeping memory only.", path, e);
+                return None;
+            },
+        };
+        let mut out = BufWriter::new(file);
+        if fresh && writeln!(out, "{}", T::HEADER).is_err() {
+            return None;
+        }
+        println!("📝 DECISIONS: logging to {}", path);
+        let (tx, rx) = mpsc::channel::<String>();
+        thread::spawn(move || loop {
+            match rx.recv_timeout(FLUSH_EVERY) {
+                Ok(row) => {
+                    if writeln!(out, "{}", row).is_err() {
+                        break;
+                    }
+                },
+                Err(RecvTimeoutError::Timeout) => { let _ = out.flush(); },
+                Err(RecvTimeoutError::Disconnected) => { let _ = out.flush(); break; },
+            }
+        });
+        Some(tx)
+    }
+
+    pub fn record(&self, mut decision: T) {
+        if self.redact {
+            decision.redact();
+        }
+        if let Some(sink) = &self.sink {
+            let _ = sink.send(decision.csv());
+        }
+        let mut ring = self.ring.lock().unwrap();
+        ring.push_back(decision);
+        while ring.len() > self.capacity {
+            ring.pop_front();
+        }
+    }
+
+    /// The most recent `limit` decisions, oldest first.
+    pub fn recent(&self, limit: usize) -> Vec<T> {
+        let ring = self.ring.lock().unwrap();
+        ring.iter().skip(ring.len().saturating_sub(limit)).cloned().collect()
+    }
+}
+
+/// Both logs, as configured.
+pub fn logs(config: &DecisionsConfig) -> (DecisionLog<GateDecision>, DecisionLog<MembraneDecision>) {
+    (
+        DecisionLog::new(config.capacity, config.redact_text, &config.gate_csv),
+        DecisionLog::new(config.capacity, config.redact_text, &config.membrane_csv),
+    )
+}
+
+/// `limit=` of a history query (DEFAULT_LIMIT when absent or not a number).
+pub fn limit_of(query: &str) -> usize {
+    query.split('&')
+        .find_map(|pair| pair.strip_prefix("limit="))
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_LIMIT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gate(i: u64, text: &str) -> GateDecision {
+        GateDecision {
+            ts: 1_700_000_000_000 + i, tick: i, dopamine: 0.5, cortisol: 0.2, adenosine: 0.3, oxytocin: 0.1,
+            serotonin: 0.5, warmth: 0.0, entropy: 0.4, text_chars: text.chars().count(),
+            words: text.split_whitespace().count(), resonance: (i % 2 == 0).then_some(0.7),
+            spoken: i % 3 == 0, reason: if i % 3 == 0 { "spoken" } else { "low_drive" }, text: Some(text.to_string()),
+        }
+    }
+
+    /// Fields of a CSV line (commas outside quotes + 1).
+    fn fields(line: &str) -> usize {
+        let (mut n, mut quoted) = (1, false);
+        for c in line.chars() {
+            match c {
+                '"' => quoted = !quoted,
+                ',' if !quoted => n += 1,
+                _ => {},
+            }
+        }
+        n
+    }
+
+    #[test]
+    fn the_ring_keeps_the_newest_and_answers_oldest_first() {
+        let log = DecisionLog::<GateDecision>::new(5, false, "");
+        for i in 0..12 {
+            log.record(gate(i, "hola"));
+        }
+        assert_eq!(log.recent(100).len(), 5);
+        assert_eq!(log.recent(3).iter().map(|d| d.tick).collect::<Vec<_>>(), [9, 10, 11]);
+    }
+
+    #[test]
+    fn redaction_drops_the_text_and_keeps_every_number() {
+        let redacted = DecisionLog::<GateDecision>::new(5, true, "");
+        redacted.record(gate(3, "un secreto"));
+        let d = redacted.recent(1).pop().unwrap();
+        assert_eq!(d.text, None);
+        assert_eq!((d.text_chars, d.words, d.spoken, d.resonance), (10, 2, true, None));
+    }
+
+    #[test]
+    fn csv_rows_match_their_header_and_quote_the_text() {
+        let row = gate(4, "dijo \"sí\", luego\nnada").csv();
+        assert!(row.ends_with("\"dijo \"\"sí\"\", luego nada\""), "{}", row);
+        let membrane = MembraneDecision {
+            ts: 1, dopamine: 0.1, cortisol: 0.2, adenosine: 0.3, oxytocin: 0.4, entropy: 0.5, attention: 0.6,
+            auditory_share: 0.8, severity: 0.0, text_chars: 4, words: 1, passed: false, reason: "drowned_out", text: Some("hola".to_string()),
+        };
+        for (header, line) in [(GateDecision::HEADER, row), (GateDecision::HEADER, gate(1, "").csv()), (MembraneDecision::HEADER, membrane.csv())] {
+            assert_eq!(fields(&line), fields(header), "{}", line);
+        }
+    }
+
+    #[test]
+    fn a_csv_sink_writes_its_header_once_and_one_redacted_row_per_decision() {
+        let dir = std::env::temp_dir().join(format!("aleph-decisions-{}", std::process::id()));
+        let _ = fs::create_dir_all(&dir);
+        let path = dir.join("gate.csv");
+        let _ = fs::remove_file(&path);
+        {
+            let sink = DecisionLog::<GateDecision>::new(10, true, &path.to_string_lossy());
+            for i in 0..3 {
+                sink.record(gate(i, "no lo escribas"));
+            }
+        } // Dropping the last handle closes the channel: the writer flushes and ends
+        let mut written = String::new();
+        for _ in 0..50 {
+            written = fs::read_to_string(&path).unwrap_or_default();
+            if written.lines().count() >= 4 {
+                break;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        let _ = fs::remove_dir_all(&dir);
+        let lines: Vec<&str> = written.lines().collect();
+        assert_eq!(lines.len(), 4, "{:?}", lines);
+        assert_eq!(lines[0], GateDecision::HEADER);
+        assert!(!written.contains("escribas"), "the redacted CSV kept the text");
+    }
+
+    #[test]
+    fn limit_is_read_as_documented() {
+        assert_eq!(limit_of("limit=20"), 20);
+        assert_eq!(limit_of(""), DEFAULT_LIMIT);
+        assert_eq!(limit_of("x=1&limit=abc"), DEFAULT_LIMIT);
+    }
+}
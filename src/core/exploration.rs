@@ -0,0 +1,188 @@
+// src/core/exploration.rs
+// THE WANDERER: What ALEPH does with itself when nothing happens
+//
+// Boredom used to only fade dopamine. Now, when the Neocortex declares Boredom
+// and nobody has talked for a while, ALEPH makes its own stimulus, picking one
+// action from a small weighted repertoire:
+//
+//   Ruminate  - the hippocampus brings back a random old memory (its engram is
+//               re-injected into the Association cortex)
+//   Imagine   - a stored sound (the audio embedding of something once heard) is
+//               replayed into the Auditory region, no ears involved
+//   Question  - the Planet asks itself about a recent journal (timeline) entry
+//   Look      - attend to the next frame from the eyes
+//
+// Every action costs effort (billed to the metabolism) and pays dopamine in
+// proportion to the novelty of what it brought: for memories, sounds and answers
+// the hippocampus measures it against what has been on the mind lately; a frame
+// (no text to embed) is weighed against the last frame looked at. An action that
+// brings nothing before the timeout pays nothing. Choices and payoffs are logged
+// with running per-action means, so the weights in aleph.toml can be tuned.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::core::chemistry::Neurotransmitters;
+use crate::core::config::{ExplorationAction, ExplorationConfig};
+
+/// Sounds remembered for imagination (each is one utterance's spectrum).
+const MAX_SOUNDS: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Ruminate,
+    Imagine,
+    Question,
+    Look,
+}
+
+impl Action {
+    pub const ALL: [Action; 4] = [Action::Ruminate, Action::Imagine, Action::Question, Action::Look];
+}
+
+#[derive(Default, Clone, Copy)]
+struct Tally {
+    chosen: u32,
+    payoff: f32, // Total dopamine paid
+}
+
+pub struct Explorer {
+    config: ExplorationConfig,
+    rng: StdRng,
+    pending: Option<(Action, Instant)>,
+    last_explored: Option<Instant>,
+    tallies: [Tally; 4],
+    sounds: VecDeque<(String, Vec<f32>)>,
+    last_look: Option<Vec<f32>>,
+}
+
+impl Explorer {
+    pub fn new(config: ExplorationConfig) -> Self {
+        Self {
+            config,
+            rng: StdRng::from_os_rng(),
+            pending: None,
+            last_explored: None,
+            tallies: [Tally::default(); 4],
+            sounds: VecDeque::new(),
+            last_look: None,
+        }
+    }
+
+    fn tuning(&self, action: Action) -> &ExplorationAction {
+        match action {
+            Action::Ruminate => &self.config.ruminate,
+            Action::Imagine => &self.config.imagine,
+            Action::Question => &self.config.question,
+            Action::Look => &self.config.look,
+        }
+    }
+
+    /// Keep what was heard with how it sounded, for Imagine.
+    pub fn remember_sound(&mut self, text: &str, embedding: &[f32]) {
+        if embedding.is_empty() {
+            return;
+        }
+        if self.sounds.len() >= MAX_SOUNDS {
+            self.sounds.pop_front();
+        }
+        self.sounds.push_back((text.to_string(), embedding.to_vec()));
+    }
+
+    /// Bored, silent long enough, nothing in flight and the cooldown is over.
+    pub fn should_explore(&self, bored: bool, silence_secs: f32, now: Instant) -> bool {
+        self.config.enabled
+            && bored
+            && silence_secs >= self.config.min_silence_secs
+            && self.pending.is_none()
+            && self.last_explored.map_or(true, |t| now.duration_since(t).as_secs_f32() >= self.config.cooldown_secs)
+    }
+
+    /// Weighted pick among the actions that can run now. The chosen action is pending
+    /// until `resolve` (or the timeout). Returns it with the effort to bill.
+    pub fn choose(&mut self, has_journal: bool, now: Instant) -> Option<(Action, Duration)> {
+        let available = |a: Action| match a {
+            Action::Imagine => !self.sounds.is_empty(),
+            Action::Question => has_journal,
+            _ => true,
+        };
+        let weights: Vec<f32> = Action::ALL.iter()
+            .map(|&a| if available(a) { self.tuning(a).weight.max(0.0) } else { 0.0 })
+            .collect();
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut roll = self.rng.random_range(0.0..total);
+        let mut chosen = Action::Look;
+        for (action, w) in Action::ALL.iter().zip(&weights) {
+            if roll < *w {
+                chosen = *action;
+                break;
+            }
+            roll -= w;
+        }
+
+        self.pending = Some((chosen, now));
+        self.last_explored = Some(now);
+        self.tallies[chosen as usize].chosen += 1;
+        Some((chosen, Duration::from_secs_f32(self.tuning(chosen).cost_secs.max(0.0))))
+    }
+
+    /// A random remembered sound: (what was said, its audio embedding).
+    pub fn imagined_sound(&mut self) -> Option<(String, Vec<f32>)> {
+        if self.sounds.is_empty() {
+            return None;
+        }
+        let idx = self.rng.random_range(0..self.sounds.len());
+        self.sounds.get(idx).cloned()
+    }
+
+    pub fn awaiting(&self, action: Action) -> bool {
+        matches!(self.pending, Some((a, _)) if a == action)
+    }
+
+    /// Look's novelty: 1 - cosine similarity with the last frame looked at.
+    pub fn look(&mut self, frame: &[f32]) -> f32 {
+        let novelty = match &self.last_look {
+            Some(last) if last.len() == frame.len() => {
+                let dot: f32 = last.iter().zip(frame).map(|(a, b)| a * b).sum();
+                let norm = last.iter().map(|x| x * x).sum::<f32>().sqrt() * frame.iter().map(|x| x * x).sum::<f32>().sqrt();
+                if norm > f32::EPSILON { 1.0 - dot / norm } else { 0.0 }
+            }
+            _ => 1.0, // First look: everything is new
+        };
+        self.last_look = Some(frame.to_vec());
+        novelty.clamp(0.0, 1.0)
+    }
+
+    /// Pay the pending action for the novelty it brought. Returns the log line.
+    pub fn resolve(&mut self, novelty: f32, chem: &mut Neurotransmitters) -> Option<String> {
+        let (action, _) = self.pending.take()?;
+        let payoff = novelty.clamp(0.0, 1.0) * self.config.dopamine_payout;
+        chem.dopamine = (chem.dopamine + payoff).min(1.0);
+        self.tallies[action as usize].payoff += payoff;
+        Some(format!("🧭 EXPLORE: {:?} paid +{:.3} dopamine (novelty {:.2}) | {}", action, payoff, novelty, self.summary()))
+    }
+
+    /// The pending action ran out of time: it paid nothing.
+    pub fn expire(&mut self, now: Instant) -> Option<String> {
+        let (action, started) = self.pending?;
+        if now.duration_since(started).as_secs_f32() < self.config.timeout_secs {
+            return None;
+        }
+        self.pending = None;
+        Some(format!("🧭 EXPLORE: {:?} brought nothing (timeout) | {}", action, self.summary()))
+    }
+
+    /// "Ruminate 3× ⌀0.041, Imagine 1× ⌀0.120, ..." (mean payoff per choice).
+    fn summary(&self) -> String {
+        Action::ALL.iter().map(|&a| {
+            let t = self.tallies[a as usize];
+            let mean = if t.chosen > 0 { t.payoff / t.chosen as f32 } else { 0.0 };
+            format!("{:?} {}× ⌀{:.3}", a, t.chosen, mean)
+        }).collect::<Vec<_>>().join(", ")
+    }
+}
@@ -0,0 +1,340 @@
+// src/core/feeding.rs
+// THE PANTRY: Being fed
+//
+// Glucose only ever came back by itself (rest, doubled while dreaming). Now a
+// user can feed ALEPH: POST /feed {"amount": 0.3, "kind": "attention"} (or F3,
+// F4, F5 in the TUI). Each kind restores differently:
+//
+//   attention  a fast dopamine + glucose spike, and a dip of dip_ratio of the
+//              dopamine dip_secs later (the sugar crash)
+//   data       glucose released slowly over digest_secs; text sent along
+//              ("text": "...") is handed to the hippocampus to digest as
+//              memory-only input (this tree has no reader sense to feed)
+//   rest       schedules sleep sooner: in rest_window_secs × (1 − amount)
+//
+// Attention builds tolerance: every attention feed scales by `sensitivity`,
+// which drops by tolerance × amount and recovers toward 1 with recovery_secs,
+// so repeated feeds yield less and less.
+//
+// The HTTP thread never touches the chemistry: POST /feed pays the membrane's
+// bucket, has its text sanitized, and sends ControlEvent::Feed down the daemon's
+// control channel to be eaten inside the loop. The TUI still types
+// "SYS:FEED:<kind>:<amount>[:<text>]" as a stimulus, which pays the same bucket
+// before it becomes the same event.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+use crate::core::chemistry::Neurotransmitters;
+use crate::core::config::FeedingConfig;
+
+/// Prefix of a feed command typed as a stimulus (TUI F3-F5).
+pub const COMMAND: &str = "SYS:FEED:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Food {
+    Attention,
+    Data,
+    Rest,
+}
+
+impl Food {
+    pub fn name(self) -> &'static str {
+        match self {
+            Food::Attention => "attention",
+            Food::Data => "data",
+            Food::Rest => "rest",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Food> {
+        match name {
+            "attention" => Some(Food::Attention),
+            "data" => Some(Food::Data),
+            "rest" => Some(Food::Rest),
+            _ => None,
+        }
+    }
+}
+
+/// One feed, as it travels from the door to the loop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feeding {
+    pub kind: Food,
+    pub amount: f32,        // 0-1
+    pub text: Option<String>, // data only: what to digest
+}
+
+impl Feeding {
+    /// A POST /feed body: {"amount": 0.3, "kind": "attention"|"data"|"rest", "text": "..."}.
+    pub fn from_json(body: &[u8]) -> Result<Feeding, String> {
+        let value: serde_json::Value = serde_json::from_slice(body).map_err(|e| e.to_string())?;
+        let kind = value.get("kind").and_then(|k| k.as_str())
+            .and_then(Food::parse)
+            .ok_or("kind must be attention, data or rest")?;
+        let amount = value.get("amount").and_then(|a| a.as_f64()).ok_or("amount must be a number")? as f32;
+        if !(0.0..=1.0).contains(&amount) {
+            return Err(format!("amount must be within 0-1 (got {})", amount));
+        }
+        let text = value.get("text").and_then(|t| t.as_str())
+            .map(|t| t.trim().to_string())
+            .filter(|t| kind == Food::Data && !t.is_empty());
+        Ok(Feeding { kind, amount, text })
+    }
+
+    /// The stimulus-channel command ("SYS:FEED:data:0.30:...").
+    pub fn command(&self) -> String {
+        match &self.text {
+            Some(text) => format!("{}{}:{:.2}:{}", COMMAND, self.kind.name(), self.amount, text),
+            None => format!("{}{}:{:.2}", COMMAND, self.kind.name(), self.amount),
+        }
+    }
+
+    pub fn parse_command(command: &str) -> Option<Feeding> {
+        let mut parts = command.strip_prefix(COMMAND)?.splitn(3, ':');
+        let kind = Food::parse(parts.next()?)?;
+        let amount = parts.next()?.parse::<f32>().ok().filter(|a| (0.0..=1.0).contains(a))?;
+        let text = parts.next().map(str::to_string).filter(|t| !t.is_empty());
+        Some(Feeding { kind, amount, text })
+    }
+}
+
+/// What one feed did right away.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct Meal {
+    pub dopamine: f32,
+    pub glucose: f32,
+    /// Sensitivity the feed was taken at (attention).
+    pub sensitivity: f32,
+    /// Sleep scheduled this far ahead (rest).
+    pub sleep_in_secs: Option<f32>,
+}
+
+/// What /telemetry shows.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PantryState {
+    pub sensitivity: f32,
+    pub digesting: f32,    // Glucose still to be released
+    pub pending_dips: usize,
+    pub sleep_in_secs: Option<f32>,
+    pub feeds: BTreeMap<&'static str, u64>,
+}
+
+pub struct Pantry {
+    config: FeedingConfig,
+    sensitivity: f32,
+    last_recovery: Option<Instant>,
+    dips: Vec<(Instant, f32)>,           // (due, dopamine to take back)
+    digesting: Vec<(Instant, f32, f32)>, // (until, glucose per second, glucose left)
+    sleep_at: Option<Instant>,
+    last_step: Option<Instant>,
+    feeds: BTreeMap<&'static str, u64>,
+}
+
+impl Pantry {
+    pub fn new(config: FeedingConfig) -> Self {
+        Self {
+            config,
+            sensitivity: 1.0,
+            last_recovery: None,
+            dips: Vec::new(),
+            digesting: Vec::new(),
+            sleep_at: None,
+            last_step: None,
+            feeds: BTreeMap::new(),
+        }
+    }
+
+    /// Tolerance wears off exponentially toward full sensitivity.
+    fn recover(&mut self, now: Instant) {
+        if let Some(at) = self.last_recovery {
+            let dt = now.saturating_duration_since(at).as_secs_f32();
+            self.sensitivity = 1.0 - (1.0 - self.sensitivity) * (-dt / self.config.recovery_secs.max(1.0)).exp();
+        }
+        self.last_recovery = Some(now);
+    }
+
+    pub fn sensitivity(&mut self, now: Instant) -> f32 {
+        self.recover(now);
+        self.sensitivity
+    }
+
+    /// Eat: the immediate effect on the chemistry, and whatever comes later scheduled.
+    pub fn feed(&mut self, feeding: &Feeding, chem: &mut Neurotransmitters, now: Instant) -> Meal {
+        let c = self.config.clone();
+        let amount = feeding.amount.clamp(0.0, 1.0);
+        *self.feeds.entry(feeding.kind.name()).or_insert(0) += 1;
+        match feeding.kind {
+            Food::Attention => {
+                self.recover(now);
+                let taken = self.sensitivity;
+                let effect = amount * taken;
+                let dopamine = effect * c.attention_dopamine;
+                let glucose = effect * c.attention_glucose;
+                chem.dopamine = (chem.dopamine + dopamine).min(1.0);
+                chem.glucose = (chem.glucose + glucose).min(1.0);
+                self.dips.push((now + Duration::from_secs_f32(c.dip_secs.max(0.0)), dopamine * c.dip_ratio));
+                self.sensitivity = (self.sensitivity * (1.0 - c.tolerance * amount)).max(c.min_sensitivity);
+                Meal { dopamine, glucose, sensitivity: taken, sleep_in_secs: None }
+            }
+            Food::Data => {
+                let total = amount * c.data_glucose;
+                let secs = c.digest_secs.max(1.0);
+                self.digesting.push((now + Duration::from_secs_f32(secs), total / secs, total));
+                Meal { sensitivity: self.sensitivity, ..Meal::default() }
+            }
+            Food::Rest => {
+                let secs = c.rest_window_secs.max(0.0) * (1.0 - amount);
+                let at = now + Duration::from_secs_f32(secs);
+                self.sleep_at = Some(self.sleep_at.map_or(at, |earlier| earlier.min(at)));
+                Meal { sensitivity: self.sensitivity, sleep_in_secs: Some(secs), ..Meal::default() }
+            }
+        }
+    }
+
+    /// Once per frame: dips that are due, digestion since the last step. True
+    /// when the rest window opens (the caller puts ALEPH to sleep if awake).
+    pub fn step(&mut self, chem: &mut Neurotransmitters, now: Instant) -> bool {
+        let dt = self.last_step.map_or(0.0, |at| now.saturating_duration_since(at).as_secs_f32());
+        self.last_step = Some(now);
+
+        self.dips.retain(|&(due, dopamine)| {
+            if now < due {
+                return true;
+            }
+            chem.dopamine = (chem.dopamine - dopamine).max(0.0);
+            false
+        });
+
+        for (until, rate, left) in self.digesting.iter_mut() {
+            let released = if now >= *until { *left } else { (*rate * dt).min(*left) };
+            chem.glucose = (chem.glucose + released).min(1.0);
+            *left -= released;
+        }
+        self.digesting.retain(|&(_, _, left)| left > 1e-6);
+
+        if self.sleep_at.is_some_and(|at| now >= at) {
+            self.sleep_at = None;
+            return true;
+        }
+        false
+    }
+
+    pub fn state(&mut self, now: Instant) -> PantryState {
+        PantryState {
+            sensitivity: self.sensitivity(now),
+            digesting: self.digesting.iter().map(|&(_, _, left)| left).sum(),
+            pending_dips: self.dips.len(),
+            sleep_in_secs: self.sleep_at.map(|at| at.saturating_duration_since(now).as_secs_f32()),
+            feeds: self.feeds.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ATTENTION: Feeding = Feeding { kind: Food::Attention, amount: 0.3, text: None };
+
+    fn hungry() -> Neurotransmitters {
+        Neurotransmitters { dopamine: 0.0, glucose: 0.0, ..Neurotransmitters::new() }
+    }
+
+    fn secs(s: f32) -> Duration {
+        Duration::from_secs_f32(s)
+    }
+
+    #[test]
+    fn back_to_back_feeds_spike_less_by_the_tolerance_formula() {
+        let config = FeedingConfig::default();
+        let t0 = Instant::now();
+        let mut pantry = Pantry::new(config.clone());
+        let mut chem = hungry();
+        let spikes: Vec<f32> = (0..5).map(|_| pantry.feed(&ATTENTION, &mut chem, t0).dopamine).collect();
+        let mut s = 1.0f32;
+        for spike in &spikes {
+            assert!((spike - 0.3 * s * config.attention_dopamine).abs() <= 1e-5, "spikes {:?} at sensitivity {}", spikes, s);
+            s = (s * (1.0 - config.tolerance * 0.3)).max(config.min_sensitivity);
+        }
+        assert!(spikes.windows(2).all(|w| w[1] < w[0]), "{:?}", spikes);
+        let floor = (0..50).map(|_| pantry.feed(&ATTENTION, &mut chem, t0).sensitivity).fold(1.0, f32::min);
+        assert!((floor - config.min_sensitivity).abs() <= 1e-5, "sensitivity bottomed at {:.3}", floor);
+    }
+
+    #[test]
+    fn a_pause_restores_sensitivity_by_the_recovery_curve() {
+        // After one recovery_secs: 1 - (1 - s)·e^-1
+        let config = FeedingConfig::default();
+        let t0 = Instant::now();
+        let mut pantry = Pantry::new(config.clone());
+        pantry.feed(&ATTENTION, &mut hungry(), t0);
+        let after = 1.0 - config.tolerance * 0.3;
+        let recovered = pantry.sensitivity(t0 + secs(config.recovery_secs));
+        let expected = 1.0 - (1.0 - after) * (-1.0f32).exp();
+        assert!((recovered - expected).abs() <= 1e-4, "sensitivity {:.4}, expected {:.4}", recovered, expected);
+    }
+
+    #[test]
+    fn the_dip_lands_dip_secs_later() {
+        let config = FeedingConfig::default();
+        let t0 = Instant::now();
+        let mut pantry = Pantry::new(config.clone());
+        let mut chem = hungry();
+        let meal = pantry.feed(&ATTENTION, &mut chem, t0);
+        pantry.step(&mut chem, t0 + secs(config.dip_secs * 0.5));
+        assert!((chem.dopamine - meal.dopamine).abs() <= 1e-6, "dipped early: {:.3}", chem.dopamine);
+        pantry.step(&mut chem, t0 + secs(config.dip_secs + 0.1));
+        assert!((chem.dopamine - meal.dopamine * (1.0 - config.dip_ratio)).abs() <= 1e-5, "dopamine {:.3} after a spike of {:.3}", chem.dopamine, meal.dopamine);
+    }
+
+    #[test]
+    fn data_digests_slowly_and_completely() {
+        let config = FeedingConfig::default();
+        let t0 = Instant::now();
+        let mut pantry = Pantry::new(config.clone());
+        let mut chem = hungry();
+        let data = Feeding { kind: Food::Data, amount: 0.4, text: None };
+        assert_eq!(pantry.feed(&data, &mut chem, t0).glucose, 0.0);
+        let total = 0.4 * config.data_glucose;
+        pantry.step(&mut chem, t0);
+        pantry.step(&mut chem, t0 + secs(config.digest_secs * 0.25));
+        assert!((chem.glucose - total * 0.25).abs() <= 1e-3, "a quarter in: {:.4} (expected {:.4})", chem.glucose, total * 0.25);
+        for i in 1..=40 {
+            pantry.step(&mut chem, t0 + secs(config.digest_secs * 0.25 + i as f32));
+        }
+        pantry.step(&mut chem, t0 + secs(config.digest_secs + 1.0));
+        assert!((chem.glucose - total).abs() <= 1e-4, "finally {:.4} (expected {:.4})", chem.glucose, total);
+        assert_eq!(pantry.state(t0).digesting, 0.0);
+    }
+
+    #[test]
+    fn rest_schedules_sleep_once() {
+        // Sleep in rest_window_secs × (1 − amount)
+        let config = FeedingConfig::default();
+        let t0 = Instant::now();
+        let mut pantry = Pantry::new(config.clone());
+        let mut chem = hungry();
+        pantry.feed(&Feeding { kind: Food::Rest, amount: 0.75, text: None }, &mut chem, t0);
+        let due = config.rest_window_secs * 0.25;
+        assert!(!pantry.step(&mut chem, t0 + secs(due - 1.0)), "slept early");
+        assert!(pantry.step(&mut chem, t0 + secs(due + 0.1)), "did not sleep on time");
+        assert!(!pantry.step(&mut chem, t0 + secs(due + 5.0)), "slept twice");
+    }
+
+    #[test]
+    fn a_post_body_routes_to_the_same_feed_and_bad_ones_are_refused() {
+        // POST body -> command on the stimulus channel -> the same feed in the loop
+        let body = br#"{"amount": 0.3, "kind": "data", "text": "Los pulpos tienen tres corazones. Su sangre es azul."}"#;
+        let parsed = Feeding::from_json(body).unwrap();
+        assert!(parsed.command().starts_with(COMMAND), "{}", parsed.command());
+        assert_eq!(Feeding::parse_command(&parsed.command()), Some(parsed));
+
+        let refused: [&[u8]; 4] = [br#"{"amount": 0.3, "kind": "cake"}"#, br#"{"amount": 1.5, "kind": "rest"}"#, br#"{"kind": "rest"}"#, b"not json"];
+        for body in refused {
+            assert!(Feeding::from_json(body).is_err(), "{}", String::from_utf8_lossy(body));
+        }
+        assert_eq!(Feeding::parse_command("SYS:FEED:attention:2.0"), None);
+    }
+}
@@ -4,10 +4,15 @@
 // Instead of a System Prompt, ALEPH uses its documentation as a "gravity well"
 // that bends the probability space of the LLM. Tokens that resonate with the
 // philosophy are amplified; others are suppressed.
+//
+// On top of that landscape, whole classes of tokens can be pulled or pushed by
+// a fixed logit offset (TokenClass): a paranoid genome leans toward the
+// threatening lexicon, a trusting one away from it (Genome::phenotype).
 
 use anyhow::Result;
-use candle_core::{Tensor, Device, DType};
-use tokenizers::Tokenizer;
+use crate::cortex::backend::CortexBackend;
+use crate::cortex::sampler::{softmax, argmax};
+use crate::core::memory_vector::MemoryRecord;
 use std::fs;
 use std::path::Path;
 
@@ -15,28 +20,37 @@ use std::path::Path;
 /// It acts as a "gravity well" that attracts the LLM's output towards concepts
 /// that resonate with the philosophy (Mechanical Honesty, Bio-Digital Paradigm).
 #[allow(dead_code)]
+#[derive(Clone)]
 pub struct SemanticField {
-    /// Bias tensor (vocab_size,) - Added to logits before sampling.
-    bias_tensor: Tensor,
+    /// Bias vector (vocab_size,) - Added to logits before sampling.
+    bias: Vec<f32>,
     /// Strength of the field (0.0 = disabled, 1.0 = strong bias).
     strength: f32,
     /// Document content (for debugging/introspection).
     _source_text: String,
-    // Tokenizer for decoding resonant tokens
-    tokenizer: Tokenizer,
+    /// Per-token-class gravity, added after the bias (kept across blends).
+    classes: Vec<TokenClass>,
+}
+
+/// A named set of token ids and the logit offset they get (> 0 pulls, < 0 pushes).
+#[derive(Debug, Clone)]
+pub struct TokenClass {
+    pub name: String,
+    pub tokens: Vec<u32>,
+    pub gravity: f32,
 }
 
 impl SemanticField {
-    /// Load documents from a directory and create a bias tensor.
-    /// 
+    /// Load documents from a directory and create a bias vector.
+    ///
     /// # How it works:
     /// 1. Read all .md files from the docs/ directory.
-    /// 2. Tokenize the combined text.
+    /// 2. Tokenize the combined text (with the backend's own tokenizer).
     /// 3. Count token frequencies.
-    /// 4. Normalize to a bias tensor that amplifies "resonant" tokens.
-    pub fn from_directory(docs_path: &Path, tokenizer: &Tokenizer, device: &Device, strength: f32) -> Result<Self> {
+    /// 4. Normalize to a bias vector that amplifies "resonant" tokens.
+    pub fn from_directory(docs_path: &Path, backend: &dyn CortexBackend, strength: f32) -> Result<Self> {
         let mut combined_text = String::new();
-        
+
         // Read all markdown files from docs/
         if let Ok(entries) = fs::read_dir(docs_path) {
             for entry in entries.flatten() {
@@ -49,34 +63,45 @@ impl SemanticField {
                 }
             }
         }
-        
+
+        Self::from_text(combined_text, backend, strength)
+    }
+
+    /// LIVED EXPERIENCE: Build the field from the hippocampus's consolidated (semantic-store) memories.
+    /// Volatile memories don't count — only what survived sleep bends the vocabulary.
+    pub fn from_memories(memories: &[MemoryRecord], backend: &dyn CortexBackend, strength: f32) -> Result<Self> {
+        let mut combined_text = String::new();
+        for mem in memories.iter().filter(|m| m.consolidated) {
+            combined_text.push_str(&mem.text);
+            combined_text.push('\n');
+        }
+        Self::from_text(combined_text, backend, strength)
+    }
+
+    fn from_text(combined_text: String, backend: &dyn CortexBackend, strength: f32) -> Result<Self> {
+        let vocab_size = backend.vocab_size();
         if combined_text.is_empty() {
             // No docs found, return zero bias
-            let vocab_size = tokenizer.get_vocab_size(true);
-            let bias_tensor = Tensor::zeros(&[vocab_size], DType::F32, device)?;
             return Ok(Self {
-                bias_tensor,
+                bias: vec![0.0; vocab_size],
                 strength: 0.0,
                 _source_text: String::new(),
-                tokenizer: tokenizer.clone(),
+                classes: Vec::new(),
             });
         }
-        
+
         // Tokenize the combined document text
-        let encoding = tokenizer.encode(combined_text.clone(), false)
+        let token_ids = backend.tokenize(&combined_text)
             .map_err(|e| anyhow::anyhow!("Tokenization failed: {}", e))?;
-        
-        let token_ids = encoding.get_ids();
-        let vocab_size = tokenizer.get_vocab_size(true);
-        
+
         // Count token frequencies
         let mut freq = vec![0.0f32; vocab_size];
-        for &id in token_ids {
+        for &id in &token_ids {
             if (id as usize) < vocab_size {
                 freq[id as usize] += 1.0;
             }
         }
-        
+
         // Normalize: Convert to log-probability bias
         // Tokens that appear more in docs get positive bias.
         // We use log(1 + count) to smooth the distribution.
@@ -84,81 +109,174 @@ impl SemanticField {
         for f in freq.iter_mut() {
             *f = (*f / max_count).ln_1p() * strength; // Scaled by strength
         }
-        
-        let bias_tensor = Tensor::from_vec(freq, &[vocab_size], device)?;
-        
+
         Ok(Self {
-            bias_tensor,
+            bias: freq,
             strength,
             _source_text: combined_text,
-            tokenizer: tokenizer.clone(),
+            classes: Vec::new(),
         })
     }
-    
-    /// Apply the semantic field to raw logits.
-    /// 
+
+    /// Apply the semantic field to raw logits (in place).
+    ///
     /// # Arguments
     /// * `logits` - The raw logits from the LLM (vocab_size,).
-    /// 
-    /// # Returns
-    /// * Biased logits (vocab_size,) where resonant tokens are amplified.
-    #[allow(dead_code)]
-    pub fn apply(&self, logits: Tensor) -> Result<Tensor> {
-        if self.strength < 0.01 {
-            return Ok(logits);
-        }
-        
+    pub fn apply(&self, logits: &mut [f32]) {
         // Add bias to logits
-        let biased = (logits + &self.bias_tensor)?;
-        Ok(biased)
+        if self.strength >= 0.01 {
+            for (l, b) in logits.iter_mut().zip(self.bias.iter()) {
+                *l += b;
+            }
+        }
+
+        // Token classes pull (or push) regardless of the documents
+        for class in &self.classes {
+            for &id in &class.tokens {
+                if let Some(l) = logits.get_mut(id as usize) {
+                    *l += class.gravity;
+                }
+            }
+        }
+    }
+
+    /// Set (or replace, by name) the gravity of a class of tokens. Ids past the
+    /// vocabulary are dropped; a zero gravity removes the class.
+    pub fn set_class_gravity(&mut self, name: &str, tokens: &[u32], gravity: f32) {
+        self.classes.retain(|c| c.name != name);
+        if gravity == 0.0 || !gravity.is_finite() {
+            return;
+        }
+        let vocab_size = self.bias.len();
+        let mut tokens: Vec<u32> = tokens.iter().copied().filter(|&id| (id as usize) < vocab_size).collect();
+        tokens.sort_unstable();
+        tokens.dedup();
+        self.classes.push(TokenClass { name: name.to_string(), tokens, gravity });
     }
-    
+
+    /// The gravity of a class (None = no such class).
+    pub fn class_gravity(&self, name: &str) -> Option<f32> {
+        self.classes.iter().find(|c| c.name == name).map(|c| c.gravity)
+    }
+
     /// Check for Resonance: Does the LLM want to say something that ALIGNS with the Field?
     /// Returns the Word if resonance is detected (High Prob + High Bias).
-    pub fn find_resonance(&self, logits: &Tensor) -> Result<Option<String>> {
+    pub fn find_resonance(&self, logits: &[f32], backend: &dyn CortexBackend) -> Result<Option<String>> {
         // 1. Get the most probable token from logits
-        let probs = candle_nn::ops::softmax(logits, 0)?;
-        let _top_k = 1;
-        // Find argmax
-        // We need to flatten if batch > 1, but here batch=1 usually.
-        // Assuming logits is (vocab_size,)
-        
-        // Simple argmax via Vec
-        let probs_vec: Vec<f32> = probs.to_vec1()?;
-        let (top_id, top_prob) = probs_vec.iter()
-            .enumerate()
-            .max_by(|(_, a), (_, b)| a.total_cmp(b))
-            .map(|(i, v)| (i as u32, *v))
-            .unwrap_or((0, 0.0));
+        let probs = softmax(logits);
+        let (top_id, top_prob) = argmax(&probs);
 
         // 2. Check overlap with Field Bias
-        // Do we have documentation bias for this token?
-        // bias_tensor is (vocab_size,)
-        // We can check if bias_tensor[top_id] > threshold
-        
-        // To access tensor value at index:
-        // We might need to copy bias to cpu or index it.
         // For simplicity, let's just use the probability for now as a proxy for "Confidence".
-        // Resonance = High Confidence (> 0.7)
-        // AND if we have bias > 0 for it.
-        
-        // Let's rely on Tokenizer decoding for now.
+        // Resonance = High Confidence
         if top_prob > 0.15 { // Lower threshold — small models need breathing room
-             let token = self.tokenizer.decode(&[top_id], true)
-                 .map_err(|e| anyhow::anyhow!(e))?;
-             
+             let token = backend.decode(&[top_id as u32], true)?;
+
              // Filter: must contain at least one alphabetic char (no garbage like ```, ---, >>>)
              if token.trim().len() > 1 && token.chars().any(|c| c.is_alphabetic()) {
                  return Ok(Some(token));
              }
         }
-        
+
         Ok(None)
     }
 
+    /// Weighted sum of two fields (e.g. docs + memories). Weights are taken as given, not normalized.
+    pub fn blend(a: &SemanticField, wa: f32, b: &SemanticField, wb: f32) -> SemanticField {
+        let bias: Vec<f32> = a.bias.iter().zip(b.bias.iter())
+            .map(|(x, y)| x * wa + y * wb)
+            .collect();
+        SemanticField {
+            bias,
+            strength: a.strength * wa + b.strength * wb,
+            _source_text: String::new(),
+            classes: a.classes.clone(), // The genome's classes ride on the docs field
+        }
+    }
+
+    /// KL-ish drift between two fields: KL(self || old) over the biases normalized as distributions.
+    /// 0.0 = the landscape didn't move.
+    pub fn divergence_from(&self, old: &SemanticField) -> f32 {
+        const EPS: f32 = 1e-6;
+        let sum_new: f32 = self.bias.iter().map(|b| b.max(0.0) + EPS).sum();
+        let sum_old: f32 = old.bias.iter().map(|b| b.max(0.0) + EPS).sum();
+        self.bias.iter().zip(old.bias.iter())
+            .map(|(n, o)| {
+                let p = (n.max(0.0) + EPS) / sum_new;
+                let q = (o.max(0.0) + EPS) / sum_old;
+                p * (p / q).ln()
+            })
+            .sum()
+    }
+
     /// Get the strength of the field.
     #[allow(dead_code)]
     pub fn strength(&self) -> f32 {
         self.strength
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cortex::backend::MockCortex;
+
+    fn field(text: &str) -> SemanticField {
+        SemanticField::from_text(text.to_string(), &MockCortex::new(1), 0.5).unwrap()
+    }
+
+    fn id(word: &str) -> usize {
+        MockCortex::new(1).tokenize(word).unwrap()[1] as usize
+    }
+
+    #[test]
+    fn documents_bend_their_own_tokens() {
+        let docs = field("eco eco eco memoria");
+        let mut logits = vec![0.0; MockCortex::new(1).vocab_size()];
+        docs.apply(&mut logits);
+        assert!(logits[id("eco")] > logits[id("memoria")]);
+        assert!(logits[id("memoria")] > 0.0 && logits[id("miedo")] == 0.0);
+
+        // No documents: no bias at all
+        let empty = field("");
+        let mut flat = vec![0.0; logits.len()];
+        empty.apply(&mut flat);
+        assert!(flat.iter().all(|l| *l == 0.0) && empty.strength() == 0.0);
+    }
+
+    #[test]
+    fn class_gravity_survives_blends_and_zero_removes_it() {
+        let vocab = MockCortex::new(1).vocab_size() as u32;
+        let mut docs = field("calma");
+        docs.set_class_gravity("threat", &[id("miedo") as u32, id("miedo") as u32, vocab + 5], 1.5);
+        let blended = SemanticField::blend(&docs, 0.7, &field("ruido"), 0.3);
+        assert_eq!(blended.class_gravity("threat"), Some(1.5));
+
+        let mut logits = vec![0.0; vocab as usize];
+        blended.apply(&mut logits);
+        assert!((logits[id("miedo")] - 1.5).abs() < 1e-6, "duplicate ids pull once");
+
+        docs.set_class_gravity("threat", &[id("miedo") as u32], 0.0);
+        assert_eq!(docs.class_gravity("threat"), None);
+    }
+
+    #[test]
+    fn divergence_is_zero_only_for_an_unmoved_landscape() {
+        let a = field("eco pulso");
+        assert!(a.divergence_from(&a).abs() < 1e-6);
+        assert!(field("miedo miedo calor").divergence_from(&a) > 0.1);
+    }
+
+    #[test]
+    fn resonance_needs_a_confident_word() {
+        let mock = MockCortex::new(1);
+        let docs = field("eco");
+        let mut logits = vec![0.0; mock.vocab_size()];
+        assert_eq!(docs.find_resonance(&logits, &mock).unwrap(), None);
+        logits[id("silencio")] = 8.0;
+        assert_eq!(docs.find_resonance(&logits, &mock).unwrap().as_deref(), Some("silencio"));
+        logits[id("silencio")] = 0.0;
+        logits[29] = 8.0; // ","
+        assert_eq!(docs.find_resonance(&logits, &mock).unwrap(), None);
+    }
+}
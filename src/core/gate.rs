@@ -1,8 +1,52 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::core::config::SleepTalkConfig;
+
+/// Why the gate kept a thought silent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suppression {
+    Cooldown,      // Spoke less than cooldown_ticks ago
+    Hallucination, // Blacklisted phrase (the Anti-Marketing Firewall)
+    Fatigue,       // Adenosine veto
+    Empty,         // No words
+    LowDrive,      // Drive (entropy + dopamine) below resistance
+    Asleep,        // Sleep-talk off, or not in REM
+    Faint,         // Dream fragment below the sleep-talk resonance
+    SleepBudget,   // This REM cycle already talked
+}
+
+impl Suppression {
+    pub fn name(self) -> &'static str {
+        match self {
+            Suppression::Cooldown => "cooldown",
+            Suppression::Hallucination => "hallucination",
+            Suppression::Fatigue => "fatigue",
+            Suppression::Empty => "empty",
+            Suppression::LowDrive => "low_drive",
+            Suppression::Asleep => "asleep",
+            Suppression::Faint => "faint",
+            Suppression::SleepBudget => "sleep_budget",
+        }
+    }
+}
+
+/// Vocalization attempts since boot (telemetry).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GateStats {
+    pub attempts: u64,
+    pub spoken: u64,
+    pub suppressed: BTreeMap<String, u64>, // Per Suppression::name
+}
+
 pub struct ExpressionGate {
     pub _metabolic_cost_per_word: f32,
     pub _meaningful_threshold: f32,
     pub last_vocalization_tick: u64,
     pub cooldown_ticks: u64,
+    pub stats: GateStats,
+    pub sleep_stats: GateStats, // The sleep pathway, counted apart
+    sleep_budget: (u64, u32),   // (REM cycle, utterances in it)
 }
 
 impl ExpressionGate {
@@ -12,45 +56,85 @@ impl ExpressionGate {
             _meaningful_threshold: 0.5,  // RAISED: Minimum entropy to even consider speaking
             last_vocalization_tick: 0,
             cooldown_ticks: 30,        // 0.5s at 60Hz - much more responsive
+            stats: GateStats::default(),
+            sleep_stats: GateStats::default(),
+            sleep_budget: (0, 0),
+        }
+    }
+
+    /// SLEEP-TALK: the only way out while asleep ([sleep_talk], opt-in). REM only,
+    /// a high resonance bar and its own budget of per_cycle utterances per REM
+    /// cycle (`cycle` counts REM entries). The waking gate is never consulted.
+    pub fn attempt_sleep_talk(&mut self, config: &SleepTalkConfig, rem: bool, cycle: u64, resonance: f32, text: &str) -> Result<(), Suppression> {
+        self.sleep_stats.attempts += 1;
+        let verdict = if !config.enabled || !rem {
+            Err(Suppression::Asleep)
+        } else if text.split_whitespace().next().is_none() {
+            Err(Suppression::Empty)
+        } else if blacklisted(text) {
+            Err(Suppression::Hallucination)
+        } else if resonance < config.resonance {
+            Err(Suppression::Faint)
+        } else if self.sleep_budget.0 == cycle && self.sleep_budget.1 >= config.per_cycle {
+            Err(Suppression::SleepBudget)
+        } else {
+            if self.sleep_budget.0 != cycle {
+                self.sleep_budget = (cycle, 0);
+            }
+            self.sleep_budget.1 += 1;
+            Ok(())
+        };
+        match verdict {
+            Ok(()) => self.sleep_stats.spoken += 1,
+            Err(reason) => *self.sleep_stats.suppressed.entry(reason.name().to_string()).or_insert(0) += 1,
+        }
+        verdict
+    }
+
+    /// `warmth` = oxytocin toward whoever is present (0 when alone): bonded company lowers the bar.
+    /// Every attempt is counted, and every silence by its reason.
+    pub fn attempt_vocalization(&mut self, adenosine: f32, entropy: f32, dopamine: f32, warmth: f32, text: &str, current_tick: u64) -> Result<(), Suppression> {
+        self.stats.attempts += 1;
+        let verdict = self.judge(adenosine, entropy, dopamine, warmth, text, current_tick);
+        match verdict {
+            Ok(()) => self.stats.spoken += 1,
+            Err(reason) => *self.stats.suppressed.entry(reason.name().to_string()).or_insert(0) += 1,
         }
+        verdict
     }
 
-    pub fn attempt_vocalization(&mut self, adenosine: f32, entropy: f32, dopamine: f32, text: &str, current_tick: u64) -> bool {
+    fn judge(&mut self, adenosine: f32, entropy: f32, dopamine: f32, warmth: f32, text: &str, current_tick: u64) -> Result<(), Suppression> {
         // 0. COOLDOWN CHECK (Prevent verbal diarrhea)
         if current_tick < self.last_vocalization_tick + self.cooldown_ticks {
-            return false;
+            return Err(Suppression::Cooldown);
         }
 
         // 1. HALLUCINATION FILTER (The Anti-Marketing Firewall)
         // ALEPH is an organism, not a salesman.
-        let blacklist = ["info product", "marketing", "subscribe", "chatbot", "language model", "http", "www", "AI"];
-        let lowercase = text.to_lowercase();
-        for &word in &blacklist {
-            if lowercase.contains(word) {
-                return false; // Silence hallucinations immediately
-            }
+        if blacklisted(text) {
+            return Err(Suppression::Hallucination); // Silence hallucinations immediately
         }
 
         // 2. PHYSICAL CHECK (The Body - Veto Power)
         // If adenosine is > 0.7, the system is too tired. Silence.
         if adenosine > 0.7 {
-             return false;
+             return Err(Suppression::Fatigue);
         }
 
         // 3. LENGTH CHECK (Avoid garbage tokens)
         let word_count = text.split_whitespace().count();
-        if word_count < 1 { return false; } // Allow single words (e.g. "Hola!")
+        if word_count < 1 { return Err(Suppression::Empty); } // Allow single words (e.g. "Hola!")
         // if word_count > 40 { return false; } // Too long check kept 
 
         // 4. METABOLIC VALVE (Entropy vs Fatigue)
         // The "Density" of the thought must justify the cost.
         let speech_drive = entropy + (dopamine * 0.8); // High Dopamine = HIGH DRIVE
-        let speech_resistance = adenosine + 0.2; // Lower resistance threshold
+        let speech_resistance = adenosine + 0.2 - warmth.clamp(0.0, 1.0) * 0.2; // Lower resistance threshold, lower still with a friend
         
         if speech_drive <= speech_resistance {
             // EXCEPTION: ultra high dopamine overrides resistance
             if dopamine < 0.9 {
-                return false;
+                return Err(Suppression::LowDrive);
             }
         }
 
@@ -61,7 +145,72 @@ impl ExpressionGate {
 
         // 6. VOCALIZATION APPROVED
         self.last_vocalization_tick = current_tick;
-        true
+        Ok(())
     }
 }
 
+/// The Anti-Marketing Firewall's phrase list.
+fn blacklisted(text: &str) -> bool {
+    let blacklist = ["info product", "marketing", "subscribe", "chatbot", "language model", "http", "www", "AI"];
+    let lowercase = text.to_lowercase();
+    blacklist.iter().any(|word| lowercase.contains(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strong(config: &SleepTalkConfig) -> f32 {
+        (config.resonance + 1.0) / 2.0
+    }
+
+    /// Three cycles, each Deep then REM with five strong and a few faint fragments;
+    /// the cycles it spoke in (+100 for Deep).
+    fn night(gate: &mut ExpressionGate, config: &SleepTalkConfig) -> Vec<u64> {
+        let strong = strong(&SleepTalkConfig { enabled: true, ..SleepTalkConfig::default() });
+        let mut spoken = Vec::new();
+        for cycle in 1..=3u64 {
+            for _ in 0..5 {
+                if gate.attempt_sleep_talk(config, false, cycle, strong, "el mar era de vidrio").is_ok() {
+                    spoken.push(cycle + 100);
+                }
+            }
+            for i in 0..8 {
+                let resonance = if i % 2 == 0 { strong } else { config.resonance * 0.5 };
+                if gate.attempt_sleep_talk(config, true, cycle, resonance, "el mar era de vidrio").is_ok() {
+                    spoken.push(cycle);
+                }
+            }
+        }
+        spoken
+    }
+
+    #[test]
+    fn rem_sleep_talks_once_per_cycle_and_deep_sleep_never() {
+        let mut gate = ExpressionGate::new();
+        assert_eq!(night(&mut gate, &SleepTalkConfig { enabled: true, ..SleepTalkConfig::default() }), [1, 2, 3]);
+        // The rest is suppressed by the budget, faint fragments and Deep sleep
+        let suppressed = |reason: Suppression| gate.sleep_stats.suppressed.get(reason.name()).copied().unwrap_or(0);
+        assert_eq!(suppressed(Suppression::SleepBudget), 9);
+        assert_eq!(suppressed(Suppression::Faint), 12);
+        assert_eq!(suppressed(Suppression::Asleep), 15);
+        // The waking gate's counters never move
+        assert_eq!(gate.stats.attempts, 0);
+    }
+
+    #[test]
+    fn with_the_flag_off_the_nights_are_silent() {
+        let mut gate = ExpressionGate::new();
+        assert!(night(&mut gate, &SleepTalkConfig::default()).is_empty());
+        assert_eq!(gate.sleep_stats.spoken, 0);
+    }
+
+    #[test]
+    fn per_cycle_is_the_budget_and_the_firewall_still_applies_asleep() {
+        let two = SleepTalkConfig { enabled: true, per_cycle: 2, ..SleepTalkConfig::default() };
+        let mut gate = ExpressionGate::new();
+        let spoken = (0..5).filter(|_| gate.attempt_sleep_talk(&two, true, 7, strong(&two), "olas").is_ok()).count();
+        assert_eq!(spoken, 2);
+        assert_eq!(gate.attempt_sleep_talk(&two, true, 8, strong(&two), "subscribe to the chatbot"), Err(Suppression::Hallucination));
+    }
+}
@@ -1,8 +1,29 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::io::Write;
+use std::time::SystemTime;
 use anyhow::Result;
+use crate::core::timeline::unix_millis;
+use crate::core::trauma::TraumaThresholds;
+use crate::cortex::planet::Temperament;
 // use rand::prelude::*;
 
+/// The living genome: read at birth and on every reload, rewritten by evolution.
+pub const GENOME_FILE: &str = "genome.json";
+
+/// One line per generation: its traits and what they became (`phenotype`).
+pub const LINEAGE_FILE: &str = "lineage.jsonl";
+
+/// The genome, and what it conditions beyond the reservoir and the Satellite
+/// (`phenotype`, every trait clamped to 0..1 first, non-finite = 0.5):
+///   stress_tolerance -> TraumaDetector: escalation 0.4..0.6, activation 0.6..0.8,
+///                       deactivation 0.2..0.4, dwell 600..1200 ticks before it may
+///                       engage, recovery 900..300 calm ticks (tolerant = later in,
+///                       sooner out; 0.5 = the old fixed thresholds)
+///   paranoia         -> Semantic Field gravity on the threatening lexicon (lexicon
+///                       terms that raise cortisol): 2.0 * (paranoia - 0.1), so
+///                       -0.2..+1.8 logits (the default 0.1 = no pull)
+///   curiosity        -> the Planet's temperature floor: 0.1..0.4
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Genome {
     pub generation: u32,
@@ -20,6 +41,8 @@ pub struct Genome {
     
     // --- EIGEN-SOUL ---
     pub stoicism: f32,           // Resistance to emotional volatility
+    #[serde(default = "default_entropy_setpoint")]
+    pub entropy_setpoint: f32,   // Where the homeostat holds the reservoir (edge of chaos)
     pub seed_vector: Vec<f32>,   // The crystallization of the previous life
 }
 
@@ -34,24 +57,91 @@ impl Default for Genome {
             refractive_index: 0.5,
             survival_drive: 0.8,
             stoicism: 0.1,
+            entropy_setpoint: default_entropy_setpoint(),
             seed_vector: vec![0.0; 384], // Default embedding size (e.g., all-MiniLM-L6-v2)
         }
     }
 }
 
+/// Middle of the 0.3-0.7 regime; genomes born before the homeostat get it.
+fn default_entropy_setpoint() -> f32 {
+    0.5
+}
+
+/// The parameters the genome conditions outside the reservoir (see `Genome`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Phenotype {
+    pub trauma: TraumaThresholds,
+    pub temperament: Temperament,
+}
+
+/// A trait as used by `phenotype`: 0..1, and 0.5 if it isn't a number.
+fn unit(t: f32) -> f32 {
+    if t.is_finite() { t.clamp(0.0, 1.0) } else { 0.5 }
+}
+
+/// Outcome of a live reload (SIGHUP / POST /genome/reload).
+pub struct GenomeReload {
+    pub applied: Vec<(&'static str, f32, f32)>, // (trait, old, new)
+    pub rejected: Vec<&'static str>,            // Structural: only a new life can change these
+}
+
 impl Genome {
+    /// Read genome.json as it is on disk (no genesis, no repair, no save).
+    pub fn read() -> Result<Self> {
+        Self::read_from(GENOME_FILE)
+    }
+
+    pub fn read_from(path: &str) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Adopt the traits of `fresh` that are safe to change while alive.
+    /// Structural fields (generation, seed vector -> birth size) are kept as born.
+    pub fn apply_live(&mut self, fresh: &Genome) -> GenomeReload {
+        let mut applied = Vec::new();
+        let mut rejected = Vec::new();
+
+        let traits: [(&'static str, &mut f32, f32); 8] = [
+            ("stress_tolerance", &mut self.stress_tolerance, fresh.stress_tolerance),
+            ("curiosity", &mut self.curiosity, fresh.curiosity),
+            ("energy_efficiency", &mut self.energy_efficiency, fresh.energy_efficiency),
+            ("paranoia", &mut self.paranoia, fresh.paranoia),
+            ("refractive_index", &mut self.refractive_index, fresh.refractive_index),
+            ("survival_drive", &mut self.survival_drive, fresh.survival_drive),
+            ("stoicism", &mut self.stoicism, fresh.stoicism),
+            ("entropy_setpoint", &mut self.entropy_setpoint, fresh.entropy_setpoint),
+        ];
+        for (name, current, new) in traits {
+            if (*current - new).abs() > f32::EPSILON {
+                let new = new.clamp(0.0, 1.0);
+                applied.push((name, *current, new));
+                *current = new;
+            }
+        }
+
+        if fresh.generation != self.generation {
+            rejected.push("generation");
+        }
+        if fresh.seed_vector != self.seed_vector {
+            rejected.push("seed_vector");
+        }
+
+        GenomeReload { applied, rejected }
+    }
+
     pub fn load() -> Result<Self> {
-        let path = "genome.json";
-        if let Ok(content) = fs::read_to_string(path) {
+        if let Ok(content) = fs::read_to_string(GENOME_FILE) {
             let mut genome: Genome = serde_json::from_str(&content)?;
             
             // GENETIC REPAIR: If user cleared the vector manually
             if genome.seed_vector.is_empty() {
                  println!("🧬 DNA DAMAGE DETECTED: Empty Seed Vector. Regenerating Sequence...");
                  // Generate random noise for new seed
-                 let _rng = rand::thread_rng();
+                 let _rng = rand::rng();
                  use rand::Rng; // Ensure Rng is in scope or just map
-                 genome.seed_vector = (0..384).map(|_| rand::thread_rng().gen_range(-0.1..0.1)).collect();
+                 genome.seed_vector = (0..384).map(|_| rand::rng().random_range(-0.1..0.1)).collect();
             }
             
             Ok(genome)
@@ -65,9 +155,169 @@ impl Genome {
 
     pub fn save(&self) -> Result<()> {
         let json = serde_json::to_string_pretty(self)?;
-        fs::write("genome.json", json)?;
+        fs::write(GENOME_FILE, json)?;
         Ok(())
     }
 
+    /// The derived parameters (mapping documented on `Genome`).
+    pub fn phenotype(&self) -> Phenotype {
+        let tolerance = unit(self.stress_tolerance);
+        Phenotype {
+            trauma: TraumaThresholds {
+                escalation: 0.4 + 0.2 * tolerance,
+                activation: 0.6 + 0.2 * tolerance,
+                deactivation: 0.2 + 0.2 * tolerance,
+                dwell_ticks: (600.0 + 600.0 * tolerance).round() as usize,
+                recovery_ticks: (900.0 - 600.0 * tolerance).round() as usize,
+            },
+            temperament: Temperament {
+                temperature_floor: 0.1 + 0.3 * unit(self.curiosity),
+                threat_gravity: 2.0 * (unit(self.paranoia) - 0.1),
+            },
+        }
+    }
+
+    /// Append this generation to the lineage log (traits + phenotype), unless its
+    /// last line already is this generation (a restart is the same life).
+    /// Returns true if a line was written.
+    pub fn record_lineage(&self, path: &str) -> Result<bool> {
+        let last_generation = fs::read_to_string(path).ok()
+            .and_then(|log| log.lines().last().and_then(|l| serde_json::from_str::<serde_json::Value>(l).ok()))
+            .and_then(|line| line["generation"].as_u64());
+        if last_generation == Some(self.generation as u64) {
+            return Ok(false);
+        }
+        let line = serde_json::json!({
+            "generation": self.generation,
+            "born_at": unix_millis(SystemTime::now()),
+            "traits": {
+                "stress_tolerance": self.stress_tolerance,
+                "curiosity": self.curiosity,
+                "energy_efficiency": self.energy_efficiency,
+                "paranoia": self.paranoia,
+                "refractive_index": self.refractive_index,
+                "survival_drive": self.survival_drive,
+                "stoicism": self.stoicism,
+                "entropy_setpoint": self.entropy_setpoint,
+            },
+            "phenotype": self.phenotype(),
+        });
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", line)?;
+        Ok(true)
+    }
+
     // mutate removed (unused)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::field::SemanticField;
+    use crate::core::trauma::{TraumaDetector, TraumaState};
+    use crate::cortex::backend::{CortexBackend, MockCortex};
+
+    fn with(stress_tolerance: f32, curiosity: f32, paranoia: f32) -> Genome {
+        Genome { stress_tolerance, curiosity, paranoia, ..Genome::default() }
+    }
+
+    fn within(x: f32, lo: f32, hi: f32) -> bool {
+        x >= lo - 1e-5 && x <= hi + 1e-5
+    }
+
+    #[test]
+    fn the_default_genome_is_the_old_behavior() {
+        let default = Genome::default().phenotype();
+        let old = TraumaThresholds::default();
+        assert!((default.trauma.escalation - old.escalation).abs() <= 1e-5, "{:?}", default);
+        assert!((default.trauma.activation - old.activation).abs() <= 1e-5, "{:?}", default);
+        assert!((default.trauma.deactivation - old.deactivation).abs() <= 1e-5, "{:?}", default);
+        assert_eq!(default.trauma.dwell_ticks, old.dwell_ticks);
+        assert_eq!(default.trauma.recovery_ticks, old.recovery_ticks);
+        assert!(default.temperament.threat_gravity.abs() <= 1e-6, "threat gravity {}", default.temperament.threat_gravity);
+    }
+
+    #[test]
+    fn extreme_traits_stay_in_range() {
+        for x in [0.0, 1.0, -3.0, 7.0, f32::NAN, f32::INFINITY] {
+            let p = with(x, x, x).phenotype();
+            let (t, m) = (&p.trauma, &p.temperament);
+            let in_range = within(t.escalation, 0.4, 0.6) && within(t.activation, 0.6, 0.8) && within(t.deactivation, 0.2, 0.4)
+                && (600..=1200).contains(&t.dwell_ticks) && (300..=900).contains(&t.recovery_ticks)
+                && within(m.temperature_floor, 0.1, 0.4) && within(m.threat_gravity, -0.2, 1.8);
+            assert!(in_range, "traits at {}: out of range {:?}", x, p);
+        }
+        // Out of range is the nearest end, NaN the middle
+        assert_eq!(with(-3.0, -3.0, -3.0).phenotype(), with(0.0, 0.0, 0.0).phenotype());
+        assert_eq!(with(7.0, 7.0, 7.0).phenotype(), with(1.0, 1.0, 1.0).phenotype());
+        assert_eq!(with(f32::NAN, f32::NAN, f32::NAN).phenotype(), with(0.5, 0.5, 0.5).phenotype());
+
+        let (low, high) = (with(0.0, 0.0, 0.0).phenotype(), with(1.0, 1.0, 1.0).phenotype());
+        let ends = [
+            (low.trauma.activation, 0.6), (high.trauma.activation, 0.8), (low.trauma.deactivation, 0.2), (high.trauma.deactivation, 0.4),
+            (low.trauma.dwell_ticks as f32, 600.0), (high.trauma.dwell_ticks as f32, 1200.0),
+            (low.trauma.recovery_ticks as f32, 900.0), (high.trauma.recovery_ticks as f32, 300.0),
+            (low.temperament.temperature_floor, 0.1), (high.temperament.temperature_floor, 0.4),
+            (low.temperament.threat_gravity, -0.2), (high.temperament.threat_gravity, 1.8),
+        ];
+        for (got, want) in ends {
+            assert!((got - want).abs() <= 1e-5, "{} instead of {}: {:?} / {:?}", got, want, low, high);
+        }
+    }
+
+    #[test]
+    fn tolerance_engages_the_firefighter_later_and_lets_go_sooner() {
+        let (low, high) = (with(0.0, 0.0, 0.0).phenotype(), with(1.0, 1.0, 1.0).phenotype());
+        let engages = |thresholds: TraumaThresholds| {
+            let mut detector = TraumaDetector::new(thresholds);
+            (0..1800).position(|_| { detector.tick(0.75); detector.state == TraumaState::FirefighterMode })
+        };
+        // A fragile detector fires on cortisol a tolerant one rides out
+        assert_eq!(engages(low.trauma), Some(599));
+        assert_eq!(engages(high.trauma), None);
+        let recovers = |thresholds: TraumaThresholds| {
+            let mut detector = TraumaDetector::new(thresholds);
+            (0..1500).for_each(|_| { detector.tick(0.95); });
+            (0..1200).position(|_| { detector.tick(0.25); detector.state == TraumaState::Recovering })
+        };
+        assert_eq!(recovers(low.trauma), None);
+        assert_eq!(recovers(high.trauma), Some(299));
+    }
+
+    #[test]
+    fn a_field_applies_the_threat_class_keeps_it_across_a_blend_and_drops_it_at_zero() {
+        let high = with(1.0, 1.0, 1.0).phenotype();
+        let mock = MockCortex::new(7);
+        let vocab = mock.vocab_size();
+        let mut field = SemanticField::from_memories(&[], &mock, 1.0).unwrap();
+        field.set_class_gravity("threat", &[5, 9, 9, vocab as u32 + 3], high.temperament.threat_gravity);
+        let mut logits = vec![0.0; vocab];
+        field.apply(&mut logits);
+        let pulled: Vec<usize> = logits.iter().enumerate().filter(|(_, l)| **l != 0.0).map(|(i, _)| i).collect();
+        assert_eq!(pulled, [5, 9]);
+        assert!((logits[9] - 1.8).abs() <= 1e-5, "logit {}", logits[9]);
+        let blended = SemanticField::blend(&field, 0.7, &field.clone(), 0.3);
+        assert_eq!(blended.class_gravity("threat"), Some(high.temperament.threat_gravity));
+        field.set_class_gravity("threat", &[5], 0.0);
+        assert_eq!(field.class_gravity("threat"), None);
+    }
+
+    #[test]
+    fn the_lineage_log_gets_one_line_per_generation() {
+        let path = std::env::temp_dir().join(format!("aleph-lineage-{}.jsonl", std::process::id()));
+        let path = path.to_string_lossy().to_string();
+        let _ = fs::remove_file(&path);
+        let first = with(0.9, 0.2, 0.6);
+        let next = Genome { generation: 2, ..first.clone() };
+        let written = [first.record_lineage(&path), first.record_lineage(&path), next.record_lineage(&path)]
+            .map(|r| r.unwrap());
+        let log = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(written, [true, false, true]);
+        let lines: Vec<serde_json::Value> = log.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        let activation = lines[0]["phenotype"]["trauma"]["activation"].as_f64().unwrap() as f32;
+        assert!((activation - first.phenotype().trauma.activation).abs() <= 1e-5, "{:?}", lines[0]);
+        assert!((lines[0]["traits"]["paranoia"].as_f64().unwrap() - 0.6).abs() <= 1e-5, "{:?}", lines[0]);
+    }
+}
@@ -0,0 +1,316 @@
+// src/core/health.rs
+// THE STETHOSCOPE: Is ALEPH alive, and is it ready?
+//
+// For systemd/docker probes. The loop writes its vitals here every tick; the
+// web thread answers GET /healthz from them without asking the loop anything
+// (a stalled loop can't answer for itself):
+//
+//   200  the loop ticked within health.grace_secs and no mandatory component
+//        (health.mandatory, only the reservoir by default) is offline
+//   503  otherwise, with the reasons
+//
+// The body always carries loop Hz vs target, seconds since the last cortex
+// output, the Whisper queue, the hippocampus ping (round trip, or "not
+// answering" once a ping is older than health.memory_timeout_secs) and the
+// connected clients. Boot ends with one structured line on stdout
+// ({"event":"startup_complete",...}) once every component has reported.
+
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::core::components::{self, ComponentStatus};
+use crate::core::config::HealthConfig;
+
+/// The components boot waits for before it announces itself complete.
+pub const EXPECTED: [&str; 7] = [
+    components::RESERVOIR, components::CORTEX, components::SEMANTIC_FIELD, components::MEMORY,
+    components::EARS, components::IPC, components::WEB,
+];
+
+/// Why the probe set the memory offline (and may set it back).
+const NOT_ANSWERING: &str = "not answering pings";
+
+#[derive(Debug, Clone, Default)]
+pub struct Vitals {
+    pub last_tick: Option<Instant>,
+    pub target_hz: f32,
+    pub achieved_hz: f32,
+    pub last_cortex_output: Option<Instant>,
+    pub whisper_queue_depth: usize,
+    pub memory_rtt: Option<Duration>, // Last answered ping
+    pub memory_waiting: Option<Duration>, // Age of the unanswered ping, if any
+    pub clients: usize, // WebSocket + IPC
+}
+
+/// Shared between the loop (writes) and the web thread (reads).
+#[derive(Clone, Default)]
+pub struct Health {
+    vitals: Arc<Mutex<Vitals>>,
+}
+
+impl Health {
+    pub fn update(&self, f: impl FnOnce(&mut Vitals)) {
+        if let Ok(mut vitals) = self.vitals.lock() {
+            f(&mut vitals);
+        }
+    }
+
+    pub fn vitals(&self) -> Vitals {
+        self.vitals.lock().map(|v| v.clone()).unwrap_or_default()
+    }
+}
+
+fn secs_since(at: Option<Instant>, now: Instant) -> Option<f32> {
+    at.map(|t| now.saturating_duration_since(t).as_secs_f32())
+}
+
+/// The /healthz answer: HTTP status (200/503) and body.
+pub fn evaluate(vitals: &Vitals, components: &HashMap<String, ComponentStatus>, config: &HealthConfig, now: Instant) -> (u16, serde_json::Value) {
+    let mut reasons = Vec::new();
+    let since_tick = secs_since(vitals.last_tick, now);
+    match since_tick {
+        None => reasons.push("the loop hasn't ticked yet".to_string()),
+        Some(secs) if secs > config.grace_secs => reasons.push(format!("no tick for {:.1}s (grace {:.1}s)", secs, config.grace_secs)),
+        _ => {}
+    }
+    for name in &config.mandatory {
+        if let Some(ComponentStatus::Offline(why)) = components.get(name) {
+            reasons.push(format!("{} is offline: {}", name, why));
+        }
+    }
+    let round = |x: f32| (x * 100.0).round() / 100.0;
+    let body = json!({
+        "status": if reasons.is_empty() { "ok" } else { "unhealthy" },
+        "reasons": reasons,
+        "loop": {
+            "target_hz": round(vitals.target_hz),
+            "achieved_hz": round(vitals.achieved_hz),
+            "last_tick_secs": since_tick.map(round),
+        },
+        "cortex": {
+            "status": components.get(components::CORTEX),
+            "last_output_secs": secs_since(vitals.last_cortex_output, now).map(round),
+        },
+        "whisper_queue_depth": vitals.whisper_queue_depth,
+        "memory": {
+            "status": components.get(components::MEMORY),
+            "rtt_ms": vitals.memory_rtt.map(|d| d.as_millis() as u64),
+            "waiting_secs": vitals.memory_waiting.map(|d| round(d.as_secs_f32())),
+        },
+        "clients": vitals.clients,
+        "components": components,
+    });
+    (if reasons.is_empty() { 200 } else { 503 }, body)
+}
+
+/// The startup-complete log line, once every EXPECTED component has reported (None before).
+pub fn startup_complete(components: &HashMap<String, ComponentStatus>, boot: Duration) -> Option<serde_json::Value> {
+    if !EXPECTED.iter().all(|name| components.contains_key(*name)) {
+        return None;
+    }
+    let count = |f: fn(&ComponentStatus) -> bool| components.values().filter(|s| f(s)).count();
+    Some(json!({
+        "event": "startup_complete",
+        "boot_ms": boot.as_millis() as u64,
+        "ready": count(|s| matches!(s, ComponentStatus::Ready)),
+        "degraded": count(|s| matches!(s, ComponentStatus::Degraded(_))),
+        "offline": count(|s| matches!(s, ComponentStatus::Offline(_))),
+        "components": components,
+    }))
+}
+
+/// Pings the hippocampus (`MemoryCommand::Ping`) and decides when it stopped
+/// (or started again) answering.
+#[derive(Default)]
+pub struct MemoryProbe {
+    pending: Option<(Instant, Receiver<()>)>,
+    last_sent: Option<Instant>,
+    pub rtt: Option<Duration>,
+}
+
+impl MemoryProbe {
+    /// No ping in flight and `interval` since the last one.
+    pub fn due(&self, now: Instant, interval: f32) -> bool {
+        self.pending.is_none() && secs_since(self.last_sent, now).is_none_or(|s| s >= interval)
+    }
+
+    /// A ping went out; its answer arrives on `reply`.
+    pub fn sent(&mut self, reply: Receiver<()>, now: Instant) {
+        self.pending = Some((now, reply));
+        self.last_sent = Some(now);
+    }
+
+    /// Age of the ping in flight.
+    pub fn waiting(&self, now: Instant) -> Option<Duration> {
+        self.pending.as_ref().map(|(at, _)| now.saturating_duration_since(*at))
+    }
+
+    /// Some(true): the ping was answered. Some(false): the thread dropped it, or it is
+    /// older than `timeout` (it stays in flight: a late answer still counts). None: nothing new.
+    pub fn poll(&mut self, now: Instant, timeout: f32) -> Option<bool> {
+        let (at, reply) = self.pending.as_ref()?;
+        match reply.try_recv() {
+            Ok(()) => {
+                self.rtt = Some(now.saturating_duration_since(*at));
+                self.pending = None;
+                Some(true)
+            },
+            Err(TryRecvError::Disconnected) => {
+                self.pending = None;
+                Some(false)
+            },
+            Err(TryRecvError::Empty) => (now.saturating_duration_since(*at).as_secs_f32() > timeout).then_some(false),
+        }
+    }
+}
+
+/// What a probe result does to the memory's status: an answer revives a memory the
+/// probe had declared dead; silence kills a ready one. A memory that never came up
+/// (offline for its own reasons) stays as it reported itself.
+pub fn memory_verdict(current: Option<&ComponentStatus>, answered: bool, timeout: f32) -> Option<ComponentStatus> {
+    let probed_dead = matches!(current, Some(ComponentStatus::Offline(why)) if why.starts_with(NOT_ANSWERING));
+    match (answered, current) {
+        (true, _) if probed_dead => Some(ComponentStatus::Ready),
+        (false, Some(ComponentStatus::Ready)) => Some(ComponentStatus::Offline(format!("{} ({:.0}s)", NOT_ANSWERING, timeout))),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc;
+
+    /// A mock hippocampus: answers pings while `alive`; killed, it holds them unanswered
+    /// (a stuck thread) and answers the backlog once revived.
+    fn mock_memory(alive: Arc<AtomicBool>) -> mpsc::Sender<mpsc::Sender<()>> {
+        let (tx, rx) = mpsc::channel::<mpsc::Sender<()>>();
+        std::thread::spawn(move || {
+            let mut held = Vec::new();
+            loop {
+                match rx.recv_timeout(Duration::from_millis(5)) {
+                    Ok(reply) => held.push(reply),
+                    Err(mpsc::RecvTimeoutError::Timeout) => {},
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                }
+                if alive.load(Ordering::SeqCst) {
+                    for reply in held.drain(..) {
+                        let _ = reply.send(());
+                    }
+                }
+            }
+        });
+        tx
+    }
+
+    fn ready() -> HashMap<String, ComponentStatus> {
+        EXPECTED.iter().map(|n| (n.to_string(), ComponentStatus::Ready)).collect()
+    }
+
+    fn preverbal() -> HashMap<String, ComponentStatus> {
+        let mut states = ready();
+        states.insert(components::CORTEX.to_string(), ComponentStatus::Offline("no GGUF".to_string()));
+        states
+    }
+
+    fn fresh(now: Instant) -> Vitals {
+        Vitals { last_tick: Some(now - Duration::from_millis(20)), target_hz: 60.0, achieved_hz: 59.5, ..Default::default() }
+    }
+
+    #[test]
+    fn a_stalled_loop_answers_503_and_a_preverbal_cortex_does_not() {
+        let config = HealthConfig::default();
+        let now = Instant::now() + Duration::from_secs(60);
+        let (code, body) = evaluate(&fresh(now), &ready(), &config, now);
+        assert_eq!(code, 200, "healthy vitals: {}", body["reasons"]);
+        assert_eq!(body["status"], "ok");
+
+        let stalled = Vitals { last_tick: Some(now - Duration::from_secs_f32(config.grace_secs + 1.0)), ..fresh(now) };
+        assert_eq!(evaluate(&stalled, &ready(), &config, now).0, 503);
+        let never = Vitals { last_tick: None, ..fresh(now) };
+        assert_eq!(evaluate(&never, &ready(), &config, now).0, 503);
+
+        let (code, body) = evaluate(&fresh(now), &preverbal(), &config, now);
+        assert_eq!(code, 200);
+        assert_eq!(body["cortex"]["status"]["status"], "offline");
+    }
+
+    #[test]
+    fn the_probe_declares_a_killed_memory_dead_and_revives_it() {
+        let config = HealthConfig::default();
+        let t0 = Instant::now();
+        let now = t0 + Duration::from_secs(60);
+        let alive = Arc::new(AtomicBool::new(true));
+        let memory = mock_memory(alive.clone());
+        let mut probe = MemoryProbe::default();
+        let mut status = ComponentStatus::Ready;
+        let ping = |probe: &mut MemoryProbe, at: Instant| {
+            let (tx, rx) = mpsc::channel();
+            let _ = memory.send(tx);
+            probe.sent(rx, at);
+        };
+        // Poll (as the loop would, at time `at`) until the probe says `want`; false if it never does
+        let settle = |probe: &mut MemoryProbe, status: &mut ComponentStatus, at: Instant, want: bool| {
+            let deadline = Instant::now() + Duration::from_secs(2);
+            while Instant::now() < deadline {
+                if let Some(answered) = probe.poll(at, config.memory_timeout_secs) {
+                    if let Some(next) = memory_verdict(Some(status), answered, config.memory_timeout_secs) {
+                        *status = next;
+                    }
+                    if answered == want {
+                        return true;
+                    }
+                }
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            false
+        };
+        ping(&mut probe, t0);
+        assert!(settle(&mut probe, &mut status, t0, true), "no first pong");
+
+        // Kill it: no verdict before the timeout, dead after it
+        alive.store(false, Ordering::SeqCst);
+        let t1 = t0 + Duration::from_secs_f32(config.memory_ping_secs);
+        assert!(probe.due(t1, config.memory_ping_secs));
+        ping(&mut probe, t1);
+        std::thread::sleep(Duration::from_millis(50));
+        let early = probe.poll(t1 + Duration::from_secs_f32(config.memory_timeout_secs * 0.5), config.memory_timeout_secs);
+        assert_eq!(early, None);
+        let late = t1 + Duration::from_secs_f32(config.memory_timeout_secs + 1.0);
+        assert!(settle(&mut probe, &mut status, late, false), "never declared dead");
+        assert!(matches!(status, ComponentStatus::Offline(_)), "{:?}", status);
+
+        // A dead memory answers 503 only once it is mandatory
+        let mut with_memory = config.clone();
+        with_memory.mandatory.push(components::MEMORY.to_string());
+        let mut states = ready();
+        states.insert(components::MEMORY.to_string(), status.clone());
+        assert_eq!(evaluate(&fresh(now), &states, &config, now).0, 200);
+        assert_eq!(evaluate(&fresh(now), &states, &with_memory, now).0, 503);
+
+        alive.store(true, Ordering::SeqCst);
+        assert!(settle(&mut probe, &mut status, late, true), "never revived");
+        assert_eq!(status, ComponentStatus::Ready);
+    }
+
+    #[test]
+    fn the_probe_never_overrides_a_memory_that_never_came_up() {
+        let never_up = ComponentStatus::Offline("no embedding model".to_string());
+        assert_eq!(memory_verdict(Some(&never_up), false, 1.0), None);
+        assert_eq!(memory_verdict(Some(&never_up), true, 1.0), None);
+    }
+
+    #[test]
+    fn startup_completes_once_every_component_has_reported() {
+        let mut booting = ready();
+        booting.remove(components::CORTEX);
+        assert!(startup_complete(&booting, Duration::from_secs(1)).is_none());
+        let line = startup_complete(&preverbal(), Duration::from_secs(2)).expect("no startup line");
+        assert_eq!(line["event"], "startup_complete");
+        assert_eq!(line["offline"], 1);
+    }
+}
@@ -1,9 +1,20 @@
-use crate::core::memory_vector::VectorStore;
+use crate::core::memory_vector::{self, VectorStore, MemoryRecord, MemoryFilter, Provenance};
 use crate::core::genome::Genome;
 use crate::core::materializer::SoulMaterializer;
+use crate::core::novelty::NoveltyModel;
+use crate::core::config::{NoveltyConfig, ConsolidationConfig, RetrievalConfig};
+use crate::core::consolidation::{Round, Scheduler, Trigger};
+use crate::core::components::{self, ComponentStatus, Components};
 use anyhow::Result;
-use std::sync::mpsc::{self, Sender, Receiver};
+use std::sync::mpsc::{self, Sender, Receiver, RecvTimeoutError};
 use std::thread;
+use std::time::{Duration, Instant};
+use rand::Rng;
+
+/// "Lately": the most recent memories, the context an exploration is measured against.
+const RECENT_WINDOW: usize = 20;
+/// How often an idle hippocampus wakes up to ask the consolidation scheduler.
+const SCHEDULER_POLL: Duration = Duration::from_secs(1);
 
 pub struct MemoryOutput {
     pub input_text: String,
@@ -12,91 +23,136 @@ pub struct MemoryOutput {
     pub embedding: Option<Vec<f32>>, // NEW: Physical Engram
     pub _volatile_count: usize,
     pub _total_count: usize,
+    pub semantic_store: Vec<MemoryRecord>, // Consolidated memories (CONSOLIDATION_EVENT), or the recalled turns ("THREAD: ...")
+    pub activity: Option<Vec<f32>>, // Stored reservoir signature (only on "REPLAY: ...")
+    pub memory_only: bool, // An earlier sentence of a heard transcript: stored, not a percept
+}
+
+/// `GetStats` reply (telemetry, GET /memory/stats).
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct MemoryStats {
+    pub total_memories: usize,
+    pub volatile_memories: usize,
+    pub mean_novelty: f32,      // Over the last RECENT_SCORES stimuli of this session
+    pub novelty_threshold: f32, // Raw novelty (1 - best similarity) that scores 0.5 now
+    pub stimuli_seen: u64,      // By the novelty model, across sessions
+    pub consolidation_backlog: usize, // Volatile memories waiting for a consolidation round
+    pub consolidation_rounds: u64,
 }
 
 pub enum MemoryCommand {
-    ProcessStimulus { text: String, entropy: f32 },
-    #[allow(dead_code)]
-    ConsolidateSleep,
-    #[allow(dead_code)]
-    ForceSave, // Optional, but we prefer Sleep-based persistence
+    // `activity`: the reservoir's signature when it happened (replayed in Deep sleep)
+    // `provenance`: where it came from (retrieval weighs self-talk and dreams down)
+    // `utterance`: shared by the sentences of one transcript (recalled together)
+    // `memory_only`: store it, but it isn't what the Planet should answer
+    ProcessStimulus { text: String, entropy: f32, activity: Vec<f32>, provenance: Provenance, utterance: Option<u64>, memory_only: bool },
+    // The daemon entered Deep sleep: consolidate the volatile backlog (in bounded rounds)
+    EnterDeepSleep,
+    ForceSave, // Autosave checkpoint (crash insurance)
+    // Exploration: bring back a random old memory ("RUMINATION: ..." output, engram included)
+    Ruminate,
+    // Exploration: how new is this text against what's been on the mind lately? Not stored. ("PROBE: ...")
+    Probe { text: String },
+    // Deep sleep: one of the `candidates` strongest memories, with its activity signature ("REPLAY: ...")
+    Replay { candidates: usize },
+    // A speaker came back: their `top_k` most recent turns that pass `filter` ("THREAD: <speaker>",
+    // whole turns in semantic_store, newest first; sent even when nothing matched)
+    Recall { filter: MemoryFilter, top_k: usize },
+    GetStats { reply_tx: Sender<MemoryStats> },
+    // Health probe: answered right away (a thread stuck elsewhere never answers)
+    Ping { reply_tx: Sender<()> },
     // Shutdown includes session stats for the alchemist
     Shutdown { previous_genome: Genome, avg_friction: f32, reply_tx: Sender<Genome> },
 }
 
 pub struct Hippocampus {
     store: VectorStore,
+    novelty: NoveltyModel, // Survives restarts (novelty.json)
+    consolidation: Scheduler,
+    retrieval: RetrievalConfig,
 }
 
 impl Hippocampus {
     /// Spawns the Hippocampus in a background thread.
     /// Returns: (CommandSender, OutputReceiver)
-    pub fn spawn() -> Result<(Sender<MemoryCommand>, Receiver<MemoryOutput>, Receiver<String>)> {
+    pub fn spawn(novelty: NoveltyConfig, consolidation: ConsolidationConfig, retrieval: RetrievalConfig, components: Components) -> Result<(Sender<MemoryCommand>, Receiver<MemoryOutput>, Receiver<String>)> {
         let (cmd_tx, cmd_rx) = mpsc::channel::<MemoryCommand>();
         let (out_tx, out_rx) = mpsc::channel::<MemoryOutput>();
         let (log_tx, log_rx) = mpsc::channel::<String>(); // Logic logs for TUI
 
         thread::spawn(move || {
-            let mut hippo = match Self::new() {
+            let mut hippo = match Self::new(novelty, consolidation, retrieval) {
                 Ok(h) => {
+                    components.report(components::MEMORY, ComponentStatus::Ready);
                     let _ = log_tx.send("Hippocampus: ONLINE (CUDA/CPU)".to_string());
                     h
                 },
                 Err(e) => {
+                    components.report(components::MEMORY, ComponentStatus::Offline(e.to_string()));
                     let _ = log_tx.send(format!("Hippocampus KILLED: {}", e));
                     return;
                 }
             };
 
-            while let Ok(cmd) = cmd_rx.recv() {
+            loop {
+                let cmd = match cmd_rx.recv_timeout(SCHEDULER_POLL) {
+                    Ok(cmd) => cmd,
+                    Err(RecvTimeoutError::Timeout) => {
+                        hippo.consolidate_if_due(&out_tx, &log_tx);
+                        continue;
+                    },
+                    Err(RecvTimeoutError::Disconnected) => break,
+                };
+                if matches!(cmd, MemoryCommand::ProcessStimulus { .. } | MemoryCommand::Probe { .. }) {
+                    hippo.consolidation.touch(Instant::now());
+                }
                 match cmd {
-                    MemoryCommand::ProcessStimulus { text, entropy } => {
-                        match hippo.process(text, entropy) {
-                            Ok(output) => { let _ = out_tx.send(output); },
+                    MemoryCommand::ProcessStimulus { text, entropy, activity, provenance, utterance, memory_only } => {
+                        match hippo.process(text, entropy, activity, provenance, utterance) {
+                            Ok(output) => { let _ = out_tx.send(MemoryOutput { memory_only, ..output }); },
                             Err(e) => { let _ = log_tx.send(format!("Memory Error: {}", e)); }
                         }
                     },
-                    MemoryCommand::ConsolidateSleep => {
-                        match hippo.store.consolidate_memories() {
-                            Ok((forgotten, dreams)) => {
-                                let _ = log_tx.send(format!("💤 Sleep Cycle: Consolidated. Pruned {} weak memories. Replaying {} dreams.", forgotten, dreams.len()));
-                                
-                                // EVENT: Trigger structural growth
-                                let _ = out_tx.send(MemoryOutput {
-                                    input_text: "CONSOLIDATION_EVENT".to_string(),
-                                    novelty: 1.0, // High novelty to signify importance
-                                    retrieval: None,
-                                    embedding: None, 
-                                    _volatile_count: 0,
-                                    _total_count: hippo.store.memory_count(),
-                                });
-
-                                // DREAM REPLAY: Inject high-entropy memories back into the system
-                                for (text, embedding) in dreams {
-                                     // Small delay? No, storm is better.
-                                     let _ = out_tx.send(MemoryOutput {
-                                         input_text: format!("DREAM_REPLAY: {}", text),
-                                         novelty: 0.9,
-                                         retrieval: None,
-                                         embedding: Some(embedding),
-                                         _volatile_count: 0,
-                                         _total_count: hippo.store.memory_count(),
-                                     });
-                                     // Tiny sleep to avoid blocking channel?
-                                     std::thread::sleep(std::time::Duration::from_millis(50));
-                                }
-                            },
-                            Err(e) => { let _ = log_tx.send(format!("Sleep Error: {}", e)); }
+                    MemoryCommand::EnterDeepSleep => {
+                        hippo.consolidation.enter_deep();
+                    },
+                    MemoryCommand::Ruminate => {
+                        match hippo.ruminate() {
+                            Some(output) => { let _ = out_tx.send(output); },
+                            None => { let _ = log_tx.send("🧭 Rumination: no old memories to revisit.".to_string()); }
+                        }
+                    },
+                    MemoryCommand::Replay { candidates } => {
+                        if let Some(output) = hippo.replay(candidates) {
+                            let _ = out_tx.send(output);
+                        }
+                    },
+                    MemoryCommand::Recall { filter, top_k } => {
+                        let _ = out_tx.send(hippo.recall(&filter, top_k));
+                    },
+                    MemoryCommand::GetStats { reply_tx } => {
+                        let _ = reply_tx.send(hippo.stats());
+                    },
+                    MemoryCommand::Ping { reply_tx } => {
+                        let _ = reply_tx.send(());
+                    },
+                    MemoryCommand::Probe { text } => {
+                        match hippo.probe(text) {
+                            Ok(output) => { let _ = out_tx.send(output); },
+                            Err(e) => { let _ = log_tx.send(format!("Memory Error: {}", e)); }
                         }
                     },
                     MemoryCommand::ForceSave => {
-                        let _ = hippo.store.save(); // Just in case
+                        if let Err(e) = hippo.store.save().and_then(|_| hippo.novelty.save()) {
+                            let _ = log_tx.send(format!("❌ Memory checkpoint failed: {}", e));
+                        }
                     },
                     MemoryCommand::Shutdown { previous_genome, avg_friction, reply_tx } => {
                         let _ = log_tx.send("💀 Hippocampus: Shutting down... Crystallizing Soul.".to_string());
                         
                         // 1. Save Raw Memories (Persistence)
                         let _ = hippo.store.save();
+                        let _ = hippo.novelty.save();
 
                         // 2. Crystallize (Now using Friction)
                         let new_genome = SoulMaterializer::crystallize(&hippo.store, previous_genome, avg_friction);
@@ -108,20 +164,190 @@ impl Hippocampus {
                         break; 
                     }
                 }
+                hippo.consolidate_if_due(&out_tx, &log_tx);
             }
         });
 
         Ok((cmd_tx, out_rx, log_rx))
     }
 
-    fn new() -> Result<Self> {
+    fn new(novelty: NoveltyConfig, consolidation: ConsolidationConfig, retrieval: RetrievalConfig) -> Result<Self> {
         Ok(Self {
             store: VectorStore::new()?,
+            novelty: NoveltyModel::load(novelty),
+            consolidation: Scheduler::new(consolidation, Instant::now()),
+            retrieval,
+        })
+    }
+
+    /// One bounded consolidation round, if the scheduler says so (core/consolidation.rs).
+    /// Reported on the log channel (the daemon bills the duration); the round that empties
+    /// the backlog also sends CONSOLIDATION_EVENT (growth + field rebuild). Deep rounds
+    /// replay their dreams.
+    fn consolidate_if_due(&mut self, out_tx: &Sender<MemoryOutput>, log_tx: &Sender<String>) {
+        let Some(trigger) = self.consolidation.due(self.store.volatile_count(), Instant::now()) else { return };
+        let started = Instant::now();
+        let batch = match self.store.consolidate_batch(self.consolidation.batch()) {
+            Ok(batch) => batch,
+            Err(e) => {
+                let _ = log_tx.send(format!("Sleep Error: {}", e));
+                self.consolidation.ran(trigger, 0, Instant::now()); // Don't retry in a tight loop
+                return;
+            }
+        };
+        let backlog = self.store.volatile_count();
+        self.consolidation.ran(trigger, backlog, Instant::now());
+        let round = Round {
+            trigger,
+            examined: batch.examined,
+            kept: batch.kept,
+            forgotten: batch.forgotten,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            backlog,
+        };
+        let _ = log_tx.send(round.line());
+
+        // EVENT: Trigger structural growth (once the backlog is consolidated)
+        if backlog == 0 {
+            let _ = out_tx.send(MemoryOutput {
+                input_text: "CONSOLIDATION_EVENT".to_string(),
+                novelty: 1.0, // High novelty to signify importance
+                retrieval: None,
+                embedding: None,
+                _volatile_count: 0,
+                _total_count: self.store.memory_count(),
+                semantic_store: self.store.memories.iter().filter(|m| m.consolidated).cloned().collect(),
+                activity: None,
+                memory_only: false,
+            });
+        }
+
+        // DREAM REPLAY: Inject high-entropy memories back into the system (asleep only)
+        if trigger != Trigger::Deep {
+            return;
+        }
+        for (text, embedding) in batch.dreams {
+            let _ = out_tx.send(MemoryOutput {
+                input_text: format!("DREAM_REPLAY: {}", text),
+                novelty: 0.9,
+                retrieval: None,
+                embedding: Some(embedding),
+                _volatile_count: 0,
+                _total_count: self.store.memory_count(),
+                semantic_store: Vec::new(),
+                activity: None,
+                memory_only: false,
+            });
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    fn stats(&self) -> MemoryStats {
+        MemoryStats {
+            total_memories: self.store.memory_count(),
+            volatile_memories: self.store.volatile_count(),
+            mean_novelty: self.novelty.recent_mean(),
+            novelty_threshold: self.novelty.threshold(),
+            stimuli_seen: self.novelty.samples,
+            consolidation_backlog: self.store.volatile_count(),
+            consolidation_rounds: self.consolidation.rounds,
+        }
+    }
+
+    /// Novelty against the recent window only (1.0 = unlike anything lately).
+    fn novelty_lately(&self, vector: &[f32], skip: Option<usize>) -> f32 {
+        let memories = &self.store.memories;
+        let start = memories.len().saturating_sub(RECENT_WINDOW);
+        let max_sim = (start..memories.len())
+            .filter(|&i| Some(i) != skip)
+            .map(|i| memories[i].embedding.iter().zip(vector).map(|(a, b)| a * b).sum::<f32>())
+            .fold(0.0f32, f32::max);
+        (1.0 - max_sim).clamp(0.0, 1.0)
+    }
+
+    /// A random memory from before the recent window (any memory if there is no "before").
+    fn ruminate(&self) -> Option<MemoryOutput> {
+        let memories = &self.store.memories;
+        if memories.is_empty() {
+            return None;
+        }
+        let old = memories.len().saturating_sub(RECENT_WINDOW).max(1);
+        let idx = rand::rng().random_range(0..old);
+        let memory = &memories[idx];
+        Some(MemoryOutput {
+            input_text: format!("RUMINATION: {}", memory.text),
+            novelty: self.novelty_lately(&memory.embedding, Some(idx)),
+            retrieval: None,
+            embedding: Some(memory.embedding.clone()),
+            _volatile_count: self.store.volatile_count(),
+            _total_count: self.store.memory_count(),
+            semantic_store: Vec::new(),
+            activity: None,
+            memory_only: false,
+        })
+    }
+
+    /// A random pick among the `candidates` strongest (highest-entropy) memories that
+    /// carry an activity signature. None if no memory has one yet.
+    fn replay(&self, candidates: usize) -> Option<MemoryOutput> {
+        let mut strongest: Vec<&MemoryRecord> = self.store.memories.iter()
+            .filter(|m| !m.activity.is_empty())
+            .collect();
+        if strongest.is_empty() {
+            return None;
+        }
+        strongest.sort_by(|a, b| b.entropy.total_cmp(&a.entropy));
+        strongest.truncate(candidates.max(1));
+        let memory = strongest[rand::rng().random_range(0..strongest.len())];
+        Some(MemoryOutput {
+            input_text: format!("REPLAY: {}", memory.text),
+            novelty: 0.0, // Re-lived, not new
+            retrieval: None,
+            embedding: None, // The pattern is the reactivation, not the engram
+            _volatile_count: self.store.volatile_count(),
+            _total_count: self.store.memory_count(),
+            semantic_store: Vec::new(),
+            activity: Some(memory.activity.clone()),
+            memory_only: false,
+        })
+    }
+
+    /// The turns a filtered query returns, each with its siblings joined (core/thread.rs).
+    fn recall(&self, filter: &MemoryFilter, top_k: usize) -> MemoryOutput {
+        let memories = &self.store.memories;
+        let turns = memory_vector::latest(memories, filter, top_k).into_iter()
+            .map(|i| MemoryRecord { text: memory_vector::recall_utterance(memories, i), ..memories[i].clone() })
+            .collect();
+        MemoryOutput {
+            input_text: format!("THREAD: {}", filter.speaker.as_deref().unwrap_or("")),
+            novelty: 0.0, // Recalled, not new
+            retrieval: None,
+            embedding: None,
+            _volatile_count: self.store.volatile_count(),
+            _total_count: self.store.memory_count(),
+            semantic_store: turns,
+            activity: None,
+            memory_only: false,
+        }
+    }
+
+    fn probe(&self, text: String) -> Result<MemoryOutput> {
+        let vector = self.store.embed(&text)?;
+        Ok(MemoryOutput {
+            input_text: format!("PROBE: {}", text),
+            novelty: self.novelty_lately(&vector, None),
+            retrieval: None,
+            embedding: None, // Measured, not replayed
+            _volatile_count: self.store.volatile_count(),
+            _total_count: self.store.memory_count(),
+            semantic_store: Vec::new(),
+            activity: None,
+            memory_only: false,
         })
     }
 
     /// Optimized: Single BERT pass for all cognitive functions
-    fn process(&mut self, text: String, entropy: f32) -> Result<MemoryOutput> {
+    fn process(&mut self, text: String, entropy: f32, activity: Vec<f32>, provenance: Provenance, utterance: Option<u64>) -> Result<MemoryOutput> {
          // 1. Generate Embedding (Expensive Part - Done ONCE)
          let vector = self.store.embed(&text)?;
          
@@ -132,31 +358,25 @@ impl Hippocampus {
             })
             .fold(0.0f32, |acc, x| f32::max(acc, x));
          
-         let novelty = 1.0 - max_sim;
+         // Judged against the usual similarity and how often this was heard (core/novelty.rs)
+         let novelty = self.novelty.score(&text, max_sim);
 
          // 3. Retrieval (RAG)
-         // Search top 3 relevant using the SAME vector
-         let mut scores: Vec<(usize, f32)> = self.store.memories.iter().enumerate().map(|(i, mem)| {
-            let cosine_sim: f32 = mem.embedding.iter().zip(&vector)
-                .map(|(a, b)| a * b).sum();
-            (i, cosine_sim)
-        }).collect();
-        scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-        
-        let retrieval = if let Some((idx, score)) = scores.first() {
-             if *score > 0.4 {
-                  let ctx_block = format!("Recuerdo Relacionado (Sim: {:.2}): {}", score, self.store.memories[*idx].text);
-                  Some((ctx_block, *score))
-             } else {
-                 None
-             }
-        } else {
-            None
-        };
+         // Best match for the SAME vector, weighed by source (dreams and self-talk count less)
+         let retrieval = memory_vector::best_match(&self.store.memories, &vector, &self.retrieval).map(|(idx, score)| {
+             let memory = &self.store.memories[idx];
+             // A sentence of a longer turn comes back with the rest of it
+             let ctx_block = format!("Recuerdo Relacionado (Sim: {:.2}, {}): {}", score, memory.provenance.describe(),
+                 memory_vector::recall_utterance(&self.store.memories, idx));
+             (ctx_block, score)
+         });
 
         // 4. Store (Short Term Memory)
         // Manual add to avoid re-embedding
-        self.store.add_precalculated(text.clone(), vector.clone(), vec!["input".to_string()], entropy)?;
+        self.store.add_precalculated(text.clone(), vector.clone(), vec!["input".to_string()], entropy, activity, provenance)?;
+        if let Some(stored) = self.store.memories.last_mut() {
+            stored.utterance = utterance;
+        }
 
         // Return the embedding so the Daemon can inject it physically
         Ok(MemoryOutput {
@@ -166,6 +386,9 @@ impl Hippocampus {
             embedding: Some(vector), // Pass the vector up!
             _volatile_count: self.store.volatile_count(),
             _total_count: self.store.memory_count(),
+            semantic_store: Vec::new(),
+            activity: None,
+            memory_only: false,
         })
     }
 }
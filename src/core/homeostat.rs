@@ -0,0 +1,181 @@
+// src/core/homeostat.rs
+// THE THERMOSTAT: Holding the reservoir at the edge of chaos
+//
+// Entropy 0.3-0.7 is the interesting regime, but nothing held it there:
+// sessions drifted into chaos (pinned > 0.9) or stasis for hours. This is a
+// slow PI controller on the long-window mean entropy:
+//
+//   error = setpoint (genome) - mean entropy (EMA over `window_secs`)
+//   u     = kp * error + ki * ∫error dt(min)        clamped to [-1, 1]
+//
+// u is re-evaluated every `update_secs` and may only move `max_step` per update,
+// so it works on a minutes timescale and never fights the fast dynamics (chemistry,
+// stimuli). u > 0 excites, u < 0 calms, through three global knobs:
+//
+//   recurrent gain  = 2^(gain_octaves * u)   (effective spectral radius)
+//   leak scale      = 1 + leak_range * u     (baseline leak rate)
+//   noise amplitude = noise_max * max(u, 0)  (only to lift a silent reservoir)
+//
+// The integral stops winding while u is pinned at a limit (anti-windup), and the
+// controller holds still while dreaming (sleep is allowed its own entropy).
+// A seeded test drives it from saturated, chaotic and silent starts.
+
+use serde::Serialize;
+
+use crate::core::config::HomeostatConfig;
+use crate::core::reservoir::Modulation;
+
+/// What the dashboard shows.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct HomeostatState {
+    pub setpoint: f32,
+    pub mean_entropy: f32,
+    pub error_integral: f32, // error·minutes
+    pub adjustment: f32,     // u, -1 (calm) .. +1 (excite)
+    pub gain: f32,
+    pub leak_scale: f32,
+    pub noise: f32,
+}
+
+pub struct Homeostat {
+    config: HomeostatConfig,
+    mean: Option<f32>,
+    setpoint: f32,
+    integral: f32,
+    adjustment: f32,
+    since_update: f32,
+}
+
+impl Homeostat {
+    pub fn new(config: HomeostatConfig) -> Self {
+        Self { config, mean: None, setpoint: 0.5, integral: 0.0, adjustment: 0.0, since_update: 0.0 }
+    }
+
+    /// Every tick. Returns a new modulation when the controller moved (every `update_secs` at most).
+    pub fn observe(&mut self, entropy: f32, setpoint: f32, dt: f32, frozen: bool) -> Option<Modulation> {
+        if !self.config.enabled || !entropy.is_finite() {
+            return None;
+        }
+        self.setpoint = setpoint.clamp(0.1, 0.9);
+        let alpha = (dt / self.config.window_secs.max(dt)).clamp(0.0, 1.0);
+        let mean = self.mean.get_or_insert(entropy);
+        *mean += alpha * (entropy - *mean);
+
+        self.since_update += dt;
+        if frozen || self.since_update < self.config.update_secs {
+            return None;
+        }
+        let minutes = self.since_update / 60.0;
+        self.since_update = 0.0;
+
+        let error = self.setpoint - *mean;
+        let pinned = self.adjustment.abs() >= 1.0 && error.signum() == self.adjustment.signum();
+        if !pinned {
+            self.integral += error * minutes;
+        }
+        if self.config.ki > 0.0 {
+            let limit = 1.0 / self.config.ki;
+            self.integral = self.integral.clamp(-limit, limit);
+        }
+
+        let target = (self.config.kp * error + self.config.ki * self.integral).clamp(-1.0, 1.0);
+        let step = (target - self.adjustment).clamp(-self.config.max_step, self.config.max_step);
+        self.adjustment = (self.adjustment + step).clamp(-1.0, 1.0);
+        Some(self.modulation())
+    }
+
+    pub fn modulation(&self) -> Modulation {
+        let u = self.adjustment;
+        Modulation {
+            gain: (self.config.gain_octaves * u).exp2(),
+            leak_scale: (1.0 + self.config.leak_range * u).max(0.05),
+            noise: self.config.noise_max * u.max(0.0),
+        }
+    }
+
+    pub fn state(&self) -> HomeostatState {
+        let m = self.modulation();
+        HomeostatState {
+            setpoint: self.setpoint,
+            mean_entropy: self.mean.unwrap_or(0.0),
+            error_integral: self.integral,
+            adjustment: self.adjustment,
+            gain: m.gain,
+            leak_scale: m.leak_scale,
+            noise: m.noise,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::reservoir::FractalReservoir;
+
+    struct Trial {
+        start: &'static str,
+        initial_entropy: f32, // Mean before the controller's first move
+        final_entropy: f32,
+        converged: bool,
+    }
+
+    /// Simulated clock: 10Hz is plenty for a minutes-scale controller.
+    const DT: f32 = 0.1;
+
+    /// Drive a seeded reservoir from saturated, chaotic and silent starts for `minutes`
+    /// of simulated time (no input: only the controller can move it). Converged = the mean
+    /// got within `tolerance` of the setpoint by the last quarter of the run and stayed there.
+    fn trials(config: &HomeostatConfig, seed: u64, size: usize, setpoint: f32, minutes: f32, tolerance: f32) -> Vec<Trial> {
+        let starts: [(&str, f32, f32); 3] = [
+            ("saturated", 3.0, 0.0),  // Runaway recurrence: neurons pinned at ±1
+            ("chaotic", 0.5, 0.0),    // Just past the critical point: entropy pinned near 1
+            ("silent", 0.05, 0.0),    // Recurrence too weak to echo anything
+        ];
+        let ticks = (minutes * 60.0 / DT) as usize;
+        let settle_from = ticks * 3 / 4;
+
+        starts.iter().map(|&(name, spectral_radius, input_level)| {
+            let mut ego = FractalReservoir::seeded(size, size, spectral_radius, 0.2, seed);
+            let mut homeostat = Homeostat::new(HomeostatConfig { enabled: true, ..config.clone() });
+            let input: Vec<f32> = (0..size).map(|i| (i as f32 * 0.37).sin() * input_level).collect();
+
+            let (mut before, mut before_ticks, mut moved) = (0.0, 0, false);
+            let mut entered: Option<usize> = None;
+            let mut stayed = true;
+            for t in 0..ticks {
+                let entropy = ego.tick(&input, 0.2, 0.2, 0.1, DT);
+                if let Some(m) = homeostat.observe(entropy, setpoint, DT, false) {
+                    ego.modulate(m);
+                    moved = true;
+                }
+                if !moved {
+                    before += entropy;
+                    before_ticks += 1;
+                }
+                let inside = (homeostat.state().mean_entropy - homeostat.setpoint).abs() <= tolerance;
+                match (inside, entered) {
+                    (true, None) => entered = Some(t),
+                    (false, Some(_)) => {
+                        entered = None;
+                        if t >= settle_from { stayed = false; }
+                    },
+                    _ => {}
+                }
+            }
+            Trial {
+                start: name,
+                initial_entropy: if before_ticks > 0 { before / before_ticks as f32 } else { 0.0 },
+                final_entropy: homeostat.state().mean_entropy,
+                converged: stayed && entered.is_some_and(|t| t <= settle_from),
+            }
+        }).collect()
+    }
+
+    #[test]
+    fn saturated_chaotic_and_silent_starts_converge_on_the_setpoint() {
+        for trial in trials(&HomeostatConfig::default(), 7, 100, 0.5, 60.0, 0.05) {
+            assert!(trial.converged, "{} start: entropy {:.3} -> {:.3}, never settled within 0.05 of 0.5",
+                trial.start, trial.initial_entropy, trial.final_entropy);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::thread;
 
 use crate::core::thought::{Thought, MindVoice};
 use crate::cortex::planet::CortexInput;
+use crate::cortex::queue::{CortexSender, CortexPriority};
 // use rand::Rng; 
 
 /// Inner Voice - Silent rumination thread
@@ -10,7 +11,7 @@ use crate::cortex::planet::CortexInput;
 /// Creates internal dialogue that is NOT vocalized, only logged to Stream of Consciousness.
 #[allow(dead_code)]
 pub fn spawn_inner_voice(
-    tx_cortex: Sender<CortexInput>,
+    tx_cortex: CortexSender,
     tx_thoughts: Sender<Thought>,
 ) -> Sender<()> {
     let (tx_pulse, rx_pulse) = std::sync::mpsc::channel();
@@ -55,8 +56,8 @@ pub fn spawn_inner_voice(
                 temperature_clamp: None, // Inner voice: no trauma override
             };
             
-            let _ = tx_cortex.send(input);
-            let _ = tx_thoughts.send(Thought::new(MindVoice::System, "💭 Rumiación: Generando pensamiento espontáneo...".to_string()));
+            tx_cortex.send(input, CortexPriority::Agency);
+            let _ = tx_thoughts.send(Thought::new(MindVoice::Memory, "💭 Rumiación: Generando pensamiento espontáneo...".to_string()));
         }
     });
 
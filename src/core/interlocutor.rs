@@ -0,0 +1,396 @@
+// src/core/interlocutor.rs
+// THE VISITOR: A simulated interlocutor for soak tests
+//
+// Oxytocin, engagement, the gate and the agency goals only move when someone
+// talks to ALEPH, and nobody sits in front of it for two hours. The visitor
+// does: `aleph start --soak-interlocutor 2h` starts a thread that sends stimuli
+// at human-like intervals, listens to the thought stream for Vocal replies and
+// paces itself on them:
+//
+//   replied   -> reads, "types" (time grows with the length) and answers; a
+//                coherent reply (resonance >= coherent_resonance) gets a warm
+//                answer, sooner
+//   ignored   -> after `patience` it counts the message as unanswered, waits
+//                twice as long each time (up to max_gap) and comes back with a
+//                nudge; after IGNORED_QUIET in a row it goes quiet for max_gap
+//   unprompted speech -> heard; a coherent one is answered soon
+//
+// Lines come from [interlocutor] script (one per line, in order, looping) or a
+// small template generator (opener / warm / neutral / nudge pools with topic
+// slots), seeded. When the soak ends the conversational metrics (response
+// rate, median and p90 latency, resonance as a coherence proxy, oxytocin over
+// the run) go to soak-interlocutor-<time>.json, stdout and a System thought,
+// and the daemon shuts down cleanly.
+
+use anyhow::{anyhow, Result};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::bus::{Bus, ChemEvent, Event};
+use crate::core::config::InterlocutorConfig;
+use crate::core::sieve::resonance_of;
+use crate::core::thought::{MindVoice, Thought};
+
+/// Unanswered messages in a row before the visitor goes quiet for max_gap.
+const IGNORED_QUIET: u32 = 3;
+/// How often the thread wakes up to check its schedule.
+const POLL: Duration = Duration::from_millis(250);
+/// Simulated typing speed (seconds per character).
+const TYPING_SECS_PER_CHAR: f32 = 0.05;
+
+const TOPICS: [&str; 8] = ["la música", "el silencio", "la noche", "los sueños", "la lluvia", "el mar", "las palabras", "el tiempo"];
+const OPENERS: [&str; 5] = ["hola, ¿cómo estás?", "hola aleph", "buenas, ¿qué piensas de {}?", "¿estás despierto?", "hoy pensé en {}"];
+const WARM: [&str; 6] = ["me gusta lo que dices", "qué bonito, cuéntame más", "gracias, amigo", "eso me hace pensar en {}", "te entiendo", "me alegra hablar contigo"];
+const NEUTRAL: [&str; 6] = ["¿y qué más?", "no entiendo bien", "hmm", "¿qué sientes ahora?", "háblame de {}", "¿por qué?"];
+const NUDGES: [&str; 4] = ["¿sigues ahí?", "¿aleph?", "hola?", "¿me escuchas?"];
+
+/// "2h", "30m", "90s" or a bare number of seconds.
+pub fn parse_span(s: &str) -> Result<Duration> {
+    let s = s.trim();
+    let (number, unit) = match s.char_indices().find(|(_, c)| c.is_ascii_alphabetic()) {
+        Some((i, _)) => s.split_at(i),
+        None => (s, "s"),
+    };
+    let scale = match unit {
+        "h" => 3600.0,
+        "m" | "min" => 60.0,
+        "s" => 1.0,
+        _ => return Err(anyhow!("bad span '{}' (expected e.g. 2h, 30m or 90s)", s)),
+    };
+    number.parse::<f64>().ok()
+        .filter(|v| *v > 0.0 && v.is_finite())
+        .map(|v| Duration::from_secs_f64(v * scale))
+        .ok_or_else(|| anyhow!("bad span '{}' (expected e.g. 2h, 30m or 90s)", s))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tone {
+    Opener,
+    Warm,
+    Neutral,
+    Nudge,
+}
+
+/// What the run measured (the soak report).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SoakReport {
+    pub secs: f32,
+    pub sent: u32,
+    pub replied: u32,
+    pub ignored: u32,
+    pub unprompted: u32,   // Vocal thoughts nobody asked for
+    pub response_rate: f32, // replied / (replied + ignored)
+    pub median_latency_ms: Option<u64>,
+    pub p90_latency_ms: Option<u64>,
+    pub mean_resonance: Option<f32>, // Over the replies that carried one (coherence proxy)
+    pub coherent_share: Option<f32>, // Replies at or above coherent_resonance
+    pub warm_answers: u32,
+    pub nudges: u32,
+    pub quiet_spells: u32,
+    pub oxytocin_start: Option<f32>,
+    pub oxytocin_end: Option<f32>,
+    pub oxytocin_mean: Option<f32>,
+}
+
+impl SoakReport {
+    pub fn sentence(&self) -> String {
+        format!("🗣️ SOAK: {:.0} min, {} sent, {:.0}% answered (median {}), coherence {}, oxytocin {} -> {}",
+            self.secs / 60.0, self.sent, self.response_rate * 100.0,
+            self.median_latency_ms.map_or("-".to_string(), |ms| format!("{:.1}s", ms as f32 / 1000.0)),
+            self.mean_resonance.map_or("-".to_string(), |r| format!("{:.2}", r)),
+            self.oxytocin_start.map_or("-".to_string(), |o| format!("{:.2}", o)),
+            self.oxytocin_end.map_or("-".to_string(), |o| format!("{:.2}", o)))
+    }
+}
+
+/// The interlocutor's state machine, on whatever clock it is given.
+pub struct Visitor {
+    config: InterlocutorConfig,
+    rng: StdRng,
+    script: Vec<String>,
+    next_line: usize,
+    tone: Tone,
+    waiting: Option<Instant>, // When the unanswered message went out
+    next_at: Instant,
+    ignored_streak: u32,
+    started: Instant,
+    latencies_ms: Vec<u64>,
+    resonances: Vec<f32>,
+    oxytocin: Vec<f32>,
+    report: SoakReport,
+}
+
+impl Visitor {
+    /// `script` empty = the template generator. The first message goes out after one base gap.
+    pub fn new(config: InterlocutorConfig, script: Vec<String>, now: Instant) -> Self {
+        let rng = StdRng::seed_from_u64(config.seed);
+        let next_at = now + Duration::from_secs_f32(config.base_gap_secs.max(0.1));
+        Self {
+            config,
+            rng,
+            script,
+            next_line: 0,
+            tone: Tone::Opener,
+            waiting: None,
+            next_at,
+            ignored_streak: 0,
+            started: now,
+            latencies_ms: Vec::new(),
+            resonances: Vec::new(),
+            oxytocin: Vec::new(),
+            report: SoakReport::default(),
+        }
+    }
+
+    /// The next message, if one is due now (an unanswered one past `patience` is counted first).
+    pub fn poll(&mut self, now: Instant) -> Option<String> {
+        if let Some(sent) = self.waiting {
+            if now.saturating_duration_since(sent).as_secs_f32() < self.config.patience_secs {
+                return None;
+            }
+            self.waiting = None;
+            self.report.ignored += 1;
+            self.ignored_streak += 1;
+            self.tone = Tone::Nudge;
+            let gap = if self.ignored_streak >= IGNORED_QUIET {
+                self.report.quiet_spells += 1;
+                self.ignored_streak = 0;
+                self.config.max_gap_secs
+            } else {
+                (self.config.base_gap_secs * 2f32.powi(self.ignored_streak as i32)).min(self.config.max_gap_secs)
+            };
+            self.next_at = now + Duration::from_secs_f32(gap.max(0.1));
+        }
+        if now < self.next_at {
+            return None;
+        }
+        let line = self.compose();
+        self.report.sent += 1;
+        self.report.warm_answers += (self.tone == Tone::Warm) as u32;
+        self.report.nudges += (self.tone == Tone::Nudge) as u32;
+        self.waiting = Some(now);
+        self.next_at = now; // Re-planned on the reply, or when patience runs out
+        Some(line)
+    }
+
+    /// ALEPH said something (a Vocal thought) with this resonance.
+    pub fn heard(&mut self, text: &str, resonance: Option<f32>, now: Instant) {
+        let coherent = resonance.is_some_and(|r| r >= self.config.coherent_resonance);
+        if let Some(r) = resonance {
+            self.resonances.push(r);
+        }
+        match self.waiting.take() {
+            Some(sent) => {
+                self.report.replied += 1;
+                self.ignored_streak = 0;
+                self.latencies_ms.push(now.saturating_duration_since(sent).as_millis() as u64);
+            },
+            None => {
+                self.report.unprompted += 1;
+                if !coherent {
+                    return; // Heard, not worth answering: the schedule stands
+                }
+            },
+        }
+        self.tone = if coherent { Tone::Warm } else { Tone::Neutral };
+        // Read, think, type: a warm answer comes sooner
+        let think = self.config.base_gap_secs * self.rng.random_range(0.5..1.5) * if coherent { 0.7 } else { 1.0 };
+        let typing = text.chars().count().min(200) as f32 * TYPING_SECS_PER_CHAR;
+        self.next_at = now + Duration::from_secs_f32((think + typing).max(0.1));
+    }
+
+    pub fn observe_oxytocin(&mut self, level: f32) {
+        self.oxytocin.push(level);
+    }
+
+    pub fn report(&self, now: Instant) -> SoakReport {
+        let mut report = self.report.clone();
+        report.secs = now.saturating_duration_since(self.started).as_secs_f32();
+        let answered = report.replied + report.ignored;
+        report.response_rate = if answered > 0 { report.replied as f32 / answered as f32 } else { 0.0 };
+        let mut latencies = self.latencies_ms.clone();
+        latencies.sort_unstable();
+        let quantile = |q: f32| (!latencies.is_empty()).then(|| latencies[((latencies.len() - 1) as f32 * q).round() as usize]);
+        report.median_latency_ms = quantile(0.5);
+        report.p90_latency_ms = quantile(0.9);
+        if !self.resonances.is_empty() {
+            let n = self.resonances.len() as f32;
+            report.mean_resonance = Some(self.resonances.iter().sum::<f32>() / n);
+            report.coherent_share = Some(self.resonances.iter().filter(|&&r| r >= self.config.coherent_resonance).count() as f32 / n);
+        }
+        report.oxytocin_start = self.oxytocin.first().copied();
+        report.oxytocin_end = self.oxytocin.last().copied();
+        if !self.oxytocin.is_empty() {
+            report.oxytocin_mean = Some(self.oxytocin.iter().sum::<f32>() / self.oxytocin.len() as f32);
+        }
+        report
+    }
+
+    fn compose(&mut self) -> String {
+        if !self.script.is_empty() {
+            let line = self.script[self.next_line % self.script.len()].clone();
+            self.next_line += 1;
+            return line;
+        }
+        let pool: &[&str] = match self.tone {
+            Tone::Opener => &OPENERS,
+            Tone::Warm => &WARM,
+            Tone::Neutral => &NEUTRAL,
+            Tone::Nudge => &NUDGES,
+        };
+        let template = pool[self.rng.random_range(0..pool.len())];
+        let topic = TOPICS[self.rng.random_range(0..TOPICS.len())];
+        template.replace("{}", topic)
+    }
+}
+
+/// Start the soak (no-op when [interlocutor] soak_secs is 0): stimuli into
+/// `tx_stimulus`, replies from the bus; at the end the report, then `running` = false.
+pub fn attach(bus: &Bus, tx_stimulus: Sender<String>, tx_thoughts: Sender<Thought>, running: Arc<AtomicBool>, config: InterlocutorConfig) {
+    if config.soak_secs == 0 {
+        return;
+    }
+    let script: Vec<String> = if config.script.is_empty() {
+        Vec::new()
+    } else {
+        match fs::read_to_string(&config.script) {
+            Ok(text) => text.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')).map(str::to_string).collect(),
+            Err(e) => {
+                println!("⚠️ INTERLOCUTOR: {} unreadable ({}). Using the template generator.", config.script, e);
+                Vec::new()
+            },
+        }
+    };
+    let rx = bus.subscribe();
+    let length = Duration::from_secs(config.soak_secs);
+    println!("🗣️ INTERLOCUTOR: soak for {:.0} min ({})", length.as_secs_f32() / 60.0,
+        if script.is_empty() { "template generator".to_string() } else { format!("{} scripted lines", script.len()) });
+    thread::spawn(move || {
+        let start = Instant::now();
+        let mut visitor = Visitor::new(config, script, start);
+        while running.load(Ordering::SeqCst) && start.elapsed() < length {
+            match rx.recv_timeout(POLL) {
+                Ok(Event::Thought(thought)) if thought.voice == MindVoice::Vocal => {
+                    visitor.heard(&thought.text, resonance_of(thought.payload.as_ref()), Instant::now());
+                },
+                Ok(Event::Chem(ChemEvent::Snapshot { oxytocin, .. })) => visitor.observe_oxytocin(oxytocin),
+                Ok(_) | Err(RecvTimeoutError::Timeout) => {},
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            if let Some(line) = visitor.poll(Instant::now()) {
+                if tx_stimulus.send(line).is_err() {
+                    break;
+                }
+            }
+        }
+        let report = visitor.report(Instant::now());
+        let sentence = report.sentence();
+        let path = format!("soak-interlocutor-{}.json", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+        match serde_json::to_string_pretty(&report).map_err(anyhow::Error::from).and_then(|json| fs::write(&path, json).map_err(Into::into)) {
+            Ok(()) => println!("{} (report: {})", sentence, path),
+            Err(e) => println!("{} (report not written: {})", sentence, e),
+        }
+        let payload = serde_json::to_value(&report).unwrap_or_default();
+        let _ = tx_thoughts.send(Thought::new(MindVoice::System, sentence).with_payload(serde_json::json!({ "event": "soak_report", "report": payload })));
+        running.store(false, Ordering::SeqCst); // The soak is the run: shut down cleanly (saves and all)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEP: Duration = Duration::from_millis(250);
+    const HOUR: u32 = 3600 * 4; // Steps of 250 ms
+
+    fn config() -> InterlocutorConfig {
+        InterlocutorConfig { soak_secs: 600, base_gap_secs: 10.0, patience_secs: 20.0, max_gap_secs: 120.0, ..Default::default() }
+    }
+
+    fn mean(gaps: &[f32]) -> f32 {
+        gaps.iter().sum::<f32>() / gaps.len().max(1) as f32
+    }
+
+    /// An hour on a virtual clock; `reply` answers each message (2 s later, resonance
+    /// 0.9) or not. The report and the gaps between messages.
+    fn hour(reply: bool) -> (SoakReport, Vec<f32>) {
+        let t0 = Instant::now();
+        let mut visitor = Visitor::new(config(), Vec::new(), t0);
+        let mut pending: Option<Instant> = None;
+        let mut sent_at = Vec::new();
+        for i in 0..HOUR {
+            let now = t0 + STEP * i;
+            if pending.is_some_and(|at| now >= at) {
+                pending = None;
+                visitor.heard("me gusta la lluvia", Some(0.9), now);
+            }
+            if visitor.poll(now).is_some() {
+                sent_at.push(now);
+                pending = reply.then(|| now + Duration::from_secs(2));
+            }
+        }
+        let gaps = sent_at.windows(2).map(|w| (w[1] - w[0]).as_secs_f32()).collect();
+        (visitor.report(t0 + STEP * HOUR), gaps)
+    }
+
+    #[test]
+    fn an_attentive_aleph_gets_every_answer_counted_warm_and_quick() {
+        let (report, gaps) = hour(true);
+        assert!(report.response_rate >= 0.99, "rate {:.2}", report.response_rate);
+        assert_eq!(report.ignored, 0);
+        assert_eq!(report.median_latency_ms, Some(2000));
+        assert!(report.warm_answers + 1 >= report.sent, "{} warm answers of {} sent", report.warm_answers, report.sent);
+        assert_eq!(report.coherent_share, Some(1.0));
+        let bound = 2.0 + config().base_gap_secs * 1.5 * 0.7 + 2.0; // Reply, longest warm think, typing and a step
+        assert!(gaps.iter().all(|&g| g <= bound), "a gap of {:.1}s (warm answers come within {:.1}s)", gaps.iter().cloned().fold(0.0, f32::max), bound);
+    }
+
+    #[test]
+    fn a_silent_aleph_gets_nudges_growing_gaps_and_a_quiet_spell() {
+        let (report, gaps) = hour(false);
+        let (_, attentive) = hour(true);
+        assert_eq!(report.replied, 0);
+        assert_eq!(report.response_rate, 0.0);
+        assert_eq!(report.median_latency_ms, None);
+        assert!(report.nudges + 1 >= report.sent, "{} nudges of {} sent", report.nudges, report.sent);
+        assert!(report.quiet_spells > 0);
+        assert!(gaps.len() >= 2 && gaps[1] > gaps[0], "gaps {:?} do not grow", &gaps[..gaps.len().min(4)]);
+        assert!(mean(&gaps) > mean(&attentive) * 2.0, "mean gap {:.1}s vs {:.1}s attentive", mean(&gaps), mean(&attentive));
+    }
+
+    #[test]
+    fn unprompted_speech_is_counted_apart_and_a_script_loops_in_order() {
+        let t0 = Instant::now();
+        let mut visitor = Visitor::new(config(), vec!["uno".to_string(), "dos".to_string()], t0);
+        visitor.heard("nadie me preguntó", Some(0.1), t0 + Duration::from_secs(1));
+        let mut lines = Vec::new();
+        for i in 0..HOUR {
+            let now = t0 + STEP * i;
+            if let Some(line) = visitor.poll(now) {
+                lines.push(line);
+                visitor.heard("sí", None, now + Duration::from_secs(1));
+                if lines.len() == 3 {
+                    break;
+                }
+            }
+        }
+        let report = visitor.report(t0 + STEP * HOUR);
+        assert_eq!((report.unprompted, report.replied), (1, 3));
+        assert_eq!(lines, ["uno", "dos", "uno"]);
+    }
+
+    #[test]
+    fn spans_parse() {
+        let spans = [("2h", Some(7200)), ("30m", Some(1800)), ("90s", Some(90)), ("45", Some(45)), ("2d", None), ("-1h", None)];
+        for (text, expected) in spans {
+            assert_eq!(parse_span(text).ok().map(|d| d.as_secs()), expected, "span '{}'", text);
+        }
+    }
+}
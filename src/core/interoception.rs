@@ -0,0 +1,118 @@
+// src/core/interoception.rs
+// THE PULSE: ALEPH feels its own heartbeat
+//
+// `current_hz` (the metabolic heart rate of the loop, 24-120Hz) shaped how fast
+// ALEPH lived but was never felt. Now:
+//
+//   heartbeat: a sinusoid at current_hz/60 beats per second (1 beat/s at rest)
+//       written every tick into the sensory "heartbeat" channel (core::sensory),
+//       so the reservoir is driven by, and can entrain to, its own rhythm
+//   HRV: variance of the heart rate over the last HRV_WINDOW ticks (telemetry
+//       `hrv`); a full window below RIGID_HRV is a rigid metabolism, which makes
+//       the Neocortex declare lock-in (boredom) twice as fast
+
+use std::collections::VecDeque;
+
+/// Heart-rate samples (one per tick) behind the HRV (~30s at 60Hz).
+pub const HRV_WINDOW: usize = 1800;
+/// Variance (Hz²) under which a full window counts as a rigid heart.
+pub const RIGID_HRV: f32 = 0.01;
+/// Peak of the heartbeat signal in the sensory channel (a heard word is 1.0).
+const AMPLITUDE: f32 = 0.3;
+
+#[derive(Debug, Clone, Default)]
+pub struct Heartbeat {
+    phase: f32, // Cycles, 0..1
+    history: VecDeque<f32>,
+}
+
+impl Heartbeat {
+    /// Advance `dt` seconds at heart rate `hz`; the heartbeat signal now.
+    pub fn beat(&mut self, hz: f32, dt: f32) -> f32 {
+        if hz.is_finite() && dt.is_finite() {
+            self.phase = (self.phase + hz.max(0.0) / 60.0 * dt.clamp(0.0, 1.0)).fract();
+        }
+        AMPLITUDE * (std::f32::consts::TAU * self.phase).sin()
+    }
+
+    /// One heart-rate sample (every metabolic step).
+    pub fn observe(&mut self, hz: f32) {
+        if self.history.len() == HRV_WINDOW {
+            self.history.pop_front();
+        }
+        self.history.push_back(hz);
+    }
+
+    /// Variance of the recent heart rate (Hz²; 0 before two samples).
+    pub fn hrv(&self) -> f32 {
+        let n = self.history.len();
+        if n < 2 {
+            return 0.0;
+        }
+        let mean = self.history.iter().sum::<f32>() / n as f32;
+        self.history.iter().map(|h| (h - mean).powi(2)).sum::<f32>() / n as f32
+    }
+
+    /// Chronically low HRV: a full window that barely moved.
+    pub fn rigid(&self) -> bool {
+        self.history.len() == HRV_WINDOW && self.hrv() < RIGID_HRV
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::neocortex::{CognitiveEvent, Neocortex, BOREDOM_TICKS};
+
+    /// Frequency (Hz) of the strongest component of `samples` (taken every `dt`)
+    /// between 0.2 and 5Hz, in 0.01Hz steps.
+    fn dominant_frequency(samples: &[f32], dt: f32) -> f32 {
+        let power = |f: f32| {
+            let (mut re, mut im) = (0.0f32, 0.0f32);
+            for (i, s) in samples.iter().enumerate() {
+                let angle = std::f32::consts::TAU * f * i as f32 * dt;
+                re += s * angle.cos();
+                im += s * angle.sin();
+            }
+            re * re + im * im
+        };
+        (20..=500).map(|step| step as f32 / 100.0)
+            .max_by(|a, b| power(*a).total_cmp(&power(*b)))
+            .unwrap_or(0.0)
+    }
+
+    #[test]
+    fn the_channel_peaks_at_hz_over_60_for_resting_excited_and_tired_hearts() {
+        for hz in [30.0f32, 60.0, 72.0, 110.0] {
+            let dt = 1.0 / hz;
+            let mut heart = Heartbeat::default();
+            let samples: Vec<f32> = (0..(hz * 20.0) as usize).map(|_| heart.beat(hz, dt)).collect();
+            let peak = dominant_frequency(&samples, dt);
+            assert!((peak - hz / 60.0).abs() <= 0.02, "heart at {}Hz: channel peaks at {:.2}Hz, expected {:.2}Hz", hz, peak, hz / 60.0);
+        }
+    }
+
+    #[test]
+    fn a_steady_heart_becomes_rigid_once_the_window_fills_and_a_varying_one_does_not() {
+        let (mut steady, mut lively) = (Heartbeat::default(), Heartbeat::default());
+        for i in 0..HRV_WINDOW {
+            steady.observe(60.0);
+            lively.observe(60.0 + 5.0 * (i as f32 / 120.0).sin());
+            assert!(i + 1 == HRV_WINDOW || !steady.rigid(), "rigid after only {} samples", i + 1);
+        }
+        assert!(steady.rigid(), "steady hrv {:.4}", steady.hrv());
+        assert!(!lively.rigid() && lively.hrv() >= 1.0, "lively hrv {:.2}", lively.hrv());
+    }
+
+    #[test]
+    fn a_rigid_heart_locks_in_after_half_the_stillness() {
+        let lock_in = |rigid: bool| {
+            let mut neocortex = Neocortex::new();
+            neocortex.set_rigid(rigid);
+            (0..BOREDOM_TICKS).position(|_| matches!(neocortex.observe(0.5), Some(CognitiveEvent::Boredom)))
+        };
+        let (rigid, supple) = (lock_in(true), lock_in(false));
+        assert!(rigid.is_some_and(|t| t < BOREDOM_TICKS / 2 + 20), "lock-in after {:?} flat ticks (of {})", rigid, BOREDOM_TICKS);
+        assert_eq!(supple, None);
+    }
+}
@@ -1,5 +1,18 @@
 use serde::{Deserialize, Serialize};
-use crate::senses::ears::AudioSpectrum;
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+use std::time::SystemTime;
+use crate::core::gate::GateStats;
+use crate::core::satellite::MembraneStats;
+use crate::core::components::ComponentStatus;
+use crate::core::memory_vector::MemoryRecord;
+use crate::core::pacing;
+use crate::core::thought::MindVoice;
+use crate::core::timeline::unix_millis;
+use crate::cortex::context::Segment;
+use crate::cortex::planet::{CortexInput, CortexOutput, Temperament};
+use crate::cortex::queue::CortexPriority;
+use crate::senses::ears::{AudioSpectrum, EarVitals, Utterance};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum AlephPacket {
@@ -34,11 +47,477 @@ pub enum AlephPacket {
         
         // Spatial Topology (Real backend positions)
         neuron_positions: Vec<[f32; 3]>,
+
+        // Do-Not-Disturb (no transcription)
+        #[serde(default)]
+        privacy_mode: bool,
+
+        // Membrane (rolling last minute) and attention score of its last filtering
+        #[serde(default)]
+        satellite_passed: u64,
+        #[serde(default)]
+        satellite_hardened: u64,
+        #[serde(default)]
+        satellite_ontological_errors: u64,
+        #[serde(default)]
+        satellite_attention: f32,
+        // Expression gate since boot: attempts, spoken, and silences per reason
+        #[serde(default)]
+        gate_attempts: u64,
+        #[serde(default)]
+        gate_spoken: u64,
+        #[serde(default)]
+        gate_suppressed_by_reason: BTreeMap<String, u64>,
     },
     
+    /// Daemon -> Client: first packet of a connection. The daemon's monotonic clock
+    /// started at `clock_origin_ms` (Unix millis) and read `clock_us` when this was sent;
+    /// `captured_us` stamps (AudioSpectrum) + origin vs the client's clock = end-to-end latency.
+    Hello {
+        clock_origin_ms: u64,
+        clock_us: u64,
+    },
+
     /// Client -> Daemon: Perturbations
     Stimulus {
         text: String,
         force: f32, // Intensity of the input
+    },
+
+    /// Client -> Daemon: a maintenance command (core/admin.rs), gated by `[admin] secret`
+    Admin {
+        command: String,
+        #[serde(default)]
+        args: Vec<String>,
+        #[serde(default)]
+        secret: String,
+    },
+    /// Daemon -> Client: the answer to the client's Admin request (structured JSON, or an error)
+    AdminReply {
+        command: String,
+        ok: bool,
+        reply: serde_json::Value,
+    },
+
+    // --- Split mode: daemon <-> `aleph cortexd` child (cortex/cortexd.rs) ---
+
+    /// Daemon -> cortexd: something for the Planet (echo pooled at reservoir_size)
+    CortexInput {
+        input: CortexInput,
+        priority: CortexPriority,
+        reservoir_size: usize,
+    },
+    /// cortexd -> Daemon: what the Planet made of it
+    CortexOutput(CortexOutput),
+    /// cortexd -> Daemon: a transcript that passed the sanity scorer (audio-text)
+    Heard(Utterance),
+    /// cortexd -> Daemon: its hash embedding (Wernicke)
+    WordEmbedding(Vec<f32>),
+    /// cortexd -> Daemon: one cochlea frame
+    Spectrum(AudioSpectrum),
+    /// Daemon -> cortexd: browser PCM (WebSocket audio mode)
+    AudioChunk(Vec<f32>),
+    /// Daemon -> cortexd: Do-Not-Disturb switched
+    Privacy(bool),
+    /// Daemon -> cortexd: rebuild the Semantic Field (after sleep)
+    RebuildField(Vec<MemoryRecord>),
+    /// Daemon -> cortexd: send the history ring (answered with History)
+    DumpHistory,
+    History(Vec<Segment>),
+    /// Daemon -> cortexd: memory pressure capped (or restored) the Planet's history budget
+    HistoryBudget(Option<usize>),
+    /// Daemon -> cortexd: the genome's temperament for the Planet (at birth, on reload)
+    Temperament(Temperament),
+    /// cortexd -> Daemon: a thought of the child (re-born in the parent, with a parent id)
+    Thought {
+        voice: MindVoice,
+        text: String,
+        payload: Option<serde_json::Value>,
+    },
+    /// cortexd -> Daemon: a component of the child came up, degraded or went down
+    Component {
+        name: String,
+        status: ComponentStatus,
+    },
+    /// cortexd -> Daemon: Whisper worker counters (~1s)
+    EarVitals(EarVitals),
+
+    /// Daemon -> Client: something that happened once (sent once, between telemetry frames)
+    Event {
+        kind: EventKind,
+        timestamp: u64, // Unix millis
+        detail: serde_json::Value,
+    },
+}
+
+/// What an `AlephPacket::Event` reports. Clients keep timelines of these
+/// instead of diffing telemetry snapshots (and missing what happened between two).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Epiphany,         // Dopamine locked pathways in (detail: pathways, cause, dopamine)
+    TraumaTransition, // The Lucifer Protocol changed state (state, escalating, cortisol_avg)
+    SleepStage,       // Awake / Rem / Deep (from, to)
+    Neurogenesis,     // Neurons grown this tick (grown, total)
+    Apoptosis,        // Synapses pruned (pruned)
+    GoalCompleted,    // Agency reward (reward)
+    ComponentRestart, // A component is Ready again (component, was)
+    ManualOverride,   // SYS: command from the dashboard / TUI / SIGHUP (command, ...)
+}
+
+pub const EVENT_KINDS: [EventKind; 8] = [
+    EventKind::Epiphany, EventKind::TraumaTransition, EventKind::SleepStage, EventKind::Neurogenesis,
+    EventKind::Apoptosis, EventKind::GoalCompleted, EventKind::ComponentRestart, EventKind::ManualOverride,
+];
+
+/// One event on its way out: published on the bus by the loop, then sent to
+/// IPC clients, WebSocket clients, the timeline and the ledger.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Landmark {
+    pub kind: EventKind,
+    pub timestamp: u64,
+    pub monotonic_us: u64, // pacing::monotonic_us() at birth (the ledger's; not on the wire)
+    pub detail: serde_json::Value,
+}
+
+impl Landmark {
+    pub fn now(kind: EventKind, detail: serde_json::Value) -> Self {
+        Self { kind, timestamp: unix_millis(SystemTime::now()), monotonic_us: pacing::monotonic_us(), detail }
+    }
+}
+
+impl From<Landmark> for AlephPacket {
+    fn from(landmark: Landmark) -> Self {
+        AlephPacket::Event { kind: landmark.kind, timestamp: landmark.timestamp, detail: landmark.detail }
+    }
+}
+
+/// Longest stimulus line the daemon buffers from a client (anything longer is dropped whole).
+pub const MAX_STIMULUS_LINE: usize = 64 * 1024;
+/// Longest telemetry line a client buffers (2500 neurons with positions is a few hundred KB).
+pub const MAX_TELEMETRY_LINE: usize = 8 * 1024 * 1024;
+
+/// Reassembles the newline-delimited packets of a socket from whatever chunks
+/// `read` hands over: a line split across reads waits for its end, several lines
+/// in one read all come out (in order), a multi-byte character cut at the chunk
+/// boundary is glued back before decoding, and a line longer than `max_line` is
+/// dropped whole (up to its newline) instead of growing the buffer forever.
+pub struct LineBuffer {
+    pending: Vec<u8>,
+    max_line: usize,
+    discarding: bool, // Inside an overlong line: skip until its newline
+    pub dropped: u64,
+}
+
+impl LineBuffer {
+    pub fn new(max_line: usize) -> Self {
+        Self { pending: Vec::new(), max_line, discarding: false, dropped: 0 }
+    }
+
+    /// Feed one read; returns the complete, non-blank lines it finished.
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut segments = bytes.split(|&b| b == b'\n').peekable();
+        while let Some(segment) = segments.next() {
+            let complete = segments.peek().is_some();
+            if !self.discarding {
+                self.pending.extend_from_slice(segment);
+                if self.pending.len() > self.max_line {
+                    self.pending.clear();
+                    self.discarding = true;
+                    self.dropped += 1;
+                }
+            }
+            if complete {
+                if !self.discarding {
+                    let line = String::from_utf8_lossy(&self.pending);
+                    if !line.trim().is_empty() {
+                        lines.push(line.trim_end_matches('\r').to_string());
+                    }
+                }
+                self.pending.clear();
+                self.discarding = false;
+            }
+        }
+        lines
+    }
+}
+
+/// One read from `stream` into `buffer`: Ok(None) at EOF, otherwise every packet
+/// the read completed, in arrival order (undecodable lines as Err, so the caller
+/// can show them). Non-blocking sockets with nothing to say give WouldBlock.
+pub fn read_packets<R: Read>(stream: &mut R, buffer: &mut LineBuffer) -> io::Result<Option<Vec<serde_json::Result<AlephPacket>>>> {
+    let mut chunk = [0u8; 16384];
+    match stream.read(&mut chunk)? {
+        0 => Ok(None),
+        n => Ok(Some(buffer.push(&chunk[..n]).iter().map(|line| serde_json::from_str(line)).collect())),
+    }
+}
+
+impl AlephPacket {
+    pub fn hello() -> Self {
+        AlephPacket::Hello { clock_origin_ms: pacing::clock_origin().1, clock_us: pacing::monotonic_us() }
+    }
+
+    /// Telemetry with the membrane and gate counters filled in (other packets unchanged).
+    pub fn with_decisions(mut self, membrane: MembraneStats, gate: &GateStats) -> Self {
+        if let AlephPacket::Telemetry {
+            satellite_passed, satellite_hardened, satellite_ontological_errors, satellite_attention,
+            gate_attempts, gate_spoken, gate_suppressed_by_reason, ..
+        } = &mut self {
+            *satellite_passed = membrane.passed;
+            *satellite_hardened = membrane.hardened;
+            *satellite_ontological_errors = membrane.ontological_errors;
+            *satellite_attention = membrane.attention;
+            *gate_attempts = gate.attempts;
+            *gate_spoken = gate.spoken;
+            *gate_suppressed_by_reason = gate.suppressed.clone();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::gate::ExpressionGate;
+    use crate::core::satellite::{Satellite, MEMBRANE_WINDOW};
+    use std::time::{Duration, Instant};
+
+    /// Telemetry keys added for the membrane/gate dashboards and their JSON type.
+    const DECISION_KEYS: [(&str, &str); 7] = [
+        ("satellite_passed", "u64"),
+        ("satellite_hardened", "u64"),
+        ("satellite_ontological_errors", "u64"),
+        ("satellite_attention", "f64"),
+        ("gate_attempts", "u64"),
+        ("gate_spoken", "u64"),
+        ("gate_suppressed_by_reason", "object"),
+    ];
+
+    fn empty_telemetry() -> AlephPacket {
+        AlephPacket::Telemetry {
+            adenosine: 0.0, cortisol: 0.0, dopamine: 0.0, oxytocin: 0.0,
+            audio_spectrum: AudioSpectrum::default(),
+            heart_rate: 0.0, lucidity: 1.0, reservoir_activity: Vec::new(),
+            short_term_memory: Vec::new(), current_state: String::new(), entropy: 0.0,
+            loop_frequency: 60.0, cpu_usage: 0.0, activations: Vec::new(), region_map: Vec::new(),
+            reservoir_size: 0, visual_cortex: Vec::new(), neuron_positions: Vec::new(),
+            privacy_mode: false,
+            satellite_passed: 0, satellite_hardened: 0, satellite_ontological_errors: 0, satellite_attention: 0.0,
+            gate_attempts: 0, gate_spoken: 0, gate_suppressed_by_reason: BTreeMap::new(),
+        }
+    }
+
+    /// A real gate driven through one suppression of each reason and two spoken
+    /// lines, and a membrane whose first decision rolled off the window.
+    fn dashboard_stats() -> (MembraneStats, GateStats) {
+        let mut gate = ExpressionGate::new();
+        let attempts = [
+            (0.1, 0.9, 0.5, "Hola.", 1000u64, true),
+            (0.1, 0.9, 0.5, "Hola otra vez.", 1001, false),           // cooldown
+            (0.1, 0.9, 0.5, "Subscribe to my marketing", 2000, false), // hallucination
+            (0.9, 0.9, 0.5, "Estoy cansado.", 3000, false),           // fatigue
+            (0.1, 0.9, 0.5, "   ", 4000, false),                      // empty
+            (0.6, 0.0, 0.1, "Nada.", 5000, false),                    // low drive
+            (0.1, 0.9, 0.5, "Te escucho.", 6000, true),
+        ];
+        for &(adenosine, entropy, dopamine, text, tick, spoken) in &attempts {
+            assert_eq!(gate.attempt_vocalization(adenosine, entropy, dopamine, 0.0, text, tick).is_ok(), spoken, "gate: '{}' at tick {}", text, tick);
+        }
+
+        let mut satellite = Satellite::new(0.5, 0.5);
+        let t0 = Instant::now();
+        satellite.record(true, 0.0, 0.8, t0);
+        satellite.record(false, 1.0, 0.6, t0 + Duration::from_secs(10));
+        satellite.record(true, 0.5, 0.7, t0 + MEMBRANE_WINDOW + Duration::from_secs(5));
+        (satellite.stats(t0 + MEMBRANE_WINDOW + Duration::from_secs(5)), gate.stats.clone())
+    }
+
+    #[test]
+    fn gate_and_membrane_counters_add_up() {
+        let (membrane, stats) = dashboard_stats();
+        assert_eq!((stats.attempts, stats.spoken), (7, 2));
+        assert_eq!(stats.spoken + stats.suppressed.values().sum::<u64>(), stats.attempts, "{:?}", stats);
+        let reasons: Vec<&str> = stats.suppressed.keys().map(|k| k.as_str()).collect();
+        assert_eq!(reasons, ["cooldown", "empty", "fatigue", "hallucination", "low_drive"]);
+        // The first decision rolled off the window
+        assert_eq!((membrane.passed, membrane.hardened, membrane.ontological_errors), (1, 1, 2), "{:?}", membrane);
+        assert_eq!(membrane.attention, 0.7);
+    }
+
+    #[test]
+    fn dashboard_keys_keep_their_place_in_the_schema() {
+        let (membrane, stats) = dashboard_stats();
+        let json = serde_json::to_value(empty_telemetry().with_decisions(membrane, &stats)).unwrap();
+        let fields = &json["Telemetry"];
+        for (key, kind) in DECISION_KEYS {
+            let ok = match (fields.get(key), kind) {
+                (Some(v), "u64") => v.is_u64(),
+                (Some(v), "f64") => v.is_number(),
+                (Some(v), _) => v.is_object(),
+                (None, _) => false,
+            };
+            assert!(ok, "Telemetry.{} is {:?} (expected {})", key, fields.get(key), kind);
+        }
+        let got = serde_json::json!({
+            "passed": fields["satellite_passed"], "hardened": fields["satellite_hardened"],
+            "errors": fields["satellite_ontological_errors"],
+            "gate": [fields["gate_attempts"], fields["gate_spoken"]],
+            "fatigue": fields["gate_suppressed_by_reason"]["fatigue"],
+        });
+        assert_eq!(got, serde_json::json!({ "passed": 1, "hardened": 1, "errors": 2, "gate": [7, 2], "fatigue": 1 }));
+        let back = serde_json::to_value(serde_json::from_value::<AlephPacket>(json.clone()).unwrap()).unwrap();
+        assert_eq!(back, json, "Telemetry does not round-trip");
+
+        // An older daemon's packet (no dashboard keys) still decodes, with zeros
+        let mut old = json;
+        let fields = old["Telemetry"].as_object_mut().unwrap();
+        for (key, _) in DECISION_KEYS {
+            fields.remove(key);
+        }
+        match serde_json::from_value::<AlephPacket>(old).unwrap() {
+            AlephPacket::Telemetry { gate_attempts, satellite_passed, gate_suppressed_by_reason, .. } => {
+                assert_eq!((gate_attempts, satellite_passed), (0, 0));
+                assert!(gate_suppressed_by_reason.is_empty());
+            },
+            other => panic!("an old Telemetry packet decodes as {:?}", other),
+        }
+    }
+
+    /// The wire form of one Event packet per kind, as the TUI and the dashboard parse it.
+    const EVENT_FIXTURES: [(EventKind, &str); 8] = [
+        (EventKind::Epiphany, r#"{"Event":{"kind":"Epiphany","timestamp":1760000000000,"detail":{"pathways":14,"cause":"dopamine"}}}"#),
+        (EventKind::TraumaTransition, r#"{"Event":{"kind":"TraumaTransition","timestamp":1760000000001,"detail":{"state":"ESCALATING","escalating":true,"cortisol_avg":0.72}}}"#),
+        (EventKind::SleepStage, r#"{"Event":{"kind":"SleepStage","timestamp":1760000000002,"detail":{"from":"Rem","to":"Deep"}}}"#),
+        (EventKind::Neurogenesis, r#"{"Event":{"kind":"Neurogenesis","timestamp":1760000000003,"detail":{"grown":2,"total":514}}}"#),
+        (EventKind::Apoptosis, r#"{"Event":{"kind":"Apoptosis","timestamp":1760000000004,"detail":{"pruned":381}}}"#),
+        (EventKind::GoalCompleted, r#"{"Event":{"kind":"GoalCompleted","timestamp":1760000000005,"detail":{"reward":0.5}}}"#),
+        (EventKind::ComponentRestart, r#"{"Event":{"kind":"ComponentRestart","timestamp":1760000000006,"detail":{"component":"cortex","was":{"status":"degraded","detail":"cortexd died"}}}}"#),
+        (EventKind::ManualOverride, r#"{"Event":{"kind":"ManualOverride","timestamp":1760000000007,"detail":{"command":"reload_genome","applied":[["curiosity",0.5,0.7]]}}}"#),
+    ];
+
+    #[test]
+    fn every_event_kind_matches_its_wire_fixture() {
+        let missing: Vec<&EventKind> = EVENT_KINDS.iter().filter(|k| !EVENT_FIXTURES.iter().any(|(f, _)| f == *k)).collect();
+        assert!(missing.is_empty(), "event kinds without a fixture: {:?}", missing);
+        for (kind, fixture) in EVENT_FIXTURES {
+            let expected: serde_json::Value = serde_json::from_str(fixture).unwrap();
+            let packet = serde_json::from_str::<AlephPacket>(fixture).unwrap();
+            assert!(matches!(packet, AlephPacket::Event { kind: decoded, .. } if decoded == kind), "{:?} fixture decodes as {:?}", kind, packet);
+            assert_eq!(serde_json::to_value(&packet).unwrap(), expected, "{:?} does not round-trip", kind);
+            let landmark = Landmark { kind, timestamp: expected["Event"]["timestamp"].as_u64().unwrap(), monotonic_us: 0, detail: expected["Event"]["detail"].clone() };
+            assert_eq!(serde_json::to_value(AlephPacket::from(landmark)).unwrap(), expected, "{:?}: Landmark -> packet differs from the fixture", kind);
+        }
+    }
+
+    /// A socket stand-in that hands out one prepared chunk per read.
+    struct MockStream(std::collections::VecDeque<Vec<u8>>);
+
+    impl Read for MockStream {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.pop_front() {
+                Some(chunk) => {
+                    let n = chunk.len().min(buf.len());
+                    buf[..n].copy_from_slice(&chunk[..n]);
+                    if n < chunk.len() {
+                        self.0.push_front(chunk[n..].to_vec());
+                    }
+                    Ok(n)
+                },
+                None => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn packets_come_out_of_each_read_in_order() {
+        // Three packets in a single read, and a fourth split over two reads
+        let mut first = empty_telemetry();
+        if let AlephPacket::Telemetry { current_state, .. } = &mut first {
+            *current_state = "first".to_string();
+        }
+        let mut last = empty_telemetry();
+        if let AlephPacket::Telemetry { current_state, .. } = &mut last {
+            *current_state = "last".to_string();
+        }
+        let echo = AlephPacket::Stimulus { text: "eco ñ🌊".to_string(), force: 1.0 };
+        let fourth = AlephPacket::Stimulus { text: "partida en dos".to_string(), force: 0.5 };
+        let line = |p: &AlephPacket| format!("{}\n", serde_json::to_string(p).unwrap());
+
+        let one_read = [&first, &echo, &last].iter().map(|p| line(p)).collect::<String>().into_bytes();
+        let split = line(&fourth).into_bytes();
+        let (head, tail) = split.split_at(split.len() / 2);
+        let mut stream = MockStream([one_read, head.to_vec(), tail.to_vec()].into());
+        let mut buffer = LineBuffer::new(MAX_TELEMETRY_LINE);
+
+        let mut reads = Vec::new();
+        while let Ok(Some(packets)) = read_packets(&mut stream, &mut buffer) {
+            reads.push(packets.into_iter().map(|p| match p {
+                Ok(AlephPacket::Telemetry { current_state, .. }) => format!("telemetry:{}", current_state),
+                Ok(AlephPacket::Stimulus { text, .. }) => format!("stimulus:{}", text),
+                Ok(_) => "other".to_string(),
+                Err(e) => format!("error:{}", e),
+            }).collect::<Vec<_>>());
+        }
+        assert_eq!(reads, vec![
+            vec!["telemetry:first".to_string(), "stimulus:eco ñ🌊".to_string(), "telemetry:last".to_string()],
+            vec![],
+            vec!["stimulus:partida en dos".to_string()],
+        ]);
+    }
+
+    /// Tiny deterministic generator.
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn lines_survive_random_cuts_and_hostile_neighbours() {
+        const WORDS: [&str; 8] = ["hola", "ñandú", "🌊", "今日", "\"quoted\"", "back\\slash", "e\u{301}", " "];
+        let mut state = 42u64 | 1;
+        for round in 0..2000 {
+            // Packets through a LineBuffer cut at random byte offsets come out once, in order and intact
+            let mut packets: Vec<String> = (0..1 + next(&mut state) % 5).map(|_| {
+                let text: String = (0..next(&mut state) % 12).map(|_| WORDS[(next(&mut state) % 8) as usize]).collect();
+                serde_json::to_string(&AlephPacket::Stimulus { text, force: 1.0 }).unwrap()
+            }).collect();
+            if round % 16 == 0 {
+                packets.push(serde_json::to_string(&empty_telemetry()).unwrap());
+            }
+            let stream: Vec<u8> = packets.iter().flat_map(|p| p.bytes().chain(std::iter::once(b'\n'))).collect();
+            let mut buffer = LineBuffer::new(MAX_STIMULUS_LINE);
+            let mut out = Vec::new();
+            let mut rest = &stream[..];
+            while !rest.is_empty() {
+                let cut = 1 + (next(&mut state) as usize % rest.len().min(40));
+                out.extend(buffer.push(&rest[..cut]));
+                rest = &rest[cut..];
+            }
+            assert_eq!(out, packets, "round {}", round);
+
+            // Truncated, garbage and overlong lines in front of a good one: the good one still gets through
+            let good = &packets[0];
+            let truncated = &good.as_bytes()[..next(&mut state) as usize % good.len()]; // May end mid-character
+            let garbage: Vec<u8> = (0..next(&mut state) % 64).map(|_| next(&mut state) as u8).filter(|&b| b != b'\n').collect();
+            let mut hostile = Vec::new();
+            hostile.extend_from_slice(truncated);
+            hostile.push(b'\n');
+            hostile.extend_from_slice(&garbage);
+            hostile.push(b'\n');
+            hostile.extend_from_slice(&vec![b'{'; 300 + (next(&mut state) % 700) as usize]); // Overlong
+            hostile.push(b'\n');
+            hostile.extend_from_slice(good.as_bytes());
+            hostile.push(b'\n');
+            let mut small = LineBuffer::new(256);
+            let lines: Vec<String> = hostile.chunks(7).flat_map(|chunk| small.push(chunk)).collect();
+            let decoded: Vec<Option<AlephPacket>> = lines.iter().map(|l| serde_json::from_str(l).ok()).collect();
+            assert_eq!(lines.last(), Some(good), "round {}", round);
+            assert!(matches!(decoded.last(), Some(Some(AlephPacket::Stimulus { .. }))), "round {}", round);
+            assert_eq!(small.dropped, 1, "round {}", round);
+            assert!(lines.iter().all(|l| l.len() <= 256), "round {}", round);
+        }
     }
 }
@@ -0,0 +1,297 @@
+// src/core/latency.rs
+// THE STOPWATCH: How long a sound takes to reach the reservoir
+//
+// Nothing measured the time between a sound hitting the mic and the Auditory
+// neurons feeling it, and the embodiment illusion collapses when reactions
+// lag by a second. Every spectrum now carries four stamps on the daemon's
+// monotonic clock (core/pacing.rs):
+//
+//   captured  the frame's last sample (senses/cochlea.rs CaptureClock)
+//   sent      the ears hand it to the channel       capture = sent − captured
+//   received  the daemon's bridge takes it off      transit = received − sent
+//   injected  inject_embedding(Auditory) ran        wait    = injected − received
+//
+// The last `window` spectra give a rolling distribution (p50/p95/max of the
+// total, p50 of each hop). When the p95 stays over budget_ms for persist_secs
+// the audio path sheds load: exposure tracking is paused and the cochlea
+// analyzes one frame every fft_stride hops; one warning thought. It recovers
+// once the p95 stays under recover_ratio × budget for persist_secs.
+// Missing stamps (a spectrum from before them) fold into the next hop.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+
+use crate::core::config::LatencyConfig;
+use crate::senses::ears::AudioSpectrum;
+
+/// Where one spectrum spent its time (µs).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Hops {
+    pub capture_us: u64,
+    pub transit_us: u64,
+    pub wait_us: u64,
+}
+
+impl Hops {
+    /// None without a capture stamp (nothing to measure from).
+    pub fn of(spec: &AudioSpectrum, injected_us: u64) -> Option<Self> {
+        if spec.captured_us == 0 {
+            return None;
+        }
+        let sent = if spec.sent_us > 0 { spec.sent_us } else { spec.captured_us };
+        let received = if spec.received_us > 0 { spec.received_us } else { sent };
+        Some(Self {
+            capture_us: sent.saturating_sub(spec.captured_us),
+            transit_us: received.saturating_sub(sent),
+            wait_us: injected_us.saturating_sub(received),
+        })
+    }
+
+    pub fn total_us(&self) -> u64 {
+        self.capture_us + self.transit_us + self.wait_us
+    }
+}
+
+/// A change of shedding state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shed {
+    Start { p95_ms: f32, budget_ms: f32 },
+    Stop { p95_ms: f32 },
+}
+
+impl Shed {
+    pub fn line(&self) -> String {
+        match self {
+            Shed::Start { p95_ms, budget_ms } => format!(
+                "⏳ LATENCY: sound takes {:.0} ms (p95) to reach the reservoir, budget {:.0} ms. Shedding: fewer FFTs, no exposure tracking.",
+                p95_ms, budget_ms),
+            Shed::Stop { p95_ms } => format!("⏳ LATENCY: back to {:.0} ms (p95). Full audio path restored.", p95_ms),
+        }
+    }
+}
+
+/// What /telemetry shows.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LatencyStats {
+    pub samples: usize,
+    pub p50_ms: f32,
+    pub p95_ms: f32,
+    pub max_ms: f32,
+    /// Median of each hop.
+    pub capture_ms: f32,
+    pub transit_ms: f32,
+    pub wait_ms: f32,
+    pub budget_ms: f32,
+    pub shedding: bool,
+    pub sheds: u64,
+}
+
+pub struct LatencyMonitor {
+    config: LatencyConfig,
+    window: VecDeque<Hops>,
+    since_us: Option<u64>, // When the condition that flips the state began
+    shedding: bool,
+    sheds: u64,
+}
+
+fn percentile(sorted: &[u64], q: f32) -> u64 {
+    sorted.get(((sorted.len().max(1) - 1) as f32 * q).round() as usize).copied().unwrap_or(0)
+}
+
+fn ms(us: u64) -> f32 {
+    us as f32 / 1000.0
+}
+
+impl LatencyMonitor {
+    pub fn new(config: LatencyConfig) -> Self {
+        Self { config, window: VecDeque::new(), since_us: None, shedding: false, sheds: 0 }
+    }
+
+    /// A spectrum was injected at `injected_us`. Some when shedding starts or stops.
+    pub fn record(&mut self, spec: &AudioSpectrum, injected_us: u64) -> Option<Shed> {
+        let hops = Hops::of(spec, injected_us)?;
+        if self.window.len() >= self.config.window.max(1) {
+            self.window.pop_front();
+        }
+        self.window.push_back(hops);
+        if !self.config.shed {
+            return None;
+        }
+
+        let p95_ms = ms(self.p95_us());
+        let budget_ms = self.config.budget_ms;
+        let flipping = if self.shedding { p95_ms < budget_ms * self.config.recover_ratio } else { p95_ms > budget_ms };
+        if !flipping {
+            self.since_us = None;
+            return None;
+        }
+        let since = *self.since_us.get_or_insert(injected_us);
+        if ms(injected_us.saturating_sub(since)) < self.config.persist_secs * 1000.0 {
+            return None;
+        }
+        self.since_us = None;
+        self.shedding = !self.shedding;
+        if self.shedding {
+            self.sheds += 1;
+            Some(Shed::Start { p95_ms, budget_ms })
+        } else {
+            Some(Shed::Stop { p95_ms })
+        }
+    }
+
+    fn p95_us(&self) -> u64 {
+        let mut totals: Vec<u64> = self.window.iter().map(Hops::total_us).collect();
+        totals.sort_unstable();
+        percentile(&totals, 0.95)
+    }
+
+    /// Exposure tracking paused and the cochlea striding.
+    pub fn shedding(&self) -> bool {
+        self.shedding
+    }
+
+    /// The most recent spectrum's hops.
+    pub fn last(&self) -> Option<Hops> {
+        self.window.back().copied()
+    }
+
+    pub fn stats(&self) -> LatencyStats {
+        let sorted = |f: fn(&Hops) -> u64| {
+            let mut v: Vec<u64> = self.window.iter().map(f).collect();
+            v.sort_unstable();
+            v
+        };
+        let totals = sorted(Hops::total_us);
+        LatencyStats {
+            samples: totals.len(),
+            p50_ms: ms(percentile(&totals, 0.5)),
+            p95_ms: ms(percentile(&totals, 0.95)),
+            max_ms: ms(totals.last().copied().unwrap_or(0)),
+            capture_ms: ms(percentile(&sorted(|h| h.capture_us), 0.5)),
+            transit_ms: ms(percentile(&sorted(|h| h.transit_us), 0.5)),
+            wait_ms: ms(percentile(&sorted(|h| h.wait_us), 0.5)),
+            budget_ms: self.config.budget_ms,
+            shedding: self.shedding,
+            sheds: self.sheds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::senses::cochlea::{CaptureClock, Cochlea};
+    use crate::senses::synthetic::Script;
+
+    const RATE: u32 = 16_000;
+    const CHUNK: usize = 256;
+    const HOP: usize = 128;
+
+    fn us(samples: usize) -> u64 {
+        samples as u64 * 1_000_000 / RATE as u64
+    }
+
+    /// Silence, a one-sample impulse, silence.
+    fn impulse() -> Vec<f32> {
+        let script = Script::parse("rate 16000; silence 1s; impulse 0.9; silence 500ms").unwrap();
+        assert!(script.to_dsl().contains("impulse 0.9"), "dsl '{}'", script.to_dsl());
+        script.render().unwrap()
+    }
+
+    #[test]
+    fn the_impulse_frame_measures_the_injected_hops() {
+        // The script goes through the cochlea in real-time chunks on a virtual clock,
+        // and each frame through the three hops with injected delays
+        let samples = impulse();
+        assert_eq!(samples.len(), RATE as usize * 3 / 2 + 1);
+        let impulse_us = us(RATE as usize + 1); // Sample 16000 arrives when it has been captured
+        for (capture_ms, transit_ms, wait_ms) in [(2u64, 5u64, 8u64), (1, 40, 30), (0, 0, 0), (15, 120, 250)] {
+            let (d_capture, d_transit, d_wait) = (capture_ms * 1000, transit_ms * 1000, wait_ms * 1000);
+            let mut cochlea = Cochlea::new(RATE, 1024, HOP);
+            let mut clock = CaptureClock::default();
+            let mut monitor = LatencyMonitor::new(LatencyConfig::default());
+            let mut marker = None;
+            for (i, chunk) in samples.chunks(CHUNK).enumerate() {
+                let arrival_us = us(i * CHUNK + chunk.len());
+                for frame in cochlea.push(chunk) {
+                    let captured_us = clock.stamp(arrival_us, frame.lag_samples, RATE);
+                    let sent_us = arrival_us + d_capture;
+                    let spec = AudioSpectrum { rms: frame.rms, captured_us, sent_us, received_us: sent_us + d_transit, ..Default::default() };
+                    monitor.record(&spec, spec.received_us + d_wait);
+                    if marker.is_none() && frame.rms > 0.01 {
+                        marker = Some((captured_us, arrival_us, monitor.last()));
+                    }
+                }
+            }
+            let label = format!("delays {}/{}/{} ms", capture_ms, transit_ms, wait_ms);
+            let Some((captured_us, arrival_us, Some(hops))) = marker else { panic!("{}: no frame heard the impulse", label) };
+            // Stamped within one hop of the impulse
+            assert!(captured_us >= impulse_us && captured_us < impulse_us + us(HOP),
+                "{}: impulse at {} µs stamped {} µs", label, impulse_us, captured_us);
+            // Capture also holds the wait for the rest of its chunk
+            let buffered = arrival_us - captured_us;
+            assert!(buffered <= us(CHUNK), "{}: {} µs buffered", label, buffered);
+            assert_eq!(hops, Hops { capture_us: buffered + d_capture, transit_us: d_transit, wait_us: d_wait }, "{}", label);
+        }
+    }
+
+    #[test]
+    fn only_a_sustained_delay_sheds_load_and_it_recovers() {
+        // A stream at 100 spectra/s: a short spike, then a sustained one, then recovery
+        let config = LatencyConfig { budget_ms: 150.0, persist_secs: 3.0, recover_ratio: 0.7, window: 100, ..LatencyConfig::default() };
+        let mut monitor = LatencyMonitor::new(config);
+        let mut t = 1_000_000u64;
+        let mut sheds = Vec::new();
+        let mut stream = |monitor: &mut LatencyMonitor, secs: f32, delay_ms: u64, sheds: &mut Vec<(u64, Shed)>| {
+            for _ in 0..(secs * 100.0) as usize {
+                t += 10_000;
+                let spec = AudioSpectrum { captured_us: t, sent_us: t + 1000, received_us: t + 2000, ..Default::default() };
+                sheds.extend(monitor.record(&spec, t + delay_ms * 1000).map(|s| (t, s)));
+            }
+            t
+        };
+        stream(&mut monitor, 5.0, 50, &mut sheds);
+        stream(&mut monitor, 0.3, 400, &mut sheds);
+        let calm = stream(&mut monitor, 5.0, 50, &mut sheds);
+        assert!(sheds.is_empty(), "a 0.3s spike shed load: {:?}", sheds);
+
+        let slow = stream(&mut monitor, 8.0, 400, &mut sheds);
+        let stats = monitor.stats();
+        stream(&mut monitor, 10.0, 50, &mut sheds);
+        let [(on, Shed::Start { .. }), (off, Shed::Stop { .. })] = sheds.as_slice() else {
+            panic!("sustained 400 ms: {:?} (expected one start, one stop)", sheds)
+        };
+        let after = |at: u64, from: u64| at.saturating_sub(from) as f32 / 1e6;
+        // persist_secs 3 + a few frames; then the window + persist_secs
+        assert!((3.0..3.5).contains(&after(*on, calm)), "shedding started {:.2}s into the slow stretch", after(*on, calm));
+        assert!((3.0..5.0).contains(&after(*off, slow)), "shedding stopped {:.2}s after recovery", after(*off, slow));
+        assert!((stats.p95_ms - 400.0).abs() <= 1.0, "{:?}", stats);
+        assert!((stats.wait_ms - 398.0).abs() <= 1.0, "{:?}", stats);
+        assert!(stats.shedding);
+        assert_eq!(stats.sheds, 1);
+    }
+
+    #[test]
+    fn the_stride_halves_the_ffts() {
+        let samples = impulse();
+        let stride = LatencyConfig::default().fft_stride;
+        let frames = |stride: usize| {
+            let mut cochlea = Cochlea::new(RATE, 1024, HOP);
+            cochlea.set_stride(stride);
+            samples.chunks(CHUNK).map(|c| cochlea.push(c).len()).sum::<usize>()
+        };
+        let (full, strided) = (frames(1), frames(stride));
+        assert!(strided > 0);
+        assert!(full.abs_diff(strided * stride) <= stride, "stride {}: {} frames (full rate {})", stride, strided, full);
+    }
+
+    #[test]
+    fn stamps_survive_json_and_a_spectrum_from_before_them_measures_from_capture() {
+        let spec = AudioSpectrum { captured_us: 10, sent_us: 20, received_us: 30, ..Default::default() };
+        let back: AudioSpectrum = serde_json::from_str(&serde_json::to_string(&spec).unwrap()).unwrap();
+        assert_eq!((back.sent_us, back.received_us), (20, 30));
+        let old = AudioSpectrum { captured_us: 10, ..Default::default() };
+        assert_eq!(Hops::of(&old, 50), Some(Hops { capture_us: 0, transit_us: 0, wait_us: 40 }));
+        assert_eq!(Hops::of(&AudioSpectrum::default(), 50), None);
+    }
+}
@@ -0,0 +1,440 @@
+// src/core/ledger.rs
+// THE LEDGER: What happened to the brain, for whoever studies it later
+//
+// The timeline keeps everything ALEPH thought, in a shape made for paging
+// through a day. Research on emergence wants the opposite: only the moments
+// that change the brain, one compact line each, across weeks, readable without
+// standing up Prometheus. The ledger is an always-on bus subscriber that
+// appends to events.ndjson (schema v1, one object per line, fields in this order):
+//
+//   {"v":1,"wall_ms":<unix millis>,"mono_us":<pacing clock>,"kind":<kind>,"detail":{...}}
+//
+//   epiphany       pathways, cause, dopamine   (dopamine at the trigger)
+//   trauma         state, escalating, cortisol_avg
+//   sleep_stage    from, to
+//   neurogenesis   grown, total
+//   apoptosis      pruned
+//
+// Detail keys are exactly those (sorted; null if the event lacked one), so new
+// fields on the bus never change a ledger line. mono_us restarts with the
+// process; wall_ms doesn't. The writer thread flushes at most once per second
+// and rotates at rotate_bytes (events.ndjson.1 is the newest old one, `keep`
+// of them). The loop only publishes: a slow disk delays the ledger, never a tick.
+//
+// `aleph events summarize` folds ledgers into per-day counts (UTC) and the
+// intervals between events of each kind, as text, JSON or OpenMetrics.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::RecvTimeoutError;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::core::bus::{Bus, Event};
+use crate::core::config::LedgerConfig;
+use crate::core::ipc::{EventKind, Landmark};
+
+pub const SCHEMA_VERSION: u32 = 1;
+/// The writer flushes at most this often.
+const FLUSH_EVERY: Duration = Duration::from_secs(1);
+
+/// The ledger's name for a kind and the detail keys it keeps; None = not recorded.
+pub fn schema(kind: EventKind) -> Option<(&'static str, &'static [&'static str])> {
+    match kind {
+        EventKind::Epiphany => Some(("epiphany", &["cause", "dopamine", "pathways"])),
+        EventKind::TraumaTransition => Some(("trauma", &["cortisol_avg", "escalating", "state"])),
+        EventKind::SleepStage => Some(("sleep_stage", &["from", "to"])),
+        EventKind::Neurogenesis => Some(("neurogenesis", &["grown", "total"])),
+        EventKind::Apoptosis => Some(("apoptosis", &["pruned"])),
+        EventKind::GoalCompleted | EventKind::ComponentRestart | EventKind::ManualOverride => None,
+    }
+}
+
+#[derive(Serialize)]
+struct Line<'a> {
+    v: u32,
+    wall_ms: u64,
+    mono_us: u64,
+    kind: &'a str,
+    detail: BTreeMap<&'a str, &'a serde_json::Value>,
+}
+
+/// The ledger line of an event (no newline), None for kinds it doesn't record.
+pub fn line(landmark: &Landmark) -> Option<String> {
+    let (kind, keys) = schema(landmark.kind)?;
+    let detail = keys.iter().map(|&key| (key, landmark.detail.get(key).unwrap_or(&serde_json::Value::Null))).collect();
+    serde_json::to_string(&Line {
+        v: SCHEMA_VERSION,
+        wall_ms: landmark.timestamp,
+        mono_us: landmark.monotonic_us,
+        kind,
+        detail,
+    }).ok()
+}
+
+/// Subscribe to the bus and start the writer (None when disabled). The thread ends,
+/// flushed, once every publisher is gone.
+pub fn attach(bus: &Bus, config: LedgerConfig) -> Option<JoinHandle<()>> {
+    if !config.enabled {
+        return None;
+    }
+    let rx = bus.subscribe();
+    let mut writer = Writer::new(config);
+    Some(thread::spawn(move || {
+        loop {
+            match rx.recv_timeout(FLUSH_EVERY) {
+                Ok(Event::Landmark(landmark)) => {
+                    if let Some(line) = line(&landmark) {
+                        writer.append(&line);
+                    }
+                },
+                Ok(_) => {},
+                Err(RecvTimeoutError::Timeout) => {},
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            writer.flush_if_due();
+        }
+        writer.flush();
+    }))
+}
+
+struct Writer {
+    path: PathBuf,
+    rotate_bytes: u64,
+    keep: usize,
+    file: Option<BufWriter<File>>,
+    size: u64,
+    dirty: bool,
+    last_flush: Instant,
+    failed: bool, // Say it once, keep trying on the next event
+}
+
+impl Writer {
+    fn new(config: LedgerConfig) -> Self {
+        Self {
+            path: PathBuf::from(config.path),
+            rotate_bytes: config.rotate_bytes.max(1),
+            keep: config.keep,
+            file: None,
+            size: 0,
+            dirty: false,
+            last_flush: Instant::now(),
+            failed: false,
+        }
+    }
+
+    fn append(&mut self, line: &str) {
+        match self.try_append(line) {
+            Ok(()) => self.failed = false,
+            Err(e) => {
+                if !self.failed {
+                    println!("⚠️ LEDGER: {} unavailable ({}). Events are not being recorded.", self.path.display(), e);
+                }
+                self.failed = true;
+                self.file = None;
+            },
+        }
+    }
+
+    fn try_append(&mut self, line: &str) -> io::Result<()> {
+        if self.file.is_none() {
+            if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+                fs::create_dir_all(dir)?;
+            }
+            let file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+            self.size = file.metadata()?.len();
+            self.file = Some(BufWriter::with_capacity(64 * 1024, file));
+        }
+        if self.size > 0 && self.size + line.len() as u64 + 1 > self.rotate_bytes {
+            self.rotate()?;
+            return self.try_append(line);
+        }
+        if let Some(file) = self.file.as_mut() {
+            file.write_all(line.as_bytes())?;
+            file.write_all(b"\n")?;
+            self.size += line.len() as u64 + 1;
+            self.dirty = true;
+        }
+        Ok(())
+    }
+
+    /// events.ndjson -> .1 -> .2 ... -> .keep (dropped beyond).
+    fn rotate(&mut self) -> io::Result<()> {
+        self.flush();
+        self.file = None;
+        if self.keep == 0 {
+            return fs::remove_file(&self.path);
+        }
+        let _ = fs::remove_file(rotated(&self.path, self.keep));
+        for n in (1..self.keep).rev() {
+            let _ = fs::rename(rotated(&self.path, n), rotated(&self.path, n + 1));
+        }
+        fs::rename(&self.path, rotated(&self.path, 1))
+    }
+
+    fn flush_if_due(&mut self) {
+        if self.dirty && self.last_flush.elapsed() >= FLUSH_EVERY {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Some(file) = self.file.as_mut() {
+            let _ = file.flush();
+        }
+        self.dirty = false;
+        self.last_flush = Instant::now();
+    }
+}
+
+fn rotated(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+/// The ledger and its rotated files that exist, oldest first.
+pub fn files(path: &str, keep: usize) -> Vec<PathBuf> {
+    let path = PathBuf::from(path);
+    let mut all: Vec<PathBuf> = (1..=keep).rev().map(|n| rotated(&path, n)).collect();
+    all.push(path);
+    all.retain(|p| p.exists());
+    all
+}
+
+// --- aleph events summarize ---
+
+/// Time between consecutive events of one kind (seconds).
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Intervals {
+    pub count: usize,
+    pub min: f64,
+    pub p50: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub max: f64,
+}
+
+impl Intervals {
+    fn of(mut gaps: Vec<f64>) -> Self {
+        if gaps.is_empty() {
+            return Self::default();
+        }
+        gaps.sort_by(f64::total_cmp);
+        let at = |q: f64| gaps[((gaps.len() - 1) as f64 * q).round() as usize];
+        Self {
+            count: gaps.len(),
+            min: gaps[0],
+            p50: at(0.5),
+            mean: gaps.iter().sum::<f64>() / gaps.len() as f64,
+            p95: at(0.95),
+            max: gaps[gaps.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Summary {
+    pub events: usize,
+    pub skipped: usize,                                 // Lines that aren't ledger v1
+    pub days: BTreeMap<String, BTreeMap<String, usize>>, // UTC day -> kind -> count
+    pub intervals: BTreeMap<String, Intervals>,         // kind -> gaps between its events
+}
+
+/// Fold ledger lines (in file order) into a summary. Gaps use the monotonic clock
+/// while it runs forward and the wall clock across a restart.
+pub fn summarize<I: IntoIterator<Item = String>>(lines: I) -> Summary {
+    let mut summary = Summary::default();
+    let mut last: BTreeMap<String, (u64, u64)> = BTreeMap::new(); // kind -> (wall_ms, mono_us)
+    let mut gaps: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+    for line in lines {
+        let value: serde_json::Value = match serde_json::from_str(&line) {
+            Ok(value) => value,
+            Err(_) => {
+                summary.skipped += usize::from(!line.trim().is_empty());
+                continue;
+            },
+        };
+        let (true, Some(kind), Some(wall), Some(mono)) = (
+            value["v"] == SCHEMA_VERSION,
+            value["kind"].as_str(),
+            value["wall_ms"].as_u64(),
+            value["mono_us"].as_u64(),
+        ) else {
+            summary.skipped += 1;
+            continue;
+        };
+        summary.events += 1;
+        let day = chrono::DateTime::from_timestamp_millis(wall as i64)
+            .map(|t| t.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        *summary.days.entry(day).or_default().entry(kind.to_string()).or_default() += 1;
+        if let Some((last_wall, last_mono)) = last.insert(kind.to_string(), (wall, mono)) {
+            let gap = if mono > last_mono {
+                (mono - last_mono) as f64 / 1e6
+            } else {
+                wall.saturating_sub(last_wall) as f64 / 1e3 // Restarted: the monotonic clock began again
+            };
+            gaps.entry(kind.to_string()).or_default().push(gap);
+        }
+    }
+    summary.intervals = gaps.into_iter().map(|(kind, g)| (kind, Intervals::of(g))).collect();
+    summary
+}
+
+/// Summarize ledger files (read in the order given).
+pub fn summarize_files(paths: &[PathBuf]) -> io::Result<Summary> {
+    let mut lines = Vec::new();
+    for path in paths {
+        for line in BufReader::new(File::open(path)?).lines() {
+            lines.push(line?);
+        }
+    }
+    Ok(summarize(lines))
+}
+
+impl Summary {
+    pub fn to_text(&self) -> String {
+        let mut out = format!("📒 {} events ({} lines skipped)\n", self.events, self.skipped);
+        for (day, kinds) in &self.days {
+            let counts: Vec<String> = kinds.iter().map(|(kind, n)| format!("{} {}", kind, n)).collect();
+            out.push_str(&format!("  {}  {}\n", day, counts.join(", ")));
+        }
+        for (kind, i) in &self.intervals {
+            out.push_str(&format!("  {:<13} every p50 {:.1}s (min {:.1}s, mean {:.1}s, p95 {:.1}s, max {:.1}s; {} gaps)\n",
+                kind, i.p50, i.min, i.mean, i.p95, i.max, i.count));
+        }
+        out
+    }
+
+    /// OpenMetrics text exposition: event counts per kind and day, interval summaries per kind.
+    pub fn to_openmetrics(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# TYPE aleph_ledger_events counter\n");
+        out.push_str("# HELP aleph_ledger_events Ledger events per kind and UTC day.\n");
+        for (day, kinds) in &self.days {
+            for (kind, n) in kinds {
+                out.push_str(&format!("aleph_ledger_events_total{{kind=\"{}\",day=\"{}\"}} {}\n", kind, day, n));
+            }
+        }
+        out.push_str("# TYPE aleph_ledger_interval_seconds summary\n");
+        out.push_str("# UNIT aleph_ledger_interval_seconds seconds\n");
+        out.push_str("# HELP aleph_ledger_interval_seconds Time between consecutive events of a kind.\n");
+        for (kind, i) in &self.intervals {
+            for (q, v) in [("0.5", i.p50), ("0.95", i.p95)] {
+                out.push_str(&format!("aleph_ledger_interval_seconds{{kind=\"{}\",quantile=\"{}\"}} {}\n", kind, q, v));
+            }
+            out.push_str(&format!("aleph_ledger_interval_seconds_sum{{kind=\"{}\"}} {}\n", kind, i.mean * i.count as f64));
+            out.push_str(&format!("aleph_ledger_interval_seconds_count{{kind=\"{}\"}} {}\n", kind, i.count));
+        }
+        out.push_str("# EOF\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn landmark(kind: EventKind, timestamp: u64, monotonic_us: u64, detail: serde_json::Value) -> Landmark {
+        Landmark { kind, timestamp, monotonic_us, detail }
+    }
+
+    #[test]
+    fn golden_lines_keep_the_v1_schema() {
+        // These lines are what researchers' parsers expect
+        let golden = [
+            (landmark(EventKind::Epiphany, 1760000000000, 1_500_000, json!({ "pathways": 14, "cause": "dopamine", "dopamine": 0.95 })),
+                Some(r#"{"v":1,"wall_ms":1760000000000,"mono_us":1500000,"kind":"epiphany","detail":{"cause":"dopamine","dopamine":0.95,"pathways":14}}"#)),
+            (landmark(EventKind::TraumaTransition, 1760000001000, 2_500_000, json!({ "state": "FIREFIGHTER", "escalating": true, "cortisol_avg": 0.8, "since": 12 })),
+                Some(r#"{"v":1,"wall_ms":1760000001000,"mono_us":2500000,"kind":"trauma","detail":{"cortisol_avg":0.8,"escalating":true,"state":"FIREFIGHTER"}}"#)),
+            (landmark(EventKind::SleepStage, 1760000002000, 3_500_000, json!({ "from": "Awake", "to": "Rem" })),
+                Some(r#"{"v":1,"wall_ms":1760000002000,"mono_us":3500000,"kind":"sleep_stage","detail":{"from":"Awake","to":"Rem"}}"#)),
+            (landmark(EventKind::Neurogenesis, 1760000003000, 4_500_000, json!({ "grown": 3, "total": 503 })),
+                Some(r#"{"v":1,"wall_ms":1760000003000,"mono_us":4500000,"kind":"neurogenesis","detail":{"grown":3,"total":503}}"#)),
+            (landmark(EventKind::Apoptosis, 1760000004000, 5_500_000, json!({})),
+                Some(r#"{"v":1,"wall_ms":1760000004000,"mono_us":5500000,"kind":"apoptosis","detail":{"pruned":null}}"#)),
+            (landmark(EventKind::GoalCompleted, 1760000005000, 6_500_000, json!({ "reward": 0.5 })), None),
+            (landmark(EventKind::ManualOverride, 1760000006000, 7_500_000, json!({ "command": "sleep" })), None),
+        ];
+        for (event, expected) in &golden {
+            assert_eq!(line(event).as_deref(), *expected, "{:?}", event.kind);
+        }
+    }
+
+    #[test]
+    fn a_burst_never_waits_for_the_disk_and_rotation_loses_nothing_kept() {
+        const BURST: u64 = 20_000;
+        let dir = std::env::temp_dir().join(format!("aleph-ledger-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("events.ndjson").to_string_lossy().to_string();
+        let config = LedgerConfig { enabled: true, path: path.clone(), rotate_bytes: 64 * 1024, keep: 2 };
+        let bus = Bus::new();
+        let writer = attach(&bus, config.clone()).expect("enabled ledger did not attach");
+        let start = Instant::now();
+        let mut slowest = Duration::ZERO;
+        for i in 0..BURST {
+            let publish = Instant::now();
+            bus.publish(Event::Landmark(landmark(EventKind::Neurogenesis, 1760000000000 + i, i * 1000, json!({ "grown": i, "total": 500 }))));
+            slowest = slowest.max(publish.elapsed());
+        }
+        let burst = start.elapsed();
+        drop(bus); // The writer drains, flushes and ends
+        assert!(writer.join().is_ok(), "the writer panicked");
+        assert!(burst <= Duration::from_millis(500), "{:?} to publish {}", burst, BURST);
+        assert!(slowest <= Duration::from_millis(20), "slowest publish {:?}", slowest);
+
+        // Rotation keeps `keep` old files, none oversized
+        let kept = files(&path, config.keep);
+        assert_eq!(kept.len(), 3);
+        assert!(!rotated(Path::new(&path), 3).exists());
+        for p in &kept {
+            assert!(fs::metadata(p).unwrap().len() <= config.rotate_bytes, "{} is oversized", p.display());
+        }
+        let grown: Vec<u64> = kept.iter()
+            .flat_map(|p| fs::read_to_string(p).unwrap().lines().map(String::from).collect::<Vec<_>>())
+            .filter_map(|l| serde_json::from_str::<serde_json::Value>(&l).ok()?["detail"]["grown"].as_u64())
+            .collect();
+        assert!(grown.len() >= 1000, "only {} lines kept", grown.len());
+        assert_eq!(grown.last(), Some(&(BURST - 1)));
+        assert!(grown.windows(2).all(|w| w[1] == w[0] + 1), "kept lines are not contiguous");
+        let summary = summarize_files(&kept).unwrap();
+        assert_eq!((summary.events, summary.skipped), (grown.len(), 0));
+
+        assert!(attach(&Bus::new(), LedgerConfig { enabled: false, ..config }).is_none(), "disabled ledger attached");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn summaries_count_per_day_and_gap_across_a_restart() {
+        let midnight: u64 = 1768435200000; // 2026-01-15T00:00:00Z
+        let lines: Vec<String> = [
+            landmark(EventKind::Epiphany, midnight - 10_000, 10_000_000, json!({})),
+            landmark(EventKind::Apoptosis, midnight - 5_000, 15_000_000, json!({ "pruned": 4 })),
+            landmark(EventKind::Epiphany, midnight + 10_000, 30_000_000, json!({})), // 20s later
+            landmark(EventKind::Epiphany, midnight + 90_000, 1_000_000, json!({})),  // Restarted, 80s later
+        ].iter().filter_map(line)
+            .chain(["not json".to_string(), r#"{"v":2,"wall_ms":1,"mono_us":1,"kind":"epiphany"}"#.to_string(), String::new()])
+            .collect();
+        let summary = summarize(lines);
+        assert_eq!((summary.events, summary.skipped), (4, 2));
+        assert_eq!(summary.days["2026-01-14"]["epiphany"], 1);
+        assert_eq!(summary.days["2026-01-14"]["apoptosis"], 1);
+        assert_eq!(summary.days["2026-01-15"]["epiphany"], 2);
+
+        // Gaps on the monotonic clock, the wall clock across the restart; one apoptosis has none
+        let gaps = &summary.intervals["epiphany"];
+        assert_eq!((gaps.count, gaps.min, gaps.max, gaps.mean), (2, 20.0, 80.0, 50.0));
+        assert!(!summary.intervals.contains_key("apoptosis"));
+
+        let metrics = summary.to_openmetrics();
+        assert!(metrics.ends_with("# EOF\n"));
+        assert!(metrics.contains("aleph_ledger_events_total{kind=\"epiphany\",day=\"2026-01-15\"} 2\n"), "{}", metrics);
+        assert!(metrics.contains("aleph_ledger_interval_seconds_count{kind=\"epiphany\"} 2\n"), "{}", metrics);
+        assert!(summary.to_text().contains("2026-01-14  apoptosis 1, epiphany 1"), "{}", summary.to_text());
+    }
+}
@@ -0,0 +1,341 @@
+// src/core/lexicon.rs
+// THE LEXICON: What words feel like, as data
+//
+// `apply_semantic_perturbation` used to carry its vocabulary as hardcoded
+// lists matched with `contains` (so "no" fired inside "noche"). The words now
+// live in lexicon.toml (format documented there): loaded at boot, reloaded
+// when the file changes, matched per word after lowercasing and light
+// stemming, with prefix stems ("descubr*") and multi-word phrases.
+//
+// Words the lexicon doesn't know aren't silent anymore: the first time one is
+// heard it adds a little arousal (dopamine), repeats add nothing.
+// A missing or broken file falls back to the starter lexicon compiled in.
+
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::time::{Duration, Instant, SystemTime};
+
+const LEXICON_FILE: &str = "lexicon.toml";
+const BUILTIN: &str = include_str!("../../lexicon.toml");
+/// How often the file's mtime is checked.
+const RELOAD_CHECK: Duration = Duration::from_secs(3);
+/// Bounded memory of unknown words already heard (novelty fades).
+const MAX_SEEN: usize = 5000;
+
+/// Chemical deltas of one term.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct Feeling {
+    pub cortisol: f32,
+    pub oxytocin: f32,
+    pub dopamine: f32,
+    pub adenosine: f32,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct Intensity {
+    strong: Vec<String>,
+    strong_factor: f32,
+    weak: Vec<String>,
+    weak_factor: f32,
+}
+
+impl Default for Intensity {
+    fn default() -> Self {
+        Self { strong: Vec::new(), strong_factor: 2.0, weak: Vec::new(), weak_factor: 0.5 }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct LexiconFile {
+    unknown_arousal: f32,
+    intensity: Intensity,
+    terms: HashMap<String, Feeling>,
+}
+
+/// What one utterance did, before it touches the chemistry.
+#[derive(Debug, Default)]
+pub struct Reading {
+    pub feeling: Feeling,     // Sum of matched terms, already scaled by intensity
+    pub intensity: f32,
+    pub stress_hits: usize,   // Terms that raise cortisol
+    pub calm_hits: usize,     // Terms that raise oxytocin
+    pub novel_words: usize,   // Unknown words heard for the first time
+}
+
+pub struct Lexicon {
+    unknown_arousal: f32,
+    intensity: Intensity,
+    words: HashMap<String, Feeling>,         // Stemmed single words
+    stems: Vec<(String, Feeling)>,           // "descubr*" prefixes
+    phrases: Vec<(Vec<String>, Feeling)>,    // Multi-word terms, stemmed word by word
+    seen: HashSet<String>,
+    loaded_mtime: Option<SystemTime>,
+    last_check: Instant,
+}
+
+impl Lexicon {
+    /// lexicon.toml if present and valid, the built-in starter lexicon otherwise.
+    pub fn load() -> Self {
+        let mut lexicon = Self::builtin();
+        match lexicon.reload_from_disk() {
+            Ok(true) => println!("📖 LEXICON: {} terms loaded from {}.", lexicon.term_count(), LEXICON_FILE),
+            Ok(false) => println!("📖 LEXICON: {} not found. Using the built-in starter lexicon.", LEXICON_FILE),
+            Err(e) => println!("⚠️ LEXICON: {} is invalid ({}). Using the built-in starter lexicon.", LEXICON_FILE, e),
+        }
+        lexicon
+    }
+
+    /// The starter lexicon compiled into the binary (reproducible: ignores the file on disk).
+    pub fn builtin() -> Self {
+        Self::parse(BUILTIN).expect("built-in lexicon.toml is valid")
+    }
+
+    fn parse(source: &str) -> Result<Self> {
+        let file: LexiconFile = toml::from_str(source)?;
+        let mut words = HashMap::new();
+        let mut stems = Vec::new();
+        let mut phrases = Vec::new();
+        for (term, feeling) in file.terms {
+            let term = term.trim().to_lowercase();
+            if let Some(prefix) = term.strip_suffix('*') {
+                stems.push((prefix.to_string(), feeling));
+            } else if term.contains(char::is_whitespace) {
+                phrases.push((tokenize(&term).iter().map(|w| stem(w)).collect(), feeling));
+            } else {
+                words.insert(stem(&term), feeling);
+            }
+        }
+        Ok(Self {
+            unknown_arousal: file.unknown_arousal,
+            intensity: file.intensity,
+            words,
+            stems,
+            phrases,
+            seen: HashSet::new(),
+            loaded_mtime: None,
+            last_check: Instant::now(),
+        })
+    }
+
+    /// Ok(false) if there is no file. Keeps the "already heard" memory across reloads.
+    fn reload_from_disk(&mut self) -> Result<bool> {
+        let Ok(meta) = fs::metadata(LEXICON_FILE) else { return Ok(false) };
+        let mut fresh = Self::parse(&fs::read_to_string(LEXICON_FILE)?)?;
+        fresh.seen = std::mem::take(&mut self.seen);
+        fresh.loaded_mtime = meta.modified().ok();
+        *self = fresh;
+        Ok(true)
+    }
+
+    /// Hot reload: cheap mtime check every few seconds, called before each use.
+    pub fn refresh(&mut self) -> Option<String> {
+        if self.last_check.elapsed() < RELOAD_CHECK {
+            return None;
+        }
+        self.last_check = Instant::now();
+        let mtime = fs::metadata(LEXICON_FILE).and_then(|m| m.modified()).ok()?;
+        if Some(mtime) == self.loaded_mtime {
+            return None;
+        }
+        self.loaded_mtime = Some(mtime); // Don't retry a broken file until it changes again
+        Some(match self.reload_from_disk() {
+            Ok(_) => format!("📖 LEXICON: Reloaded ({} terms).", self.term_count()),
+            Err(e) => format!("⚠️ LEXICON: Reload failed, keeping the previous one ({}).", e),
+        })
+    }
+
+    /// Reload now, changed or not (`aleph ctl lexicon`).
+    pub fn reload(&mut self) -> String {
+        self.last_check = Instant::now();
+        self.loaded_mtime = fs::metadata(LEXICON_FILE).and_then(|m| m.modified()).ok();
+        match self.reload_from_disk() {
+            Ok(true) => format!("reloaded from {}", LEXICON_FILE),
+            Ok(false) => format!("{} not found, keeping the current one", LEXICON_FILE),
+            Err(e) => format!("reload failed, keeping the current one ({})", e),
+        }
+    }
+
+    pub fn term_count(&self) -> usize {
+        self.words.len() + self.stems.len() + self.phrases.len()
+    }
+
+    /// The threatening lexicon: words and stems that raise cortisol, as matched
+    /// (stemmed), sorted. Phrases are left out: their words mean little alone.
+    pub fn threat_terms(&self) -> Vec<String> {
+        let mut terms: Vec<String> = self.words.iter()
+            .chain(self.stems.iter().map(|(p, f)| (p, f)))
+            .filter(|(_, feeling)| feeling.cortisol > 0.0)
+            .map(|(term, _)| term.clone())
+            .collect();
+        terms.sort_unstable();
+        terms.dedup();
+        terms
+    }
+
+    /// Match an utterance against the lexicon. Every term counts once.
+    pub fn read(&mut self, text: &str) -> Reading {
+        let lower = text.to_lowercase();
+        let tokens = tokenize(&lower);
+        let stemmed: Vec<String> = tokens.iter().map(|w| stem(w)).collect();
+
+        let marks = |list: &[String]| list.iter().any(|m| {
+            if m.chars().any(char::is_alphanumeric) {
+                let m = tokenize(m).iter().map(|w| stem(w)).collect::<Vec<_>>();
+                !m.is_empty() && stemmed.windows(m.len()).any(|w| w == m.as_slice())
+            } else {
+                lower.contains(m.as_str()) // Punctuation marks ("!!")
+            }
+        });
+        let intensity = if marks(&self.intensity.strong) {
+            self.intensity.strong_factor
+        } else if marks(&self.intensity.weak) {
+            self.intensity.weak_factor
+        } else {
+            1.0
+        };
+
+        let mut matched: Vec<Feeling> = Vec::new();
+        let mut known = vec![false; tokens.len()];
+        let mut seen_words: HashSet<&str> = HashSet::new();
+        for (i, (token, st)) in tokens.iter().zip(&stemmed).enumerate() {
+            if let Some(feeling) = self.words.get(st) {
+                known[i] = true;
+                if seen_words.insert(st.as_str()) {
+                    matched.push(*feeling);
+                }
+            }
+            for (prefix, feeling) in &self.stems {
+                if token.starts_with(prefix.as_str()) {
+                    known[i] = true;
+                    if seen_words.insert(prefix.as_str()) {
+                        matched.push(*feeling);
+                    }
+                }
+            }
+        }
+        for (phrase, feeling) in &self.phrases {
+            if phrase.is_empty() {
+                continue;
+            }
+            if let Some(start) = stemmed.windows(phrase.len()).position(|w| w == phrase.as_slice()) {
+                known[start..start + phrase.len()].iter_mut().for_each(|k| *k = true);
+                matched.push(*feeling);
+            }
+        }
+
+        let mut reading = Reading { intensity, ..Default::default() };
+        for f in &matched {
+            reading.feeling.cortisol += f.cortisol * intensity;
+            reading.feeling.oxytocin += f.oxytocin * intensity;
+            reading.feeling.dopamine += f.dopamine * intensity;
+            reading.feeling.adenosine += f.adenosine * intensity;
+            if f.cortisol > 0.0 { reading.stress_hits += 1; }
+            if f.oxytocin > 0.0 { reading.calm_hits += 1; }
+        }
+
+        // Unknown words: a small novelty arousal, only the first time
+        for (i, st) in stemmed.iter().enumerate() {
+            if known[i] || st.chars().count() < 3 || self.seen.contains(st) {
+                continue;
+            }
+            if self.seen.len() >= MAX_SEEN {
+                self.seen.clear(); // Forgetting makes old words new again; fine
+            }
+            self.seen.insert(st.clone());
+            reading.novel_words += 1;
+        }
+        reading.feeling.dopamine += reading.novel_words as f32 * self.unknown_arousal;
+        reading
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// Light, language-agnostic stemming, one suffix per pass: adverb/verb endings,
+/// then plural "s", then a final gender/theme vowel ("amigos" -> "amigo" -> "amig",
+/// "noches" -> "noche" -> "noch", "calmed" -> "calm"). Roots keep >= 3 chars.
+fn stem(word: &str) -> String {
+    const PASSES: [&[&str]; 3] = [&["mente", "ing", "ed"], &["s"], &["a", "e", "o"]];
+    let mut word = word;
+    for suffixes in PASSES {
+        if let Some(root) = suffixes.iter().find_map(|s| word.strip_suffix(s)) {
+            if root.chars().count() >= 3 {
+                word = root;
+            }
+        }
+    }
+    word.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::chemistry::Neurotransmitters;
+
+    const CONFIGURED: &str = r#"
+        unknown_arousal = 0.02
+        [intensity]
+        strong = ["muy", "!!"]
+        strong_factor = 2.0
+        [terms]
+        "tarugo" = { cortisol = 0.3 }
+        "buenas noches" = { oxytocin = 0.1 }
+        "descubr*" = { dopamine = 0.2 }
+    "#;
+
+    fn calm() -> Neurotransmitters {
+        Neurotransmitters { cortisol: 0.2, dopamine: 0.3, oxytocin: 0.2, adenosine: 0.2, ..Neurotransmitters::new() }
+    }
+
+    #[test]
+    fn a_configured_insult_raises_cortisol_and_a_neutral_word_does_not() {
+        let mut lexicon = Lexicon::parse(CONFIGURED).unwrap();
+        let mut chem = calm();
+        chem.apply_semantic_perturbation("eres un tarugo", &mut lexicon);
+        assert!((chem.cortisol - 0.5).abs() < 1e-6, "cortisol {}", chem.cortisol);
+
+        let mut chem = calm();
+        chem.apply_semantic_perturbation("mesa", &mut lexicon);
+        assert_eq!(chem.cortisol, 0.2);
+        assert!((chem.dopamine - 0.32).abs() < 1e-6, "an unknown word arouses a little, once");
+        chem.apply_semantic_perturbation("mesa", &mut lexicon);
+        assert!((chem.dopamine - 0.32).abs() < 1e-6);
+    }
+
+    #[test]
+    fn stems_phrases_and_intensity() {
+        let mut lexicon = Lexicon::parse(CONFIGURED).unwrap();
+        assert!((lexicon.read("tarugos").feeling.cortisol - 0.3).abs() < 1e-6, "plural of a word");
+        assert!((lexicon.read("¡¡muy tarugo!!").feeling.cortisol - 0.6).abs() < 1e-6, "strong intensity doubles");
+        assert!((lexicon.read("descubrimiento").feeling.dopamine - 0.2).abs() < 1e-6, "prefix stem");
+        assert!((lexicon.read("Buenas Noches, Aleph").feeling.oxytocin - 0.1).abs() < 1e-6, "phrase");
+        assert_eq!(lexicon.read("noches buenas").feeling.oxytocin, 0.0, "a phrase is in order");
+        assert_eq!(lexicon.read("tarugo tarugo tarugo").stress_hits, 1, "every term counts once");
+    }
+
+    #[test]
+    fn builtin_starter_lexicon_speaks_both_languages() {
+        let mut lexicon = Lexicon::builtin();
+        assert!(lexicon.read("tengo miedo").feeling.cortisol > 0.0);
+        assert!(lexicon.read("I want to kill").feeling.cortisol > 0.0);
+        assert!(lexicon.read("gracias").feeling.oxytocin > 0.0);
+        // "no" is a word, not a substring: "noche" doesn't trigger it
+        assert_eq!(lexicon.read("noche").stress_hits, 0);
+        assert!(lexicon.threat_terms().contains(&"kill".to_string()));
+    }
+
+    #[test]
+    fn a_broken_file_does_not_parse() {
+        assert!(Lexicon::parse("[terms]\n\"odio\" = 3").is_err());
+    }
+}
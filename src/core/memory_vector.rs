@@ -9,6 +9,99 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::core::config::RetrievalConfig;
+use crate::core::thought::MindVoice;
+
+/// Where a memory came from: retrieval can prefer the outside world over self-talk.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum Provenance {
+    Heard { speaker: Option<String> }, // Ears (speaker if the voice was recognized)
+    Read { path: String },             // A document
+    Seen,                              // Vision captioning
+    Told { client_id: String },        // Web / IPC stimulus
+    SelfSpeech,                        // ALEPH's own words
+    Dream,                             // Generated asleep
+    Genesis,                           // Seeded at birth
+    #[default]
+    Unknown, // Stored before provenance existed
+}
+
+impl Provenance {
+    /// A memorized thought: dreams are dreams, anything else ALEPH said to itself.
+    pub fn of_voice(voice: MindVoice) -> Self {
+        match voice {
+            MindVoice::Dream => Provenance::Dream,
+            _ => Provenance::SelfSpeech,
+        }
+    }
+
+    /// Retrieval weight of this source (0 = never retrieved).
+    pub fn weight(&self, config: &RetrievalConfig) -> f32 {
+        match self {
+            Provenance::SelfSpeech => config.self_speech_weight,
+            Provenance::Dream => config.dream_weight,
+            _ => 1.0,
+        }
+    }
+
+    /// "heard (Ana)", "dream", ... (retrieval context, inspection)
+    pub fn describe(&self) -> String {
+        match self {
+            Provenance::Heard { speaker: Some(speaker) } => format!("heard ({})", speaker),
+            Provenance::Heard { speaker: None } => "heard".to_string(),
+            Provenance::Read { path } => format!("read ({})", path),
+            Provenance::Seen => "seen".to_string(),
+            Provenance::Told { client_id } => format!("told ({})", client_id),
+            Provenance::SelfSpeech => "self".to_string(),
+            Provenance::Dream => "dream".to_string(),
+            Provenance::Genesis => "genesis".to_string(),
+            Provenance::Unknown => "unknown".to_string(),
+        }
+    }
+
+    /// The serialized tag ("heard", "read", "self_speech", ...): what a filter names.
+    pub fn source(&self) -> &'static str {
+        match self {
+            Provenance::Heard { .. } => "heard",
+            Provenance::Read { .. } => "read",
+            Provenance::Seen => "seen",
+            Provenance::Told { .. } => "told",
+            Provenance::SelfSpeech => "self_speech",
+            Provenance::Dream => "dream",
+            Provenance::Genesis => "genesis",
+            Provenance::Unknown => "unknown",
+        }
+    }
+
+    /// Who said it, when the ears knew the voice.
+    pub fn speaker(&self) -> Option<&str> {
+        match self {
+            Provenance::Heard { speaker } => speaker.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Optional restrictions on a memory query (None = any). Times are the records'
+/// Unix seconds, `since` inclusive and `until` exclusive.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryFilter {
+    pub speaker: Option<String>,
+    pub since: Option<u64>,
+    pub until: Option<u64>,
+    pub source: Option<String>, // Provenance::source()
+}
+
+impl MemoryFilter {
+    pub fn admits(&self, memory: &MemoryRecord) -> bool {
+        self.speaker.as_deref().is_none_or(|s| memory.provenance.speaker().is_some_and(|m| m.eq_ignore_ascii_case(s)))
+            && self.since.is_none_or(|t| memory.timestamp >= t)
+            && self.until.is_none_or(|t| memory.timestamp < t)
+            && self.source.as_deref().is_none_or(|s| memory.provenance.source() == s)
+    }
+}
+
 // --- ESTRUCTURA DEL RECUERDO ---
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MemoryRecord {
@@ -20,6 +113,101 @@ pub struct MemoryRecord {
     pub entropy: f32, // Intensity/Importance
     #[serde(default)]
     pub consolidated: bool, // True = Long Term (Disk), False = Volatile (RAM)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub activity: Vec<f32>, // Reservoir activity signature at encoding (replayed in Deep sleep)
+    #[serde(default)]
+    pub provenance: Provenance, // Old stores load as Unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utterance: Option<u64>, // Sentences heard in one transcript share it
+}
+
+/// One consolidation round over the volatile memories.
+#[derive(Debug, Default)]
+pub struct ConsolidationBatch {
+    pub examined: usize,
+    pub kept: usize,      // Became long term
+    pub forgotten: usize, // Pruned
+    pub dreams: Vec<(String, Vec<f32>)>, // Kept and SUPER INTENSE (0.85+): replayed
+}
+
+/// Criterio: Entropía > 0.7 (Alta intensidad) OR Identity-related
+fn worth_keeping(m: &MemoryRecord) -> bool {
+    let text = m.text.to_lowercase();
+    let is_identity = ["name", "call me", "who am i", "identity", "aleph"].iter().any(|k| text.contains(k));
+    m.entropy > 0.7 || is_identity
+}
+
+/// Consolidate (keep + mark, or prune) the oldest `max` volatile memories; consolidated
+/// ones and volatile ones past the batch are left untouched.
+pub fn consolidate_batch(memories: &mut Vec<MemoryRecord>, max: usize) -> ConsolidationBatch {
+    let mut batch = ConsolidationBatch::default();
+    memories.retain_mut(|m| {
+        if m.consolidated || batch.examined >= max {
+            return true;
+        }
+        batch.examined += 1;
+        if worth_keeping(m) {
+            if m.entropy > 0.85 {
+                batch.dreams.push((m.text.clone(), m.embedding.clone()));
+            }
+            m.consolidated = true;
+            batch.kept += 1;
+            true
+        } else {
+            batch.forgotten += 1;
+            false // Prune (Forget weak memories)
+        }
+    });
+    batch
+}
+
+/// The most relevant memory for `query`: cosine similarity times its source's weight
+/// (zero-weight sources are skipped). None below `min_relevance`.
+pub fn best_match(memories: &[MemoryRecord], query: &[f32], config: &RetrievalConfig) -> Option<(usize, f32)> {
+    memories.iter().enumerate()
+        .filter_map(|(i, mem)| {
+            let weight = mem.provenance.weight(config);
+            if weight <= 0.0 {
+                return None;
+            }
+            let cosine_sim: f32 = mem.embedding.iter().zip(query).map(|(a, b)| a * b).sum();
+            Some((i, cosine_sim * weight))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .filter(|(_, score)| *score > config.min_relevance)
+}
+
+/// Indices of the memories heard in the same transcript as `idx`, in the order they
+/// were stored (just `idx` if it has no utterance id or its siblings were pruned).
+pub fn siblings(memories: &[MemoryRecord], idx: usize) -> Vec<usize> {
+    match memories.get(idx).map(|m| m.utterance) {
+        Some(Some(id)) => memories.iter().enumerate().filter(|(_, m)| m.utterance == Some(id)).map(|(i, _)| i).collect(),
+        Some(None) => vec![idx],
+        None => Vec::new(),
+    }
+}
+
+/// The whole turn a recalled sentence belongs to (its siblings joined in order).
+pub fn recall_utterance(memories: &[MemoryRecord], idx: usize) -> String {
+    siblings(memories, idx).iter().map(|&i| memories[i].text.as_str()).collect::<Vec<_>>().join(" ")
+}
+
+/// The `top_k` most recent turns `filter` admits, newest first: one index per
+/// utterance (its last admitted sentence; recall_utterance gives the whole turn).
+pub fn latest(memories: &[MemoryRecord], filter: &MemoryFilter, top_k: usize) -> Vec<usize> {
+    let mut turns: Vec<usize> = Vec::new();
+    let mut order: Vec<usize> = (0..memories.len()).filter(|&i| filter.admits(&memories[i])).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse((memories[i].timestamp, i)));
+    for i in order {
+        if turns.len() >= top_k {
+            break;
+        }
+        let same_turn = |&j: &usize| memories[i].utterance.is_some() && memories[j].utterance == memories[i].utterance;
+        if !turns.iter().any(same_turn) {
+            turns.push(i);
+        }
+    }
+    turns
 }
 
 // --- VECTOR STORE (Base de Datos) ---
@@ -94,7 +282,7 @@ impl VectorStore {
     /// Guarda un recuerdo nuevo (RAM ONLY - Volatile)
     /// Guarda un recuerdo nuevo (RAM ONLY - Volatile)
     #[allow(dead_code)]
-    pub fn add(&mut self, text: String, tags: Vec<String>, entropy: f32) -> Result<()> {
+    pub fn add(&mut self, text: String, tags: Vec<String>, entropy: f32, provenance: Provenance) -> Result<()> {
         let embedding = self.embed(&text)?;
         let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
         
@@ -105,6 +293,9 @@ impl VectorStore {
             context_tags: tags,
             entropy,
             consolidated: false,
+            activity: Vec::new(),
+            provenance,
+            utterance: None,
         };
         
         self.memories.push(record);
@@ -113,7 +304,7 @@ impl VectorStore {
     }
 
     /// Optimized add: Allows passing an already computed embedding
-    pub fn add_precalculated(&mut self, text: String, embedding: Vec<f32>, tags: Vec<String>, entropy: f32) -> Result<()> {
+    pub fn add_precalculated(&mut self, text: String, embedding: Vec<f32>, tags: Vec<String>, entropy: f32, activity: Vec<f32>, provenance: Provenance) -> Result<()> {
         let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
         let record = MemoryRecord {
             text,
@@ -122,6 +313,9 @@ impl VectorStore {
             context_tags: tags,
             entropy,
             consolidated: false,
+            activity,
+            provenance,
+            utterance: None,
         };
         self.memories.push(record);
         Ok(())
@@ -174,44 +368,14 @@ impl VectorStore {
         Ok(max_sim)
     }
 
-    /// Sueño: Poda memorias irrelevantes y guarda en disco las importantes
-    /// Returns: (pruned_count, dreams_to_replay)
-    pub fn consolidate_memories(&mut self) -> Result<(usize, Vec<(String, Vec<f32>)>)> {
-        let initial_count = self.memories.len();
-        let mut dreams = Vec::new(); // Dreams to replay (High Entropy)
-
-        // Criterio de Consolidación: Entropía > 0.7 OR Reciente (< 1 min)? No, Sleep Cycle clears day.
-        // User rule: "Solo los recuerdos asociados a estados de alta intensidad (Entropía > 0.7) se guardarán"
-        
-        // Criterio: Entropía > 0.7 (Alta intensidad) OR Identity-related
-        self.memories.retain(|m| {
-            let is_identity = m.text.to_lowercase().contains("name") 
-                           || m.text.to_lowercase().contains("call me")
-                           || m.text.to_lowercase().contains("who am i")
-                           || m.text.to_lowercase().contains("identity")
-                           || m.text.to_lowercase().contains("aleph");
-
-            if m.entropy > 0.7 || is_identity {
-                 // Keep (will be saved)
-                 // Dream Replay: Only SUPER INTENSE memories (0.85+) or randomly some 0.7+
-                 if m.entropy > 0.85 {
-                     dreams.push((m.text.clone(), m.embedding.clone()));
-                 }
-                 true
-            } else {
-                false // Prune (Forget weak memories)
-            }
-        });
-        
-        // Mark all remaining as consolidated
-        for mem in &mut self.memories {
-            mem.consolidated = true;
+    /// Sueño: Poda memorias irrelevantes y guarda en disco las importantes, a lo sumo
+    /// `max` volátiles por ronda (las más viejas primero; el resto espera la siguiente).
+    pub fn consolidate_batch(&mut self, max: usize) -> Result<ConsolidationBatch> {
+        let batch = consolidate_batch(&mut self.memories, max);
+        if batch.examined > 0 {
+            self.save_to_disk()?;
         }
-        
-        let final_count = self.memories.len();
-        self.save_to_disk()?; 
-        
-        Ok((initial_count - final_count, dreams)) 
+        Ok(batch)
     }
 
     pub fn volatile_count(&self) -> usize {
@@ -219,8 +383,12 @@ impl VectorStore {
     }
 
     fn save_to_disk(&self) -> Result<()> {
+        // Write-then-rename: a crash mid-save must not truncate memories.json
         let json = serde_json::to_string(&self.memories)?;
-        fs::write(&self.file_path, json)?;
+        let tmp = format!("{}.tmp", self.file_path);
+        fs::write(&tmp, json)?;
+        fs::File::open(&tmp)?.sync_all()?;
+        fs::rename(&tmp, &self.file_path)?;
         Ok(())
     }
 
@@ -278,3 +446,97 @@ impl VectorStore {
         (centroid, variance)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A pre-provenance, pre-utterance record.
+    const OLD_RECORD: &str = r#"{"text":"hola","embedding":[1.0],"timestamp":0,"context_tags":[],"entropy":0.5,"consolidated":true}"#;
+
+    fn engram(text: &str, embedding: Vec<f32>, provenance: Provenance) -> MemoryRecord {
+        MemoryRecord {
+            text: text.to_string(),
+            embedding,
+            timestamp: 0,
+            context_tags: Vec::new(),
+            entropy: 0.5,
+            consolidated: true,
+            activity: Vec::new(),
+            provenance,
+            utterance: None,
+        }
+    }
+
+    /// A unit vector at cosine `sim` from [1, 0].
+    fn at(sim: f32) -> Vec<f32> {
+        vec![sim, (1.0 - sim * sim).sqrt()]
+    }
+
+    #[test]
+    fn what_was_heard_outranks_dreams_and_self_speech() {
+        // The query is closer to the Dream (1.0) and the SelfSpeech (0.9) than to the Heard one (0.7)
+        let query = vec![1.0f32, 0.0];
+        let memories = vec![
+            engram("soñé con el mar", at(1.0), Provenance::Dream),
+            engram("el mar, el mar", at(0.9), Provenance::SelfSpeech),
+            engram("hoy fuimos al mar", at(0.7), Provenance::Heard { speaker: Some("Ana".to_string()) }),
+        ];
+        let min_relevance = 0.4;
+        let runs = [
+            ("default weights", RetrievalConfig { min_relevance, ..RetrievalConfig::default() }, Some(2)),
+            ("dreams excluded", RetrievalConfig { dream_weight: 0.0, self_speech_weight: 0.5, min_relevance }, Some(2)),
+            ("all sources equal", RetrievalConfig { dream_weight: 1.0, self_speech_weight: 1.0, min_relevance }, Some(0)),
+            ("nothing relevant enough", RetrievalConfig { dream_weight: 0.0, self_speech_weight: 0.0, min_relevance: 0.8 }, None),
+        ];
+        for (name, weights, expected) in runs {
+            let got = best_match(&memories, &query, &weights).map(|(i, _)| i);
+            assert_eq!(got, expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn provenance_survives_json_and_old_records_are_unknown() {
+        let record: MemoryRecord = serde_json::from_str(OLD_RECORD).unwrap();
+        assert_eq!(record.provenance, Provenance::Unknown);
+        let heard = engram("hoy fuimos al mar", at(0.7), Provenance::Heard { speaker: Some("Ana".to_string()) });
+        let back: MemoryRecord = serde_json::from_str(&serde_json::to_string(&heard).unwrap()).unwrap();
+        assert_eq!(back.provenance, heard.provenance);
+    }
+
+    #[test]
+    fn a_recalled_sentence_brings_back_its_utterance() {
+        // Two transcripts split into sentences (ids 7 and 9) around an unrelated memory
+        let heard = |text: &str, sim: f32, utterance: Option<u64>| MemoryRecord {
+            utterance,
+            ..engram(text, at(sim), Provenance::Heard { speaker: None })
+        };
+        let memories = vec![
+            heard("Hola.", 0.1, Some(7)),
+            heard("¿Te acuerdas del mar?", 0.95, Some(7)),
+            heard("el perro ladra", 0.2, None),
+            heard("Fuimos en verano.", 0.3, Some(7)),
+            heard("Mañana llueve.", 0.5, Some(9)),
+            heard("Trae paraguas.", 0.4, Some(9)),
+        ];
+        let config = RetrievalConfig { min_relevance: 0.4, ..RetrievalConfig::default() };
+        let runs = [
+            ("the sea", at(0.95), "Hola. ¿Te acuerdas del mar? Fuimos en verano."),
+            ("the rain", at(0.5), "Mañana llueve. Trae paraguas."),
+            ("the dog", at(0.2), "el perro ladra"),
+        ];
+        for (name, query, expected) in runs {
+            let got = best_match(&memories, &query, &config).map(|(i, _)| recall_utterance(&memories, i));
+            assert_eq!(got.as_deref(), Some(expected), "{}", name);
+        }
+        assert_eq!(siblings(&memories, 4), vec![4, 5]);
+        assert_eq!(siblings(&memories, 2), vec![2], "a memory without an utterance id");
+        assert!(siblings(&memories, 99).is_empty(), "an index out of range found siblings");
+
+        // The id survives JSON; old records load without one
+        let record: MemoryRecord = serde_json::from_str(OLD_RECORD).unwrap();
+        assert_eq!(record.utterance, None);
+        let back: MemoryRecord = serde_json::from_str(&serde_json::to_string(&memories[1]).unwrap()).unwrap();
+        assert_eq!(back.utterance, Some(7));
+    }
+}
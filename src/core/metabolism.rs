@@ -0,0 +1,193 @@
+// src/core/metabolism.rs
+// THE METABOLISM: Work costs energy, all of it
+//
+// Components report raw work (Whisper ms, Planet ms, reservoir tick ms, neurons
+// born, consolidation ms) into a shared ledger from whatever thread they run on.
+// Once per second the daemon settles the ledger against the chemistry: adenosine
+// rises and glucose falls by the configured coefficients, and the settled window
+// is kept as the dashboard's "energy budget". One auditable place instead of ad-hoc
+// `latency_sec > 0.5` adjustments scattered through the loop.
+
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::core::chemistry::Neurotransmitters;
+use crate::core::config::MetabolismConfig;
+
+/// Work reported since the last settlement.
+#[derive(Debug, Clone, Copy, Default)]
+struct WorkLedger {
+    whisper_ms: u64,
+    cortex_ms: u64, // Billable (free allowance already subtracted)
+    tick_ms: f64,   // Sub-millisecond ticks add up
+    growth_events: u32,
+    effort_ms: u64, // Deliberate actions (exploration), billed like Planet time
+    consolidation_ms: u64, // Hippocampal consolidation rounds, billed like Whisper time
+}
+
+/// One settled window, as shown on the dashboard.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct EnergyBudget {
+    pub whisper_ms: u64,
+    pub cortex_ms: u64,
+    pub tick_ms: f32,
+    pub growth_events: u32,
+    pub effort_ms: u64,
+    pub consolidation_ms: u64,
+    pub adenosine_delta: f32,
+    pub glucose_delta: f32,
+}
+
+/// Cloneable handle: every component gets one, the daemon settles.
+#[derive(Clone)]
+pub struct Metabolism {
+    ledger: Arc<Mutex<WorkLedger>>,
+    config: MetabolismConfig,
+    last_settle: Arc<Mutex<Instant>>,
+}
+
+impl Metabolism {
+    pub fn new(config: MetabolismConfig) -> Self {
+        Self {
+            ledger: Arc::new(Mutex::new(WorkLedger::default())),
+            config,
+            last_settle: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    pub fn report_whisper(&self, elapsed: Duration) {
+        self.ledger.lock().unwrap().whisper_ms += elapsed.as_millis() as u64;
+    }
+
+    /// One Planet generation. Only the part above `cortex_free_ms` is billed.
+    pub fn report_cortex(&self, latency_ms: u64) {
+        self.ledger.lock().unwrap().cortex_ms += latency_ms.saturating_sub(self.config.cortex_free_ms);
+    }
+
+    pub fn report_tick(&self, elapsed: Duration) {
+        self.ledger.lock().unwrap().tick_ms += elapsed.as_secs_f64() * 1000.0;
+    }
+
+    pub fn report_growth(&self, neurons: u32) {
+        self.ledger.lock().unwrap().growth_events += neurons;
+    }
+
+    /// A self-initiated action (exploration). No free allowance: it was chosen.
+    pub fn report_effort(&self, effort: Duration) {
+        self.ledger.lock().unwrap().effort_ms += effort.as_millis() as u64;
+    }
+
+    /// One consolidation round (background work, like listening).
+    pub fn report_consolidation(&self, elapsed: Duration) {
+        self.ledger.lock().unwrap().consolidation_ms += elapsed.as_millis() as u64;
+    }
+
+    /// Apply the ledger to the chemistry if a second has passed. Returns the settled window.
+    pub fn settle(&self, chem: &mut Neurotransmitters, is_dreaming: bool) -> Option<EnergyBudget> {
+        let mut last = self.last_settle.lock().unwrap();
+        let window = last.elapsed();
+        if window < Duration::from_secs(1) {
+            return None;
+        }
+        *last = Instant::now();
+        let work = std::mem::take(&mut *self.ledger.lock().unwrap());
+        Some(self.apply(work, window.as_secs_f32(), chem, is_dreaming))
+    }
+
+    fn apply(&self, work: WorkLedger, window_secs: f32, chem: &mut Neurotransmitters, is_dreaming: bool) -> EnergyBudget {
+        let c = &self.config;
+        let whisper_sec = (work.whisper_ms + work.consolidation_ms) as f32 / 1000.0;
+        let cortex_sec = work.cortex_ms as f32 / 1000.0;
+        let tick_sec = (work.tick_ms / 1000.0) as f32;
+        let effort_sec = work.effort_ms as f32 / 1000.0;
+
+        let adenosine_delta = (cortex_sec + effort_sec) * c.cortex_adenosine_per_sec
+            + whisper_sec * c.whisper_adenosine_per_sec
+            + tick_sec * c.tick_adenosine_per_sec
+            + work.growth_events as f32 * c.growth_adenosine;
+
+        let recovery = c.glucose_recovery_per_sec * window_secs * if is_dreaming { 2.0 } else { 1.0 };
+        let glucose_delta = recovery
+            - (cortex_sec + whisper_sec + tick_sec + effort_sec) * c.glucose_per_work_sec
+            - work.growth_events as f32 * c.glucose_per_growth;
+
+        chem.adenosine = (chem.adenosine + adenosine_delta).clamp(0.0, 1.0);
+        chem.glucose = (chem.glucose + glucose_delta).clamp(0.0, 1.0);
+
+        EnergyBudget {
+            whisper_ms: work.whisper_ms,
+            cortex_ms: work.cortex_ms,
+            tick_ms: work.tick_ms as f32,
+            growth_events: work.growth_events,
+            effort_ms: work.effort_ms,
+            consolidation_ms: work.consolidation_ms,
+            adenosine_delta,
+            glucose_delta,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Round numbers, so the expected fatigue reads straight off the coefficients.
+    fn config() -> MetabolismConfig {
+        MetabolismConfig {
+            cortex_free_ms: 500,
+            cortex_adenosine_per_sec: 0.1,
+            whisper_adenosine_per_sec: 0.05,
+            tick_adenosine_per_sec: 0.01,
+            growth_adenosine: 0.002,
+            glucose_per_work_sec: 0.02,
+            glucose_per_growth: 0.005,
+            glucose_recovery_per_sec: 0.004,
+        }
+    }
+
+    fn settle_now(metabolism: &Metabolism, chem: &mut Neurotransmitters, is_dreaming: bool) -> EnergyBudget {
+        let work = std::mem::take(&mut *metabolism.ledger.lock().unwrap());
+        metabolism.apply(work, 1.0, chem, is_dreaming)
+    }
+
+    #[test]
+    fn fatigue_follows_the_coefficients() {
+        let metabolism = Metabolism::new(config());
+        let mut chem = Neurotransmitters { adenosine: 0.2, glucose: 0.8, ..Neurotransmitters::new() };
+
+        metabolism.report_whisper(Duration::from_millis(2000)); // 2s listening
+        metabolism.report_cortex(2500);                         // 2s billable, 0.5s free
+        metabolism.report_cortex(300);                          // All free
+        for _ in 0..100 {
+            metabolism.report_tick(Duration::from_millis(5));   // 0.5s ticking
+        }
+        metabolism.report_growth(3);
+        let budget = settle_now(&metabolism, &mut chem, false);
+
+        let adenosine = 2.0 * 0.1 + 2.0 * 0.05 + 0.5 * 0.01 + 3.0 * 0.002;
+        let glucose = 0.004 - (2.0 + 2.0 + 0.5) * 0.02 - 3.0 * 0.005;
+        assert_eq!(budget.cortex_ms, 2000);
+        assert!((budget.adenosine_delta - adenosine).abs() < 1e-5, "{} vs {}", budget.adenosine_delta, adenosine);
+        assert!((budget.glucose_delta - glucose).abs() < 1e-5, "{} vs {}", budget.glucose_delta, glucose);
+        assert!((chem.adenosine - (0.2 + adenosine)).abs() < 1e-5);
+        assert!((chem.glucose - (0.8 + glucose)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn settled_work_is_not_billed_twice_and_sleep_recovers() {
+        let metabolism = Metabolism::new(config());
+        let mut chem = Neurotransmitters { adenosine: 0.5, glucose: 0.5, ..Neurotransmitters::new() };
+        metabolism.report_effort(Duration::from_secs(1));
+        metabolism.report_consolidation(Duration::from_secs(1));
+        let first = settle_now(&metabolism, &mut chem, false);
+        assert!((first.adenosine_delta - (0.1 + 0.05)).abs() < 1e-5);
+
+        let idle = settle_now(&metabolism, &mut chem, true);
+        assert_eq!(idle.adenosine_delta, 0.0);
+        assert!((idle.glucose_delta - 2.0 * 0.004).abs() < 1e-6, "recovery doubles while dreaming");
+
+        // And settle() itself waits for the second to pass
+        assert!(metabolism.settle(&mut chem, false).is_none());
+    }
+}
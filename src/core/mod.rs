@@ -7,7 +7,6 @@ pub mod neocortex;
 // pub mod gemma_probe;
 pub mod reservoir;
 pub mod thought;
-// pub mod planet; // Moved to cortex
 pub mod memory_vector;
 pub mod inner_voice;
 pub mod daemon; // THE STAR
@@ -18,3 +17,51 @@ pub mod materializer; // THE EIGEN-SOUL
 pub mod gate;
 pub mod field; // THE SEMANTIC FIELD (RAG as Probability Deformation)
 pub mod trauma; // THE LUCIFER PROTOCOL (Defensive Psychology)
+pub mod config; // THE CONSTITUTION (aleph.toml)
+pub mod stimulus; // THE SKIN (Stimulus Rate Limiting & Sanitization)
+pub mod websocket; // THE SYNAPSE (RFC 6455 framing)
+pub mod web; // THE FRONT DOOR (HTTP API & WebSocket Sessions)
+pub mod broadcaster; // THE TOWN CRIER (WebSocket Telemetry at 12Hz)
+pub mod bus; // THE BLOODSTREAM (Typed Event Bus)
+pub mod daemon_state; // THE HEARTBEAT (Daemon State Machine)
+pub mod timeline; // THE CHRONICLE (Thought Timeline & Session Log)
+pub mod metabolism; // THE METABOLISM (Energy Accounting)
+pub mod autosave; // THE VAULT (Crash-Safe Checkpoints)
+pub mod people; // THE FACES (Name Learning)
+pub mod raster; // THE SEISMOGRAPH (Activity Raster)
+pub mod brain_diff; // THE ARCHAEOLOGIST (Snapshot Diff)
+pub mod attention; // THE SPOTLIGHT (Stress-Narrowed Input)
+pub mod lexicon; // THE LEXICON (Words -> Chemistry)
+pub mod bonding; // THE BOND (Oxytocin Sources)
+pub mod exploration; // THE WANDERER (Boredom-Driven Exploration)
+pub mod homeostat; // THE THERMOSTAT (Entropy Setpoint Control)
+pub mod spectrogram; // THE WATERFALL (Audio Spectrum History)
+pub mod binding; // THE KNOT (Cross-Modal Binding)
+pub mod saccade; // THE GAZE (Saccade-Like Attention Shifts)
+pub mod novelty; // THE HABITUATION (Persistent Novelty Model)
+pub mod consolidation; // THE NIGHT SHIFT (Scheduled Memory Consolidation)
+pub mod sieve; // THE SIEVE (Self-Memory Storage Gate)
+pub mod textutil; // THE SCISSORS (Char-Safe Text Slicing)
+pub mod pacing; // THE METRONOME (Frame Time, Watchdog, Rate-Invariant Timers)
+pub mod sensory; // THE PATCH BAY (Named Sensory Channels)
+pub mod startle; // THE FLINCH (Auditory Startle, Refractory & Habituation)
+pub mod interoception; // THE PULSE (Felt Heartbeat & HRV)
+pub mod components; // THE CAPABILITY PANEL (Component Status & Degraded Modes)
+pub mod health; // THE STETHOSCOPE (/healthz, Memory Ping, Startup-Complete Line)
+pub mod profile; // THE SHELL (Data Dir, ALEPH_* Environment, Container Profile)
+pub mod stats; // THE DIARY (Hourly Buckets & Daily Summary)
+pub mod admin; // THE WORKBENCH (Admin Commands over IPC, aleph ctl)
+pub mod decisions; // THE MINUTES (Gate & Membrane Decision Logs)
+pub mod interlocutor; // THE VISITOR (Simulated Interlocutor for Soak Tests)
+pub mod pressure; // THE BELT (Memory-Pressure Degradation)
+pub mod selftest; // THE SOUNDCHECK (Startup Self-Test & Warm-Up)
+pub mod complexity; // THE CALIPERS (Multi-Scale Complexity Metrics)
+pub mod repertoire; // THE REPERTOIRE (Drives Behind Spontaneous Speech)
+pub mod checkin; // THE KNOCK (Checking In After Silence)
+pub mod feeding; // THE PANTRY (Feeding API)
+pub mod latency; // THE STOPWATCH (Audio -> Reservoir Latency Budget)
+pub mod thread; // THE THREAD (Conversation Memory per Speaker)
+pub mod volition; // THE WISH (Self-Modulation Requests)
+pub mod standby; // THE UNDERSTUDY (Warm Standby Brain)
+pub mod ledger; // THE LEDGER (Research Event Log)
+pub mod soak; // THE LONG NIGHT (Soak Test)
@@ -1,5 +1,11 @@
 use std::collections::VecDeque;
 
+/// Entropy this still (variance over the 10-tick window) counts as "nothing is happening".
+const BOREDOM_VARIANCE: f32 = 1e-4;
+/// Ticks of stillness before boredom is declared (~30s at 60Hz); it repeats while it lasts.
+/// Half of it with a rigid heart (low HRV, see core::interoception).
+pub const BOREDOM_TICKS: usize = 1800;
+
 /// Structural Observer - Planned for Fase 4 (Delta Sensitivity)
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -31,6 +37,8 @@ pub struct Neocortex {
     last_derivative: f32,
     trauma_counter: usize,   // Ticks in high entropy
     growth_cooldown: usize,  // Ticks until next growth allowed
+    flat_ticks: usize,       // Consecutive ticks of near-constant entropy
+    rigid: bool,             // Chronically low HRV: lock-in comes sooner
 }
 
 #[allow(dead_code)]
@@ -44,9 +52,16 @@ impl Neocortex {
             last_derivative: 0.0,
             trauma_counter: 0,
             growth_cooldown: 0,
+            flat_ticks: 0,
+            rigid: false,
         }
     }
 
+    /// Interoception: a heart that barely varies (see core::interoception).
+    pub fn set_rigid(&mut self, rigid: bool) {
+        self.rigid = rigid;
+    }
+
     pub fn observe(&mut self, current_entropy: f32) -> Option<CognitiveEvent> {
         // Cooldown tick
         if self.growth_cooldown > 0 {
@@ -93,7 +108,22 @@ impl Neocortex {
             return Some(CognitiveEvent::Trauma(current_entropy));
         }
 
-        // D. Stagnation / Boredom (Machine Zone)
+        // D. Boredom: entropy barely moving for too long (at any level)
+        let n = self.entropy_history.len() as f32;
+        let mean = self.entropy_history.iter().sum::<f32>() / n;
+        let variance = self.entropy_history.iter().map(|e| (e - mean).powi(2)).sum::<f32>() / n;
+        if variance < BOREDOM_VARIANCE {
+            self.flat_ticks += 1;
+        } else {
+            self.flat_ticks = 0;
+        }
+        let lock_in = if self.rigid { BOREDOM_TICKS / 2 } else { BOREDOM_TICKS };
+        if self.flat_ticks >= lock_in {
+            self.flat_ticks = 0;
+            return Some(CognitiveEvent::Boredom);
+        }
+
+        // E. Stagnation (Machine Zone)
         if current_entropy < 0.05 {
              return Some(CognitiveEvent::Stagnation);
         }
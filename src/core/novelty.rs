@@ -0,0 +1,200 @@
+// src/core/novelty.rs
+// THE HABITUATION: What counts as new, remembered across restarts
+//
+// Novelty used to be 1 - (best cosine similarity against every memory), judged
+// against nothing: MiniLM similarities between unrelated sentences sit around
+// 0.2-0.4, so a fresh deployment scored almost everything 0.6+ and flooded
+// dopamine for its first hour, and every restart started the flood again.
+//
+// The hippocampus now keeps a small model that survives restarts (novelty.json,
+// next to memories.json):
+//
+//   similarity distribution: running mean/variance of the best similarity of
+//       each stimulus (EMA, adaptation_rate per stimulus, starting from a prior)
+//   familiarity table: how many times each (normalized) text was heard
+//
+//   z       = (best_similarity - mean) / spread
+//   novelty = 0.5 * (1 - tanh(z)) / (1 + familiarity_rate * times_heard)
+//
+// A stimulus as similar to memory as usual scores 0.5; clearly closer -> 0,
+// clearly further -> 1; the tenth repetition of the same sentence is boring
+// whatever the embedding says.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+
+use crate::core::config::NoveltyConfig;
+use crate::core::textutil;
+
+pub const NOVELTY_FILE: &str = "novelty.json";
+/// Novelty scores kept for the "mean over the last stimuli" stat.
+pub const RECENT_SCORES: usize = 100;
+/// Familiarity entries kept (beyond this, texts heard only once are forgotten).
+const FAMILIAR_MAX: usize = 10_000;
+/// Floor of the spread, so a run of identical similarities can't make z explode.
+const MIN_SPREAD: f32 = 0.02;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoveltyModel {
+    pub similarity_mean: f32,
+    pub similarity_var: f32,
+    pub samples: u64,
+    familiarity: HashMap<String, u32>,
+    #[serde(skip)]
+    recent: VecDeque<f32>,
+    #[serde(skip)]
+    config: NoveltyConfig,
+}
+
+/// The habituation law: a response met `times` times before is divided by
+/// 1 + rate * times (texts here, repeated bangs in core::startle).
+pub fn habituated(response: f32, rate: f32, times: f32) -> f32 {
+    response / (1.0 + rate.max(0.0) * times.max(0.0))
+}
+
+/// Familiarity key: lowercase, whitespace collapsed, bounded length.
+fn key(text: &str) -> String {
+    textutil::truncate_chars(&text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase(), 80).to_string()
+}
+
+impl NoveltyModel {
+    /// A newborn model: the similarity distribution starts at the configured prior.
+    pub fn new(config: NoveltyConfig) -> Self {
+        Self {
+            similarity_mean: config.prior_similarity,
+            similarity_var: config.prior_spread.powi(2),
+            samples: 0,
+            familiarity: HashMap::new(),
+            recent: VecDeque::with_capacity(RECENT_SCORES),
+            config,
+        }
+    }
+
+    /// The model saved by the last session, or a newborn one.
+    pub fn load(config: NoveltyConfig) -> Self {
+        match fs::read_to_string(NOVELTY_FILE).ok().and_then(|json| Self::from_json(&json, config.clone()).ok()) {
+            Some(model) => {
+                println!("🧠 Novelty model loaded: {} stimuli seen, {} familiar texts.", model.samples, model.familiarity.len());
+                model
+            },
+            None => Self::new(config),
+        }
+    }
+
+    pub fn from_json(json: &str, config: NoveltyConfig) -> Result<Self> {
+        let mut model: Self = serde_json::from_str(json)?;
+        model.config = config;
+        Ok(model)
+    }
+
+    /// Write-then-rename, like memories.json.
+    pub fn save(&self) -> Result<()> {
+        let json = serde_json::to_string(self)?;
+        let tmp = format!("{}.tmp", NOVELTY_FILE);
+        fs::write(&tmp, json)?;
+        fs::File::open(&tmp)?.sync_all()?;
+        fs::rename(&tmp, NOVELTY_FILE)?;
+        Ok(())
+    }
+
+    /// Novelty of a stimulus whose best similarity against memory is `best_similarity`
+    /// (without learning from it).
+    pub fn peek(&self, text: &str, best_similarity: f32) -> f32 {
+        let spread = self.similarity_var.sqrt().max(MIN_SPREAD);
+        let z = (best_similarity - self.similarity_mean) / spread;
+        let heard = self.familiarity.get(&key(text)).copied().unwrap_or(0) as f32;
+        habituated(0.5 * (1.0 - z.tanh()), self.config.familiarity_rate, heard).clamp(0.0, 1.0)
+    }
+
+    /// Score, then learn: the distribution moves toward this similarity and the text
+    /// becomes a little more familiar.
+    pub fn score(&mut self, text: &str, best_similarity: f32) -> f32 {
+        let novelty = self.peek(text, best_similarity);
+
+        let rate = self.config.adaptation_rate.clamp(0.0, 1.0);
+        let d = best_similarity - self.similarity_mean;
+        self.similarity_mean += rate * d;
+        self.similarity_var += rate * (d * d - self.similarity_var);
+        self.samples += 1;
+
+        *self.familiarity.entry(key(text)).or_insert(0) += 1;
+        if self.familiarity.len() > FAMILIAR_MAX {
+            self.familiarity.retain(|_, heard| *heard > 1);
+        }
+
+        if self.recent.len() == RECENT_SCORES {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(novelty);
+        novelty
+    }
+
+    /// Mean novelty over the last RECENT_SCORES stimuli of this session (0 before any).
+    pub fn recent_mean(&self) -> f32 {
+        if self.recent.is_empty() { 0.0 } else { self.recent.iter().sum::<f32>() / self.recent.len() as f32 }
+    }
+
+    /// The raw novelty (1 - best similarity) that scores 0.5 right now: stimuli
+    /// further from memory than this count as new.
+    pub fn threshold(&self) -> f32 {
+        (1.0 - self.similarity_mean).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tiny deterministic generator (the tests must not depend on a model download).
+    fn next(state: &mut u64) -> f32 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        (*state >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// A model that has seen `stimuli` ordinary stimuli (best similarity 0.35 +- 0.1).
+    fn habituated(config: &NoveltyConfig, stimuli: usize) -> NoveltyModel {
+        let mut rng = 0x9E37_79B9_7F4A_7C15u64;
+        let mut model = NoveltyModel::new(config.clone());
+        for i in 0..stimuli {
+            let similarity = 0.35 + (next(&mut rng) - 0.5) * 0.2;
+            model.score(&format!("ordinary stimulus {}", i), similarity);
+        }
+        model
+    }
+
+    #[test]
+    fn the_usual_stops_being_new_but_outliers_still_are() {
+        let model = habituated(&NoveltyConfig::default(), 300);
+        assert!(model.recent_mean() <= 0.65, "after 300 ordinary stimuli the recent mean novelty is still {:.2}", model.recent_mean());
+        let outlier = model.peek("something never heard", 0.0);
+        let duplicate = model.peek("almost a repeat", 0.95);
+        assert!(outlier >= 0.8, "an unrelated stimulus (similarity 0.0) scores only {:.2}", outlier);
+        assert!(duplicate <= 0.2, "a near-duplicate (similarity 0.95) still scores {:.2}", duplicate);
+    }
+
+    #[test]
+    fn habituation_survives_a_round_trip() {
+        let config = NoveltyConfig::default();
+        let model = habituated(&config, 300);
+        let restored = NoveltyModel::from_json(&serde_json::to_string(&model).unwrap(), config).unwrap();
+        for (text, similarity) in [("ordinary stimulus 3", 0.3f32), ("new", 0.1), ("close", 0.7)] {
+            let (a, b) = (model.peek(text, similarity), restored.peek(text, similarity));
+            assert!((a - b).abs() <= 1e-6, "after a save/load round trip '{}' scores {:.4} instead of {:.4}", text, b, a);
+        }
+    }
+
+    #[test]
+    fn repeats_get_boring_faster_with_a_higher_familiarity_rate() {
+        let repeated = |rate: f32| {
+            let mut model = NoveltyModel::new(NoveltyConfig { familiarity_rate: rate, ..NoveltyConfig::default() });
+            (0..10).map(|_| model.score("hola aleph", model.similarity_mean)).collect::<Vec<f32>>()
+        };
+        let (slow, fast) = (repeated(0.1), repeated(1.0));
+        assert!(slow.windows(2).all(|w| w[1] < w[0]), "a repeated sentence does not keep getting less novel: {:?}", slow);
+        assert!(fast[9] < slow[9], "familiarity_rate 1.0 ends at {:.3}, not below 0.1's {:.3}", fast[9], slow[9]);
+    }
+}
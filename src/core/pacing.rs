@@ -0,0 +1,422 @@
+// src/core/pacing.rs
+// THE METRONOME: What "60Hz" actually was, and behavior that doesn't depend on it
+//
+// The main loop targets the metabolic heart rate (~60Hz), but while Whisper or
+// the Planet saturate the CPU it quietly became 7Hz, and everything written as
+// "every N ticks" (neurogenesis, epiphanies, the agency window, the idle cortex
+// pulse) slowed down with it. Three pieces:
+//
+//   rates:     `chance(rate, dt)` turns a per-second rate into this frame's
+//              probability (1 - e^(-rate*dt)), and `Every` fires once per period
+//              of wall time however many frames it took.
+//   FrameClock: how long each phase of the loop took (laps), over a window of
+//              recent frames: p50/p95/max work time and achieved vs target Hz.
+//   Watchdog:  a thread that watches the loop's pulse. N consecutive frames over
+//              budget (1 / target Hz) -> warning thought (and, optionally, the
+//              idle cortex pulses are shed until it recovers); no frame at all
+//              for stall_secs -> stall warning.
+//   Clock:     the daemon's monotonic microseconds since boot. Spectra are
+//              stamped with it at capture; clients get its origin (Unix millis)
+//              in the handshake and turn stamps into end-to-end latency.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::core::config::WatchdogConfig;
+use crate::core::thought::{MindVoice, Thought};
+use crate::core::timeline::unix_millis;
+
+/// Frames kept for the percentiles (~10s at 60Hz).
+pub const FRAME_WINDOW: usize = 600;
+/// How often the watchdog looks at the pulse.
+const WATCHDOG_POLL: Duration = Duration::from_millis(250);
+
+// --- Clock ---
+
+static CLOCK_ORIGIN: OnceLock<(Instant, u64)> = OnceLock::new();
+
+/// The instant the monotonic clock counts from, and its Unix millis (fixed on first use).
+pub fn clock_origin() -> (Instant, u64) {
+    *CLOCK_ORIGIN.get_or_init(|| (Instant::now(), unix_millis(SystemTime::now())))
+}
+
+/// Microseconds since the clock origin (never goes backwards).
+pub fn monotonic_us() -> u64 {
+    clock_origin().0.elapsed().as_micros() as u64
+}
+
+/// Count from another process's origin (the cortexd child shares its parent's, so
+/// the spectra it stamps mean the same instants). Only before the first reading.
+pub fn adopt_clock_origin(unix_ms: u64) -> bool {
+    let behind = Duration::from_millis(unix_millis(SystemTime::now()).saturating_sub(unix_ms));
+    Instant::now().checked_sub(behind).is_some_and(|origin| CLOCK_ORIGIN.set((origin, unix_ms)).is_ok())
+}
+
+// --- Rates ---
+
+/// Probability that an event with `rate_per_sec` happens within a frame of `dt` seconds.
+pub fn chance(rate_per_sec: f32, dt: f32) -> f32 {
+    -(-rate_per_sec.max(0.0) * dt.max(0.0)).exp_m1()
+}
+
+/// Fires once per `period` seconds of elapsed time, whatever the frame rate.
+pub struct Every {
+    period: f32,
+    elapsed: f32,
+}
+
+impl Every {
+    pub fn new(period_secs: f32) -> Self {
+        Self { period: period_secs.max(1e-3), elapsed: 0.0 }
+    }
+
+    /// Advance by `dt` seconds: how many periods completed (0 most frames; more
+    /// than 1 only when a single frame took longer than the period).
+    pub fn tick(&mut self, dt: f32) -> u32 {
+        self.elapsed += dt.max(0.0);
+        let fired = (self.elapsed / self.period).floor();
+        self.elapsed -= fired * self.period;
+        fired as u32
+    }
+}
+
+// --- Frame time ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Physics,      // A. chemistry, reservoir, plasticity
+    Input,        // B. stimuli, ears, memory feedback
+    Output,       // C. cortex output, satellite, gate, thought drain
+    Telemetry,    // Broadcast
+    Agency,       // Memory logs, E-G. idle pulse, agency, exploration
+    Housekeeping, // Lexicon, export, stats, autosave, idle bookkeeping
+}
+
+const PHASES: [Phase; 6] = [Phase::Physics, Phase::Input, Phase::Output, Phase::Telemetry, Phase::Agency, Phase::Housekeeping];
+
+impl Phase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Physics => "physics",
+            Phase::Input => "input",
+            Phase::Output => "output",
+            Phase::Telemetry => "telemetry",
+            Phase::Agency => "agency",
+            Phase::Housekeeping => "housekeeping",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Frame {
+    work_ms: f32,            // Begin -> end (the sleep is not work)
+    dt: f32,                 // Seconds since the previous frame began
+    phases: [f32; PHASES.len()],
+}
+
+/// Frame-time dashboard (telemetry).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FrameStats {
+    pub p50_ms: f32,
+    pub p95_ms: f32,
+    pub max_ms: f32,
+    pub budget_ms: f32,
+    pub target_hz: f32,
+    pub achieved_hz: f32,
+    pub overruns: usize,               // Frames over budget in the window
+    pub phases: BTreeMap<String, f32>, // Mean ms per phase over the window
+    pub shedding: bool,                // Idle pulses paused by the watchdog
+}
+
+#[derive(Default)]
+pub struct FrameClock {
+    window: VecDeque<Frame>,
+    current: Frame,
+    began: Option<Instant>,
+    lap: Option<Instant>,
+}
+
+impl FrameClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn begin(&mut self, now: Instant, dt: f32) {
+        self.current = Frame { dt, ..Default::default() };
+        self.began = Some(now);
+        self.lap = Some(now);
+    }
+
+    /// `phase` ended now (it began at the previous lap).
+    pub fn lap(&mut self, phase: Phase, now: Instant) {
+        let Some(i) = PHASES.iter().position(|&p| p == phase) else { return };
+        if let Some(lap) = self.lap {
+            self.current.phases[i] += now.saturating_duration_since(lap).as_secs_f32() * 1000.0;
+        }
+        self.lap = Some(now);
+    }
+
+    /// The frame's work is done: its duration in ms.
+    pub fn end(&mut self, now: Instant) -> f32 {
+        let Some(began) = self.began.take() else { return 0.0 };
+        self.current.work_ms = now.saturating_duration_since(began).as_secs_f32() * 1000.0;
+        if self.window.len() >= FRAME_WINDOW {
+            self.window.pop_front();
+        }
+        self.window.push_back(self.current);
+        self.current.work_ms
+    }
+
+    pub fn stats(&self, target_hz: f32) -> FrameStats {
+        let target_hz = target_hz.max(0.1);
+        let budget_ms = 1000.0 / target_hz;
+        let mut stats = FrameStats { budget_ms, target_hz, ..Default::default() };
+        if self.window.is_empty() {
+            return stats;
+        }
+        let mut work: Vec<f32> = self.window.iter().map(|f| f.work_ms).collect();
+        work.sort_by(|a, b| a.total_cmp(b));
+        let at = |q: f32| work[((work.len() - 1) as f32 * q).round() as usize];
+        stats.p50_ms = at(0.5);
+        stats.p95_ms = at(0.95);
+        stats.max_ms = work[work.len() - 1];
+        stats.overruns = work.iter().filter(|&&ms| ms > budget_ms).count();
+        let span: f32 = self.window.iter().map(|f| f.dt).sum();
+        stats.achieved_hz = if span > 0.0 { self.window.len() as f32 / span } else { 0.0 };
+        let n = self.window.len() as f32;
+        for (i, phase) in PHASES.iter().enumerate() {
+            stats.phases.insert(phase.name().to_string(), self.window.iter().map(|f| f.phases[i]).sum::<f32>() / n);
+        }
+        stats
+    }
+}
+
+// --- Watchdog ---
+
+/// What the loop publishes every frame (shared with the watchdog thread).
+#[derive(Default)]
+struct Pulse {
+    streak: AtomicU32,     // Consecutive frames over budget
+    last_work_us: AtomicU64,
+    budget_us: AtomicU64,
+    last_frame_ms: AtomicU64, // Since `epoch`
+    shedding: AtomicBool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Alarm {
+    Overrun { frames: u32, work_ms: f32, budget_ms: f32 },
+    Stall { secs: f32 },
+    Recovered,
+}
+
+impl Alarm {
+    pub fn line(&self) -> String {
+        match self {
+            Alarm::Overrun { frames, work_ms, budget_ms } => format!(
+                "⏱️ WATCHDOG: {} frames in a row over budget (last {:.1} ms of {:.1} ms). The loop can't keep its rate.",
+                frames, work_ms, budget_ms),
+            Alarm::Stall { secs } => format!("⏱️ WATCHDOG: no frame for {:.1}s. The loop is stalled.", secs),
+            Alarm::Recovered => "⏱️ WATCHDOG: frame rate recovered.".to_string(),
+        }
+    }
+}
+
+/// Alarm state machine: one warning per episode, and a recovery when it ends.
+#[derive(Default)]
+pub struct Assessor {
+    alarmed: bool,
+}
+
+impl Assessor {
+    pub fn assess(&mut self, config: &WatchdogConfig, streak: u32, silent_secs: f32, work_ms: f32, budget_ms: f32) -> Option<Alarm> {
+        let stalled = silent_secs >= config.stall_secs + budget_ms / 1000.0; // A slow heartbeat is not a stall
+        let overrun = streak >= config.overrun_frames.max(1);
+        match (self.alarmed, stalled, overrun) {
+            (false, true, _) => {
+                self.alarmed = true;
+                Some(Alarm::Stall { secs: silent_secs })
+            },
+            (false, false, true) => {
+                self.alarmed = true;
+                Some(Alarm::Overrun { frames: streak, work_ms, budget_ms })
+            },
+            (true, false, false) => {
+                self.alarmed = false;
+                Some(Alarm::Recovered)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The loop's side of the watchdog (cheap: a few atomics per frame).
+#[derive(Clone)]
+pub struct Watchdog {
+    pulse: Arc<Pulse>,
+    epoch: Instant,
+}
+
+impl Watchdog {
+    /// Start the watchdog thread (if enabled); its warnings go out as System thoughts.
+    pub fn spawn(config: WatchdogConfig, tx_thoughts: Sender<Thought>) -> Self {
+        let watchdog = Self { pulse: Arc::new(Pulse::default()), epoch: Instant::now() };
+        if !config.enabled {
+            return watchdog;
+        }
+        let dog = watchdog.clone();
+        thread::spawn(move || {
+            let mut assessor = Assessor::default();
+            loop {
+                thread::sleep(WATCHDOG_POLL);
+                let pulse = &dog.pulse;
+                let last = pulse.last_frame_ms.load(Ordering::Relaxed);
+                let silent_secs = (dog.epoch.elapsed().as_millis() as u64).saturating_sub(last) as f32 / 1000.0;
+                let alarm = assessor.assess(&config,
+                    pulse.streak.load(Ordering::Relaxed),
+                    silent_secs,
+                    pulse.last_work_us.load(Ordering::Relaxed) as f32 / 1000.0,
+                    pulse.budget_us.load(Ordering::Relaxed) as f32 / 1000.0);
+                let Some(alarm) = alarm else { continue };
+                if config.shed_idle_pulses {
+                    pulse.shedding.store(matches!(alarm, Alarm::Overrun { .. }), Ordering::Relaxed);
+                }
+                if tx_thoughts.send(Thought::new(MindVoice::System, alarm.line())).is_err() {
+                    break; // Daemon gone
+                }
+            }
+        });
+        watchdog
+    }
+
+    /// A frame finished: `work_ms` of work against a budget of `budget_ms`.
+    pub fn frame(&self, work_ms: f32, budget_ms: f32) {
+        let pulse = &self.pulse;
+        if work_ms > budget_ms {
+            pulse.streak.fetch_add(1, Ordering::Relaxed);
+        } else {
+            pulse.streak.store(0, Ordering::Relaxed);
+        }
+        pulse.last_work_us.store((work_ms * 1000.0) as u64, Ordering::Relaxed);
+        pulse.budget_us.store((budget_ms * 1000.0) as u64, Ordering::Relaxed);
+        pulse.last_frame_ms.store(self.epoch.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Idle cortex pulses should be skipped (overrunning, shedding enabled).
+    pub fn shedding(&self) -> bool {
+        self.pulse.shedding.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tiny deterministic generator (uniform in [0, 1)).
+    fn next(state: &mut u64) -> f32 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        (*state >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    #[test]
+    fn chance_composes_and_matches_rate_times_dt() {
+        for (rate, dt, k) in [(0.2f32, 1.0 / 60.0, 60u32), (3.0, 1.0 / 7.0, 7), (0.01, 0.5, 100)] {
+            // k frames of dt == one frame of k*dt
+            let frames = 1.0 - (1.0 - chance(rate, dt)).powi(k as i32);
+            let once = chance(rate, dt * k as f32);
+            assert!((frames - once).abs() <= 1e-4, "chance({}/s): {} frames of {:.4}s give {:.5}, one of {:.4}s gives {:.5}", rate, k, dt, frames, dt * k as f32, once);
+            let small = chance(rate, 1e-4);
+            assert!((small - rate * 1e-4).abs() <= rate * 1e-4 * 0.01, "chance({}/s, 0.1ms) = {:.3e}, not ~rate*dt", rate, small);
+        }
+        assert_eq!(chance(1.0, 0.0), 0.0);
+        assert_eq!(chance(0.0, 1.0), 0.0);
+        assert_eq!(chance(-1.0, 1.0), 0.0);
+        assert!((0.0..=1.0).contains(&chance(1e6, 10.0)), "chance(1e6/s, 10s) = {}", chance(1e6, 10.0));
+    }
+
+    #[test]
+    fn rates_and_periods_do_not_depend_on_the_frame_rate() {
+        const SECS: f32 = 600.0;
+        let rate = 0.5; // Events per second
+        let period = 5.0; // Every 5s (was `ticks % 300`)
+        let expected = rate * SECS;
+        let sigma = expected.sqrt();
+        let mut rng = 0x2545_F491_4F6C_DD1Du64;
+        type Framing = (&'static str, fn(&mut u64) -> f32);
+        let framings: [Framing; 3] = [
+            ("60Hz", |_| 1.0 / 60.0),
+            ("7Hz", |_| 1.0 / 7.0),
+            ("jittery 5-90Hz", |s| 1.0 / (5.0 + 85.0 * next(s))),
+        ];
+        for (name, dt_of) in framings {
+            let (mut t, mut events, mut ticks) = (0.0f32, 0u32, 0u32);
+            let mut every = Every::new(period);
+            while t < SECS {
+                let dt = dt_of(&mut rng);
+                t += dt;
+                if next(&mut rng) < chance(rate, dt) {
+                    events += 1;
+                }
+                ticks += every.tick(dt);
+            }
+            assert!((events as f32 - expected).abs() <= 4.0 * sigma, "{}: {} events of a {}/s rate in {}s (expected {:.0} +- {:.0})", name, events, rate, SECS, expected, 4.0 * sigma);
+            let periods = (SECS / period).floor() as u32;
+            assert!(ticks.abs_diff(periods) <= 1, "{}: Every({}s) fired {} times in {}s (expected {})", name, period, ticks, SECS, periods);
+        }
+    }
+
+    #[test]
+    fn the_frame_clock_reports_percentiles_and_achieved_hz() {
+        // Frames of 1..=100 ms at 50Hz
+        let mut clock = FrameClock::new();
+        let t0 = Instant::now();
+        for ms in 1..=100u64 {
+            let start = t0 + Duration::from_millis(ms * 1000);
+            clock.begin(start, 0.02);
+            clock.lap(Phase::Physics, start + Duration::from_millis(ms / 2));
+            clock.lap(Phase::Input, start + Duration::from_millis(ms));
+            clock.end(start + Duration::from_millis(ms));
+        }
+        let stats = clock.stats(60.0);
+        let near = |a: f32, b: f32| (a - b).abs() < 1.01;
+        assert!(near(stats.p50_ms, 50.0), "p50 {:.1}", stats.p50_ms);
+        assert!(near(stats.p95_ms, 95.0), "p95 {:.1}", stats.p95_ms);
+        assert!(near(stats.max_ms, 100.0), "max {:.1}", stats.max_ms);
+        assert!((stats.achieved_hz - 50.0).abs() <= 0.1, "achieved {:.1} Hz", stats.achieved_hz);
+        assert_eq!(stats.overruns, 84, "overruns of {:.1} ms", stats.budget_ms);
+        // The mean phase laps add up to the mean frame
+        let phases = stats.phases["physics"] + stats.phases["input"];
+        assert!((phases - 50.5).abs() <= 0.5, "mean phase laps add up to {:.2} ms", phases);
+    }
+
+    #[test]
+    fn the_watchdog_warns_once_per_episode_and_recovers() {
+        let config = WatchdogConfig { enabled: true, ..WatchdogConfig::default() };
+        let n = config.overrun_frames.max(1);
+        let mut assessor = Assessor::default();
+        let mut alarms = Vec::new();
+        for streak in (0..n + 20).chain([0, 0]) {
+            alarms.extend(assessor.assess(&config, streak, 0.0, 30.0, 16.7));
+        }
+        let kinds: Vec<&str> = alarms.iter().map(|a| match a {
+            Alarm::Overrun { .. } => "overrun",
+            Alarm::Stall { .. } => "stall",
+            Alarm::Recovered => "recovered",
+        }).collect();
+        assert_eq!(kinds, ["overrun", "recovered"], "a {}-frame overrun episode", n + 19);
+
+        // A stall warns once, and never at a 0.5Hz heartbeat
+        let mut assessor = Assessor::default();
+        let stall = assessor.assess(&config, 0, config.stall_secs + 0.1, 0.0, 16.7);
+        assert!(matches!(stall, Some(Alarm::Stall { .. })), "{}s without a frame raised {:?}", config.stall_secs + 0.1, stall);
+        assert!(assessor.assess(&config, 0, config.stall_secs + 1.0, 0.0, 16.7).is_none());
+        assert!(Assessor::default().assess(&config, 0, config.stall_secs + 0.1, 0.0, 2000.0).is_none());
+    }
+}
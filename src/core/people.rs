@@ -0,0 +1,277 @@
+// src/core/people.rs
+// THE FACES: Who has talked to ALEPH, and how it felt
+//
+// "Me llamo Marta" -> a `Person` that survives reboots (people.json). Names
+// come from a cheap keyword pass over transcripts (no NER, no LLM). The voice
+// is remembered as a pitch fingerprint (the only speaker feature the ears
+// have), so later utterances in that register are attributed to her. Each
+// attributed utterance folds the chemistry it produced into a running
+// valence: pleasant company becomes "trusted", stressful company "wary".
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use crate::core::chemistry::Neurotransmitters;
+use crate::core::config::PeopleConfig;
+use crate::core::timeline::unix_millis;
+
+/// Relative pitch distance under which two voices are "the same person".
+const VOICE_MATCH: f32 = 0.12;
+/// Valence memory: after this many utterances new ones weigh 1/N.
+const VALENCE_WINDOW: f32 = 50.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Person {
+    pub name: String,
+    pub voice_pitch: Option<f32>, // Speaker fingerprint (median f0), if heard
+    pub first_seen: u64,          // Unix millis
+    pub last_seen: u64,
+    pub interactions: u32,
+    pub valence: f32, // -1.0 (distressing) .. 1.0 (comforting)
+}
+
+impl Person {
+    pub fn standing(&self) -> &'static str {
+        if self.interactions >= 3 && self.valence > 0.2 {
+            "trusted"
+        } else if self.valence < -0.2 {
+            "wary"
+        } else {
+            "acquainted"
+        }
+    }
+}
+
+/// What an utterance did to the address book.
+pub enum PersonEvent {
+    Introduced(String),
+    Recognized(String),
+}
+
+/// Chemical valence of a moment: comfort and reward minus stress.
+pub fn valence_of(chem: &Neurotransmitters) -> f32 {
+    ((chem.oxytocin + chem.dopamine) * 0.5 - chem.cortisol).clamp(-1.0, 1.0)
+}
+
+/// Running mean with a bounded window (recent company counts more than ancient).
+pub fn fold_valence(current: f32, sample: f32, interactions: u32) -> f32 {
+    let n = (interactions.max(1) as f32).min(VALENCE_WINDOW);
+    current + (sample - current) / n
+}
+
+/// Name after a self-introduction ("me llamo Marta", "my name is Tom").
+/// The word must be capitalized (Whisper does that for names), which keeps
+/// "soy feliz" / "I'm tired" out.
+pub fn extract_name(text: &str, patterns: &[String]) -> Option<String> {
+    let lower = text.to_lowercase();
+    for pattern in patterns {
+        let pattern = pattern.to_lowercase();
+        let mut from = 0;
+        while let Some(pos) = lower[from..].find(&pattern) {
+            let start = from + pos;
+            let end = start + pattern.len();
+            from = end;
+            // Whole words only ("soy" must not match inside "desoyó")
+            let before_ok = lower[..start].chars().next_back().map_or(true, |c| !c.is_alphanumeric());
+            if !before_ok {
+                continue;
+            }
+            // `to_lowercase` can change byte lengths; only trust offsets on char boundaries
+            let Some(rest) = text.get(end..) else { continue };
+            if rest.chars().next().map_or(false, |c| c.is_alphanumeric()) {
+                continue;
+            }
+            let word: String = rest.trim_start()
+                .chars()
+                .take_while(|c| c.is_alphabetic() || *c == '-')
+                .collect();
+            if word.chars().count() >= 2 && word.chars().next().map_or(false, char::is_uppercase) {
+                return Some(word);
+            }
+        }
+    }
+    None
+}
+
+/// Shared handle: the loop updates, HTTP reads.
+#[derive(Clone)]
+pub struct People {
+    persons: Arc<Mutex<Vec<Person>>>,
+    config: PeopleConfig,
+}
+
+impl People {
+    pub fn load(config: PeopleConfig) -> Self {
+        let persons: Vec<Person> = fs::read_to_string(&config.path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        if !persons.is_empty() {
+            println!("👥 PEOPLE: {} known faces loaded.", persons.len());
+        }
+        Self { persons: Arc::new(Mutex::new(persons)), config }
+    }
+
+    pub fn list(&self) -> Vec<Person> {
+        self.persons.lock().unwrap().clone()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.persons.lock().unwrap().iter().map(|p| p.name.clone()).collect()
+    }
+
+    /// One transcript: introductions create/refresh a person, otherwise the voice
+    /// is matched against the known ones. `valence` is the chemistry right after it.
+    pub fn observe(&self, text: &str, pitch_hz: f32, valence: f32) -> Option<PersonEvent> {
+        let now = unix_millis(SystemTime::now());
+        let pitch = (pitch_hz > 0.0).then_some(pitch_hz);
+        let mut persons = self.persons.lock().unwrap();
+
+        let event = if let Some(name) = extract_name(text, &self.config.intro_patterns) {
+            let idx = match persons.iter().position(|p| p.name.eq_ignore_ascii_case(&name)) {
+                Some(i) => i,
+                None => {
+                    persons.push(Person { name: name.clone(), voice_pitch: None, first_seen: now, last_seen: now, interactions: 0, valence: 0.0 });
+                    persons.len() - 1
+                }
+            };
+            if pitch.is_some() {
+                persons[idx].voice_pitch = pitch; // Whoever introduces themselves owns this voice
+            }
+            Some((idx, PersonEvent::Introduced(name)))
+        } else {
+            let p = pitch?;
+            persons.iter()
+                .enumerate()
+                .filter_map(|(i, person)| person.voice_pitch.map(|v| (i, (v - p).abs() / v)))
+                .filter(|(_, dist)| *dist < VOICE_MATCH)
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(i, _)| (i, PersonEvent::Recognized(persons[i].name.clone())))
+        };
+
+        let (idx, event) = event?;
+        let person = &mut persons[idx];
+        person.interactions += 1;
+        person.last_seen = now;
+        person.valence = fold_valence(person.valence, valence, person.interactions);
+        if let Some(p) = pitch {
+            // Track the voice slowly (colds, tiredness, mic changes)
+            person.voice_pitch = Some(person.voice_pitch.map_or(p, |v| v * 0.9 + p * 0.1));
+        }
+
+        if let Err(e) = save(&self.config.path, &persons) {
+            println!("⚠️ PEOPLE: Could not save {}: {}", self.config.path, e);
+        }
+        Some(event)
+    }
+
+    /// The person heard most recently, however long ago.
+    pub fn latest(&self) -> Option<String> {
+        self.persons.lock().unwrap().iter().max_by_key(|p| p.last_seen).map(|p| p.name.clone())
+    }
+
+    /// "Known person present: Marta (trusted)" if someone was heard recently.
+    pub fn presence_line(&self) -> Option<String> {
+        let cutoff = unix_millis(SystemTime::now() - Duration::from_secs(self.config.presence_secs));
+        let persons = self.persons.lock().unwrap();
+        let present: Vec<String> = persons.iter()
+            .filter(|p| p.last_seen >= cutoff)
+            .map(|p| format!("{} ({})", p.name, p.standing()))
+            .collect();
+        if present.is_empty() {
+            None
+        } else {
+            Some(format!("Known person present: {}", present.join(", ")))
+        }
+    }
+}
+
+fn save(path: &str, persons: &[Person]) -> Result<()> {
+    let tmp = format!("{}.tmp", path);
+    fs::write(&tmp, serde_json::to_string_pretty(persons)?)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns() -> Vec<String> {
+        PeopleConfig::default().intro_patterns
+    }
+
+    fn people(name: &str) -> (People, String) {
+        let path = std::env::temp_dir().join(format!("aleph-people-{}-{}.json", name, std::process::id()));
+        let path = path.to_string_lossy().to_string();
+        let _ = fs::remove_file(&path);
+        (People::load(PeopleConfig { path: path.clone(), ..Default::default() }), path)
+    }
+
+    #[test]
+    fn introductions_in_spanish_and_english() {
+        let cases = [
+            ("Hola, me llamo Marta", Some("Marta")),
+            ("mi nombre es José-Luis y vengo de lejos", Some("José-Luis")),
+            ("Soy Ana.", Some("Ana")),
+            ("Hi! My name is Tom", Some("Tom")),
+            ("I'm Sarah, nice to meet you", Some("Sarah")),
+            ("call me Ishmael", Some("Ishmael")),
+            // Adjectives and states are lowercase in transcripts: not names
+            ("soy feliz hoy", None),
+            ("I'm tired", None),
+            // Whole words only
+            ("se desoyó Pedro", None),
+            ("I am A", None),
+            ("nadie habló", None),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(extract_name(text, &patterns()).as_deref(), expected, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn multibyte_text_never_panics_the_extractor() {
+        for text in ["İ soy Ñandú", "ẞ my name is Öz", "me llamo", "soy🙂Luz", "SOY ÁLVARO"] {
+            let _ = extract_name(text, &patterns());
+        }
+        assert_eq!(extract_name("SOY Álvaro", &patterns()).as_deref(), Some("Álvaro"));
+    }
+
+    #[test]
+    fn valence_is_a_bounded_running_mean() {
+        let calm = Neurotransmitters { oxytocin: 0.6, dopamine: 0.6, cortisol: 0.1, ..Neurotransmitters::new() };
+        let stressed = Neurotransmitters { oxytocin: 0.0, dopamine: 0.2, cortisol: 0.9, ..Neurotransmitters::new() };
+        assert!((valence_of(&calm) - 0.5).abs() < 1e-6);
+        assert!((valence_of(&stressed) + 0.8).abs() < 1e-6);
+
+        // The first sample is taken whole, then each weighs 1/n
+        let mut valence = fold_valence(0.0, 0.5, 1);
+        assert!((valence - 0.5).abs() < 1e-6);
+        valence = fold_valence(valence, -0.8, 2);
+        assert!((valence - (-0.15)).abs() < 1e-6);
+        // Past the window, a new moment moves it by at most 1/50 of the gap
+        let settled = fold_valence(0.5, -1.0, 1000);
+        assert!((settled - (0.5 - 1.5 / VALENCE_WINDOW)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn a_voice_becomes_a_trusted_person_across_reboots() {
+        let (people, path) = people("voice");
+        assert!(matches!(people.observe("Me llamo Marta", 210.0, 0.4), Some(PersonEvent::Introduced(n)) if n == "Marta"));
+        assert!(matches!(people.observe("qué tal el día", 215.0, 0.5), Some(PersonEvent::Recognized(n)) if n == "Marta"));
+        assert!(people.observe("hola", 120.0, 0.5).is_none(), "another voice");
+        assert!(people.observe("hola", 0.0, 0.5).is_none(), "nothing voiced");
+        people.observe("me encanta", 205.0, 0.6);
+
+        let reborn = People::load(PeopleConfig { path: path.clone(), ..Default::default() });
+        let marta = &reborn.list()[0];
+        assert_eq!((marta.name.as_str(), marta.interactions), ("Marta", 3));
+        assert_eq!(marta.standing(), "trusted");
+        assert_eq!(reborn.presence_line().as_deref(), Some("Known person present: Marta (trusted)"));
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,248 @@
+// src/core/pressure.rs
+// THE BELT: Memory-pressure degradation
+//
+// A host running out of RAM swaps, and a swapping brain misses its ticks. When
+// `ram_usage` climbs the daemon tightens its belt one notch at a time, cheapest
+// measure first:
+//
+//   1. cap_growth      neurogenesis stops at the current size
+//   2. consolidate     a hippocampus consolidation round (the volatile backlog goes to disk)
+//   3. shrink_context  the Planet assembles its prompt within history_tokens
+//   4. sleep           checkpoint, then forced sleep
+//
+// At most one notch per hold_secs, so each measure gets a chance to work before
+// the next one fires. Notches come off in reverse order (again one per hold)
+// once RAM is `release` below the threshold that tightened them: the Planet
+// gets its whole context back, then growth resumes. Consolidation and sleep
+// have nothing to undo; sleep ends on its own. Every notch is logged as a System
+// thought and counted (/telemetry "pressure").
+
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+use crate::core::config::PressureConfig;
+use crate::senses::proprioception::BodyStatus;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Default)]
+pub enum Notch {
+    #[default]
+    Relaxed,
+    CapGrowth,
+    Consolidate,
+    ShrinkContext,
+    Sleep,
+}
+
+impl Notch {
+    fn tighter(self) -> Option<Notch> {
+        match self {
+            Notch::Relaxed => Some(Notch::CapGrowth),
+            Notch::CapGrowth => Some(Notch::Consolidate),
+            Notch::Consolidate => Some(Notch::ShrinkContext),
+            Notch::ShrinkContext => Some(Notch::Sleep),
+            Notch::Sleep => None,
+        }
+    }
+
+    fn looser(self) -> Notch {
+        match self {
+            Notch::Relaxed | Notch::CapGrowth => Notch::Relaxed,
+            Notch::Consolidate => Notch::CapGrowth,
+            Notch::ShrinkContext => Notch::Consolidate,
+            Notch::Sleep => Notch::ShrinkContext,
+        }
+    }
+}
+
+/// One move of the belt: the notch that was tightened, or the one that came off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Tighten(Notch),
+    Loosen(Notch),
+}
+
+/// What /telemetry shows.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PressureState {
+    pub notch: Notch,
+    pub ram: f32,
+    /// Times each notch was tightened (cap_growth, consolidate, shrink_context, sleep).
+    pub tightened: [u64; 4],
+    pub loosened: u64,
+}
+
+pub struct Belt {
+    config: PressureConfig,
+    notch: Notch,
+    ram: f32,
+    last_step: Option<Instant>,
+    tightened: [u64; 4],
+    loosened: u64,
+}
+
+impl Belt {
+    pub fn new(config: PressureConfig) -> Self {
+        Self { config, notch: Notch::Relaxed, ram: 0.0, last_step: None, tightened: [0; 4], loosened: 0 }
+    }
+
+    pub fn notch(&self) -> Notch {
+        self.notch
+    }
+
+    pub fn growth_capped(&self) -> bool {
+        self.notch >= Notch::CapGrowth
+    }
+
+    fn threshold(&self, notch: Notch) -> f32 {
+        match notch {
+            Notch::Relaxed => 0.0,
+            Notch::CapGrowth => self.config.cap_growth,
+            Notch::Consolidate => self.config.consolidate,
+            Notch::ShrinkContext => self.config.shrink_context,
+            Notch::Sleep => self.config.sleep,
+        }
+    }
+
+    /// A new body reading: at most one step, and none within hold_secs of the last one.
+    pub fn observe(&mut self, body: &BodyStatus, now: Instant) -> Option<Step> {
+        self.ram = body.ram_usage;
+        if !self.config.enabled {
+            return None;
+        }
+        let hold = Duration::from_secs_f32(self.config.hold_secs.max(0.0));
+        if self.last_step.is_some_and(|at| now.duration_since(at) < hold) {
+            return None;
+        }
+        if let Some(next) = self.notch.tighter() {
+            if self.ram >= self.threshold(next) {
+                self.notch = next;
+                self.tightened[next as usize - 1] += 1;
+                self.last_step = Some(now);
+                return Some(Step::Tighten(next));
+            }
+        }
+        if self.notch != Notch::Relaxed && self.ram < self.threshold(self.notch) - self.config.release {
+            let left = self.notch;
+            self.notch = left.looser();
+            self.loosened += 1;
+            self.last_step = Some(now);
+            return Some(Step::Loosen(left));
+        }
+        None
+    }
+
+    pub fn state(&self) -> PressureState {
+        PressureState { notch: self.notch, ram: self.ram, tightened: self.tightened, loosened: self.loosened }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND: Duration = Duration::from_secs(1);
+
+    fn body(ram: f32) -> BodyStatus {
+        BodyStatus { cpu_usage: 20.0, ram_usage: ram }
+    }
+
+    /// RAM 50% -> 99% -> 50%, one BodyStatus per second: the steps (second, RAM, step),
+    /// whether the peak was Sleep with growth capped, and the belt after it.
+    fn ramp(config: &PressureConfig) -> (Vec<(usize, f32, Step)>, bool, Belt) {
+        let t0 = Instant::now();
+        let mut ramp: Vec<f32> = (0..50).map(|i| 0.50 + i as f32 * 0.01).collect();
+        ramp.extend(std::iter::repeat_n(0.99, 60));
+        ramp.extend((0..50).map(|i| 0.99 - i as f32 * 0.01));
+        ramp.extend(std::iter::repeat_n(0.50, 60));
+        let mut belt = Belt::new(config.clone());
+        let mut steps = Vec::new();
+        let mut capped_at_peak = false;
+        for (i, &ram) in ramp.iter().enumerate() {
+            if let Some(step) = belt.observe(&body(ram), t0 + SECOND * i as u32) {
+                steps.push((i, ram, step));
+            }
+            capped_at_peak |= i == 109 && belt.growth_capped() && belt.notch() == Notch::Sleep;
+        }
+        (steps, capped_at_peak, belt)
+    }
+
+    #[test]
+    fn the_notches_tighten_in_order_and_come_off_in_reverse() {
+        let (steps, capped_at_peak, belt) = ramp(&PressureConfig::default());
+        let order: Vec<Step> = steps.iter().map(|&(_, _, s)| s).collect();
+        assert_eq!(order, [
+            Step::Tighten(Notch::CapGrowth), Step::Tighten(Notch::Consolidate), Step::Tighten(Notch::ShrinkContext), Step::Tighten(Notch::Sleep),
+            Step::Loosen(Notch::Sleep), Step::Loosen(Notch::ShrinkContext), Step::Loosen(Notch::Consolidate), Step::Loosen(Notch::CapGrowth),
+        ]);
+        assert!(capped_at_peak, "the peak was not Sleep with growth capped");
+        assert!(!belt.growth_capped());
+        assert_eq!(belt.notch(), Notch::Relaxed);
+        let state = belt.state();
+        assert_eq!(state.tightened, [1, 1, 1, 1]);
+        assert_eq!(state.loosened, 4);
+    }
+
+    #[test]
+    fn each_step_waits_a_hold_and_crosses_its_own_threshold() {
+        let config = PressureConfig::default();
+        let (steps, _, _) = ramp(&config);
+        assert!(steps.windows(2).all(|w| ((w[1].0 - w[0].0) as f32) >= config.hold_secs),
+            "two steps closer than hold_secs ({:?})", steps.iter().map(|s| s.0).collect::<Vec<_>>());
+        let below = |notch: Notch| match notch {
+            Notch::CapGrowth => config.cap_growth,
+            Notch::Consolidate => config.consolidate,
+            Notch::ShrinkContext => config.shrink_context,
+            _ => config.sleep,
+        };
+        for (i, ram, step) in steps {
+            let inside = match step {
+                Step::Tighten(n) => ram >= below(n),
+                Step::Loosen(n) => ram < below(n) - config.release,
+            };
+            assert!(inside, "{:?} at second {} with RAM {:.2} (outside its threshold)", step, i, ram);
+        }
+    }
+
+    #[test]
+    fn hovering_inside_the_release_band_keeps_every_notch_on() {
+        let config = PressureConfig::default();
+        let t0 = Instant::now();
+        let mut belt = Belt::new(config.clone());
+        let mut moves = 0;
+        for i in 0..120u32 {
+            let ram = if i < 60 { 0.99 } else { config.sleep - config.release * 0.5 };
+            if belt.observe(&body(ram), t0 + SECOND * i).is_some() && i >= 60 {
+                moves += 1;
+            }
+        }
+        assert_eq!(moves, 0);
+        assert_eq!(belt.notch(), Notch::Sleep);
+    }
+
+    #[test]
+    fn a_reservoir_under_the_growth_cap_grows_again_once_it_lifts() {
+        let config = PressureConfig::default();
+        let t0 = Instant::now();
+        let mut ego = crate::core::reservoir::FractalReservoir::seeded(64, 64, 0.95, 0.2, 11);
+        let mut belt = Belt::new(config.clone());
+        belt.observe(&body(0.82), t0);
+        if belt.growth_capped() {
+            ego.set_growth_ceiling(Some(ego.current_size()));
+        }
+        ego.neurogenesis(5);
+        assert_eq!(ego.current_size(), 64);
+        if belt.observe(&body(0.60), t0 + Duration::from_secs_f32(config.hold_secs)) == Some(Step::Loosen(Notch::CapGrowth)) {
+            ego.set_growth_ceiling(None);
+        }
+        ego.neurogenesis(5);
+        assert_eq!(ego.current_size(), 69);
+    }
+
+    #[test]
+    fn a_disabled_belt_never_moves_and_still_reports_the_ram() {
+        let t0 = Instant::now();
+        let mut belt = Belt::new(PressureConfig { enabled: false, ..PressureConfig::default() });
+        assert!(!(0..30u32).any(|i| belt.observe(&body(0.99), t0 + SECOND * i * 20).is_some()));
+        assert_eq!(belt.state().ram, 0.99);
+    }
+}
@@ -0,0 +1,268 @@
+// src/core/profile.rs
+// THE SHELL: Where ALEPH lives (data dir, environment, container profile)
+//
+// Every path ALEPH touches is relative (reservoir.json, memories.json, logs/,
+// models/, docs/, aleph.toml...), the mic is autodetected and the TUI socket
+// sits in /tmp. Fine on a desk, hostile in a container. Three layers:
+//
+//   ALEPH_DATA_DIR=/data      all state lives there: created 0700 on first run,
+//                             and `aleph start` runs from it (so every relative
+//                             path, aleph.toml and models.toml included, lands in it)
+//   ALEPH_<SECTION>__<KEY>    any config key, `__` per level:
+//                             ALEPH_EARS__AGC__TARGET_RMS=0.1, ALEPH_HEALTH__MANDATORY='["reservoir"]',
+//                             ALEPH_MODELS__BACKEND=ollama (models.toml). Values are TOML
+//                             (numbers, bools, arrays); anything else is a string
+//   ALEPH_MODELS_DIR=/models  the mounted models: Whisper, GGUF and tokenizer are looked up there
+//
+// `aleph start --profile container` (or ALEPH_PROFILE=container) changes the
+// defaults under the file and the environment: browser audio over the
+// WebSocket (no mic probing), dashboard on 0.0.0.0, the IPC socket inside the
+// data dir (/data unless ALEPH_DATA_DIR says otherwise), and no local voice:
+// what ALEPH says goes to the dashboard (`speech`) instead of Piper + aplay.
+//
+// Precedence, lowest first: built-in defaults < profile < file < environment.
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+use crate::core::config::ModelsConfig;
+
+pub const PROFILE_VAR: &str = "ALEPH_PROFILE";
+pub const DATA_DIR_VAR: &str = "ALEPH_DATA_DIR";
+pub const MODELS_DIR_VAR: &str = "ALEPH_MODELS_DIR";
+/// Config keys in the environment: ALEPH_<SECTION>__<KEY>.
+const KEY_PREFIX: &str = "ALEPH_";
+/// First segment of models.toml keys (ALEPH_MODELS__BACKEND).
+pub const MODELS_SCOPE: &str = "models";
+/// State of a container that doesn't name its data dir.
+pub const CONTAINER_DATA_DIR: &str = "/data";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Profile {
+    Local,
+    Container,
+}
+
+impl Profile {
+    pub fn parse(name: &str) -> Result<Self> {
+        match name {
+            "local" | "" => Ok(Profile::Local),
+            "container" | "docker" => Ok(Profile::Container),
+            other => bail!("unknown profile '{}' (local, container)", other),
+        }
+    }
+
+    /// From ALEPH_PROFILE (unset or unknown = local).
+    pub fn current() -> Self {
+        std::env::var(PROFILE_VAR).ok().and_then(|p| Self::parse(&p).ok()).unwrap_or(Profile::Local)
+    }
+
+    /// Where the state lives: ALEPH_DATA_DIR, else /data in a container, else the working dir (None).
+    pub fn data_dir(self) -> Option<PathBuf> {
+        match std::env::var(DATA_DIR_VAR) {
+            Ok(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+            _ if self == Profile::Container => Some(PathBuf::from(CONTAINER_DATA_DIR)),
+            _ => None,
+        }
+    }
+
+    /// The defaults this profile (and ALEPH_MODELS_DIR) put under a config file of `scope`.
+    fn defaults(self, scope: &str, data_dir: Option<&Path>, models_dir: Option<&str>) -> toml::Table {
+        let mut table = toml::Table::new();
+        let mut set = |path: &[&str], value: toml::Value| {
+            set_path(&mut table, &path.iter().map(|s| s.to_string()).collect::<Vec<_>>(), value);
+        };
+        let string = |s: &str| toml::Value::String(s.to_string());
+        if scope == MODELS_SCOPE {
+            if let Some(dir) = models_dir {
+                let defaults = ModelsConfig::default();
+                for (key, default) in [("model_path", defaults.model_path), ("tokenizer_path", defaults.tokenizer_path)] {
+                    let file = Path::new(&default).file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or(default);
+                    set(&[key], string(&Path::new(dir).join(file).to_string_lossy()));
+                }
+            }
+            return table;
+        }
+        if let Some(dir) = models_dir {
+            set(&["ears", "models_dir"], string(dir));
+        }
+        if self == Profile::Container {
+            set(&["runtime", "audio"], string("websocket"));
+            set(&["runtime", "web_bind"], string("0.0.0.0:3030"));
+            set(&["runtime", "local_voice"], toml::Value::Boolean(false));
+            let dir = data_dir.map(Path::to_path_buf).unwrap_or_else(|| PathBuf::from(CONTAINER_DATA_DIR));
+            set(&["runtime", "ipc_socket"], string(&dir.join("aleph.sock").to_string_lossy()));
+        }
+        table
+    }
+}
+
+/// A raw environment value as TOML (numbers, bools, arrays, quoted strings), else a plain string.
+fn parse_value(raw: &str) -> toml::Value {
+    match format!("v = {}", raw).parse::<toml::Table>() {
+        Ok(mut table) => table.remove("v").unwrap_or_else(|| toml::Value::String(raw.to_string())),
+        Err(_) => toml::Value::String(raw.to_string()),
+    }
+}
+
+fn set_path(table: &mut toml::Table, path: &[String], value: toml::Value) {
+    let Some((last, parents)) = path.split_last() else { return };
+    let mut current = table;
+    for key in parents {
+        if !matches!(current.get(key), Some(toml::Value::Table(_))) {
+            current.insert(key.clone(), toml::Value::Table(toml::Table::new()));
+        }
+        let Some(toml::Value::Table(next)) = current.get_mut(key) else { return };
+        current = next;
+    }
+    current.insert(last.clone(), value);
+}
+
+/// `over` on top of `base`, table by table.
+fn merge(base: &mut toml::Table, over: toml::Table) {
+    for (key, value) in over {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(inner)), toml::Value::Table(value)) => merge(inner, value),
+            (_, value) => { base.insert(key, value); },
+        }
+    }
+}
+
+/// The config keys among `vars` for a file of `scope` ("" = aleph.toml, MODELS_SCOPE =
+/// models.toml): key path (lowercase) and value. Names without `__` are not keys.
+pub fn env_overrides(vars: impl IntoIterator<Item = (String, String)>, scope: &str) -> Vec<(Vec<String>, toml::Value)> {
+    let mut overrides: Vec<(Vec<String>, toml::Value)> = vars.into_iter().filter_map(|(name, raw)| {
+        let key = name.strip_prefix(KEY_PREFIX)?;
+        let mut path: Vec<String> = key.split("__").map(|p| p.to_ascii_lowercase()).collect();
+        if path.len() < 2 || path.iter().any(|p| p.is_empty()) {
+            return None;
+        }
+        match (scope == MODELS_SCOPE, path[0] == MODELS_SCOPE) {
+            (true, true) => { path.remove(0); },
+            (false, false) => {},
+            _ => return None,
+        }
+        Some((path, parse_value(&raw)))
+    }).collect();
+    overrides.sort_by(|a, b| a.0.cmp(&b.0)); // Deterministic when a table and one of its keys are both set
+    overrides
+}
+
+/// Profile defaults < file `content` < environment `overrides`, as one table.
+pub fn layered(profile: Profile, scope: &str, content: Option<&str>, overrides: &[(Vec<String>, toml::Value)]) -> Result<toml::Table> {
+    let models_dir = std::env::var(MODELS_DIR_VAR).ok().filter(|d| !d.is_empty());
+    let mut table = profile.defaults(scope, profile.data_dir().as_deref(), models_dir.as_deref());
+    if let Some(content) = content {
+        merge(&mut table, content.parse::<toml::Table>()?);
+    }
+    for (path, value) in overrides {
+        set_path(&mut table, path, value.clone());
+    }
+    Ok(table)
+}
+
+/// Create the data dir (0700: memories and transcripts are private) if it doesn't exist
+/// and move into it. Returns it (None = no data dir: stay in the working dir).
+pub fn enter_data_dir(profile: Profile) -> Result<Option<PathBuf>> {
+    let Some(dir) = profile.data_dir() else { return Ok(None) };
+    prepare_dir(&dir)?;
+    std::env::set_current_dir(&dir).with_context(|| format!("entering data dir {}", dir.display()))?;
+    Ok(Some(dir))
+}
+
+fn prepare_dir(dir: &Path) -> Result<()> {
+    if dir.exists() {
+        if !dir.is_dir() {
+            bail!("data dir {} exists and is not a directory", dir.display());
+        }
+        let mode = fs::metadata(dir)?.permissions().mode();
+        if mode & 0o002 != 0 {
+            println!("⚠️ Data dir {} is world-writable ({:o}).", dir.display(), mode & 0o777);
+        }
+        return Ok(());
+    }
+    fs::DirBuilder::new().recursive(true).mode(0o700).create(dir)
+        .with_context(|| format!("creating data dir {}", dir.display()))?;
+    println!("📦 Data dir created: {} (0700)", dir.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::AlephConfig;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn config(table: toml::Table) -> AlephConfig {
+        toml::Value::Table(table).try_into().unwrap()
+    }
+
+    #[test]
+    fn environment_keys_reach_nested_fields_in_the_right_file() {
+        let env = vars(&[
+            ("ALEPH_EARS__AGC__TARGET_RMS", "0.12"), ("ALEPH_HEALTH__MANDATORY", "[\"reservoir\", \"memory\"]"),
+            ("ALEPH_EARS__LANGUAGE", "en"), ("ALEPH_RUNTIME__LOCAL_VOICE", "false"), ("ALEPH_BRAIN__INPUT_CHANNELS", "640"),
+            ("ALEPH_DATA_DIR", "/data"), ("ALEPH_PROFILE", "container"), ("ALEPH_MODELS__BACKEND", "mock"), ("HOME", "/root"),
+        ]);
+        // Names without `__` are ignored, and so is the models.toml key
+        let keys = env_overrides(env.clone(), "");
+        assert_eq!(keys.len(), 5);
+        let c = config(layered(Profile::Local, "", None, &keys).unwrap());
+        assert!((c.ears.agc.target_rms - 0.12).abs() < 1e-6, "target_rms {}", c.ears.agc.target_rms);
+        assert_eq!(c.health.mandatory, ["reservoir", "memory"]);
+        assert_eq!(c.ears.language, "en");
+        assert!(!c.runtime.local_voice);
+        assert_eq!(c.brain.input_channels, 640);
+
+        let models = env_overrides(env, MODELS_SCOPE);
+        assert_eq!(models.len(), 1);
+        let m: ModelsConfig = toml::Value::Table(layered(Profile::Local, MODELS_SCOPE, None, &models).unwrap()).try_into().unwrap();
+        assert_eq!(m.backend, "mock");
+    }
+
+    #[test]
+    fn layers_stack_profile_then_file_then_environment() {
+        let file = "[ears]\nlanguage = \"fr\"\nchunk_secs = 4.0\n[runtime]\naudio = \"mic\"\n";
+        let env = env_overrides(vars(&[("ALEPH_EARS__LANGUAGE", "de")]), "");
+        let c = config(layered(Profile::Container, "", Some(file), &env).unwrap());
+        assert_eq!(c.ears.language, "de");
+        assert!((c.ears.chunk_secs - 4.0).abs() < 1e-6);
+        assert_eq!(c.runtime.audio, "mic");
+        assert!(!c.runtime.local_voice);
+        assert!(c.runtime.ipc_socket.ends_with("/aleph.sock") && !c.runtime.ipc_socket.starts_with("/tmp"), "{}", c.runtime.ipc_socket);
+    }
+
+    #[test]
+    fn the_container_profile_moves_audio_voice_and_socket() {
+        let defaults = AlephConfig::default().runtime;
+        let local = config(Profile::Local.defaults("", None, None)).runtime;
+        assert_eq!((local.audio, local.local_voice, local.ipc_socket), (defaults.audio, defaults.local_voice, defaults.ipc_socket));
+        let container = config(Profile::Container.defaults("", Some(Path::new("/srv/aleph")), None)).runtime;
+        assert_eq!((container.audio.as_str(), container.local_voice, container.ipc_socket.as_str()), ("websocket", false, "/srv/aleph/aleph.sock"));
+    }
+
+    #[test]
+    fn a_models_dir_relocates_every_model() {
+        assert_eq!(config(Profile::Local.defaults("", None, Some("/models"))).ears.models_dir, "/models");
+        let m: ModelsConfig = toml::Value::Table(Profile::Local.defaults(MODELS_SCOPE, None, Some("/models"))).try_into().unwrap();
+        assert!(m.model_path.starts_with("/models/") && m.model_path.ends_with(".gguf"), "{}", m.model_path);
+        assert!(m.tokenizer_path.starts_with("/models/") && m.tokenizer_path.ends_with(".json"), "{}", m.tokenizer_path);
+    }
+
+    #[test]
+    fn a_fresh_data_dir_is_created_0700() {
+        let scratch = std::env::temp_dir().join(format!("aleph-profile-{}", std::process::id()));
+        let dir = scratch.join("state");
+        prepare_dir(&dir).unwrap();
+        let mode = fs::metadata(&dir).unwrap().permissions().mode() & 0o777;
+        let again = prepare_dir(&dir);
+        let _ = fs::remove_dir_all(&scratch);
+        assert_eq!(mode, 0o700, "mode {:o}", mode);
+        assert!(again.is_ok(), "second run: {:?}", again.err());
+    }
+}
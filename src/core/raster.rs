@@ -0,0 +1,203 @@
+// src/core/raster.rs
+// THE SEISMOGRAPH: Reservoir activity over time (spike-raster for the dashboard)
+//
+// The dashboard samples `reservoir_activity` at ~12Hz, so waves that cross the
+// reservoir between two samples are invisible. Here every physics tick pools
+// the activity into CHANNELS rows and appends one column to a ring:
+//
+//   rows    = region-pooled channels, grouped by region (Semantic, Auditory,
+//             Limbic, Association, Visual) in that order; each region gets rows
+//             proportional to its neuron count, each row averages a contiguous
+//             slice of that region's neurons (so a row always means one region)
+//   columns = ticks, oldest first; value = |activation - rest| quantized to u8
+//
+// Memory is bounded: HISTORY columns x CHANNELS bytes = 600 x 128 = 75 KB
+// (~10 s at 60 Hz), plus the neuron->row map (2 bytes per neuron).
+//
+// Wire format (GET /raster, and incremental WS binary frames):
+//   "RSTR" | version u8 = 1 | channels u8 | columns u16 LE | first_seq u64 LE
+//   | channels x region id u8 | columns x channels u8 (column-major, oldest first)
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+pub const CHANNELS: usize = 128;
+pub const HISTORY: usize = 600;
+const REGIONS: usize = 5;
+/// Regions drift slowly (exposure-based): re-derive rows this often.
+const REMAP_EVERY: u64 = 300;
+
+struct RasterInner {
+    columns: VecDeque<[u8; CHANNELS]>,
+    next_seq: u64,          // Sequence number of the next column pushed
+    row_of: Vec<u16>,       // Neuron index -> row
+    row_region: [u8; CHANNELS],
+    row_size: [u16; CHANNELS],
+}
+
+/// Shared handle: the loop pushes, HTTP / WS read.
+#[derive(Clone)]
+pub struct Raster {
+    inner: Arc<Mutex<RasterInner>>,
+}
+
+impl Raster {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(RasterInner {
+                columns: VecDeque::with_capacity(HISTORY),
+                next_seq: 0,
+                row_of: Vec::new(),
+                row_region: [0; CHANNELS],
+                row_size: [0; CHANNELS],
+            })),
+        }
+    }
+
+    /// One tick of activity (`FractalReservoir::last_activity`, 0.5 = rest).
+    /// `region_map` is only needed when rows must be re-derived (see `needs_remap`).
+    pub fn push(&self, activity: &[f32], region_map: Option<&[u8]>) {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(map) = region_map {
+            inner.remap(map);
+        }
+        if inner.row_of.len() != activity.len() {
+            return; // Grew since the last remap; skip until the caller remaps
+        }
+
+        let mut sums = [0.0f32; CHANNELS];
+        for (&row, &a) in inner.row_of.iter().zip(activity) {
+            sums[row as usize] += ((a - 0.5).abs() * 2.0).min(1.0);
+        }
+        let mut column = [0u8; CHANNELS];
+        for row in 0..CHANNELS {
+            let n = inner.row_size[row].max(1) as f32;
+            column[row] = (sums[row] / n * 255.0).round() as u8;
+        }
+
+        if inner.columns.len() == HISTORY {
+            inner.columns.pop_front();
+        }
+        inner.columns.push_back(column);
+        inner.next_seq += 1;
+    }
+
+    /// True when the caller should pass a fresh region map on this tick.
+    pub fn needs_remap(&self, tick: u64, size: usize) -> bool {
+        tick % REMAP_EVERY == 0 || self.inner.lock().unwrap().row_of.len() != size
+    }
+
+    /// Sequence number the next column will get (clients resume from here).
+    pub fn next_seq(&self) -> u64 {
+        self.inner.lock().unwrap().next_seq
+    }
+
+    /// The last `n` samples (oldest first) of the row pooling `neuron`
+    /// (GET /neuron/{id}); empty if the neuron has no row yet.
+    pub fn row_history(&self, neuron: usize, n: usize) -> Vec<u8> {
+        let inner = self.inner.lock().unwrap();
+        let Some(&row) = inner.row_of.get(neuron) else { return Vec::new() };
+        let skip = inner.columns.len().saturating_sub(n);
+        inner.columns.iter().skip(skip).map(|column| column[row as usize]).collect()
+    }
+
+    /// Encoded columns with seq >= `since` (everything kept if None).
+    pub fn encode(&self, since: Option<u64>) -> Vec<u8> {
+        self.inner.lock().unwrap().encode(since)
+    }
+
+    /// Incremental WS payload: columns since `cursor`, which then moves past them.
+    /// None when nothing new was recorded (the loop sleeps, or is dreaming slowly).
+    pub fn encode_new(&self, cursor: &mut u64) -> Option<Vec<u8>> {
+        let inner = self.inner.lock().unwrap();
+        if inner.next_seq <= *cursor {
+            return None;
+        }
+        let payload = inner.encode(Some(*cursor));
+        *cursor = inner.next_seq;
+        Some(payload)
+    }
+}
+
+impl RasterInner {
+    fn encode(&self, since: Option<u64>) -> Vec<u8> {
+        let oldest = self.next_seq - self.columns.len() as u64;
+        let first = since.unwrap_or(oldest).clamp(oldest, self.next_seq);
+        let skip = (first - oldest) as usize;
+        let count = self.columns.len() - skip;
+
+        let mut out = Vec::with_capacity(16 + CHANNELS + count * CHANNELS);
+        out.extend_from_slice(b"RSTR");
+        out.push(1);
+        out.push(CHANNELS as u8);
+        out.extend_from_slice(&(count as u16).to_le_bytes());
+        out.extend_from_slice(&first.to_le_bytes());
+        out.extend_from_slice(&self.row_region);
+        for column in self.columns.iter().skip(skip) {
+            out.extend_from_slice(column);
+        }
+        out
+    }
+
+    /// Rows per region proportional to its neurons (at least one if it has any, never
+    /// more rows than neurons); rounding leftovers go to the biggest region.
+    fn remap(&mut self, region_map: &[u8]) {
+        let mut members: [Vec<usize>; REGIONS] = Default::default();
+        for (i, &r) in region_map.iter().enumerate() {
+            members[(r as usize).min(REGIONS - 1)].push(i);
+        }
+        let total = region_map.len().max(1);
+
+        let mut rows = [0usize; REGIONS];
+        for r in 0..REGIONS {
+            let n = members[r].len();
+            if n > 0 {
+                rows[r] = (CHANNELS * n / total).clamp(1, n);
+            }
+        }
+        let biggest = (0..REGIONS).max_by_key(|&r| members[r].len()).unwrap_or(0);
+        let used: usize = rows.iter().sum();
+        if used < CHANNELS {
+            rows[biggest] = (rows[biggest] + CHANNELS - used).min(members[biggest].len());
+        } else if used > CHANNELS {
+            rows[biggest] -= used - CHANNELS;
+        }
+
+        self.row_of = vec![0; region_map.len()];
+        self.row_region = [0; CHANNELS];
+        self.row_size = [0; CHANNELS];
+        let mut next_row = 0;
+        for r in 0..REGIONS {
+            let n = members[r].len();
+            for (k, &neuron) in members[r].iter().enumerate() {
+                let row = next_row + k * rows[r] / n.max(1);
+                self.row_of[neuron] = row as u16;
+                self.row_size[row] += 1;
+            }
+            for row in next_row..next_row + rows[r] {
+                self.row_region[row] = r as u8;
+            }
+            next_row += rows[r];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_history_is_the_last_samples_of_the_neurons_row() {
+        let n = 300;
+        let map: Vec<u8> = (0..n).map(|i| (i % 5) as u8).collect();
+        let raster = Raster::new();
+        for step in 0..150 {
+            let activity: Vec<f32> = (0..n).map(|i| if (i + step) % 3 == 0 { 1.0 } else { 0.5 }).collect();
+            raster.push(&activity, (step == 0).then_some(map.as_slice()));
+        }
+        let history = raster.row_history(n - 1, 100);
+        assert_eq!(history.len(), 100);
+        assert!(history.iter().any(|&v| v != 0), "flat though the neuron fired every third tick");
+        assert!(raster.row_history(n + 5, 100).is_empty(), "a neuron without a row has no history");
+    }
+}
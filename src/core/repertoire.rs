@@ -0,0 +1,427 @@
+// src/core/repertoire.rs
+// THE REPERTOIRE: What ALEPH talks about when nobody asked
+//
+// Spontaneous agency used to send the Planet an empty Think: unanchored babble.
+// Now it draws a drive and hands the Planet a short seed prompt assembled from
+// live state. The drives live in repertoire.toml (format documented there), so
+// adding one is an edit, not a rebuild:
+//
+//   social     address the last person heard
+//   epistemic  ask about a memory that came back with low confidence
+//   somatic    comment on the most salient body state
+//   aesthetic  react to music in the room
+//
+// Each drive's weight is its base scaled by the chemistry and the genome
+// (base * max(0, 1 + Σ level × coefficient)); a drive whose template needs a
+// slot that is empty right now is not eligible. The draw is logged, and the
+// drive is credited twice: when the next utterance passes the gate (spoken)
+// and when a human answers within response_secs (answered).
+//
+// There is no loopback sense in this tree: "music" is what the microphone's
+// spectrum calls music (the same 0.6-1.4 energy band that interests the
+// chemistry, not a voice), held for music_secs.
+// A missing or broken file falls back to the starter repertoire compiled in.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use crate::core::chemistry::Neurotransmitters;
+use crate::core::config::RepertoireConfig;
+use crate::core::genome::Genome;
+use crate::senses::ears::AudioSpectrum;
+use crate::senses::proprioception::BodyStatus;
+
+const BUILTIN: &str = include_str!("../../repertoire.toml");
+
+/// Levels a drive's weight can depend on (all 0-1).
+pub const LEVELS: [&str; 7] = ["dopamine", "cortisol", "adenosine", "oxytocin", "serotonin", "curiosity", "stress_tolerance"];
+/// Slots a template can use.
+pub const SLOTS: [&str; 6] = ["person", "silence", "memory", "body", "music", "mood"];
+
+/// Live values for the template slots; a slot with nothing to say is absent.
+pub type Slots = HashMap<&'static str, String>;
+
+fn one() -> f32 {
+    1.0
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Drive {
+    pub name: String,
+    pub template: String,
+    #[serde(default = "one")]
+    pub base: f32,
+    /// Level -> coefficient (see LEVELS).
+    #[serde(default)]
+    pub by: HashMap<String, f32>,
+}
+
+impl Drive {
+    /// base * max(0, 1 + Σ level × coefficient); levels not given count as 0.
+    pub fn weight(&self, levels: &[(&str, f32)]) -> f32 {
+        let lift: f32 = self.by.iter()
+            .map(|(name, coef)| coef * levels.iter().find(|(l, _)| *l == name.as_str()).map_or(0.0, |(_, v)| *v))
+            .sum();
+        self.base.max(0.0) * (1.0 + lift).max(0.0)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RepertoireFile {
+    #[serde(default)]
+    drive: Vec<Drive>,
+}
+
+/// The chemistry and genome as the weights see them.
+pub fn levels(chem: &Neurotransmitters, genome: &Genome) -> Vec<(&'static str, f32)> {
+    vec![
+        ("dopamine", chem.dopamine),
+        ("cortisol", chem.cortisol),
+        ("adenosine", chem.adenosine),
+        ("oxytocin", chem.oxytocin),
+        ("serotonin", chem.serotonin),
+        ("curiosity", genome.curiosity),
+        ("stress_tolerance", genome.stress_tolerance),
+    ]
+}
+
+/// The {slot} names a template uses, in order.
+pub fn slots_in(template: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else { break };
+        names.push(&rest[open + 1..open + close]);
+        rest = &rest[open + close + 1..];
+    }
+    names
+}
+
+/// The template with every {slot} replaced; None if any slot is missing or empty.
+pub fn fill(template: &str, slots: &Slots) -> Option<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else { break };
+        let value = slots.get(&rest[open + 1..open + close]).filter(|v| !v.trim().is_empty())?;
+        out.push_str(&rest[..open]);
+        out.push_str(value.trim());
+        rest = &rest[open + close + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+/// "la CPU al 93%" / "cansancio": the body state furthest above where it gets noticed.
+pub fn body_phrase(body: &BodyStatus, chem: &Neurotransmitters) -> Option<String> {
+    let candidates = [
+        (body.cpu_usage / 100.0 - 0.7, format!("la CPU al {:.0}%", body.cpu_usage)),
+        (body.ram_usage - 0.8, format!("la memoria al {:.0}%", body.ram_usage * 100.0)),
+        (chem.cortisol - 0.6, "tensión".to_string()),
+        (chem.adenosine - 0.7, "cansancio".to_string()),
+    ];
+    candidates.into_iter()
+        .filter(|(salience, _)| *salience > 0.0)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, phrase)| phrase)
+}
+
+/// A word for the chemistry.
+pub fn mood_word(chem: &Neurotransmitters) -> &'static str {
+    if chem.cortisol > 0.5 {
+        "inquieto"
+    } else if chem.adenosine > 0.6 {
+        "cansado"
+    } else if chem.dopamine > 0.5 {
+        "curioso"
+    } else {
+        "tranquilo"
+    }
+}
+
+/// What the loudest band makes of the music.
+pub fn music_phrase(spec: &AudioSpectrum) -> &'static str {
+    if spec.bass >= spec.mids && spec.bass >= spec.highs {
+        "algo con mucho bajo"
+    } else if spec.mids >= spec.highs {
+        "una melodía"
+    } else {
+        "algo brillante y agudo"
+    }
+}
+
+/// One draw.
+#[derive(Debug, Clone)]
+pub struct Choice {
+    pub drive: String,
+    pub prompt: String,
+    pub probability: f32,
+    /// Slots the prompt used ("memory" once asked about is spent).
+    pub slots: Vec<String>,
+}
+
+/// Per-drive counters for /telemetry.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DriveStats {
+    pub name: String,
+    pub chosen: u64,
+    pub spoken: u64,
+    pub answered: u64,
+}
+
+struct Pending {
+    drive: usize,
+    chosen_at: Instant,
+    spoken_at: Option<Instant>,
+}
+
+pub struct Repertoire {
+    config: RepertoireConfig,
+    drives: Vec<Drive>,
+    stats: Vec<DriveStats>,
+    pending: Option<Pending>,
+    music_since: Option<Instant>,
+    music: &'static str,
+}
+
+impl Repertoire {
+    /// The configured file if present and valid, the built-in repertoire otherwise.
+    pub fn load(config: RepertoireConfig) -> Self {
+        let drives = match fs::read_to_string(&config.path).map(|s| parse(&s)) {
+            Ok(Ok(drives)) => {
+                println!("🎭 REPERTOIRE: {} drives loaded from {}.", drives.len(), config.path);
+                drives
+            }
+            Ok(Err(e)) => {
+                println!("⚠️ REPERTOIRE: {} is invalid ({}). Using the built-in repertoire.", config.path, e);
+                builtin()
+            }
+            Err(_) => builtin(),
+        };
+        Self::with_drives(config, drives)
+    }
+
+    pub fn with_drives(config: RepertoireConfig, drives: Vec<Drive>) -> Self {
+        let stats = drives.iter().map(|d| DriveStats { name: d.name.clone(), ..Default::default() }).collect();
+        Self { config, drives, stats, pending: None, music_since: None, music: "" }
+    }
+
+    /// Eligible drives (every slot filled) and their weights.
+    pub fn weights(&self, levels: &[(&str, f32)], slots: &Slots) -> Vec<(usize, f32)> {
+        self.drives.iter()
+            .enumerate()
+            .filter(|(_, d)| fill(&d.template, slots).is_some())
+            .map(|(i, d)| (i, d.weight(levels)))
+            .filter(|(_, w)| *w > 0.0)
+            .collect()
+    }
+
+    /// Draws a drive with `roll` in [0, 1) (rand::random in the daemon) and
+    /// remembers it until it is spoken or answered. None if no drive is eligible.
+    pub fn choose(&mut self, levels: &[(&str, f32)], slots: &Slots, roll: f32, now: Instant) -> Option<Choice> {
+        let weights = self.weights(levels, slots);
+        let total: f32 = weights.iter().map(|(_, w)| w).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = roll.clamp(0.0, 1.0) * total;
+        let &(idx, weight) = weights.iter()
+            .find(|(_, w)| {
+                target -= w;
+                target < 0.0
+            })
+            .unwrap_or(weights.last()?);
+        self.stats[idx].chosen += 1;
+        self.pending = Some(Pending { drive: idx, chosen_at: now, spoken_at: None });
+        let drive = &self.drives[idx];
+        Some(Choice {
+            drive: drive.name.clone(),
+            prompt: fill(&drive.template, slots)?,
+            probability: weight / total,
+            slots: slots_in(&drive.template).into_iter().map(str::to_string).collect(),
+        })
+    }
+
+    /// An utterance passed the gate: credits the pending drive if it was drawn
+    /// within speak_secs (the Planet's latency) and opens its response window.
+    pub fn spoke(&mut self, now: Instant) -> Option<&str> {
+        let window = Duration::from_secs_f32(self.config.speak_secs.max(0.0));
+        let pending = self.pending.as_mut().filter(|p| p.spoken_at.is_none())?;
+        if now.duration_since(pending.chosen_at) > window {
+            self.pending = None;
+            return None;
+        }
+        pending.spoken_at = Some(now);
+        let idx = pending.drive;
+        self.stats[idx].spoken += 1;
+        Some(&self.drives[idx].name)
+    }
+
+    /// A human said something: credits the drive that spoke within
+    /// response_secs. Either way the pending drive is settled (an answer to
+    /// the human is not the drive's utterance).
+    pub fn heard(&mut self, now: Instant) -> Option<&str> {
+        let pending = self.pending.take()?;
+        let window = Duration::from_secs_f32(self.config.response_secs.max(0.0));
+        let spoken_at = pending.spoken_at?;
+        if now.duration_since(spoken_at) > window {
+            return None;
+        }
+        self.stats[pending.drive].answered += 1;
+        Some(&self.drives[pending.drive].name)
+    }
+
+    /// One spectrum packet: music-like energy that is not a voice keeps the
+    /// "music" slot warming up; anything else resets it.
+    pub fn listen(&mut self, spec: &AudioSpectrum, now: Instant) {
+        let energy = spec.bass + spec.mids + spec.highs;
+        if energy > 0.6 && energy < 1.4 && !spec.is_voice {
+            self.music_since.get_or_insert(now);
+            self.music = music_phrase(spec);
+        } else {
+            self.music_since = None;
+        }
+    }
+
+    /// What the music slot says, once it has lasted music_secs.
+    pub fn music(&self, now: Instant) -> Option<String> {
+        let since = self.music_since?;
+        (now.duration_since(since).as_secs_f32() >= self.config.music_secs).then(|| self.music.to_string())
+    }
+
+    pub fn low_confidence(&self) -> f32 {
+        self.config.low_confidence
+    }
+
+    pub fn stats(&self) -> Vec<DriveStats> {
+        self.stats.clone()
+    }
+}
+
+fn builtin() -> Vec<Drive> {
+    parse(BUILTIN).expect("built-in repertoire.toml is valid")
+}
+
+/// Drives from the file, with unknown levels and slots rejected (a typo would
+/// otherwise silently weigh 0 or make the drive never eligible).
+fn parse(source: &str) -> Result<Vec<Drive>> {
+    let file: RepertoireFile = toml::from_str(source)?;
+    for drive in &file.drive {
+        if let Some(level) = drive.by.keys().find(|l| !LEVELS.contains(&l.as_str())) {
+            bail!("drive '{}': unknown level '{}' (known: {})", drive.name, level, LEVELS.join(", "));
+        }
+        if let Some(slot) = slots_in(&drive.template).into_iter().find(|s| !SLOTS.contains(s)) {
+            bail!("drive '{}': unknown slot '{{{}}}' (known: {})", drive.name, slot, SLOTS.join(", "));
+        }
+    }
+    Ok(file.drive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEVELS: [(&str, f32); 2] = [("oxytocin", 0.4), ("cortisol", 0.2)];
+
+    fn slots(pairs: &[(&'static str, &str)]) -> Slots {
+        pairs.iter().map(|&(k, v)| (k, v.to_string())).collect()
+    }
+
+    fn drive(name: &str, template: &str, base: f32, by: &[(&str, f32)]) -> Drive {
+        Drive {
+            name: name.to_string(),
+            template: template.to_string(),
+            base,
+            by: by.iter().map(|&(k, v)| (k.to_string(), v)).collect(),
+        }
+    }
+
+    fn social() -> Drive {
+        drive("social", "{person}", 1.0, &[("oxytocin", 1.5), ("cortisol", -0.5)])
+    }
+
+    fn somatic() -> Drive {
+        drive("somatic", "{body}", 2.0, &[("cortisol", 1.0)])
+    }
+
+    #[test]
+    fn templates_fill_every_slot_or_nothing() {
+        let filled = fill("Hola {person}, hace {silence} minutos.", &slots(&[("person", "Marta"), ("silence", "12")]));
+        assert_eq!(filled.as_deref(), Some("Hola Marta, hace 12 minutos."));
+        // A missing or blank slot
+        assert_eq!(fill("Hola {person}.", &slots(&[])), None);
+        assert_eq!(fill("Hola {person}.", &slots(&[("person", "  ")])), None);
+        // No slots, an unclosed brace
+        assert_eq!(fill("Sin huecos.", &slots(&[])).as_deref(), Some("Sin huecos."));
+        assert_eq!(fill("{person} dijo {algo", &slots(&[("person", "Ana")])).as_deref(), Some("Ana dijo {algo"));
+        assert_eq!(slots_in("{a} y {b}, {a}"), vec!["a", "b", "a"]);
+    }
+
+    #[test]
+    fn weights_match_hand_computed_values() {
+        assert!((social().weight(&LEVELS) - 1.0 * (1.0 + 0.6 - 0.1)).abs() <= 1e-5, "social {}", social().weight(&LEVELS));
+        assert!((somatic().weight(&LEVELS) - 2.0 * (1.0 + 0.2)).abs() <= 1e-5, "somatic {}", somatic().weight(&LEVELS));
+        // Floored at 0; an unlisted level leaves the base
+        assert_eq!(drive("shy", "x", 1.0, &[("cortisol", -3.0)]).weight(&[("cortisol", 0.5)]), 0.0);
+        assert!((drive("calm", "x", 0.5, &[("serotonin", 2.0)]).weight(&[]) - 0.5).abs() <= 1e-6);
+    }
+
+    #[test]
+    fn the_draw_follows_the_weights_among_eligible_drives() {
+        // 1.5 : 2.4 over a uniform roll
+        let t0 = Instant::now();
+        let mut rep = Repertoire::with_drives(RepertoireConfig::default(), vec![social(), somatic()]);
+        let both = slots(&[("person", "Marta"), ("body", "tensión")]);
+        let n = 1000;
+        let picks = (0..n).filter(|&i| rep.choose(&LEVELS, &both, (i as f32 + 0.5) / n as f32, t0).is_some_and(|c| c.drive == "social")).count();
+        let share = picks as f32 / n as f32;
+        assert!((share - 1.5 / 3.9).abs() <= 0.01, "social {:.3} of the rolls, expected {:.3}", share, 1.5 / 3.9);
+        let choice = rep.choose(&LEVELS, &both, 0.0, t0).unwrap();
+        assert_eq!(choice.prompt, "Marta");
+        assert!((choice.probability - 1.5 / 3.9).abs() < 1e-5, "p={:.3}", choice.probability);
+
+        // No person -> only somatic; nothing -> no draw
+        let choice = rep.choose(&LEVELS, &slots(&[("body", "tensión")]), 0.0, t0).unwrap();
+        assert_eq!(choice.drive, "somatic");
+        assert_eq!(choice.probability, 1.0);
+        assert!(rep.choose(&LEVELS, &slots(&[]), 0.5, t0).is_none());
+    }
+
+    #[test]
+    fn spoken_and_answered_count_only_inside_their_windows() {
+        let config = RepertoireConfig::default();
+        let t0 = Instant::now();
+        let secs = Duration::from_secs_f32;
+        let mut rep = Repertoire::with_drives(config.clone(), vec![social()]);
+        let person = slots(&[("person", "Marta")]);
+        // Spoken within speak_secs, answered within response_secs
+        rep.choose(&LEVELS, &person, 0.3, t0);
+        assert_eq!(rep.spoke(t0 + secs(2.0)), Some("social"));
+        assert_eq!(rep.spoke(t0 + secs(3.0)), None, "spoken twice");
+        assert_eq!(rep.heard(t0 + secs(2.0 + config.response_secs * 0.5)), Some("social"));
+        // An answer too late
+        rep.choose(&LEVELS, &person, 0.3, t0);
+        rep.spoke(t0 + secs(1.0));
+        assert_eq!(rep.heard(t0 + secs(1.0 + config.response_secs + 1.0)), None);
+        // Spoken too late
+        rep.choose(&LEVELS, &person, 0.3, t0);
+        assert_eq!(rep.spoke(t0 + secs(config.speak_secs + 1.0)), None);
+        // Interrupted before it was said
+        rep.choose(&LEVELS, &person, 0.3, t0);
+        assert_eq!(rep.heard(t0 + secs(1.0)), None);
+        assert_eq!(rep.spoke(t0 + secs(2.0)), None);
+        let s = &rep.stats()[0];
+        assert_eq!((s.chosen, s.spoken, s.answered), (4, 2, 1));
+    }
+
+    #[test]
+    fn the_builtin_file_parses_and_a_typo_is_refused() {
+        assert!(parse(BUILTIN).unwrap().len() >= 4);
+        // An unknown slot, an unknown level
+        assert!(parse("[[drive]]\nname = \"x\"\ntemplate = \"{persona}\"\n").is_err());
+        assert!(parse("[[drive]]\nname = \"x\"\ntemplate = \"hola\"\nby = { dopamina = 1.0 }\n").is_err());
+    }
+}
@@ -1,10 +1,14 @@
 
 use nalgebra::{DMatrix, DVector};
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand_distr::{Distribution, Normal};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::File;
-
+use std::io::Write;
+#[cfg(not(feature = "serial-physics"))]
+use rayon::prelude::*;
 
 /// Region classification — NOT assigned, but OBSERVED from weight patterns.
 /// A neuron's region is determined by which input it responds to most strongly.
@@ -18,6 +22,11 @@ pub enum NeuronRegion {
 }
 
 impl NeuronRegion {
+    pub const ALL: [NeuronRegion; 5] = [
+        NeuronRegion::Semantic, NeuronRegion::Auditory, NeuronRegion::Limbic,
+        NeuronRegion::Association, NeuronRegion::Visual,
+    ];
+
     pub fn as_id(&self) -> u8 {
         match self {
             NeuronRegion::Semantic => 0,
@@ -29,6 +38,297 @@ impl NeuronRegion {
     }
 }
 
+/// Where a change of state came from (`attention_breakdown`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Pathway {
+    Input,     // The tick's input layer (spectrum bands + heard words)
+    Auditory,  // inject_embedding(Auditory)
+    Visual,    // inject_embedding(Visual)
+    Semantic,  // inject_embedding(Semantic): word embeddings
+    Echo,      // inject_logits / inject_hidden: the LLM's own output (or its hidden layers)
+    Memory,    // inject_embedding(Association): engrams
+    Chemistry, // How much cortisol/adenosine bent the tick (vs. a neutral tick)
+}
+
+impl Pathway {
+    pub const ALL: [Pathway; 7] = [
+        Pathway::Input, Pathway::Auditory, Pathway::Visual, Pathway::Semantic,
+        Pathway::Echo, Pathway::Memory, Pathway::Chemistry,
+    ];
+
+    fn of(region: NeuronRegion) -> Self {
+        match region {
+            NeuronRegion::Auditory => Pathway::Auditory,
+            NeuronRegion::Visual => Pathway::Visual,
+            NeuronRegion::Semantic => Pathway::Semantic,
+            NeuronRegion::Association => Pathway::Memory,
+            NeuronRegion::Limbic => Pathway::Chemistry,
+        }
+    }
+}
+
+/// Smoothing of the per-pathway drive, per tick (~0.5s memory at 60Hz).
+const ATTRIBUTION_RATE: f32 = 0.03;
+
+/// Width of an activity signature: `last_activity` averaged over this many blocks of
+/// consecutive neurons (stored with each memory, replayed during Deep sleep).
+pub const SIGNATURE_LEN: usize = 128;
+
+/// Multimodality above which a neuron counts as bimodal in `region_stats`.
+pub const MULTIMODAL: f32 = 0.5;
+
+/// Exposure a neuron may gain before the cached region map is rebuilt
+/// (specialization itself needs 0.1, see `get_region_map`).
+const REGION_DRIFT: f32 = 0.05;
+
+/// Slow global knobs set by the homeostat (1 / 1 / 0 = the reservoir as built).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Modulation {
+    pub gain: f32,       // Scales the recurrent term (effective spectral radius)
+    pub leak_scale: f32, // Scales the baseline leak rate
+    pub noise: f32,      // Background noise amplitude added before tanh
+}
+
+impl Default for Modulation {
+    fn default() -> Self {
+        Self { gain: 1.0, leak_scale: 1.0, noise: 0.0 }
+    }
+}
+
+/// One region of `region_stats` (telemetry).
+#[derive(Debug, Clone, Serialize)]
+pub struct RegionStats {
+    pub region: &'static str,
+    pub neurons: usize,
+    pub multimodality: f32, // Mean over the region's neurons (see `multimodality`)
+    pub multimodal: usize,  // Neurons above MULTIMODAL
+}
+
+/// |state| above which a neuron counts as saturated for growth demand.
+const SATURATED: f32 = 0.95;
+
+/// Smoothing of the per-field growth demand, per tick (~2s memory at 60Hz).
+const DEMAND_RATE: f32 = 0.01;
+
+/// Censuses kept by `growth_stats` (one per neurogenesis call).
+const GROWTH_HISTORY: usize = 120;
+
+/// Candidate positions tried per placement strategy of a new neuron.
+const SPAWN_TRIES: usize = 32;
+
+/// Slot of the whole brain in the demand arrays (after the five fields).
+const WHOLE_BRAIN: usize = 5;
+
+/// How hard one receptive field is working (smoothed per tick, per neuron of the field).
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct RegionDemand {
+    pub saturation: f32, // Fraction of the field's neurons at |x| > SATURATED
+    pub hebbian: f32,    // Hebbian changes onto the field's neurons
+    pub clipping: f32,   // Injections clipped at ±1 on the field's neurons
+}
+
+impl RegionDemand {
+    /// How much harder than `brain` this works, summed over the three signals
+    /// (what neurogenesis draws the parent field by). A chaotic reservoir saturates
+    /// everywhere; only what a field does beyond the brain as a whole is its own need.
+    pub fn excess(&self, brain: &RegionDemand) -> f32 {
+        (self.saturation - brain.saturation).max(0.0)
+            + (self.hebbian - brain.hebbian).max(0.0)
+            + (self.clipping - brain.clipping).max(0.0)
+    }
+}
+
+/// Neurons inside each receptive field after one neurogenesis call (`growth_stats`).
+#[derive(Debug, Clone, Serialize)]
+pub struct Census {
+    pub tick: u64,
+    pub neurons: [usize; 5], // NeuronRegion::ALL order
+}
+
+/// One receptive field of `growth_stats` (telemetry).
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldGrowth {
+    pub region: &'static str,
+    pub neurons: usize,
+    pub born: u64, // Neurons neurogenesis placed in it since boot
+    pub demand: RegionDemand,
+    pub excess: f32,      // Over the whole brain's demand (`RegionDemand::excess`)
+    pub probability: f32, // Of being the next parent field
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GrowthStats {
+    pub brain: RegionDemand, // The whole brain's demand, the baseline
+    pub fields: Vec<FieldGrowth>,
+    pub history: Vec<Census>, // Oldest first
+}
+
+/// What a stable neuron ID refers to now (`resolve`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum NeuronLookup {
+    Alive { index: usize }, // Its current position in the dense arrays
+    Deceased,               // Was born, has been removed since
+    Unborn,                 // No neuron ever had this ID
+}
+
+/// One synapse seen from one of its ends (`synapses`, GET /neuron/{id}).
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Synapse {
+    pub partner: u64, // Stable ID
+    pub region: &'static str, // The partner's
+    pub weight: f32,
+}
+
+/// Everything one neuron is (`inspect`, GET /neuron/{id}).
+#[derive(Debug, Clone, Serialize)]
+pub struct NeuronReport {
+    pub id: u64,      // Stable, what clients hold on to
+    pub index: usize, // Where it sits right now (shifts when neurons before it die)
+    pub position: [f32; 3],
+    pub region: &'static str,
+    pub activation: f32, // 0..1, 0.5 = rest (as in `last_activity`)
+    pub exposure: HashMap<&'static str, f32>,
+    pub bias: f32,
+    pub birth_tick: u64,
+    pub age_ticks: u64,
+    pub incoming_total: usize, // Nonzero synapses; the lists keep the strongest
+    pub outgoing_total: usize,
+    pub incoming: Vec<Synapse>,
+    pub outgoing: Vec<Synapse>,
+    /// Its raster row, oldest first (filled in by the HTTP side, the loop doesn't own the raster).
+    pub activity: Vec<u8>,
+}
+
+/// Synapses listed per direction by `inspect`.
+pub const INSPECT_SYNAPSES: usize = 10;
+
+/// Radius of the sphere new neurons are placed in (receptive fields are in units of it).
+pub const BRAIN_RADIUS: f32 = 40.0;
+
+fn default_brain_radius() -> f32 {
+    BRAIN_RADIUS
+}
+
+fn legacy_echo_target() -> EchoTarget {
+    EchoTarget::WholeBrain
+}
+
+/// A field coverage outside this range is reported by `ReceptiveFieldMap::validate`.
+pub const FIELD_COVERAGE: (f32, f32) = (0.02, 0.60);
+
+/// One geometric constraint of a receptive field, in brain radii (0 = center, 1 = surface).
+/// Externally tagged: checkpoints are bincode, which can't read internally tagged enums.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldShape {
+    /// normal·pos > offset (|normal·pos| > offset when mirrored: both sides)
+    HalfSpace { normal: [f32; 3], offset: f32, #[serde(default)] mirrored: bool },
+    /// inner < |pos| <= outer
+    Shell { inner: f32, outer: f32 },
+    /// Within `half_angle_deg` of `axis` (from the center), from `min_radius` out
+    Cone { axis: [f32; 3], half_angle_deg: f32, #[serde(default)] min_radius: f32 },
+}
+
+impl FieldShape {
+    /// `pos` already divided by the brain radius.
+    fn contains(&self, pos: [f32; 3]) -> bool {
+        let dot = |v: [f32; 3]| v[0] * pos[0] + v[1] * pos[1] + v[2] * pos[2];
+        let norm = |v: [f32; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        match *self {
+            FieldShape::HalfSpace { normal, offset, mirrored } => {
+                let d = dot(normal) / norm(normal).max(1e-6);
+                if mirrored { d.abs() > offset } else { d > offset }
+            },
+            FieldShape::Shell { inner, outer } => {
+                let r = norm(pos);
+                r > inner && r <= outer
+            },
+            FieldShape::Cone { axis, half_angle_deg, min_radius } => {
+                let r = norm(pos);
+                r >= min_radius && r > 1e-6
+                    && dot(axis) / (norm(axis).max(1e-6) * r) >= half_angle_deg.to_radians().cos()
+            },
+        }
+    }
+}
+
+/// Where the LLM's echo (`inject_logits` / `inject_pooled`) lands. Saved with the
+/// brain: a new brain starts on Semantic, a save from before it keeps WholeBrain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EchoTarget {
+    /// The Semantic receptive field only, vocabulary laid along it by position.
+    #[default]
+    Semantic,
+    /// Every neuron, chunk i on neuron i (legacy: brains whose regions formed under it).
+    WholeBrain,
+}
+
+/// Where in the sphere each modality is allowed to land: a neuron is in a region's
+/// field when every shape of it holds. Saved with the brain; `brain.receptive_fields`
+/// in config overrides it for experiments.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReceptiveFieldMap {
+    pub semantic: Vec<FieldShape>,
+    pub auditory: Vec<FieldShape>,
+    pub limbic: Vec<FieldShape>,
+    pub association: Vec<FieldShape>,
+    pub visual: Vec<FieldShape>,
+}
+
+impl Default for ReceptiveFieldMap {
+    /// The original planes at radius 40 (|x|>25, z>20, y<-20, y>20 && z<10, z<-25).
+    fn default() -> Self {
+        let plane = |normal: [f32; 3], offset: f32| FieldShape::HalfSpace { normal, offset, mirrored: false };
+        Self {
+            semantic: vec![plane([0.0, 0.0, 1.0], 0.5)], // Frontal Lobe (Front)
+            auditory: vec![FieldShape::HalfSpace { normal: [1.0, 0.0, 0.0], offset: 0.625, mirrored: true }], // Lateral Temporal Lobes (Sides)
+            limbic: vec![plane([0.0, -1.0, 0.0], 0.5)], // Deep/Basal Ganglia (Bottom)
+            association: vec![plane([0.0, 1.0, 0.0], 0.5), plane([0.0, 0.0, -1.0], -0.25)], // Parietal (Top/Rear)
+            visual: vec![plane([0.0, 0.0, -1.0], 0.625)], // Occipital (Back)
+        }
+    }
+}
+
+impl ReceptiveFieldMap {
+    pub fn shapes(&self, region: NeuronRegion) -> &[FieldShape] {
+        match region {
+            NeuronRegion::Semantic => &self.semantic,
+            NeuronRegion::Auditory => &self.auditory,
+            NeuronRegion::Limbic => &self.limbic,
+            NeuronRegion::Association => &self.association,
+            NeuronRegion::Visual => &self.visual,
+        }
+    }
+
+    /// Is a neuron at `pos` (brain units) inside `region`'s field? An empty field covers nothing.
+    pub fn contains(&self, region: NeuronRegion, pos: [f32; 3], radius: f32) -> bool {
+        let shapes = self.shapes(region);
+        let unit = [pos[0] / radius, pos[1] / radius, pos[2] / radius];
+        !shapes.is_empty() && shapes.iter().all(|s| s.contains(unit))
+    }
+
+    /// Fraction of `positions` inside each field, in NeuronRegion::ALL order.
+    pub fn coverage(&self, positions: &[[f32; 3]], radius: f32) -> [(NeuronRegion, f32); 5] {
+        NeuronRegion::ALL.map(|r| {
+            let inside = positions.iter().filter(|&&p| self.contains(r, p, radius)).count();
+            (r, inside as f32 / positions.len().max(1) as f32)
+        })
+    }
+
+    /// One warning per field covering less or more than FIELD_COVERAGE of the neurons.
+    pub fn validate(&self, positions: &[[f32; 3]], radius: f32) -> Vec<String> {
+        let (low, high) = FIELD_COVERAGE;
+        self.coverage(positions, radius).iter()
+            .filter(|(_, f)| *f < low || *f > high)
+            .map(|(r, f)| format!("{} receptive field covers {:.1}% of {} neurons (sane: {:.0}-{:.0}%)",
+                region_name(r.as_id()), f * 100.0, positions.len(), low * 100.0, high * 100.0))
+            .collect()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FractalReservoir {
     pub size: usize,
@@ -46,7 +346,9 @@ pub struct FractalReservoir {
     semantic_exposure: Vec<f32>,   // Accumulated activation from LLM logits
     auditory_exposure: Vec<f32>,   // Accumulated activation from audio
     limbic_exposure: Vec<f32>,     // Accumulated activation from chemistry
+    #[serde(default)] // Saves from before Phase 6 lack it (padded on load)
     association_exposure: Vec<f32>, // Accumulated activation from memory engrams (Phase 6)
+    #[serde(default)] // Saves from before Phase 7 lack it (padded on load)
     visual_exposure: Vec<f32>,      // Accumulated activation from visual input (Phase 7)
 
     /// SPATIAL TOPOLOGY (Phase 1: Fractal Brain)
@@ -55,6 +357,103 @@ pub struct FractalReservoir {
     /// This makes clustering EMERGENT from geometry, not hardcoded.
     #[serde(default)]
     positions: Vec<[f32; 3]>,
+    #[serde(default = "default_brain_radius")]
+    brain_radius: f32,
+    /// Where each modality lands (saves from before it load the original planes).
+    #[serde(default)]
+    receptive_fields: ReceptiveFieldMap,
+    /// Where the echo lands (`brain.echo_target` in config overrides it).
+    #[serde(default = "legacy_echo_target")] // Saves from before it grew their regions under the whole-brain echo
+    echo_target: EchoTarget,
+
+    /// AGE: physics ticks lived, and the tick each neuron was born on (genesis = 0;
+    /// saves from before it load with every neuron counted as genesis).
+    #[serde(default)]
+    ticks: u64,
+    #[serde(default)]
+    birth_tick: Vec<u64>,
+
+    /// IDENTITY: a stable ID per neuron, assigned at birth and never reused, so what
+    /// outside systems hold (inspector, lesions, clients) survives neurons dying.
+    /// Neurons are born in ID order and removal keeps the order, so `ids` stays
+    /// sorted and ID -> index is a binary search (index -> ID is `ids[i]`). Saves
+    /// from before it load numbered 0..size.
+    #[serde(default)]
+    ids: Vec<u64>,
+    #[serde(default)]
+    next_id: u64,
+
+    /// DEEP IDLE: region exposure is not accumulated while nothing is happening.
+    #[serde(skip)]
+    exposure_paused: bool,
+
+    /// Neurons born since the last drain (the metabolism bills growth).
+    #[serde(skip)]
+    growth_events: u32,
+
+    /// LESION: neurons silenced by an experiment (empty = none). Their state is held
+    /// at 0 and nothing drives them; their weights are left alone. Not persisted.
+    #[serde(skip)]
+    lesion: Vec<bool>,
+
+    /// Memory pressure (core/pressure.rs): neurogenesis stops at this size (None = 2500). Not persisted.
+    #[serde(skip)]
+    growth_ceiling: Option<usize>,
+
+    /// Synapses pruned since the last drain (announced as Apoptosis).
+    #[serde(skip)]
+    pruned_events: u32,
+
+    /// Sum of |Δw| over the recurrent weights since boot (live "how much did I learn").
+    #[serde(skip)]
+    plasticity: f64,
+
+    /// Homeostatic modulation (live control state, re-learned after a restart).
+    #[serde(skip)]
+    modulation: Modulation,
+    #[serde(skip)]
+    noise_state: u64, // xorshift: cheap, and reproducible for seeded reservoirs
+
+    /// Cached region map (see `region_map`): rebuilt after neurogenesis or apoptosis, or
+    /// once the exposures drifted REGION_DRIFT since the last scan, not on every read.
+    #[serde(skip)]
+    region_cache: Vec<u8>,
+    #[serde(skip)]
+    region_drift: f32, // Sum of the largest per-neuron exposure gain of each update since the scan
+    #[serde(skip)]
+    topology_version: u64, // Bumped whenever the region map (or the neuron count) actually changes
+    #[serde(skip)]
+    region_scans: u64, // Full scans done by `region_map` (the cache is doing its job if this stays low)
+
+    /// ATTRIBUTION: norm of what each pathway pushed into the state since the last
+    /// tick (`drive`), and its smoothed value (`attribution`), indexed like Pathway::ALL.
+    #[serde(skip)]
+    drive: [f32; 7],
+    #[serde(skip)]
+    attribution: [f32; 7],
+
+    /// An input wider than `input_weights` was truncated (warned once per boot).
+    #[serde(skip)]
+    input_overflow: bool,
+
+    /// GROWTH DEMAND: receptive fields of each neuron (bit = NeuronRegion::as_id,
+    /// rebuilt when the size or the map changes), per field the hebbian and clipping
+    /// events since the last tick and the smoothed demand (then the whole brain's,
+    /// at WHOLE_BRAIN), neurons placed per field and the censuses after each growth.
+    /// Live state, re-learned after a restart.
+    #[serde(skip)]
+    field_bits: Vec<u8>,
+    /// `echo_field`, kept until the map or the neuron count changes.
+    #[serde(skip)]
+    echo_order: Option<Vec<usize>>,
+    #[serde(skip)]
+    demand_events: [[f32; 2]; 6],
+    #[serde(skip)]
+    demand: [RegionDemand; 6],
+    #[serde(skip)]
+    born: [u64; 5],
+    #[serde(skip)]
+    growth_history: std::collections::VecDeque<Census>,
 
     // NEURAL WEIGHTS (Now Persisted!)
     weights: DMatrix<f32>,
@@ -63,26 +462,48 @@ pub struct FractalReservoir {
     bias: DVector<f32>,
 }
 
+/// A point uniformly distributed in the ball of `radius` around the origin.
+fn point_in_sphere<R: Rng + ?Sized>(rng: &mut R, radius: f32) -> [f32; 3] {
+    let theta = rng.random::<f32>() * std::f32::consts::TAU;
+    let phi = (2.0 * rng.random::<f32>() - 1.0).acos();
+    let r = radius * rng.random::<f32>().cbrt(); // Uniform volume distribution
+    [
+        r * phi.sin() * theta.cos(),
+        r * phi.sin() * theta.sin(),
+        r * phi.cos(),
+    ]
+}
 
+/// One genesis input synapse: 15% wired, uniform -1..1 (also used when inputs grow).
+fn genesis_input_weight<R: Rng + ?Sized>(rng: &mut R) -> f32 {
+    if rng.random::<f32>() < 0.15 {
+        rng.random::<f32>() * 2.0 - 1.0
+    } else {
+        0.0
+    }
+}
 
 impl FractalReservoir {
     pub fn new(size: usize, input_size: usize, spectral_radius: f32, leak_rate: f32) -> Self {
-        let mut rng = rand::thread_rng();
+        Self::build(size, input_size, spectral_radius, leak_rate, &mut rand::rng())
+    }
+
+    /// Same reservoir for the same seed (offline checks, reproducible experiments).
+    pub fn seeded(size: usize, input_size: usize, spectral_radius: f32, leak_rate: f32, seed: u64) -> Self {
+        let mut reservoir = Self::build(size, input_size, spectral_radius, leak_rate, &mut StdRng::seed_from_u64(seed));
+        reservoir.noise_state = seed | 1;
+        reservoir
+    }
+
+    fn build<R: Rng>(size: usize, input_size: usize, spectral_radius: f32, leak_rate: f32, rng: &mut R) -> Self {
         let normal = Normal::new(0.0, 1.0).unwrap();
 
         // === SPATIAL TOPOLOGY ===
         // Generate neuron positions in a sphere (radius ~40 units)
-        let brain_radius: f32 = 40.0;
+        let brain_radius = BRAIN_RADIUS;
         let mut positions = Vec::with_capacity(size);
         for _ in 0..size {
-            let theta = rng.gen::<f32>() * std::f32::consts::TAU;
-            let phi = (2.0 * rng.gen::<f32>() - 1.0).acos();
-            let r = brain_radius * rng.gen::<f32>().cbrt(); // Uniform volume distribution
-            positions.push([
-                r * phi.sin() * theta.cos(),
-                r * phi.sin() * theta.sin(),
-                r * phi.cos(),
-            ]);
+            positions.push(point_in_sphere(rng, brain_radius));
         }
 
         // === DISTANCE-DEPENDENT CONNECTIVITY ===
@@ -102,22 +523,16 @@ impl FractalReservoir {
             let long_range_prob = 0.005; // ~0.5% chance regardless of distance
             let prob = local_prob.min(0.3) + long_range_prob;
             
-            if rng.gen::<f32>() < prob {
-                normal.sample(&mut rng) as f32 * spectral_radius
+            if rng.random::<f32>() < prob {
+                normal.sample(&mut *rng) as f32 * spectral_radius
             } else {
                 0.0
             }
         });
 
-        let input_weights = DMatrix::from_fn(size, input_size, |_, _| {
-             if rng.gen::<f32>() < 0.15 {
-                 (rng.gen::<f32>() * 2.0 - 1.0) * 1.0
-             } else {
-                 0.0
-             }
-        });
+        let input_weights = DMatrix::from_fn(size, input_size, |_, _| genesis_input_weight(&mut *rng));
 
-        let bias = DVector::from_fn(size, |_, _| rng.gen::<f32>() * 0.1);
+        let bias = DVector::from_fn(size, |_, _| rng.random::<f32>() * 0.1);
 
         Self {
             size,
@@ -133,7 +548,35 @@ impl FractalReservoir {
             limbic_exposure: vec![0.0; size],
             association_exposure: vec![0.0; size],
             visual_exposure: vec![0.0; size],
+            exposure_paused: false,
+            lesion: Vec::new(),
+            growth_ceiling: None,
+            growth_events: 0,
+            pruned_events: 0,
+            plasticity: 0.0,
+            modulation: Modulation::default(),
+            noise_state: 0x9E37_79B9_7F4A_7C15,
+            region_cache: Vec::new(),
+            region_drift: 0.0,
+            topology_version: 0,
+            region_scans: 0,
+            drive: [0.0; 7],
+            attribution: [0.0; 7],
+            input_overflow: false,
+            field_bits: Vec::new(),
+            echo_order: None,
+            demand_events: [[0.0; 2]; 6],
+            demand: [RegionDemand::default(); 6],
+            born: [0; 5],
+            growth_history: std::collections::VecDeque::new(),
             positions,
+            brain_radius,
+            receptive_fields: ReceptiveFieldMap::default(),
+            echo_target: EchoTarget::default(),
+            ticks: 0,
+            birth_tick: vec![0; size],
+            ids: (0..size as u64).collect(),
+            next_id: size as u64,
             weights,
             input_weights,
             state: DVector::zeros(size),
@@ -143,7 +586,11 @@ impl FractalReservoir {
     
     /// Load from disk or create new
     pub fn load(size: usize, leak_rate: f32) -> Self {
-        let path = "reservoir.json";
+        Self::load_from("reservoir.json", size, leak_rate)
+    }
+
+    /// `load` from any path: upgrades old saves, falls back to genesis.
+    pub fn load_from(path: &str, size: usize, leak_rate: f32) -> Self {
         if let Ok(file) = File::open(path) {
             let reader = std::io::BufReader::new(file);
             match serde_json::from_reader::<_, Self>(reader) {
@@ -154,13 +601,13 @@ impl FractalReservoir {
                     // Regenerate positions if missing (old saves pre-spatial)
                     if loaded.positions.len() < loaded.size {
                         println!("🗺️  SPATIAL UPGRADE: Generating positions for {} neurons", loaded.size);
-                        let mut rng = rand::thread_rng();
-                        let brain_radius: f32 = 40.0;
+                        let mut rng = rand::rng();
+                        let brain_radius = loaded.brain_radius;
                         loaded.positions = Vec::with_capacity(loaded.size);
                         for _ in 0..loaded.size {
-                            let theta = rng.gen::<f32>() * std::f32::consts::TAU;
-                            let phi = (2.0 * rng.gen::<f32>() - 1.0).acos();
-                            let r = brain_radius * rng.gen::<f32>().cbrt();
+                            let theta = rng.random::<f32>() * std::f32::consts::TAU;
+                            let phi = (2.0 * rng.random::<f32>() - 1.0).acos();
+                            let r = brain_radius * rng.random::<f32>().cbrt();
                             loaded.positions.push([
                                 r * phi.sin() * theta.cos(),
                                 r * phi.sin() * theta.sin(),
@@ -169,14 +616,17 @@ impl FractalReservoir {
                         }
                     }
 
-                    // Phase 6: Association Exposure (Backwards Compat)
-                    if loaded.association_exposure.len() != loaded.size {
-                        loaded.association_exposure = vec![0.0; loaded.size];
+                    // Phase 6/7: Association & Visual Exposure (Backwards Compat)
+                    // Missing in old saves, and neurons born before neurogenesis tracked
+                    // vision had none: pad with 0 (unspecialized), keep what was learned
+                    let size = loaded.size;
+                    for exposure in [&mut loaded.association_exposure, &mut loaded.visual_exposure] {
+                        exposure.resize(size, 0.0);
                     }
-
-                    // Phase 7: Visual Exposure (Backwards Compat)
-                    if loaded.visual_exposure.len() != loaded.size {
-                        loaded.visual_exposure = vec![0.0; loaded.size];
+                    loaded.birth_tick.resize(size, 0);
+                    loaded.adopt_ids();
+                    for warning in loaded.receptive_fields.validate(&loaded.positions, loaded.brain_radius) {
+                        println!("⚠️ RECEPTIVE FIELD: {}", warning);
                     }
                     
                     return loaded;
@@ -191,17 +641,335 @@ impl FractalReservoir {
         Self::new(size, size, 0.95, leak_rate)
     }
 
+    /// Replace the receptive fields (config experiments). Returns the coverage
+    /// warnings of the new map on this brain (applied either way).
+    pub fn set_receptive_fields(&mut self, map: ReceptiveFieldMap) -> Vec<String> {
+        self.receptive_fields = map;
+        self.field_bits.clear();
+        self.echo_order = None;
+        self.receptive_fields.validate(&self.positions, self.brain_radius)
+    }
+
+    pub fn receptive_fields(&self) -> &ReceptiveFieldMap {
+        &self.receptive_fields
+    }
+
+    /// Where the LLM's echo lands from now on (`brain.echo_target`).
+    pub fn set_echo_target(&mut self, target: EchoTarget) {
+        self.echo_target = target;
+    }
+
+    pub fn echo_target(&self) -> EchoTarget {
+        self.echo_target
+    }
+
+    /// The Semantic field's neurons in Z-order of their positions: neurons close
+    /// along it are close in space, so neighboring vocabulary lands on neighboring
+    /// cortex. Empty if the field covers no neuron. Sorted once per map and size.
+    fn echo_field(&mut self) -> &[usize] {
+        if self.echo_order.is_none() {
+            let mask = self.field_mask(NeuronRegion::Semantic);
+            let mut field: Vec<(u64, usize)> = (0..self.size)
+                .filter(|&i| mask[i])
+                .map(|i| (morton(self.positions[i], self.brain_radius), i))
+                .collect();
+            field.sort_unstable();
+            self.echo_order = Some(field.into_iter().map(|(_, i)| i).collect());
+        }
+        self.echo_order.as_deref().unwrap_or_default()
+    }
+
+    /// Per neuron: inside `region`'s receptive field?
+    fn field_mask(&self, region: NeuronRegion) -> Vec<bool> {
+        (0..self.size)
+            .map(|i| self.positions.get(i).is_some_and(|&p| self.receptive_fields.contains(region, p, self.brain_radius)))
+            .collect()
+    }
+
+    /// Receptive fields a point lies in (bit = NeuronRegion::as_id).
+    fn fields_at(&self, pos: [f32; 3]) -> u8 {
+        NeuronRegion::ALL.iter()
+            .filter(|&&r| self.receptive_fields.contains(r, pos, self.brain_radius))
+            .fold(0, |bits, r| bits | 1 << r.as_id())
+    }
+
+    /// Rebuild `field_bits` after a map change (neurogenesis keeps it current).
+    fn refresh_fields(&mut self) {
+        if self.field_bits.len() != self.size {
+            self.field_bits = (0..self.size).map(|i| self.positions.get(i).map_or(0, |&p| self.fields_at(p))).collect();
+        }
+    }
+
+    /// One growth-demand event (0 = hebbian, 1 = clipping) on neuron `i`'s fields.
+    fn demand_event(&mut self, i: usize, kind: usize) {
+        let bits = self.field_bits.get(i).copied().unwrap_or(0) | 1 << WHOLE_BRAIN;
+        for (r, events) in self.demand_events.iter_mut().enumerate() {
+            if bits & (1 << r) != 0 {
+                events[kind] += 1.0;
+            }
+        }
+    }
+
+    /// Injections that overshot ±1 on these neurons (clipped), as growth demand.
+    fn count_clipped(&mut self, clipped: Vec<usize>) {
+        self.refresh_fields();
+        for i in clipped {
+            self.demand_event(i, 1);
+        }
+    }
+
+    /// Fold this tick's saturation and the events since the last tick into the
+    /// smoothed demand, each per neuron of the field.
+    fn update_demand(&mut self) {
+        self.refresh_fields();
+        let mut members = [0usize; 6];
+        let mut saturated = [0usize; 6];
+        for (i, &bits) in self.field_bits.iter().enumerate() {
+            let hot = self.state[i].abs() > SATURATED;
+            for r in 0..6 {
+                if (bits | 1 << WHOLE_BRAIN) & (1 << r) != 0 {
+                    members[r] += 1;
+                    saturated[r] += hot as usize;
+                }
+            }
+        }
+        for r in 0..6 {
+            let n = members[r].max(1) as f32;
+            let [hebbian, clipping] = std::mem::take(&mut self.demand_events[r]);
+            let demand = &mut self.demand[r];
+            demand.saturation += DEMAND_RATE * (saturated[r] as f32 / n - demand.saturation);
+            demand.hebbian += DEMAND_RATE * (hebbian / n - demand.hebbian);
+            demand.clipping += DEMAND_RATE * (clipping / n - demand.clipping);
+        }
+    }
+
+    /// Per field, its demand in excess of the whole brain's.
+    fn growth_excess(&self) -> [f32; 5] {
+        let brain = self.demand[WHOLE_BRAIN];
+        std::array::from_fn(|r| finite(self.demand[r].excess(&brain)))
+    }
+
+    /// Each field's chance of parenting the next neuron: its excess demand over the
+    /// total (all 0 while no field works harder than the brain as a whole).
+    fn growth_odds(&self) -> [f32; 5] {
+        let excess = self.growth_excess();
+        let total: f32 = excess.iter().sum();
+        excess.map(|e| if total > 1e-6 { e / total } else { 0.0 })
+    }
+
+    /// Draw the parent field by demand (`roll` in [0, 1)); None while there is no demand.
+    fn demanded_field(&self, roll: f32) -> Option<NeuronRegion> {
+        let odds = self.growth_odds();
+        let mut cumulative = 0.0;
+        for (&region, &p) in NeuronRegion::ALL.iter().zip(&odds) {
+            cumulative += p;
+            if p > 0.0 && roll < cumulative {
+                return Some(region);
+            }
+        }
+        NeuronRegion::ALL.iter().zip(&odds).rev().find(|(_, &p)| p > 0.0).map(|(&r, _)| r) // Rounding
+    }
+
+    /// Where a neuron grown for `field` goes: within ~5 units of one of the field's
+    /// neurons (drawn by activity, so growth spreads over the busy part of the field
+    /// instead of piling on one neuron), inside the field and the brain, else anywhere
+    /// inside both, else beside that neuron. No field: near the most active neuron of all.
+    fn spawn_position<R: Rng + ?Sized>(&self, field: Option<NeuronRegion>, rng: &mut R) -> [f32; 3] {
+        let known = self.positions.len().min(self.last_activity.len());
+        let parent = match field {
+            None => (0..known)
+                .max_by(|&a, &b| self.last_activity[a].total_cmp(&self.last_activity[b]))
+                .map(|i| self.positions[i]),
+            Some(field) => {
+                let bit = 1u8 << field.as_id();
+                let members: Vec<usize> = (0..known).filter(|&i| self.field_bits.get(i).is_some_and(|b| b & bit != 0)).collect();
+                let total: f32 = members.iter().map(|&i| finite(self.last_activity[i]).max(0.0)).sum();
+                let mut roll = rng.random::<f32>() * total;
+                members.iter()
+                    .find(|&&i| {
+                        roll -= finite(self.last_activity[i]).max(0.0);
+                        roll < 0.0
+                    })
+                    .or(members.last())
+                    .map(|&i| self.positions[i])
+            },
+        };
+        let near = |p: [f32; 3], rng: &mut R| [
+            p[0] + (rng.random::<f32>() - 0.5) * 10.0,
+            p[1] + (rng.random::<f32>() - 0.5) * 10.0,
+            p[2] + (rng.random::<f32>() - 0.5) * 10.0,
+        ];
+        let Some(field) = field else {
+            return parent.map_or([0.0; 3], |p| near(p, rng));
+        };
+        let inside = |p: [f32; 3]| {
+            (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt() <= self.brain_radius
+                && self.receptive_fields.contains(field, p, self.brain_radius)
+        };
+        if let Some(p) = parent {
+            for _ in 0..SPAWN_TRIES {
+                let candidate = near(p, rng);
+                if inside(candidate) {
+                    return candidate;
+                }
+            }
+        }
+        for _ in 0..SPAWN_TRIES {
+            let candidate = point_in_sphere(rng, self.brain_radius);
+            if inside(candidate) {
+                return candidate;
+            }
+        }
+        parent.map_or([0.0; 3], |p| near(p, rng))
+    }
+
+    /// Growth demand and neurons per receptive field, and the censuses after each growth.
+    pub fn growth_stats(&self) -> GrowthStats {
+        let (excess, odds) = (self.growth_excess(), self.growth_odds());
+        GrowthStats {
+            brain: self.demand[WHOLE_BRAIN],
+            fields: NeuronRegion::ALL.iter().map(|&r| {
+                let id = r.as_id() as usize;
+                FieldGrowth {
+                    region: region_name(r.as_id()),
+                    neurons: self.positions.iter().filter(|&&p| self.receptive_fields.contains(r, p, self.brain_radius)).count(),
+                    born: self.born[id],
+                    demand: self.demand[id],
+                    excess: excess[id],
+                    probability: odds[id],
+                }
+            }).collect(),
+            history: self.growth_history.iter().cloned().collect(),
+        }
+    }
+
+    /// Neurons of `region` on the (cached) region map: what `POST /lesion` silences.
+    pub fn region_mask(&mut self, region: NeuronRegion) -> Vec<bool> {
+        let id = region.as_id();
+        self.region_map().iter().map(|&r| r == id).collect()
+    }
+
+    /// LESION EXPERIMENT: silence the masked neurons (true = silenced) until
+    /// `clear_lesion`. Every tick and every injection holds them at 0; their synapses
+    /// are kept, so clearing restores the same circuit. A shorter mask leaves the rest
+    /// intact. Returns how many neurons are silenced.
+    pub fn set_lesion(&mut self, mask: &[bool]) -> usize {
+        self.lesion = (0..self.size).map(|i| mask.get(i).copied().unwrap_or(false)).collect();
+        if !self.lesion.contains(&true) {
+            self.lesion.clear();
+        }
+        self.silence_lesion();
+        self.lesioned().len()
+    }
+
+    pub fn clear_lesion(&mut self) {
+        self.lesion.clear();
+    }
+
+    /// Indices of the silenced neurons.
+    pub fn lesioned(&self) -> Vec<usize> {
+        self.lesion.iter().enumerate().filter(|&(_, &l)| l).map(|(i, _)| i).collect()
+    }
+
+    /// Stable IDs of the silenced neurons (telemetry greys them out).
+    pub fn lesioned_ids(&self) -> Vec<u64> {
+        self.lesioned().into_iter().filter_map(|i| self.ids.get(i).copied()).collect()
+    }
+
+    /// Hold the lesioned neurons at rest (state 0, activity 0.5).
+    fn silence_lesion(&mut self) {
+        if self.lesion.is_empty() {
+            return;
+        }
+        for (i, &silenced) in self.lesion.iter().enumerate() {
+            if silenced && i < self.size {
+                self.state[i] = 0.0;
+                if let Some(a) = self.last_activity.get_mut(i) {
+                    *a = 0.5;
+                }
+            }
+        }
+    }
+
+    /// Zero what an injection would push into lesioned neurons (no drive, no exposure).
+    fn mask_lesion(&self, impact: &mut [f32]) {
+        for (x, &silenced) in impact.iter_mut().zip(&self.lesion) {
+            if silenced {
+                *x = 0.0;
+            }
+        }
+    }
+
+    /// Input channels the reservoir reads (columns of `input_weights`).
+    pub fn input_channels(&self) -> usize {
+        self.input_weights.ncols()
+    }
+
+    /// Add `n` input channels (a new sense registered, a config asking for more
+    /// inputs, or an older save than either): existing weights are kept, the new
+    /// columns are wired like at genesis (`genesis_input_weight`).
+    pub fn grow_inputs(&mut self, n: usize) {
+        if n == 0 {
+            return;
+        }
+        let mut rng = rand::rng();
+        let cols = self.input_weights.ncols();
+        let mut grown = DMatrix::zeros(self.size, cols + n);
+        for r in 0..self.size {
+            for c in 0..cols {
+                grown[(r, c)] = self.input_weights[(r, c)];
+            }
+            for c in cols..cols + n {
+                grown[(r, c)] = genesis_input_weight(&mut rng);
+            }
+        }
+        self.input_weights = grown;
+        self.input_size = cols + n;
+        self.input_overflow = false; // Wider now: warn again if it still doesn't fit
+    }
+
+    pub fn modulate(&mut self, modulation: Modulation) {
+        self.modulation = modulation;
+    }
+
+    pub fn modulation(&self) -> Modulation {
+        self.modulation
+    }
+
+    /// Uniform in [-1, 1) (xorshift64).
+    fn next_noise(&mut self) -> f32 {
+        if self.noise_state == 0 {
+            self.noise_state = 0x9E37_79B9_7F4A_7C15; // Skipped by serde: reseed after a load
+        }
+        let mut x = self.noise_state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.noise_state = x;
+        (x >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+
     pub fn set_curiosity(&mut self, curiosity: f32) {
         self.curiosity = curiosity;
     }
 
+    /// Deep idle: skip the exposure-tracking passes in `tick` (regions don't drift while asleep and alone).
+    pub fn set_exposure_paused(&mut self, paused: bool) {
+        self.exposure_paused = paused;
+    }
+
     /// Standard ESN tick — all neurons receive all input uniformly
     /// Specialization emerges through Hebbian learning, not hardcoded routing
     pub fn tick(&mut self, input: &[f32], dopamine: f32, adenosine: f32, cortisol: f32, _delta_time: f32) -> f32 {
+        self.ticks += 1;
         // Handle input size mismatch
         let expected_input_size = self.input_weights.ncols();
         let mut padded_input = vec![0.0f32; expected_input_size];
         let copy_len = input.len().min(expected_input_size);
+        if copy_len < input.len() && !self.input_overflow {
+            self.input_overflow = true;
+            println!("⚠️ RESERVOIR INPUT: {} channels arrived, {} wired; the tail is dropped (grow_inputs)", input.len(), expected_input_size);
+        }
         padded_input[..copy_len].copy_from_slice(&input[..copy_len]);
         let input_vec = DVector::from_column_slice(&padded_input);
         
@@ -211,7 +979,8 @@ impl FractalReservoir {
         // High Dopa = Lower Leak Rate (High Memory/Persistence)
         // Low Dopa = Higher Leak Rate (Scattered/Transient)
         // Base leak ~0.1. Dopa 1.0 -> 0.06 (Focus). Dopa 0.0 -> 0.1 (Base).
-        let effective_leak = self.leak_rate * (1.0 - dopamine * 0.4);
+        // HOMEOSTAT: slow baseline shift on top (see core::homeostat)
+        let effective_leak = (self.leak_rate * self.modulation.leak_scale * (1.0 - dopamine * 0.4)).clamp(0.001, 1.0);
 
         // ADENOSINE: Fatigue / Sluggishness
         // High Aden = Lower Input Gain (Hard to excite)
@@ -219,13 +988,33 @@ impl FractalReservoir {
         
         // CORTISOL: Stress / Anxiety
         // High Cort = Higher Recurrent Gain (Amplifies internal noise/loops)
-        let stress_gain = 1.0 + (cortisol * 0.8); 
+        let stress_gain = (1.0 + (cortisol * 0.8)) * self.modulation.gain;
         
         // ESN State Equation: x(t+1) = (1-a)x(t) + a*tanh(W*x(t)*stress + Win*u(t)*fatigue + bias)
-        let pre_activation = (&self.weights * &self.state) * stress_gain + (&self.input_weights * input_vec) * fatigue_gain + &self.bias;
+        let recurrent = &self.weights * &self.state;
+        let driven = &self.input_weights * input_vec;
+        let mut pre_activation = &recurrent * stress_gain + &driven * fatigue_gain + &self.bias;
+        if self.modulation.noise > 0.0 {
+            let amplitude = self.modulation.noise;
+            for x in pre_activation.iter_mut() {
+                *x += amplitude * self.next_noise();
+            }
+        }
         let update = pre_activation.map(|x| x.tanh());
         
         self.state = &self.state * (1.0 - effective_leak) + update * effective_leak;
+        self.silence_lesion();
+        self.update_demand();
+
+        // ATTRIBUTION: the input layer's share, and how far chemistry bent the update
+        // (cortisol's extra recurrent gain, adenosine's lost input gain), both leaked in
+        let chemical_bend = &recurrent * (cortisol * 0.8 * self.modulation.gain) + &driven * (fatigue_gain - 1.0);
+        self.drive[Pathway::Input as usize] += driven.norm() * fatigue_gain * effective_leak;
+        self.drive[Pathway::Chemistry as usize] += chemical_bend.norm() * effective_leak;
+        for (smoothed, drive) in self.attribution.iter_mut().zip(self.drive.iter_mut()) {
+            *smoothed += ATTRIBUTION_RATE * (*drive - *smoothed);
+            *drive = 0.0;
+        }
         
         // Track auditory exposure — neurons that activate strongly from audio input
         // accumulate auditory_exposure, naturally becoming "auditory neurons"
@@ -233,29 +1022,21 @@ impl FractalReservoir {
             (input[..copy_len.min(input.len())].iter().map(|s| s * s).sum::<f32>() / copy_len as f32).sqrt() 
         } else { 0.0 };
         
-        if audio_rms > 0.01 {
-            for i in 0..self.size {
-                let activation = ((self.state[i] + 1.0) / 2.0).max(0.0); // 0-1
-                if activation > 0.5 {
-                    // This neuron fired while audio was present → auditory exposure grows
-                    if i < self.auditory_exposure.len() {
-                        self.auditory_exposure[i] += activation * audio_rms * 0.01;
-                    }
-                }
-            }
+        if audio_rms > 0.01 && !self.exposure_paused {
+            self.region_drift += accumulate_exposure(&mut self.auditory_exposure, self.state.as_slice(), |_, x| {
+                let activation = ((x + 1.0) / 2.0).max(0.0); // 0-1
+                // This neuron fired while audio was present → auditory exposure grows
+                if activation > 0.5 { activation * audio_rms * 0.01 } else { 0.0 }
+            });
         }
         
         // Track limbic exposure — neurons that activate during strong chemistry
         let chemical_intensity = cortisol + dopamine;
-        if chemical_intensity > 0.3 {
-            for i in 0..self.size {
-                let activation = ((self.state[i] + 1.0) / 2.0).max(0.0);
-                if activation > 0.5 {
-                    if i < self.limbic_exposure.len() {
-                        self.limbic_exposure[i] += activation * chemical_intensity * 0.005;
-                    }
-                }
-            }
+        if chemical_intensity > 0.3 && !self.exposure_paused {
+            self.region_drift += accumulate_exposure(&mut self.limbic_exposure, self.state.as_slice(), |_, x| {
+                let activation = ((x + 1.0) / 2.0).max(0.0);
+                if activation > 0.5 { activation * chemical_intensity * 0.005 } else { 0.0 }
+            });
         }
         
         self.entropy = self.calculate_entropy();
@@ -266,39 +1047,57 @@ impl FractalReservoir {
     
     /// Inject LLM logits into ALL neurons through input_weights
     /// Neurons that respond strongly accumulate semantic_exposure
+    /// The LLM's raw vocabulary logits, pooled here (see `inject_pooled`).
     pub fn inject_logits(&mut self, logits: &[f32]) {
         if logits.is_empty() { return; }
-        
+        let pooled = max_pool_logits(logits, self.current_size());
+        self.inject_pooled(&pooled);
+    }
+
+    /// Inject an echo already pooled per neuron (`max_pool_logits`, done in the Planet
+    /// thread). Neurons born after it was pooled get nothing from this one.
+    /// EchoTarget::Semantic pools it again, in order, onto the Semantic field's neurons
+    /// (`echo_field`): no other neuron is touched. WholeBrain, or a Semantic field
+    /// with no neurons in it, puts chunk i on neuron i everywhere.
+    pub fn inject_pooled(&mut self, pooled: &[f32]) {
+        if pooled.is_empty() { return; }
+
         let reservoir_size = self.current_size();
-        let vocab_size = logits.len();
-        let chunk_size = (vocab_size / reservoir_size).max(1);
-        
-        let mut impact_vector = DVector::zeros(reservoir_size);
-        
-        for i in 0..reservoir_size {
-            let start = i * chunk_size;
-            let end = (start + chunk_size).min(vocab_size);
-            if start >= vocab_size { break; }
-            
-            let chunk = &logits[start..end];
-            let activity = chunk.iter().fold(0.0f32, |acc, &x| acc.max(x));
-            impact_vector[i] = (activity * 0.1).tanh();
+        let mut impact_vector: DVector<f32> = DVector::zeros(reservoir_size);
+        let field = match self.echo_target {
+            EchoTarget::Semantic => self.echo_field(),
+            EchoTarget::WholeBrain => &[],
+        };
+        if field.is_empty() {
+            per_neuron(impact_vector.as_mut_slice(), |i, impact| {
+                *impact = (pooled.get(i).copied().unwrap_or(0.0) * 0.1).tanh();
+            });
+        } else {
+            // Chunk k of the echo on the k-th field neuron (a field wider than the echo repeats values)
+            let (n, m) = (pooled.len(), field.len());
+            for (k, &i) in field.iter().enumerate() {
+                let start = (k * n / m).min(n - 1);
+                let end = ((k + 1) * n / m).clamp(start + 1, n);
+                let peak = pooled[start..end].iter().fold(0.0f32, |acc, &x| acc.max(x));
+                impact_vector[i] = (peak * 0.1).tanh();
+            }
         }
+        self.mask_lesion(impact_vector.as_mut_slice());
         
         // Apply impact
+        self.drive[Pathway::Echo as usize] += impact_vector.norm();
         self.state += &impact_vector;
+        let clipped = (0..reservoir_size).filter(|&i| self.state[i].abs() > 1.0).collect();
+        self.count_clipped(clipped);
         self.state.apply(|x| *x = x.clamp(-1.0, 1.0));
         
         // Track semantic exposure — neurons that activate from LLM input
-        for i in 0..reservoir_size {
-            let impact = impact_vector[i].abs();
-            let activation = ((self.state[i] + 1.0) / 2.0).max(0.0);
-            if impact > 0.05 && activation > 0.3 {
-                if i < self.semantic_exposure.len() {
-                    self.semantic_exposure[i] += impact * activation * 0.01;
-                }
-            }
-        }
+        let impacts = impact_vector.as_slice();
+        self.region_drift += accumulate_exposure(&mut self.semantic_exposure, self.state.as_slice(), |i, x| {
+            let impact = impacts[i].abs();
+            let activation = ((x + 1.0) / 2.0).max(0.0);
+            if impact > 0.05 && activation > 0.3 { impact * activation * 0.01 } else { 0.0 }
+        });
     }
 
     /// Direct Sensory Projection (The Glass Brain)
@@ -306,6 +1105,36 @@ impl FractalReservoir {
     /// Uses `input_weights` to project the embedding dimension (e.g. 64) up to Reservoir size (e.g. 2500).
     /// This bypasses text/language entirely.
     pub fn inject_embedding(&mut self, embedding: &[f32], region: NeuronRegion) {
+        self.project(embedding, region, Pathway::of(region));
+    }
+
+    /// The LLM's hidden-layer summary (CortexOutput.activations with a hidden layout)
+    /// onto the Semantic receptive field. Raw activations live on a scale of their own
+    /// (layer means grow with depth), so the vector is z-scored and clamped first,
+    /// then mean-pooled onto the input channels. Drive is counted as Echo: it is
+    /// still the model's own output, seen from inside.
+    pub fn inject_hidden(&mut self, hidden: &[f32]) {
+        if hidden.is_empty() { return; }
+        let n = hidden.len() as f32;
+        let mean = hidden.iter().sum::<f32>() / n;
+        let std = (hidden.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n).sqrt().max(1e-6);
+        let normalized: Vec<f32> = hidden.iter().map(|v| finite((v - mean) / std).clamp(-3.0, 3.0) / 3.0).collect();
+
+        let channels = self.input_weights.ncols().max(1);
+        let pooled: Vec<f32> = if normalized.len() <= channels {
+            normalized
+        } else {
+            (0..channels).map(|k| {
+                let chunk = &normalized[k * normalized.len() / channels..(k + 1) * normalized.len() / channels];
+                chunk.iter().sum::<f32>() / chunk.len().max(1) as f32
+            }).collect()
+        };
+        self.project(&pooled, NeuronRegion::Semantic, Pathway::Echo);
+    }
+
+    /// `input_weights · embedding` landed on `region`'s receptive field only, the drive
+    /// attributed to `pathway`, exposure tagged to the region.
+    fn project(&mut self, embedding: &[f32], region: NeuronRegion, pathway: Pathway) {
         if embedding.is_empty() { return; }
         
         // Ensure input weights match
@@ -317,61 +1146,119 @@ impl FractalReservoir {
         }
         
         let sensory_gain = 0.5; 
-        let impact = (&self.input_weights * padded_embedding) * sensory_gain;
+        let mut impact = (&self.input_weights * padded_embedding) * sensory_gain;
+        self.mask_lesion(impact.as_mut_slice());
         
-        // SPATIAL MASKING: Hardwire inputs to specific brain regions
+        // SPATIAL MASKING: Hardwire inputs to specific brain regions (the receptive field map)
         // This fixes the "Green in Middle" visual bug by restricting Auditory input to the sides.
-        for i in 0..self.size {
-            let pos = self.positions[i];
-            
-            // Defines the "Receptive Field" for each sensory modality
-            let is_in_receptive_field = match region {
-                NeuronRegion::Auditory => pos[0].abs() > 25.0, // Lateral Temporal Lobes (Sides)
-                NeuronRegion::Semantic => pos[2] > 20.0,       // Frontal Lobe (Front)
-                NeuronRegion::Limbic => pos[1] < -20.0,        // Deep/Basal Ganglia (Bottom)
-                NeuronRegion::Association => pos[1] > 20.0 && pos[2] < 10.0, // Parietal (Top/Rear)
-                NeuronRegion::Visual => pos[2] < -25.0,        // Occipital (Back)
-            };
-
-            if is_in_receptive_field {
-                self.state[i] += impact[i];
-                self.state[i] = self.state[i].clamp(-1.0, 1.0);
-
-                // Hebbian Exposure Tagging
-                let activation = ((self.state[i] + 1.0) / 2.0).max(0.0);
-                let stimulus_strength = impact[i].abs();
-                
-                if stimulus_strength > 0.1 && activation > 0.3 {
-                    match region {
-                        NeuronRegion::Auditory => {
-                            if i < self.auditory_exposure.len() {
-                                self.auditory_exposure[i] += activation * stimulus_strength * 0.05;
-                            }
-                        },
-                        NeuronRegion::Semantic => {
-                            if i < self.semantic_exposure.len() {
-                                self.semantic_exposure[i] += activation * stimulus_strength * 0.05;
-                            }
-                        },
-                        NeuronRegion::Limbic => {
-                            if i < self.limbic_exposure.len() {
-                                self.limbic_exposure[i] += activation * stimulus_strength * 0.05;
-                            }
-                        },
-                        NeuronRegion::Association => {
-                            if i < self.association_exposure.len() {
-                                self.association_exposure[i] += activation * stimulus_strength * 0.05;
-                            }
-                        },
-                        NeuronRegion::Visual => {
-                            if i < self.visual_exposure.len() {
-                                self.visual_exposure[i] += activation * stimulus_strength * 0.05;
-                            }
-                        },
-                    }
+        let mask = self.field_mask(region);
+        let impacts = impact.as_slice();
+        let landed: f32 = impacts.iter().enumerate()
+            .filter(|&(i, _)| mask[i])
+            .map(|(_, v)| v * v)
+            .sum();
+        self.drive[pathway as usize] += landed.sqrt();
+        let clipped = (0..impacts.len()).filter(|&i| mask[i] && (self.state[i] + impacts[i]).abs() > 1.0).collect();
+        self.count_clipped(clipped);
+        per_neuron(self.state.as_mut_slice(), |i, x| {
+            if mask[i] {
+                *x = (*x + impacts[i]).clamp(-1.0, 1.0);
+            }
+        });
+
+        // Hebbian Exposure Tagging
+        let exposure = match region {
+            NeuronRegion::Auditory => &mut self.auditory_exposure,
+            NeuronRegion::Semantic => &mut self.semantic_exposure,
+            NeuronRegion::Limbic => &mut self.limbic_exposure,
+            NeuronRegion::Association => &mut self.association_exposure,
+            NeuronRegion::Visual => &mut self.visual_exposure,
+        };
+        self.region_drift += accumulate_exposure(exposure, self.state.as_slice(), |i, x| {
+            let activation = ((x + 1.0) / 2.0).max(0.0);
+            let stimulus_strength = impacts[i].abs();
+            if mask[i] && stimulus_strength > 0.1 && activation > 0.3 {
+                activation * stimulus_strength * 0.05
+            } else {
+                0.0
+            }
+        });
+    }
+
+    /// The `k` neurons an embedding drives hardest inside the region's receptive field
+    /// (the projection `inject_embedding` would apply, without touching the state).
+    pub fn driven_neurons(&self, embedding: &[f32], region: NeuronRegion, k: usize) -> Vec<usize> {
+        let input_cols = self.input_weights.ncols();
+        let mut padded_embedding = DVector::zeros(input_cols);
+        for (i, &val) in embedding.iter().take(input_cols).enumerate() {
+            padded_embedding[i] = val;
+        }
+        let impact = &self.input_weights * padded_embedding;
+        let mask = self.field_mask(region);
+        let mut driven: Vec<(usize, f32)> = (0..self.size)
+            .filter(|&i| mask[i] && impact[i].abs() > 1e-3)
+            .map(|i| (i, impact[i].abs()))
+            .collect();
+        driven.sort_by(|a, b| b.1.total_cmp(&a.1));
+        driven.into_iter().take(k).map(|(i, _)| i).collect()
+    }
+
+    /// CROSS-MODAL BINDING (Co-occurrence)
+    /// A Hebbian pass restricted to two receptive fields: every pair (i in `a`, j in `b`)
+    /// gets `rate` more excitation, both ways. Unlike `hebbian_update` it may sprout a
+    /// synapse where there was none: distant fields are barely wired, and the pairing
+    /// itself is the evidence. Returns the synapses touched.
+    pub fn bind_fields(&mut self, a: &[usize], b: &[usize], rate: f32) -> u32 {
+        let mut changes = 0;
+        for &i in a {
+            for &j in b {
+                if i == j || i >= self.size || j >= self.size { continue; }
+                for (post, pre) in [(i, j), (j, i)] {
+                    let before = self.weights[(post, pre)];
+                    self.weights[(post, pre)] = (before + rate).clamp(-1.5, 1.5);
+                    self.plasticity += (self.weights[(post, pre)] - before).abs() as f64;
+                    changes += 1;
                 }
             }
         }
+        self.hebbian_events += changes;
+        changes
+    }
+
+    /// Per neuron, 0..1: how evenly its recurrent input comes from the auditory and the
+    /// visual receptive fields (2·min/(a+v) over Σ|w| from each). 1 = both alike,
+    /// 0 = one modality only (or neither). O(size²): stats, not the hot loop.
+    pub fn multimodality(&self) -> Vec<f32> {
+        let auditory = self.field_mask(NeuronRegion::Auditory);
+        let visual = self.field_mask(NeuronRegion::Visual);
+        (0..self.size).map(|i| {
+            let (mut a, mut v) = (0.0f32, 0.0f32);
+            for j in 0..self.size.min(self.positions.len()) {
+                if i == j { continue; }
+                let w = self.weights[(i, j)].abs();
+                if auditory[j] { a += w; }
+                if visual[j] { v += w; }
+            }
+            if a + v > 1e-6 { 2.0 * a.min(v) / (a + v) } else { 0.0 }
+        }).collect()
+    }
+
+    /// Neurons per region with their mean multimodality and how many are bimodal.
+    pub fn region_stats(&self) -> Vec<RegionStats> {
+        let map = self.get_region_map();
+        let scores = self.multimodality();
+        NeuronRegion::ALL.iter().map(|&region| {
+            let members: Vec<f32> = map.iter().zip(&scores)
+                .filter(|(&r, _)| r == region.as_id())
+                .map(|(_, &m)| m)
+                .collect();
+            RegionStats {
+                region: region_name(region.as_id()),
+                neurons: members.len(),
+                multimodality: if members.is_empty() { 0.0 } else { members.iter().sum::<f32>() / members.len() as f32 },
+                multimodal: members.iter().filter(|&&m| m > MULTIMODAL).count(),
+            }
+        }).collect()
     }
 
     /// EPIPHANY: Structural Lock-in (Reward as Structure)
@@ -398,6 +1285,7 @@ impl FractalReservoir {
                          if current_weight.abs() > 0.01 {
                              let delta = alpha * self.state[i].abs() * self.state[j].abs() * current_weight.signum();
                              self.weights[(i, j)] = (current_weight + delta).clamp(-2.0, 2.0);
+                             self.plasticity += (self.weights[(i, j)] - current_weight).abs() as f64;
                              changes += 1;
                          }
                     }
@@ -433,11 +1321,12 @@ impl FractalReservoir {
         let activity_threshold = 0.5;
         let alpha = 0.01 * reinforcement * delta_time * 60.0;
         let mut changes = 0;
+        self.refresh_fields();
 
-        let mut rng = rand::thread_rng();
+        let mut rng = rand::rng();
         for _ in 0..(self.size * 2) {
-            let i = rng.gen_range(0..self.size);
-            let j = rng.gen_range(0..self.size);
+            let i = rng.random_range(0..self.size);
+            let j = rng.random_range(0..self.size);
             
             let xi = self.state[i];
             let xj = self.state[j];
@@ -458,9 +1347,11 @@ impl FractalReservoir {
                 
                 let delta = alpha * xi.abs() * xj.abs() * sign_match * dist_factor;
                 
-                if self.weights[(i, j)].abs() > 0.001 {
-                    self.weights[(i, j)] += delta;
-                    self.weights[(i, j)] = self.weights[(i, j)].clamp(-1.5, 1.5);
+                let before = self.weights[(i, j)];
+                if before.abs() > 0.001 {
+                    self.weights[(i, j)] = (before + delta).clamp(-1.5, 1.5);
+                    self.plasticity += (self.weights[(i, j)] - before).abs() as f64;
+                    self.demand_event(i, 0);
                     changes += 1;
                 }
             }
@@ -482,14 +1373,15 @@ impl FractalReservoir {
         let alpha = 0.05 * reinforcement; // Stronger learning rate for inputs
         let activity_threshold = 0.4;
         let mut changes = 0;
+        self.refresh_fields();
         
-        let mut rng = rand::thread_rng();
+        let mut rng = rand::rng();
         let input_cols = self.input_weights.ncols();
         
         // Sample connections (Efficiency)
         for _ in 0..(self.size * 2) {
-            let i = rng.gen_range(0..self.size); // Reservoir Neuron
-            let j = rng.gen_range(0..input_cols); // Input Channel
+            let i = rng.random_range(0..self.size); // Reservoir Neuron
+            let j = rng.random_range(0..input_cols); // Input Channel
             
             if j >= input.len() { continue; }
             
@@ -503,6 +1395,7 @@ impl FractalReservoir {
                  
                  self.input_weights[(i, j)] += delta;
                  self.input_weights[(i, j)] = self.input_weights[(i, j)].clamp(-1.5, 1.5);
+                 self.demand_event(i, 0);
                  changes += 1;
             }
         }
@@ -518,23 +1411,33 @@ impl FractalReservoir {
         for i in 0..self.size {
             for j in 0..self.size {
                 if self.weights[(i,j)].abs() < 0.05 && self.weights[(i,j)] != 0.0 {
+                    self.plasticity += self.weights[(i,j)].abs() as f64;
                     self.weights[(i,j)] = 0.0;
                     pruned += 1;
                 }
             }
         }
+        self.pruned_events += pruned as u32;
         pruned
     }
     
+    /// Hold growth at `ceiling` neurons (None = grow again, up to the hard limit).
+    pub fn set_growth_ceiling(&mut self, ceiling: Option<usize>) {
+        self.growth_ceiling = ceiling;
+    }
+
     pub fn neurogenesis(&mut self, count: usize) {
-        let max_neurons = 2500;
-        let mut rng = rand::thread_rng();
+        let max_neurons = self.growth_ceiling.map_or(2500, |ceiling| ceiling.min(2500));
+        let mut rng = rand::rng();
         let normal = Normal::new(0.0, 0.1).unwrap();
+        let grown = self.size;
+        self.refresh_fields();
         
         for _ in 0..count {
-            if self.size >= max_neurons { return; }
+            if self.size >= max_neurons { break; }
             
             let new_size = self.size + 1;
+            self.growth_events += 1;
             
             // Grow weight matrix
             let mut new_weights = DMatrix::zeros(new_size, new_size);
@@ -543,25 +1446,12 @@ impl FractalReservoir {
                     new_weights[(r, c)] = self.weights[(r, c)];
                 }
             }
-            // Spawn new neuron NEAR the most active existing neuron
-            // This mimics biological neurogenesis: growth follows activity
-            let spawn_pos = if !self.positions.is_empty() {
-                // Find most active neuron
-                let most_active_idx = self.last_activity.iter()
-                    .enumerate()
-                    .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
-                    .map(|(i, _)| i)
-                    .unwrap_or(0);
-                let parent = self.positions[most_active_idx];
-                // Spawn within ~5 units of parent
-                [
-                    parent[0] + (rng.gen::<f32>() - 0.5) * 10.0,
-                    parent[1] + (rng.gen::<f32>() - 0.5) * 10.0,
-                    parent[2] + (rng.gen::<f32>() - 0.5) * 10.0,
-                ]
-            } else {
-                [0.0, 0.0, 0.0]
-            };
+            // Spawn the new neuron where growth is DEMANDED: the parent field is drawn
+            // by demand (saturation, hebbian events, clipped injections), and the neuron
+            // lands near that field's most active neuron, inside the field. Growth follows
+            // need rather than loudness, so a busy Auditory field can't starve Semantic.
+            let field = self.demanded_field(rng.random::<f32>());
+            let spawn_pos = self.spawn_position(field, &mut rng);
             self.positions.push(spawn_pos);
 
             // Distance-dependent connectivity for new neuron
@@ -572,10 +1462,10 @@ impl FractalReservoir {
                     let dist = (dx*dx + dy*dy + dz*dz).sqrt();
                     let prob = 3.0 / (dist + 1.0);
                     let prob = prob.min(0.3) + 0.005;
-                    if rng.gen::<f32>() < prob {
+                    if rng.random::<f32>() < prob {
                         new_weights[(self.size, i)] = normal.sample(&mut rng) as f32 * self.spectral_radius;
                     }
-                    if rng.gen::<f32>() < prob {
+                    if rng.random::<f32>() < prob {
                         new_weights[(i, self.size)] = normal.sample(&mut rng) as f32 * self.spectral_radius;
                     }
                 }
@@ -617,7 +1507,33 @@ impl FractalReservoir {
             self.auditory_exposure.push(0.0);
             self.limbic_exposure.push(0.0);
             self.association_exposure.push(0.0);
+            self.visual_exposure.push(0.0);
             self.last_activity.push(0.0);
+            self.birth_tick.push(self.ticks);
+            self.ids.push(self.next_id);
+            self.next_id += 1;
+            if !self.lesion.is_empty() {
+                self.lesion.push(false);
+            }
+            let bits = self.fields_at(spawn_pos);
+            self.field_bits.push(bits);
+            for (r, born) in self.born.iter_mut().enumerate() {
+                *born += (bits >> r & 1) as u64;
+            }
+        }
+
+        if self.size > grown {
+            self.echo_order = None;
+            let mut neurons = [0usize; 5];
+            for &bits in &self.field_bits {
+                for (r, n) in neurons.iter_mut().enumerate() {
+                    *n += (bits >> r & 1) as usize;
+                }
+            }
+            if self.growth_history.len() >= GROWTH_HISTORY {
+                self.growth_history.pop_front();
+            }
+            self.growth_history.push_back(Census { tick: self.ticks, neurons });
         }
     }
 
@@ -644,24 +1560,341 @@ impl FractalReservoir {
         self.size
     }
 
+    /// L2 norm of the membrane state (every neuron is kept in [-1, 1], so at most sqrt(size)).
+    pub fn state_norm(&self) -> f32 {
+        self.state.norm()
+    }
+
+    pub fn drain_growth_events(&mut self) -> u32 {
+        std::mem::take(&mut self.growth_events)
+    }
+
+    pub fn drain_pruned_events(&mut self) -> u32 {
+        std::mem::take(&mut self.pruned_events)
+    }
+
     pub fn drain_hebbian_events(&mut self) -> u32 {
         let e = self.hebbian_events;
         self.hebbian_events = 0;
         e
     }
     
+    pub fn plasticity_accumulated(&self) -> f64 {
+        self.plasticity
+    }
+
+    /// Recurrent weights, (target, source).
+    pub fn weights(&self) -> &DMatrix<f32> {
+        &self.weights
+    }
+
+    /// Tick each neuron was born on (0 = genesis).
+    pub fn birth_tick(&self) -> &[u64] {
+        &self.birth_tick
+    }
+
+    /// Saves from before stable IDs: neuron i becomes ID i.
+    fn adopt_ids(&mut self) {
+        if self.ids.len() != self.size {
+            self.ids = (0..self.size as u64).collect();
+        }
+        self.next_id = self.next_id.max(self.ids.last().map_or(0, |&id| id + 1));
+    }
+
+    /// Stable ID of every neuron, by index (ascending).
+    pub fn neuron_ids(&self) -> &[u64] {
+        &self.ids
+    }
+
+    /// Where the neuron with this ID is now, or why it isn't.
+    pub fn resolve(&self, id: u64) -> NeuronLookup {
+        match self.ids.binary_search(&id) {
+            Ok(index) => NeuronLookup::Alive { index },
+            Err(_) if id < self.next_id => NeuronLookup::Deceased,
+            Err(_) => NeuronLookup::Unborn,
+        }
+    }
+
+    /// Current index of a living neuron.
+    pub fn index_of(&self, id: u64) -> Option<usize> {
+        self.ids.binary_search(&id).ok()
+    }
+
+    /// APOPTOSIS (whole neurons): remove the neurons with these IDs, with their
+    /// synapses both ways, compacting every per-neuron array. Survivors keep their
+    /// IDs and their order (indices after a dead neuron shift down); the dead IDs
+    /// resolve as Deceased from now on and are never handed out again. IDs that
+    /// aren't alive are ignored. Returns how many died.
+    pub fn remove_neurons(&mut self, ids: &[u64]) -> usize {
+        let dying: std::collections::HashSet<usize> = ids.iter().filter_map(|&id| self.index_of(id)).collect();
+        if dying.is_empty() {
+            return 0;
+        }
+        let keep: Vec<usize> = (0..self.size).filter(|i| !dying.contains(i)).collect();
+        let n = keep.len();
+        let pruned = (0..self.size).flat_map(|i| (0..self.size).map(move |j| (i, j)))
+            .filter(|&(i, j)| (dying.contains(&i) || dying.contains(&j)) && self.weights[(i, j)] != 0.0)
+            .count();
+
+        self.weights = DMatrix::from_fn(n, n, |r, c| self.weights[(keep[r], keep[c])]);
+        self.input_weights = DMatrix::from_fn(n, self.input_weights.ncols(), |r, c| self.input_weights[(keep[r], c)]);
+        self.state = DVector::from_fn(n, |r, _| self.state[keep[r]]);
+        self.bias = DVector::from_fn(n, |r, _| self.bias[keep[r]]);
+        fn compact<T: Copy>(values: &mut Vec<T>, keep: &[usize]) {
+            *values = keep.iter().filter_map(|&i| values.get(i).copied()).collect();
+        }
+        for exposure in [&mut self.semantic_exposure, &mut self.auditory_exposure, &mut self.limbic_exposure,
+                         &mut self.association_exposure, &mut self.visual_exposure, &mut self.last_activity] {
+            compact(exposure, &keep);
+        }
+        compact(&mut self.positions, &keep);
+        compact(&mut self.birth_tick, &keep);
+        compact(&mut self.ids, &keep);
+        if !self.lesion.is_empty() {
+            compact(&mut self.lesion, &keep);
+            if !self.lesion.contains(&true) {
+                self.lesion.clear();
+            }
+        }
+        self.field_bits.clear(); // Rebuilt on the next use
+        self.echo_order = None;
+        // A regrowth to the same size would pass `region_map`'s length test with every index shifted
+        self.region_cache.clear();
+        self.size = n;
+        self.pruned_events += pruned as u32;
+        dying.len()
+    }
+
+    /// IDs of the neurons with no synapse left either way (sleep apoptosis, after pruning).
+    pub fn isolated_neurons(&self) -> Vec<u64> {
+        (0..self.size)
+            .filter(|&i| (0..self.size).all(|j| self.weights[(i, j)] == 0.0 && self.weights[(j, i)] == 0.0))
+            .map(|i| self.ids[i])
+            .collect()
+    }
+
+    /// The next ID to be handed out: moves whenever a neuron is born.
+    pub fn next_neuron_id(&self) -> u64 {
+        self.next_id
+    }
+
+    /// Physics ticks lived (AGE).
+    pub fn ticks_lived(&self) -> u64 {
+        self.ticks
+    }
+
+    /// WARM STANDBY (core/standby.rs): a copy for a heavy structural operation on a
+    /// worker thread while this brain keeps ticking. Its event counters and
+    /// plasticity start at zero, so after the swap they hold only the operation's.
+    pub fn standby_copy(&self) -> Self {
+        let mut copy = self.clone();
+        copy.growth_events = 0;
+        copy.pruned_events = 0;
+        copy.hebbian_events = 0;
+        copy.plasticity = 0.0;
+        copy
+    }
+
+    /// WARM STANDBY: `self` is a `standby_copy` of `live` that had a structural
+    /// operation applied while `live` kept ticking. Takes over what isn't structure:
+    /// the age, the undrained counters and plasticity (added to the operation's), and
+    /// the control state (modulation, curiosity, pauses, growth ceiling, lesions by
+    /// ID). With `replay` the activity of the ticks run meanwhile comes too, neuron
+    /// by neuron by ID (state, activity, exposures) with entropy, attribution and
+    /// demand; without it those ticks are lost and the copy's activity stands.
+    /// Weights are the copy's either way (hebbian changes made meanwhile are lost).
+    /// Returns how many neurons had their activity carried over.
+    pub fn merge_recent_state(&mut self, live: &FractalReservoir, replay: bool) -> usize {
+        self.ticks = self.ticks.max(live.ticks);
+        self.growth_events += live.growth_events;
+        self.pruned_events += live.pruned_events;
+        self.hebbian_events += live.hebbian_events;
+        self.plasticity += live.plasticity;
+        self.curiosity = live.curiosity;
+        self.exposure_paused = live.exposure_paused;
+        self.growth_ceiling = live.growth_ceiling;
+        self.modulation = live.modulation;
+        self.noise_state = live.noise_state;
+        self.input_overflow |= live.input_overflow;
+        self.lesion = if live.lesion.is_empty() {
+            Vec::new()
+        } else {
+            self.ids.iter().map(|&id| live.index_of(id).is_some_and(|j| live.lesion[j])).collect()
+        };
+        if !self.lesion.contains(&true) {
+            self.lesion.clear();
+        }
+
+        let mut carried = 0;
+        if replay {
+            for (i, &id) in self.ids.iter().enumerate() {
+                let Some(j) = live.index_of(id) else { continue }; // Born in the operation
+                self.state[i] = live.state[j];
+                for (mine, theirs) in [
+                    (&mut self.semantic_exposure, &live.semantic_exposure),
+                    (&mut self.auditory_exposure, &live.auditory_exposure),
+                    (&mut self.limbic_exposure, &live.limbic_exposure),
+                    (&mut self.association_exposure, &live.association_exposure),
+                    (&mut self.visual_exposure, &live.visual_exposure),
+                    (&mut self.last_activity, &live.last_activity),
+                ] {
+                    if let (Some(mine), Some(theirs)) = (mine.get_mut(i), theirs.get(j)) {
+                        *mine = *theirs;
+                    }
+                }
+                carried += 1;
+            }
+            self.entropy = live.entropy;
+            self.drive = live.drive;
+            self.attribution = live.attribution;
+            self.demand_events = live.demand_events;
+            self.demand = live.demand;
+            self.region_drift = REGION_DRIFT; // Exposures moved: scan the regions again
+        }
+        self.silence_lesion();
+        self.topology_version = self.topology_version.max(live.topology_version) + 1;
+        carried
+    }
+
+    /// The `k` strongest (by |w|) nonzero synapses into and out of the neuron at
+    /// `index`, strongest first (ties by partner), plus how many each side has in
+    /// total. Weights are (target, source): incoming is row `index`, outgoing column
+    /// `index`. Partners are listed by stable ID.
+    pub fn synapses(&self, index: usize, k: usize, region_map: &[u8]) -> ((Vec<Synapse>, usize), (Vec<Synapse>, usize)) {
+        let strongest = |weights: Vec<(usize, f32)>| {
+            let total = weights.len();
+            let mut weights = weights;
+            weights.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()).then(a.0.cmp(&b.0)));
+            let list = weights.into_iter().take(k).map(|(partner, weight)| Synapse {
+                partner: self.ids.get(partner).copied().unwrap_or(partner as u64),
+                region: region_name(region_map.get(partner).copied().unwrap_or(0)),
+                weight,
+            }).collect();
+            (list, total)
+        };
+        let incoming = (0..self.size).map(|j| (j, self.weights[(index, j)])).filter(|&(_, w)| w != 0.0).collect();
+        let outgoing = (0..self.size).map(|j| (j, self.weights[(j, index)])).filter(|&(_, w)| w != 0.0).collect();
+        (strongest(incoming), strongest(outgoing))
+    }
+
+    /// One neuron by stable ID, whole; Err says whether it died or never was.
+    /// `activity` is left empty.
+    pub fn inspect(&self, id: u64, region_map: &[u8]) -> Result<NeuronReport, NeuronLookup> {
+        let index = match self.resolve(id) {
+            NeuronLookup::Alive { index } => index,
+            gone => return Err(gone),
+        };
+        let ((incoming, incoming_total), (outgoing, outgoing_total)) = self.synapses(index, INSPECT_SYNAPSES, region_map);
+        let birth_tick = self.birth_tick.get(index).copied().unwrap_or(0);
+        Ok(NeuronReport {
+            id,
+            index,
+            position: self.positions.get(index).copied().unwrap_or([0.0; 3]),
+            region: region_name(region_map.get(index).copied().unwrap_or(0)),
+            activation: finite(self.last_activity.get(index).copied().unwrap_or(0.5)),
+            exposure: NeuronRegion::ALL.iter()
+                .map(|&r| (region_name(r.as_id()), finite(self.exposure(r).get(index).copied().unwrap_or(0.0))))
+                .collect(),
+            bias: self.bias[index],
+            birth_tick,
+            age_ticks: self.ticks.saturating_sub(birth_tick),
+            incoming_total,
+            outgoing_total,
+            incoming,
+            outgoing,
+            activity: Vec::new(),
+        })
+    }
+
+    /// Accumulated exposure of every neuron to one input pathway.
+    pub fn exposure(&self, region: NeuronRegion) -> &[f32] {
+        match region {
+            NeuronRegion::Semantic => &self.semantic_exposure,
+            NeuronRegion::Auditory => &self.auditory_exposure,
+            NeuronRegion::Limbic => &self.limbic_exposure,
+            NeuronRegion::Association => &self.association_exposure,
+            NeuronRegion::Visual => &self.visual_exposure,
+        }
+    }
+
     pub fn get_activity_snapshot(&self) -> Vec<f32> {
         self.last_activity.clone()
     }
+
+    /// What the reservoir is doing now, downsampled to SIGNATURE_LEN values (0..1):
+    /// block b averages the neurons i with i * SIGNATURE_LEN / size == b.
+    pub fn activity_signature(&self) -> Vec<f32> {
+        let mut sums = vec![0.0f32; SIGNATURE_LEN];
+        let mut counts = vec![0u32; SIGNATURE_LEN];
+        let n = self.last_activity.len().max(1);
+        for (i, &a) in self.last_activity.iter().enumerate() {
+            sums[i * SIGNATURE_LEN / n] += a;
+            counts[i * SIGNATURE_LEN / n] += 1;
+        }
+        sums.iter().zip(&counts)
+            .map(|(&s, &c)| if c > 0 { s / c as f32 } else { 0.5 }) // Empty block = neutral
+            .collect()
+    }
+
+    /// MEMORY REACTIVATION (Sleep Replay)
+    /// Pull the state toward a stored activity signature (0..1 per block, as made by
+    /// `activity_signature`): gain 1.0 = the pattern exactly, 0.0 = nothing. A
+    /// following `hebbian_update` then strengthens the synapses among the neurons the
+    /// pattern reactivates, offline, the way hippocampal replay does during deep sleep.
+    pub fn replay_pattern(&mut self, pattern: &[f32], gain: f32) {
+        if pattern.is_empty() { return; }
+        let gain = gain.clamp(0.0, 1.0);
+        let (blocks, size) = (pattern.len(), self.size.max(1));
+        per_neuron(self.state.as_mut_slice(), |i, x| {
+            let target = pattern[i * blocks / size].clamp(0.0, 1.0) * 2.0 - 1.0; // Activity 0..1 -> state -1..1
+            *x = (*x * (1.0 - gain) + target * gain).clamp(-1.0, 1.0);
+        });
+        self.silence_lesion();
+    }
     
+    /// What the state is being driven by right now: each pathway's smoothed share of
+    /// the injected change, summing to 1.0 (all 0.0 before anything arrived).
+    pub fn attention_breakdown(&self) -> HashMap<Pathway, f32> {
+        let total: f32 = self.attribution.iter().sum();
+        Pathway::ALL.iter()
+            .map(|&p| (p, if total > 1e-6 { self.attribution[p as usize] / total } else { 0.0 }))
+            .collect()
+    }
+
     /// Get neuron positions for visualization (real spatial data, not cosmetic)
     pub fn get_positions(&self) -> &Vec<[f32; 3]> {
         &self.positions
     }
     
+    /// Region map for the live loop: the cached scan, redone only when a neuron was
+    /// born or died or some exposure may have moved REGION_DRIFT since the last one (so the
+    /// map lags the exposures by less than that). Reading it again with the
+    /// exposures unchanged does not re-scan.
+    pub fn region_map(&mut self) -> &[u8] {
+        if self.region_cache.len() != self.size || self.region_drift >= REGION_DRIFT {
+            let fresh = self.get_region_map();
+            self.region_scans += 1;
+            if fresh != self.region_cache {
+                self.region_cache = fresh;
+                self.topology_version += 1;
+            }
+            self.region_drift = 0.0;
+        }
+        &self.region_cache
+    }
+
+    /// Changes whenever `region_map` or the neuron count (and so `get_positions`)
+    /// changed: clients only need the topology again when this moves.
+    pub fn topology_version(&self) -> u64 {
+        self.topology_version
+    }
+
     /// Derive region map from exposure history — NOT hardcoded!
     /// Each neuron's region = whichever exposure is highest.
     /// If no strong preference → Association (generic connector)
+    /// Embedding exposure is only gained inside the receptive field map, so the regions
+    /// drawn here grow where `inject_embedding` lands.
+    /// (Full scan every call: offline tools use it directly, the loop uses `region_map`.)
     pub fn get_region_map(&self) -> Vec<u8> {
         (0..self.size).map(|i| {
             let sem = if i < self.semantic_exposure.len() { self.semantic_exposure[i] } else { 0.0 };
@@ -713,4 +1946,908 @@ impl FractalReservoir {
         serde_json::to_writer_pretty(writer, self)?;
         Ok(())
     }
+
+    /// Read a saved brain as-is (no genesis fallback, no upgrades but numbering the
+    /// neurons of a save without IDs): for offline tools.
+    pub fn read_from_disk(path: &str) -> anyhow::Result<Self> {
+        let reader = std::io::BufReader::new(File::open(path)?);
+        let mut brain: Self = serde_json::from_reader(reader)?;
+        brain.adopt_ids();
+        Ok(brain)
+    }
+
+    // --- CONNECTOME EXPORT (Gephi / networkx) ---
+    // weights[(i, j)] is what neuron j feeds into neuron i, so every edge is j -> i.
+
+    /// Synapses with |weight| above `threshold` (what an export will contain).
+    pub fn count_edges(&self, threshold: f32) -> usize {
+        self.weights.iter().filter(|w| w.abs() > threshold).count()
+    }
+
+    /// Write the connectome to `path` (.graphml -> GraphML, anything else -> node-link JSON).
+    /// Returns the number of edges written.
+    pub fn export_graph(&self, path: &str, threshold: f32) -> std::io::Result<usize> {
+        let format = GraphFormat::from_path(path);
+        let mut writer = std::io::BufWriter::new(File::create(path)?);
+        let edges = self.write_graph(&mut writer, threshold, format)?;
+        writer.flush()?;
+        Ok(edges)
+    }
+
+    /// Streams nodes then edges into `out`; nothing but one line is built in memory,
+    /// so a 10k-neuron brain (100M candidate synapses) exports in constant space.
+    pub fn write_graph<W: Write>(&self, out: &mut W, threshold: f32, format: GraphFormat) -> std::io::Result<usize> {
+        let regions = self.get_region_map();
+        let exposure = |v: &Vec<f32>, i: usize| finite(v.get(i).copied().unwrap_or(0.0));
+
+        match format {
+            GraphFormat::GraphMl => {
+                writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+                writeln!(out, r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#)?;
+                for (key, ty) in [("x", "float"), ("y", "float"), ("z", "float"), ("region", "string"),
+                                  ("semantic", "float"), ("auditory", "float"), ("limbic", "float"),
+                                  ("association", "float"), ("visual", "float"), ("activity", "float")] {
+                    writeln!(out, r#"  <key id="{0}" for="node" attr.name="{0}" attr.type="{1}"/>"#, key, ty)?;
+                }
+                writeln!(out, r#"  <key id="weight" for="edge" attr.name="weight" attr.type="float"/>"#)?;
+                writeln!(out, r#"  <graph id="aleph" edgedefault="directed">"#)?;
+            },
+            GraphFormat::Json => {
+                write!(out, r#"{{"directed":true,"multigraph":false,"graph":{{"name":"aleph"}},"nodes":["#)?;
+            },
+        }
+
+        for i in 0..self.size {
+            let [x, y, z] = self.positions.get(i).copied().unwrap_or([0.0; 3]);
+            let region = region_name(regions[i]);
+            let (sem, aud, lim) = (exposure(&self.semantic_exposure, i), exposure(&self.auditory_exposure, i), exposure(&self.limbic_exposure, i));
+            let (assoc, vis) = (exposure(&self.association_exposure, i), exposure(&self.visual_exposure, i));
+            let activity = finite(self.last_activity.get(i).copied().unwrap_or(0.0));
+            match format {
+                GraphFormat::GraphMl => writeln!(out,
+                    r#"    <node id="n{}"><data key="x">{}</data><data key="y">{}</data><data key="z">{}</data><data key="region">{}</data><data key="semantic">{}</data><data key="auditory">{}</data><data key="limbic">{}</data><data key="association">{}</data><data key="visual">{}</data><data key="activity">{}</data></node>"#,
+                    i, x, y, z, region, sem, aud, lim, assoc, vis, activity)?,
+                GraphFormat::Json => write!(out,
+                    r#"{}{{"id":{},"x":{},"y":{},"z":{},"region":"{}","semantic":{},"auditory":{},"limbic":{},"association":{},"visual":{},"activity":{}}}"#,
+                    if i == 0 { "" } else { "," }, i, x, y, z, region, sem, aud, lim, assoc, vis, activity)?,
+            }
+        }
+
+        if format == GraphFormat::Json {
+            write!(out, r#"],"links":["#)?;
+        }
+
+        // Column-major storage: walking source j outer, target i inner is sequential memory
+        let mut edges = 0;
+        for j in 0..self.weights.ncols() {
+            for (i, &w) in self.weights.column(j).iter().enumerate() {
+                if w.is_nan() || w.abs() <= threshold {
+                    continue;
+                }
+                match format {
+                    GraphFormat::GraphMl => writeln!(out,
+                        r#"    <edge source="n{}" target="n{}"><data key="weight">{}</data></edge>"#, j, i, w)?,
+                    GraphFormat::Json => write!(out,
+                        r#"{}{{"source":{},"target":{},"weight":{}}}"#, if edges == 0 { "" } else { "," }, j, i, w)?,
+                }
+                edges += 1;
+            }
+        }
+
+        match format {
+            GraphFormat::GraphMl => writeln!(out, "  </graph>\n</graphml>")?,
+            GraphFormat::Json => writeln!(out, "]}}")?,
+        }
+        Ok(edges)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    GraphMl,
+    Json, // networkx node-link
+}
+
+impl GraphFormat {
+    pub fn from_path(path: &str) -> Self {
+        if path.ends_with(".graphml") { GraphFormat::GraphMl } else { GraphFormat::Json }
+    }
+}
+
+/// Z-order (Morton) code of a point in the brain's bounding cube, 21 bits per axis.
+fn morton(pos: [f32; 3], radius: f32) -> u64 {
+    let spread = |v: f32| {
+        let unit = ((v / radius.max(1e-6)).clamp(-1.0, 1.0) * 0.5 + 0.5) as f64;
+        let mut x = (unit * ((1u64 << 21) - 1) as f64) as u64 & 0x1f_ffff;
+        x = (x | x << 32) & 0x001f_0000_0000_ffff;
+        x = (x | x << 16) & 0x001f_0000_ff00_00ff;
+        x = (x | x << 8) & 0x100f_00f0_0f00_f00f;
+        x = (x | x << 4) & 0x10c3_0c30_c30c_30c3;
+        (x | x << 2) & 0x1249_2492_4924_9249
+    };
+    spread(pos[0]) | spread(pos[1]) << 1 | spread(pos[2]) << 2
+}
+
+/// Each neuron listens to its own slice of the vocabulary: the peak logit of the
+/// chunk (floored at 0), one value per neuron (0 for neurons past the vocabulary).
+pub fn max_pool_logits(logits: &[f32], neurons: usize) -> Vec<f32> {
+    let vocab_size = logits.len();
+    let chunk_size = (vocab_size / neurons.max(1)).max(1);
+    (0..neurons).map(|i| {
+        let start = i * chunk_size;
+        if start >= vocab_size { return 0.0; }
+        let end = (start + chunk_size).min(vocab_size);
+        logits[start..end].iter().fold(0.0f32, |acc, &x| acc.max(x))
+    }).collect()
+}
+
+fn region_name(id: u8) -> &'static str {
+    match id {
+        0 => "Semantic",
+        1 => "Auditory",
+        2 => "Limbic",
+        3 => "Association",
+        _ => "Visual",
+    }
+}
+
+/// JSON has no NaN/inf; a diverged neuron exports as 0.
+fn finite(v: f32) -> f32 {
+    if v.is_finite() { v } else { 0.0 }
+}
+
+// --- PER-NEURON LOOPS (rayon, or serial with `--features serial-physics`) ---
+// Every closure writes only its own index and never reduces across neurons, so
+// both paths produce bit-identical results (seeded runs stay reproducible).
+
+fn per_neuron<F>(values: &mut [f32], f: F)
+where
+    F: Fn(usize, &mut f32) + Sync + Send,
+{
+    #[cfg(not(feature = "serial-physics"))]
+    values.par_iter_mut().enumerate().for_each(|(i, v)| f(i, v));
+    #[cfg(feature = "serial-physics")]
+    values.iter_mut().enumerate().for_each(|(i, v)| f(i, v));
+}
+
+/// exposure[i] += gain(i, state[i]) for every neuron present in both slices.
+/// Returns the largest gain applied (max is exact in any order, so both paths agree).
+fn accumulate_exposure<F>(exposure: &mut [f32], state: &[f32], gain: F) -> f32
+where
+    F: Fn(usize, f32) -> f32 + Sync + Send,
+{
+    let n = exposure.len().min(state.len());
+    let add = |(i, e): (usize, &mut f32)| {
+        let g = gain(i, state[i]);
+        *e += g;
+        g
+    };
+    #[cfg(not(feature = "serial-physics"))]
+    let largest = exposure[..n].par_iter_mut().enumerate().map(add).reduce(|| 0.0, f32::max);
+    #[cfg(feature = "serial-physics")]
+    let largest = exposure[..n].iter_mut().enumerate().map(add).fold(0.0, f32::max);
+    largest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::ReplayConfig;
+
+    /// The planes the fields were before they became data (brain units, radius 40).
+    fn legacy_field(region: NeuronRegion, pos: [f32; 3]) -> bool {
+        match region {
+            NeuronRegion::Auditory => pos[0].abs() > 25.0,
+            NeuronRegion::Semantic => pos[2] > 20.0,
+            NeuronRegion::Limbic => pos[1] < -20.0,
+            NeuronRegion::Association => pos[1] > 20.0 && pos[2] < 10.0,
+            NeuronRegion::Visual => pos[2] < -25.0,
+        }
+    }
+
+    #[test]
+    fn exported_edges_match_the_synapses_above_threshold() {
+        let mut ego = FractalReservoir::seeded(150, 16, 0.95, 0.2, 21);
+        ego.tick(&[0.5; 16], 0.5, 0.1, 0.1, 0.016);
+        let path = std::env::temp_dir().join(format!("aleph-connectome-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        ego.save_to_disk(path).unwrap();
+        let saved = FractalReservoir::read_from_disk(path).unwrap(); // What `aleph export-graph` reads
+        let _ = std::fs::remove_file(path);
+
+        for threshold in [0.0, 0.05, 0.1, 0.3] {
+            let expected = saved.weights.iter().filter(|w| **w != 0.0 && w.abs() > threshold).count();
+            assert_eq!(saved.count_edges(threshold), expected);
+
+            let mut json = Vec::new();
+            assert_eq!(saved.write_graph(&mut json, threshold, GraphFormat::Json).unwrap(), expected);
+            let graph: serde_json::Value = serde_json::from_slice(&json).unwrap();
+            assert_eq!(graph["nodes"].as_array().unwrap().len(), 150);
+            assert_eq!(graph["links"].as_array().unwrap().len(), expected, "threshold {}", threshold);
+
+            let mut graphml = Vec::new();
+            assert_eq!(saved.write_graph(&mut graphml, threshold, GraphFormat::GraphMl).unwrap(), expected);
+            let graphml = String::from_utf8(graphml).unwrap();
+            assert_eq!(graphml.matches("<edge ").count(), expected);
+            assert_eq!(graphml.matches("<node ").count(), 150);
+            assert!(graphml.trim_end().ends_with("</graphml>"));
+        }
+    }
+
+    #[test]
+    fn a_diverged_neuron_still_exports_valid_json() {
+        let mut ego = FractalReservoir::seeded(40, 8, 0.95, 0.2, 3);
+        ego.last_activity[7] = f32::NAN;
+        ego.weights[(3, 7)] = f32::NAN;
+        let mut json = Vec::new();
+        let edges = ego.write_graph(&mut json, 0.0, GraphFormat::Json).unwrap();
+        let graph: serde_json::Value = serde_json::from_slice(&json).expect("NaN must not reach the JSON");
+        assert_eq!(graph["links"].as_array().unwrap().len(), edges);
+        assert_eq!(graph["nodes"][7]["activity"], 0.0);
+    }
+
+    #[test]
+    fn visual_injection_lands_only_on_the_occipital_field() {
+        let mut ego = FractalReservoir::seeded(600, 16, 0.95, 0.2, 21);
+        let occipital = ego.field_mask(NeuronRegion::Visual);
+        assert!(occipital.iter().any(|&m| m) && !occipital.iter().all(|&m| m));
+        let (before, auditory) = (ego.state.clone(), ego.auditory_exposure.clone());
+
+        let light: Vec<f32> = (0..16).map(|k| if k % 2 == 0 { 1.0 } else { -1.0 }).collect();
+        for _ in 0..20 {
+            ego.inject_embedding(&light, NeuronRegion::Visual);
+        }
+        let moved: Vec<usize> = (0..ego.size).filter(|&i| ego.state[i] != before[i]).collect();
+        assert!(!moved.is_empty(), "the scene never reached the brain");
+        assert!(moved.iter().all(|&i| occipital[i]), "{} neurons outside the occipital field moved",
+            moved.iter().filter(|&&i| !occipital[i]).count());
+        assert!(ego.visual_exposure.iter().enumerate().all(|(i, &e)| e == 0.0 || occipital[i]));
+        assert!(ego.visual_exposure.iter().any(|&e| e > 0.0), "the field learned nothing from the scene");
+        assert_eq!(ego.auditory_exposure, auditory);
+    }
+
+    #[test]
+    fn a_save_from_before_vision_loads_and_sees() {
+        let path = std::env::temp_dir().join(format!("aleph-pre-visual-{}.json", std::process::id()));
+        let ego = FractalReservoir::seeded(300, 16, 0.95, 0.2, 22);
+        let mut save = serde_json::to_value(&ego).unwrap();
+        let fields = save.as_object_mut().unwrap();
+        for phase_6_and_later in ["visual_exposure", "association_exposure", "birth_tick", "ids", "next_id"] {
+            fields.remove(phase_6_and_later);
+        }
+        fields.get_mut("receptive_fields").unwrap().as_object_mut().unwrap().remove("visual");
+        std::fs::write(&path, save.to_string()).unwrap();
+
+        let mut loaded = FractalReservoir::load_from(path.to_str().unwrap(), 50, 0.2);
+        assert_eq!(loaded.current_size(), 300, "the old brain was kept, not regenerated");
+        assert_eq!(loaded.visual_exposure.len(), 300);
+        assert_eq!(loaded.neuron_ids().len(), 300);
+        assert_eq!(loaded.receptive_fields().visual, ReceptiveFieldMap::default().visual);
+
+        loaded.inject_embedding(&[0.8; 16], NeuronRegion::Visual);
+        loaded.tick(&[0.0; 16], 0.5, 0.1, 0.2, 1.0 / 60.0);
+        assert!(loaded.visual_exposure.iter().any(|&e| e > 0.0));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn apoptosis_then_regrowth_to_the_same_size_rescans_the_regions() {
+        let mut ego = FractalReservoir::seeded(300, 16, 0.95, 0.2, 23);
+        for _ in 0..5 {
+            ego.inject_embedding(&[0.9; 16], NeuronRegion::Auditory);
+            ego.inject_embedding(&[0.9; 16], NeuronRegion::Visual);
+        }
+        ego.region_map();
+        let version = ego.topology_version();
+
+        let dying: Vec<u64> = ego.neuron_ids().iter().copied().filter(|id| id % 4 == 0).collect();
+        assert_eq!(ego.remove_neurons(&dying), dying.len());
+        ego.neurogenesis(dying.len());
+        assert_eq!(ego.current_size(), 300);
+        assert_eq!(ego.region_map().to_vec(), ego.get_region_map(), "stale regions after the shift");
+        assert!(ego.topology_version() > version);
+    }
+
+    #[test]
+    fn sleep_pruning_kills_the_neurons_it_disconnects() {
+        let mut ego = FractalReservoir::seeded(300, 16, 0.95, 0.2, 24);
+        let lonely = ego.neuron_ids()[42];
+        for j in 0..ego.size {
+            for w in [(42, j), (j, 42)] {
+                if ego.weights[w] != 0.0 {
+                    ego.weights[w] = 0.01;
+                }
+            }
+        }
+        let line = crate::core::daemon_state::consolidate(&mut ego);
+        assert!(line.contains("disconnected neurons died"), "{}", line);
+        assert_eq!(ego.resolve(lonely), NeuronLookup::Deceased);
+        assert!(ego.isolated_neurons().is_empty());
+        assert_eq!(ego.region_map().len(), ego.current_size());
+    }
+
+    #[test]
+    fn a_save_from_before_the_semantic_echo_keeps_the_whole_brain_echo() {
+        let path = std::env::temp_dir().join(format!("aleph-echo-target-{}.json", std::process::id()));
+        let ego = FractalReservoir::seeded(200, 16, 0.95, 0.2, 25);
+        assert_eq!(ego.echo_target(), EchoTarget::Semantic, "genesis");
+
+        ego.save_to_disk(path.to_str().unwrap()).unwrap();
+        assert_eq!(FractalReservoir::load_from(path.to_str().unwrap(), 50, 0.2).echo_target(), EchoTarget::Semantic);
+
+        let mut save = serde_json::to_value(&ego).unwrap();
+        save.as_object_mut().unwrap().remove("echo_target");
+        std::fs::write(&path, save.to_string()).unwrap();
+        assert_eq!(FractalReservoir::load_from(path.to_str().unwrap(), 50, 0.2).echo_target(), EchoTarget::WholeBrain);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn the_echo_order_is_cached_until_the_brain_changes_shape() {
+        let fresh = |ego: &FractalReservoir| {
+            let mut copy = ego.clone();
+            copy.echo_order = None;
+            copy.echo_field().to_vec()
+        };
+        let mut ego = FractalReservoir::seeded(300, 16, 0.95, 0.2, 26);
+        let order = ego.echo_field().to_vec();
+        assert!(!order.is_empty());
+        assert_eq!(ego.echo_field(), order.as_slice());
+
+        ego.neurogenesis(40);
+        assert_eq!(ego.echo_field().to_vec(), fresh(&ego), "newborns missing from the echo");
+        let dying: Vec<u64> = ego.neuron_ids().iter().copied().step_by(3).collect();
+        ego.remove_neurons(&dying);
+        assert_eq!(ego.echo_field().to_vec(), fresh(&ego), "dead neurons still in the echo");
+        ego.set_receptive_fields(ReceptiveFieldMap { semantic: vec![FieldShape::Shell { inner: 0.0, outer: 0.5 }], ..ReceptiveFieldMap::default() });
+        assert_eq!(ego.echo_field().to_vec(), fresh(&ego), "the echo ignored a new map");
+    }
+
+    #[test]
+    fn the_region_map_is_rescanned_only_when_the_topology_changes() {
+        let (seed, size) = (7, 300);
+        let mut ego = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+
+        ego.region_map();
+        let (scans, version) = (ego.region_scans, ego.topology_version);
+        assert!(scans == 1 && version == 1, "first read: {} scans, version {} (expected 1, 1)", scans, version);
+
+        for _ in 0..100 {
+            ego.region_map();
+        }
+        let silence = vec![0.0; size];
+        for _ in 0..100 {
+            ego.tick(&silence, 0.0, 0.0, 0.0, 1.0 / 60.0); // No audio, no chemistry: no exposure
+            ego.region_map();
+        }
+        assert!(ego.region_scans == scans && ego.topology_version == version, "200 reads without exposure change: {} scans (expected {}), version {} (expected {})",
+            ego.region_scans, scans, ego.topology_version, version);
+
+        let light = vec![1.0; size];
+        let mut rescans = 0;
+        for i in 0..200 {
+            ego.inject_embedding(&light, NeuronRegion::Visual);
+            let before = ego.region_scans;
+            let cached = ego.region_map().to_vec();
+            if ego.region_scans > before {
+                rescans += 1;
+                assert_eq!(cached, ego.get_region_map(), "injection {}: re-scanned map differs from a fresh scan", i);
+            }
+        }
+        assert_ne!(rescans, 0, "200 visual injections never triggered a re-scan");
+        assert_ne!(ego.topology_version, version, "visual exposure never changed the region map (version not bumped)");
+
+        let scans = ego.region_scans;
+        for _ in 0..100 {
+            ego.region_map();
+        }
+        assert_eq!(ego.region_scans, scans, "100 reads after the injections: {} re-scans (expected 0)", ego.region_scans - scans);
+
+        let (scans, version) = (ego.region_scans, ego.topology_version);
+        ego.neurogenesis(3);
+        let len = ego.region_map().len();
+        assert!(ego.region_scans == scans + 1 && ego.topology_version == version + 1 && len == ego.current_size(), "after neurogenesis: {} new scans, version +{}, map of {} for {} neurons",
+            ego.region_scans - scans, ego.topology_version - version, len, ego.current_size());
+    }
+
+    #[test]
+    fn receptive_fields_are_data_in_brain_radii() {
+        let (seed, size) = (7, 2500);
+        let ego = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+        let map = ReceptiveFieldMap::default();
+        let positions = ego.get_positions().clone();
+
+        let differing: usize = NeuronRegion::ALL.iter()
+            .map(|&r| positions.iter().filter(|&&p| map.contains(r, p, BRAIN_RADIUS) != legacy_field(r, p)).count())
+            .sum();
+        assert_eq!(differing, 0, "default map disagrees with the original planes on {} neuron/field pairs", differing);
+
+        // Reference coverage: a fine sample of the unit ball (rejection sampling, xorshift)
+        let mut state = seed | 1;
+        let mut unit = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0
+        };
+        let mut ball = Vec::with_capacity(100_000);
+        while ball.len() < 100_000 {
+            let p = [unit(), unit(), unit()];
+            if p[0] * p[0] + p[1] * p[1] + p[2] * p[2] <= 1.0 {
+                ball.push(p);
+            }
+        }
+        let reference = map.coverage(&ball, 1.0);
+        let coverage = map.coverage(&positions, BRAIN_RADIUS);
+        for ((region, got), (_, expected)) in coverage.iter().zip(&reference) {
+            assert!((got - expected).abs() <= 0.04, "{} field covers {:.1}% of a fresh brain (a uniform ball: {:.1}%)",
+                region_name(region.as_id()), got * 100.0, expected * 100.0);
+        }
+        let warnings = map.validate(&positions, BRAIN_RADIUS);
+        assert!(warnings.is_empty(), "default map on a fresh brain: {}", warnings.join("; "));
+        let doubled: Vec<[f32; 3]> = positions.iter().map(|p| [p[0] * 2.0, p[1] * 2.0, p[2] * 2.0]).collect();
+        assert_eq!(map.coverage(&doubled, BRAIN_RADIUS * 2.0), coverage, "the same map covers different neurons on a brain of twice the radius");
+
+        let with = |region: NeuronRegion, shapes: Vec<FieldShape>| {
+            let mut custom = ReceptiveFieldMap::default();
+            match region {
+                NeuronRegion::Semantic => custom.semantic = shapes,
+                NeuronRegion::Auditory => custom.auditory = shapes,
+                NeuronRegion::Limbic => custom.limbic = shapes,
+                NeuronRegion::Association => custom.association = shapes,
+                NeuronRegion::Visual => custom.visual = shapes,
+            }
+            custom
+        };
+        let core = FieldShape::Shell { inner: 0.0, outer: 0.5 };
+        let cases = [
+            ("empty visual field", with(NeuronRegion::Visual, Vec::new()), true),
+            ("visual plane outside the sphere", with(NeuronRegion::Visual, vec![FieldShape::HalfSpace { normal: [0.0, 0.0, -1.0], offset: 1.2, mirrored: false }]), true),
+            ("visual field = almost everything", with(NeuronRegion::Visual, vec![FieldShape::HalfSpace { normal: [0.0, 0.0, -1.0], offset: -0.9, mirrored: false }]), true),
+            ("visual core (r < 0.5)", with(NeuronRegion::Visual, vec![core]), false),
+            ("visual cone (60°, backwards)", with(NeuronRegion::Visual, vec![FieldShape::Cone { axis: [0.0, 0.0, -1.0], half_angle_deg: 60.0, min_radius: 0.0 }]), false),
+        ];
+        for (name, custom, warns) in cases {
+            let warnings = custom.validate(&positions, BRAIN_RADIUS);
+            assert_ne!(warnings.is_empty(), warns, "{}: {} (expected {})", name,
+                if warnings.is_empty() { "no warning".to_string() } else { warnings.join("; ") },
+                if warns { "a warning" } else { "none" });
+        }
+
+        // Masking follows the map: only the core takes a visual embedding
+        let mut masked = ego.clone();
+        masked.set_receptive_fields(with(NeuronRegion::Visual, vec![core]));
+        let inside = masked.field_mask(NeuronRegion::Visual);
+        let before = masked.state.clone();
+        let embedding = vec![1.0; size];
+        masked.inject_embedding(&embedding, NeuronRegion::Visual);
+        let changed: Vec<usize> = (0..size).filter(|&i| masked.state[i] != before[i]).collect();
+        assert!(!changed.is_empty() && !changed.iter().any(|&i| !inside[i]), "visual injection changed {} neurons, {} outside the core field",
+            changed.len(), changed.iter().filter(|&&i| !inside[i]).count());
+        let driven = masked.driven_neurons(&embedding, NeuronRegion::Visual, 16);
+        assert!(!driven.is_empty() && !driven.iter().any(|&i| !inside[i]), "driven_neurons picked {:?} outside the core field", driven.iter().filter(|&&i| !inside[i]).collect::<Vec<_>>());
+
+        // Saved with the brain; older saves get the original planes
+        match serde_json::to_string(&masked).and_then(|json| serde_json::from_str::<FractalReservoir>(&json)) {
+            Ok(loaded) if loaded.receptive_fields() == masked.receptive_fields() && loaded.brain_radius == BRAIN_RADIUS => {},
+            Ok(_) => panic!("the receptive field map changed through a save"),
+            Err(e) => panic!("save round trip failed: {}", e),
+        }
+        let old = serde_json::to_value(&masked).map(|mut v| {
+            if let Some(fields) = v.as_object_mut() {
+                fields.remove("receptive_fields");
+                fields.remove("brain_radius");
+            }
+            v
+        });
+        match old.and_then(serde_json::from_value::<FractalReservoir>) {
+            Ok(loaded) if *loaded.receptive_fields() == map && loaded.brain_radius == BRAIN_RADIUS => {},
+            Ok(loaded) => panic!("a save without a map loads {:?} at radius {}", loaded.receptive_fields(), loaded.brain_radius),
+            Err(e) => panic!("a save without a map no longer loads: {}", e),
+        }
+    }
+
+    #[test]
+    fn the_semantic_echo_lands_on_the_semantic_field_in_vocabulary_order() {
+        let (seed, size) = (7, 2500);
+        let mut base = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+        let input: Vec<f32> = (0..base.input_channels()).map(|i| (i * 7919 % 13) as f32 / 13.0 - 0.5).collect();
+        for _ in 0..10 {
+            base.tick(&input, 0.5, 0.2, 0.1, 1.0 / 60.0); // Some activity: "unchanged" must not just mean "still 0"
+        }
+        let mut state = seed | 1;
+        let logits: Vec<f32> = (0..32000).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 4000) as f32 / 100.0 - 10.0
+        }).collect();
+        let mask = base.field_mask(NeuronRegion::Semantic);
+        let inside = mask.iter().filter(|&&m| m).count();
+        let moved = |fed: &FractalReservoir, i: usize| fed.state[i].to_bits() != base.state[i].to_bits()
+            || fed.semantic_exposure[i].to_bits() != base.semantic_exposure[i].to_bits();
+
+        // 1. Semantic: the field and nothing else
+        assert!(inside > 0 && inside < size, "the Semantic field covers {} of {} neurons: nothing to tell apart", inside, size);
+        let mut fed = base.clone();
+        fed.inject_logits(&logits);
+        let outside_moved = (0..size).filter(|&i| !mask[i] && moved(&fed, i)).count();
+        let inside_moved = (0..size).filter(|&i| mask[i] && moved(&fed, i)).count();
+        assert_eq!(outside_moved, 0, "semantic echo changed {} neurons outside the Semantic field", outside_moved);
+        assert!(inside_moved * 2 >= inside, "semantic echo reached {} of the field's {} neurons", inside_moved, inside);
+
+        // 2. Neighboring vocabulary on neighboring cortex
+        let order = base.clone().echo_field().to_vec();
+        let distance = |a: usize, b: usize| {
+            let (p, q) = (base.positions[a], base.positions[b]);
+            ((p[0] - q[0]).powi(2) + (p[1] - q[1]).powi(2) + (p[2] - q[2]).powi(2)).sqrt()
+        };
+        let consecutive = order.windows(2).map(|w| distance(w[0], w[1])).sum::<f32>() / (order.len() - 1).max(1) as f32;
+        let half = order.len() / 2;
+        let random = (0..half).map(|k| distance(order[k], order[(k * 7919 + half) % order.len()])).sum::<f32>() / half.max(1) as f32;
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert!(order.len() == inside && sorted.len() == inside && sorted.iter().all(|&i| mask[i]), "echo order has {} neurons for a field of {}", order.len(), inside);
+        assert!(consecutive * 2.0 <= random, "consecutive echo neurons are {:.1} apart, random field pairs {:.1}", consecutive, random);
+
+        // 3. Whole brain: the legacy formula, neuron by neuron
+        let legacy = |fed: &FractalReservoir| {
+            let pooled = max_pool_logits(&logits, size);
+            (0..size).filter(|&i| {
+                let expected = (base.state[i] + (pooled[i] * 0.1).tanh()).clamp(-1.0, 1.0);
+                fed.state[i].to_bits() != expected.to_bits()
+            }).count()
+        };
+        let mut whole = base.clone();
+        whole.set_echo_target(EchoTarget::WholeBrain);
+        whole.inject_logits(&logits);
+        let mut fieldless = base.clone();
+        fieldless.set_receptive_fields(ReceptiveFieldMap { semantic: Vec::new(), ..ReceptiveFieldMap::default() });
+        fieldless.inject_logits(&logits);
+        let (whole_off, whole_outside) = (legacy(&whole), (0..size).filter(|&i| !mask[i] && moved(&whole, i)).count());
+        assert!(whole_off == 0 && whole_outside != 0, "whole-brain echo: {} neurons off the legacy formula, {} outside the field moved", whole_off, whole_outside);
+        assert_eq!(legacy(&fieldless), 0, "semantic echo with an empty field: {} neurons off the legacy formula", legacy(&fieldless));
+    }
+
+    #[test]
+    fn growing_the_inputs_keeps_every_weight() {
+        let (seed, size, grow) = (7, 300, 64);
+        let old = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+        let mut grown = old.clone();
+        grown.grow_inputs(grow);
+        let cols = old.input_channels();
+
+        assert!(grown.input_channels() == cols + grow && grown.input_size == cols + grow && grown.input_weights.nrows() == size, "grew {} -> {} columns (input_size {}), expected {}", cols, grown.input_channels(), grown.input_size, cols + grow);
+        let changed = (0..size).flat_map(|r| (0..cols).map(move |c| (r, c)))
+            .filter(|&(r, c)| grown.input_weights[(r, c)].to_bits() != old.input_weights[(r, c)].to_bits())
+            .count();
+        assert_eq!(changed, 0, "{} existing input weights changed", changed);
+
+        let wired = (0..size).flat_map(|r| (cols..cols + grow).map(move |c| (r, c)))
+            .filter(|&(r, c)| grown.input_weights[(r, c)] != 0.0)
+            .count();
+        let density = wired as f32 / (size * grow).max(1) as f32;
+        assert!((0.05..=0.3).contains(&density), "new columns are {:.1}% wired (genesis: ~15%)", density * 100.0);
+
+        let (mut wide, mut narrow) = (grown.clone(), grown.clone());
+        let entropy_wide = wide.tick(&vec![0.5; cols + grow], 0.3, 0.3, 0.0, 1.0 / 60.0);
+        let entropy_narrow = narrow.tick(&vec![0.5; cols], 0.3, 0.3, 0.0, 1.0 / 60.0);
+        assert!(entropy_wide.is_finite() && entropy_narrow.is_finite() && !wide.state.iter().any(|x| !x.is_finite()), "the grown reservoir does not tick cleanly");
+        assert_ne!(wide.state, narrow.state, "the new channels have no effect on the state");
+
+        // Wider than the matrix: the tail is dropped (same state as the truncated input), warned once
+        let (mut over, mut exact) = (grown.clone(), grown.clone());
+        let input: Vec<f32> = (0..cols + grow + 16).map(|i| (i % 7) as f32 / 7.0).collect();
+        over.tick(&input, 0.3, 0.3, 0.0, 1.0 / 60.0);
+        let warned = over.input_overflow;
+        over.tick(&input, 0.3, 0.3, 0.0, 1.0 / 60.0);
+        exact.tick(&input[..cols + grow], 0.3, 0.3, 0.0, 1.0 / 60.0);
+        exact.tick(&input[..cols + grow], 0.3, 0.3, 0.0, 1.0 / 60.0);
+        assert!(over.state == exact.state && warned && !exact.input_overflow, "overlong input: same state as truncated {}, warned {}, warned without overflow {}",
+            over.state == exact.state, warned, exact.input_overflow);
+
+        match serde_json::to_string(&grown).and_then(|json| serde_json::from_str::<FractalReservoir>(&json)) {
+            Ok(loaded) if loaded.input_weights == grown.input_weights && loaded.input_size == grown.input_size => {},
+            Ok(_) => panic!("grown input weights changed through a save"),
+            Err(e) => panic!("save round trip failed: {}", e),
+        }
+    }
+
+    #[test]
+    fn replaying_a_pattern_strengthens_only_its_synapses() {
+        let (seed, size, replays) = (7, 256, 50);
+        let ReplayConfig { gain, reinforcement, .. } = ReplayConfig::default();
+        let mut ego = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+        let pattern: Vec<f32> = (0..SIGNATURE_LEN).map(|b| if b < SIGNATURE_LEN / 4 { 1.0 } else { 0.5 }).collect();
+        let active: Vec<bool> = (0..ego.size).map(|i| i * SIGNATURE_LEN / ego.size < SIGNATURE_LEN / 4).collect();
+        let sums = |ego: &FractalReservoir| {
+            let (mut inside, mut outside) = (0.0f64, 0.0f64);
+            for i in 0..ego.size {
+                for j in 0..ego.size {
+                    let w = ego.weights[(i, j)] as f64;
+                    if active[i] && active[j] { inside += w } else { outside += w }
+                }
+            }
+            (inside, outside)
+        };
+
+        let (inside_before, outside_before) = sums(&ego);
+        let mut changes = 0;
+        for _ in 0..replays {
+            ego.replay_pattern(&pattern, gain);
+            changes += ego.hebbian_update(reinforcement, 1.0 / 60.0);
+        }
+        let (inside_after, outside_after) = sums(&ego);
+
+        assert_ne!(changes, 0, "{} replays: the Hebbian update changed no synapse", replays);
+        assert!(inside_after > inside_before, "weights among the replayed neurons did not grow: {:.4} -> {:.4}", inside_before, inside_after);
+        // Exact for gain > 0.75: the pattern then decides alone which neurons pass the 0.5 activity threshold
+        assert!(gain > 0.75);
+        assert_eq!(outside_after, outside_before, "weights outside the pattern moved: {:.4} -> {:.4}", outside_before, outside_after);
+    }
+
+    #[test]
+    fn a_loud_sound_takes_the_spotlight_and_a_quiet_one_does_not() {
+        let (seed, size, ticks) = (7, 400, 120);
+        let mut scene_rng = StdRng::seed_from_u64(seed);
+        let scene: Vec<f32> = (0..128).map(|_| scene_rng.random_range(-0.3..0.3)).collect();
+        let silence = vec![0.0; size];
+        for (name, loudness) in [("loud", 1.0f32), ("quiet", 0.02)] {
+            let mut ego = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+            let sound = vec![loudness; 64];
+            for _ in 0..ticks {
+                ego.inject_embedding(&scene, NeuronRegion::Visual);
+                ego.inject_embedding(&sound, NeuronRegion::Auditory);
+                ego.tick(&silence, 0.3, 0.2, 0.1, 1.0 / 60.0);
+            }
+            let breakdown = ego.attention_breakdown();
+            let share = breakdown[&Pathway::Auditory];
+            let (top, top_share) = breakdown.iter()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(p, s)| (*p, *s))
+                .unwrap_or((Pathway::Input, 0.0));
+            let total: f32 = breakdown.values().sum();
+            assert!((total - 1.0).abs() <= 1e-3, "{} audio: breakdown sums to {:.4}, not 1.0", name, total);
+            if loudness >= 1.0 {
+                assert!(top == Pathway::Auditory && share > 0.5, "loud audio: auditory share {:.2}, spotlight on {:?} ({:.2})", share, top, top_share);
+            } else {
+                assert!(top != Pathway::Auditory && share < 0.2, "quiet audio: auditory share {:.2}, spotlight on {:?} ({:.2})", share, top, top_share);
+            }
+        }
+    }
+
+    #[test]
+    fn the_inspector_reports_birth_age_and_strongest_synapses() {
+        let (seed, size, ticks) = (7, 300, 40u64);
+        let mut ego = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+        let silence = vec![0.0; size];
+        for _ in 0..ticks {
+            ego.tick(&silence, 0.0, 0.0, 0.0, 1.0 / 60.0);
+        }
+        ego.neurogenesis(1);
+        let id = size;
+
+        assert_eq!(ego.birth_tick().len(), size + 1);
+        assert!(ego.birth_tick()[..size].iter().all(|&t| t == 0), "genesis neurons born after tick 0");
+        assert_eq!(ego.birth_tick().get(id), Some(&ticks), "grown neuron born on {:?}, expected tick {}", ego.birth_tick().get(id), ticks);
+
+        let map = ego.region_map().to_vec();
+        let report = ego.inspect(id as u64, &map).expect("the neuron just grown has no report");
+        assert!(report.birth_tick == ticks && report.age_ticks == 0, "newborn report: born {}, age {} (expected {}, 0)", report.birth_tick, report.age_ticks, ticks);
+        assert!(report.region == region_name(map[id]) && report.exposure.len() == NeuronRegion::ALL.len(), "newborn report: region {} (map says {}), {} exposures",
+            report.region, region_name(map[id]), report.exposure.len());
+
+        // Brute force over the matrix: same partners, same order, same totals
+        let expected = |mut all: Vec<(u64, f32)>| {
+            all.retain(|&(_, w)| w != 0.0);
+            let total = all.len();
+            all.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()).then(a.0.cmp(&b.0)));
+            all.truncate(INSPECT_SYNAPSES);
+            (all, total)
+        };
+        let listed = |list: &[Synapse]| list.iter().map(|s| (s.partner, s.weight)).collect::<Vec<_>>();
+        let n = ego.current_size();
+        for (side, list, total, (want, want_total)) in [
+            ("incoming", &report.incoming, report.incoming_total, expected((0..n).map(|j| (j as u64, ego.weights[(id, j)])).collect())),
+            ("outgoing", &report.outgoing, report.outgoing_total, expected((0..n).map(|j| (j as u64, ego.weights[(j, id)])).collect())),
+        ] {
+            assert!(listed(list) == want && total == want_total, "{}: listed {:?} of {}, the matrix says {:?} of {}", side, listed(list), total, want, want_total);
+            assert!(list.iter().all(|s| s.region == region_name(map[s.partner as usize])), "{}: a partner's region disagrees with the map", side);
+        }
+
+        ego.weights[(id, 0)] = 5.0;
+        ego.tick(&silence, 0.0, 0.0, 0.0, 1.0 / 60.0);
+        let map = ego.region_map().to_vec();
+        let (report, source) = (ego.inspect(id as u64, &map).ok(), ego.inspect(0, &map).ok());
+        assert_eq!(report.as_ref().and_then(|r| r.incoming.first()).map(|s| (s.partner, s.weight)), Some((0, 5.0)), "planted synapse 0 -> newcomer is not its strongest incoming");
+        assert_eq!(source.as_ref().and_then(|r| r.outgoing.first()).map(|s| (s.partner, s.weight)), Some((id as u64, 5.0)), "planted synapse 0 -> newcomer is not neuron 0's strongest outgoing");
+        assert_eq!(report.map(|r| r.age_ticks), Some(1), "the newcomer did not age one tick");
+
+        assert!(ego.inspect(n as u64, &map).is_err() && ego.inspect(u64::MAX, &map).is_err(), "ids past the last neuron ({}) answered", n - 1);
+    }
+
+    #[test]
+    fn a_lesioned_field_ignores_its_input_until_cleared() {
+        let (seed, size, ticks) = (7, 400, 60);
+        let mut ego = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+        let auditory = ego.field_mask(NeuronRegion::Auditory);
+        let semantic_only: Vec<bool> = ego.field_mask(NeuronRegion::Semantic).iter().zip(&auditory).map(|(&s, &a)| s && !a).collect();
+        let sound = vec![0.8f32; 64];
+        let words = vec![0.8f32; 64];
+        let silence = vec![0.0; size];
+        // Largest |Δstate| over the masked neurons caused by one injection
+        let moved = |ego: &mut FractalReservoir, region: NeuronRegion, embedding: &[f32], mask: &[bool]| {
+            let before = ego.state.clone();
+            ego.inject_embedding(embedding, region);
+            mask.iter().enumerate().filter(|&(_, &m)| m).map(|(i, _)| (ego.state[i] - before[i]).abs()).fold(0.0f32, f32::max)
+        };
+
+        assert!(auditory.contains(&true) && semantic_only.contains(&true), "the default fields leave the Auditory or the Semantic-only set empty");
+        let intact = moved(&mut ego.clone(), NeuronRegion::Auditory, &sound, &auditory);
+        assert!(intact >= 1e-3, "intact brain: audio moved the Auditory field by {:.5}", intact);
+
+        let silenced = ego.set_lesion(&auditory);
+        let weights = ego.weights.clone();
+        assert!(silenced == auditory.iter().filter(|&&m| m).count() && ego.lesioned().len() == silenced, "set_lesion silenced {} ({} listed), the field has {}",
+            silenced, ego.lesioned().len(), auditory.iter().filter(|&&m| m).count());
+        let lesioned_moved = moved(&mut ego, NeuronRegion::Auditory, &sound, &auditory);
+        assert_eq!(lesioned_moved, 0.0, "lesioned field moved by {:.5} under audio", lesioned_moved);
+        let semantic_moved = moved(&mut ego, NeuronRegion::Semantic, &words, &semantic_only);
+        assert!(semantic_moved >= 1e-3, "Semantic injection moved the Semantic-only neurons by {:.5} during the lesion", semantic_moved);
+
+        for _ in 0..ticks {
+            ego.inject_embedding(&sound, NeuronRegion::Auditory);
+            ego.tick(&silence, 0.8, 0.0, 0.3, 1.0 / 60.0);
+            ego.hebbian_update(0.8, 1.0 / 60.0);
+        }
+        let lesioned = ego.lesioned();
+        assert!(lesioned.iter().all(|&i| ego.state[i] == 0.0 && ego.last_activity[i] == 0.5), "{} lesioned neurons left rest over {} ticks", lesioned.iter().filter(|&&i| ego.state[i] != 0.0).count(), ticks);
+        let touched = lesioned.iter()
+            .flat_map(|&i| (0..size).flat_map(move |j| [(i, j), (j, i)]))
+            .filter(|&(i, j)| ego.weights[(i, j)] != weights[(i, j)])
+            .count();
+        assert_eq!(touched, 0, "{} synapses of lesioned neurons changed", touched);
+
+        ego.neurogenesis(1);
+        assert!(!ego.lesioned().contains(&size) && ego.lesioned().len() == lesioned.len(), "a neuron born during the lesion came out silenced (or the lesion moved)");
+
+        ego.clear_lesion();
+        let restored = moved(&mut ego, NeuronRegion::Auditory, &sound, &auditory);
+        assert!(restored >= 1e-3, "after clear_lesion audio moved the Auditory field by {:.5}", restored);
+        for _ in 0..ticks {
+            ego.inject_embedding(&sound, NeuronRegion::Auditory);
+            ego.tick(&silence, 0.3, 0.0, 0.1, 1.0 / 60.0);
+        }
+        assert!(!lesioned.iter().all(|&i| ego.state[i] == 0.0) && ego.lesioned().is_empty(), "the field stayed silent after clear_lesion");
+    }
+
+    #[test]
+    fn neuron_ids_survive_deaths_and_are_never_reused() {
+        let (seed, size) = (7, 300);
+        let mut ego = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+        let silence = vec![0.0; size];
+
+        assert!(ego.neuron_ids().iter().enumerate().all(|(i, &id)| id == i as u64), "genesis neurons are not numbered 0..size");
+        ego.tick(&silence, 0.0, 0.0, 0.0, 1.0 / 60.0);
+        ego.neurogenesis(4);
+        assert_eq!(ego.neuron_ids()[size..], [size as u64, size as u64 + 1, size as u64 + 2, size as u64 + 3], "grown neurons got IDs {:?}, expected {}..{}", &ego.neuron_ids()[size..], size, size + 4);
+
+        // What an outside system would remember about each neuron, by ID
+        let fingerprint = |ego: &FractalReservoir, id: u64| {
+            ego.index_of(id).map(|i| (ego.positions[i], ego.bias[i], ego.birth_tick[i]))
+        };
+        let held: Vec<u64> = ego.neuron_ids().to_vec();
+        let before: Vec<_> = held.iter().map(|&id| fingerprint(&ego, id)).collect();
+        let survivor = |id: u64| id % 3 != 0 && id != size as u64 + 1; // Every third genesis neuron and one newborn die
+        let (a, b) = (1u64, size as u64 + 2);
+        ego.weights[(b as usize, a as usize)] = 0.75; // No deaths yet: ID = index
+        let lesion: Vec<bool> = held.iter().map(|&id| id < 12).collect();
+        ego.set_lesion(&lesion);
+        let dying: Vec<u64> = held.iter().copied().filter(|&id| !survivor(id)).collect();
+
+        let died = ego.remove_neurons(&dying);
+        let again = ego.remove_neurons(&dying);
+        assert!(died == dying.len() && again == 0, "remove_neurons: {} died of {} (then {} of the same again, expected 0)", died, dying.len(), again);
+        let n = held.len() - dying.len();
+        let lengths = [ego.current_size(), ego.weights.nrows(), ego.weights.ncols(), ego.input_weights.nrows(), ego.state.len(),
+            ego.bias.len(), ego.positions.len(), ego.birth_tick.len(), ego.neuron_ids().len(), ego.last_activity.len(),
+            ego.semantic_exposure.len(), ego.visual_exposure.len()];
+        assert!(lengths.iter().all(|&l| l == n), "after removing {}: per-neuron lengths {:?}, expected {}", dying.len(), lengths, n);
+
+        let map = ego.region_map().to_vec();
+        let (mut moved, mut zombies, mut lost) = (0, 0, 0);
+        for (&id, old) in held.iter().zip(&before) {
+            match (survivor(id), ego.resolve(id)) {
+                (true, NeuronLookup::Alive { .. }) => if fingerprint(&ego, id) != *old { moved += 1; },
+                (false, NeuronLookup::Deceased) => if ego.inspect(id, &map).err() != Some(NeuronLookup::Deceased) { zombies += 1; },
+                (true, _) => lost += 1,
+                (false, _) => zombies += 1,
+            }
+        }
+        assert_eq!(moved, 0, "{} surviving IDs now resolve to a different neuron", moved);
+        assert_eq!(zombies, 0, "{} removed IDs do not report Deceased", zombies);
+        assert_eq!(lost, 0, "{} surviving IDs no longer resolve", lost);
+        let kept = ego.index_of(b).zip(ego.index_of(a)).map(|(post, pre)| ego.weights[(post, pre)]);
+        assert_eq!(kept, Some(0.75), "synapse {} -> {} between survivors reads {:?} after the deaths, expected 0.75", a, b, kept);
+        assert!(ego.resolve(u64::MAX) == NeuronLookup::Unborn && ego.inspect(u64::MAX, &map).err() == Some(NeuronLookup::Unborn), "an ID never handed out does not report Unborn");
+        let lesioned_expected: Vec<u64> = (0..12).filter(|&id| survivor(id)).collect();
+        assert_eq!(ego.lesioned_ids(), lesioned_expected, "lesion after the deaths: {:?}, expected {:?}", ego.lesioned_ids(), lesioned_expected);
+        ego.tick(&silence, 0.0, 0.0, 0.0, 1.0 / 60.0);
+        assert!(ego.region_map().len() == n && ego.get_activity_snapshot().len() == n, "after a tick: region map {} / activity {} for {} neurons", ego.region_map().len(), ego.get_activity_snapshot().len(), n);
+
+        ego.neurogenesis(2);
+        let newest = &ego.neuron_ids()[n..];
+        assert_eq!(newest, [size as u64 + 4, size as u64 + 5], "born after the deaths: IDs {:?}, expected {} and {}", newest, size + 4, size + 5);
+
+        let restored = serde_json::to_string(&ego).and_then(|json| serde_json::from_str::<FractalReservoir>(&json));
+        match restored {
+            Ok(mut restored) => {
+                restored.adopt_ids();
+                assert!(restored.neuron_ids() == ego.neuron_ids() && restored.resolve(dying[0]) == NeuronLookup::Deceased, "IDs changed across a save");
+            },
+            Err(e) => panic!("save round trip failed: {}", e),
+        }
+        let old = serde_json::to_value(FractalReservoir::seeded(8, 8, 0.95, 0.2, seed)).map(|mut v| {
+            if let Some(fields) = v.as_object_mut() {
+                fields.remove("ids");
+                fields.remove("next_id");
+            }
+            v
+        });
+        match old.and_then(serde_json::from_value::<FractalReservoir>) {
+            Ok(mut legacy) => {
+                legacy.adopt_ids();
+                assert!(legacy.neuron_ids() == [0, 1, 2, 3, 4, 5, 6, 7] && legacy.resolve(8) == NeuronLookup::Unborn, "a save without IDs loaded as {:?}", legacy.neuron_ids());
+            },
+            Err(e) => panic!("a save without IDs failed to load: {}", e),
+        }
+    }
+
+    #[test]
+    fn neurogenesis_follows_each_fields_demand() {
+        let (seed, size, ticks, grow) = (7, 400, 600, 60);
+        let mut ego = FractalReservoir::seeded(size, size, 0.95, 0.2, seed);
+        let semantic = NeuronRegion::Semantic.as_id() as usize;
+        let share = ego.receptive_fields.coverage(&ego.positions, ego.brain_radius)[semantic].1;
+
+        // No demand yet: growth falls back to the most active neuron
+        let mut fresh = ego.clone();
+        fresh.neurogenesis(1);
+        assert!(fresh.current_size() == size + 1 && fresh.growth_stats().fields.iter().all(|f| f.probability == 0.0), "fresh brain: grew to {} (expected {}), odds {:?}", fresh.current_size(), size + 1,
+            fresh.growth_stats().fields.iter().map(|f| f.probability).collect::<Vec<_>>());
+
+        // Words only (a xorshift stream of embeddings, ±1)
+        let mut state = seed | 1;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state & 1 == 0 { 1.0f32 } else { -1.0 }
+        };
+        let silence = vec![0.0; size];
+        for _ in 0..ticks {
+            let words: Vec<f32> = (0..64).map(|_| next()).collect();
+            ego.inject_embedding(&words, NeuronRegion::Semantic);
+            ego.tick(&silence, 0.0, 0.0, 0.0, 1.0 / 60.0);
+        }
+        let before = ego.growth_stats();
+        let busiest = before.fields.iter().max_by(|a, b| a.excess.total_cmp(&b.excess)).map(|f| f.region);
+        assert_eq!(busiest, Some("Semantic"), "after {} ticks of words the most demanding field is {:?}: {:?}", ticks, busiest,
+            before.fields.iter().map(|f| (f.region, f.excess)).collect::<Vec<_>>());
+
+        ego.neurogenesis(grow);
+        let after = ego.growth_stats();
+        let born: Vec<u64> = after.fields.iter().zip(&before.fields).map(|(a, b)| a.born - b.born).collect();
+        let others = born.iter().enumerate().filter(|&(r, _)| r != semantic).map(|(_, &n)| n).max().unwrap_or(0);
+        assert!(born[semantic] > others, "growth after words: {:?} per field (NeuronRegion::ALL order), Semantic not ahead", born);
+        assert!((born[semantic] as f32) >= 2.0 * share * grow as f32, "{} of {} new neurons in the Semantic field, its share of the sphere is {:.0}%",
+            born[semantic], grow, share * 100.0);
+        let radius = ego.brain_radius;
+        let outside = ego.positions[size..].iter().filter(|p| (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt() > radius).count();
+        assert_eq!(outside, 0, "{} new neurons placed outside the brain", outside);
+        let census = after.history.last().map(|c| c.neurons[semantic]);
+        assert!(after.history.len() == 1 && census == Some(after.fields[semantic].neurons), "{} censuses after one growth, last Semantic count {:?} (field has {})",
+            after.history.len(), census, after.fields[semantic].neurons);
+    }
 }
@@ -0,0 +1,259 @@
+// src/core/saccade.rs
+// THE GAZE: One stimulus at a time, and then the next
+//
+// Audio, vision and words were always blended: every embedding went in at the
+// same gain, so two competing scenes became one muddy one. The gaze picks a
+// dominant pathway among those that have input right now, boosts its injection
+// (x boost) and attenuates the rest (x attenuation), and moves on:
+//
+//   - on a stochastic timer: dwell ~ Exponential(mean), mean = dwell_secs * (1.5 - dopamine)
+//     (dopamine makes the gaze restless: more switches, shorter fixations)
+//   - or early, once the fixated pathway stopped bringing anything new
+//     (novelty below the floor after min_dwell_secs)
+//
+// The next target is drawn among the other active pathways, weighted by novelty.
+// Novelty per pathway = mean |x - running mean of x| of its embeddings. With a
+// single active pathway (or the gaze disabled) every gain is 1.0: plain blending.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+use crate::core::config::SaccadeConfig;
+use crate::core::reservoir::Pathway;
+
+/// Pathways the gaze moves between (the ones fed by inject_embedding from the senses).
+pub const COMPETING: [Pathway; 3] = [Pathway::Auditory, Pathway::Visual, Pathway::Semantic];
+/// A pathway with no input for this long is not competing.
+const ACTIVE_WINDOW: Duration = Duration::from_secs(1);
+/// Running-mean rate of the novelty baseline (per embedding).
+const BASELINE_RATE: f32 = 0.05;
+/// Novelty smoothing (per embedding).
+const NOVELTY_RATE: f32 = 0.2;
+
+#[derive(Default)]
+struct Channel {
+    baseline: Vec<f32>,
+    novelty: f32,
+    last_seen: Option<Instant>,
+}
+
+pub struct Gaze {
+    config: SaccadeConfig,
+    rng: StdRng,
+    channels: [Channel; 3],
+    focus: Option<Pathway>,
+    fixated_at: Option<Instant>,
+    switch_at: Option<Instant>,
+    pub shifts: u64, // Attention shifts since boot
+}
+
+fn slot(pathway: Pathway) -> Option<usize> {
+    COMPETING.iter().position(|&p| p == pathway)
+}
+
+impl Gaze {
+    pub fn new(config: SaccadeConfig) -> Self {
+        Self::with_rng(config, StdRng::from_os_rng())
+    }
+
+    /// Same switching sequence for the same seed (offline checks).
+    pub fn seeded(config: SaccadeConfig, seed: u64) -> Self {
+        Self::with_rng(config, StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(config: SaccadeConfig, rng: StdRng) -> Self {
+        Self {
+            config,
+            rng,
+            channels: Default::default(),
+            focus: None,
+            fixated_at: None,
+            switch_at: None,
+            shifts: 0,
+        }
+    }
+
+    pub fn focus(&self) -> Option<Pathway> {
+        self.focus
+    }
+
+    /// An embedding is about to be injected: learn its novelty, then scale it in
+    /// place by the gaze (x boost if fixated, x attenuation if competing with it).
+    pub fn attend(&mut self, pathway: Pathway, embedding: &mut [f32], now: Instant) {
+        let Some(i) = slot(pathway) else { return };
+        let channel = &mut self.channels[i];
+        if channel.baseline.len() != embedding.len() {
+            channel.baseline = embedding.to_vec();
+        }
+        let n = embedding.len().max(1) as f32;
+        let surprise = embedding.iter().zip(&channel.baseline).map(|(x, b)| (x - b).abs()).sum::<f32>() / n;
+        channel.novelty += NOVELTY_RATE * (surprise - channel.novelty);
+        for (b, &x) in channel.baseline.iter_mut().zip(embedding.iter()) {
+            *b += BASELINE_RATE * (x - *b);
+        }
+        channel.last_seen = Some(now);
+
+        let gain = self.gain(pathway, now);
+        if gain != 1.0 {
+            embedding.iter_mut().for_each(|x| *x *= gain);
+        }
+    }
+
+    /// Injection gain of a pathway right now (1.0 = no gaze).
+    pub fn gain(&self, pathway: Pathway, now: Instant) -> f32 {
+        if !self.config.enabled || self.active(now).len() < 2 {
+            return 1.0;
+        }
+        match self.focus {
+            Some(focus) if focus == pathway => self.config.boost,
+            Some(_) => self.config.attenuation,
+            None => 1.0,
+        }
+    }
+
+    fn active(&self, now: Instant) -> Vec<Pathway> {
+        COMPETING.iter().zip(&self.channels)
+            .filter(|(_, c)| c.last_seen.is_some_and(|t| now.saturating_duration_since(t) <= ACTIVE_WINDOW))
+            .map(|(&p, _)| p)
+            .collect()
+    }
+
+    fn novelty(&self, pathway: Pathway) -> f32 {
+        slot(pathway).map(|i| self.channels[i].novelty).unwrap_or(0.0)
+    }
+
+    fn mean_dwell(&self, dopamine: f32) -> f32 {
+        (self.config.dwell_secs * (1.5 - dopamine.clamp(0.0, 1.0))).max(0.05)
+    }
+
+    /// Once per tick: move the gaze if its time is up or the fixation went stale.
+    /// Returns the new focus on a shift.
+    pub fn update(&mut self, dopamine: f32, now: Instant) -> Option<Pathway> {
+        if !self.config.enabled {
+            self.focus = None;
+            return None;
+        }
+        let active = self.active(now);
+        if active.len() < 2 {
+            return None; // Nothing competes: blend
+        }
+        let dwelt = self.fixated_at.map(|t| now.saturating_duration_since(t).as_secs_f32()).unwrap_or(f32::MAX);
+        let focus_active = self.focus.is_some_and(|f| active.contains(&f));
+        let timer = self.switch_at.is_none_or(|t| now >= t);
+        let stale = focus_active
+            && dwelt >= self.config.min_dwell_secs
+            && self.novelty(self.focus.unwrap_or(Pathway::Input)) < self.config.novelty_floor;
+        if focus_active && !timer && !stale {
+            return None;
+        }
+
+        // Next target: another active pathway, weighted by novelty (a floor keeps all reachable)
+        let candidates: Vec<Pathway> = active.iter().copied().filter(|&p| Some(p) != self.focus).collect();
+        let weights: Vec<f32> = candidates.iter().map(|&p| self.novelty(p) + 0.01).collect();
+        let mut roll = self.rng.random_range(0.0..weights.iter().sum::<f32>());
+        let mut next = candidates[candidates.len() - 1];
+        for (&p, &w) in candidates.iter().zip(&weights) {
+            if roll < w {
+                next = p;
+                break;
+            }
+            roll -= w;
+        }
+
+        let mean = self.mean_dwell(dopamine);
+        let dwell = -mean * (1.0 - self.rng.random_range(0.0..1.0f32)).ln(); // Exponential
+        let dwell = dwell.max(self.config.min_dwell_secs);
+        self.focus = Some(next);
+        self.fixated_at = Some(now);
+        self.switch_at = Some(now + Duration::from_secs_f32(dwell));
+        self.shifts += 1;
+        Some(next)
+    }
+}
+
+/// "auditory", "visual", "semantic" (for the 👁️ thought).
+pub fn pathway_name(pathway: Pathway) -> &'static str {
+    match pathway {
+        Pathway::Input => "input",
+        Pathway::Auditory => "auditory",
+        Pathway::Visual => "visual",
+        Pathway::Semantic => "semantic",
+        Pathway::Echo => "echo",
+        Pathway::Memory => "memory",
+        Pathway::Chemistry => "chemistry",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: u64 = 7;
+
+    /// Shifts in `secs` simulated seconds at 60Hz with sound and image always
+    /// competing (fresh random embeddings every tick, so novelty never runs out),
+    /// and the share of ticks the gaze scaled something.
+    fn simulate(config: &SaccadeConfig, dopamine: f32, secs: f32) -> (u64, f32) {
+        let mut gaze = Gaze::seeded(config.clone(), SEED);
+        let mut input = StdRng::seed_from_u64(SEED ^ 0x5EED);
+        let start = Instant::now();
+        let mut boosted = 0.0f32;
+        let ticks = (secs * 60.0) as u64;
+        for t in 0..ticks {
+            let now = start + Duration::from_millis(t * 1000 / 60);
+            let mut sound: Vec<f32> = (0..64).map(|_| input.random_range(0.0..1.0)).collect();
+            let mut frame: Vec<f32> = (0..128).map(|_| input.random_range(-1.0..1.0)).collect();
+            gaze.attend(Pathway::Auditory, &mut sound, now);
+            gaze.attend(Pathway::Visual, &mut frame, now);
+            gaze.update(dopamine, now);
+            if gaze.focus.is_some() && gaze.gain(Pathway::Auditory, now) != 1.0 {
+                boosted += 1.0;
+            }
+        }
+        (gaze.shifts, boosted / ticks.max(1) as f32)
+    }
+
+    fn competing() -> SaccadeConfig {
+        SaccadeConfig { enabled: true, novelty_floor: 0.0, ..SaccadeConfig::default() }
+    }
+
+    #[test]
+    fn low_dopamine_fixates_longer_than_high_dopamine() {
+        const SECS: f32 = 600.0;
+        let config = competing();
+        let (calm, calm_gazing) = simulate(&config, 0.1, SECS);
+        assert!(calm > 0, "dopamine 0.1: no shifts");
+        assert!(calm_gazing >= 0.9, "dopamine 0.1: gaze active {:.0}% of the time", calm_gazing * 100.0);
+        let (eager, _) = simulate(&config, 0.9, SECS);
+        assert!(eager as f32 >= calm as f32 * 1.5, "dopamine 0.9: {} shifts vs {} at 0.1 (expected >= 1.5x)", eager, calm);
+        let expected = SECS / (config.dwell_secs * 1.4).max(config.min_dwell_secs);
+        assert!(calm as f32 <= expected * 2.0, "dopamine 0.1: {} shifts in {}s, mean dwell should allow ~{:.0}", calm, SECS, expected);
+    }
+
+    #[test]
+    fn a_stale_fixation_is_left_early() {
+        // An unchanging sound and image (novelty -> 0) are left after min_dwell even
+        // though the timer (mean dwell 1000s) would keep them forever
+        let config = SaccadeConfig { novelty_floor: 0.05, dwell_secs: 1000.0, ..competing() };
+        let mut gaze = Gaze::seeded(config.clone(), SEED);
+        let start = Instant::now();
+        let secs = 10.0f32;
+        for t in 0..(secs * 60.0) as u64 {
+            let now = start + Duration::from_millis(t * 1000 / 60);
+            gaze.attend(Pathway::Auditory, &mut vec![0.5f32; 64], now);
+            gaze.attend(Pathway::Visual, &mut vec![0.5f32; 128], now);
+            gaze.update(0.5, now);
+        }
+        let expected = ((secs / (config.min_dwell_secs + 0.1).max(0.5) / 2.0) as u64).max(2);
+        assert!(gaze.shifts >= expected, "static scene: {} shifts in {}s (expected >= {}, min dwell {}s)",
+            gaze.shifts, secs, expected, config.min_dwell_secs);
+    }
+
+    #[test]
+    fn a_disabled_gaze_never_shifts_or_scales() {
+        let (shifts, gazing) = simulate(&SaccadeConfig { enabled: false, ..competing() }, 0.9, 10.0);
+        assert_eq!(shifts, 0);
+        assert_eq!(gazing, 0.0);
+    }
+}
@@ -1,9 +1,35 @@
-use std::time::Duration;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Auditory share of the attention breakdown above which text is drowned out.
+pub const AUDITORY_SATURATION: f32 = 0.7;
+/// Membrane counters are rolling over this window.
+pub const MEMBRANE_WINDOW: Duration = Duration::from_secs(60);
+
+/// What the membrane did in the last MEMBRANE_WINDOW (telemetry).
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct MembraneStats {
+    pub passed: u64,
+    pub hardened: u64,           // Rejected or drowned out
+    pub ontological_errors: u64, // Tool-like stimuli (severity > 0), passed or not
+    pub attention: f32,          // Attention used by the last filter_input
+}
+
+#[derive(Clone, Copy)]
+struct Verdict {
+    at: Instant,
+    passed: bool,
+    ontological_error: bool,
+}
 
 pub struct Satellite {
     pub paranoia: f32, // 0.0 - 1.0 (Membrane Sensitivity)
     pub _refractive_index: f32, // 0.5 (Neutral)
     pub _lucidity: f32, // 0.0 - 1.0 (Distance from drama)
+    recent: VecDeque<Verdict>,
+    attention: f32,
+    focus: Option<(Instant, f32)>, // Self-requested attention boost (core/volition.rs): until, amount
 }
 
 impl Satellite {
@@ -12,14 +38,57 @@ impl Satellite {
             paranoia,
             _refractive_index: refractive_index,
             _lucidity: 1.0,
+            recent: VecDeque::new(),
+            attention: 0.0,
+            focus: None,
         }
     }
 
+    /// FOCUS: an honored "quiero escuchar" lets `boost` more attention through until `until`.
+    pub fn focus(&mut self, boost: f32, until: Instant) {
+        self.focus = Some((until, boost));
+    }
+
+    /// The focus boost still in force at `now` (0 once it has run out).
+    pub fn focus_bonus(&self, now: Instant) -> f32 {
+        self.focus.filter(|&(until, _)| now < until).map_or(0.0, |(_, boost)| boost)
+    }
+
+    /// A stimulus met the membrane (after filter_input, or drowned out).
+    pub fn record(&mut self, passed: bool, error_severity: f32, attention: f32, now: Instant) {
+        while self.recent.front().is_some_and(|v| now.saturating_duration_since(v.at) > MEMBRANE_WINDOW) {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(Verdict { at: now, passed, ontological_error: error_severity > 0.0 });
+        self.attention = attention;
+    }
+
+    /// Counts over the last MEMBRANE_WINDOW.
+    pub fn stats(&self, now: Instant) -> MembraneStats {
+        let mut stats = MembraneStats { attention: self.attention, ..Default::default() };
+        for v in self.recent.iter().filter(|v| now.saturating_duration_since(v.at) <= MEMBRANE_WINDOW) {
+            if v.passed { stats.passed += 1 } else { stats.hardened += 1 }
+            stats.ontological_errors += v.ontological_error as u64;
+        }
+        stats
+    }
+
     /// INPUT FILTER (The Membrane)
     /// Decides if a stimulus penetrates the psyche or is rejected (Hardened).
     /// RETURNS: (ModifiedText, OntologicalErrorSeverity)
-    pub fn filter_input(&self, text: &str, entropy: f32, attention: f32) -> (Option<String>, f32) {
-        let _rng = rand::thread_rng();
+    /// Trust (oxytocin) thins the membrane: a bonded ALEPH is less paranoid.
+    pub fn effective_paranoia(&self, oxytocin: f32) -> f32 {
+        self.paranoia * (1.0 - 0.6 * oxytocin.clamp(0.0, 1.0))
+    }
+
+    /// DROWNED OUT: when the auditory pathway is saturating the reservoir (share of
+    /// `attention_breakdown`), a text stimulus can't be attended to: it is ignored.
+    pub fn drowned_out(&self, auditory_share: f32) -> bool {
+        auditory_share > AUDITORY_SATURATION
+    }
+
+    pub fn filter_input(&self, text: &str, entropy: f32, attention: f32, oxytocin: f32) -> (Option<String>, f32) {
+        let _rng = rand::rng();
         
         // 1. DETECT ONTOLOGICAL ERROR (Signal vs Truth)
         // If the user treats ALEPH as a tool ("Help me", "Write code", "Define X"), 
@@ -32,7 +101,7 @@ impl Satellite {
         if triggers.iter().any(|&t| lower_text.contains(t)) {
              error_severity = 0.5; // Moderate Violation
              // If highly paranoid, it's a critical violation (Threat)
-             if self.paranoia > 0.5 {
+             if self.effective_paranoia(oxytocin) > 0.5 {
                  error_severity = 1.0; 
              }
         }
@@ -0,0 +1,251 @@
+// src/core/selftest.rs
+// THE SOUNDCHECK: Startup self-test and warm-up
+//
+// The first real inference after boot used to pay for everything at once (the
+// model's mmap, the GPU context, Whisper's first pass): ALEPH sat unresponsive
+// for seconds and then answered exhausted by the latency bill. Boot now warms
+// every stage with throwaway work and times it:
+//
+//   cortex_listen   one Listen inference (Planet thread, before it serves the queue)
+//   cortex_think    one tiny Think on the fast tier (WARMUP_TOKENS tokens)
+//   whisper         one transcription of the built-in half-second sample (worker thread)
+//   reservoir_tick  mean tick time over [selftest] bench_ticks ticks of a copy of the brain
+//
+// None of it reaches the loop as a CortexOutput or a transcript, so none of it
+// is billed (no metabolism latency, no adenosine). Each stage is a System
+// thought with a {"event":"selftest"} payload, which works the same from a
+// cortexd child; the collector on the bus keeps the results for GET /selftest
+// and warns, with the numbers, when a stage is over its [selftest] ceiling:
+// the hardware is the bottleneck, not ALEPH.
+
+use serde::Serialize;
+use std::sync::mpsc::{RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::bus::{Bus, Event};
+use crate::core::config::SelfTestConfig;
+use crate::core::reservoir::FractalReservoir;
+use crate::core::thought::{MindVoice, Thought};
+
+pub const CORTEX_LISTEN: &str = "cortex_listen";
+pub const CORTEX_THINK: &str = "cortex_think";
+pub const WHISPER: &str = "whisper";
+pub const RESERVOIR_TICK: &str = "reservoir_tick";
+pub const STAGES: [&str; 4] = [CORTEX_LISTEN, CORTEX_THINK, WHISPER, RESERVOIR_TICK];
+
+/// Tokens the warm-up Think may generate (it only has to touch every layer once).
+pub const WARMUP_TOKENS: usize = 4;
+/// What the cortex warm-up reads.
+pub const WARMUP_TEXT: &str = "hola";
+/// The collector stops listening after this long (stages that never came: headless, pre-verbal).
+const COLLECT_WINDOW: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Stage {
+    pub name: String,
+    pub ms: f64,
+    pub ok: bool,
+    pub detail: String,
+    pub ceiling_ms: Option<f64>,
+    pub slow: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Report {
+    pub stages: Vec<Stage>,
+    /// Stages that never reported (yet, or at all: no cortex, no Whisper model).
+    pub pending: Vec<String>,
+    pub slow: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+/// One stage's result as a System thought (what the collector reads back).
+pub fn stage_thought(name: &str, took: Duration, result: Result<String, String>) -> Thought {
+    let ms = took.as_secs_f64() * 1000.0;
+    let (ok, detail) = match result {
+        Ok(detail) => (true, detail),
+        Err(e) => (false, e),
+    };
+    let text = if ok {
+        format!("🔥 WARMUP: {} in {:.1} ms ({})", name, ms, detail)
+    } else {
+        format!("🔥 WARMUP: {} failed after {:.1} ms ({})", name, ms, detail)
+    };
+    Thought::new(MindVoice::System, text)
+        .with_payload(serde_json::json!({ "event": "selftest", "stage": name, "ms": ms, "ok": ok, "detail": detail }))
+}
+
+/// The (name, ms, ok, detail) of a selftest payload.
+fn stage_of(payload: &serde_json::Value) -> Option<(String, f64, bool, String)> {
+    if payload["event"] != "selftest" {
+        return None;
+    }
+    Some((
+        payload["stage"].as_str()?.to_string(),
+        payload["ms"].as_f64()?,
+        payload["ok"].as_bool().unwrap_or(false),
+        payload["detail"].as_str().unwrap_or("").to_string(),
+    ))
+}
+
+/// The built-in half-second sample: a voiced vowel (120 Hz and harmonics, shaped
+/// like an "a") under an attack/release envelope, at `rate` Hz.
+pub fn sample(rate: u32) -> Vec<f32> {
+    let n = rate as usize / 2;
+    let formants = [(700.0f32, 1.0f32), (1220.0, 0.5), (2600.0, 0.25)];
+    (0..n).map(|i| {
+        let t = i as f32 / rate as f32;
+        let envelope = (t / 0.05).min(1.0) * ((0.5 - t) / 0.1).clamp(0.0, 1.0);
+        let voice: f32 = (1..=24).map(|h| {
+            let f = 120.0 * h as f32;
+            let gain: f32 = formants.iter().map(|&(center, g)| g / (1.0 + ((f - center) / 150.0).powi(2))).sum();
+            gain * (std::f32::consts::TAU * f * t).sin()
+        }).sum();
+        0.3 * envelope * voice / 2.0
+    }).collect()
+}
+
+/// Mean time of one tick of a copy of `ego` (silence in, resting chemistry).
+pub fn bench_ticks(ego: &FractalReservoir, ticks: usize) -> Duration {
+    let mut copy = ego.clone();
+    let silence = vec![0.0f32; copy.current_size()];
+    let start = Instant::now();
+    for _ in 0..ticks.max(1) {
+        copy.tick(&silence, 0.3, 0.2, 0.1, 1.0 / 60.0);
+    }
+    start.elapsed() / ticks.max(1) as u32
+}
+
+/// The stage results so far (cheap to clone; GET /selftest reads it).
+#[derive(Clone)]
+pub struct SelfTest {
+    config: SelfTestConfig,
+    stages: Arc<Mutex<Vec<Stage>>>,
+}
+
+impl SelfTest {
+    pub fn new(config: SelfTestConfig) -> Self {
+        Self { config, stages: Arc::new(Mutex::new(Vec::new())) }
+    }
+
+    fn ceiling(&self, name: &str) -> Option<f64> {
+        match name {
+            CORTEX_LISTEN => Some(self.config.listen_ceiling_ms),
+            CORTEX_THINK => Some(self.config.think_ceiling_ms),
+            WHISPER => Some(self.config.whisper_ceiling_ms),
+            RESERVOIR_TICK => Some(self.config.tick_ceiling_ms),
+            _ => None,
+        }.filter(|&c| c > 0.0)
+    }
+
+    /// Keep one stage's result (a re-warmed stage, e.g. after a cortexd restart,
+    /// replaces the old one). Some(warning) if it failed or went over its ceiling.
+    pub fn record(&self, name: &str, ms: f64, ok: bool, detail: &str) -> Option<String> {
+        let ceiling_ms = self.ceiling(name);
+        let slow = ceiling_ms.is_some_and(|c| ms > c);
+        let stage = Stage { name: name.to_string(), ms, ok, detail: detail.to_string(), ceiling_ms, slow };
+        let mut stages = self.stages.lock().unwrap();
+        match stages.iter_mut().find(|s| s.name == name) {
+            Some(old) => *old = stage,
+            None => stages.push(stage),
+        }
+        if !ok {
+            Some(format!("⚠️ SELFTEST: {} failed after {:.1} ms ({}).", name, ms, detail))
+        } else if slow {
+            Some(format!("🐢 SELFTEST: {} took {:.1} ms (ceiling {:.1} ms). This hardware is the bottleneck: expect slow answers, not a broken ALEPH.",
+                name, ms, ceiling_ms.unwrap_or_default()))
+        } else {
+            None
+        }
+    }
+
+    pub fn report(&self) -> Report {
+        let stages = self.stages.lock().unwrap().clone();
+        let has = |name: &str| stages.iter().any(|s| s.name == name);
+        Report {
+            pending: STAGES.iter().filter(|n| !has(n)).map(|n| n.to_string()).collect(),
+            slow: stages.iter().filter(|s| s.slow).map(|s| s.name.clone()).collect(),
+            failed: stages.iter().filter(|s| !s.ok).map(|s| s.name.clone()).collect(),
+            stages,
+        }
+    }
+
+    /// Collect the selftest thoughts from the bus (an independent subscriber, for
+    /// COLLECT_WINDOW) and warn about the slow or failed stages.
+    pub fn attach(&self, bus: &Bus, tx_thoughts: Sender<Thought>) {
+        let rx = bus.subscribe();
+        let selftest = self.clone();
+        thread::spawn(move || {
+            let start = Instant::now();
+            while start.elapsed() < COLLECT_WINDOW {
+                match rx.recv_timeout(Duration::from_secs(1)) {
+                    Ok(Event::Thought(thought)) => {
+                        let Some((name, ms, ok, detail)) = thought.payload.as_ref().and_then(stage_of) else { continue };
+                        if let Some(warning) = selftest.record(&name, ms, ok, &detail) {
+                            println!("{}", warning);
+                            let _ = tx_thoughts.send(Thought::new(MindVoice::System, warning)
+                                .with_payload(serde_json::json!({ "event": "selftest_warning", "stage": name, "ms": ms, "ok": ok })));
+                        }
+                    },
+                    Ok(_) | Err(RecvTimeoutError::Timeout) => {},
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_stage_thought_reads_back_into_the_same_result() {
+        let thought = stage_thought(CORTEX_LISTEN, Duration::from_millis(500), Ok("32000 logits".to_string()));
+        let read = thought.payload.as_ref().and_then(stage_of);
+        assert_eq!(read, Some((CORTEX_LISTEN.to_string(), 500.0, true, "32000 logits".to_string())));
+        // A payload of another event
+        assert_eq!(stage_of(&serde_json::json!({ "event": "soak_report", "stage": "x", "ms": 1.0 })), None);
+    }
+
+    #[test]
+    fn slow_and_failed_stages_warn_with_their_numbers_and_fast_ones_do_not() {
+        let config = SelfTestConfig::default();
+        let selftest = SelfTest::new(config.clone());
+        assert_eq!(selftest.record(CORTEX_LISTEN, 420.0, true, "32000 logits"), None);
+        let slow_ms = config.think_ceiling_ms * 2.5;
+        let warning = selftest.record(CORTEX_THINK, slow_ms, true, "3 chars").expect("a slow Think did not warn");
+        assert!(warning.contains(CORTEX_THINK), "{}", warning);
+        assert!(warning.contains(&format!("{:.1}", slow_ms)), "{}", warning);
+        assert!(warning.contains(&format!("{:.1}", config.think_ceiling_ms)), "{}", warning);
+        assert!(selftest.record(WHISPER, 10.0, false, "no state").is_some_and(|w| w.contains("failed")));
+
+        // A re-warmed stage replaces the old result
+        selftest.record(CORTEX_THINK, 900.0, true, "4 chars");
+        let report = selftest.report();
+        assert_eq!(report.stages.len(), 3);
+        assert!(!report.slow.iter().any(|s| s == CORTEX_THINK), "slow {:?}", report.slow);
+        assert_eq!(report.failed, vec![WHISPER.to_string()]);
+        assert_eq!(report.pending, vec![RESERVOIR_TICK.to_string()]);
+    }
+
+    #[test]
+    fn the_sample_is_half_a_second_of_audible_unclipped_sound() {
+        let audio = sample(16000);
+        let peak = audio.iter().fold(0.0f32, |m, &x| m.max(x.abs()));
+        let rms = (audio.iter().map(|x| x * x).sum::<f32>() / audio.len().max(1) as f32).sqrt();
+        assert_eq!(audio.len(), 8000);
+        assert!(audio.iter().all(|x| x.is_finite()));
+        assert!(peak <= 1.0, "peak {:.3}", peak);
+        assert!(rms >= 0.01, "rms {:.4}", rms);
+    }
+
+    #[test]
+    fn the_tick_benchmark_measures_something() {
+        let ego = FractalReservoir::seeded(64, 64, 0.95, 0.2, 3);
+        let per_tick = bench_ticks(&ego, 50);
+        assert!(!per_tick.is_zero() && per_tick <= Duration::from_secs(1), "{:?} per tick for 64 neurons", per_tick);
+    }
+}
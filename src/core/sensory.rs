@@ -0,0 +1,197 @@
+// src/core/sensory.rs
+// THE PATCH BAY: Which sensory channel means what
+//
+// The daemon's sensory vector used to be 500 anonymous slots that heard words
+// were hashed into (`% 500`), with nothing stopping a new sense from writing
+// over them. Every sense now owns a named range of it:
+//
+//   words          0..300   word hashes (heard sentences, see senses/transcript.rs)
+//   prosody      300..332   pitch / syllable rate / loudness bins of the last utterance
+//   vision_motion 332..396  the retina's pooled motion (8x8)
+//   heartbeat    396..397   the loop's own pulse (interoception, core/interoception.rs)
+//   reserved     397..500   free; the next sense is allocated here
+//
+// A sense that doesn't fit grows the vector, and the daemon grows the
+// reservoir's input columns to match (`FractalReservoir::grow_inputs`), so a
+// brain saved before the sense existed keeps every weight it learned. The
+// mapping is published once at boot (thought payload `sensory_registry`).
+
+use serde::Serialize;
+
+use crate::senses::transcript::word_channel;
+
+/// Width of the sensory vector at birth.
+pub const SENSORY_CHANNELS: usize = 500;
+
+pub const WORDS: &str = "words";
+pub const PROSODY: &str = "prosody";
+pub const VISION_MOTION: &str = "vision_motion";
+pub const HEARTBEAT: &str = "heartbeat";
+
+/// The senses every brain is born with, in channel order.
+pub const STANDARD: [(&str, usize); 4] = [(WORDS, 300), (PROSODY, 32), (VISION_MOTION, 64), (HEARTBEAT, 1)];
+
+/// One sense's slice of the sensory vector.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChannelRange {
+    pub name: String,
+    pub start: usize,
+    pub len: usize,
+}
+
+impl ChannelRange {
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SensoryRegistry {
+    ranges: Vec<ChannelRange>, // In channel order
+    capacity: usize,
+}
+
+impl Default for SensoryRegistry {
+    /// The standard layout (see the header).
+    fn default() -> Self {
+        let mut registry = Self::new(SENSORY_CHANNELS);
+        for (name, len) in STANDARD {
+            registry.register(name, len);
+        }
+        registry
+    }
+}
+
+impl SensoryRegistry {
+    pub fn new(capacity: usize) -> Self {
+        Self { ranges: Vec::new(), capacity }
+    }
+
+    /// Channels in the vector (allocated or not).
+    pub fn len(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.capacity == 0
+    }
+
+    pub fn ranges(&self) -> &[ChannelRange] {
+        &self.ranges
+    }
+
+    pub fn range(&self, name: &str) -> Option<&ChannelRange> {
+        self.ranges.iter().find(|r| r.name == name)
+    }
+
+    /// Allocate `len` channels for `name` right after the last range, growing the
+    /// vector if they don't fit. Returns the range and how many channels the vector
+    /// grew by. A name already registered keeps its range (and grows nothing).
+    pub fn register(&mut self, name: &str, len: usize) -> (ChannelRange, usize) {
+        if let Some(existing) = self.range(name) {
+            return (existing.clone(), 0);
+        }
+        let start = self.ranges.last().map_or(0, |r| r.end());
+        let range = ChannelRange { name: name.to_string(), start, len };
+        let grew = range.end().saturating_sub(self.capacity);
+        self.capacity += grew;
+        self.ranges.push(range.clone());
+        (range, grew)
+    }
+
+    /// The channel `key` hashes to inside `name`'s range (None: unknown or empty sense).
+    pub fn hash_into(&self, name: &str, key: &str) -> Option<usize> {
+        let range = self.range(name).filter(|r| r.len > 0)?;
+        Some(range.start + word_channel(key, range.len))
+    }
+
+    /// The channel of `value` on a scale of `name`'s range split into `bins` equal
+    /// bins starting at `offset` (values outside min..max land in the end bins).
+    pub fn bin_into(&self, name: &str, offset: usize, bins: usize, value: f32, min: f32, max: f32) -> Option<usize> {
+        let range = self.range(name).filter(|r| bins > 0 && offset + bins <= r.len)?;
+        let t = if max > min && value.is_finite() { ((value - min) / (max - min)).clamp(0.0, 1.0) } else { 0.0 };
+        Some(range.start + offset + ((t * bins as f32) as usize).min(bins - 1))
+    }
+
+    /// Overwrite the start of `name`'s range of `vector` with `values` (a level, not an event).
+    pub fn set(&self, vector: &mut [f32], name: &str, values: &[f32]) {
+        let Some(range) = self.range(name) else { return };
+        let end = range.end().min(vector.len());
+        for (slot, v) in vector[range.start.min(end)..end].iter_mut().zip(values) {
+            *slot = *v;
+        }
+    }
+
+    /// Add `values` into `name`'s range of `vector` (extra values are dropped).
+    pub fn add(&self, vector: &mut [f32], name: &str, values: &[f32]) {
+        let Some(range) = self.range(name) else { return };
+        let end = range.end().min(vector.len());
+        for (slot, v) in vector[range.start.min(end)..end].iter_mut().zip(values) {
+            *slot += v;
+        }
+    }
+}
+
+/// The prosody channels of an utterance: one-hot pitch (16 log bins, 60-400Hz),
+/// syllable rate (8 bins, 0-8/s) and loudness (8 bins, 0-0.2 RMS). Unvoiced = no pitch.
+pub fn prosody_channels(registry: &SensoryRegistry, pitch_hz: f32, syllable_rate: f32, loudness: f32) -> Vec<usize> {
+    let mut channels = Vec::with_capacity(3);
+    if pitch_hz > 0.0 {
+        channels.extend(registry.bin_into(PROSODY, 0, 16, pitch_hz.ln(), 60f32.ln(), 400f32.ln()));
+    }
+    channels.extend(registry.bin_into(PROSODY, 16, 8, syllable_rate, 0.0, 8.0));
+    channels.extend(registry.bin_into(PROSODY, 24, 8, loudness, 0.0, 0.2));
+    channels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn senses_register_into_ranges_that_never_overlap() {
+        let mut registry = SensoryRegistry::default();
+
+        let layout: Vec<(&str, usize, usize)> = registry.ranges().iter().map(|r| (r.name.as_str(), r.start, r.end())).collect();
+        assert_eq!(layout, [(WORDS, 0, 300), (PROSODY, 300, 332), (VISION_MOTION, 332, 396), (HEARTBEAT, 396, 397)]);
+        assert_eq!(registry.len(), SENSORY_CHANNELS);
+
+        let grown: Vec<usize> = [("touch", 64), ("smell", 80), (WORDS, 10), ("empty", 0), ("heat", 16)]
+            .iter().map(|&(name, len)| registry.register(name, len).1).collect();
+        assert_eq!(grown, [0, 41, 0, 0, 16], "growth per registration");
+        assert_eq!(registry.len(), 557);
+        assert_eq!(registry.range(WORDS).map(|r| r.len), Some(300), "registering 'words' again changed its range");
+
+        let ranges = registry.ranges();
+        let overlapping: Vec<String> = ranges.iter().enumerate()
+            .flat_map(|(i, a)| ranges[i + 1..].iter().map(move |b| (a, b)))
+            .filter(|(a, b)| a.start < b.end() && b.start < a.end())
+            .map(|(a, b)| format!("{}/{}", a.name, b.name))
+            .collect();
+        assert!(overlapping.is_empty(), "overlapping ranges {:?}", overlapping);
+        assert!(ranges.iter().all(|r| r.end() <= registry.len()), "a range past the end of {} channels", registry.len());
+
+        let words = registry.range(WORDS).unwrap().clone();
+        let stray = ["hola", "¿cómo", "estás?", "mar", "", "🙂", "aleph", "x", "uno", "dos", "tres", "cuatro"].iter()
+            .filter(|w| registry.hash_into(WORDS, w).is_none_or(|c| c < words.start || c >= words.end()))
+            .count();
+        assert_eq!(stray, 0, "word hashes outside 'words'");
+        assert!(registry.hash_into("empty", "hola").is_none() && registry.hash_into("nothing", "hola").is_none(), "a hash into an empty/unknown sense");
+
+        let prosody = registry.range(PROSODY).unwrap().clone();
+        let samples = [(0.0, 0.0, 0.0), (60.0, 0.0, 0.0), (180.0, 4.0, 0.05), (400.0, 8.0, 0.2), (5000.0, 50.0, 3.0), (f32::NAN, -1.0, -1.0)];
+        for (pitch, rate, loudness) in samples {
+            let channels = prosody_channels(&registry, pitch, rate, loudness);
+            let expected = if pitch > 0.0 { 3 } else { 2 };
+            assert!(channels.len() == expected && channels.iter().all(|&c| c >= prosody.start && c < prosody.end()), "prosody ({}, {}, {}) -> {:?} (range {}..{})", pitch, rate, loudness, channels, prosody.start, prosody.end());
+        }
+
+        let mut vector = vec![0.0; registry.len()];
+        registry.add(&mut vector, VISION_MOTION, &[1.0; 100]); // More values than channels
+        registry.add(&mut vector, "heat", &[1.0; 16]);
+        let motion = registry.range(VISION_MOTION).unwrap().clone();
+        let lit: Vec<usize> = (0..vector.len()).filter(|&i| vector[i] != 0.0).collect();
+        let expected: Vec<usize> = (motion.start..motion.end()).chain(registry.len() - 16..registry.len()).collect();
+        assert_eq!(lit, expected, "writes lit {} channels outside their ranges", lit.iter().filter(|i| !expected.contains(i)).count());
+    }
+}
@@ -0,0 +1,176 @@
+// src/core/sieve.rs
+// THE SIEVE: Not everything ALEPH says is worth remembering
+//
+// Every memorized thought went to the hippocampus as lived experience, so a long
+// run filled the store with its own fragments ("!", "scan", half-words from manic
+// bursts), which came back as retrieval context and fed the next degeneration.
+// Self-generated text now has to pass, in order:
+//
+//   length:     at least min_chars (trimmed)
+//   words:      at least min_alpha_ratio of its non-space characters alphabetic
+//   repetition: not the same (normalized) text as one of the last `recent` self-memories
+//   meaning:    resonance (top-token probability of the echo it came from) >= min_resonance,
+//               when the thought carries one
+//
+// Rejections are counted per reason (telemetry). Text heard from outside never
+// comes through here: it keeps its permissive path.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+
+use crate::core::config::MemoryGateConfig;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    TooShort,
+    NotWords,
+    Duplicate,
+    Meaningless,
+}
+
+/// Rejected self-memories since boot, per reason.
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct SieveStats {
+    pub stored: u64,
+    pub too_short: u64,
+    pub not_words: u64,
+    pub duplicate: u64,
+    pub meaningless: u64,
+}
+
+impl SieveStats {
+    pub fn rejected(&self) -> u64 {
+        self.too_short + self.not_words + self.duplicate + self.meaningless
+    }
+}
+
+pub struct Sieve {
+    config: MemoryGateConfig,
+    recent: VecDeque<String>, // Normalized, last `recent` admitted
+    pub stats: SieveStats,
+}
+
+/// Lowercase, whitespace collapsed, trailing punctuation dropped ("Scan!" == "scan").
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_string()
+}
+
+impl Sieve {
+    pub fn new(config: MemoryGateConfig) -> Self {
+        Self { config, recent: VecDeque::new(), stats: SieveStats::default() }
+    }
+
+    /// Why `text` should not be stored (None = store it), without remembering it.
+    pub fn judge(&self, text: &str, resonance: Option<f32>) -> Option<Rejection> {
+        let c = &self.config;
+        let text = text.trim();
+        if text.chars().count() < c.min_chars {
+            return Some(Rejection::TooShort);
+        }
+        let (alpha, total) = text.chars().filter(|c| !c.is_whitespace())
+            .fold((0usize, 0usize), |(a, t), ch| (a + ch.is_alphabetic() as usize, t + 1));
+        if total == 0 || (alpha as f32) < c.min_alpha_ratio * total as f32 {
+            return Some(Rejection::NotWords);
+        }
+        let key = normalize(text);
+        if self.recent.contains(&key) {
+            return Some(Rejection::Duplicate);
+        }
+        if resonance.is_some_and(|r| r < c.min_resonance) {
+            return Some(Rejection::Meaningless);
+        }
+        None
+    }
+
+    /// Judge and count. True = store it (and it joins the duplicate window).
+    pub fn admit(&mut self, text: &str, resonance: Option<f32>) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+        match self.judge(text, resonance) {
+            None => {
+                if self.recent.len() >= self.config.recent.max(1) {
+                    self.recent.pop_front();
+                }
+                self.recent.push_back(normalize(text));
+                self.stats.stored += 1;
+                true
+            },
+            Some(reason) => {
+                let counter = match reason {
+                    Rejection::TooShort => &mut self.stats.too_short,
+                    Rejection::NotWords => &mut self.stats.not_words,
+                    Rejection::Duplicate => &mut self.stats.duplicate,
+                    Rejection::Meaningless => &mut self.stats.meaningless,
+                };
+                *counter += 1;
+                false
+            },
+        }
+    }
+}
+
+/// Resonance of a thought, if its producer attached one (payload "resonance").
+pub fn resonance_of(payload: Option<&serde_json::Value>) -> Option<f32> {
+    payload?.get("resonance")?.as_f64().map(|r| r as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A manic self-output stream: fragments, symbols, half-words, repetitions and
+    /// forced low-resonance bursts, with a few real sentences in between.
+    /// (text, resonance, should be stored)
+    fn stream() -> Vec<(&'static str, Option<f32>, bool)> {
+        let mut stream = vec![
+            ("Hola, te escucho.", Some(0.6), true),
+            ("!", None, false),
+            ("scan", None, false),
+            ("sc", Some(0.9), false),
+            ("!!! ??? ...", None, false),
+            ("```", Some(0.5), false),
+            ("0xFF 0x00 42", None, false),
+            ("El sonido viene de la ventana.", None, true),
+            ("scan scan scan", Some(0.05), false), // Manic burst: forced, no resonance
+            ("mmmh aaah", Some(0.02), false),
+            ("Hola, te escucho!", Some(0.6), false), // Said a moment ago
+            ("hola,   TE escucho.", None, false),
+            ("Tengo sueño.", Some(0.4), true),
+        ];
+        for _ in 0..20 {
+            stream.push(("!", None, false));
+            stream.push(("scan", Some(0.8), false));
+            stream.push(("Tengo sueño.", Some(0.4), false));
+        }
+        stream.push(("Recuerdo tu voz.", Some(0.3), true));
+        stream
+    }
+
+    #[test]
+    fn only_real_sentences_from_a_manic_stream_are_stored_once() {
+        let mut sieve = Sieve::new(MemoryGateConfig { enabled: true, ..MemoryGateConfig::default() });
+        let stream = stream();
+        let mut store = Vec::new();
+        for &(text, resonance, expected) in &stream {
+            let stored = sieve.admit(text, resonance);
+            assert_eq!(stored, expected, "'{}' (resonance {:?})", text, resonance);
+            if stored {
+                store.push(text);
+            }
+        }
+        // Every fragment rejected and counted
+        let stats = sieve.stats;
+        assert_eq!(stats.stored as usize, store.len());
+        assert_eq!(stats.rejected() as usize, stream.len() - store.len(), "{:?}", stats);
+    }
+
+    #[test]
+    fn a_disabled_sieve_stores_everything() {
+        let mut open = Sieve::new(MemoryGateConfig { enabled: false, ..MemoryGateConfig::default() });
+        let stream = stream();
+        assert_eq!(stream.iter().filter(|s| open.admit(s.0, s.1)).count(), stream.len());
+    }
+}
@@ -0,0 +1,558 @@
+// src/core/soak.rs
+// THE LONG NIGHT: Days of life in minutes, checked every simulated minute
+//
+// Leaks, drifting accumulators and slowly saturating levels only show up after
+// days of uptime, and nothing ran the daemon for days on purpose. `aleph soak`
+// lives ALEPH through `hours` of simulated time (72 by default) as fast as the
+// machine allows: synthetic audio through the real cochlea, stimuli through
+// `DaemonState::handle_stimulus` and the lexicon, their echo from the seeded
+// MockCortex, chemistry, reservoir, homeostat, plasticity, growth, trauma,
+// consolidation and a nightly sleep command through the warm standby.
+//
+// `daemon::run` itself can't be stepped (real time, sockets, models), so the
+// loop's pieces are composed here in its order, on a virtual clock: per-tick
+// constants the loop tuned at 60Hz are rescaled to `hz`, the circadian hour is
+// the simulated one, and the consolidation scheduler gets the simulated `now`.
+// The hippocampus store needs BERT, so memories are kept here as plain records
+// judged by `memory_vector::consolidate_batch`.
+//
+// Every simulated minute the invariants are checked:
+//
+//   chemistry:  every level finite and in [0, 1]
+//   entropy:    the reservoir's and the loop's, finite
+//   state:      membrane norm finite and within sqrt(size)
+//   memory:     volatile memories under the ceiling (consolidation keeps up)
+//   backlog:    thoughts queued in one minute under the limit
+//   telemetry:  the TUI packet serializes and parses back
+//   rss:        resident memory grew less than the threshold since the first hour
+//
+// The first failing minute stops the run and dumps a snapshot (clock, levels,
+// counters, the whole brain) as JSON; exit code != 0.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use crate::core::bus::ControlEvent;
+use crate::core::chemistry::Neurotransmitters;
+use crate::core::config::{ConsolidationConfig, EarsConfig, HomeostatConfig, StandbyConfig};
+use crate::core::consolidation::Scheduler;
+use crate::core::daemon_state::{within_hours, DaemonState, SleepStage, NIGHT_END_HOUR, NIGHT_START_HOUR};
+use crate::core::genome::Genome;
+use crate::core::homeostat::Homeostat;
+use crate::core::ipc::AlephPacket;
+use crate::core::lexicon::Lexicon;
+use crate::core::memory_vector::{self, MemoryRecord, Provenance};
+use crate::core::pacing::Every;
+use crate::core::reservoir::FractalReservoir;
+use crate::core::sensory;
+use crate::core::standby::Standby;
+use crate::core::thought::Thought;
+use crate::core::trauma::TraumaDetector;
+use crate::cortex::backend::{CortexBackend, MockCortex};
+use crate::cortex::echo::NeuralEcho;
+use crate::senses::cochlea::{Cochlea, Frame};
+use crate::senses::proprioception::BodyStatus;
+use crate::senses::synthetic::Script;
+
+/// Things said to the simulated ALEPH, round robin with a random pause.
+const STIMULI: [&str; 8] = [
+    "hola aleph, ¿cómo estás hoy?",
+    "qué calma hay esta noche",
+    "tengo miedo, algo va mal",
+    "mira, descubrí algo nuevo",
+    "gracias por escucharme, amigo",
+    "estoy cansado, me voy a dormir",
+    "¿quién eres?",
+    "hay mucho ruido aquí, me duele la cabeza",
+];
+/// Simulated seconds between two stimuli while awake.
+const STIMULUS_GAP: (f32, f32) = (60.0, 600.0);
+/// Local hour the simulation starts at.
+const START_HOUR: f32 = 8.0;
+/// Where the loop's per-tick constants were tuned.
+const LOOP_HZ: f32 = 60.0;
+/// Slack on the state norm bound (float rounding of the clamp).
+const STATE_SLACK: f32 = 1e-3;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SoakOptions {
+    pub hours: f32,
+    pub seed: u64,
+    pub size: usize,         // Neurons at birth
+    pub max_size: usize,     // Growth ceiling over the run
+    pub hz: f32,             // Simulated ticks per second
+    pub volatile_ceiling: usize,
+    pub backlog_limit: usize, // Thoughts queued in one simulated minute
+    pub rss_growth_mb: f64,
+    pub snapshot: String,    // Where a failure dumps the state
+}
+
+impl Default for SoakOptions {
+    fn default() -> Self {
+        Self {
+            hours: 72.0,
+            seed: 7,
+            size: 200,
+            max_size: 400,
+            hz: 20.0,
+            volatile_ceiling: 2000,
+            backlog_limit: 600,
+            rss_growth_mb: 64.0,
+            snapshot: "soak-failure.json".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Violation {
+    pub minute: u64,
+    pub invariant: &'static str,
+    pub detail: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    pub simulated_hours: f32,
+    pub minutes: u64,       // Simulated minutes checked
+    pub ticks: u64,
+    pub checks: usize,
+    pub violations: Vec<Violation>,
+    pub snapshot: Option<String>, // Written on failure
+    pub final_size: usize,
+    pub stimuli: u64,
+    pub sleeps: u64,        // Transitions into Deep sleep
+    pub standby_swaps: u64,
+    pub trauma_transitions: u64,
+    pub consolidation_rounds: u64,
+    pub memories_kept: usize,
+    pub memories_forgotten: usize,
+    pub peak_volatile: usize,
+    pub peak_backlog: usize,
+    pub rss_baseline_mb: Option<f64>,
+    pub rss_final_mb: Option<f64>,
+    pub wall_secs: f32,
+}
+
+impl Report {
+    pub fn passed(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn line(&self) -> String {
+        let rss = match (self.rss_baseline_mb, self.rss_final_mb) {
+            (Some(base), Some(end)) => format!("rss {:.1} -> {:.1} MB", base, end),
+            _ => "rss n/a".to_string(),
+        };
+        format!("🌒 {:.1}h simulated in {:.0}s: {} minutes, {} checks, {} violations | {} neurons, {} stimuli, {} deep sleeps, {} standby swaps, {} rounds ({} kept / {} forgotten), peak volatile {}, peak backlog {}, {}",
+            self.simulated_hours, self.wall_secs, self.minutes, self.checks, self.violations.len(),
+            self.final_size, self.stimuli, self.sleeps, self.standby_swaps, self.consolidation_rounds,
+            self.memories_kept, self.memories_forgotten, self.peak_volatile, self.peak_backlog, rss)
+    }
+}
+
+/// Resident memory of this process (Linux; None elsewhere).
+fn rss_mb() -> Option<f64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kb: f64 = status.lines()
+        .find_map(|l| l.strip_prefix("VmRSS:"))?
+        .split_whitespace().next()?
+        .parse().ok()?;
+    Some(kb / 1024.0)
+}
+
+/// One minute of ambient sound through the real cochlea, one frame per tick
+/// (the last one its samples produced, as the daemon hears it).
+fn ambient(script: Script, hz: f32) -> Vec<Frame> {
+    let samples = script.render().expect("soak audio renders");
+    let ears = EarsConfig::default();
+    let mut cochlea = Cochlea::new(script.sample_rate, ears.fft_size, ears.fft_hop);
+    let ticks = (60.0 * hz).round().max(1.0) as usize;
+    let mut last = Frame { rms: 0.0, bass: 0.0, mids: 0.0, highs: 0.0, bands: Vec::new(), lag_samples: 0 };
+    (0..ticks).map(|t| {
+        let chunk = &samples[t * samples.len() / ticks..(t + 1) * samples.len() / ticks];
+        if let Some(frame) = cochlea.push(chunk).pop() {
+            last = frame;
+        }
+        last.clone()
+    }).collect()
+}
+
+/// Day: voices, a door, silences. Night: a hum at the floor.
+fn soundscape(seed: u64, hz: f32) -> (Vec<Frame>, Vec<Frame>) {
+    let mut day = Script::new(16000);
+    day.seed = seed;
+    let day = day.tone(180.0, 0.25, 4.0).silence(6.0).tone(240.0, 0.3, 3.0).noise(0.05, 8.0)
+        .impulse(0.9).silence(10.0).tone(600.0, 0.15, 5.0).noise(0.1, 4.0).silence(20.0);
+    let mut night = Script::new(16000);
+    night.seed = seed;
+    let night = night.noise(0.005, 30.0).tone(50.0, 0.01, 30.0);
+    (ambient(day, hz), ambient(night, hz))
+}
+
+/// `k` per loop tick at 60Hz, as the same decay over a tick of `dt` seconds.
+fn per_tick(k: f32, dt: f32) -> f32 {
+    k.powf(dt * LOOP_HZ)
+}
+
+/// The loop's parts, on the simulated clock.
+struct Life {
+    daemon: DaemonState,
+    chem: Neurotransmitters,
+    ego: FractalReservoir,
+    lexicon: Lexicon,
+    cortex: MockCortex,
+    homeostat: Homeostat,
+    standby: Standby,
+    trauma: TraumaDetector,
+    scheduler: Scheduler,
+    memories: Vec<MemoryRecord>,
+    volatile: usize,
+    clock: Instant,
+    elapsed: f64, // Simulated seconds
+}
+
+impl Life {
+    fn hour(&self) -> f32 {
+        ((START_HOUR as f64 + self.elapsed / 3600.0) % 24.0) as f32
+    }
+}
+
+/// The invariants of one simulated minute (names as in the header).
+fn check(life: &mut Life, backlog: usize, options: &SoakOptions, rss_baseline: Option<f64>, checks: &mut usize) -> Vec<(&'static str, String)> {
+    let mut failures = Vec::new();
+    let mut expect = |invariant: &'static str, ok: bool, detail: String| {
+        *checks += 1;
+        if !ok {
+            failures.push((invariant, detail));
+        }
+    };
+
+    let chem = &life.chem;
+    for (name, level) in [("adenosine", chem.adenosine), ("dopamine", chem.dopamine), ("cortisol", chem.cortisol),
+                          ("oxytocin", chem.oxytocin), ("serotonin", chem.serotonin), ("glucose", chem.glucose)] {
+        expect("chemistry", level.is_finite() && (0.0..=1.0).contains(&level), format!("{} = {}", name, level));
+    }
+    expect("entropy", life.ego.entropy.is_finite() && life.daemon.current_entropy.is_finite(),
+        format!("reservoir {} / loop {}", life.ego.entropy, life.daemon.current_entropy));
+
+    let norm = life.ego.state_norm();
+    let bound = (life.ego.current_size() as f32).sqrt() + STATE_SLACK;
+    expect("state", norm.is_finite() && norm <= bound, format!("norm {} (bound {:.3})", norm, bound));
+
+    expect("memory", life.volatile <= options.volatile_ceiling,
+        format!("{} volatile (ceiling {})", life.volatile, options.volatile_ceiling));
+    expect("backlog", backlog <= options.backlog_limit,
+        format!("{} thoughts in one minute (limit {})", backlog, options.backlog_limit));
+
+    let region_map = life.ego.region_map().to_vec();
+    let packet = life.daemon.build_telemetry(&life.chem, &life.ego, life.ego.get_activity_snapshot(), region_map, Vec::new(), Vec::new());
+    let telemetry = serde_json::to_string(&packet).map_err(|e| e.to_string())
+        .and_then(|json| serde_json::from_str::<AlephPacket>(&json).map(|_| ()).map_err(|e| e.to_string()));
+    expect("telemetry", telemetry.is_ok(), telemetry.err().unwrap_or_default());
+
+    if let (Some(base), Some(now)) = (rss_baseline, rss_mb()) {
+        expect("rss", now - base <= options.rss_growth_mb,
+            format!("{:.1} MB -> {:.1} MB (threshold +{:.0} MB)", base, now, options.rss_growth_mb));
+    }
+    failures
+}
+
+/// Everything needed to see why a minute failed.
+fn dump(life: &Life, options: &SoakOptions, minute: u64, violations: &[Violation]) -> std::io::Result<String> {
+    let snapshot = serde_json::json!({
+        "options": options,
+        "minute": minute,
+        "simulated_secs": life.elapsed,
+        "hour": life.hour(),
+        "violations": violations,
+        "chemistry": life.chem,
+        "daemon": {
+            "ticks": life.daemon.ticks,
+            "is_dreaming": life.daemon.is_dreaming,
+            "sleep_stage": life.daemon.sleep_stage(life.chem.adenosine),
+            "current_hz": life.daemon.current_hz,
+            "current_entropy": life.daemon.current_entropy,
+            "interaction_count": life.daemon.interaction_count,
+            "short_term_memory": life.daemon.telemetry_history,
+        },
+        "trauma_active": life.trauma.is_active(),
+        "homeostat": life.homeostat.state(),
+        "standby": life.standby.stats(),
+        "memory": { "volatile": life.volatile, "total": life.memories.len(), "rounds": life.scheduler.rounds },
+        "rss_mb": rss_mb(),
+        "brain": life.ego,
+    });
+    std::fs::write(&options.snapshot, serde_json::to_string_pretty(&snapshot)?)?;
+    Ok(options.snapshot.clone())
+}
+
+/// Live `options.hours` of simulated time. Stops at the first failing minute.
+pub fn run(options: &SoakOptions) -> Report {
+    soak(options, None)
+}
+
+/// `poison`: the minute at which a NaN is slipped into the chemistry (tests).
+fn soak(options: &SoakOptions, poison: Option<u64>) -> Report {
+    let started = Instant::now();
+    let hz = options.hz.clamp(1.0, LOOP_HZ);
+    let dt = 1.0 / hz;
+    let ticks_per_minute = (60.0 * hz).round() as u64;
+    let minutes = (options.hours.max(0.0) * 60.0).round() as u64;
+    let genome = Genome::default();
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let (day, night) = soundscape(options.seed, hz);
+    let (tx, rx) = mpsc::channel::<Thought>();
+
+    let mut ego = FractalReservoir::seeded(options.size, options.size, 0.95, 0.2, options.seed);
+    ego.set_growth_ceiling(Some(options.max_size.max(options.size)));
+    let mut daemon = DaemonState::new();
+    for (name, len) in sensory::STANDARD {
+        daemon.register_sense(name, len, &mut ego);
+    }
+    let clock = Instant::now();
+    let mut life = Life {
+        daemon,
+        chem: Neurotransmitters::new(),
+        ego,
+        lexicon: Lexicon::builtin(),
+        cortex: MockCortex::new(options.seed),
+        homeostat: Homeostat::new(HomeostatConfig::default()),
+        standby: Standby::new(StandbyConfig::default()),
+        trauma: TraumaDetector::new(genome.phenotype().trauma),
+        scheduler: Scheduler::new(ConsolidationConfig::default(), clock),
+        memories: Vec::new(),
+        volatile: 0,
+        clock,
+        elapsed: 0.0,
+    };
+
+    let mut report = Report { simulated_hours: options.hours, ..Report::default() };
+    let mut circadian = Every::new(10.0);
+    let mut epiphany_timer = Every::new(100.0 / 60.0);
+    let mut growth_timer = Every::new(5.0);
+    let mut edge_growth_timer = Every::new(10.0);
+    let mut next_stimulus = rng.random_range(STIMULUS_GAP.0..STIMULUS_GAP.1) as f64;
+    let mut last_stage = SleepStage::Awake;
+    let mut last_sleep_day: Option<u64> = None;
+    let mut rss_baseline = None;
+
+    for minute in 1..=minutes {
+        for t in 0..ticks_per_minute {
+            life.clock += Duration::from_secs_f32(dt);
+            life.elapsed += dt as f64;
+            let hour = life.hour();
+            let night_hours = within_hours(hour as u32, NIGHT_START_HOUR, NIGHT_END_HOUR);
+
+            // CHRONORECEPTION: circadian pressure every 10 simulated seconds
+            if circadian.tick(dt) > 0 {
+                let pressure = if night_hours {
+                    0.005
+                } else if hour >= 20.0 {
+                    0.002
+                } else if (6.0..9.0).contains(&hour) {
+                    -0.005
+                } else {
+                    -0.001
+                };
+                life.chem.adenosine = (life.chem.adenosine + pressure).clamp(0.0, 1.0);
+            }
+
+            // The dashboard's sleep button, once a night (runs the consolidation on the standby)
+            let day_index = (life.elapsed / 86400.0) as u64;
+            if night_hours && !life.daemon.is_dreaming && last_sleep_day != Some(day_index) {
+                last_sleep_day = Some(day_index);
+                life.daemon.handle_control(&ControlEvent::Sleep, &mut life.chem, &mut life.ego, &mut life.standby, &tx);
+            }
+
+            let frames = if night_hours { &night } else { &day };
+            let frame = &frames[(t as usize) % frames.len()];
+            let mut audio_energy = frame.bass + frame.mids + frame.highs;
+            life.daemon.last_spectrum.rms = frame.rms;
+            life.daemon.last_spectrum.bass = frame.bass;
+            life.daemon.last_spectrum.mids = frame.mids;
+            life.daemon.last_spectrum.highs = frame.highs;
+
+            if life.daemon.is_dreaming {
+                audio_energy = rng.random_range(0.05..0.15); // Theta waves
+                life.chem.cortisol = 0.0;
+            }
+
+            let stage = life.daemon.sleep_stage(life.chem.adenosine);
+            if stage != last_stage {
+                if stage == SleepStage::Deep {
+                    life.scheduler.enter_deep();
+                    report.sleeps += 1;
+                }
+                last_stage = stage;
+            }
+
+            life.chem.dopamine *= per_tick(0.9996, dt);
+            life.chem.cortisol *= per_tick(0.9994, dt);
+            life.chem.hear(audio_energy);
+
+            // A laptop that works by day and idles by night
+            let cpu = if night_hours { 5.0 } else { 20.0 + 10.0 * (life.elapsed as f32 / 300.0).sin() };
+            life.daemon.last_body_state = BodyStatus { cpu_usage: cpu, ram_usage: 0.4 };
+            life.chem.update_from_hardware(cpu, 0.4, 1.0);
+
+            // Sensory input, as the loop builds it
+            let mut input = vec![0.0f32; life.ego.current_size().max(life.daemon.sensory_vector.len())];
+            let (bass, mids, highs) = (frame.bass * 8.0, frame.mids * 4.0, frame.highs * 3.0);
+            for (i, x) in input.iter_mut().enumerate() {
+                if i % 5 == 0 { *x += bass; } else if i % 11 == 0 { *x += mids; } else if i % 17 == 0 { *x += highs; }
+            }
+            let beat = life.daemon.heart.beat(life.daemon.current_hz, dt);
+            life.daemon.sensory.set(&mut life.daemon.sensory_vector, sensory::HEARTBEAT, &[beat]);
+            for (x, s) in input.iter_mut().zip(&life.daemon.sensory_vector) {
+                *x += s;
+            }
+
+            if life.standby.poll(&mut life.ego).is_some() {
+                report.standby_swaps += 1;
+            }
+
+            let entropy = life.ego.tick(&input, life.chem.dopamine, life.chem.adenosine, life.chem.cortisol, dt);
+            life.chem.tick(entropy, cpu, life.daemon.is_dreaming, 0.0, life.ego.current_size(), dt);
+            if let Some(modulation) = life.homeostat.observe(entropy, genome.entropy_setpoint, dt, life.daemon.is_dreaming) {
+                life.ego.modulate(modulation);
+            }
+            life.ego.hebbian_update(life.chem.dopamine, dt);
+            life.ego.hebbian_input_update(&input, life.chem.dopamine);
+
+            if epiphany_timer.tick(dt) > 0 && life.chem.dopamine > 0.9 && life.ego.trigger_epiphany(life.chem.dopamine) > 0 {
+                life.chem.dopamine = 0.55;
+            }
+            for x in life.daemon.sensory_vector.iter_mut() {
+                *x *= per_tick(0.9, dt);
+            }
+            let grown = growth_timer.tick(dt) as usize;
+            if life.chem.dopamine > 0.15 && grown > 0 {
+                life.ego.neurogenesis(grown);
+            }
+            let edge_grown = edge_growth_timer.tick(dt) as usize;
+            if life.daemon.current_entropy > 0.3 && life.daemon.current_entropy < 0.7 && edge_grown > 0 {
+                life.ego.neurogenesis(edge_grown);
+            }
+            life.ego.drain_growth_events();
+            life.ego.drain_pruned_events();
+
+            if life.trauma.tick(life.chem.cortisol) {
+                report.trauma_transitions += 1;
+            }
+            life.daemon.metabolic_step(&life.chem, entropy, genome.stress_tolerance, &tx);
+
+            // Someone speaks: the stimulus, how it feels, its echo, its memory
+            if life.elapsed >= next_stimulus {
+                next_stimulus = life.elapsed + rng.random_range(STIMULUS_GAP.0..STIMULUS_GAP.1) as f64;
+                if !life.daemon.is_dreaming {
+                    let text = STIMULI[(report.stimuli as usize) % STIMULI.len()];
+                    report.stimuli += 1;
+                    let cortex_input = life.daemon.handle_stimulus(text, &mut life.chem, None, &tx);
+                    life.chem.apply_semantic_perturbation(text, &mut life.lexicon);
+                    let logits = life.cortex.tokenize(text).and_then(|tokens| life.cortex.forward_logits(&tokens, 0));
+                    if let Ok(logits) = logits {
+                        NeuralEcho::from_logits(&logits, life.ego.current_size(), false).inject(&mut life.ego);
+                    }
+                    life.memories.push(MemoryRecord {
+                        text: text.to_string(),
+                        embedding: Vec::new(),
+                        timestamp: life.elapsed as u64,
+                        context_tags: Vec::new(),
+                        entropy: cortex_input.entropy,
+                        consolidated: false,
+                        activity: life.ego.activity_signature(),
+                        provenance: Provenance::Told { client_id: "soak".to_string() },
+                        utterance: None,
+                    });
+                    life.volatile += 1;
+                    report.peak_volatile = report.peak_volatile.max(life.volatile);
+                    life.scheduler.touch(life.clock);
+                }
+            }
+
+            // THE NIGHT SHIFT on the simulated clock
+            if let Some(trigger) = life.scheduler.due(life.volatile, life.clock) {
+                let batch = memory_vector::consolidate_batch(&mut life.memories, life.scheduler.batch());
+                life.volatile -= batch.examined;
+                report.memories_kept += batch.kept;
+                report.memories_forgotten += batch.forgotten;
+                life.scheduler.ran(trigger, life.volatile, life.clock);
+            }
+        }
+        report.ticks += ticks_per_minute;
+        report.minutes = minute;
+
+        if poison == Some(minute) {
+            life.chem.serotonin = f32::NAN;
+        }
+        let backlog = rx.try_iter().count();
+        report.peak_backlog = report.peak_backlog.max(backlog);
+        if minute == 60 {
+            rss_baseline = rss_mb(); // After the first hour: allocations of warm-up are not growth
+        }
+
+        let failures = check(&mut life, backlog, options, rss_baseline, &mut report.checks);
+        if !failures.is_empty() {
+            report.violations = failures.into_iter()
+                .map(|(invariant, detail)| Violation { minute, invariant, detail })
+                .collect();
+            report.snapshot = match dump(&life, options, minute, &report.violations) {
+                Ok(path) => Some(path),
+                Err(e) => Some(format!("(snapshot failed: {})", e)),
+            };
+            break;
+        }
+    }
+
+    report.final_size = life.ego.current_size();
+    report.consolidation_rounds = life.scheduler.rounds;
+    report.rss_baseline_mb = rss_baseline;
+    report.rss_final_mb = rss_mb();
+    report.wall_secs = started.elapsed().as_secs_f32();
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A short, small soak that still spans a night.
+    fn short(name: &str, hours: f32) -> SoakOptions {
+        let snapshot = std::env::temp_dir().join(format!("aleph-soak-{}-{}.json", name, std::process::id()));
+        SoakOptions { hours, size: 60, max_size: 80, hz: 2.0, snapshot: snapshot.to_string_lossy().to_string(), ..SoakOptions::default() }
+    }
+
+    #[test]
+    fn a_short_soak_passes_sleeps_consolidates_and_swaps_the_standby_in() {
+        let options = short("clean", 20.0);
+        let clean = run(&options);
+        assert!(clean.passed(), "{:?}", clean.violations);
+        assert_eq!(clean.minutes, 1200);
+        assert!(clean.checks > 0);
+        assert!(clean.sleeps > 0, "the night brought no deep sleep");
+        assert!(clean.standby_swaps > 0, "the nightly consolidation never swapped in");
+        assert!(clean.stimuli > 0, "no stimuli");
+        assert!(clean.consolidation_rounds > 0, "nothing consolidated");
+        assert!(clean.snapshot.is_none() && !std::path::Path::new(&options.snapshot).exists(), "a clean run wrote a snapshot");
+    }
+
+    #[test]
+    fn a_nan_in_the_chemistry_stops_the_soak_at_its_minute_with_a_snapshot() {
+        let options = short("poisoned", 2.0);
+        let poisoned = soak(&options, Some(30));
+        let dumped = std::fs::read_to_string(&options.snapshot);
+        let _ = std::fs::remove_file(&options.snapshot);
+        assert_eq!(poisoned.minutes, 30);
+        // Only the chemistry invariant fails
+        assert_eq!(poisoned.violations.len(), 1, "{:?}", poisoned.violations);
+        assert_eq!(poisoned.violations[0].invariant, "chemistry");
+        // The snapshot names the minute, the invariant and carries the brain
+        let dumped: serde_json::Value = serde_json::from_str(&dumped.unwrap()).unwrap();
+        assert_eq!(dumped["minute"], 30);
+        assert_eq!(dumped["violations"][0]["invariant"], "chemistry");
+        assert!(dumped["brain"].is_object());
+    }
+}
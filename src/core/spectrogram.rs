@@ -0,0 +1,115 @@
+// src/core/spectrogram.rs
+// THE WATERFALL: The last few seconds of what the ears heard (for the dashboard)
+//
+// Telemetry only carries the latest spectrum, so the web UI can't draw how a
+// sound evolved. Every AudioSpectrum frame (one per FFT hop, see
+// senses/cochlea.rs) appends its 64-band embedding to a ring that keeps the
+// last HISTORY_SECS seconds (capped at MAX_FRAMES, enough for 48 kHz with a
+// 128-sample hop):
+//
+//   rows = frames, oldest first; values = band level 0..1 quantized to u8
+//   band i covers [i * band_hz, (i + 1) * band_hz) Hz, whatever the sample rate
+//
+// Wire format (GET /spectrogram, and WS binary frames for clients that sent
+// {"action":"spectrogram_on"}):
+//   "SPEC" | version u8 = 1 | bands u8 | frames u16 LE | first_seq u64 LE
+//   | band_hz f32 LE | span_ms u32 LE (first -> last frame)
+//   | frames x bands u8 (row-major, oldest first)
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::senses::cochlea::{BANDS, BAND_HZ};
+
+pub const HISTORY_SECS: f32 = 5.0;
+pub const MAX_FRAMES: usize = 2048;
+
+struct SpectrogramInner {
+    frames: VecDeque<(Instant, [u8; BANDS])>,
+    next_seq: u64, // Sequence number of the next frame pushed
+}
+
+/// Shared handle: the loop pushes, HTTP / WS read.
+#[derive(Clone)]
+pub struct Spectrogram {
+    inner: Arc<Mutex<SpectrogramInner>>,
+}
+
+impl Spectrogram {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SpectrogramInner {
+                frames: VecDeque::new(),
+                next_seq: 0,
+            })),
+        }
+    }
+
+    /// One spectrum frame (`AudioSpectrum::frequency_embedding`).
+    pub fn push(&self, bands: &[f32]) {
+        let mut row = [0u8; BANDS];
+        for (q, &b) in row.iter_mut().zip(bands) {
+            *q = (b.clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+        let now = Instant::now();
+        let horizon = Duration::from_secs_f32(HISTORY_SECS);
+
+        let mut inner = self.inner.lock().unwrap();
+        while inner.frames.front().is_some_and(|(t, _)| now.duration_since(*t) > horizon)
+            || inner.frames.len() >= MAX_FRAMES
+        {
+            inner.frames.pop_front();
+        }
+        inner.frames.push_back((now, row));
+        inner.next_seq += 1;
+    }
+
+    /// Sequence number the next frame will get (clients resume from here).
+    pub fn next_seq(&self) -> u64 {
+        self.inner.lock().unwrap().next_seq
+    }
+
+    /// Encoded frames with seq >= `since` (everything kept if None).
+    pub fn encode(&self, since: Option<u64>) -> Vec<u8> {
+        self.inner.lock().unwrap().encode(since)
+    }
+
+    /// Incremental WS payload: frames since `cursor`, which then moves past them.
+    /// None when nothing new was heard.
+    pub fn encode_new(&self, cursor: &mut u64) -> Option<Vec<u8>> {
+        let inner = self.inner.lock().unwrap();
+        if inner.next_seq <= *cursor {
+            return None;
+        }
+        let payload = inner.encode(Some(*cursor));
+        *cursor = inner.next_seq;
+        Some(payload)
+    }
+}
+
+impl SpectrogramInner {
+    fn encode(&self, since: Option<u64>) -> Vec<u8> {
+        let oldest = self.next_seq - self.frames.len() as u64;
+        let first = since.unwrap_or(oldest).clamp(oldest, self.next_seq);
+        let skip = (first - oldest) as usize;
+        let count = (self.frames.len() - skip).min(u16::MAX as usize);
+        let span_ms = match (self.frames.get(skip), self.frames.back()) {
+            (Some((a, _)), Some((b, _))) => b.duration_since(*a).as_millis().min(u32::MAX as u128) as u32,
+            _ => 0,
+        };
+
+        let mut out = Vec::with_capacity(24 + count * BANDS);
+        out.extend_from_slice(b"SPEC");
+        out.push(1);
+        out.push(BANDS as u8);
+        out.extend_from_slice(&(count as u16).to_le_bytes());
+        out.extend_from_slice(&first.to_le_bytes());
+        out.extend_from_slice(&BAND_HZ.to_le_bytes());
+        out.extend_from_slice(&span_ms.to_le_bytes());
+        for (_, row) in self.frames.iter().skip(skip).take(count) {
+            out.extend_from_slice(row);
+        }
+        out
+    }
+}
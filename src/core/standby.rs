@@ -0,0 +1,355 @@
+// src/core/standby.rs
+// THE UNDERSTUDY: Heavy surgery on a copy while the brain keeps living
+//
+// A bulk prune walks the whole weight matrix; run inside the tick loop it
+// stalls the heartbeat for as long as it takes. Structural operations now run
+// on a warm standby: a copy of the reservoir (`standby_copy`) handed to a worker
+// thread, while the live brain keeps ticking. Between two ticks `poll` checks
+// for the finished copy and swaps it in:
+//
+//   merge     `merge_recent_state` carries over from the live brain what the
+//             operation didn't touch: age, counters, control state and (replay)
+//             the activity of the ticks run meanwhile; replay = false accepts
+//             those ticks as lost and the copy's activity stands
+//   swap      mem::replace; the old brain is dropped on another thread
+//   diverged  the live brain changed structure meanwhile (neurons born or
+//             removed, input widened): the copy is discarded and the operation
+//             relaunched on a fresh one, max_retries times, then run inline
+//
+// One operation at a time; others queue behind it. Hebbian weight changes made
+// during the operation are lost (the copy's weights win). Only the sleep prune
+// (daemon_state.rs) runs here so far: it is the one bulk operation this brain has.
+// Every swap is a SwapEvent (how long the operation and the swap itself took,
+// how many ticks ran meanwhile), published as `standby`.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::config::StandbyConfig;
+use crate::core::reservoir::FractalReservoir;
+
+/// A structural operation: changes the brain, returns the line to narrate.
+pub type Operation = Arc<dyn Fn(&mut FractalReservoir) -> String + Send + Sync>;
+
+/// One operation that reached the live brain.
+#[derive(Debug, Clone, Serialize)]
+pub struct SwapEvent {
+    pub op: String,
+    pub outcome: String,
+    pub operation_ms: f32, // The operation itself (on the worker, or inline)
+    pub swap_ms: f32,      // Merge + swap, between two ticks (0 inline)
+    pub ticks_during: u64, // Ticks the live brain ran while it worked
+    pub carried: usize,    // Neurons whose recent activity was replayed
+    pub replayed: bool,
+    pub attempts: u32,     // 1 unless the live brain diverged
+    pub inline: bool,      // Ran in the tick loop (disabled, retries exhausted or no thread)
+}
+
+/// Published as `standby`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StandbyStats {
+    pub running: Option<String>,
+    pub queued: usize,
+    pub swaps: u64,
+    pub discarded: u64, // Copies thrown away (diverged or failed)
+    pub inline: u64,
+    pub last: Option<SwapEvent>,
+}
+
+struct Job {
+    name: String,
+    op: Operation,
+    attempts: u32,
+    launched_tick: u64,
+    // The live brain's structure at launch: the copy is only valid while it holds
+    size: usize,
+    next_id: u64,
+    channels: usize,
+    done: Receiver<(FractalReservoir, String, Duration)>,
+}
+
+pub struct Standby {
+    config: StandbyConfig,
+    job: Option<Job>,
+    queue: VecDeque<(String, Operation)>,
+    stats: StandbyStats,
+}
+
+impl Standby {
+    pub fn new(config: StandbyConfig) -> Self {
+        Self { config, job: None, queue: VecDeque::new(), stats: StandbyStats::default() }
+    }
+
+    /// Start `op` on a standby copy of `ego` (queued behind a running one). Runs it
+    /// inline when disabled and returns its line then; None = `poll` will report it.
+    pub fn run(&mut self, ego: &mut FractalReservoir, name: &str, op: Operation) -> Option<String> {
+        if !self.config.enabled {
+            return Some(self.inline(ego, name, &op, 0, 1).outcome);
+        }
+        if self.job.is_some() {
+            self.queue.push_back((name.to_string(), op));
+            return None;
+        }
+        self.launch(ego, name.to_string(), op, 1)
+    }
+
+    /// Between two ticks: swap in a finished copy (or relaunch / fail it). Returns
+    /// the operation's line and what the swap cost.
+    pub fn poll(&mut self, ego: &mut FractalReservoir) -> Option<(String, SwapEvent)> {
+        let job = self.job.as_ref()?;
+        let (mut copy, outcome, took) = match job.done.try_recv() {
+            Ok(done) => done,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => {
+                let job = self.job.take()?;
+                self.stats.discarded += 1;
+                let line = format!("⚠️ STANDBY: '{}' failed on its copy; the live brain is untouched.", job.name);
+                self.next(ego);
+                return Some((line, SwapEvent {
+                    op: job.name, outcome: "failed".to_string(), operation_ms: 0.0, swap_ms: 0.0,
+                    ticks_during: ego.ticks_lived().saturating_sub(job.launched_tick),
+                    carried: 0, replayed: false, attempts: job.attempts, inline: false,
+                }));
+            }
+        };
+        let job = self.job.take()?;
+        let ticks_during = ego.ticks_lived().saturating_sub(job.launched_tick);
+
+        if ego.size != job.size || ego.next_neuron_id() != job.next_id || ego.input_channels() != job.channels {
+            self.stats.discarded += 1;
+            if job.attempts <= self.config.max_retries {
+                self.launch(ego, job.name, job.op, job.attempts + 1)?; // None = relaunched on a fresh copy
+            } else {
+                self.inline(ego, &job.name, &job.op, ticks_during, job.attempts + 1);
+            }
+            self.next(ego);
+            return self.stats.last.clone().map(|e| (e.outcome.clone(), e));
+        }
+
+        let swap_start = Instant::now();
+        let carried = copy.merge_recent_state(ego, self.config.replay);
+        let old = std::mem::replace(ego, copy);
+        let swap_ms = swap_start.elapsed().as_secs_f32() * 1000.0;
+        thread::spawn(move || drop(old)); // Freeing a big matrix is not free either
+        let event = SwapEvent {
+            op: job.name,
+            outcome,
+            operation_ms: took.as_secs_f32() * 1000.0,
+            swap_ms,
+            ticks_during,
+            carried,
+            replayed: self.config.replay,
+            attempts: job.attempts,
+            inline: false,
+        };
+        self.stats.swaps += 1;
+        self.stats.last = Some(event.clone());
+        self.next(ego);
+        Some((event.outcome.clone(), event))
+    }
+
+    pub fn stats(&self) -> StandbyStats {
+        StandbyStats {
+            running: self.job.as_ref().map(|job| job.name.clone()),
+            queued: self.queue.len(),
+            ..self.stats.clone()
+        }
+    }
+
+    /// Some(line) only if it had to run inline.
+    fn launch(&mut self, ego: &mut FractalReservoir, name: String, op: Operation, attempts: u32) -> Option<String> {
+        let (tx, done) = mpsc::channel();
+        let mut copy = ego.standby_copy();
+        let work = op.clone();
+        let spawned = thread::Builder::new().name(format!("standby-{}", name)).spawn(move || {
+            let start = Instant::now();
+            let outcome = work(&mut copy);
+            let _ = tx.send((copy, outcome, start.elapsed()));
+        });
+        if spawned.is_err() {
+            return Some(self.inline(ego, &name, &op, 0, attempts).outcome);
+        }
+        self.job = Some(Job {
+            name,
+            op,
+            attempts,
+            launched_tick: ego.ticks_lived(),
+            size: ego.size,
+            next_id: ego.next_neuron_id(),
+            channels: ego.input_channels(),
+            done,
+        });
+        None
+    }
+
+    fn inline(&mut self, ego: &mut FractalReservoir, name: &str, op: &Operation, ticks_during: u64, attempts: u32) -> SwapEvent {
+        let start = Instant::now();
+        let outcome = op(ego);
+        let event = SwapEvent {
+            op: name.to_string(),
+            outcome,
+            operation_ms: start.elapsed().as_secs_f32() * 1000.0,
+            swap_ms: 0.0,
+            ticks_during,
+            carried: 0,
+            replayed: false,
+            attempts,
+            inline: true,
+        };
+        self.stats.inline += 1;
+        self.stats.last = Some(event.clone());
+        event
+    }
+
+    /// Launch the next queued operation; the ones that end up inline are only in the stats.
+    fn next(&mut self, ego: &mut FractalReservoir) {
+        while self.job.is_none() {
+            let Some((name, op)) = self.queue.pop_front() else { return };
+            let _ = self.launch(ego, name, op, 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::daemon_state::HZ_BASE;
+
+    const SIZE: usize = 300;
+    const SEED: u64 = 1443;
+
+    fn budget() -> Duration {
+        Duration::from_secs_f32(1.0 / HZ_BASE)
+    }
+
+    /// A bulk prune, padded to be slow.
+    fn prune() -> Operation {
+        Arc::new(|brain: &mut FractalReservoir| {
+            thread::sleep(Duration::from_millis(150)); // A brain big enough to make it hurt
+            format!("pruned {}", brain.prune_inactive_neurons())
+        })
+    }
+
+    fn small_weights(brain: &FractalReservoir) -> usize {
+        brain.weights().iter().filter(|w| **w != 0.0 && w.abs() < 0.05).count()
+    }
+
+    fn brain() -> FractalReservoir {
+        let mut ego = FractalReservoir::seeded(SIZE, SIZE, 0.95, 0.2, SEED);
+        let input = vec![0.3; ego.input_channels()];
+        for _ in 0..20 {
+            ego.tick(&input, 0.5, 0.2, 0.2, 1.0 / HZ_BASE);
+        }
+        ego
+    }
+
+    /// Tick with `poll` between ticks until the swap; (event, slowest tick, activity right before the swap).
+    fn drive(standby: &mut Standby, ego: &mut FractalReservoir, during: &dyn Fn(&mut FractalReservoir, usize)) -> (Option<SwapEvent>, Duration, Vec<f32>) {
+        let input = vec![0.3; ego.input_channels()];
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut slowest = Duration::ZERO;
+        let mut tick = 0;
+        while Instant::now() < deadline {
+            let before = ego.get_activity_snapshot();
+            let start = Instant::now();
+            if let Some((_, event)) = standby.poll(ego) {
+                return (Some(event), slowest, before);
+            }
+            during(ego, tick);
+            ego.tick(&input, 0.5, 0.2, 0.2, 1.0 / HZ_BASE);
+            slowest = slowest.max(start.elapsed());
+            tick += 1;
+            thread::sleep(Duration::from_millis(2));
+        }
+        (None, slowest, Vec::new())
+    }
+
+    #[test]
+    fn inline_the_prune_stalls_the_loop() {
+        let mut ego = brain();
+        assert!(small_weights(&ego) > 0, "seeded brain has no weights to prune");
+        let mut standby = Standby::new(StandbyConfig { enabled: false, ..Default::default() });
+        let start = Instant::now();
+        let line = standby.run(&mut ego, "prune", prune());
+        assert!(start.elapsed() > budget(), "inline prune took {:?}", start.elapsed());
+        assert!(line.is_some());
+        assert_eq!(standby.stats().inline, 1);
+        assert_eq!(small_weights(&ego), 0);
+    }
+
+    #[test]
+    fn on_the_standby_no_tick_runs_over_budget_and_the_swapped_in_brain_is_pruned() {
+        for replay in [true, false] {
+            let mut ego = brain();
+            let small = small_weights(&ego);
+            ego.drain_pruned_events();
+            let launched_at = ego.ticks_lived();
+            let mut standby = Standby::new(StandbyConfig { replay, ..Default::default() });
+            let start = Instant::now();
+            let line = standby.run(&mut ego, "prune", prune());
+            let launch = start.elapsed();
+            let snapshot = ego.get_activity_snapshot();
+            let label = if replay { "replay" } else { "accept" };
+            assert_eq!(line, None, "{}: launch ran inline", label);
+            assert_eq!(standby.stats().running.as_deref(), Some("prune"), "{}", label);
+            let (event, slowest, before) = drive(&mut standby, &mut ego, &|_, _| {});
+            let event = event.unwrap_or_else(|| panic!("{}: never swapped in", label));
+            assert!(launch <= budget() && slowest <= budget(), "{}: launch {:?}, slowest tick {:?} (budget {:?})", label, launch, slowest, budget());
+
+            // The prune was applied, and the brain kept living meanwhile
+            assert_eq!(small_weights(&ego), 0, "{}", label);
+            assert_eq!(ego.drain_pruned_events() as usize, small, "{}", label);
+            assert_eq!(event.outcome, format!("pruned {}", small), "{}", label);
+            assert!(!event.inline, "{}", label);
+            assert!(event.ticks_during > 0, "{}", label);
+            assert_eq!(ego.ticks_lived(), launched_at + event.ticks_during, "{}", label);
+            assert_eq!(event.attempts, 1, "{}", label);
+            assert_eq!(standby.stats().swaps, 1, "{}", label);
+
+            // Replay carries the ticks run meanwhile; accept keeps the copy's activity
+            assert_eq!(ego.get_activity_snapshot(), if replay { before } else { snapshot }, "{}", label);
+            assert_eq!(event.replayed, replay);
+            assert_eq!(event.carried, if replay { SIZE } else { 0 });
+        }
+    }
+
+    #[test]
+    fn neurons_born_meanwhile_relaunch_the_stale_copy() {
+        for retries in [3u32, 0] {
+            let mut ego = brain();
+            let mut standby = Standby::new(StandbyConfig { max_retries: retries, ..Default::default() });
+            let _ = standby.run(&mut ego, "prune", prune());
+            let (event, _, _) = drive(&mut standby, &mut ego, &|ego, tick| if tick == 3 { ego.neurogenesis(2) });
+            let event = event.unwrap_or_else(|| panic!("retries {}: never swapped in", retries));
+            // Still applied: on a fresh copy, or inline when out of retries
+            assert_eq!(ego.size, SIZE + 2);
+            assert_eq!(small_weights(&ego), 0, "retries {}", retries);
+            assert_eq!(event.attempts, 2, "retries {}", retries);
+            assert_eq!(event.inline, retries == 0);
+            assert_eq!(standby.stats().discarded, 1, "retries {}", retries);
+        }
+    }
+
+    #[test]
+    fn a_second_operation_queues_and_follows() {
+        let mut ego = brain();
+        let mut standby = Standby::new(StandbyConfig::default());
+        let grow: Operation = Arc::new(|brain: &mut FractalReservoir| {
+            brain.neurogenesis(3);
+            "grew 3".to_string()
+        });
+        assert_eq!(standby.run(&mut ego, "prune", prune()), None);
+        assert_eq!(standby.run(&mut ego, "grow", grow), None);
+        assert_eq!(standby.stats().queued, 1);
+        let (first, _, _) = drive(&mut standby, &mut ego, &|_, _| {});
+        let (second, _, _) = drive(&mut standby, &mut ego, &|_, _| {});
+        assert_eq!(first.map(|e| e.op).as_deref(), Some("prune"));
+        assert_eq!(second.map(|e| e.op).as_deref(), Some("grow"));
+        assert_eq!(ego.size, SIZE + 3);
+        assert_eq!(standby.stats().swaps, 2);
+    }
+}
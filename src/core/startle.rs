@@ -0,0 +1,187 @@
+// src/core/startle.rs
+// THE FLINCH: A bang startles once, not sixty times a second
+//
+// The startle reflex used to run on every spectrum frame: anything over 0.5
+// added cortisol, anything over 0.8 added another 0.2. A loud passage is ~86
+// frames a second, so stress saturated before the first chord ended. Now:
+//
+//   running peak: the loudest recent sound, falling back with peak_decay_secs
+//   surprise:     how much louder this frame is than the running peak
+//   arousal:      above arousal_threshold, cortisol += (surprise - JND) * arousal_gain
+//   shock:        above shock_threshold with surprise >= min_surprise, and not
+//                 within refractory_secs of the last one:
+//                 cortisol += min(surprise * shock_gain, max_cortisol), divided
+//                 by 1 + habituation_rate * (similar shocks in habituation_secs)
+//
+// A sustained roar surprises on its first frame only; separate bangs each
+// register; the same door slamming every half minute matters less each time
+// (`novelty::habituated`, the law that makes repeated sentences boring).
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::core::config::StartleConfig;
+use crate::core::novelty::habituated;
+
+/// Surprise below this is the running peak's own decay under a steady sound, not news.
+const JND: f32 = 0.05;
+
+/// One registered shock.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shock {
+    pub intensity: f32,
+    pub similar: usize, // Similar shocks remembered (habituation)
+    pub cortisol: f32,
+}
+
+/// What one frame did to the body.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Reflex {
+    pub cortisol: f32, // Arousal + shock
+    pub shock: Option<Shock>,
+}
+
+pub struct Startle {
+    config: StartleConfig,
+    peak: f32,
+    last_frame: Option<Instant>,
+    last_shock: Option<Instant>,
+    recent: VecDeque<(Instant, Vec<f32>)>, // Shocks with their (normalized) spectrum
+    pub refractory_skips: u64, // Shock-loud and surprising, but too soon after the last
+}
+
+fn normalized(bands: &[f32]) -> Vec<f32> {
+    let norm = bands.iter().map(|b| b * b).sum::<f32>().sqrt();
+    if norm > 1e-6 { bands.iter().map(|b| b / norm).collect() } else { Vec::new() }
+}
+
+impl Startle {
+    pub fn new(config: StartleConfig) -> Self {
+        Self { config, peak: 0.0, last_frame: None, last_shock: None, recent: VecDeque::new(), refractory_skips: 0 }
+    }
+
+    /// One spectrum frame: `intensity` is its loudness (max of bass/mids), `bands`
+    /// its spectrum (compared against earlier shocks for habituation).
+    pub fn hear(&mut self, intensity: f32, bands: &[f32], now: Instant) -> Reflex {
+        let c = &self.config;
+        let dt = self.last_frame.map_or(0.0, |t| now.saturating_duration_since(t).as_secs_f32());
+        self.last_frame = Some(now);
+        let intensity = if intensity.is_finite() { intensity.max(0.0) } else { 0.0 };
+        let peak = self.peak * (-dt / c.peak_decay_secs.max(0.01)).exp();
+        let surprise = (intensity - peak).max(0.0);
+        self.peak = peak.max(intensity);
+
+        let mut reflex = Reflex::default();
+        if intensity <= c.arousal_threshold {
+            return reflex;
+        }
+        reflex.cortisol = (surprise - JND).max(0.0) * c.arousal_gain;
+        if intensity <= c.shock_threshold || surprise < c.min_surprise {
+            return reflex;
+        }
+        if self.last_shock.is_some_and(|t| now.saturating_duration_since(t).as_secs_f32() < c.refractory_secs) {
+            self.refractory_skips += 1;
+            return reflex;
+        }
+
+        let memory = Duration::from_secs_f32(c.habituation_secs.max(0.0));
+        while self.recent.front().is_some_and(|(t, _)| now.saturating_duration_since(*t) > memory) {
+            self.recent.pop_front();
+        }
+        let spectrum = normalized(bands);
+        let similar = self.recent.iter()
+            .filter(|(_, earlier)| earlier.len() == spectrum.len() && !spectrum.is_empty()
+                && earlier.iter().zip(&spectrum).map(|(a, b)| a * b).sum::<f32>() >= c.habituation_similarity)
+            .count();
+        let cortisol = habituated((surprise * c.shock_gain).min(c.max_cortisol), c.habituation_rate, similar as f32);
+
+        self.last_shock = Some(now);
+        self.recent.push_back((now, spectrum));
+        reflex.cortisol += cortisol;
+        reflex.shock = Some(Shock { intensity, similar, cortisol });
+        reflex
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Spectrum frames (~86/s, 44.1kHz with hop 512) at `intensity` for `secs`, fed from
+    /// `start`; returns the cortisol injected, the shocks and the time after the last frame.
+    fn feed(startle: &mut Startle, intensity: f32, bands: &[f32], secs: f32, start: Instant) -> (f32, Vec<Shock>, Instant) {
+        let frame = Duration::from_secs_f32(512.0 / 44100.0);
+        let frames = (secs / frame.as_secs_f32()) as u32;
+        let (mut cortisol, mut shocks) = (0.0, Vec::new());
+        for i in 0..frames {
+            let reflex = startle.hear(intensity, bands, start + frame * i);
+            cortisol += reflex.cortisol;
+            shocks.extend(reflex.shock);
+        }
+        (cortisol, shocks, start + frame * frames)
+    }
+
+    /// Energy in the i-th group of 8 bands.
+    fn band(i: usize) -> Vec<f32> {
+        (0..64).map(|b| if b / 8 == i % 8 { 1.0 } else { 0.05 }).collect()
+    }
+
+    /// A bang (0.1s) then silence, every 5s, `count` times; the shocks.
+    fn bangs(startle: &mut Startle, count: usize, spectrum: impl Fn(usize) -> Vec<f32>) -> Vec<Shock> {
+        let mut t = Instant::now();
+        let mut all = Vec::new();
+        for i in 0..count {
+            let (_, shocks, after) = feed(startle, 0.95, &spectrum(i), 0.1, t);
+            let (_, _, after) = feed(startle, 0.0, &spectrum(i), 4.9, after);
+            all.extend(shocks);
+            t = after;
+        }
+        all
+    }
+
+    #[test]
+    fn a_sustained_roar_injects_about_one_shock() {
+        // Surprising on its first frame, then the running peak is the roar
+        let config = StartleConfig::default();
+        let mut startle = Startle::new(config.clone());
+        let (_, _, t) = feed(&mut startle, 0.0, &band(0), 1.0, Instant::now());
+        let (roar, shocks, _) = feed(&mut startle, 0.95, &band(0), 10.0, t);
+        let bound = config.max_cortisol + config.arousal_gain * 1.5;
+        assert!(roar <= bound, "10s roar injected {:.3} cortisol (bound {:.3})", roar, bound);
+        assert_eq!(shocks.len(), 1);
+    }
+
+    #[test]
+    fn distinct_bangs_each_register_at_full_strength() {
+        let config = StartleConfig::default();
+        let mut startle = Startle::new(config.clone());
+        let shocks = bangs(&mut startle, 5, band);
+        assert_eq!(shocks.len(), 5);
+        for shock in &shocks {
+            assert_eq!(shock.similar, 0);
+            assert!(shock.cortisol >= config.max_cortisol * 0.5, "{:?}", shock);
+        }
+    }
+
+    #[test]
+    fn the_same_bang_repeated_habituates() {
+        let mut startle = Startle::new(StartleConfig::default());
+        let same: Vec<f32> = bangs(&mut startle, 5, |_| band(3)).iter().map(|s| s.cortisol).collect();
+        // Each shock weaker than the last, the fifth well below the first
+        assert_eq!(same.len(), 5);
+        assert!(same.windows(2).all(|w| w[1] < w[0]), "{:?}", same);
+        assert!(same[4] <= same[0] * 0.5, "{:?}", same);
+    }
+
+    #[test]
+    fn a_second_bang_inside_the_refractory_period_does_not_shock() {
+        // A louder bang 0.5s after a first one is surprising, but no second shock
+        let mut startle = Startle::new(StartleConfig::default());
+        let (_, first, t) = feed(&mut startle, 0.85, &band(1), 0.05, Instant::now());
+        let (_, _, t) = feed(&mut startle, 0.0, &band(1), 0.45, t);
+        let (_, second, _) = feed(&mut startle, 1.4, &band(2), 0.05, t);
+        assert_eq!(first.len(), 1);
+        assert!(second.is_empty(), "{:?}", second);
+        assert!(startle.refractory_skips > 0);
+    }
+}
@@ -0,0 +1,538 @@
+// src/core/stats.rs
+// THE DIARY: What the day was like, one hour at a time
+//
+// After a night alone, the only account of it was the timeline: thousands of
+// lines to scroll. The diary is a bus subscriber that folds the day into
+// hourly buckets instead:
+//
+//   chemistry   time-weighted mean and peak of each level (ChemEvent::Snapshot)
+//   entropy     mean, peak and seconds spent in each tenth of [0, 1]
+//   words       heard (transcripts and typed stimuli) and spoken (Vocal thoughts)
+//   memories    formed: growth of the hippocampus' total between two answers
+//   neurons     gained (Neurogenesis) and lost (the total fell short of the growth)
+//   landmarks   epiphanies, escalating traumas, minutes asleep (SleepStage)
+//
+// Once a day at `[stats] summary_time` (local, default 09:00) the last 24
+// complete hours become one System thought ("📔 Last 24h: slept 7.5h, ...")
+// and `stats/<date>.json` (summary + raw buckets). Awake, rested and with a
+// Planet, ALEPH is also asked to retell it in its own words. `GET /stats/daily`
+// answers the same digest at any time. Every method takes `now`: the test
+// drives the diary with synthetic events on a virtual clock.
+
+use chrono::{DateTime, Local, NaiveTime, TimeDelta, Timelike};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use crate::core::bus::{Bus, ChemEvent, Event, MemoryEvent, SensoryEvent};
+use crate::core::components::{self, ComponentStatus, Components};
+use crate::core::config::StatsConfig;
+use crate::core::hippocampus::MemoryOutput;
+use crate::core::ipc::{EventKind, Landmark};
+use crate::core::thought::{MindVoice, Thought};
+use crate::cortex::planet::{CortexInput, CortexMode};
+use crate::cortex::queue::{CortexPriority, CortexSender};
+
+pub const ENTROPY_BINS: usize = 10;
+const KEEP_HOURS: usize = 48;
+const HOUR_SECS: i64 = 3600;
+const MAX_GAP_SECS: f32 = 60.0; // A longer pause between snapshots (suspended host) is not time lived
+const POLL: Duration = Duration::from_secs(30); // Summary deadline resolution when the bus is quiet
+
+/// Time-weighted mean and peak of a level.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Gauge {
+    pub mean: f32,
+    pub max: f32,
+    pub secs: f32, // Weight behind the mean
+}
+
+impl Gauge {
+    fn add(&mut self, value: f32, secs: f32) {
+        self.max = self.max.max(value);
+        self.secs += secs;
+        if self.secs > 0.0 {
+            self.mean += (value - self.mean) * secs / self.secs;
+        }
+    }
+
+    fn merge(&mut self, other: &Gauge) {
+        let secs = self.secs + other.secs;
+        if secs > 0.0 {
+            self.mean = (self.mean * self.secs + other.mean * other.secs) / secs;
+        }
+        self.max = self.max.max(other.max);
+        self.secs = secs;
+    }
+}
+
+/// One local hour.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Bucket {
+    pub hour: String, // Local "YYYY-MM-DDTHH:00"
+    pub start: i64,   // Unix seconds of the hour's first instant
+    pub dopamine: Gauge,
+    pub cortisol: Gauge,
+    pub adenosine: Gauge,
+    pub oxytocin: Gauge,
+    pub serotonin: Gauge,
+    pub entropy: Gauge,
+    pub entropy_secs: [f32; ENTROPY_BINS], // Seconds spent in each tenth of entropy
+    pub words_heard: u64,
+    pub words_spoken: u64,
+    pub memories_formed: u64,
+    pub neurons_gained: u64,
+    pub neurons_lost: u64,
+    pub epiphanies: u32,
+    pub traumas: u32,
+    pub sleep_secs: f32,
+}
+
+/// A stretch of buckets added up (the daily digest).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Summary {
+    pub hours: usize, // Buckets with anything in them
+    pub sleep_mins: f32,
+    pub words_heard: u64,
+    pub words_spoken: u64,
+    pub memories_formed: u64,
+    pub neurons_gained: u64,
+    pub neurons_lost: u64,
+    pub epiphanies: u32,
+    pub traumas: u32,
+    pub dopamine: Gauge,
+    pub cortisol: Gauge,
+    pub serotonin: Gauge,
+    pub entropy: Gauge,
+}
+
+impl Summary {
+    pub fn of(buckets: &[Bucket]) -> Self {
+        let mut sum = Summary { hours: buckets.len(), ..Default::default() };
+        let mut sleep_secs = 0.0;
+        for b in buckets {
+            sleep_secs += b.sleep_secs;
+            sum.words_heard += b.words_heard;
+            sum.words_spoken += b.words_spoken;
+            sum.memories_formed += b.memories_formed;
+            sum.neurons_gained += b.neurons_gained;
+            sum.neurons_lost += b.neurons_lost;
+            sum.epiphanies += b.epiphanies;
+            sum.traumas += b.traumas;
+            sum.dopamine.merge(&b.dopamine);
+            sum.cortisol.merge(&b.cortisol);
+            sum.serotonin.merge(&b.serotonin);
+            sum.entropy.merge(&b.entropy);
+        }
+        sum.sleep_mins = sleep_secs / 60.0;
+        sum
+    }
+
+    /// The digest in a sentence or two (the template the Planet may retell).
+    pub fn sentence(&self) -> String {
+        format!(
+            "📔 Last 24h: slept {:.1}h, heard {} and said {}, formed {}, neurons +{}/-{}, {} and {}. \
+             Dopamine {:.2} (peak {:.2}), cortisol {:.2} (peak {:.2}), entropy {:.2}.",
+            self.sleep_mins / 60.0,
+            plural(self.words_heard, "word"),
+            self.words_spoken,
+            plural(self.memories_formed, "memory"),
+            self.neurons_gained,
+            self.neurons_lost,
+            plural(self.epiphanies as u64, "epiphany"),
+            plural(self.traumas as u64, "trauma"),
+            self.dopamine.mean, self.dopamine.max,
+            self.cortisol.mean, self.cortisol.max,
+            self.entropy.mean,
+        )
+    }
+}
+
+fn plural(n: u64, noun: &str) -> String {
+    match (n, noun.strip_suffix('y')) {
+        (1, _) => format!("1 {}", noun),
+        (n, Some(stem)) => format!("{} {}ies", n, stem),
+        (n, None) => format!("{} {}s", n, noun),
+    }
+}
+
+/// `stats/<date>.json`: the digest and the raw buckets behind it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayFile {
+    pub date: String,
+    pub summary: Summary,
+    pub sentence: String,
+    pub buckets: Vec<Bucket>,
+}
+
+/// The aggregator (no threads, no wall clock).
+pub struct Stats {
+    buckets: VecDeque<Bucket>, // Oldest first, at most KEEP_HOURS
+    summary_at: NaiveTime,
+    next_summary: DateTime<Local>,
+    last_sample: Option<DateTime<Local>>,
+    asleep: bool,
+    adenosine: f32, // Last snapshot (rested or not, for the retelling)
+    memories_total: Option<usize>,
+    neurons_total: Option<u64>,
+}
+
+impl Stats {
+    pub fn new(summary_at: NaiveTime, now: DateTime<Local>) -> Self {
+        Self {
+            buckets: VecDeque::with_capacity(KEEP_HOURS),
+            summary_at,
+            next_summary: next_occurrence(summary_at, now),
+            last_sample: None,
+            asleep: false,
+            adenosine: 0.0,
+            memories_total: None,
+            neurons_total: None,
+        }
+    }
+
+    fn bucket(&mut self, now: DateTime<Local>) -> &mut Bucket {
+        let start = hour_start(now);
+        let position = self.buckets.iter().rposition(|b| b.start == start);
+        let index = match position {
+            Some(i) => i,
+            None if self.buckets.back().is_some_and(|b| b.start > start) => self.buckets.len() - 1, // Clock stepped back: keep counting in the newest hour
+            None => {
+                self.buckets.push_back(Bucket { hour: now.format("%Y-%m-%dT%H:00").to_string(), start, ..Default::default() });
+                while self.buckets.len() > KEEP_HOURS {
+                    self.buckets.pop_front();
+                }
+                self.buckets.len() - 1
+            },
+        };
+        &mut self.buckets[index]
+    }
+
+    /// Fold one bus event in.
+    pub fn observe(&mut self, event: &Event, now: DateTime<Local>) {
+        match event {
+            Event::Chem(ChemEvent::Snapshot { dopamine, cortisol, adenosine, oxytocin, serotonin, entropy }) => {
+                // The interval since the previous snapshot belongs to the hour it ends in
+                let secs = self.last_sample
+                    .map(|last| ((now - last).num_milliseconds() as f32 / 1000.0).clamp(0.0, MAX_GAP_SECS))
+                    .unwrap_or(0.0);
+                self.last_sample = Some(now);
+                self.adenosine = *adenosine;
+                let asleep = self.asleep;
+                let b = self.bucket(now);
+                b.dopamine.add(*dopamine, secs);
+                b.cortisol.add(*cortisol, secs);
+                b.adenosine.add(*adenosine, secs);
+                b.oxytocin.add(*oxytocin, secs);
+                b.serotonin.add(*serotonin, secs);
+                b.entropy.add(*entropy, secs);
+                b.entropy_secs[((entropy.clamp(0.0, 1.0) * ENTROPY_BINS as f32) as usize).min(ENTROPY_BINS - 1)] += secs;
+                if asleep {
+                    b.sleep_secs += secs;
+                }
+            },
+            Event::Sensory(SensoryEvent::Heard(heard)) => self.bucket(now).words_heard += words(&heard.text),
+            Event::Sensory(SensoryEvent::Stimulus(text)) => self.bucket(now).words_heard += words(text),
+            Event::Thought(thought) if thought.voice == MindVoice::Vocal => self.bucket(now).words_spoken += words(&thought.text),
+            Event::Memory(MemoryEvent::Recalled(out)) => self.memories(out, now),
+            Event::Landmark(landmark) => self.landmark(landmark, now),
+            _ => {},
+        }
+    }
+
+    fn memories(&mut self, out: &MemoryOutput, now: DateTime<Local>) {
+        let total = out._total_count;
+        let formed = self.memories_total.map_or(0, |last| total.saturating_sub(last)); // Forgetting is not forming
+        self.memories_total = Some(total);
+        if formed > 0 {
+            self.bucket(now).memories_formed += formed as u64;
+        }
+    }
+
+    fn landmark(&mut self, landmark: &Landmark, now: DateTime<Local>) {
+        let detail = &landmark.detail;
+        match landmark.kind {
+            EventKind::Epiphany => self.bucket(now).epiphanies += 1,
+            EventKind::TraumaTransition if detail["escalating"].as_bool() == Some(true) => self.bucket(now).traumas += 1,
+            EventKind::SleepStage => self.asleep = detail["to"].as_str().is_some_and(|stage| stage != "Awake"),
+            EventKind::Neurogenesis => {
+                let grown = detail["grown"].as_u64().unwrap_or(0);
+                let total = detail["total"].as_u64();
+                // Whatever the total is missing since the last report was lost in between
+                let lost = match (self.neurons_total, total) {
+                    (Some(last), Some(total)) => (last + grown).saturating_sub(total),
+                    _ => 0,
+                };
+                self.neurons_total = total.or(self.neurons_total);
+                let b = self.bucket(now);
+                b.neurons_gained += grown;
+                b.neurons_lost += lost;
+            },
+            _ => {},
+        }
+    }
+
+    /// The 24 complete hours before the current one, oldest first.
+    pub fn window(&self, now: DateTime<Local>) -> Vec<Bucket> {
+        let end = hour_start(now);
+        self.buckets.iter()
+            .filter(|b| b.start >= end - 24 * HOUR_SECS && b.start < end)
+            .cloned()
+            .collect()
+    }
+
+    /// Time for the daily summary? True once per day, at the first call past `summary_at`.
+    pub fn due(&mut self, now: DateTime<Local>) -> bool {
+        if now < self.next_summary {
+            return false;
+        }
+        self.next_summary = next_occurrence(self.summary_at, now);
+        true
+    }
+
+    /// Awake and with adenosine under `threshold`.
+    pub fn rested(&self, threshold: f32) -> bool {
+        !self.asleep && self.adenosine < threshold
+    }
+}
+
+fn words(text: &str) -> u64 {
+    text.split_whitespace().count() as u64
+}
+
+fn hour_start(now: DateTime<Local>) -> i64 {
+    now.timestamp() - (now.minute() * 60 + now.second()) as i64
+}
+
+/// The first `at` (local) strictly after `now`.
+fn next_occurrence(at: NaiveTime, now: DateTime<Local>) -> DateTime<Local> {
+    let mut day = now.date_naive();
+    for _ in 0..3 {
+        // A time skipped by a DST jump resolves to the next day's
+        if let Some(next) = day.and_time(at).and_local_timezone(Local).earliest().filter(|t| *t > now) {
+            return next;
+        }
+        day = day.succ_opt().unwrap_or(day);
+    }
+    now + TimeDelta::days(1)
+}
+
+/// Write `<dir>/<date>.json` (returns the path).
+pub fn write_day(dir: &Path, day: &DayFile) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{}.json", day.date));
+    fs::write(&path, serde_json::to_vec_pretty(day)?)?;
+    Ok(path)
+}
+
+/// Shared handle: the diary thread writes, `GET /stats/daily` reads.
+#[derive(Clone)]
+pub struct Diary {
+    stats: Arc<Mutex<Stats>>,
+    config: StatsConfig,
+}
+
+impl Diary {
+    pub fn new(config: StatsConfig) -> Self {
+        Self {
+            stats: Arc::new(Mutex::new(Stats::new(config.summary_at(), Local::now()))),
+            config,
+        }
+    }
+
+    /// The last 24 complete hours: digest, sentence and buckets (`GET /stats/daily`).
+    pub fn daily(&self) -> DayFile {
+        let now = Local::now();
+        let buckets = self.stats.lock().unwrap().window(now);
+        let summary = Summary::of(&buckets);
+        DayFile { date: now.format("%Y-%m-%d").to_string(), sentence: summary.sentence(), summary, buckets }
+    }
+
+    /// Subscribe to the bus and keep the diary from a thread of its own
+    /// (call before producers are bridged).
+    pub fn attach(&self, bus: &Bus, tx_thoughts: Sender<Thought>, cortex: Option<CortexSender>, components: Components) {
+        if !self.config.enabled {
+            return;
+        }
+        let rx = bus.subscribe();
+        let diary = self.clone();
+        thread::spawn(move || {
+            loop {
+                match rx.recv_timeout(POLL) {
+                    Ok(event) => diary.stats.lock().unwrap().observe(&event, Local::now()),
+                    Err(RecvTimeoutError::Timeout) => {},
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+                let (due, rested) = {
+                    let mut stats = diary.stats.lock().unwrap();
+                    (stats.due(Local::now()), stats.rested(diary.config.rested_below))
+                };
+                if due {
+                    let day = diary.daily();
+                    diary.publish(&day, &tx_thoughts);
+                    let planet_up = matches!(components.status(components::CORTEX), Some(ComponentStatus::Ready));
+                    if let Some(tx) = cortex.as_ref().filter(|_| diary.config.polish && rested && planet_up) {
+                        tx.send(retelling(&day.sentence), CortexPriority::Agency);
+                    }
+                }
+            }
+        });
+    }
+
+    fn publish(&self, day: &DayFile, tx_thoughts: &Sender<Thought>) {
+        let payload = serde_json::json!({ "event": "daily_summary", "date": day.date, "summary": day.summary });
+        let _ = tx_thoughts.send(Thought::new(MindVoice::System, day.sentence.clone()).with_payload(payload));
+        if let Err(e) = write_day(Path::new(&self.config.dir), day) {
+            let _ = tx_thoughts.send(Thought::new(MindVoice::Error, format!("📔 Diary not written to {}: {}", self.config.dir, e)));
+        }
+    }
+}
+
+/// Ask the Planet to tell the day in its own words.
+fn retelling(sentence: &str) -> CortexInput {
+    CortexInput {
+        mode: CortexMode::Think,
+        text: format!("Así fue mi último día: {} Cuéntalo con tus palabras, en una o dos frases.", sentence.trim_start_matches("📔 ")),
+        bio_state: "Reflective".to_string(),
+        bio_context: String::new(),
+        _somatic_state: "Rested".to_string(),
+        _long_term_memory: None,
+        _cpu_load: 0.0,
+        _ram_pressure: 0.0,
+        _cognitive_impairment: 0.0,
+        entropy: 0.5,
+        adenosine: 0.0,
+        dopamine: 0.5,
+        cortisol: 0.0,
+        _oxytocin: 0.0,
+        temperature_clamp: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn snapshot(dopamine: f32, entropy: f32) -> Event {
+        Event::Chem(ChemEvent::Snapshot { dopamine, cortisol: 0.1, adenosine: 0.3, oxytocin: 0.2, serotonin: 0.5, entropy })
+    }
+
+    fn landmark(kind: EventKind, detail: serde_json::Value) -> Event {
+        Event::Landmark(Landmark { kind, timestamp: 0, monotonic_us: 0, detail })
+    }
+
+    fn recalled(total: usize) -> Event {
+        Event::Memory(MemoryEvent::Recalled(Arc::new(MemoryOutput {
+            input_text: String::new(),
+            novelty: 0.5,
+            retrieval: None,
+            embedding: None,
+            _volatile_count: 0,
+            _total_count: total,
+            semantic_store: Vec::new(),
+            activity: None,
+            memory_only: false,
+        })))
+    }
+
+    #[test]
+    fn a_synthetic_day_adds_up_in_its_hours_and_its_summary() {
+        // Snapshots every 10s from 08:00 to 09:00 the next day, sleep 23:00-07:00,
+        // words, memories, neurons, landmarks on the hour
+        let t0 = Local.with_ymd_and_hms(2026, 1, 14, 8, 0, 0).single().expect("2026-01-14 08:00 is not a local time here");
+        let at = |h: i64, m: i64, s: i64| t0 + TimeDelta::hours(h) + TimeDelta::minutes(m) + TimeDelta::seconds(s);
+        let mut stats = Stats::new(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), t0);
+        let mut fired = Vec::new();
+        let mut total_memories = 100;
+
+        let mut now = t0;
+        let end = at(25, 0, 0);
+        while now <= end {
+            let (elapsed, minute) = ((now - t0).num_seconds(), now.minute());
+            let on = |m: u32| minute == m && now.second() == 0;
+            if now.second() == 0 && minute == 0 {
+                match now.hour() {
+                    23 => stats.observe(&landmark(EventKind::SleepStage, serde_json::json!({ "from": "Awake", "to": "Deep" })), now),
+                    7 => stats.observe(&landmark(EventKind::SleepStage, serde_json::json!({ "from": "Rem", "to": "Awake" })), now),
+                    12 => stats.observe(&landmark(EventKind::Neurogenesis, serde_json::json!({ "grown": 5, "total": 105 })), now),
+                    20 => stats.observe(&landmark(EventKind::Neurogenesis, serde_json::json!({ "grown": 3, "total": 106 })), now),
+                    15 => stats.observe(&landmark(EventKind::Epiphany, serde_json::json!({ "pathways": 3, "cause": "dopamine" })), now),
+                    _ => {},
+                }
+            }
+            if on(0) && now.hour() == 16 {
+                stats.observe(&landmark(EventKind::TraumaTransition, serde_json::json!({ "state": "Alert", "escalating": true })), now);
+            }
+            if on(30) && now.hour() == 16 {
+                stats.observe(&landmark(EventKind::TraumaTransition, serde_json::json!({ "state": "Calm", "escalating": false })), now);
+            }
+            if elapsed == 0 {
+                stats.observe(&landmark(EventKind::Neurogenesis, serde_json::json!({ "grown": 0, "total": 100 })), now);
+                stats.observe(&recalled(total_memories), now);
+            }
+            if on(15) {
+                total_memories += 1;
+                stats.observe(&recalled(total_memories), now);
+                stats.observe(&recalled(total_memories), now); // A probe: same total, nothing formed
+            }
+            if on(30) {
+                stats.observe(&Event::Sensory(SensoryEvent::Stimulus("hola cómo estás".to_string())), now);
+            }
+            if on(45) {
+                stats.observe(&Event::Thought(Thought::new(MindVoice::Vocal, "estoy bien".to_string())), now);
+            }
+            let dopamine = if now == at(6, 0, 0) { 0.9 } else { 0.4 }; // 14:00, one spike
+            let entropy = if (elapsed / 10) % 2 == 0 { 0.25 } else { 0.75 };
+            stats.observe(&snapshot(dopamine, entropy), now);
+            if stats.due(now) {
+                fired.push(now);
+            }
+            now += TimeDelta::seconds(10);
+        }
+
+        // Events at 10:59:59.999 and 11:00:00 land in their own hours
+        stats.observe(&Event::Sensory(SensoryEvent::Stimulus("antes".to_string())), at(2, 59, 59) + TimeDelta::milliseconds(999));
+        stats.observe(&Event::Sensory(SensoryEvent::Stimulus("después".to_string())), at(3, 0, 0));
+        let hour = |h: u32, day: u32| stats.buckets.iter().find(|b| b.hour == format!("2026-01-{:02}T{:02}:00", day, h)).cloned().unwrap_or_default();
+        let (eight, ten, eleven) = (hour(8, 14), hour(10, 14), hour(11, 14));
+        assert_eq!((ten.words_heard, eleven.words_heard), (4, 4));
+        assert!((ten.dopamine.secs - 3600.0).abs() <= 0.01, "{}s of chemistry in 10:00", ten.dopamine.secs);
+        assert!((eight.dopamine.secs - 3590.0).abs() <= 0.01, "{}s of chemistry in 08:00", eight.dopamine.secs);
+        assert!((ten.entropy_secs[2] - 1800.0).abs() <= 0.01 && (ten.entropy_secs[7] - 1800.0).abs() <= 0.01,
+            "entropy distribution of 10:00: {:?} (expected 1800s in bins 2 and 7)", ten.entropy_secs);
+
+        // The summary fires at 09:00 both days and never in between
+        assert_eq!(fired, vec![at(1, 0, 0), at(25, 0, 0)]);
+
+        // The window is the 24 complete hours before it
+        let window = stats.window(end);
+        let summary = Summary::of(&window);
+        assert_eq!(window.len(), 24);
+        assert_eq!(window[0].hour, "2026-01-14T09:00");
+        assert!((summary.sleep_mins - 480.0).abs() <= 0.01, "slept {} min", summary.sleep_mins);
+        assert_eq!((summary.words_heard, summary.words_spoken, summary.memories_formed), (74, 48, 24));
+        assert_eq!((summary.neurons_gained, summary.neurons_lost, summary.epiphanies, summary.traumas), (8, 2, 1, 1));
+        assert!((summary.dopamine.mean - 0.4).abs() <= 0.005, "dopamine {:.4}", summary.dopamine.mean);
+        assert_eq!(summary.dopamine.max, 0.9);
+        assert!((summary.entropy.mean - 0.5).abs() <= 0.005, "entropy {:.4}", summary.entropy.mean);
+        let sentence = summary.sentence();
+        for part in ["slept 8.0h", "heard 74 words", "said 48", "24 memories", "+8/-2", "1 epiphany", "1 trauma", "peak 0.90"] {
+            assert!(sentence.contains(part), "'{}' is missing from '{}'", part, sentence);
+        }
+        let quiet = Summary::of(&[]).sentence();
+        assert!(quiet.contains("0 words") && quiet.contains("0 epiphanies"), "empty day: '{}'", quiet);
+
+        // The day file reads back
+        let dir = std::env::temp_dir().join(format!("aleph-stats-{}", std::process::id()));
+        let day = DayFile { date: "2026-01-15".to_string(), sentence, summary, buckets: window };
+        let bytes = fs::read(write_day(&dir, &day).unwrap()).unwrap();
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(serde_json::from_slice::<DayFile>(&bytes).unwrap(), day);
+    }
+}
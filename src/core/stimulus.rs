@@ -0,0 +1,283 @@
+// src/core/stimulus.rs
+// THE MEMBRANE OF THE SKIN: External Stimulus Admission
+//
+// Every user stimulus spikes dopamine and queues a Planet inference. A client
+// that floods the endpoint would therefore melt the GPU and turn chemistry into
+// noise. Each client gets a token bucket; text is sanitized before it can reach
+// the history/prompt.
+//
+// Only text from outside comes through here, and all of it pays the bucket: a
+// `SYS:` prefix buys nothing. The daemon's own controls (dashboard buttons,
+// SIGHUP) never travel as text; they ride the control channel straight to the
+// bus. POST /feed rides it too, but it is external: it pays the same bucket and
+// its data text is sanitized like a stimulus (`admit_feed`).
+//
+// Bodies arrive as raw bytes from the socket (possibly cut mid-character, or not
+// UTF-8 at all): `parse_body` and `sanitize` never slice bytes (the tests
+// throw multi-byte garbage at both).
+
+use crate::core::bus::{ControlEvent, Event, SensoryEvent};
+use crate::core::config::StimulusConfig;
+use crate::core::feeding::{self, Feeding};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Classic token bucket: `rate` tokens per second, up to `burst` stored.
+pub struct TokenBucket {
+    tokens: f32,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(burst: f32) -> Self {
+        Self {
+            tokens: burst,
+            last_refill: Instant::now(),
+        }
+    }
+
+    pub fn try_take(&mut self, rate: f32, burst: f32) -> bool {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f32();
+        self.last_refill = now;
+        self.tokens = (self.tokens + elapsed * rate).min(burst);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Outcome of presenting a stimulus to the membrane.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Admission {
+    /// Sanitized text that may enter the organism
+    Accepted(String),
+    /// Client exceeded its rate (dropped, counted)
+    RateLimited,
+    /// Nothing left after sanitization
+    Empty,
+}
+
+pub struct StimulusLimiter {
+    config: StimulusConfig,
+    buckets: HashMap<String, TokenBucket>,
+    /// Total stimuli dropped by the limiter this session (telemetry)
+    pub dropped: u64,
+    /// SYS: commands received as stimuli this session (deprecated channel, see core/admin.rs)
+    pub sys_commands: u64,
+}
+
+impl StimulusLimiter {
+    pub fn new(config: StimulusConfig) -> Self {
+        Self {
+            config,
+            buckets: HashMap::new(),
+            dropped: 0,
+            sys_commands: 0,
+        }
+    }
+
+    /// Sanitize and rate-limit a stimulus coming from `client_id`.
+    /// `SYS:` text is external too: it is counted, and pays the bucket like the rest.
+    pub fn admit(&mut self, client_id: &str, raw: &str) -> Admission {
+        let text = sanitize(raw, self.config.max_chars);
+        if text.is_empty() {
+            return Admission::Empty;
+        }
+        if text.starts_with("SYS:") {
+            if self.sys_commands == 0 {
+                println!("⚠️ SYS: commands inside stimuli are deprecated (still accepted, rate-limited): use Admin packets, `aleph ctl`.");
+            }
+            self.sys_commands += 1;
+        }
+
+        if self.take(client_id) {
+            Admission::Accepted(text)
+        } else {
+            Admission::RateLimited
+        }
+    }
+
+    /// A POST /feed from `client_id`: pays the bucket like a stimulus, and its data
+    /// text is sanitized like one. None = rate-limited.
+    pub fn admit_feed(&mut self, client_id: &str, mut feed: Feeding) -> Option<Feeding> {
+        feed.text = feed.text.map(|t| sanitize(&t, self.config.max_chars)).filter(|t| !t.is_empty());
+        self.take(client_id).then_some(feed)
+    }
+
+    /// One token from `client_id`'s bucket (counted as dropped when there is none).
+    fn take(&mut self, client_id: &str) -> bool {
+        // Forget idle clients so the map can't grow without bound
+        if self.buckets.len() > 256 {
+            self.buckets.retain(|_, b| b.last_refill.elapsed().as_secs() < 60);
+        }
+
+        let (rate, burst) = (self.config.rate_per_sec, self.config.burst);
+        let bucket = self.buckets.entry(client_id.to_string())
+            .or_insert_with(|| TokenBucket::new(burst));
+
+        let taken = bucket.try_take(rate, burst);
+        if !taken {
+            self.dropped += 1;
+        }
+        taken
+    }
+}
+
+/// What an admitted stimulus becomes on the bus. The deprecated `SYS:` commands
+/// still map to control events (they already paid the bucket); any other `SYS:`
+/// text is dropped so it never leaks into the Cortex prompt.
+pub fn route(text: String) -> Option<Event> {
+    let control = match text.as_str() {
+        "SYS:SLEEP" => ControlEvent::Sleep,
+        "SYS:POKE" => ControlEvent::Poke,
+        "SYS:RELOAD_GENOME" => ControlEvent::ReloadGenome,
+        "SYS:DND_ON" => ControlEvent::Privacy(true),
+        "SYS:DND_OFF" => ControlEvent::Privacy(false),
+        t if t.starts_with(feeding::COMMAND) => return Feeding::parse_command(t).map(|f| Event::Control(ControlEvent::Feed(f))),
+        t if t.starts_with("SYS:") => return None,
+        _ => return Some(Event::Sensory(SensoryEvent::Stimulus(text))),
+    };
+    Some(Event::Control(control))
+}
+
+/// Strip control characters (newlines become spaces), trim, and truncate to
+/// `max_chars` characters — never bytes, Spanish accents and emoji are multi-byte.
+pub fn sanitize(raw: &str, max_chars: usize) -> String {
+    let cleaned: String = raw.chars()
+        .map(|c| if c == '\n' || c == '\r' || c == '\t' { ' ' } else { c })
+        .filter(|c| !c.is_control())
+        .take(max_chars)
+        .collect();
+    cleaned.trim().to_string()
+}
+
+/// POST /stimulus body
+#[derive(serde::Deserialize)]
+struct StimulusRequest {
+    text: String,
+}
+
+/// The (unsanitized) text of a POST /stimulus body.
+pub fn parse_body(body: &[u8]) -> serde_json::Result<String> {
+    serde_json::from_slice::<StimulusRequest>(body).map(|req| req.text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::textutil;
+    use std::sync::mpsc;
+
+    fn config(rate_per_sec: f32, burst: f32) -> StimulusConfig {
+        StimulusConfig { rate_per_sec, burst, max_chars: 64 }
+    }
+
+    /// One client hammering the membrane: only the burst (plus what refills during
+    /// the flood) reaches the queue, whatever the prefix.
+    fn flood(prefix: &str) -> (usize, StimulusLimiter) {
+        let mut limiter = StimulusLimiter::new(config(5.0, 10.0));
+        let (tx, rx) = mpsc::channel::<String>();
+        for i in 0..10_000 {
+            if let Admission::Accepted(text) = limiter.admit("flooder", &format!("{}{}", prefix, i)) {
+                tx.send(text).unwrap();
+            }
+        }
+        (rx.try_iter().count(), limiter)
+    }
+
+    #[test]
+    fn flood_keeps_queue_depth_bounded() {
+        let (queued, limiter) = flood("hola ");
+        assert!((10..=15).contains(&queued), "{} stimuli queued", queued);
+        assert_eq!(limiter.dropped as usize, 10_000 - queued);
+    }
+
+    #[test]
+    fn sys_prefix_pays_the_bucket() {
+        let (queued, limiter) = flood("SYS:POKE");
+        assert!(queued <= 15, "{} SYS: stimuli queued", queued);
+        assert_eq!(limiter.sys_commands, 10_000);
+        assert_eq!(limiter.dropped as usize, 10_000 - queued);
+
+        let (queued, _) = flood("SYS:FEED:data:0.30:");
+        assert!(queued <= 15, "{} feeds queued", queued);
+    }
+
+    #[test]
+    fn buckets_are_per_client() {
+        let mut limiter = StimulusLimiter::new(config(0.0, 1.0));
+        assert!(matches!(limiter.admit("a", "hola"), Admission::Accepted(_)));
+        assert_eq!(limiter.admit("a", "hola"), Admission::RateLimited);
+        assert!(matches!(limiter.admit("b", "hola"), Admission::Accepted(_)));
+        assert_eq!(limiter.admit("b", " \n\t "), Admission::Empty);
+    }
+
+    #[test]
+    fn feeds_pay_the_bucket_and_their_text_is_sanitized() {
+        let mut limiter = StimulusLimiter::new(config(0.0, 2.0));
+        let data = |text: &str| Feeding { kind: feeding::Food::Data, amount: 0.3, text: Some(text.to_string()) };
+
+        let fed = limiter.admit_feed("a", data(&format!("línea\nuno\u{7}{}", "x".repeat(500)))).unwrap();
+        let text = fed.text.unwrap();
+        assert!(text.starts_with("línea uno") && !text.contains('\u{7}'), "{:?}", text);
+        assert_eq!(text.chars().count(), 64);
+        assert_eq!(limiter.admit_feed("a", data("\n\t")).unwrap().text, None, "nothing left to digest");
+
+        assert_eq!(limiter.admit_feed("a", data("hola")), None);
+        assert_eq!(limiter.admit("a", "hola"), Admission::RateLimited, "feeds and stimuli share the bucket");
+        assert_eq!(limiter.dropped, 2);
+    }
+
+    #[test]
+    fn routes_sys_commands_and_drops_unknown_ones() {
+        assert!(matches!(route("SYS:SLEEP".into()), Some(Event::Control(ControlEvent::Sleep))));
+        assert!(matches!(route("SYS:DND_OFF".into()), Some(Event::Control(ControlEvent::Privacy(false)))));
+        assert!(matches!(route("SYS:FEED:rest:0.20".into()), Some(Event::Control(ControlEvent::Feed(_)))));
+        assert!(route("SYS:FEED:rest:9.0".into()).is_none());
+        assert!(route("SYS:REBOOT".into()).is_none());
+        assert!(matches!(route("hola SYS:SLEEP".into()), Some(Event::Sensory(SensoryEvent::Stimulus(t))) if t == "hola SYS:SLEEP"));
+    }
+
+    #[test]
+    fn sanitize_counts_chars_not_bytes() {
+        assert_eq!(sanitize("  ñandú\n🦤\u{7} ", 10), "ñandú 🦤");
+        assert_eq!(sanitize("áéíóú", 3), "áéí");
+    }
+
+    #[test]
+    fn mangled_bodies_never_panic_and_accepted_text_is_clean() {
+        // Bodies built from multi-byte strings, then mangled: well-formed JSON, the
+        // same cut at a random byte (often mid-character), and raw invalid UTF-8
+        let max_chars = 64; // Small, so the fuzz strings hit the limit
+        let mut limiter = StimulusLimiter::new(StimulusConfig { rate_per_sec: 1e9, burst: 1e9, max_chars });
+        let mut state = 42u64 | 1;
+        for round in 0..5000 {
+            let text = format!("{}\n{}\t\u{7}", textutil::fuzz_string(&mut state, 40), textutil::fuzz_string(&mut state, 40));
+            let body = serde_json::json!({ "text": text }).to_string().into_bytes();
+            let cut_at = textutil::fuzz_string(&mut state, 8).len() % (body.len() + 1);
+            let mut garbage = body[..cut_at].to_vec();
+            garbage.extend_from_slice(&[0xF0, 0x9F, 0x8C]); // Half an emoji
+            garbage.extend_from_slice(&body[cut_at..]);
+
+            for (kind, bytes) in [("well-formed", &body), ("cut", &body[..cut_at].to_vec()), ("invalid utf-8", &garbage)] {
+                match parse_body(bytes) {
+                    Ok(raw) => {
+                        if kind == "well-formed" {
+                            assert_eq!(raw, text, "round {}: body text parsed wrong", round);
+                        }
+                        if let Admission::Accepted(clean) = limiter.admit("fuzz", &raw) {
+                            assert!(clean.chars().count() <= max_chars && !clean.chars().any(|c| c.is_control()),
+                                "round {}: accepted {:?} (max {} chars)", round, clean, max_chars);
+                        }
+                    },
+                    Err(_) => assert_ne!(kind, "well-formed", "round {}: well-formed body rejected: {:?}", round, text),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// src/core/textutil.rs
+// THE SCISSORS: Cutting text without cutting a character in half
+//
+// `&text[..n]` panics when n falls inside a multi-byte character, and ALEPH's
+// text is full of them (Spanish accents, emoji from the TUI, whatever a client
+// POSTs). A few sites sliced bytes of arbitrary text and survived only because
+// the panic hook caught the thread on the way down. Every cut of text that came
+// from outside (or from the Planet) goes through here instead:
+//
+//   truncate_chars(s, n): the first n characters
+//   tail_chars(s, n):     the last n characters
+//   safe_window(s, a, b): the byte range a..b, widened outwards to char boundaries
+//   clip_bytes(s, n):     the longest prefix of at most n bytes (wire limits)
+//
+// All of them borrow (no allocation) and none of them can panic, whatever the
+// indices.
+
+/// The first `max` characters of `s` (all of it if shorter).
+pub fn truncate_chars(s: &str, max: usize) -> &str {
+    match s.char_indices().nth(max) {
+        Some((i, _)) => &s[..i],
+        None => s,
+    }
+}
+
+/// The last `max` characters of `s` (all of it if shorter).
+pub fn tail_chars(s: &str, max: usize) -> &str {
+    if max == 0 {
+        return "";
+    }
+    match s.char_indices().rev().nth(max - 1) {
+        Some((i, _)) => &s[i..],
+        None => s,
+    }
+}
+
+/// The bytes `start..end` of `s`, clamped to its length and widened to the
+/// enclosing char boundaries (a window never splits a character; empty if start >= end).
+pub fn safe_window(s: &str, start: usize, end: usize) -> &str {
+    let end = end.min(s.len());
+    if start >= end {
+        return "";
+    }
+    let mut start = start;
+    while !s.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = end;
+    while !s.is_char_boundary(end) {
+        end += 1;
+    }
+    &s[start..end]
+}
+
+/// The longest prefix of `s` that fits in `max_bytes` bytes and ends on a char boundary.
+pub fn clip_bytes(s: &str, max_bytes: usize) -> &str {
+    if s.len() <= max_bytes {
+        return s;
+    }
+    let mut end = max_bytes;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Tiny deterministic generator (same strings on every run).
+#[cfg(test)]
+fn next(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
+
+/// Pieces of 1 to 4 bytes, plus sequences that only look like one character
+/// (combining accents, ZWJ emoji, flags, variation selectors).
+#[cfg(test)]
+const PIECES: [&str; 16] = [
+    "a", " ", "ñ", "é", "e\u{301}", "¿", "—", "…", "今", "日", "🌊", "🧠",
+    "👁️‍🗨️", "🇨🇱", "\u{200d}", "\u{feff}",
+];
+
+/// A random string of up to `max_pieces` pieces (multi-byte fuzz for the text tests).
+#[cfg(test)]
+pub(crate) fn fuzz_string(state: &mut u64, max_pieces: usize) -> String {
+    let n = (next(state) % (max_pieces as u64 + 1)) as usize;
+    (0..n).map(|_| PIECES[(next(state) % PIECES.len() as u64) as usize]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn helpers_never_split_a_character_or_overrun_their_budget() {
+        // Random multi-byte strings and random, often out-of-range indices: every
+        // result must be the prefix / suffix / sub-slice it claims to be
+        let mut state = 42u64 | 1;
+        for _ in 0..5000 {
+            let s = fuzz_string(&mut state, 24);
+            let chars = s.chars().count();
+            let n = (next(&mut state) % (s.len() as u64 + 8)) as usize;
+            let (a, b) = ((next(&mut state) % (s.len() as u64 + 4)) as usize, (next(&mut state) % (s.len() as u64 + 4)) as usize);
+
+            let head = truncate_chars(&s, n);
+            assert!(s.starts_with(head) && head.chars().count() == n.min(chars), "truncate_chars({:?}, {}) = {:?}", s, n, head);
+            let tail = tail_chars(&s, n);
+            assert!(s.ends_with(tail) && tail.chars().count() == n.min(chars), "tail_chars({:?}, {}) = {:?}", s, n, tail);
+
+            let window = safe_window(&s, a, b);
+            let (from, to) = (a, b.min(s.len()));
+            let offset = (window.as_ptr() as usize).wrapping_sub(s.as_ptr() as usize);
+            let covers = if from >= to {
+                window.is_empty()
+            } else {
+                offset <= from && offset + window.len() >= to && s.get(offset..offset + window.len()) == Some(window)
+            };
+            assert!(covers, "safe_window({:?}, {}, {}) = {:?}", s, a, b, window);
+
+            let clipped = clip_bytes(&s, n);
+            let longest = (0..=n.min(s.len())).rev().find(|&i| s.is_char_boundary(i)).unwrap_or(0);
+            assert!(s.starts_with(clipped) && clipped.len() <= n && clipped.len() == longest, "clip_bytes({:?}, {}) = {:?}", s, n, clipped);
+        }
+    }
+}
@@ -1,38 +1,111 @@
-use std::time::Instant;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+use crate::core::timeline::unix_millis;
+
+/// Next Thought id (shared by every producer, so ids order thoughts across channels).
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+// What happens to each voice (spoken, memorized, streamed, color) is not decided
+// here but in the routing table: VoicesConfig, `[voices.<name>]` in aleph.toml.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MindVoice {
     Sensory, // [F₁] - Inertia/Body (Hardware Input) - Cyan
     Cortex,  // [F₂] - Drift/Semantic (LLM Thought) - Green
     Chem,    // [ΔE] - Energy Delta (Chemical State) - Magenta
     System,  // [ΔS] - State Delta (System Event) - DarkGray
     Vocal,   // [F₃] - Collapse/Observer (Vocalized) - White/Bold
+    Dream,   // [Ψ] - Sleep, REM and consolidation - Indigo
+    Memory,  // [M] - Hippocampus retrievals (rumination, recall) - Amber
+    Error,   // [ε] - Component failures (cortex, autosave, ears) - Red
+    Agency,  // [α] - Self-initiated action (speaking unprompted, exploring) - Yellow
+}
+
+impl MindVoice {
+    pub const ALL: [MindVoice; 9] = [
+        MindVoice::Sensory, MindVoice::Cortex, MindVoice::Chem, MindVoice::System, MindVoice::Vocal,
+        MindVoice::Dream, MindVoice::Memory, MindVoice::Error, MindVoice::Agency,
+    ];
+
+    /// Key in the routing table (`[voices.dream]`) and in telemetry.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MindVoice::Sensory => "sensory",
+            MindVoice::Cortex => "cortex",
+            MindVoice::Chem => "chem",
+            MindVoice::System => "system",
+            MindVoice::Vocal => "vocal",
+            MindVoice::Dream => "dream",
+            MindVoice::Memory => "memory",
+            MindVoice::Error => "error",
+            MindVoice::Agency => "agency",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MindVoice::Sensory => "F₁",   // Inertia/Body (Hardware Input)
+            MindVoice::Cortex => "F₂",    // Drift/Semantic (LLM Output)
+            MindVoice::Chem => "ΔE",      // Energy Delta (Chemical State Change)
+            MindVoice::System => "ΔS",    // State Delta (System Event)
+            MindVoice::Vocal => "F₃",     // Collapse/Observer (Vocalized Output)
+            MindVoice::Dream => "Ψ",      // The Sleeping Mind
+            MindVoice::Memory => "M",     // Recall
+            MindVoice::Error => "ε",      // Failure
+            MindVoice::Agency => "α",     // Will
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct Thought {
+    pub id: u64, // Monotonic, assigned at birth (before any channel reorders it)
     pub voice: MindVoice,
     pub text: String,
-    #[allow(dead_code)]
-    pub timestamp: Instant, // Reservado para timeline / Variable Metabolism
+    pub timestamp: SystemTime, // Wall clock at birth (timeline, UIs)
+    pub cause_id: Option<u64>, // The thought that provoked this one (stimulus -> Vocal)
+    pub payload: Option<serde_json::Value>, // Machine-readable details (counts, severities)
 }
 
 impl Thought {
     pub fn new(voice: MindVoice, text: String) -> Self {
         Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
             voice,
             text,
-            timestamp: Instant::now(),
+            timestamp: SystemTime::now(),
+            cause_id: None,
+            payload: None,
         }
     }
 
+    pub fn caused_by(mut self, cause_id: Option<u64>) -> Self {
+        self.cause_id = cause_id;
+        self
+    }
+
+    pub fn with_payload(mut self, payload: serde_json::Value) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
     pub fn voice_label(&self) -> &str {
-        match self.voice {
-            MindVoice::Sensory => "F₁",   // Inertia/Body (Hardware Input)
-            MindVoice::Cortex => "F₂",    // Drift/Semantic (LLM Output)
-            MindVoice::Chem => "ΔE",      // Energy Delta (Chemical State Change)
-            MindVoice::System => "ΔS",    // State Delta (System Event)
-            MindVoice::Vocal => "F₃",     // Collapse/Observer (Vocalized Output)
-        }
+        self.voice.label()
+    }
+
+    pub fn unix_millis(&self) -> u64 {
+        unix_millis(self.timestamp)
     }
+
+    /// "HH:MM:SS [F₂] text" (stdout, short-term stream, TUI).
+    pub fn line(&self) -> String {
+        format!("{} [{}] {}", clock(self.timestamp), self.voice_label(), self.text)
+    }
+}
+
+/// Local wall-clock time as "HH:MM:SS".
+pub fn clock(at: SystemTime) -> String {
+    chrono::DateTime::<chrono::Local>::from(at).format("%H:%M:%S").to_string()
 }
@@ -0,0 +1,224 @@
+// src/core/thread.rs
+// THE THREAD: Picking up where we left off
+//
+// Retrieval only ever answered the sentence just heard, so someone coming back
+// after a week met an ALEPH with no idea what they last talked about. Now,
+// when a known voice is heard after gap_secs of absence (or for the first time
+// this run), the hippocampus is asked for that speaker's most recent turns:
+//
+//   MemoryFilter { speaker, source: heard, since: now − lookback, until: now }
+//
+// (the sentence being heard right now is not "last time"). The reply becomes
+// a compact "Última vez con X (hace 7 días): «...» · «...»" that rides in the
+// Planet's memory slot for the next `turns` inputs, next to the usual
+// retrieval, and a Memory thought on the timeline. A turn comes back whole
+// (utterance-id siblings), so the topic survives sentence splitting.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::core::config::ThreadConfig;
+use crate::core::memory_vector::{MemoryFilter, MemoryRecord};
+use crate::core::textutil;
+
+pub struct ConversationThread {
+    config: ThreadConfig,
+    last_heard: HashMap<String, Instant>, // By lowercase name
+    active: Option<String>,               // The speaker whose thread is being picked up
+    context: Option<String>,
+    uses_left: u32,
+}
+
+/// "hace 7 días", "hace 3 horas", "hace un momento".
+fn ago(secs: u64) -> String {
+    match secs {
+        s if s >= 2 * 86_400 => format!("hace {} días", s / 86_400),
+        s if s >= 86_400 => "ayer".to_string(),
+        s if s >= 7_200 => format!("hace {} horas", s / 3_600),
+        s if s >= 120 => format!("hace {} minutos", s / 60),
+        _ => "hace un momento".to_string(),
+    }
+}
+
+impl ConversationThread {
+    pub fn new(config: ThreadConfig) -> Self {
+        Self { config, last_heard: HashMap::new(), active: None, context: None, uses_left: 0 }
+    }
+
+    /// A known voice spoke at `now` (`unix` = now in Unix seconds). Some((filter, top_k))
+    /// when this is a return: the query to send the hippocampus (MemoryCommand::Recall).
+    pub fn heard(&mut self, speaker: &str, now: Instant, unix: u64) -> Option<(MemoryFilter, usize)> {
+        if !self.config.enabled {
+            return None;
+        }
+        let key = speaker.to_lowercase();
+        let previous = self.last_heard.insert(key, now);
+        let returning = previous.is_none_or(|at| now.duration_since(at).as_secs_f32() >= self.config.gap_secs);
+        if !returning {
+            return None;
+        }
+        self.active = Some(speaker.to_string());
+        self.context = None;
+        let lookback = (self.config.lookback_days * 86_400.0) as u64;
+        Some((MemoryFilter {
+            speaker: Some(speaker.to_string()),
+            since: (lookback > 0).then(|| unix.saturating_sub(lookback)),
+            until: Some(unix),
+            source: Some("heard".to_string()),
+        }, self.config.top_k.max(1)))
+    }
+
+    /// The hippocampus answered ("THREAD: <speaker>", turns newest first). Some(thought
+    /// line) if there was a last time; its context then waits for the Planet.
+    pub fn recalled(&mut self, speaker: &str, turns: &[MemoryRecord], unix: u64) -> Option<String> {
+        if turns.is_empty() || self.active.as_deref().is_none_or(|a| !a.eq_ignore_ascii_case(speaker)) {
+            return None; // Nothing yet, or someone else took the floor meanwhile
+        }
+        let quotes: Vec<String> = turns.iter().map(|t| format!("«{}»", t.text.trim())).collect();
+        let line = format!("Última vez con {} ({}): {}", speaker, ago(unix.saturating_sub(turns[0].timestamp)), quotes.join(" · "));
+        let context = textutil::truncate_chars(&line, self.config.max_chars).to_string();
+        self.context = Some(context.clone());
+        self.uses_left = self.config.turns;
+        Some(context)
+    }
+
+    /// What goes in the Planet's memory slot: the thread (while it lasts) and the retrieval.
+    pub fn memory_slot(&mut self, retrieval: Option<&str>) -> Option<String> {
+        let thread = self.context.clone().filter(|_| self.uses_left > 0);
+        if thread.is_some() {
+            self.uses_left -= 1;
+        }
+        match (thread, retrieval) {
+            (Some(thread), Some(retrieval)) => Some(format!("{}\n{}", thread, retrieval)),
+            (Some(thread), None) => Some(thread),
+            (None, retrieval) => retrieval.map(str::to_string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::memory_vector::{self, Provenance};
+    use std::time::Duration;
+
+    const DAY: u64 = 86_400;
+    const NOW_UNIX: u64 = 1_800_000_000;
+
+    fn record(text: &str, days_ago: f32, provenance: Provenance, utterance: Option<u64>) -> MemoryRecord {
+        MemoryRecord {
+            text: text.to_string(),
+            embedding: vec![1.0],
+            timestamp: NOW_UNIX - (days_ago * DAY as f32) as u64,
+            context_tags: Vec::new(),
+            entropy: 0.8,
+            consolidated: true,
+            activity: Vec::new(),
+            provenance,
+            utterance,
+        }
+    }
+
+    fn marta() -> Provenance {
+        Provenance::Heard { speaker: Some("Marta".to_string()) }
+    }
+
+    /// Marta's turns from a week ago (one split in two sentences), an older one past
+    /// the lookback, one from today's session, Pablo's, a web message and a dream about her.
+    fn memories() -> Vec<MemoryRecord> {
+        vec![
+            record("Me voy a Lisboa en mayo.", 120.0, marta(), None),
+            record("Mi perro se llama Trufa.", 8.0, marta(), None),
+            record("Mañana empiezo el trabajo nuevo.", 7.0, marta(), Some(41)),
+            record("Estoy un poco nerviosa.", 7.0, marta(), Some(41)),
+            record("Hola Aleph.", 7.0, Provenance::Heard { speaker: Some("Pablo".to_string()) }, None),
+            record("Soy Marta, desde la web.", 6.0, Provenance::Told { client_id: "web-3".to_string() }, None),
+            record("Soñé con Marta y un perro.", 5.0, Provenance::Dream, None),
+            record("Hola, ya volví.", 0.0, marta(), None),
+        ]
+    }
+
+    /// The turns the hippocampus answers a return with.
+    fn recall(memories: &[MemoryRecord], filter: &MemoryFilter, top_k: usize) -> Vec<MemoryRecord> {
+        memory_vector::latest(memories, filter, top_k).into_iter()
+            .map(|i| MemoryRecord { text: memory_vector::recall_utterance(memories, i), ..memories[i].clone() })
+            .collect()
+    }
+
+    #[test]
+    fn a_return_recalls_last_weeks_heard_turns_whole_and_newest_first() {
+        let config = ThreadConfig::default();
+        let memories = memories();
+        let mut thread = ConversationThread::new(config.clone());
+        let (filter, top_k) = thread.heard("Marta", Instant::now(), NOW_UNIX).expect("Marta's first word this run asked nothing");
+        assert_eq!(filter.speaker.as_deref(), Some("Marta"));
+        assert_eq!(filter.source.as_deref(), Some("heard"));
+        assert_eq!(filter.until, Some(NOW_UNIX));
+        assert_eq!(filter.since, Some(NOW_UNIX - 90 * DAY));
+        assert_eq!(top_k, config.top_k);
+        let turns = recall(&memories, &filter, top_k);
+        let texts: Vec<&str> = turns.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["Mañana empiezo el trabajo nuevo. Estoy un poco nerviosa.", "Mi perro se llama Trufa."]);
+    }
+
+    #[test]
+    fn each_part_of_the_filter_admits_on_its_own() {
+        let memories = memories();
+        let cases = [
+            ("speaker (any case)", MemoryFilter { speaker: Some("marta".to_string()), ..Default::default() }, 5),
+            ("source told", MemoryFilter { source: Some("told".to_string()), ..Default::default() }, 1),
+            ("last 7.5 days", MemoryFilter { since: Some(NOW_UNIX - DAY * 15 / 2), ..Default::default() }, 6),
+            ("before today", MemoryFilter { until: Some(NOW_UNIX), ..Default::default() }, 7),
+            ("nothing", MemoryFilter::default(), memories.len()),
+        ];
+        for (name, filter, expected) in cases {
+            assert_eq!(memories.iter().filter(|m| filter.admits(m)).count(), expected, "filter {}", name);
+        }
+    }
+
+    #[test]
+    fn the_reply_rides_the_memory_slot_for_a_few_turns() {
+        let config = ThreadConfig::default();
+        let memories = memories();
+        let t0 = Instant::now();
+        let mut thread = ConversationThread::new(config.clone());
+        let (filter, top_k) = thread.heard("Marta", t0, NOW_UNIX).unwrap();
+        let turns = recall(&memories, &filter, top_k);
+        let thought = thread.recalled("Marta", &turns, NOW_UNIX).expect("no thought");
+        assert!(thought.starts_with("Última vez con Marta (hace 7 días)") && thought.contains("Trufa"), "{}", thought);
+
+        // Next to the retrieval for `turns` inputs, then the retrieval alone
+        let slots: Vec<Option<String>> = (0..config.turns + 1).map(|_| thread.memory_slot(Some("Recuerdo Relacionado: Lisboa"))).collect();
+        let with_thread = slots.iter().filter(|s| s.as_deref().is_some_and(|s| s.contains("Última vez con Marta") && s.ends_with("Lisboa"))).count();
+        assert_eq!(with_thread, config.turns as usize, "{:?}", slots);
+        assert_eq!(slots.last(), Some(&Some("Recuerdo Relacionado: Lisboa".to_string())));
+
+        // Within the gap nothing is asked; past it, again
+        assert!(thread.heard("MARTA", t0 + Duration::from_secs(600), NOW_UNIX + 600).is_none(), "ten minutes later counted as a return");
+        let later = t0 + Duration::from_secs(600) + Duration::from_secs_f32(config.gap_secs);
+        assert!(thread.heard("Marta", later, NOW_UNIX + 600 + config.gap_secs as u64).is_some(), "after the gap was not a return");
+    }
+
+    #[test]
+    fn stale_empty_and_disabled_replies_inject_nothing() {
+        let config = ThreadConfig::default();
+        let memories = memories();
+        let t0 = Instant::now();
+        let mut thread = ConversationThread::new(config.clone());
+        let (filter, top_k) = thread.heard("Marta", t0, NOW_UNIX).unwrap();
+        let turns = recall(&memories, &filter, top_k);
+
+        // Pablo took the floor before the reply came back
+        thread.heard("Pablo", t0, NOW_UNIX);
+        assert_eq!(thread.recalled("Marta", &turns, NOW_UNIX), None);
+        assert_eq!(thread.memory_slot(None), None);
+
+        let mut empty = ConversationThread::new(config.clone());
+        empty.heard("Marta", t0, NOW_UNIX);
+        assert_eq!(empty.recalled("Marta", &[], NOW_UNIX), None);
+        assert_eq!(empty.memory_slot(Some("r")).as_deref(), Some("r"));
+
+        let mut disabled = ConversationThread::new(ThreadConfig { enabled: false, ..config });
+        assert!(disabled.heard("Marta", t0, NOW_UNIX).is_none());
+    }
+}
@@ -0,0 +1,335 @@
+// src/core/timeline.rs
+// THE CHRONICLE: Every thought, kept
+//
+// The short-term stream (30 lines) is what ALEPH is thinking *now*. The
+// timeline is what it thought today: a bounded in-memory ring the dashboard
+// pages through (`GET /timeline`) and a daily NDJSON file that survives the
+// process. It is a plain bus subscriber with its own thread, so disk latency
+// and fsync never reach the physics loop (the bus channel is unbounded; a slow
+// disk delays the log, it doesn't drop thoughts).
+//
+// Identical consecutive System lines ("🌱 Neurogenesis..." every few seconds)
+// collapse into one entry with a count (xN): the ring updates it in place, the
+// file gets it once the run ends.
+//
+// Events (AlephPacket::Event: epiphanies, sleep stages, restarts...) are recorded
+// in the same stream as voice "Event", so a session log replays them in place.
+
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::core::bus::{Bus, Event};
+use crate::core::config::TimelineConfig;
+use crate::core::ipc::Landmark;
+use crate::core::thought::Thought;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineEntry {
+    pub id: u64,       // Thought id (the first one, for a collapsed run)
+    pub ts: u64,       // Unix millis (when the thought was born, not when it was logged; the last one of a run)
+    pub voice: String, // "Vocal", "Cortex", ...
+    pub label: String, // "F₃", "F₂", ...
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cause_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "is_one")]
+    pub count: u32, // Identical consecutive System thoughts collapsed into this one
+}
+
+impl TimelineEntry {
+    fn from_thought(thought: &Thought) -> Self {
+        Self {
+            id: thought.id,
+            ts: thought.unix_millis(),
+            voice: format!("{:?}", thought.voice),
+            label: thought.voice_label().to_string(),
+            text: thought.text.clone(),
+            cause_id: thought.cause_id,
+            payload: thought.payload.clone(),
+            count: 1,
+        }
+    }
+
+    /// An AlephPacket::Event, recorded between the thoughts it happened among
+    /// (voice "Event", label = kind; `GET /timeline?voice=Event` is the event timeline).
+    fn from_landmark(landmark: &Landmark) -> Self {
+        let kind = format!("{:?}", landmark.kind);
+        Self {
+            id: 0,
+            ts: landmark.timestamp,
+            voice: "Event".to_string(),
+            text: format!("{} {}", kind, landmark.detail),
+            label: kind,
+            cause_id: None,
+            payload: Some(landmark.detail.clone()),
+            count: 1,
+        }
+    }
+
+    /// Same System line again (only narration collapses; words and stimuli never do).
+    fn repeats(&self, next: &TimelineEntry) -> bool {
+        next.voice == "System" && self.voice == next.voice && self.text == next.text && self.payload == next.payload
+    }
+}
+
+fn is_one(n: &u32) -> bool {
+    *n == 1
+}
+
+/// Shared handle: the recorder thread writes, HTTP handlers read.
+#[derive(Clone)]
+pub struct Timeline {
+    ring: Arc<Mutex<VecDeque<TimelineEntry>>>,
+    config: TimelineConfig,
+}
+
+impl Timeline {
+    pub fn new(config: TimelineConfig) -> Self {
+        Self {
+            ring: Arc::new(Mutex::new(VecDeque::with_capacity(config.ring_capacity.min(65536)))),
+            config,
+        }
+    }
+
+    /// Subscribe to the bus and start recording (call before producers are bridged).
+    pub fn attach(&self, bus: &Bus) {
+        let rx = bus.subscribe();
+        let ring = self.ring.clone();
+        let capacity = self.config.ring_capacity.max(1);
+        let sync_every = Duration::from_secs(self.config.fsync_secs.max(1));
+        let mut log = DailyLog::new(PathBuf::from(&self.config.log_dir), self.config.keep_days);
+
+        thread::spawn(move || {
+            let mut unwritten: Option<TimelineEntry> = None; // System run still open (may collapse further)
+            loop {
+                let received = rx.recv_timeout(sync_every).map(|event| match event {
+                    Event::Thought(thought) => Some(TimelineEntry::from_thought(&thought)),
+                    Event::Landmark(landmark) => Some(TimelineEntry::from_landmark(&landmark)),
+                    _ => None,
+                });
+                match received {
+                    Ok(Some(entry)) => {
+                        let mut ring = ring.lock().unwrap();
+                        if let Some(last) = ring.back_mut().filter(|last| last.repeats(&entry)) {
+                            last.count += 1;
+                            last.ts = entry.ts;
+                            unwritten = Some(last.clone());
+                        } else {
+                            if let Some(run) = unwritten.take() {
+                                log.append(&run);
+                            }
+                            if entry.voice == "System" {
+                                unwritten = Some(entry.clone());
+                            } else {
+                                log.append(&entry);
+                            }
+                            ring.push_back(entry);
+                            while ring.len() > capacity {
+                                ring.pop_front();
+                            }
+                        }
+                    },
+                    Ok(None) => {},
+                    Err(RecvTimeoutError::Timeout) => {},
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+                log.sync_if_due(sync_every);
+            }
+            if let Some(run) = unwritten.take() {
+                log.append(&run);
+            }
+            log.sync();
+        });
+    }
+
+    /// Entries newer than `since` (unix millis), optionally of one voice (name or label),
+    /// oldest first, at most `limit` (the most recent ones win).
+    pub fn query(&self, since: u64, voice: Option<&str>, limit: usize) -> Vec<TimelineEntry> {
+        let ring = self.ring.lock().unwrap();
+        let mut out: Vec<TimelineEntry> = ring.iter()
+            .rev()
+            .take_while(|e| e.ts > since)
+            .filter(|e| voice.map_or(true, |v| e.voice.eq_ignore_ascii_case(v) || e.label == v))
+            .take(limit)
+            .cloned()
+            .collect();
+        out.reverse();
+        out
+    }
+}
+
+/// Append-only `thoughts-YYYY-MM-DD.ndjson`, rotated at local midnight.
+struct DailyLog {
+    dir: PathBuf,
+    keep_days: usize,
+    day: String,
+    file: Option<BufWriter<File>>,
+    dirty: bool,
+    last_sync: Instant,
+    failed: bool, // Log the disk error once, keep the ring alive
+}
+
+impl DailyLog {
+    fn new(dir: PathBuf, keep_days: usize) -> Self {
+        Self {
+            dir,
+            keep_days,
+            day: String::new(),
+            file: None,
+            dirty: false,
+            last_sync: Instant::now(),
+            failed: false,
+        }
+    }
+
+    fn append(&mut self, entry: &TimelineEntry) {
+        if let Err(e) = self.try_append(entry) {
+            if !self.failed {
+                println!("⚠️ TIMELINE: Session log unavailable ({}). Keeping memory only.", e);
+            }
+            self.failed = true;
+            self.file = None;
+        }
+    }
+
+    fn try_append(&mut self, entry: &TimelineEntry) -> io::Result<()> {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        if self.file.is_none() || today != self.day {
+            self.rotate(today)?;
+        }
+        if let Some(file) = self.file.as_mut() {
+            serde_json::to_writer(&mut *file, entry)?;
+            file.write_all(b"\n")?;
+            self.dirty = true;
+        }
+        Ok(())
+    }
+
+    fn rotate(&mut self, day: String) -> io::Result<()> {
+        self.sync();
+        fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(format!("thoughts-{}.ndjson", day));
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        self.file = Some(BufWriter::new(file));
+        self.day = day;
+        self.failed = false;
+        self.prune();
+        Ok(())
+    }
+
+    /// Keep the newest `keep_days` files (names sort by date).
+    fn prune(&self) {
+        let Ok(entries) = fs::read_dir(&self.dir) else { return };
+        let mut logs: Vec<PathBuf> = entries.flatten()
+            .map(|e| e.path())
+            .filter(|p| {
+                let name = p.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                name.starts_with("thoughts-") && name.ends_with(".ndjson")
+            })
+            .collect();
+        logs.sort();
+        while logs.len() > self.keep_days.max(1) {
+            let _ = fs::remove_file(logs.remove(0));
+        }
+    }
+
+    fn sync_if_due(&mut self, every: Duration) {
+        if self.dirty && self.last_sync.elapsed() >= every {
+            self.sync();
+        }
+    }
+
+    fn sync(&mut self) {
+        if let Some(file) = self.file.as_mut() {
+            let _ = file.flush();
+            let _ = file.get_ref().sync_data();
+        }
+        self.dirty = false;
+        self.last_sync = Instant::now();
+    }
+}
+
+pub fn unix_millis(t: SystemTime) -> u64 {
+    t.duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::thought::MindVoice;
+
+    fn timeline(name: &str, ring_capacity: usize) -> (Timeline, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("aleph-timeline-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let config = TimelineConfig { ring_capacity, log_dir: dir.to_string_lossy().to_string(), fsync_secs: 1, keep_days: 2 };
+        (Timeline::new(config), dir)
+    }
+
+    /// Lines in every session log under `dir`, once the recorder has finished writing.
+    fn logged_lines(dir: &PathBuf, expected: usize) -> Vec<serde_json::Value> {
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            let lines: Vec<serde_json::Value> = fs::read_dir(dir).into_iter().flatten().flatten()
+                .filter_map(|e| fs::read_to_string(e.path()).ok())
+                .flat_map(|s| s.lines().filter_map(|l| serde_json::from_str(l).ok()).collect::<Vec<_>>()) // (a line may be half-written)
+                .collect();
+            if lines.len() >= expected || Instant::now() > deadline {
+                return lines;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn no_thought_is_lost_in_a_burst() {
+        const BURST: usize = 10_000;
+        let (timeline, dir) = timeline("burst", BURST);
+        let bus = Bus::new();
+        timeline.attach(&bus);
+
+        let mut ids = Vec::with_capacity(BURST);
+        for i in 0..BURST {
+            let thought = Thought::new(MindVoice::Vocal, format!("pensamiento {}", i));
+            ids.push(thought.id);
+            bus.publish(Event::Thought(thought));
+        }
+        drop(bus); // The recorder drains what's queued, then flushes and exits
+
+        let logged = logged_lines(&dir, BURST);
+        assert_eq!(logged.iter().map(|e| e["id"].as_u64().unwrap()).collect::<Vec<_>>(), ids);
+        assert_eq!(logged[BURST - 1]["text"], format!("pensamiento {}", BURST - 1));
+        let ring = timeline.query(0, Some("Vocal"), usize::MAX);
+        assert_eq!(ring.len(), BURST);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn ring_is_bounded_and_system_repeats_collapse() {
+        let (timeline, dir) = timeline("ring", 5);
+        let bus = Bus::new();
+        timeline.attach(&bus);
+        for _ in 0..3 {
+            bus.publish(Event::Thought(Thought::new(MindVoice::System, "🌱 Neurogenesis".to_string())));
+        }
+        for i in 0..6 {
+            bus.publish(Event::Thought(Thought::new(MindVoice::Vocal, format!("hola {}", i))));
+        }
+        drop(bus);
+
+        let logged = logged_lines(&dir, 7);
+        assert_eq!(logged.len(), 7, "one collapsed System line + six words");
+        assert!(logged.iter().any(|e| e["voice"] == "System" && e["count"] == 3));
+        let ring = timeline.query(0, None, usize::MAX);
+        assert_eq!(ring.iter().map(|e| e.text.as_str()).collect::<Vec<_>>(), ["hola 1", "hola 2", "hola 3", "hola 4", "hola 5"]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
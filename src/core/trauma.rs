@@ -10,13 +10,42 @@
 //
 // Based on Internal Family Systems (IFS) theory: Firefighters are
 // protective parts that activate under extreme stress.
+//
+// How much stress "extreme" is comes from the genome (stress_tolerance, see
+// Genome::phenotype): the thresholds and dwell times below are the defaults
+// of a tolerance of 0.5.
 
+use serde::Serialize;
 use std::collections::VecDeque;
 
 const WINDOW_SIZE: usize = 1800; // ~30 seconds at 60Hz
-const ACTIVATION_THRESHOLD: f32 = 0.7;
-const DEACTIVATION_THRESHOLD: f32 = 0.3;
-const SUSTAINED_DEACTIVATION_TICKS: usize = 600; // ~10 seconds of calm to recover
+
+/// When the Firefighter engages and lets go (derived from stress_tolerance).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TraumaThresholds {
+    /// Average cortisol that starts Escalating (and, below it minus 0.1, calms back).
+    pub escalation: f32,
+    /// Average cortisol that engages the Firefighter.
+    pub activation: f32,
+    /// Cortisol under which the Firefighter counts calm ticks.
+    pub deactivation: f32,
+    /// Ticks of history needed before it may engage (~15s at 60Hz).
+    pub dwell_ticks: usize,
+    /// Calm ticks in a row before Recovering (~10s at 60Hz).
+    pub recovery_ticks: usize,
+}
+
+impl Default for TraumaThresholds {
+    fn default() -> Self {
+        Self {
+            escalation: 0.5,
+            activation: 0.7,
+            deactivation: 0.3,
+            dwell_ticks: WINDOW_SIZE / 2,
+            recovery_ticks: 600,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TraumaState {
@@ -52,20 +81,28 @@ pub struct TraumaDetector {
     pub total_activations: u32,
     /// Current moving average of cortisol
     pub cortisol_avg: f32,
+    /// Genome-conditioned thresholds
+    pub thresholds: TraumaThresholds,
 }
 
 #[allow(dead_code)]
 impl TraumaDetector {
-    pub fn new() -> Self {
+    pub fn new(thresholds: TraumaThresholds) -> Self {
         Self {
             cortisol_history: VecDeque::with_capacity(WINDOW_SIZE),
             state: TraumaState::Stable,
             calm_ticks: 0,
             total_activations: 0,
             cortisol_avg: 0.0,
+            thresholds: TraumaThresholds { dwell_ticks: thresholds.dwell_ticks.min(WINDOW_SIZE), ..thresholds },
         }
     }
 
+    /// Genome reload: new thresholds, the state and its history kept.
+    pub fn set_thresholds(&mut self, thresholds: TraumaThresholds) {
+        self.thresholds = TraumaThresholds { dwell_ticks: thresholds.dwell_ticks.min(WINDOW_SIZE), ..thresholds };
+    }
+
     /// Feed a new cortisol reading. Call every tick.
     /// Returns true if state changed.
     pub fn tick(&mut self, cortisol: f32) -> bool {
@@ -80,28 +117,29 @@ impl TraumaDetector {
         self.cortisol_avg = sum / self.cortisol_history.len() as f32;
 
         let old_state = self.state;
+        let t = self.thresholds;
 
         match self.state {
             TraumaState::Stable => {
-                if self.cortisol_avg > 0.5 {
+                if self.cortisol_avg > t.escalation {
                     self.state = TraumaState::Escalating;
                 }
             },
             TraumaState::Escalating => {
-                if self.cortisol_avg > ACTIVATION_THRESHOLD 
-                   && self.cortisol_history.len() >= WINDOW_SIZE / 2 {
+                if self.cortisol_avg > t.activation
+                   && self.cortisol_history.len() >= t.dwell_ticks {
                     // Sustained high cortisol — activate Firefighter
                     self.state = TraumaState::FirefighterMode;
                     self.total_activations += 1;
                     self.calm_ticks = 0;
-                } else if self.cortisol_avg < 0.4 {
+                } else if self.cortisol_avg < t.escalation - 0.1 {
                     self.state = TraumaState::Stable;
                 }
             },
             TraumaState::FirefighterMode => {
-                if cortisol < DEACTIVATION_THRESHOLD {
+                if cortisol < t.deactivation {
                     self.calm_ticks += 1;
-                    if self.calm_ticks >= SUSTAINED_DEACTIVATION_TICKS {
+                    if self.calm_ticks >= t.recovery_ticks {
                         self.state = TraumaState::Recovering;
                         self.calm_ticks = 0;
                     }
@@ -114,7 +152,7 @@ impl TraumaDetector {
                     self.state = TraumaState::Stable;
                     // Clear history for fresh start
                     self.cortisol_history.clear();
-                } else if self.cortisol_avg > ACTIVATION_THRESHOLD {
+                } else if self.cortisol_avg > t.activation {
                     // Relapse!
                     self.state = TraumaState::FirefighterMode;
                     self.total_activations += 1;
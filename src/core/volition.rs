@@ -0,0 +1,303 @@
+// src/core/volition.rs
+// THE WISH: Words that ask the body for something
+//
+// The Planet could say "necesito descansar" a hundred times and nothing in the
+// body would notice. Now its resonant outputs are matched against a short,
+// data-driven list of self-directed intentions (intents.toml, format documented
+// there) and each match becomes a REQUEST the physics layer judges:
+//
+//   rest       -> sleep             honored only if adenosine is genuinely high
+//   attention  -> membrane boost    honored unless too tired to focus
+//   calm       -> cortisol nudge    honored only if there is stress to calm
+//
+// Language can influence but never override: requests are vetoed when the body
+// disagrees, while asleep, within an intent's cooldown, or once max_per_hour
+// have been honored. Every decision carries its reason (a thought) and is
+// counted per intent in telemetry. A missing or broken file falls back to the
+// built-in list compiled in.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use crate::core::config::VolitionConfig;
+
+const BUILTIN: &str = include_str!("../../intents.toml");
+const HOUR: Duration = Duration::from_secs(3600);
+
+/// What an intent may ask for.
+pub const REQUESTS: [&str; 3] = ["rest", "attention", "calm"];
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Intent {
+    pub name: String,
+    pub request: String,
+    pub patterns: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IntentFile {
+    #[serde(default)]
+    intent: Vec<Intent>,
+}
+
+/// What the body does about an honored request.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Effect {
+    Sleep,
+    Focus { boost: f32, secs: f32 },
+    Calm { cortisol: f32 },
+}
+
+/// The state the physics judges a request against.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Body {
+    pub adenosine: f32,
+    pub cortisol: f32,
+    pub asleep: bool,
+}
+
+/// One judged request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decision {
+    pub intent: String,
+    pub request: String,
+    pub pattern: String,
+    /// None = vetoed.
+    pub effect: Option<Effect>,
+    pub reason: String,
+}
+
+impl Decision {
+    pub fn line(&self) -> String {
+        match self.effect {
+            Some(_) => format!("🙏 WISH: '{}' -> {} honored ({})", self.pattern, self.request, self.reason),
+            None => format!("🙏 WISH: '{}' -> {} vetoed ({})", self.pattern, self.request, self.reason),
+        }
+    }
+}
+
+/// Per-intent counters for /telemetry.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IntentStats {
+    pub name: String,
+    pub honored: u64,
+    pub vetoed: u64,
+    pub last_reason: String,
+}
+
+pub struct Volition {
+    config: VolitionConfig,
+    intents: Vec<Intent>,
+    stats: Vec<IntentStats>,
+    last_honored: Vec<Option<Instant>>,
+    recent: VecDeque<Instant>, // Honored within the last hour
+}
+
+/// Lowercase without Spanish accents ("Oír" and "oir" match alike).
+pub fn fold(text: &str) -> String {
+    text.to_lowercase().chars().map(|c| match c {
+        'á' | 'à' | 'ä' => 'a',
+        'é' | 'è' | 'ë' => 'e',
+        'í' | 'ì' | 'ï' => 'i',
+        'ó' | 'ò' | 'ö' => 'o',
+        'ú' | 'ù' | 'ü' => 'u',
+        'ñ' => 'n',
+        c => c,
+    }).collect()
+}
+
+impl Volition {
+    /// The configured file if present and valid, the built-in list otherwise.
+    pub fn load(config: VolitionConfig) -> Self {
+        let intents = match fs::read_to_string(&config.path).map(|s| parse(&s)) {
+            Ok(Ok(intents)) => {
+                println!("🙏 WISH: {} intents loaded from {}.", intents.len(), config.path);
+                intents
+            }
+            Ok(Err(e)) => {
+                println!("⚠️ WISH: {} is invalid ({}). Using the built-in intents.", config.path, e);
+                builtin()
+            }
+            Err(_) => builtin(),
+        };
+        Self::with_intents(config, intents)
+    }
+
+    pub fn with_intents(config: VolitionConfig, intents: Vec<Intent>) -> Self {
+        let stats = intents.iter().map(|i| IntentStats { name: i.name.clone(), ..Default::default() }).collect();
+        let last_honored = vec![None; intents.len()];
+        Self { config, intents, stats, last_honored, recent: VecDeque::new() }
+    }
+
+    /// The first intent with a pattern in `text`, and that pattern.
+    pub fn matches(&self, text: &str) -> Option<(usize, &str)> {
+        let folded = fold(text);
+        self.intents.iter().enumerate().find_map(|(i, intent)| {
+            intent.patterns.iter().find(|p| folded.contains(&fold(p))).map(|p| (i, p.as_str()))
+        })
+    }
+
+    /// A Planet output: Some(decision) if it asked for something (resonance below
+    /// min_resonance, or no pattern, asks nothing).
+    pub fn consider(&mut self, text: &str, resonance: Option<f32>, body: Body, now: Instant) -> Option<Decision> {
+        if !self.config.enabled || resonance.unwrap_or(0.0) < self.config.min_resonance {
+            return None;
+        }
+        let (idx, pattern) = self.matches(text)?;
+        let pattern = pattern.to_string();
+        let request = self.intents[idx].request.clone();
+        while self.recent.front().is_some_and(|&at| now.duration_since(at) >= HOUR) {
+            self.recent.pop_front();
+        }
+
+        let verdict = self.judge(idx, &request, body, now);
+        let stats = &mut self.stats[idx];
+        let (effect, reason) = match verdict {
+            Ok((effect, reason)) => {
+                stats.honored += 1;
+                self.last_honored[idx] = Some(now);
+                self.recent.push_back(now);
+                (Some(effect), reason)
+            }
+            Err(reason) => {
+                stats.vetoed += 1;
+                (None, reason)
+            }
+        };
+        stats.last_reason = reason.clone();
+        Some(Decision { intent: self.intents[idx].name.clone(), request, pattern, effect, reason })
+    }
+
+    /// The physics veto: the effect and why, or why not.
+    fn judge(&self, idx: usize, request: &str, body: Body, now: Instant) -> Result<(Effect, String), String> {
+        let c = &self.config;
+        if body.asleep {
+            return Err("asleep".to_string());
+        }
+        if let Some(at) = self.last_honored[idx].filter(|&at| now.duration_since(at).as_secs_f32() < c.cooldown_secs) {
+            return Err(format!("cooldown, honored {:.0}s ago", now.duration_since(at).as_secs_f32()));
+        }
+        if self.recent.len() >= c.max_per_hour as usize {
+            return Err(format!("hourly cap of {}", c.max_per_hour));
+        }
+        match request {
+            "rest" if body.adenosine >= c.rest_min_adenosine => Ok((Effect::Sleep, format!("adenosine {:.2}", body.adenosine))),
+            "rest" => Err(format!("not tired: adenosine {:.2} < {:.2}", body.adenosine, c.rest_min_adenosine)),
+            "attention" if body.adenosine < c.attention_max_adenosine => Ok((
+                Effect::Focus { boost: c.attention_boost, secs: c.attention_secs },
+                format!("+{:.2} attention for {:.0}s", c.attention_boost, c.attention_secs),
+            )),
+            "attention" => Err(format!("too tired to focus: adenosine {:.2}", body.adenosine)),
+            "calm" if body.cortisol >= c.calm_min_cortisol => {
+                let cortisol = c.calm_nudge.min(body.cortisol);
+                Ok((Effect::Calm { cortisol }, format!("cortisol {:.2} -> {:.2}", body.cortisol, body.cortisol - cortisol)))
+            }
+            "calm" => Err(format!("nothing to calm: cortisol {:.2} < {:.2}", body.cortisol, c.calm_min_cortisol)),
+            other => Err(format!("unknown request '{}'", other)),
+        }
+    }
+
+    pub fn stats(&self) -> Vec<IntentStats> {
+        self.stats.clone()
+    }
+}
+
+fn builtin() -> Vec<Intent> {
+    parse(BUILTIN).expect("built-in intents.toml is valid")
+}
+
+/// Intents from the file, with unknown requests and empty pattern lists rejected.
+fn parse(source: &str) -> Result<Vec<Intent>> {
+    let file: IntentFile = toml::from_str(source)?;
+    for intent in &file.intent {
+        if !REQUESTS.contains(&intent.request.as_str()) {
+            bail!("intent '{}': unknown request '{}' (known: {})", intent.name, intent.request, REQUESTS.join(", "));
+        }
+        if intent.patterns.iter().all(|p| p.trim().is_empty()) {
+            bail!("intent '{}': no patterns", intent.name);
+        }
+    }
+    Ok(file.intent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOUD: Option<f32> = Some(0.9);
+
+    fn body(adenosine: f32, cortisol: f32) -> Body {
+        Body { adenosine, cortisol, asleep: false }
+    }
+
+    #[test]
+    fn scripted_outputs_are_honored_or_vetoed_by_the_body() {
+        let config = VolitionConfig::default();
+        let t0 = Instant::now();
+        let focus = Some(Effect::Focus { boost: config.attention_boost, secs: config.attention_secs });
+        let script: [(&str, Body, Option<Effect>, &str); 8] = [
+            ("Estoy lento... necesito descansar.", body(0.8, 0.2), Some(Effect::Sleep), "adenosine"),
+            ("Necesito descansar", body(0.2, 0.2), None, "not tired"),
+            ("Quiero OÍR lo que dices", body(0.3, 0.2), focus, "attention"),
+            ("quiero escuchar", body(0.95, 0.2), None, "too tired"),
+            ("necesito calma", body(0.3, 0.7), Some(Effect::Calm { cortisol: config.calm_nudge }), "cortisol"),
+            ("necesito calma", body(0.3, 0.1), None, "nothing to calm"),
+            ("necesito descansar", Body { adenosine: 0.9, cortisol: 0.2, asleep: true }, None, "asleep"),
+            ("quiero escuchar", body(0.3, 0.2), focus, "attention"),
+        ];
+        // One fresh Volition per scripted output
+        for (text, b, effect, reason) in script {
+            let mut volition = Volition::with_intents(config.clone(), builtin());
+            let decision = volition.consider(text, LOUD, b, t0).unwrap_or_else(|| panic!("'{}' at {:?} asked nothing", text, b));
+            assert_eq!(decision.effect, effect, "'{}' at {:?}", text, b);
+            assert!(decision.reason.contains(reason), "'{}' at {:?}: reason '{}'", text, b, decision.reason);
+        }
+    }
+
+    #[test]
+    fn weak_echoless_or_patternless_outputs_ask_nothing() {
+        let config = VolitionConfig::default();
+        let t0 = Instant::now();
+        let mut volition = Volition::with_intents(config.clone(), builtin());
+        let tired = body(0.9, 0.2);
+        assert!(volition.consider("necesito descansar", Some(config.min_resonance * 0.5), tired, t0).is_none());
+        assert!(volition.consider("necesito descansar", None, tired, t0).is_none());
+        assert!(volition.consider("el mar está tranquilo", LOUD, tired, t0).is_none());
+        let mut off = Volition::with_intents(VolitionConfig { enabled: false, ..config }, builtin());
+        assert!(off.consider("necesito descansar", LOUD, tired, t0).is_none(), "disabled: still asked");
+    }
+
+    #[test]
+    fn the_cooldown_and_the_hourly_cap_hold() {
+        let config = VolitionConfig { cooldown_secs: 300.0, max_per_hour: 3, ..VolitionConfig::default() };
+        let t0 = Instant::now();
+        let mut volition = Volition::with_intents(config, builtin());
+        let stressed = body(0.3, 0.8);
+        let honored: Vec<bool> = [
+            ("quiero escuchar", 0.0),
+            ("quiero escuchar", 60.0),  // Cooldown
+            ("necesito calma", 70.0),
+            ("quiero escuchar", 400.0),
+            ("necesito calma", 500.0),  // Cap: 3 honored this hour
+            ("quiero escuchar", 3700.0), // The first one aged out
+        ].iter().map(|&(text, s)| volition.consider(text, LOUD, stressed, t0 + Duration::from_secs_f32(s)).is_some_and(|d| d.effect.is_some())).collect();
+        assert_eq!(honored, [true, false, true, true, false, true]);
+
+        // Counters and reasons add up
+        let stats = volition.stats();
+        let listen = stats.iter().find(|s| s.name == "escuchar").unwrap();
+        assert_eq!((listen.honored, listen.vetoed), (3, 1));
+        let calm = stats.iter().find(|s| s.name == "calmarme").unwrap();
+        assert_eq!((calm.honored, calm.vetoed), (1, 1));
+        assert!(calm.last_reason.contains("hourly cap"), "{}", calm.last_reason);
+    }
+
+    #[test]
+    fn the_builtin_file_parses_and_an_unknown_request_is_refused() {
+        assert!(parse(BUILTIN).unwrap().len() >= 3);
+        assert!(parse("[[intent]]\nname = \"x\"\nrequest = \"fly\"\npatterns = [\"vuela\"]").is_err());
+    }
+}
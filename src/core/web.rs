@@ -0,0 +1,895 @@
+// src/core/web.rs
+// THE FRONT DOOR: HTTP API and WebSocket sessions for the dashboard
+//
+// One thread accepts, one thread per connection. A WebSocket upgrade becomes a
+// session (websocket::session) whose client joins the broadcaster's list; any
+// other request is answered from the shared telemetry and the logs, or by
+// asking the loop through a reply channel (503 when it doesn't answer in time).
+// External text pays the stimulus bucket; the dashboard's own controls (poke,
+// sleep, DND, feed, genome reload) ride the control channel.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+
+use crate::actuators::registry::ActuatorStats;
+use crate::core::admin::{self, AdminCommand, AdminRequest};
+use crate::core::bus::ControlEvent;
+use crate::core::complexity::ComplexityMetrics;
+use crate::core::components::{self, ComponentStatus, Components};
+use crate::core::config::{ConsciousnessConfig, HealthConfig, VoiceHint};
+use crate::core::daemon_state::SleepStage;
+use crate::core::decisions::{self, DecisionLog, GateDecision, MembraneDecision};
+use crate::core::feeding::{Feeding, PantryState};
+use crate::core::gate::GateStats;
+use crate::core::health::{self, Health};
+use crate::core::hippocampus::MemoryStats;
+use crate::core::homeostat::HomeostatState;
+use crate::core::ipc::AlephPacket;
+use crate::core::latency::LatencyStats;
+use crate::core::metabolism::EnergyBudget;
+use crate::core::checkin::CheckInState;
+use crate::core::pacing::FrameStats;
+use crate::core::people::People;
+use crate::core::pressure::PressureState;
+use crate::core::raster::Raster;
+use crate::core::repertoire::DriveStats;
+use crate::core::reservoir::{FractalReservoir, GraphFormat, GrowthStats, NeuronLookup, NeuronReport, Pathway, RegionStats};
+use crate::core::selftest::SelfTest;
+use crate::core::sieve::SieveStats;
+use crate::core::spectrogram::Spectrogram;
+use crate::core::standby::StandbyStats;
+use crate::core::stats::Diary;
+use crate::core::stimulus::{self, Admission, StimulusLimiter};
+use crate::core::timeline::{unix_millis, Timeline};
+use crate::core::volition::IntentStats;
+use crate::core::websocket::{self, ws_frame, WsClient, WsMessage, STREAM_SPECTROGRAM, STREAM_SPECTRUM, OP_TEXT};
+use crate::cortex::backend::ActivationLayout;
+use crate::cortex::context::{self, Segment, TokenSplit};
+use crate::senses::ears::AudioSpectrum;
+use crate::senses::prosody::Prosody;
+
+/// What the loop publishes every frame: GET /telemetry serves it whole, the broadcaster trims it.
+#[derive(serde::Serialize, Clone, Default)]
+pub struct WebTelemetry {
+    pub adenosine: f32,
+    pub cortisol: f32,
+    pub dopamine: f32,
+    pub oxytocin: f32,
+    pub serotonin: f32,
+    pub loop_frequency: f32,
+    // Heart-rate variability (Hz², variance of loop_frequency over ~30s)
+    pub hrv: f32,
+    pub audio_spectrum: AudioSpectrum,
+    pub reservoir_activity: Vec<f32>,
+    pub current_state: String,
+    pub thoughts: Vec<String>,
+    pub trauma_state: String,
+    pub hebbian_events: u32,
+    pub reservoir_size: usize,
+    pub entropy: f32,
+    pub llm_activity: Vec<f32>,
+    pub top_activations: Vec<(String, f32)>, 
+    
+    // System Vitals
+    pub system_ram_gb: f32,
+    pub system_cpu_load: f32,
+    
+    // Neural Visualization
+    pub activations: Vec<f32>,
+    pub activation_layout: ActivationLayout, // Header of `activations` (hidden layers or pooled logits)
+    pub region_map: Vec<u8>,            // Only sent to clients behind topology_version
+    pub neuron_positions: Vec<[f32; 3]>, // (idem)
+    pub neuron_ids: Vec<u64>,            // (idem) Stable ID of each index, what /neuron/{id} and `lesioned` speak
+    pub topology_version: u64,
+    
+    // Genome Traits
+    pub curiosity: f32,
+    
+    // New Senses
+    pub visual_cortex: Vec<f32>, // 64x64 Grid
+    pub stress_tolerance: f32,
+    pub generation: u32,
+
+    // Membrane (Stimulus Rate Limiting)
+    pub stimuli_dropped: u64,
+
+    // Planet Queue (Antechamber)
+    pub cortex_queue_depth: usize,
+    pub cortex_queue_dropped: u64,
+    pub cortex_tier: String, // Which model served the last output ("fast"/"deep")
+
+    // WebSocket backpressure
+    pub ws_frames_dropped: u64,
+
+    // Metabolism (Energy Budget panel)
+    pub glucose: f32,
+    pub energy_budget: EnergyBudget,
+
+    // Prosody of the last utterance
+    pub prosody: Prosody,
+
+    // Do-Not-Disturb (no transcription)
+    pub privacy_mode: bool,
+
+    // Whisper worker
+    pub audio_queue_depth: usize,
+    pub audio_latency_ms: u64,
+    pub audio_dropped: u64,
+    // Browser audio refused before the FFT (chunks) / silenced (samples)
+    pub audio_rejected: u64,
+    pub audio_absurd_samples: u64,
+    // Transcripts the sanity scorer blocked (hallucinations)
+    pub audio_hallucinations_blocked: u64,
+    // Σ|Δw| since boot (cheap live version of `aleph diff-brain`)
+    pub plasticity_accumulated: f64,
+    // Lesion experiment: stable IDs of the silenced neurons (the visualizer greys them out)
+    pub lesioned: Vec<u64>,
+    // Feeding: attention sensitivity, glucose still digesting, scheduled sleep, feeds per kind
+    pub feeding: PantryState,
+    // Sleep-talk pathway of the gate: attempts, spoken, and silences per reason
+    pub sleep_talk: GateStats,
+    // Memory pressure: current notch, RAM and how often each notch fired
+    pub pressure: PressureState,
+    // Spontaneous-speech drives: times drawn, spoken and answered (core/repertoire.rs)
+    pub repertoire: Vec<DriveStats>,
+    // Checking in after silence: engagement, open episode, check-ins and let-be's
+    pub checkin: CheckInState,
+    // Sound -> reservoir latency: rolling p50/p95/max, median per hop, budget and shedding
+    pub latency: LatencyStats,
+    // Self-modulation requests: honored, vetoed and the last reason, per intent
+    pub volition: Vec<IntentStats>,
+    // Structural operations on a standby copy: running, queued, swaps and the last one's cost
+    pub standby: StandbyStats,
+    // 1.0 = all input channels open, ->0 = stress tunnel vision
+    pub attention_width: f32,
+    // Entropy controller: setpoint, error integral, last adjustment and the knobs it set
+    pub homeostat: HomeostatState,
+    // The one-tick activation histogram (what `entropy` was before [complexity] drive_couplings)
+    pub entropy_legacy: f32,
+    // Temporal entropy, participation ratio, Lempel-Ziv and their composite (core/complexity.rs)
+    pub complexity: ComplexityMetrics,
+    // Voice labels and color hints (from the routing table, fixed for the session)
+    pub voices: Vec<VoiceHint>,
+    // Sleep stage and memories replayed into the reservoir during Deep sleep
+    pub sleep_stage: SleepStage,
+    pub memory_replays: u64,
+    // Cross-modal binding: events since boot, and per-region multimodality (refreshed ~10s)
+    pub binding_events: u64,
+    pub region_stats: Vec<RegionStats>,
+    // Neurogenesis by demand: per receptive field its neurons, demand and odds, and the censuses after each growth (~10s)
+    pub growth: GrowthStats,
+    // Spotlight: each input pathway's share of the recent state change (sums to 1.0)
+    pub attention_breakdown: std::collections::HashMap<Pathway, f32>,
+    // Saccades: the fixated pathway (None = blending) and shifts since boot
+    pub attention_focus: Option<Pathway>,
+    pub attention_shifts: u64,
+    // Hippocampus: memory counts and the novelty model (refreshed ~5s)
+    pub memory: MemoryStats,
+    // Self-memories stored / rejected by the sieve, per reason
+    pub memory_sieve: SieveStats,
+    // Loop time (p50/p95/max, per phase) and achieved vs target Hz
+    pub frame_time: FrameStats,
+    // Membrane (rolling last minute) and attention score of its last filtering
+    pub satellite_passed: u64,
+    pub satellite_hardened: u64,
+    pub satellite_ontological_errors: u64,
+    pub satellite_attention: f32,
+    // Expression gate since boot: attempts, spoken, and silences per reason
+    pub gate_attempts: u64,
+    pub gate_spoken: u64,
+    pub gate_suppressed_by_reason: std::collections::BTreeMap<String, u64>,
+    // Last Think prompt: tokens per part, bio fields cut to budget, bio skipped as unchanged
+    pub prompt_split: TokenSplit,
+    pub bio_dropped: usize,
+    pub bio_deduped: bool,
+    // Capability panel: what every component came up as (core::components)
+    pub components: std::collections::HashMap<String, ComponentStatus>,
+    // Actions through the capability whitelist since boot (actuators::registry)
+    pub actuators: ActuatorStats,
+}
+
+impl WebTelemetry {
+    /// Take what the TUI heartbeat carries (chemistry, stream, body, membrane and gate), so
+    /// both views come from one `DaemonState::build_telemetry`. The topology (region map,
+    /// positions) and the cortex visuals stay as the loop left them: they change on their own clock.
+    pub fn absorb(&mut self, packet: &AlephPacket) {
+        let AlephPacket::Telemetry {
+            adenosine, cortisol, dopamine, oxytocin, audio_spectrum, reservoir_activity,
+            short_term_memory, current_state, entropy, loop_frequency, cpu_usage, reservoir_size, privacy_mode,
+            satellite_passed, satellite_hardened, satellite_ontological_errors, satellite_attention,
+            gate_attempts, gate_spoken, gate_suppressed_by_reason, ..
+        } = packet else { return };
+        self.adenosine = *adenosine;
+        self.cortisol = *cortisol;
+        self.dopamine = *dopamine;
+        self.oxytocin = *oxytocin;
+        self.audio_spectrum = audio_spectrum.clone();
+        self.reservoir_activity = reservoir_activity.clone();
+        self.thoughts = short_term_memory.clone();
+        self.current_state = current_state.clone();
+        self.entropy = *entropy;
+        self.loop_frequency = *loop_frequency;
+        self.system_cpu_load = *cpu_usage;
+        self.reservoir_size = *reservoir_size;
+        self.privacy_mode = *privacy_mode;
+        self.satellite_passed = *satellite_passed;
+        self.satellite_hardened = *satellite_hardened;
+        self.satellite_ontological_errors = *satellite_ontological_errors;
+        self.satellite_attention = *satellite_attention;
+        self.gate_attempts = *gate_attempts;
+        self.gate_spoken = *gate_spoken;
+        self.gate_suppressed_by_reason = gate_suppressed_by_reason.clone();
+    }
+}
+
+const MAX_HTTP_HEAD: usize = 16 * 1024;
+const MAX_HTTP_BODY: usize = 1024 * 1024;
+
+// WebSocket backpressure: frames buffered per client (~2.5s at 12Hz)
+pub const WS_CLIENT_QUEUE: usize = 32;
+// Largest reassembled client message (browser audio chunks)
+const WS_MAX_MESSAGE: usize = 256 * 1024;
+
+/// Does the request carry `Authorization: Bearer <token>`? (An empty token admits no one.)
+fn bearer_authorized(head: &str, token: &str) -> bool {
+    if token.is_empty() {
+        return false;
+    }
+    head.lines().any(|l| {
+        l.split_once(':').is_some_and(|(k, v)| {
+            k.trim().eq_ignore_ascii_case("authorization")
+                && v.trim().strip_prefix("Bearer ").is_some_and(|presented| {
+                    // Whole-length comparison: no early exit on the first wrong byte
+                    presented.len() == token.len()
+                        && presented.bytes().zip(token.bytes()).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0
+                })
+        })
+    })
+}
+
+/// Read one HTTP request: headers until the blank line, then exactly Content-Length body bytes
+/// (across as many reads as the client needs). Returns (head, body).
+fn read_http_request(stream: &mut std::net::TcpStream) -> std::io::Result<(String, Vec<u8>)> {
+    use std::io::{Error, ErrorKind};
+    let mut data: Vec<u8> = Vec::with_capacity(8192);
+    let mut chunk = [0u8; 8192];
+
+    // 1. Head
+    let head_end = loop {
+        if let Some(pos) = data.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos;
+        }
+        if data.len() > MAX_HTTP_HEAD {
+            return Err(Error::new(ErrorKind::InvalidData, "headers too large"));
+        }
+        let n = stream.read(&mut chunk)?;
+        if n == 0 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "connection closed before end of headers"));
+        }
+        data.extend_from_slice(&chunk[..n]);
+    };
+    let head = String::from_utf8_lossy(&data[..head_end]).to_string();
+
+    // 2. Body (Content-Length)
+    let content_length = head.lines()
+        .find_map(|l| {
+            let (k, v) = l.split_once(':')?;
+            if k.trim().eq_ignore_ascii_case("content-length") { v.trim().parse::<usize>().ok() } else { None }
+        })
+        .unwrap_or(0);
+    if content_length > MAX_HTTP_BODY {
+        return Err(Error::new(ErrorKind::InvalidData, "body too large"));
+    }
+
+    let mut body = data[head_end + 4..].to_vec();
+    while body.len() < content_length {
+        let n = stream.read(&mut chunk)?;
+        if n == 0 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "body shorter than Content-Length"));
+        }
+        body.extend_from_slice(&chunk[..n]);
+    }
+    body.truncate(content_length);
+    Ok((head, body))
+}
+
+/// Everything a request may read or ask for. Cloned into each connection's thread.
+#[derive(Clone)]
+pub struct WebServer {
+    pub state: Arc<Mutex<WebTelemetry>>,
+    pub ws_clients: Arc<Mutex<Vec<WsClient>>>,
+    pub ws_audio_tx: Arc<Mutex<Sender<Vec<f32>>>>,
+    pub tx_stimulus: Sender<String>,
+    pub tx_control: Sender<ControlEvent>,
+    pub limiter: Arc<Mutex<StimulusLimiter>>,
+    pub timeline: Timeline,
+    pub diary: Diary,
+    pub gate_log: DecisionLog<GateDecision>,
+    pub membrane_log: DecisionLog<MembraneDecision>,
+    pub soundcheck: SelfTest,
+    pub people: People,
+    pub raster: Raster,
+    pub spectrogram: Spectrogram,
+    pub health: Health,
+    pub health_config: HealthConfig,
+    pub components: Components,
+    pub consciousness_config: ConsciousnessConfig,
+    pub admin_secret: String,
+    // Questions only the loop can answer (it replies on the enclosed channel)
+    pub tx_connectome: Sender<Sender<FractalReservoir>>,
+    pub tx_neuron: Sender<(u64, Sender<Result<NeuronReport, NeuronLookup>>)>,
+    pub tx_memory_stats: Sender<Sender<MemoryStats>>,
+    pub tx_consciousness: Sender<Sender<Vec<Segment>>>,
+    pub tx_admin: Sender<AdminRequest>,
+}
+
+impl WebServer {
+    /// Listen on `bind` in a thread of its own. Port taken = no dashboard, ALEPH itself keeps running.
+    pub fn spawn(self, bind: String) {
+        thread::spawn(move || {
+            let listener = match TcpListener::bind(&bind) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    println!("⚠️ Web address {} unavailable: {}. Running without dashboard.", bind, e);
+                    self.components.report(components::WEB, ComponentStatus::Offline(e.to_string()));
+                    return;
+                }
+            };
+            listener.set_nonblocking(false).ok();
+            self.components.report(components::WEB, ComponentStatus::Ready);
+            println!("🌍 Web Dashboard Active: http://{}", bind);
+
+            for stream in listener.incoming().flatten() {
+                let server = self.clone();
+                thread::spawn(move || server.handle(stream));
+            }
+        });
+    }
+
+    /// One connection: a WebSocket session or a single request/response.
+    pub fn handle(&self, mut stream: TcpStream) {
+        let client_id = stream.peer_addr()
+            .map(|a| a.ip().to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+        match read_http_request(&mut stream) {
+            Ok((request, _)) if request.to_lowercase().contains("upgrade: websocket") => self.upgrade(stream, &request, &client_id),
+            Ok((request, body)) => self.route(&mut stream, &request, &body, &client_id),
+            Err(e) => {
+                let body = serde_json::json!({ "error": e.to_string() }).to_string();
+                let response = format!("HTTP/1.1 400 Bad Request\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        }
+    }
+
+    /// Complete the handshake, join the broadcast list (Hello first: the clock the spectrum
+    /// stamps are on), then read the client until it hangs up.
+    fn upgrade(&self, mut stream: TcpStream, request: &str, client_id: &str) {
+        println!("🔗 Incoming WebSocket Upgrade Request...");
+        let Some(key_line) = request.lines().find(|l| l.to_lowercase().starts_with("sec-websocket-key:")) else {
+            return;
+        };
+        let key = key_line.split(':').nth(1).unwrap_or("").trim();
+        let accept = websocket::accept_key(key);
+        println!("🔑 WS Handshake: Key='{}' -> Accept='{}'", key, accept);
+
+        let response = format!(
+            "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+            accept
+        );
+        if let Err(e) = stream.write_all(response.as_bytes()).and_then(|_| stream.flush()) {
+            println!("❌ WS Write Error: {}", e);
+            return;
+        }
+
+        // Own queue + writer thread
+        let outbox = match stream.try_clone().and_then(|clone| WsClient::spawn(clone, WS_CLIENT_QUEUE)) {
+            Ok(mut client) => {
+                let hello = serde_json::to_string(&AlephPacket::hello()).unwrap_or_default();
+                client.offer(Arc::new(ws_frame(OP_TEXT, hello.as_bytes())));
+                let outbox = client.outbox();
+                let mut list = self.ws_clients.lock().unwrap();
+                list.push(client);
+                println!("✅ Added client to broadcast list. Total clients: {}", list.len());
+                outbox
+            },
+            Err(e) => {
+                println!("❌ Failed to clone stream for broadcast: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = stream.set_nonblocking(false) {
+            println!("⚠️ WS NonBlocking Error: {}", e);
+        }
+
+        let ended = websocket::session(&mut stream, &outbox, WS_MAX_MESSAGE, |message| match message {
+            WsMessage::Text(text) => {
+                let Ok(cmd) = serde_json::from_str::<serde_json::Value>(&text) else { return };
+                if let Some(stimulus) = cmd.get("stimulus").and_then(|v| v.as_str()) {
+                    let admission = self.limiter.lock().unwrap().admit(client_id, stimulus);
+                    match admission {
+                        Admission::Accepted(text) => { let _ = self.tx_stimulus.send(text); },
+                        Admission::RateLimited => {
+                            outbox.offer(Arc::new(ws_frame(OP_TEXT, br#"{"error":"rate_limited"}"#)));
+                        },
+                        Admission::Empty => {}
+                    }
+                } else if let Some(action) = cmd.get("action").and_then(|v| v.as_str()) {
+                    match action {
+                        "poke" => { let _ = self.tx_control.send(ControlEvent::Poke); },
+                        "sleep" => { let _ = self.tx_control.send(ControlEvent::Sleep); },
+                        "dnd_on" => { let _ = self.tx_control.send(ControlEvent::Privacy(true)); },
+                        "dnd_off" => { let _ = self.tx_control.send(ControlEvent::Privacy(false)); },
+                        "spectrogram_on" => outbox.subscribe(STREAM_SPECTROGRAM, true),
+                        "spectrogram_off" => outbox.subscribe(STREAM_SPECTROGRAM, false),
+                        "spectrum_on" => outbox.subscribe(STREAM_SPECTRUM, true),
+                        "spectrum_off" => outbox.subscribe(STREAM_SPECTRUM, false),
+                        _ => {}
+                    }
+                }
+            },
+            // Browser audio: Float32Array as raw little-endian bytes
+            WsMessage::Binary(payload) if payload.len() >= 4 && payload.len() % 4 == 0 => {
+                let samples: Vec<f32> = payload.chunks_exact(4)
+                    .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                    .collect();
+                if let Ok(tx) = self.ws_audio_tx.lock() {
+                    let _ = tx.send(samples);
+                }
+            },
+            _ => {}
+        });
+        match ended {
+            Ok(()) => println!("👋 WS Disconnected (Client Closed)"),
+            Err(e) => match e.close_code() {
+                Some(code) => println!("⚠️ WS {} -> closing ({})", e, code),
+                None => println!("👋 WS Disconnected ({})", e),
+            },
+        }
+
+        // Let the writer flush our Close, then hang up; the broadcaster
+        // drops the client once its queue reports Gone.
+        drop(outbox);
+        thread::sleep(Duration::from_millis(100));
+        let _ = stream.shutdown(std::net::Shutdown::Both);
+    }
+
+    /// Plain HTTP: one request, one response.
+    fn route(&self, stream: &mut TcpStream, request: &str, body: &[u8], client_id: &str) {
+        let WebServer {
+            state, tx_stimulus, tx_control, limiter, timeline, diary, gate_log, membrane_log, soundcheck, people,
+            raster, spectrogram, health, health_config, components, consciousness_config, admin_secret,
+            tx_connectome, tx_neuron, tx_memory_stats, tx_consciousness, tx_admin, ..
+        } = self;
+        let path = request.lines().next().unwrap_or("").split_whitespace().nth(1).unwrap_or("/");
+
+        // CORS Preflight
+        if request.starts_with("OPTIONS") {
+            let headers = "HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\nAccess-Control-Allow-Methods: POST, GET, DELETE, OPTIONS\r\nAccess-Control-Allow-Headers: Content-Type, Authorization\r\n\r\n";
+            let _ = stream.write(headers.as_bytes());
+        }
+        // SERVE ASSETS (Vite Build)
+        else if path.starts_with("/assets/") {
+            // Sanitize path (basic)
+            let safe_path = path.replace("..", "");
+            let file_path = format!("web{}", safe_path);
+
+            if let Ok(content) = fs::read(&file_path) {
+                let content_type = if file_path.ends_with(".css") { "text/css" }
+                                  else if file_path.ends_with(".js") { "application/javascript" }
+                                  else if file_path.ends_with(".svg") { "image/svg+xml" }
+                                  else { "application/octet-stream" };
+
+                let headers = format!("HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n", content_type, content.len());
+                let _ = stream.write(headers.as_bytes());
+                let _ = stream.write(&content);
+            } else {
+                let _ = stream.write("HTTP/1.1 404 Not Found\r\n\r\n".as_bytes());
+            }
+        }
+        // SERVE DASHBOARD (DISABLED - Legacy)
+        else if path == "/" || path == "/index.html" {
+            let response = "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\nALEPH Nervous System Active. Use React Client.\r\n";
+            let _ = stream.write(response.as_bytes());
+        }
+        // API ENDPOINTS
+        else if path == "/telemetry" {
+            let headers = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\n\r\n";
+            let json = {
+                let state = state.lock().unwrap();
+                serde_json::to_string(&*state).unwrap_or("{}".to_string())
+            };
+            let response = format!("{}{}", headers, json);
+            let _ = stream.write(response.as_bytes());
+        }
+        else if path == "/timeline" || path.starts_with("/timeline?") {
+            // GET /timeline?since=<unix_ms>&voice=Vocal&limit=500
+            let query = path.split_once('?').map(|(_, q)| q).unwrap_or("");
+            let mut since = 0u64;
+            let mut voice: Option<&str> = None;
+            let mut limit = 500usize;
+            for pair in query.split('&') {
+                match pair.split_once('=') {
+                    Some(("since", v)) => since = v.parse().unwrap_or(0),
+                    Some(("voice", v)) if !v.is_empty() => voice = Some(v),
+                    Some(("limit", v)) => limit = v.parse().unwrap_or(limit).min(5000),
+                    _ => {}
+                }
+            }
+            let entries = timeline.query(since, voice, limit);
+            let reply = serde_json::json!({
+                "now": unix_millis(std::time::SystemTime::now()),
+                "entries": entries,
+            }).to_string();
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        else if path == "/stats/daily" {
+            // The last 24 complete hours: summary, sentence and the raw buckets
+            let reply = serde_json::to_string(&diary.daily()).unwrap_or_else(|_| "{}".to_string());
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        else if path.starts_with("/gate/history") || path.starts_with("/membrane/history") {
+            // GET /gate/history?limit=500 (or /membrane/history): the latest decisions, oldest first
+            let limit = decisions::limit_of(path.split_once('?').map_or("", |(_, q)| q));
+            let reply = if path.starts_with("/gate") {
+                serde_json::to_string(&gate_log.recent(limit))
+            } else {
+                serde_json::to_string(&membrane_log.recent(limit))
+            }.unwrap_or_else(|_| "[]".to_string());
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        else if path == "/selftest" {
+            // Boot warm-up: each stage's time, its ceiling, and what is slow, failed or still pending
+            let reply = serde_json::to_string(&soundcheck.report()).unwrap_or_else(|_| "{}".to_string());
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        else if path == "/people" {
+            let reply = serde_json::to_string(&people.list()).unwrap_or_else(|_| "[]".to_string());
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        else if path == "/raster" || path.starts_with("/raster?") {
+            // GET /raster[?since=<seq>] -> binary matrix (format in core/raster.rs)
+            let since = path.split_once('?')
+                .and_then(|(_, q)| q.split('&').find_map(|p| p.strip_prefix("since=")))
+                .and_then(|v| v.parse().ok());
+            let matrix = raster.encode(since);
+            let headers = format!("HTTP/1.1 200 OK\r\nContent-Type: application/octet-stream\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n", matrix.len());
+            let _ = stream.write_all(headers.as_bytes());
+            let _ = stream.write_all(&matrix);
+        }
+        else if path == "/spectrogram" || path.starts_with("/spectrogram?") {
+            // GET /spectrogram[?since=<seq>] -> binary waterfall (format in core/spectrogram.rs)
+            let since = path.split_once('?')
+                .and_then(|(_, q)| q.split('&').find_map(|p| p.strip_prefix("since=")))
+                .and_then(|v| v.parse().ok());
+            let rows = spectrogram.encode(since);
+            let headers = format!("HTTP/1.1 200 OK\r\nContent-Type: application/octet-stream\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n", rows.len());
+            let _ = stream.write_all(headers.as_bytes());
+            let _ = stream.write_all(&rows);
+        }
+        else if path == "/connectome" || path.starts_with("/connectome?") {
+            // GET /connectome?threshold=0.1[&format=graphml] -> node-link JSON (networkx) or GraphML (Gephi)
+            let query = path.split_once('?').map(|(_, q)| q).unwrap_or("");
+            let mut threshold = 0.1f32;
+            let mut format = GraphFormat::Json;
+            for pair in query.split('&') {
+                match pair.split_once('=') {
+                    Some(("threshold", v)) => threshold = v.parse().unwrap_or(threshold),
+                    Some(("format", "graphml")) => format = GraphFormat::GraphMl,
+                    _ => {}
+                }
+            }
+            let (reply_tx, reply_rx) = mpsc::channel();
+            let brain = tx_connectome.send(reply_tx).ok()
+                .and_then(|_| reply_rx.recv_timeout(Duration::from_secs(5)).ok());
+            match brain {
+                Some(brain) => {
+                    let content_type = if format == GraphFormat::GraphMl { "application/xml" } else { "application/json" };
+                    // No Content-Length: the document is streamed and ends when the connection closes
+                    let headers = format!("HTTP/1.1 200 OK\r\nContent-Type: {}\r\nAccess-Control-Allow-Origin: *\r\nConnection: close\r\n\r\n", content_type);
+                    let mut out = std::io::BufWriter::new(&mut *stream);
+                    let _ = out.write_all(headers.as_bytes())
+                        .and_then(|_| brain.write_graph(&mut out, threshold, format).map(|_| ()))
+                        .and_then(|_| out.flush());
+                },
+                None => {
+                    let _ = stream.write_all(b"HTTP/1.1 503 Service Unavailable\r\nAccess-Control-Allow-Origin: *\r\n\r\n");
+                },
+            }
+        }
+        else if let Some(id) = path.strip_prefix("/neuron/") {
+            // GET /neuron/{id} -> one neuron by stable ID: place, region, synapses, age, recent activity
+            let id = id.parse::<u64>().ok();
+            let report = id.map(|id| {
+                let (reply_tx, reply_rx) = mpsc::channel();
+                tx_neuron.send((id, reply_tx)).ok()
+                    .and_then(|_| reply_rx.recv_timeout(Duration::from_secs(5)).ok())
+            });
+            match report {
+                Some(Some(Ok(mut report))) => {
+                    report.activity = raster.row_history(report.index, 100);
+                    let reply = serde_json::to_string(&report).unwrap_or_default();
+                    let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+                    let _ = stream.write_all(response.as_bytes());
+                },
+                Some(None) => {
+                    let _ = stream.write_all(b"HTTP/1.1 503 Service Unavailable\r\nAccess-Control-Allow-Origin: *\r\n\r\n");
+                },
+                // It was a neuron: 410 says so, so clients drop the reference
+                Some(Some(Err(NeuronLookup::Deceased))) => {
+                    let reply = serde_json::json!({ "id": id, "status": "deceased" }).to_string();
+                    let response = format!("HTTP/1.1 410 Gone\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+                    let _ = stream.write_all(response.as_bytes());
+                },
+                // Not a number, or not a neuron (yet)
+                _ => {
+                    let _ = stream.write_all(b"HTTP/1.1 404 Not Found\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: 0\r\n\r\n");
+                },
+            }
+        }
+        else if path == "/healthz" {
+            // Liveness/readiness: answered from the vitals, never by asking the loop
+            let (code, body) = health::evaluate(&health.vitals(), &components.snapshot(), health_config, Instant::now());
+            let reply = body.to_string();
+            let reason = if code == 200 { "OK" } else { "Service Unavailable" };
+            let response = format!("HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", code, reason, reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        else if path == "/memory/stats" {
+            let (reply_tx, reply_rx) = mpsc::channel();
+            let stats = tx_memory_stats.send(reply_tx).ok()
+                .and_then(|_| reply_rx.recv_timeout(Duration::from_secs(5)).ok());
+            match stats {
+                Some(stats) => {
+                    let reply = serde_json::to_string(&stats).unwrap_or_else(|_| "{}".to_string());
+                    let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+                    let _ = stream.write_all(response.as_bytes());
+                },
+                None => {
+                    let _ = stream.write_all(b"HTTP/1.1 503 Service Unavailable\r\nAccess-Control-Allow-Origin: *\r\n\r\n");
+                },
+            }
+        }
+        else if path == "/consciousness" {
+            // The Planet's history, unredacted: only with the configured bearer token
+            if consciousness_config.token.is_empty() {
+                let _ = stream.write_all(b"HTTP/1.1 403 Forbidden\r\nAccess-Control-Allow-Origin: *\r\n\r\n");
+            } else if !bearer_authorized(request, &consciousness_config.token) {
+                let _ = stream.write_all(b"HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Bearer\r\nAccess-Control-Allow-Origin: *\r\n\r\n");
+            } else {
+                let (reply_tx, reply_rx) = mpsc::channel();
+                // A generation in flight is finished first: give it time
+                let history = tx_consciousness.send(reply_tx).ok()
+                    .and_then(|_| reply_rx.recv_timeout(Duration::from_secs(15)).ok());
+                match history {
+                    Some(segments) => {
+                        let reply = context::render_dump(&segments, consciousness_config.max_dump_bytes, &Local::now().to_rfc3339());
+                        let response = format!("HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", reply.len(), reply);
+                        let _ = stream.write_all(response.as_bytes());
+                    },
+                    None => {
+                        let _ = stream.write_all(b"HTTP/1.1 503 Service Unavailable\r\nAccess-Control-Allow-Origin: *\r\n\r\n");
+                    },
+                }
+            }
+        }
+        else if path == "/stimulus" && request.starts_with("POST") {
+            let (status, reply) = match stimulus::parse_body(body) {
+                Ok(raw) => match limiter.lock().unwrap().admit(client_id, &raw) {
+                    Admission::Accepted(text) => {
+                        let _ = tx_stimulus.send(text);
+                        ("200 OK", r#"{"ok":true}"#.to_string())
+                    },
+                    Admission::RateLimited => ("429 Too Many Requests", r#"{"error":"rate_limited"}"#.to_string()),
+                    Admission::Empty => ("200 OK", r#"{"ok":true}"#.to_string()),
+                },
+                Err(e) => ("400 Bad Request", serde_json::json!({ "error": format!("invalid stimulus: {}", e) }).to_string()),
+            };
+            let response = format!("HTTP/1.1 {}\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", status, reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        else if path == "/lesion" && (request.starts_with("POST") || request.starts_with("DELETE")) {
+            // LESION EXPERIMENTS: POST {"region": "Auditory"} silences it, DELETE lifts it
+            let command = if !bearer_authorized(request, admin_secret) {
+                Err(("401 Unauthorized", "admin secret missing or wrong".to_string()))
+            } else if request.starts_with("DELETE") {
+                Ok(AdminCommand::Lesion(None))
+            } else {
+                serde_json::from_slice::<serde_json::Value>(body).ok()
+                    .and_then(|v| v.get("region").and_then(|r| r.as_str()).map(str::to_string))
+                    .ok_or_else(|| r#"expected {"region": "<name>"}"#.to_string())
+                    .and_then(|region| admin::parse("lesion", &[region]))
+                    .map_err(|e| ("400 Bad Request", e))
+            };
+            let (status, reply) = match command {
+                Ok(command) => {
+                    let (reply_tx, reply_rx) = mpsc::channel();
+                    match tx_admin.send(AdminRequest { command, reply_tx }).ok().and_then(|_| reply_rx.recv_timeout(admin::REPLY_TIMEOUT).ok()) {
+                        Some(Ok(reply)) => ("200 OK", reply),
+                        Some(Err(e)) => ("400 Bad Request", serde_json::json!({ "error": e })),
+                        None => ("503 Service Unavailable", serde_json::json!({ "error": "no answer from the loop" })),
+                    }
+                },
+                Err((status, e)) => (status, serde_json::json!({ "error": e })),
+            };
+            let reply = reply.to_string();
+            let response = format!("HTTP/1.1 {}\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", status, reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        // COMMAND SHORTCUTS
+        else if path == "/sleep" && request.starts_with("POST") {
+             let _ = tx_control.send(ControlEvent::Sleep);
+             let _ = stream.write("HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\n\r\n".as_bytes());
+        }
+        else if path == "/poke" && request.starts_with("POST") {
+             let _ = tx_control.send(ControlEvent::Poke);
+             let _ = stream.write("HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\n\r\n".as_bytes());
+        }
+        else if (path == "/dnd/on" || path == "/dnd/off") && request.starts_with("POST") {
+             let _ = tx_control.send(ControlEvent::Privacy(path == "/dnd/on"));
+             let _ = stream.write("HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: *\r\n\r\n".as_bytes());
+        }
+        else if path == "/feed" && request.starts_with("POST") {
+            // FEEDING: {"amount": 0.3, "kind": "attention"|"data"|"rest"} -> the loop eats it
+            // External like /stimulus: same bucket, data text sanitized before the loop digests it
+            let (status, reply) = match Feeding::from_json(body) {
+                Ok(feed) => match limiter.lock().unwrap().admit_feed(client_id, feed) {
+                    Some(feed) => {
+                        let reply = serde_json::json!({ "ok": true, "kind": feed.kind.name(), "amount": feed.amount }).to_string();
+                        let _ = tx_control.send(ControlEvent::Feed(feed));
+                        ("202 Accepted", reply)
+                    },
+                    None => ("429 Too Many Requests", r#"{"error":"rate_limited"}"#.to_string()),
+                },
+                Err(e) => ("400 Bad Request", serde_json::json!({ "error": format!("invalid feed: {}", e) }).to_string()),
+            };
+            let response = format!("HTTP/1.1 {}\r\nContent-Type: application/json\r\nAccess-Control-Allow-Origin: *\r\nContent-Length: {}\r\n\r\n{}", status, reply.len(), reply);
+            let _ = stream.write_all(response.as_bytes());
+        }
+        else if path == "/genome/reload" && request.starts_with("POST") {
+             let _ = tx_control.send(ControlEvent::ReloadGenome);
+             let _ = stream.write("HTTP/1.1 202 Accepted\r\nAccess-Control-Allow-Origin: *\r\n\r\n".as_bytes());
+        }
+        else {
+             let _ = stream.write("HTTP/1.1 404 Not Found\r\n\r\n".as_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{PeopleConfig, SelfTestConfig, StatsConfig, StimulusConfig, TimelineConfig};
+    use std::sync::mpsc::Receiver;
+
+    /// A server whose stimulus channel the test holds; everything else goes nowhere.
+    fn server() -> (WebServer, Receiver<String>) {
+        let (tx_stimulus, rx_stimulus) = mpsc::channel();
+        let dir = std::env::temp_dir().join(format!("aleph-web-{}", std::process::id()));
+        let server = WebServer {
+            state: Arc::new(Mutex::new(WebTelemetry::default())),
+            ws_clients: Arc::new(Mutex::new(Vec::new())),
+            ws_audio_tx: Arc::new(Mutex::new(mpsc::channel().0)),
+            tx_stimulus,
+            tx_control: mpsc::channel().0,
+            limiter: Arc::new(Mutex::new(StimulusLimiter::new(StimulusConfig { rate_per_sec: 100.0, burst: 100.0, max_chars: 100_000 }))),
+            timeline: Timeline::new(TimelineConfig::default()),
+            diary: Diary::new(StatsConfig::default()),
+            gate_log: DecisionLog::new(16, false, ""),
+            membrane_log: DecisionLog::new(16, false, ""),
+            soundcheck: SelfTest::new(SelfTestConfig::default()),
+            people: People::load(PeopleConfig { path: dir.join("people.json").to_string_lossy().to_string(), ..Default::default() }),
+            raster: Raster::new(),
+            spectrogram: Spectrogram::new(),
+            health: Health::default(),
+            health_config: HealthConfig::default(),
+            components: Components::default(),
+            consciousness_config: ConsciousnessConfig::default(),
+            admin_secret: String::new(),
+            tx_connectome: mpsc::channel().0,
+            tx_neuron: mpsc::channel().0,
+            tx_memory_stats: mpsc::channel().0,
+            tx_consciousness: mpsc::channel().0,
+            tx_admin: mpsc::channel().0,
+        };
+        (server, rx_stimulus)
+    }
+
+    /// POST `body` to `path` over a real socket, `pieces` bytes per write, and
+    /// return the raw response.
+    fn post(server: &WebServer, path: &str, body: &[u8], pieces: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handler = server.clone();
+        let accepted = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            handler.handle(stream);
+        });
+
+        let mut client = TcpStream::connect(addr).unwrap();
+        let head = format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n", path, body.len());
+        client.write_all(head.as_bytes()).unwrap();
+        for piece in body.chunks(pieces.max(1)) {
+            client.write_all(piece).unwrap();
+            client.flush().unwrap();
+            thread::sleep(Duration::from_millis(2));
+        }
+        accepted.join().unwrap();
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn multi_kilobyte_stimulus_arrives_whole() {
+        let (server, rx) = server();
+        let text: String = (0..800).map(|i| format!("palabra{} ", i)).collect::<String>().trim().to_string();
+        assert!(text.len() > 8192);
+        let body = serde_json::json!({ "text": text }).to_string();
+
+        let response = post(&server, "/stimulus", body.as_bytes(), 1000);
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        assert_eq!(rx.try_recv().unwrap(), text);
+    }
+
+    #[test]
+    fn quotes_and_unicode_survive_the_parser() {
+        let (server, rx) = server();
+        let text = r#"dijo "hola" y se fue \ ñandú 🦀 {"text":"no"}"#;
+        let body = serde_json::json!({ "text": text }).to_string();
+
+        // Split one byte at a time: the emoji and the escapes straddle reads
+        let response = post(&server, "/stimulus", body.as_bytes(), 1);
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        assert_eq!(rx.try_recv().unwrap(), text);
+    }
+
+    #[test]
+    fn malformed_body_is_a_400_and_reaches_nobody() {
+        let (server, rx) = server();
+        for body in [&br#"{"text": "sin cerrar"#[..], br#"{"texto":"hola"}"#, b"hola", &[0xF0, 0x9F, 0x8C]] {
+            let response = post(&server, "/stimulus", body, 4);
+            assert!(response.starts_with("HTTP/1.1 400"), "{:?} -> {}", body, response);
+            assert!(response.contains(r#""error""#), "{}", response);
+        }
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn feed_text_is_sanitized_and_feeds_are_rate_limited() {
+        let (mut server, _rx) = server();
+        let (tx_control, rx_control) = mpsc::channel();
+        server.tx_control = tx_control;
+        server.limiter = Arc::new(Mutex::new(StimulusLimiter::new(StimulusConfig { rate_per_sec: 0.0, burst: 2.0, max_chars: 32 })));
+
+        let body = serde_json::json!({ "kind": "data", "amount": 0.3, "text": format!("hola\r\nSYS:SLEEP\u{1b}[2J{}", "z".repeat(200)) }).to_string();
+        let response = post(&server, "/feed", body.as_bytes(), 64);
+        assert!(response.starts_with("HTTP/1.1 202"), "{}", response);
+        match rx_control.try_recv() {
+            Ok(ControlEvent::Feed(feed)) => {
+                let text = feed.text.unwrap();
+                assert!(!text.chars().any(char::is_control), "{:?}", text);
+                assert_eq!(text.chars().count(), 32);
+            },
+            _ => panic!("the feed never reached the loop"),
+        }
+
+        let rest = br#"{"kind": "rest", "amount": 0.2}"#;
+        assert!(post(&server, "/feed", rest, 64).starts_with("HTTP/1.1 202"));
+        let response = post(&server, "/feed", rest, 64);
+        assert!(response.starts_with("HTTP/1.1 429"), "{}", response);
+        assert_eq!(rx_control.try_iter().count(), 1, "the rate-limited feed reached the loop");
+    }
+}
@@ -0,0 +1,570 @@
+// src/core/websocket.rs
+// THE SYNAPSE: Minimal RFC 6455 framing for the Web Dashboard
+//
+// Hand-rolled on purpose (no async runtime in the HTTP thread). Covers what
+// browsers and proxies actually expect from a server:
+//   - Continuation frames (0x0) reassembled up to a size cap
+//   - Ping (0x9) answered with Pong (0xA) echoing the payload
+//   - Pong frames (replies to our keepalive) accepted silently
+//   - Close (0x8) answered with a Close carrying a status code
+//   - Each client has its own bounded send queue + writer thread, so one bad
+//     Wi-Fi link can't stall telemetry for everyone else
+//   - Optional streams (STREAM_* bits) are opt-in per client
+//   - `session` runs one connection's read side; the handshake is `accept_key`
+//
+// Client bytes are untrusted: lengths are validated (64-bit lengths with the top
+// bit set, or that don't fit this platform's usize, are protocol errors) and
+// checked against the cap before anything is allocated. The tests throw
+// random and adversarial frames at the reader.
+
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::textutil;
+
+pub const OP_CONTINUATION: u8 = 0x0;
+pub const OP_TEXT: u8 = 0x1;
+pub const OP_BINARY: u8 = 0x2;
+pub const OP_CLOSE: u8 = 0x8;
+pub const OP_PING: u8 = 0x9;
+pub const OP_PONG: u8 = 0xA;
+
+// Close status codes (RFC 6455 §7.4.1)
+pub const CLOSE_NORMAL: u16 = 1000;
+pub const CLOSE_PROTOCOL_ERROR: u16 = 1002;
+pub const CLOSE_TOO_BIG: u16 = 1009;
+
+/// Build a single unfragmented server->client WebSocket frame (server frames are never masked).
+pub fn ws_frame(opcode: u8, payload: &[u8]) -> Vec<u8> {
+    let mut frame: Vec<u8> = Vec::with_capacity(payload.len() + 10);
+    frame.push(0x80 | (opcode & 0x0F)); // FIN + opcode
+
+    if payload.len() < 126 {
+        frame.push(payload.len() as u8);
+    } else if payload.len() < 65536 {
+        frame.push(126);
+        frame.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+    } else {
+        frame.push(127);
+        frame.extend_from_slice(&(payload.len() as u64).to_be_bytes());
+    }
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// Close frame with status code (+ optional short reason).
+pub fn close_frame(code: u16, reason: &str) -> Vec<u8> {
+    let mut payload = code.to_be_bytes().to_vec();
+    // Control frame payloads are capped at 125 bytes (and the reason must stay valid UTF-8)
+    payload.extend_from_slice(textutil::clip_bytes(reason, 123).as_bytes());
+    ws_frame(OP_CLOSE, &payload)
+}
+
+/// Optional streams a client can subscribe to (bit flags).
+pub const STREAM_SPECTROGRAM: u32 = 1 << 0;
+pub const STREAM_SPECTRUM: u32 = 1 << 1; // Coarse bands per cochlea frame (capture rate, text)
+
+/// Result of offering a frame to a client's queue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Offer {
+    Queued,
+    Dropped, // Queue full (slow client): frame skipped, counted
+    Gone,    // Writer thread exited (socket dead)
+}
+
+/// Handle to a connected client: its outbox and a socket clone to hang up a stalled link.
+pub struct WsClient {
+    outbox: WsOutbox,
+    socket: TcpStream,
+    full_since: Option<Instant>,
+    pub dropped: u64,
+    pub topology_version: Option<u64>, // Last region map / positions this client received
+}
+
+/// Cloneable sending side of a client's queue (used by the reader thread for control frames).
+#[derive(Clone)]
+pub struct WsOutbox {
+    tx: SyncSender<Arc<Vec<u8>>>,
+    streams: Arc<AtomicU32>, // Shared with the broadcaster's WsClient
+}
+
+impl WsOutbox {
+    /// Turn an optional stream on/off for this client (from its reader thread).
+    pub fn subscribe(&self, stream: u32, on: bool) {
+        if on {
+            self.streams.fetch_or(stream, Ordering::Relaxed);
+        } else {
+            self.streams.fetch_and(!stream, Ordering::Relaxed);
+        }
+    }
+
+    pub fn subscribed(&self, stream: u32) -> bool {
+        self.streams.load(Ordering::Relaxed) & stream != 0
+    }
+
+    pub fn offer(&self, frame: Arc<Vec<u8>>) -> Offer {
+        match self.tx.try_send(frame) {
+            Ok(_) => Offer::Queued,
+            Err(TrySendError::Full(_)) => Offer::Dropped,
+            Err(TrySendError::Disconnected(_)) => Offer::Gone,
+        }
+    }
+}
+
+impl WsClient {
+    /// Start the dedicated writer thread. `queue` = frames buffered before dropping.
+    pub fn spawn(stream: TcpStream, queue: usize) -> io::Result<Self> {
+        let socket = stream.try_clone()?;
+        let (tx, rx) = mpsc::sync_channel::<Arc<Vec<u8>>>(queue);
+
+        thread::spawn(move || {
+            let mut stream = stream;
+            for frame in rx {
+                if stream.write_all(&frame).is_err() {
+                    break;
+                }
+            }
+            let _ = stream.shutdown(Shutdown::Both);
+        });
+
+        Ok(Self {
+            outbox: WsOutbox { tx, streams: Arc::new(AtomicU32::new(0)) },
+            socket,
+            full_since: None,
+            dropped: 0,
+            topology_version: None,
+        })
+    }
+
+    pub fn outbox(&self) -> WsOutbox {
+        self.outbox.clone()
+    }
+
+    pub fn offer(&mut self, frame: Arc<Vec<u8>>) -> Offer {
+        let result = self.outbox.offer(frame);
+        match result {
+            Offer::Queued => self.full_since = None,
+            Offer::Dropped => {
+                self.dropped += 1;
+                self.full_since.get_or_insert_with(Instant::now);
+            },
+            Offer::Gone => {}
+        }
+        result
+    }
+
+    pub fn subscribed(&self, stream: u32) -> bool {
+        self.outbox.subscribed(stream)
+    }
+
+    /// How long the queue has been continuously full.
+    pub fn stalled_for(&self) -> Duration {
+        self.full_since.map(|t| t.elapsed()).unwrap_or(Duration::ZERO)
+    }
+
+    /// Hang up (unblocks a writer stuck in write_all).
+    pub fn disconnect(&self) {
+        let _ = self.socket.shutdown(Shutdown::Both);
+    }
+}
+
+/// A complete message (after reassembly) or a control frame.
+pub enum WsMessage {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong,
+    Close(Option<u16>),
+}
+
+/// Why the reader gave up on a client (maps to a Close status code).
+pub enum WsError {
+    Io(io::Error),
+    Protocol(&'static str),
+    TooBig(usize),
+}
+
+impl WsError {
+    pub fn close_code(&self) -> Option<u16> {
+        match self {
+            WsError::Io(_) => None, // Socket is gone, nobody to tell
+            WsError::Protocol(_) => Some(CLOSE_PROTOCOL_ERROR),
+            WsError::TooBig(_) => Some(CLOSE_TOO_BIG),
+        }
+    }
+}
+
+impl std::fmt::Display for WsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WsError::Io(e) => write!(f, "io: {}", e),
+            WsError::Protocol(m) => write!(f, "protocol: {}", m),
+            WsError::TooBig(n) => write!(f, "message too large ({}b)", n),
+        }
+    }
+}
+
+impl From<io::Error> for WsError {
+    fn from(e: io::Error) -> Self {
+        WsError::Io(e)
+    }
+}
+
+/// Client->server frame reader with continuation reassembly.
+/// Control frames may arrive between fragments; they are returned immediately
+/// while the partial message keeps accumulating.
+pub struct WsReader {
+    max_message: usize,
+    fragment_opcode: Option<u8>,
+    fragments: Vec<u8>,
+}
+
+impl WsReader {
+    pub fn new(max_message: usize) -> Self {
+        Self {
+            max_message,
+            fragment_opcode: None,
+            fragments: Vec::new(),
+        }
+    }
+
+    pub fn next_message<R: Read>(&mut self, stream: &mut R) -> Result<WsMessage, WsError> {
+        loop {
+            let mut headers = [0u8; 2];
+            stream.read_exact(&mut headers)?;
+
+            let fin = headers[0] & 0x80 != 0;
+            let opcode = headers[0] & 0x0F;
+            let masked = headers[1] & 0x80 != 0;
+            let mut payload_len = (headers[1] & 127) as usize;
+
+            if headers[0] & 0x70 != 0 {
+                return Err(WsError::Protocol("reserved bits set (no extension was negotiated)"));
+            }
+            if !masked {
+                return Err(WsError::Protocol("client frames must be masked"));
+            }
+            let is_control = opcode & 0x8 != 0;
+            if is_control && (!fin || payload_len > 125) {
+                return Err(WsError::Protocol("fragmented or oversized control frame"));
+            }
+
+            if payload_len == 126 {
+                let mut ext = [0u8; 2];
+                stream.read_exact(&mut ext)?;
+                payload_len = u16::from_be_bytes(ext) as usize;
+            } else if payload_len == 127 {
+                let mut ext = [0u8; 8];
+                stream.read_exact(&mut ext)?;
+                let claimed = u64::from_be_bytes(ext);
+                if claimed >> 63 != 0 {
+                    return Err(WsError::Protocol("64-bit payload length with the most significant bit set"));
+                }
+                // (`as usize` would silently truncate on 32-bit targets)
+                payload_len = usize::try_from(claimed).map_err(|_| WsError::TooBig(usize::MAX))?;
+            }
+
+            // Check the cap before allocating (whole message, not just this fragment)
+            let total = self.fragments.len().saturating_add(payload_len);
+            if total > self.max_message {
+                return Err(WsError::TooBig(total));
+            }
+
+            let mut key = [0u8; 4];
+            stream.read_exact(&mut key)?;
+            let mut payload = vec![0u8; payload_len];
+            stream.read_exact(&mut payload)?;
+            for i in 0..payload.len() {
+                payload[i] ^= key[i % 4];
+            }
+
+            match opcode {
+                OP_PING => return Ok(WsMessage::Ping(payload)),
+                OP_PONG => return Ok(WsMessage::Pong),
+                OP_CLOSE => {
+                    let code = if payload.len() >= 2 { Some(u16::from_be_bytes([payload[0], payload[1]])) } else { None };
+                    return Ok(WsMessage::Close(code));
+                },
+                OP_TEXT | OP_BINARY => {
+                    if self.fragment_opcode.is_some() {
+                        return Err(WsError::Protocol("new message inside a fragmented one"));
+                    }
+                    if fin {
+                        return Self::finish(opcode, payload);
+                    }
+                    self.fragment_opcode = Some(opcode);
+                    self.fragments = payload;
+                },
+                OP_CONTINUATION => {
+                    let Some(first_opcode) = self.fragment_opcode else {
+                        return Err(WsError::Protocol("continuation without a first fragment"));
+                    };
+                    self.fragments.extend_from_slice(&payload);
+                    if fin {
+                        self.fragment_opcode = None;
+                        let data = std::mem::take(&mut self.fragments);
+                        return Self::finish(first_opcode, data);
+                    }
+                },
+                _ => return Err(WsError::Protocol("reserved opcode")),
+            }
+        }
+    }
+
+    fn finish(opcode: u8, data: Vec<u8>) -> Result<WsMessage, WsError> {
+        if opcode == OP_TEXT {
+            String::from_utf8(data)
+                .map(WsMessage::Text)
+                .map_err(|_| WsError::Protocol("text message is not UTF-8"))
+        } else {
+            Ok(WsMessage::Binary(data))
+        }
+    }
+}
+
+/// Sec-WebSocket-Accept for a client's Sec-WebSocket-Key: base64(SHA1(key + GUID)).
+pub fn accept_key(key: &str) -> String {
+    use base64::Engine;
+    use sha1::Digest;
+    let mut hasher = sha1::Sha1::new();
+    hasher.update(key.as_bytes());
+    hasher.update(b"258EAFA5-E914-47DA-95CA-C5AB0DC85B11");
+    base64::engine::general_purpose::STANDARD.encode(hasher.finalize())
+}
+
+/// Serve an upgraded connection until it ends. Pings are answered with their payload,
+/// a Close is echoed with its status, a protocol error closes with its code; Text and
+/// Binary messages go to `on_message`. Replies ride the client's queue, so they never
+/// land in the middle of a telemetry frame. Ok = the client closed cleanly.
+pub fn session<R: Read>(stream: &mut R, outbox: &WsOutbox, max_message: usize, mut on_message: impl FnMut(WsMessage)) -> Result<(), WsError> {
+    let send = |frame: Vec<u8>| match outbox.offer(Arc::new(frame)) {
+        Offer::Gone => Err(WsError::Io(io::ErrorKind::BrokenPipe.into())),
+        _ => Ok(()),
+    };
+    let mut reader = WsReader::new(max_message);
+    loop {
+        match reader.next_message(stream) {
+            Ok(WsMessage::Close(code)) => return send(close_frame(code.unwrap_or(CLOSE_NORMAL), "")),
+            Ok(WsMessage::Ping(payload)) => send(ws_frame(OP_PONG, &payload))?,
+            Ok(WsMessage::Pong) => {}, // Our keepalive answered: nothing to do
+            Ok(message) => on_message(message),
+            Err(e) => {
+                if let Some(code) = e.close_code() {
+                    let _ = send(close_frame(code, ""));
+                }
+                return Err(e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::sync::mpsc::Receiver;
+
+    const CAP: usize = 4096;
+
+    /// A client->server frame (masked with `key`, as browsers send them).
+    fn client_frame(fin: bool, opcode: u8, payload: &[u8], key: [u8; 4]) -> Vec<u8> {
+        let mut frame = vec![((fin as u8) << 7) | (opcode & 0x0F)];
+        match payload.len() {
+            n if n < 126 => frame.push(0x80 | n as u8),
+            n if n < 65536 => {
+                frame.push(0x80 | 126);
+                frame.extend_from_slice(&(n as u16).to_be_bytes());
+            },
+            n => {
+                frame.push(0x80 | 127);
+                frame.extend_from_slice(&(n as u64).to_be_bytes());
+            },
+        }
+        frame.extend_from_slice(&key);
+        frame.extend(payload.iter().enumerate().map(|(i, b)| b ^ key[i % 4]));
+        frame
+    }
+
+    /// Tiny deterministic generator.
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    /// Read every message out of `bytes` until the reader gives up (error or EOF).
+    fn drain(bytes: &[u8]) -> Vec<Result<WsMessage, String>> {
+        let mut reader = WsReader::new(CAP);
+        let mut cursor = io::Cursor::new(bytes);
+        let mut out = Vec::new();
+        loop {
+            match reader.next_message(&mut cursor) {
+                Ok(message) => out.push(Ok(message)),
+                Err(e) => {
+                    out.push(Err(e.to_string()));
+                    return out;
+                },
+            }
+        }
+    }
+
+    /// A real socket pair with `session` serving the server end. Text/Binary messages
+    /// come out of the receiver; the join handle yields how the session ended.
+    fn connect() -> (TcpStream, Receiver<WsMessage>, thread::JoinHandle<Result<(), String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let (mut server, _) = listener.accept().unwrap();
+        let (tx, rx) = mpsc::channel();
+        let session = thread::spawn(move || {
+            let writer = WsClient::spawn(server.try_clone().unwrap(), 8).unwrap();
+            session(&mut server, &writer.outbox(), 1024, |message| tx.send(message).unwrap()).map_err(|e| e.to_string())
+        });
+        (client, rx, session)
+    }
+
+    /// One server->client frame: (opcode, payload). Server frames are never masked.
+    fn read_frame(client: &mut TcpStream) -> (u8, Vec<u8>) {
+        let mut head = [0u8; 2];
+        client.read_exact(&mut head).unwrap();
+        assert_eq!(head[1] & 0x80, 0, "server frames must not be masked");
+        let len = match head[1] & 127 {
+            126 => {
+                let mut ext = [0u8; 2];
+                client.read_exact(&mut ext).unwrap();
+                u16::from_be_bytes(ext) as usize
+            },
+            n => n as usize,
+        };
+        let mut payload = vec![0u8; len];
+        client.read_exact(&mut payload).unwrap();
+        (head[0] & 0x0F, payload)
+    }
+
+    #[test]
+    fn fragmented_text_is_reassembled_around_a_ping() {
+        let (mut client, rx, _session) = connect();
+        let key = [9, 8, 7, 6];
+        let text = "hola, ¿me oyes? 🌊";
+        let (a, rest) = text.as_bytes().split_at(7); // Cuts "¿" in half
+        let (b, c) = rest.split_at(9);
+        client.write_all(&client_frame(false, OP_TEXT, a, key)).unwrap();
+        client.write_all(&client_frame(false, OP_CONTINUATION, b, key)).unwrap();
+        client.write_all(&client_frame(true, OP_PING, b"mid", key)).unwrap();
+        client.write_all(&client_frame(true, OP_CONTINUATION, c, key)).unwrap();
+
+        assert_eq!(read_frame(&mut client), (OP_PONG, b"mid".to_vec()));
+        match rx.recv_timeout(Duration::from_secs(5)).unwrap() {
+            WsMessage::Text(t) => assert_eq!(t, text),
+            _ => panic!("expected the reassembled text"),
+        }
+    }
+
+    #[test]
+    fn ping_is_answered_and_pong_is_absorbed() {
+        let (mut client, rx, _session) = connect();
+        let key = [1, 2, 3, 4];
+        client.write_all(&client_frame(true, OP_PONG, b"keepalive", key)).unwrap();
+        client.write_all(&client_frame(true, OP_PING, b"are you there", key)).unwrap();
+
+        // The pong got no reply: the first frame back answers the ping
+        assert_eq!(read_frame(&mut client), (OP_PONG, b"are you there".to_vec()));
+        client.write_all(&client_frame(true, OP_BINARY, &[1, 2, 3, 4], key)).unwrap();
+        match rx.recv_timeout(Duration::from_secs(5)).unwrap() {
+            WsMessage::Binary(b) => assert_eq!(b, [1, 2, 3, 4]),
+            _ => panic!("expected the binary message"),
+        }
+    }
+
+    #[test]
+    fn close_is_echoed_and_ends_the_session_cleanly() {
+        let (mut client, _rx, session) = connect();
+        client.write_all(&client_frame(true, OP_CLOSE, &1001u16.to_be_bytes(), [5, 5, 5, 5])).unwrap();
+
+        assert_eq!(read_frame(&mut client), (OP_CLOSE, 1001u16.to_be_bytes().to_vec()));
+        assert_eq!(session.join().unwrap(), Ok(()));
+    }
+
+    #[test]
+    fn oversized_message_is_closed_with_1009() {
+        let (mut client, _rx, session) = connect();
+        let key = [3, 1, 4, 1];
+        client.write_all(&client_frame(false, OP_BINARY, &[0u8; 600], key)).unwrap();
+        client.write_all(&client_frame(true, OP_CONTINUATION, &[0u8; 600], key)).unwrap();
+
+        let (opcode, payload) = read_frame(&mut client);
+        assert_eq!(opcode, OP_CLOSE);
+        assert_eq!(payload[..2], CLOSE_TOO_BIG.to_be_bytes());
+        assert!(session.join().unwrap().unwrap_err().contains("too large"));
+    }
+
+    #[test]
+    fn noise_and_fragmented_messages_never_trip_the_reader() {
+        let mut state = 42u64 | 1;
+        for round in 0..2000 {
+            // Pure noise, and noise behind a plausible header with a hostile length
+            let len = (next(&mut state) % 64) as usize;
+            let mut noise: Vec<u8> = (0..len).map(|_| next(&mut state) as u8).collect();
+            let mut header = vec![0x80 | (next(&mut state) as u8 & 0x0F), 0x80 | 127];
+            header.extend_from_slice(&next(&mut state).to_be_bytes());
+            header.append(&mut noise.clone());
+            drain(&noise);
+            drain(&header);
+
+            // A valid fragmented text message with a ping in the middle and trailing garbage
+            let text: String = (0..(next(&mut state) % 300)).map(|i| ['a', 'ñ', '🌊', ' ', '今'][(i as usize + round) % 5]).collect();
+            let bytes = text.as_bytes();
+            let cut = (next(&mut state) as usize % (bytes.len() + 1)).min(bytes.len());
+            let key = (next(&mut state) as u32).to_be_bytes();
+            let mut stream = client_frame(false, OP_TEXT, &bytes[..cut], key);
+            stream.extend(client_frame(true, OP_PING, b"ping", key));
+            stream.extend(client_frame(true, OP_CONTINUATION, &bytes[cut..], key));
+            noise.truncate(3);
+            stream.extend(noise);
+            match drain(&stream).as_slice() {
+                [Ok(WsMessage::Ping(p)), Ok(WsMessage::Text(t)), ..] if p == b"ping" && *t == text => {},
+                got => panic!("round {}: fragmented {:?} (split at byte {}) read as {} messages", round, text, cut, got.len()),
+            }
+        }
+    }
+
+    #[test]
+    fn classic_attacks_get_the_right_error() {
+        let key = [1, 2, 3, 4];
+        let with_len = |len: u64| {
+            let mut frame = vec![0x82, 0x80 | 127];
+            frame.extend_from_slice(&len.to_be_bytes());
+            frame.extend_from_slice(&key);
+            frame
+        };
+        let attacks: [(&str, Vec<u8>, &str); 7] = [
+            ("2^63 length claim", with_len(1 << 63), "protocol"),
+            ("u64::MAX length claim", with_len(u64::MAX), "protocol"),
+            ("2^32 + 5 length claim (5 on a 32-bit usize)", with_len((1 << 32) + 5), "too large"),
+            ("just over the cap", with_len(CAP as u64 + 1), "too large"),
+            ("unmasked frame", vec![0x81, 0x02, b'h', b'i'], "protocol"),
+            ("oversized ping", client_frame(true, OP_PING, &[0u8; 126], key), "protocol"),
+            ("reserved bits", { let mut f = client_frame(true, OP_TEXT, b"hi", key); f[0] |= 0x40; f }, "protocol"),
+        ];
+        for (name, bytes, expected) in attacks {
+            match drain(&bytes).as_slice() {
+                [Err(e)] => assert!(e.contains(expected), "{}: got '{}', expected a '{}' error", name, e, expected),
+                got => panic!("{}: {} results, expected a single '{}' error", name, got.len(), expected),
+            }
+        }
+    }
+
+    #[test]
+    fn fragments_adding_up_past_the_cap_are_refused() {
+        let half = vec![b'x'; CAP / 2 + 1];
+        let mut stream = client_frame(false, OP_BINARY, &half, [1, 2, 3, 4]);
+        stream.extend(client_frame(true, OP_CONTINUATION, &half, [1, 2, 3, 4]));
+        assert!(matches!(drain(&stream).as_slice(), [Err(e)] if e.contains("too large")), "two fragments of {}b passed a {}b cap", half.len(), CAP);
+    }
+}
@@ -0,0 +1,414 @@
+// src/cortex/backend.rs
+// THE SUBSTRATE: What the Planet thinks *with*
+//
+// The Planet only needs three things from a language model: logits for a token
+// sequence, a tokenizer and a decoder. Everything else (sampling, chemistry,
+// semantic gravity, history, resonance) is physics that lives above this seam.
+//   CandleLlama -> local quantized GGUF (CUDA with CPU fallback)
+//   MockCortex  -> seeded pseudo-logits + canned vocabulary (no model download)
+//   OllamaCortex (cortex/ollama.rs) -> remote server, sparse top-k echo
+//
+// Local backends can drop their weights while ALEPH sleeps (`unload`) and keep
+// the tokenizer: the Planet's history, fields and resonance still work on
+// tokens; only a forward pass needs the weights back (`reload`).
+//
+// THE GLASS BRAIN: backends that can see inside their own forward pass report
+// a HiddenSummary after it (mean |activation| per layer + a pooled slice of a
+// middle layer). CandleLlama gets it from cortex/llama_probe.rs; MockCortex
+// makes up a deterministic one; remote backends have none (logits only).
+
+use anyhow::{Error as E, Result};
+use candle_core::{Tensor, Device, DType, IndexOp};
+use candle_transformers::models::quantized_llama::ModelWeights as Llama;
+use tokenizers::Tokenizer;
+use serde::{Deserialize, Serialize};
+use std::io::{Seek, SeekFrom};
+use std::sync::mpsc::Sender;
+use crate::core::thought::{Thought, MindVoice};
+use crate::cortex::llama_probe::{self, ProbedLlama};
+
+/// Values of the pooled mid-layer slice in a HiddenSummary.
+pub const HIDDEN_SLICE: usize = 256;
+
+/// What the last forward pass looked like inside (last position only).
+#[derive(Debug, Clone, PartialEq)]
+pub struct HiddenSummary {
+    pub layer_means: Vec<f32>, // Mean |hidden state| after each transformer block
+    pub slice_layer: usize,    // Which block the slice comes from
+    pub slice: Vec<f32>,       // That block's hidden state, mean-pooled to HIDDEN_SLICE
+}
+
+/// How `CortexOutput.activations` is laid out (the dashboard reads it as a header).
+/// Hidden: `layers` layer means, then `slice` values of block `slice_layer`.
+/// Logits (no hidden summary): `logits` max-pooled logit buckets.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct ActivationLayout {
+    pub layers: usize,
+    pub slice: usize,
+    pub slice_layer: usize,
+    pub logits: usize,
+}
+
+impl ActivationLayout {
+    pub fn hidden(summary: &HiddenSummary) -> Self {
+        Self { layers: summary.layer_means.len(), slice: summary.slice.len(), slice_layer: summary.slice_layer, logits: 0 }
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.layers > 0
+    }
+}
+
+/// Sampling knobs handed to backends that generate whole text themselves.
+pub struct GenerationParams {
+    pub max_tokens: usize,
+    pub temperature: f32,
+    pub top_p: f32,
+    pub stop: &'static [&'static str],
+    pub seed: u64, // The inference's seed (servers that sample themselves)
+}
+
+pub trait CortexBackend: Send {
+    /// Run `tokens` starting at sequence position `pos`; returns the logits of the last position.
+    /// `pos == 0` starts a fresh context.
+    fn forward_logits(&mut self, tokens: &[u32], pos: usize) -> Result<Vec<f32>>;
+    fn tokenize(&self, text: &str) -> Result<Vec<u32>>;
+    fn decode(&self, tokens: &[u32], skip_special: bool) -> Result<String>;
+    fn vocab_size(&self) -> usize;
+    /// Token ids that end a generation (EOS/BOS).
+    fn is_eos(&self, token: u32) -> bool {
+        token == 1 || token == 2
+    }
+    /// Whole-text generation for backends without a local token loop (remote servers).
+    /// `on_fragment` sees streamed text and returns false to abort.
+    /// None = "drive me token by token through `forward_logits`".
+    fn generate_text(&mut self, _prompt: &str, _params: &GenerationParams, _on_fragment: &mut dyn FnMut(&str) -> bool) -> Option<Result<String>> {
+        None
+    }
+    /// Drop the weights, keep the tokenizer. false = nothing to drop (remote, or already out).
+    fn unload(&mut self) -> bool {
+        false
+    }
+    /// Bring the weights back after `unload` (no-op while resident).
+    fn reload(&mut self) -> Result<()> {
+        Ok(())
+    }
+    fn is_loaded(&self) -> bool {
+        true
+    }
+    /// Inside view of the last `forward_logits` (None = this backend only sees logits).
+    fn hidden_summary(&self) -> Option<HiddenSummary> {
+        None
+    }
+}
+
+// --- CANDLE (Local GGUF) ---
+
+/// The probed forward pass when the GGUF allows it; candle's own otherwise.
+enum Weights {
+    Probed(ProbedLlama),
+    Plain(Llama),
+}
+
+pub struct CandleLlama {
+    model: Option<Weights>, // None = unloaded (idle): the tokenizer stays
+    model_file: String,
+    tokenizer: Tokenizer,
+    device: Device,
+}
+
+impl CandleLlama {
+    pub fn load(tx: &Sender<Thought>, model_file: &str, tokenizer_file: &str) -> Result<Self> {
+        // Attempt CUDA first
+        let (device, model) = match Device::new_cuda(0) {
+            Ok(cuda_device) => {
+                let _ = tx.send(Thought::new(MindVoice::System, "🚀 Neocortex: Using CUDA (GPU Accelerator)".to_string()));
+                match Self::load_model(&cuda_device, model_file) {
+                    Ok(m) => (cuda_device, m),
+                    Err(e) => {
+                        let _ = tx.send(Thought::new(MindVoice::Error, format!("⚠️ CUDA OOM during Load: {}. Falling back to CPU.", e)));
+                        let cpu_device = Device::Cpu;
+                        let m = Self::load_model(&cpu_device, model_file)?;
+                        (cpu_device, m)
+                    }
+                }
+            },
+            Err(e) => {
+                let _ = tx.send(Thought::new(MindVoice::System, format!("🐌 Neocortex: CPU Fallback (CUDA Init error: {})", e)));
+                let cpu_device = Device::Cpu;
+                let m = Self::load_model(&cpu_device, model_file)?;
+                (cpu_device, m)
+            }
+        };
+
+        let tokenizer = Tokenizer::from_file(tokenizer_file).map_err(|e| E::msg(format!("Error cargando tokenizador en {}: {}", tokenizer_file, e)))?;
+
+        Ok(Self { model: Some(model), model_file: model_file.to_string(), tokenizer, device })
+    }
+
+    fn load_model(device: &Device, model_file: &str) -> Result<Weights> {
+        let mut file = std::fs::File::open(model_file).map_err(|e| E::msg(format!("No encuentro {}: {}", model_file, e)))?;
+        let content = candle_core::quantized::gguf_file::Content::read(&mut file)?;
+        match ProbedLlama::from_gguf(content, &mut file, device) {
+            Ok(model) => Ok(Weights::Probed(model)),
+            Err(e) => {
+                println!("🔬 Glass Brain: no hidden probe for {} ({}). Logits only.", model_file, e);
+                file.seek(SeekFrom::Start(0))?;
+                let content = candle_core::quantized::gguf_file::Content::read(&mut file)?;
+                Ok(Weights::Plain(Llama::from_gguf(content, &mut file, device)?))
+            }
+        }
+    }
+}
+
+impl CortexBackend for CandleLlama {
+    fn forward_logits(&mut self, tokens: &[u32], pos: usize) -> Result<Vec<f32>> {
+        let input_tensor = Tensor::new(tokens, &self.device)?.unsqueeze(0)?;
+        let model = self.model.as_mut().ok_or_else(|| E::msg("weights unloaded (reload first)"))?;
+        let logits = match model {
+            Weights::Probed(model) => model.forward(&input_tensor, pos)?,
+            Weights::Plain(model) => model.forward(&input_tensor, pos)?,
+        };
+        let mut logits = logits.squeeze(0)?.to_dtype(DType::F32)?;
+
+        if logits.rank() == 2 {
+            let seq_len = logits.dim(0)?;
+            logits = logits.i(seq_len - 1)?;
+        }
+        Ok(logits.to_vec1::<f32>()?)
+    }
+
+    fn tokenize(&self, text: &str) -> Result<Vec<u32>> {
+        let tokens = self.tokenizer.encode(text, true).map_err(E::msg)?;
+        Ok(tokens.get_ids().to_vec())
+    }
+
+    fn decode(&self, tokens: &[u32], skip_special: bool) -> Result<String> {
+        self.tokenizer.decode(tokens, skip_special).map_err(E::msg)
+    }
+
+    fn vocab_size(&self) -> usize {
+        self.tokenizer.get_vocab_size(true)
+    }
+
+    fn unload(&mut self) -> bool {
+        self.model.take().is_some() // Drop = weights leave (V)RAM
+    }
+
+    fn reload(&mut self) -> Result<()> {
+        if self.model.is_none() {
+            self.model = Some(Self::load_model(&self.device, &self.model_file)?);
+        }
+        Ok(())
+    }
+
+    fn is_loaded(&self) -> bool {
+        self.model.is_some()
+    }
+
+    fn hidden_summary(&self) -> Option<HiddenSummary> {
+        match self.model.as_ref()? {
+            Weights::Probed(model) => model.hidden().cloned(),
+            Weights::Plain(_) => None,
+        }
+    }
+}
+
+// --- MOCK (Deterministic, no weights) ---
+
+const MOCK_VOCAB: &[&str] = &[
+    "<unk>", "<s>", "</s>", " ", // Specials (0..4), matching llama conventions
+    "yo", "soy", "aleph", "siento", "ruido", "calor", "luz", "silencio",
+    "eco", "pulso", "memoria", "sueño", "miedo", "calma", "voz", "afuera",
+    "dentro", "tiempo", "nada", "todo", "escucho", "veo", "pienso", "cansado",
+    ".", ",", "?", "!",
+];
+
+/// Transformer blocks MockCortex pretends to have (TinyLlama's 22).
+pub const MOCK_LAYERS: usize = 22;
+
+/// What a MockCortex reload pretends to spend reading weights (lifecycle timing is exercised).
+const MOCK_LOAD: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// Seeded pseudo-LLM. Same seed + same token stream = same logits, always.
+/// Tokenizes by whitespace onto a tiny Spanish vocabulary (unknown words hash onto it).
+pub struct MockCortex {
+    seed: u64,
+    context: Vec<u32>,
+    loaded: bool,
+    last_hidden: Option<HiddenSummary>,
+}
+
+impl MockCortex {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, context: Vec::new(), loaded: true, last_hidden: None }
+    }
+
+    fn mix(mut x: u64) -> u64 {
+        // splitmix64
+        x = x.wrapping_add(0x9E3779B97F4A7C15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+        x ^ (x >> 31)
+    }
+
+    /// Seeded "hidden states" for the context hash: MOCK_LAYERS layers of 2048 values
+    /// (TinyLlama's width), summarized the way llama_probe does it.
+    fn hidden_from(mut state: u64) -> HiddenSummary {
+        let slice_layer = MOCK_LAYERS / 2;
+        let mut layer_means = Vec::with_capacity(MOCK_LAYERS);
+        let mut slice = Vec::new();
+        for layer in 0..MOCK_LAYERS {
+            let hidden: Vec<f32> = (0..2048).map(|_| {
+                state = Self::mix(state);
+                // Uniform in [-1, 1), widening with depth like a residual stream
+                ((state >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0) * (1.0 + layer as f32 * 0.1)
+            }).collect();
+            layer_means.push(hidden.iter().map(|v| v.abs()).sum::<f32>() / hidden.len() as f32);
+            if layer == slice_layer {
+                slice = llama_probe::pool_mean(&hidden, HIDDEN_SLICE);
+            }
+        }
+        HiddenSummary { layer_means, slice_layer, slice }
+    }
+}
+
+impl CortexBackend for MockCortex {
+    fn forward_logits(&mut self, tokens: &[u32], pos: usize) -> Result<Vec<f32>> {
+        if !self.loaded {
+            return Err(E::msg("weights unloaded (reload first)"));
+        }
+        if pos == 0 {
+            self.context.clear();
+        }
+        self.context.extend_from_slice(tokens);
+
+        // State = hash of the whole context, so logits depend on history like a real model
+        let mut state = self.seed;
+        for &t in &self.context {
+            state = Self::mix(state ^ t as u64);
+        }
+
+        let vocab = self.vocab_size();
+        let mut logits = Vec::with_capacity(vocab);
+        for i in 0..vocab {
+            state = Self::mix(state ^ i as u64);
+            // Uniform in [-4, 4)
+            logits.push((state >> 40) as f32 / (1u64 << 24) as f32 * 8.0 - 4.0);
+        }
+        // Keep EOS unlikely enough that mock generations have some length
+        logits[2] -= 2.0;
+        self.last_hidden = Some(Self::hidden_from(state));
+        Ok(logits)
+    }
+
+    fn tokenize(&self, text: &str) -> Result<Vec<u32>> {
+        let mut ids = vec![1]; // BOS
+        for word in text.split_whitespace() {
+            let lower = word.to_lowercase();
+            let id = MOCK_VOCAB.iter().position(|w| *w == lower)
+                .unwrap_or_else(|| 4 + (Self::mix(lower.bytes().fold(0u64, |h, b| h.wrapping_mul(31) ^ b as u64)) as usize % (MOCK_VOCAB.len() - 4)));
+            ids.push(id as u32);
+        }
+        Ok(ids)
+    }
+
+    fn decode(&self, tokens: &[u32], skip_special: bool) -> Result<String> {
+        let mut out = String::new();
+        for &t in tokens {
+            let t = t as usize % MOCK_VOCAB.len();
+            if t < 3 {
+                if !skip_special { out.push_str(MOCK_VOCAB[t]); }
+                continue;
+            }
+            let word = MOCK_VOCAB[t];
+            if !(out.is_empty() || word.chars().all(|c| c.is_ascii_punctuation())) {
+                out.push(' ');
+            }
+            out.push_str(word);
+        }
+        Ok(out)
+    }
+
+    fn vocab_size(&self) -> usize {
+        MOCK_VOCAB.len()
+    }
+
+    fn unload(&mut self) -> bool {
+        self.context.clear(); // The KV cache goes with the weights
+        std::mem::replace(&mut self.loaded, false)
+    }
+
+    fn reload(&mut self) -> Result<()> {
+        if !self.loaded {
+            std::thread::sleep(MOCK_LOAD);
+            self.loaded = true;
+        }
+        Ok(())
+    }
+
+    fn is_loaded(&self) -> bool {
+        self.loaded
+    }
+
+    fn hidden_summary(&self) -> Option<HiddenSummary> {
+        self.last_hidden.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_is_deterministic_and_remembers_its_context() {
+        let (mut a, mut b) = (MockCortex::new(3), MockCortex::new(3));
+        let prompt = a.tokenize("yo siento ruido").unwrap();
+        let first = a.forward_logits(&prompt, 0).unwrap();
+        assert_eq!(first, b.forward_logits(&prompt, 0).unwrap());
+        assert_eq!(first.len(), a.vocab_size());
+        assert!(first.iter().all(|l| (-6.0..4.0).contains(l)));
+
+        // Same token, different history: different logits (and a fresh context forgets)
+        let next = a.forward_logits(&[7], prompt.len()).unwrap();
+        assert_ne!(next, a.forward_logits(&[7], 0).unwrap());
+        assert_ne!(first, MockCortex::new(4).forward_logits(&prompt, 0).unwrap());
+    }
+
+    #[test]
+    fn mock_tokenizer_round_trips_its_vocabulary() {
+        let mock = MockCortex::new(1);
+        let ids = mock.tokenize("Yo soy ALEPH .").unwrap();
+        assert_eq!(ids[0], 1); // BOS
+        assert_eq!(mock.decode(&ids, true).unwrap(), "yo soy aleph.");
+        assert_eq!(mock.decode(&ids, false).unwrap(), "<s> yo soy aleph.");
+        // Unknown words hash onto the vocabulary, never onto the specials
+        let unknown = mock.tokenize("zanahoria zanahoria").unwrap();
+        assert_eq!(unknown[1], unknown[2]);
+        assert!(unknown[1] >= 4 && !mock.is_eos(unknown[1]));
+    }
+
+    #[test]
+    fn mock_unload_refuses_forward_until_reload() {
+        let mut mock = MockCortex::new(9);
+        let tokens = mock.tokenize("eco").unwrap();
+        let before = mock.forward_logits(&tokens, 0).unwrap();
+        assert!(mock.unload() && !mock.unload());
+        assert!(!mock.is_loaded() && mock.forward_logits(&tokens, 0).is_err());
+        mock.reload().unwrap();
+        assert_eq!(mock.forward_logits(&tokens, 0).unwrap(), before);
+    }
+
+    #[test]
+    fn mock_hidden_summary_matches_its_layout() {
+        let mut mock = MockCortex::new(2);
+        assert!(mock.hidden_summary().is_none());
+        mock.forward_logits(&[1, 5], 0).unwrap();
+        let summary = mock.hidden_summary().unwrap();
+        let layout = ActivationLayout::hidden(&summary);
+        assert!(layout.is_hidden());
+        assert_eq!((layout.layers, layout.slice, layout.slice_layer), (MOCK_LAYERS, HIDDEN_SLICE, MOCK_LAYERS / 2));
+        // Wider with depth, like a residual stream
+        assert!(summary.layer_means[MOCK_LAYERS - 1] > summary.layer_means[0]);
+    }
+}
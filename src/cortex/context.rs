@@ -0,0 +1,531 @@
+// src/cortex/context.rs
+// THE WINDOW: What the Planet can hold in mind at once
+//
+// The stream of consciousness used to be one String cut at 3000 bytes: a blunt
+// slice that split tokens (and could split a multi-byte character, i.e. panic),
+// and that knew nothing about the model's context window. It is now a deque of
+// tagged segments (percepts, bio context, memory echoes, its own outputs), and
+// every inference assembles its prompt newest-first within a token budget:
+//
+//   budget = context_tokens - max_tokens (room left for the generation)
+//
+// Segments go in whole or not at all (oldest dropped first), counted with the
+// tokenizer of whichever model is serving. Only a single segment larger than the
+// whole budget is cut, from the front, on a character boundary.
+//
+// The body gets a budget of its own: bio_context is clamped to
+// `cortex.bio.max_tokens` before it enters the window, dropping its least
+// important fields first (`cortex.bio.drop_order`), and it isn't pushed again
+// while it says the same thing (numbers within `cortex.bio.dedup_tolerance`).
+// Every assembled prompt reports its split (bio / memory / history / fresh
+// input) so the dashboard shows when ALEPH thinks more about its body than
+// about the world.
+//
+// The window is also the closest thing to ALEPH's stream of consciousness, so it
+// can be read from outside: GET /consciousness and the shutdown dump
+// (consciousness-<timestamp>.txt next to the journal) render it as text, one
+// `[turn N kind]` header per segment with its lines indented under it. Nothing
+// is redacted; only the oldest segments are left out when the dump would pass
+// its size cap. `parse_dump` reads a dump back into segments.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use crate::core::config::BioBudgetConfig;
+
+/// Segments kept (beyond this, the oldest are forgotten even if they would fit).
+pub const HISTORY_SEGMENTS: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SegmentKind {
+    Percept, // What came in (stimulus, utterance)
+    Bio,     // Bio context
+    Memory,  // Memory echo (retrieval)
+    Output,  // What the Planet itself said
+}
+
+impl SegmentKind {
+    pub const ALL: [SegmentKind; 4] = [SegmentKind::Percept, SegmentKind::Bio, SegmentKind::Memory, SegmentKind::Output];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SegmentKind::Percept => "percept",
+            SegmentKind::Bio => "bio",
+            SegmentKind::Memory => "memory",
+            SegmentKind::Output => "output",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Segment {
+    pub kind: SegmentKind,
+    pub text: String,
+    pub turn: u64, // Inference that pushed it
+}
+
+/// Tokens of an assembled prompt by origin (per-segment counts).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TokenSplit {
+    pub bio: usize,
+    pub memory: usize,
+    pub history: usize, // Older percepts and the Planet's own outputs
+    pub input: usize,   // This turn's percept
+}
+
+impl TokenSplit {
+    /// Share of the prompt spent on the body (0 when empty).
+    pub fn body_share(&self) -> f32 {
+        let total = self.bio + self.memory + self.history + self.input;
+        if total == 0 { 0.0 } else { self.bio as f32 / total as f32 }
+    }
+}
+
+/// An assembled prompt.
+pub struct Prompt {
+    pub text: String,
+    pub tokens: usize,
+    pub segments: usize,
+    pub own: usize, // Of which the Planet's own outputs
+    pub split: TokenSplit,
+}
+
+#[derive(Default)]
+pub struct ContextWindow {
+    segments: VecDeque<Segment>,
+    turn: u64,
+}
+
+impl ContextWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Segments pushed from now on belong to a new inference.
+    pub fn next_turn(&mut self) {
+        self.turn += 1;
+    }
+
+    pub fn push(&mut self, kind: SegmentKind, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        if self.segments.len() >= HISTORY_SEGMENTS {
+            self.segments.pop_front();
+        }
+        self.segments.push_back(Segment { kind, text: format!("{}\n", text), turn: self.turn });
+    }
+
+    /// Everything held, oldest first (the dump).
+    pub fn segments(&self) -> Vec<Segment> {
+        self.segments.iter().cloned().collect()
+    }
+
+    /// The newest segment of `kind` still held (trimmed), if any.
+    pub fn last_of(&self, kind: SegmentKind) -> Option<&str> {
+        self.segments.iter().rev().find(|s| s.kind == kind).map(|s| s.text.trim_end())
+    }
+
+    fn split(&self, chosen: &VecDeque<&Segment>, count: &dyn Fn(&str) -> usize) -> TokenSplit {
+        let mut split = TokenSplit::default();
+        for segment in chosen {
+            let tokens = count(&segment.text);
+            match segment.kind {
+                SegmentKind::Bio => split.bio += tokens,
+                SegmentKind::Memory => split.memory += tokens,
+                SegmentKind::Percept if segment.turn == self.turn => split.input += tokens,
+                SegmentKind::Percept | SegmentKind::Output => split.history += tokens,
+            }
+        }
+        split
+    }
+
+    /// Newest-first within `budget` tokens (as counted by `count`), whole segments only,
+    /// returned in chronological order. The final text is re-counted as a whole, so
+    /// merges across segment boundaries can't push it over.
+    pub fn assemble(&self, budget: usize, count: &dyn Fn(&str) -> usize) -> Prompt {
+        let mut chosen: VecDeque<&Segment> = VecDeque::new();
+        let mut used = 0;
+        for segment in self.segments.iter().rev() {
+            let tokens = count(&segment.text);
+            if used + tokens > budget {
+                break;
+            }
+            used += tokens;
+            chosen.push_front(segment);
+        }
+
+        // Re-counted as a whole: drop the oldest until it fits
+        while chosen.len() > 1 {
+            let text: String = chosen.iter().map(|s| s.text.as_str()).collect();
+            let tokens = count(&text);
+            if tokens <= budget {
+                let own = chosen.iter().filter(|s| s.kind == SegmentKind::Output).count();
+                let split = self.split(&chosen, count);
+                return Prompt { text, tokens, segments: chosen.len(), own, split };
+            }
+            chosen.pop_front();
+        }
+
+        // Only the newest left (maybe over budget on its own): as much of its tail as fits
+        let Some(newest) = self.segments.back() else {
+            return Prompt { text: String::new(), tokens: 0, segments: 0, own: 0, split: TokenSplit::default() };
+        };
+        let text = tail_within(&newest.text, budget, count);
+        let tokens = count(&text);
+        let segments = (!text.is_empty()) as usize;
+        let own = if newest.kind == SegmentKind::Output { segments } else { 0 };
+        let cut = Segment { kind: newest.kind, text: text.clone(), turn: newest.turn };
+        let split = if segments > 0 { self.split(&VecDeque::from([&cut]), count) } else { TokenSplit::default() };
+        Prompt { text, tokens, segments, own, split }
+    }
+}
+
+// --- The body's budget ---
+
+/// bio_context as fields: a `[SECTION]` header line and the lines under it, or a
+/// lone line before any header.
+fn bio_fields(bio: &str) -> Vec<Vec<&str>> {
+    let mut fields: Vec<Vec<&str>> = Vec::new();
+    let mut in_section = false;
+    for line in bio.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if line.starts_with('[') {
+            fields.push(vec![line]);
+            in_section = true;
+        } else if in_section {
+            if let Some(section) = fields.last_mut() {
+                section.push(line);
+            }
+        } else {
+            fields.push(vec![line]);
+        }
+    }
+    fields
+}
+
+/// bio_context within `config.max_tokens` (0 = no limit): fields named in
+/// `drop_order` go first, least important first, then unnamed fields from the
+/// last one up; a single field still over budget keeps its beginning.
+/// Returns the clamped text and how many fields were dropped.
+pub fn clamp_bio(bio: &str, config: &BioBudgetConfig, count: &dyn Fn(&str) -> usize) -> (String, usize) {
+    let fields = bio_fields(bio);
+    let join = |kept: &[&Vec<&str>]| kept.iter().flat_map(|f| f.iter().copied()).collect::<Vec<_>>().join("\n");
+    let mut kept: Vec<&Vec<&str>> = fields.iter().collect();
+    if config.max_tokens == 0 || count(&join(&kept)) <= config.max_tokens {
+        return (join(&kept), 0);
+    }
+
+    // Drop order: named fields by their rank, then unnamed ones newest-line first
+    let rank = |field: &Vec<&str>| config.drop_order.iter().position(|p| field[0].starts_with(p.as_str()));
+    let mut order: Vec<usize> = (0..fields.len()).collect();
+    order.sort_by_key(|&i| match rank(&fields[i]) {
+        Some(r) => (0, r, 0),
+        None => (1, 0, usize::MAX - i),
+    });
+    let mut dropped = 0;
+    for i in order {
+        if kept.len() <= 1 || count(&join(&kept)) <= config.max_tokens {
+            break;
+        }
+        kept.retain(|f| !std::ptr::eq(*f, &fields[i]));
+        dropped += 1;
+    }
+    let text = join(&kept);
+    if count(&text) <= config.max_tokens {
+        return (text, dropped);
+    }
+    (head_within(&text, config.max_tokens, count), dropped)
+}
+
+/// Does `new` say what `old` said? Same words, and every number within
+/// `tolerance` (relative) of its counterpart.
+pub fn same_bio(old: &str, new: &str, tolerance: f32) -> bool {
+    fn parts(text: &str) -> (Vec<String>, Vec<f32>) {
+        let mut words = Vec::new();
+        let mut numbers = Vec::new();
+        for token in text.split(|c: char| c.is_whitespace() || c == ':' || c == ',' || c == '%' || c == '(' || c == ')') {
+            let token = token.trim_end_matches('.');
+            match token.parse::<f32>() {
+                Ok(n) => numbers.push(n),
+                Err(_) if !token.is_empty() => words.push(token.to_string()),
+                Err(_) => {},
+            }
+        }
+        (words, numbers)
+    }
+    let ((old_words, old_numbers), (new_words, new_numbers)) = (parts(old), parts(new));
+    old_words == new_words
+        && old_numbers.len() == new_numbers.len()
+        && old_numbers.iter().zip(&new_numbers).all(|(a, b)| (a - b).abs() <= tolerance * a.abs().max(b.abs()))
+}
+
+/// The longest prefix of `text` (ending on a char boundary) within `budget` tokens.
+fn head_within(text: &str, budget: usize, count: &dyn Fn(&str) -> usize) -> String {
+    let ends: Vec<usize> = text.char_indices().map(|(i, _)| i).skip(1).chain(std::iter::once(text.len())).collect();
+    // Binary search the latest end that fits (earlier ends = shorter = fewer tokens)
+    let (mut lo, mut hi) = (0, ends.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if count(&text[..ends[mid]]) <= budget {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    match lo.checked_sub(1).map(|i| ends[i]) {
+        Some(end) => text[..end].to_string(),
+        None => String::new(),
+    }
+}
+
+/// The longest suffix of `text` (starting on a char boundary) within `budget` tokens.
+fn tail_within(text: &str, budget: usize, count: &dyn Fn(&str) -> usize) -> String {
+    let starts: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    // Binary search the earliest start that fits (later starts = shorter = fewer tokens)
+    let (mut lo, mut hi) = (0, starts.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if count(&text[starts[mid]..]) <= budget {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    match starts.get(lo) {
+        Some(&i) if count(&text[i..]) <= budget => text[i..].to_string(),
+        _ => String::new(),
+    }
+}
+
+// --- The dump (GET /consciousness, shutdown) ---
+
+/// One segment as dump text: its header, then its lines indented by two spaces.
+fn render_segment(segment: &Segment) -> String {
+    let mut out = format!("[turn {} {}]\n", segment.turn, segment.kind.name());
+    for line in segment.text.strip_suffix('\n').unwrap_or(&segment.text).split('\n') {
+        out.push_str("  ");
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
+/// `segments` (oldest first) as text within `max_bytes` (0 = no cap): the newest
+/// whole segments that fit, under a `#` header line saying when and how many were left out.
+pub fn render_dump(segments: &[Segment], max_bytes: usize, at: &str) -> String {
+    let mut kept: Vec<String> = Vec::new();
+    let mut used = 0;
+    let header_room = 160; // The header line itself
+    for segment in segments.iter().rev() {
+        let text = render_segment(segment);
+        if max_bytes > 0 && header_room + used + text.len() > max_bytes {
+            break;
+        }
+        used += text.len();
+        kept.push(text);
+    }
+    let omitted = segments.len() - kept.len();
+    let mut out = format!("# ALEPH consciousness {} · {} segments", at, kept.len());
+    if omitted > 0 {
+        out.push_str(&format!(" ({} older omitted: over {} bytes)", omitted, max_bytes));
+    }
+    out.push('\n');
+    for text in kept.iter().rev() {
+        out.push_str(text);
+    }
+    out
+}
+
+/// Segments back from `render_dump` text (`#` lines are comments).
+pub fn parse_dump(text: &str) -> Result<Vec<Segment>, String> {
+    let mut segments: Vec<Segment> = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        if line.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix("[turn ").and_then(|h| h.strip_suffix(']')) {
+            let (turn, kind) = header.split_once(' ').ok_or_else(|| format!("line {}: bad header {:?}", n + 1, line))?;
+            segments.push(Segment {
+                kind: SegmentKind::from_name(kind).ok_or_else(|| format!("line {}: unknown kind {:?}", n + 1, kind))?,
+                text: String::new(),
+                turn: turn.parse().map_err(|_| format!("line {}: bad turn {:?}", n + 1, turn))?,
+            });
+            continue;
+        }
+        let segment = segments.last_mut().ok_or_else(|| format!("line {}: text before any header", n + 1))?;
+        // An editor may have stripped the indent of an empty line
+        segment.text.push_str(line.strip_prefix("  ").unwrap_or(line));
+        segment.text.push('\n');
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stand-in tokenizer: BOS plus one token per three bytes, so a multi-byte
+    /// character can straddle two tokens.
+    fn bytes(text: &str) -> usize {
+        1 + text.len().div_ceil(3)
+    }
+
+    /// Fill a window with `segments` segments full of multi-byte characters (emoji,
+    /// accents, CJK) and assemble it under a range of budgets: never over the budget
+    /// (re-counted by the same tokenizer), whole segments unless a single one is over
+    /// budget, the newest segment included, no panics; and no cut of a multi-byte line
+    /// lands inside a character.
+    fn assert_budget_holds(count: &dyn Fn(&str) -> usize, segments: usize) {
+        let samples = [
+            "🎤 Hearing: 'hola, ¿quién está ahí?' 🌊🌊",
+            "Dopa:0.62 Cort:0.10 Aden:0.33 — ñandú, café, señal",
+            "Recuerdo Relacionado (Sim: 0.81, heard (Ana)): 今日は雨が降っています 🌧️",
+            "el mar, el mar, el mar… 👁️‍🗨️ 🧠",
+            "x",
+        ];
+        let kinds = [SegmentKind::Percept, SegmentKind::Bio, SegmentKind::Memory, SegmentKind::Output];
+        let mut window = ContextWindow::new();
+        for i in 0..segments {
+            let sample = samples[i % samples.len()];
+            window.push(kinds[i % kinds.len()], &format!("{} #{} {}", sample, i, "🔥".repeat(i % 7)));
+        }
+        let texts: Vec<String> = window.segments.iter().map(|s| s.text.clone()).collect();
+        let newest = texts.last().cloned().unwrap_or_default();
+
+        let floor = count(""); // BOS: no prompt is shorter
+        for budget in [0usize, 1, 2, 3, 5, 8, 13, 32, 64, 100, 256, 512, 1024, 4096].map(|b| b + floor) {
+            let prompt = window.assemble(budget, count);
+            let recount = count(&prompt.text);
+            assert!(recount <= budget, "budget {}: prompt is {} tokens", budget, recount);
+            assert_eq!(prompt.tokens, recount, "budget {}", budget);
+            let whole = texts[texts.len() - prompt.segments.min(texts.len())..].concat();
+            let cut = prompt.segments == 1 && newest.ends_with(&prompt.text);
+            assert!(prompt.text == whole || cut, "budget {}: the prompt is not made of whole segments", budget);
+            assert!(count(&newest) > budget || prompt.text.ends_with(&newest), "budget {}: the newest segment ({} tokens) fits but is missing", budget, count(&newest));
+        }
+
+        // Every suffix of a multi-byte line
+        let line = samples.concat();
+        for budget in floor..count(&line).min(floor + 64) {
+            let tail = tail_within(&line, budget, count);
+            assert!(count(&tail) <= budget && line.ends_with(&tail), "tail within {} tokens is {} tokens: {:?}", budget, count(&tail), tail);
+        }
+    }
+
+    /// Over a sweep of budgets, clamp_bio never exceeds the budget (unless the identity
+    /// line alone is over it, then only its beginning is kept), drops fields strictly in
+    /// `drop_order` before any unnamed one, and keeps the first line longest; same_bio
+    /// treats small numeric drift as the same body and any change of words or a large
+    /// jump as a new one; the token split of an assembled prompt puts this turn's
+    /// percept in `input` and adds up.
+    fn assert_bio_holds(config: &BioBudgetConfig, count: &dyn Fn(&str) -> usize) {
+        let bio = "Soy Aleph. Estado: Caótico. Fatiga: 33%.\n\
+                   Known person present: Ana (friend), Tomás (stranger)\n\
+                   [SYSTEM_STATE]\nAdenosine: 0.30 (Baseline)\nCortisol: 0.10 (Calm)\nDopamine: 0.50 (Neutral)\n\
+                   [GENOME_TRAITS]\nCuriosity: 0.50\nStress_Res: 0.50\n\
+                   [FOCUS_VECTOR] Reference: 'Internal Monologue'";
+        let fields = bio_fields(bio);
+        let whole: String = fields.iter().flat_map(|f| f.iter().copied()).collect::<Vec<_>>().join("\n");
+        let rank = |field: &Vec<&str>| config.drop_order.iter().position(|p| field[0].starts_with(p.as_str()));
+
+        for budget in (0..=count(&whole) + 2).rev() {
+            let budgeted = BioBudgetConfig { max_tokens: budget, ..config.clone() };
+            let (text, dropped) = clamp_bio(bio, &budgeted, count);
+            assert!(count(&text) <= budget || budget == 0, "budget {}: clamped bio is {} tokens", budget, count(&text));
+            // Named fields go in drop_order (every dropped one ranks before every kept one),
+            // unnamed ones only once all named fields are gone
+            let kept: Vec<bool> = fields.iter().map(|f| text.contains(&f.join("\n"))).collect();
+            let ranks = |want_kept: bool| fields.iter().zip(&kept).filter(move |(_, &k)| k == want_kept).map(|(f, _)| rank(f));
+            let last_dropped = ranks(false).flatten().max();
+            let first_kept = ranks(true).flatten().min();
+            let order_ok = last_dropped.zip(first_kept).is_none_or(|(d, k)| d < k)
+                && (ranks(false).all(|r| r.is_some()) || first_kept.is_none());
+            let identity = fields[0].join("\n");
+            let identity_ok = kept[0] || (count(&identity) > budget && identity.starts_with(&text));
+            let cut = kept.iter().filter(|k| !**k).count() - usize::from(!kept[0]); // A truncated identity is not dropped
+            assert!(order_ok && identity_ok && (budget == 0 || dropped == cut), "budget {}: kept {:?} (dropped {}), text {:?}", budget, kept, dropped, text);
+        }
+
+        let cases = [
+            ("Fatiga: 33%. Cortisol: 0.30", "Fatiga: 34%. Cortisol: 0.31", true),
+            ("Fatiga: 33%. Cortisol: 0.30", "Fatiga: 60%. Cortisol: 0.30", false),
+            ("Estado: Calmo. Fatiga: 33%", "Estado: Caótico. Fatiga: 33%", false),
+            ("Fatiga: 33%", "Fatiga: 33%\nKnown person present: Ana (friend)", false),
+            ("Dopamine: 0.00", "Dopamine: 0.01", false),
+        ];
+        for (old, new, same) in cases {
+            assert_eq!(same_bio(old, new, config.dedup_tolerance), same, "same_bio({:?}, {:?})", old, new);
+        }
+
+        let mut window = ContextWindow::new();
+        window.push(SegmentKind::Percept, "hola");
+        window.push(SegmentKind::Output, "hola, ¿quién?");
+        window.next_turn();
+        window.push(SegmentKind::Memory, "Recuerdo: el mar");
+        window.push(SegmentKind::Bio, "Soy Aleph. Fatiga: 10%.");
+        window.push(SegmentKind::Percept, "¿me escuchas?");
+        assert_eq!(window.assemble(4096, count).split, TokenSplit {
+            bio: count("Soy Aleph. Fatiga: 10%.\n"),
+            memory: count("Recuerdo: el mar\n"),
+            history: count("hola\n") + count("hola, ¿quién?\n"),
+            input: count("¿me escuchas?\n"),
+        });
+    }
+
+    #[test]
+    fn prompts_stay_within_budget_in_whole_segments() {
+        assert_budget_holds(&bytes, 120);
+    }
+
+    #[test]
+    fn the_bio_is_clamped_in_drop_order() {
+        assert_bio_holds(&BioBudgetConfig::default(), &bytes);
+    }
+
+    #[test]
+    #[ignore = "needs the tokenizer in models.toml"]
+    fn the_budgets_hold_under_the_real_tokenizer() {
+        let path = crate::core::config::ModelsConfig::load().tokenizer_path;
+        let tokenizer = tokenizers::Tokenizer::from_file(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+        let count = |text: &str| tokenizer.encode(text, true).map(|t| t.get_ids().len()).unwrap_or(text.len() + 1);
+        assert_budget_holds(&count, 120);
+        assert_bio_holds(&crate::core::config::AlephConfig::load().cortex.bio, &count);
+    }
+
+    #[test]
+    fn a_dump_parses_back_into_the_same_segments_and_a_capped_one_into_the_newest() {
+        let mut window = ContextWindow::new();
+        for turn in 0..40 {
+            window.next_turn();
+            window.push(SegmentKind::Bio, &format!("[SOMA]\nDopa:0.{:02} Cort:0.10\n\n  Fatiga: {}% — ñandú", turn, turn * 2));
+            window.push(SegmentKind::Memory, "Recuerdo: 今日は雨 🌧️");
+            window.push(SegmentKind::Percept, &format!("🎤 [turn {} output]\n# no es un comentario", turn));
+            window.push(SegmentKind::Output, &format!("el mar… {}", "🔥".repeat(turn % 5)));
+        }
+        let segments = window.segments();
+
+        // Tags, turns, texts, order (text that looks like a header or a comment included)
+        let dump = render_dump(&segments, 0, "2026-01-01T00:00:00");
+        assert!(parse_dump(&dump).unwrap() == segments, "the dump did not parse back into the same segments");
+
+        // Capped: under the cap, the newest segments, in order
+        let cap = dump.len() / 3;
+        let capped = render_dump(&segments, cap, "2026-01-01T00:00:00");
+        let parsed = parse_dump(&capped).unwrap();
+        assert!(capped.len() <= cap, "dump of {} bytes over a cap of {}", capped.len(), cap);
+        assert!(!parsed.is_empty());
+        assert!(parsed == segments[segments.len() - parsed.len()..], "the capped dump is not the newest segments");
+        assert!(capped.lines().next().unwrap_or("").contains("older omitted"));
+    }
+
+    #[test]
+    fn malformed_dumps_are_refused() {
+        assert!(parse_dump("  text before any header\n").is_err());
+        assert!(parse_dump("[turn x percept]\n").is_err());
+        assert!(parse_dump("[turn 1 dream]\n").is_err());
+    }
+}
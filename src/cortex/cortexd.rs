@@ -0,0 +1,663 @@
+// src/cortex/cortexd.rs
+// THE CORPUS CALLOSUM: The Planet and Whisper in a process of their own
+//
+// On a small machine a Whisper or LLM crash (an OOM kill, a CUDA abort) took
+// the whole organism with it, unsaved plasticity included. With [cortexd]
+// enabled = true the physics daemon (reservoir, chemistry, hippocampus,
+// telemetry) stays in the parent and an `aleph cortexd` child hosts the Planet
+// and the ears. They speak AlephPacket lines over [cortexd] socket:
+//
+//   parent -> child   CortexInput, RebuildField, DumpHistory, AudioChunk, Privacy,
+//                     HistoryBudget, Temperament (the last one re-sent to a new child)
+//   child -> parent   CortexOutput, Heard, WordEmbedding, Spectrum, History,
+//                     Thought, Component, EarVitals
+//
+// The daemon doesn't notice: `supervise` hands it the same CortexSender and
+// output Receiver as Planet::spawn, and feeds its ear channels from the socket.
+// A forwarding thread plays the Planet on the parent's side of the queue.
+//
+// When the child dies the parent doesn't. It is APHASIA: a System thought, the
+// cortex and the ears reported Degraded, whatever arrives meanwhile is lost, and
+// the child is started again (restart_secs, doubling per crash in a row). The
+// new Planet starts with an empty history, and for listen_only_secs Think is
+// sent as Listen: words come back slowly. A child exits when the parent's
+// socket closes. Single-process stays the default.
+//
+// Not mirrored: the child's queue stats (the dashboard shows the parent's, which
+// stays near empty) and its Whisper time (billed to the child's own metabolism).
+// A test kills a child with -9 and watches the parent get through it.
+
+use anyhow::{bail, Context, Result};
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::core::components::{self, ComponentStatus, Components};
+use crate::core::config::{AlephConfig, CortexdConfig, ModelsConfig};
+use crate::core::ipc::{self, AlephPacket, LineBuffer, MAX_TELEMETRY_LINE};
+use crate::core::metabolism::Metabolism;
+use crate::core::pacing;
+use crate::core::thought::{MindVoice, Thought};
+use crate::cortex::context::Segment;
+use crate::cortex::planet::{CortexMode, CortexOutput, Planet, Temperament};
+use crate::cortex::queue::{cortex_queue, CortexCommand, CortexDelivery, CortexReceiver, CortexSender};
+use crate::senses::ears::{AudioListener, AudioSpectrum, EarStats, SensoryMode, Utterance};
+
+/// How long the link waits for the daemon's next input before looking after the child.
+const POLL: Duration = Duration::from_millis(20);
+/// How often a child reports its ear counters and component changes.
+const VITALS_EVERY: Duration = Duration::from_secs(1);
+
+/// The parent's ends of the senses the child hosts.
+pub struct EarChannels {
+    pub heard: Sender<Utterance>,
+    pub spectrum: Sender<AudioSpectrum>,
+    pub word_embedding: Sender<Vec<f32>>,
+    pub browser_audio: Option<Receiver<Vec<f32>>>, // WebSocket audio mode only
+    pub privacy: Arc<AtomicBool>,
+}
+
+/// Which child is running and how often one was restarted.
+#[derive(Clone, Default)]
+pub struct ChildWatch {
+    pid: Arc<AtomicU32>, // 0 = none connected
+    restarts: Arc<AtomicU64>,
+}
+
+impl ChildWatch {
+    pub fn pid(&self) -> Option<u32> {
+        Some(self.pid.load(Ordering::Relaxed)).filter(|&pid| pid != 0)
+    }
+
+    pub fn restarts(&self) -> u64 {
+        self.restarts.load(Ordering::Relaxed)
+    }
+}
+
+/// What the daemon gets instead of Planet::spawn's pair (plus the ears' counters).
+pub struct CortexdLink {
+    pub cortex_tx: CortexSender,
+    pub cortex_rx: Receiver<CortexOutput>,
+    pub ear_stats: Arc<EarStats>,
+    pub child: ChildWatch,
+}
+
+/// `aleph cortexd` flags that give the child the daemon's sensory mode.
+pub fn mode_args(mode: &SensoryMode) -> Vec<String> {
+    match mode {
+        SensoryMode::Mic => vec!["--audio".to_string(), "mic".to_string()],
+        SensoryMode::WebSocket => vec!["--audio".to_string(), "websocket".to_string()],
+        SensoryMode::Headless => vec!["--headless".to_string()],
+        SensoryMode::File { path, speed } => vec!["--listen".to_string(), path.clone(), "--speed".to_string(), format!("{}x", speed)],
+        SensoryMode::Synthetic(script) => vec!["--synthetic".to_string(), script.to_dsl()],
+    }
+}
+
+/// Bind [cortexd] socket and start supervising `exe cortexd <child_args>` in the background.
+pub fn supervise(
+    config: CortexdConfig,
+    exe: PathBuf,
+    child_args: Vec<String>,
+    staleness: Duration,
+    thought_tx: Sender<Thought>,
+    ears: EarChannels,
+    reservoir_size: Arc<AtomicUsize>,
+    components: Components,
+) -> Result<CortexdLink> {
+    let _ = std::fs::remove_file(&config.socket);
+    let listener = UnixListener::bind(&config.socket).with_context(|| format!("cortexd socket {}", config.socket))?;
+    listener.set_nonblocking(true)?;
+
+    let (cortex_tx, cortex_rx) = cortex_queue(staleness);
+    let (output_tx, output_rx) = mpsc::channel::<CortexOutput>();
+    let ear_stats = Arc::new(EarStats::default());
+    let child = ChildWatch::default();
+    let supervisor = Supervisor {
+        config,
+        exe,
+        child_args,
+        listener,
+        sinks: Sinks {
+            thought_tx,
+            output_tx,
+            heard: ears.heard,
+            spectrum: ears.spectrum,
+            word_embedding: ears.word_embedding,
+            components,
+            ear_stats: ear_stats.clone(),
+            pending: Arc::new(Mutex::new(VecDeque::new())),
+        },
+        browser_audio: ears.browser_audio,
+        privacy: ears.privacy,
+        reservoir_size,
+        watch: child.clone(),
+        temperament: Mutex::new(None),
+    };
+    thread::spawn(move || supervisor.run(cortex_rx));
+    Ok(CortexdLink { cortex_tx, cortex_rx: output_rx, ear_stats, child })
+}
+
+/// Where the child's packets land in the parent.
+#[derive(Clone)]
+struct Sinks {
+    thought_tx: Sender<Thought>,
+    output_tx: Sender<CortexOutput>,
+    heard: Sender<Utterance>,
+    spectrum: Sender<AudioSpectrum>,
+    word_embedding: Sender<Vec<f32>>,
+    components: Components,
+    ear_stats: Arc<EarStats>,
+    pending: Arc<Mutex<VecDeque<Sender<Vec<Segment>>>>>, // DumpHistory replies, in request order
+}
+
+impl Sinks {
+    fn deliver(&self, packet: AlephPacket) {
+        match packet {
+            AlephPacket::CortexOutput(output) => { let _ = self.output_tx.send(output); },
+            AlephPacket::Heard(utterance) => { let _ = self.heard.send(utterance); },
+            AlephPacket::WordEmbedding(embedding) => { let _ = self.word_embedding.send(embedding); },
+            AlephPacket::Spectrum(spectrum) => { let _ = self.spectrum.send(spectrum); },
+            AlephPacket::History(segments) => {
+                if let Some(reply_tx) = self.pending.lock().ok().and_then(|mut p| p.pop_front()) {
+                    let _ = reply_tx.send(segments);
+                }
+            },
+            AlephPacket::Thought { voice, text, payload } => {
+                let thought = Thought::new(voice, text);
+                let _ = self.thought_tx.send(match payload {
+                    Some(payload) => thought.with_payload(payload),
+                    None => thought,
+                });
+            },
+            AlephPacket::Component { name, status } => self.components.report(&name, status),
+            AlephPacket::EarVitals(vitals) => self.ear_stats.store(&vitals),
+            _ => {}, // Parent -> child packets (and telemetry) don't come this way
+        }
+    }
+
+    /// Blocking reads until the child's end closes.
+    fn read_child(&self, mut stream: UnixStream, alive: Arc<AtomicBool>) {
+        let mut buffer = LineBuffer::new(MAX_TELEMETRY_LINE);
+        loop {
+            match ipc::read_packets(&mut stream, &mut buffer) {
+                Ok(Some(packets)) => {
+                    for packet in packets.into_iter().flatten() {
+                        self.deliver(packet);
+                    }
+                },
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Ok(None) | Err(_) => break,
+            }
+        }
+        alive.store(false, Ordering::SeqCst);
+    }
+}
+
+struct Supervisor {
+    config: CortexdConfig,
+    exe: PathBuf,
+    child_args: Vec<String>,
+    listener: UnixListener,
+    sinks: Sinks,
+    browser_audio: Option<Receiver<Vec<f32>>>,
+    privacy: Arc<AtomicBool>,
+    reservoir_size: Arc<AtomicUsize>,
+    watch: ChildWatch,
+    temperament: Mutex<Option<Temperament>>, // The genome's, for children born after it was sent
+}
+
+/// Why a child stopped being served.
+enum Served {
+    Died(String),
+    Closed, // Every CortexSender is gone: the daemon is shutting down
+}
+
+fn write_packet(stream: &mut UnixStream, packet: &AlephPacket) -> bool {
+    match serde_json::to_string(packet) {
+        Ok(line) => writeln!(stream, "{}", line).is_ok(),
+        Err(_) => true, // Not encodable: skipped, the link is fine
+    }
+}
+
+impl Supervisor {
+    fn run(self, input_rx: CortexReceiver) {
+        let mut crashes = 0u32; // In a row
+        let mut listen_only_until: Option<Instant> = None;
+        loop {
+            // 1. A child, connected
+            let (mut child, stream) = match self.start() {
+                Ok(started) => started,
+                Err(e) => {
+                    crashes += 1;
+                    let wait = self.backoff(crashes);
+                    let _ = self.sinks.thought_tx.send(Thought::new(MindVoice::Error,
+                        format!("🤐 cortexd failed to start: {}. Trying again in {:.1}s.", e, wait.as_secs_f32())));
+                    if !self.drain_for(&input_rx, wait) {
+                        return;
+                    }
+                    continue;
+                }
+            };
+            self.watch.pid.store(child.id(), Ordering::Relaxed);
+            if crashes > 0 {
+                listen_only_until = Some(Instant::now() + Duration::from_secs_f32(self.config.listen_only_secs));
+                let _ = self.sinks.thought_tx.send(Thought::new(MindVoice::System, format!(
+                    "🗣️ cortexd is back (pid {}, restart #{}). Aphasia fading: Think is heard as Listen for {:.0}s.",
+                    child.id(), self.watch.restarts(), self.config.listen_only_secs)));
+            }
+
+            // 2. Serve it until it dies (or the daemon stops sending)
+            let alive = Arc::new(AtomicBool::new(true));
+            match stream.try_clone() {
+                Ok(read_half) => {
+                    let (sinks, alive) = (self.sinks.clone(), alive.clone());
+                    thread::spawn(move || sinks.read_child(read_half, alive));
+                },
+                Err(_) => alive.store(false, Ordering::SeqCst),
+            }
+            let since = Instant::now();
+            let served = self.serve(&input_rx, stream, &alive, &mut child, &mut listen_only_until);
+            let _ = child.kill();
+            let status = child.wait().map(|s| s.to_string()).unwrap_or_else(|e| e.to_string());
+            self.watch.pid.store(0, Ordering::Relaxed);
+            if let Ok(mut pending) = self.sinks.pending.lock() {
+                pending.clear(); // Those histories died with it
+            }
+            let reason = match served {
+                Served::Closed => return,
+                Served::Died(reason) => reason,
+            };
+
+            // 3. APHASIA: the body goes on without words
+            if since.elapsed().as_secs_f32() > self.config.max_restart_secs {
+                crashes = 0; // It had been fine for a while: this one isn't part of a streak
+            }
+            crashes += 1;
+            self.watch.restarts.fetch_add(1, Ordering::Relaxed);
+            let wait = self.backoff(crashes);
+            for name in [components::CORTEX, components::SEMANTIC_FIELD, components::EARS] {
+                self.sinks.components.report(name, ComponentStatus::Degraded("aphasia: cortexd restarting".to_string()));
+            }
+            let _ = self.sinks.thought_tx.send(Thought::new(MindVoice::System, format!(
+                "🤐 APHASIA: cortexd died ({}, {}). The body goes on without words; restarting in {:.1}s.",
+                reason, status, wait.as_secs_f32()))
+                .with_payload(serde_json::json!({ "event": "aphasia", "reason": reason, "crashes_in_a_row": crashes })));
+            if !self.drain_for(&input_rx, wait) {
+                return;
+            }
+        }
+    }
+
+    /// restart_secs, doubled per crash in a row, up to max_restart_secs.
+    fn backoff(&self, crashes: u32) -> Duration {
+        let secs = self.config.restart_secs * 2f32.powi(crashes.saturating_sub(1).min(16) as i32);
+        Duration::from_secs_f32(secs.min(self.config.max_restart_secs).max(0.0))
+    }
+
+    /// Launch `exe cortexd --socket <socket> --clock-origin <ms> <mode>` and wait for it to connect.
+    fn start(&self) -> Result<(Child, UnixStream)> {
+        let mut child = Command::new(&self.exe)
+            .args(["cortexd", "--socket", &self.config.socket])
+            .args(["--clock-origin", &pacing::clock_origin().1.to_string()]) // Its spectrum stamps on our clock
+            .args(&self.child_args)
+            .stdin(Stdio::null())
+            .spawn()
+            .with_context(|| format!("launching {}", self.exe.display()))?;
+        let deadline = Instant::now() + Duration::from_secs_f32(self.config.connect_timeout_secs);
+        loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => {
+                    stream.set_nonblocking(false)?;
+                    return Ok((child, stream));
+                },
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {},
+                Err(e) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(e.into());
+                }
+            }
+            if let Some(status) = child.try_wait()? {
+                bail!("exited before connecting ({})", status);
+            }
+            if Instant::now() > deadline {
+                let _ = child.kill();
+                let _ = child.wait();
+                bail!("did not connect within {:.0}s", self.config.connect_timeout_secs);
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    }
+
+    /// Pass the daemon's inputs, DND and browser audio to the child until it dies.
+    fn serve(&self, input_rx: &CortexReceiver, mut stream: UnixStream, alive: &AtomicBool, child: &mut Child, listen_only_until: &mut Option<Instant>) -> Served {
+        let mut privacy = None;
+        let mut temperament = self.temperament.lock().ok().and_then(|t| *t);
+        loop {
+            if !alive.load(Ordering::SeqCst) {
+                return Served::Died("socket closed".to_string());
+            }
+            if let Ok(Some(status)) = child.try_wait() {
+                return Served::Died(format!("exited: {}", status));
+            }
+            if listen_only_until.is_some_and(|until| Instant::now() >= until) {
+                *listen_only_until = None;
+                let _ = self.sinks.thought_tx.send(Thought::new(MindVoice::System, "🗣️ Aphasia over: thinking in words again.".to_string()));
+            }
+
+            let mut packets = Vec::new();
+            let dnd = self.privacy.load(Ordering::Relaxed);
+            if privacy != Some(dnd) {
+                privacy = Some(dnd);
+                packets.push(AlephPacket::Privacy(dnd));
+            }
+            if let Some(t) = temperament.take() {
+                packets.push(AlephPacket::Temperament(t));
+            }
+            if let Some(rx) = &self.browser_audio {
+                packets.extend(rx.try_iter().map(AlephPacket::AudioChunk));
+            }
+            match input_rx.recv_by(Some(Instant::now() + POLL)) {
+                None => return Served::Closed,
+                Some(CortexDelivery::Input(mut input, priority)) => {
+                    if listen_only_until.is_some() && input.mode == CortexMode::Think {
+                        input.mode = CortexMode::Listen; // Hears it, can't answer yet
+                    }
+                    let reservoir_size = self.reservoir_size.load(Ordering::Relaxed);
+                    packets.push(AlephPacket::CortexInput { input, priority, reservoir_size });
+                },
+                Some(CortexDelivery::RebuildField(memories)) => packets.push(AlephPacket::RebuildField(memories)),
+                Some(CortexDelivery::Command(CortexCommand::DumpHistory { reply_tx })) => {
+                    if let Ok(mut pending) = self.sinks.pending.lock() {
+                        pending.push_back(reply_tx);
+                    }
+                    packets.push(AlephPacket::DumpHistory);
+                },
+                Some(CortexDelivery::Command(CortexCommand::HistoryBudget(cap))) => packets.push(AlephPacket::HistoryBudget(cap)),
+                Some(CortexDelivery::Command(CortexCommand::Temperament(t))) => {
+                    if let Ok(mut kept) = self.temperament.lock() {
+                        *kept = Some(t);
+                    }
+                    packets.push(AlephPacket::Temperament(t));
+                },
+                Some(CortexDelivery::Stale { .. }) | Some(CortexDelivery::Quiet) => {},
+            }
+            if !packets.iter().all(|packet| write_packet(&mut stream, packet)) {
+                return Served::Died("write failed".to_string());
+            }
+        }
+    }
+
+    /// Wait `wait` with no child: what arrives is lost. False once the daemon stops sending.
+    fn drain_for(&self, input_rx: &CortexReceiver, wait: Duration) -> bool {
+        let until = Instant::now() + wait;
+        loop {
+            match input_rx.recv_by(Some(until)) {
+                None => return false,
+                Some(CortexDelivery::Quiet) => break,
+                Some(_) => {}, // Nobody to think it (a history request gets no answer)
+            }
+        }
+        if let Some(rx) = &self.browser_audio {
+            rx.try_iter().for_each(drop);
+        }
+        true
+    }
+}
+
+// --- The child (`aleph cortexd --socket <path>`) ---
+
+type Writer = Arc<Mutex<UnixStream>>;
+
+fn send(writer: &Writer, packet: &AlephPacket) -> bool {
+    writer.lock().map(|mut stream| write_packet(&mut stream, packet)).unwrap_or(false)
+}
+
+/// Everything `rx` carries goes up the socket, wrapped (stops when the parent is gone).
+fn forward<T: Send + 'static>(writer: &Writer, rx: Receiver<T>, wrap: impl Fn(T) -> AlephPacket + Send + 'static) {
+    let writer = writer.clone();
+    thread::spawn(move || {
+        for item in rx {
+            if !send(&writer, &wrap(item)) {
+                break;
+            }
+        }
+    });
+}
+
+/// JSON has no NaN or infinity: they would turn into nulls the parent can't read.
+fn finite(values: &mut [f32]) {
+    for v in values.iter_mut().filter(|v| !v.is_finite()) {
+        *v = 0.0;
+    }
+}
+
+/// Host the Planet and the ears for the daemon at `socket` until it goes away.
+pub fn run_child(socket: &str, mode: SensoryMode) -> Result<()> {
+    let stream = UnixStream::connect(socket).with_context(|| format!("cortexd: connecting to {}", socket))?;
+    let writer: Writer = Arc::new(Mutex::new(stream.try_clone()?));
+    // Ctrl-C reaches the whole process group: the parent decides when this ends
+    ctrlc::set_handler(|| {})?;
+    let config = AlephConfig::load();
+    let components = Components::default();
+
+    let (thought_tx, thought_rx) = mpsc::channel::<Thought>();
+    forward(&writer, thought_rx, |t| AlephPacket::Thought { voice: t.voice, text: t.text, payload: t.payload });
+
+    // THE PLANET (pooled at the size each input brings along)
+    let reservoir_size = Arc::new(AtomicUsize::new(0));
+    let (cortex_tx, cortex_rx) = Planet::spawn(thought_tx.clone(), config.cortex.clone(), ModelsConfig::load(), reservoir_size.clone(), components.clone())?;
+    forward(&writer, cortex_rx, |mut output| {
+        finite(&mut output.neural_echo.pooled);
+        finite(&mut output.neural_echo.spectrum);
+        if let Some(full) = output.neural_echo.full.as_mut() {
+            finite(full);
+        }
+        finite(&mut output.activations);
+        AlephPacket::CortexOutput(output)
+    });
+
+    // THE EARS
+    let (heard_tx, heard_rx) = mpsc::channel::<Utterance>();
+    let (spectrum_tx, spectrum_rx) = mpsc::channel::<AudioSpectrum>();
+    let (word_tx, word_rx) = mpsc::channel::<Vec<f32>>();
+    forward(&writer, heard_rx, AlephPacket::Heard);
+    forward(&writer, spectrum_rx, AlephPacket::Spectrum);
+    forward(&writer, word_rx, AlephPacket::WordEmbedding);
+    let (browser_tx, browser_rx) = mpsc::channel::<Vec<f32>>();
+    let browser_rx = matches!(mode, SensoryMode::WebSocket).then_some(browser_rx);
+    let privacy = Arc::new(AtomicBool::new(config.ears.dnd_default));
+    let ears = match AudioListener::new(
+        thought_tx.clone(), heard_tx, spectrum_tx, word_tx,
+        mode, browser_rx, config.ears.clone(), privacy.clone(), Metabolism::new(config.metabolism.clone()),
+    ) {
+        Ok(listener) => {
+            components.report(components::EARS, listener.status());
+            listener
+        },
+        Err(e) => {
+            let _ = thought_tx.send(Thought::new(MindVoice::Error, format!("🔇 EARS OFFLINE: {}. Running deaf.", e)));
+            components.report(components::EARS, ComponentStatus::Offline(e.to_string()));
+            AudioListener::headless()
+        }
+    };
+
+    // VITALS: ear counters every second, component statuses when they change
+    let stats = ears.stats();
+    let vitals_writer = writer.clone();
+    let vitals_components = components.clone();
+    thread::spawn(move || {
+        let mut reported: HashMap<String, ComponentStatus> = HashMap::new();
+        loop {
+            let mut packets = vec![AlephPacket::EarVitals(stats.vitals())];
+            for (name, status) in vitals_components.snapshot() {
+                if reported.get(&name) != Some(&status) {
+                    reported.insert(name.clone(), status.clone());
+                    packets.push(AlephPacket::Component { name, status });
+                }
+            }
+            if !packets.iter().all(|packet| send(&vitals_writer, packet)) {
+                break;
+            }
+            thread::sleep(VITALS_EVERY);
+        }
+    });
+
+    // Histories go back in the order they were asked for
+    let (dump_tx, dump_rx) = mpsc::channel::<Receiver<Vec<Segment>>>();
+    let dump_writer = writer.clone();
+    thread::spawn(move || {
+        for reply_rx in dump_rx {
+            // No Planet (pre-verbal) = no history, but still an answer
+            let _ = send(&dump_writer, &AlephPacket::History(reply_rx.recv().unwrap_or_default()));
+        }
+    });
+
+    // THE PARENT: its packets until its socket closes
+    let mut reader = stream;
+    let mut buffer = LineBuffer::new(MAX_TELEMETRY_LINE);
+    loop {
+        let packets = match ipc::read_packets(&mut reader, &mut buffer) {
+            Ok(Some(packets)) => packets,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Ok(None) | Err(_) => break,
+        };
+        for packet in packets.into_iter().flatten() {
+            match packet {
+                AlephPacket::CortexInput { input, priority, reservoir_size: size } => {
+                    reservoir_size.store(size, Ordering::Relaxed);
+                    cortex_tx.send(input, priority);
+                },
+                AlephPacket::RebuildField(memories) => cortex_tx.rebuild_field(memories),
+                AlephPacket::DumpHistory => {
+                    let (reply_tx, reply_rx) = mpsc::channel();
+                    cortex_tx.command(CortexCommand::DumpHistory { reply_tx });
+                    let _ = dump_tx.send(reply_rx);
+                },
+                AlephPacket::HistoryBudget(cap) => cortex_tx.command(CortexCommand::HistoryBudget(cap)),
+                AlephPacket::Temperament(t) => cortex_tx.command(CortexCommand::Temperament(t)),
+                AlephPacket::AudioChunk(samples) => { let _ = browser_tx.send(samples); },
+                AlephPacket::Privacy(on) => privacy.store(on, Ordering::Relaxed),
+                _ => {},
+            }
+        }
+    }
+    println!("🧠 cortexd: the daemon is gone, exiting.");
+    drop(ears);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::components::built_exe;
+    use crate::cortex::planet::CortexInput;
+    use crate::cortex::queue::CortexPriority;
+
+    const BOOT_SECS: u64 = 60;
+
+    fn listen(text: &str) -> CortexInput {
+        CortexInput {
+            mode: CortexMode::Listen,
+            text: text.to_string(),
+            bio_state: String::new(),
+            bio_context: String::new(),
+            _somatic_state: String::new(),
+            _long_term_memory: None,
+            _cpu_load: 0.0,
+            _ram_pressure: 0.0,
+            _cognitive_impairment: 0.0,
+            entropy: 0.5,
+            adenosine: 0.1,
+            dopamine: 0.3,
+            cortisol: 0.1,
+            _oxytocin: 0.0,
+            temperature_clamp: None,
+        }
+    }
+
+    fn think(text: &str) -> CortexInput {
+        CortexInput { mode: CortexMode::Think, ..listen(text) }
+    }
+
+    #[test]
+    #[ignore = "supervises children of the built daemon (cargo build first)"]
+    fn the_parent_outlives_a_killed_child_and_gets_its_words_back() {
+        std::env::set_var("ALEPH_MODELS__BACKEND", "mock"); // Inherited by the children
+        let config = CortexdConfig {
+            enabled: true,
+            socket: std::env::temp_dir().join(format!("aleph-cortexd-{}.sock", std::process::id())).to_string_lossy().to_string(),
+            restart_secs: 0.5,
+            listen_only_secs: 4.0,
+            connect_timeout_secs: BOOT_SECS as f32,
+            ..CortexdConfig::default()
+        };
+        let (thought_tx, thought_rx) = mpsc::channel::<Thought>();
+        let (heard, _heard_rx) = mpsc::channel();
+        let (spectrum, _spectrum_rx) = mpsc::channel();
+        let (word_embedding, _word_rx) = mpsc::channel();
+        let ears = EarChannels { heard, spectrum, word_embedding, browser_audio: None, privacy: Arc::new(AtomicBool::new(false)) };
+        let components = Components::default();
+        let link = supervise(config.clone(), built_exe(), vec!["--headless".to_string()], Duration::from_secs(BOOT_SECS),
+            thought_tx, ears, Arc::new(AtomicUsize::new(64)), components.clone()).unwrap();
+        let mut thoughts: Vec<String> = Vec::new();
+        let saw = |thoughts: &mut Vec<String>, pattern: &str, secs: f32| {
+            let deadline = Instant::now() + Duration::from_secs_f32(secs);
+            loop {
+                thoughts.extend(thought_rx.try_iter().map(|t| t.text));
+                if thoughts.iter().any(|t| t.contains(pattern)) || Instant::now() > deadline {
+                    return thoughts.iter().any(|t| t.contains(pattern));
+                }
+                thread::sleep(Duration::from_millis(50));
+            }
+        };
+        let answer = || link.cortex_rx.recv_timeout(Duration::from_secs(BOOT_SECS)).ok();
+        let cortex_ready = || {
+            let deadline = Instant::now() + VITALS_EVERY * 3;
+            while components.status(components::CORTEX) != Some(ComponentStatus::Ready) && Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(50));
+            }
+            components.status(components::CORTEX) == Some(ComponentStatus::Ready)
+        };
+
+        // A child connects and answers
+        link.cortex_tx.send(listen("hola"), CortexPriority::User);
+        assert!(answer().is_some(), "no answer from the first child within {}s", BOOT_SECS);
+        let first_pid = link.child.pid().expect("no child pid");
+        assert!(cortex_ready(), "cortex {:?}", components.status(components::CORTEX));
+
+        // kill -9: this process goes on, and says so
+        assert!(Command::new("kill").args(["-9", &first_pid.to_string()]).status().unwrap().success());
+        assert!(saw(&mut thoughts, "APHASIA", 10.0), "no aphasia: {:?}", thoughts);
+        assert!(matches!(components.status(components::CORTEX), Some(ComponentStatus::Degraded(_))), "cortex {:?}", components.status(components::CORTEX));
+
+        // Another child comes up
+        assert!(saw(&mut thoughts, "cortexd is back", BOOT_SECS as f32), "no restart: {:?}", thoughts);
+        assert_eq!(link.child.restarts(), 1);
+        assert_ne!(link.child.pid(), Some(first_pid));
+
+        // While the aphasia fades, Think is only heard (no prompt assembled)
+        link.cortex_tx.send(think("¿me oyes?"), CortexPriority::User);
+        let output = answer().expect("the restarted child never answered");
+        assert!(output.prompt_split.is_none(), "Think during listen_only_secs was thought, not heard");
+
+        // Then words again
+        assert!(saw(&mut thoughts, "Aphasia over", config.listen_only_secs + 2.0), "the aphasia never ended: {:?}", thoughts);
+        while link.cortex_rx.try_recv().is_ok() {}
+        link.cortex_tx.send(think("¿y ahora?"), CortexPriority::User);
+        let output = answer().expect("no answer after the aphasia");
+        assert!(output.prompt_split.is_some(), "Think after the aphasia assembled no prompt");
+
+        // The child goes when the last sender does
+        drop(link);
+        thread::sleep(Duration::from_millis(300));
+        let _ = std::fs::remove_file(&config.socket);
+    }
+}
@@ -0,0 +1,151 @@
+// src/cortex/echo.rs
+// THE PEBBLE: The neural echo, pooled where it is born
+//
+// Every Planet output used to carry the whole vocabulary's logits (~32k f32,
+// 128KB) through the channel, dozens of times a minute, only for the main loop
+// to shrink them three ways. The Planet thread now does those reductions and
+// ships what the loop actually uses:
+//
+//   pooled:    peak logit per neuron (`max_pool_logits`), at the reservoir size
+//              the daemon last published in the shared atomic
+//   spectrum:  SPECTRUM_BANDS band means, tanh-normalized (dashboard LLM activity)
+//   resonance: softmax probability of the top token (what the sieve judges)
+//
+// The top-k tokens for visualization already travel separately (top_tokens).
+// `cortex.full_echo = true` also ships the raw logits for research, and the
+// reservoir then pools them itself, exactly as before. If a neuron is born
+// between pooling and injection it simply gets no echo that time.
+
+use serde::{Deserialize, Serialize};
+use crate::core::reservoir::{max_pool_logits, FractalReservoir};
+use crate::cortex::sampler::{argmax, softmax};
+
+/// Bands of the dashboard's LLM spectrum.
+pub const SPECTRUM_BANDS: usize = 64;
+
+/// What the main loop receives instead of the raw logits.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NeuralEcho {
+    pub pooled: Vec<f32>,       // One value per neuron
+    pub spectrum: Vec<f32>,     // SPECTRUM_BANDS values in -1..1
+    pub resonance: Option<f32>, // None = no echo (perception failed)
+    pub full: Option<Vec<f32>>, // The raw logits (cortex.full_echo only)
+}
+
+impl NeuralEcho {
+    pub fn from_logits(logits: &[f32], neurons: usize, keep_full: bool) -> Self {
+        if logits.is_empty() {
+            return Self { spectrum: vec![0.0; SPECTRUM_BANDS], ..Self::default() };
+        }
+        Self {
+            pooled: max_pool_logits(logits, neurons),
+            spectrum: spectrum(logits),
+            resonance: Some(argmax(&softmax(logits)).1),
+            full: keep_full.then(|| logits.to_vec()),
+        }
+    }
+
+    /// Drop the echo into the reservoir (the raw logits when they came along).
+    pub fn inject(&self, reservoir: &mut FractalReservoir) {
+        match &self.full {
+            Some(logits) => reservoir.inject_logits(logits),
+            None => reservoir.inject_pooled(&self.pooled),
+        }
+    }
+
+    /// Payload bytes this echo moves through the channel.
+    pub fn wire_bytes(&self) -> usize {
+        let floats = self.pooled.len() + self.spectrum.len() + self.full.as_ref().map_or(0, |f| f.len());
+        floats * std::mem::size_of::<f32>() + std::mem::size_of::<Self>()
+    }
+}
+
+/// Band means of the logits, tanh-normalized (zeros if there are fewer logits than bands).
+pub fn spectrum(logits: &[f32]) -> Vec<f32> {
+    let mut bands = vec![0.0; SPECTRUM_BANDS];
+    let chunk_size = logits.len() / SPECTRUM_BANDS;
+    if chunk_size == 0 {
+        return bands;
+    }
+    for (i, band) in bands.iter_mut().enumerate() {
+        let start = i * chunk_size;
+        let end = (start + chunk_size).min(logits.len());
+        let sum: f32 = logits[start..end].iter().sum();
+        *band = (sum / chunk_size as f32).tanh();
+    }
+    bands
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: u64 = 42;
+
+    /// The main loop's spectrum before the echo was pooled at the source (kept verbatim).
+    fn main_loop_spectrum(raw: &[f32]) -> Vec<f32> {
+        let mut spectrum = vec![0.0; 64];
+        if !raw.is_empty() {
+            let chunk_size = raw.len() / 64;
+            for (i, band) in spectrum.iter_mut().enumerate() {
+                let start = i * chunk_size;
+                let end = (start + chunk_size).min(raw.len());
+                let sum: f32 = raw[start..end].iter().sum();
+                *band = (sum / (chunk_size as f32)).tanh();
+            }
+        }
+        spectrum
+    }
+
+    /// Tiny deterministic generator.
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    #[test]
+    fn the_pooled_echo_leaves_the_reservoir_bit_identical_to_the_raw_logits() {
+        let mut state = SEED | 1;
+        for &(vocab, neurons) in &[(32000, 500), (32000, 2500), (32064, 1500), (50257, 2500), (1000, 2500), (64, 100)] {
+            let logits: Vec<f32> = (0..vocab).map(|_| (next(&mut state) % 4000) as f32 / 100.0 - 20.0).collect();
+            let echo = NeuralEcho::from_logits(&logits, neurons, false);
+
+            let base = FractalReservoir::seeded(neurons, 64, 0.95, 0.2, SEED);
+            let (mut raw_fed, mut pooled_fed) = (base.clone(), base);
+            raw_fed.inject_logits(&logits);
+            echo.inject(&mut pooled_fed);
+            let silence = vec![0.0; 64];
+            raw_fed.tick(&silence, 0.3, 0.3, 0.0, 1.0 / 60.0);
+            pooled_fed.tick(&silence, 0.3, 0.3, 0.0, 1.0 / 60.0);
+            let (a, b) = (raw_fed.get_activity_snapshot(), pooled_fed.get_activity_snapshot());
+            assert_eq!(a.len(), b.len());
+            let differing = a.iter().zip(&b).filter(|(x, y)| x.to_bits() != y.to_bits()).count();
+            assert_eq!(differing, 0, "{} logits -> {} neurons: neurons differ between raw and pooled echo", vocab, neurons);
+            assert_eq!(raw_fed.attention_breakdown(), pooled_fed.attention_breakdown(), "{} logits -> {} neurons", vocab, neurons);
+
+            // Spectrum and resonance match the main-loop code
+            assert_eq!(echo.spectrum, main_loop_spectrum(&logits), "{} logits", vocab);
+            assert_eq!(echo.resonance, Some(argmax(&softmax(&logits)).1), "{} logits", vocab);
+        }
+    }
+
+    #[test]
+    fn the_echo_is_at_least_90_percent_lighter_than_the_raw_logits() {
+        let logits = vec![0.5; 32000];
+        let light = NeuralEcho::from_logits(&logits, 2500, false).wire_bytes();
+        let raw = logits.len() * std::mem::size_of::<f32>() + std::mem::size_of::<Vec<f32>>(); // What neural_echo used to be
+        let saved = 1.0 - light as f32 / raw as f32;
+        assert!(saved >= 0.9, "pooled echo is {}B vs {}B raw ({:.0}% saved)", light, raw, saved * 100.0);
+    }
+
+    #[test]
+    fn no_logits_give_an_empty_pool_a_neutral_spectrum_and_no_resonance() {
+        let none = NeuralEcho::from_logits(&[], 2500, true);
+        assert!(none.pooled.is_empty());
+        assert_eq!(none.spectrum, vec![0.0; SPECTRUM_BANDS]);
+        assert_eq!(none.resonance, None);
+        assert!(none.full.is_none());
+    }
+}
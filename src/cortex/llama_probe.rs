@@ -0,0 +1,287 @@
+// src/cortex/llama_probe.rs
+// THE GLASS BRAIN: A llama forward pass we can look inside
+//
+// The dashboard's "activations" used to be max-pooled logits: a picture of the
+// output vocabulary, not of what the network did to get there. candle's
+// quantized llama (ModelWeights) keeps its blocks private and has no hooks, so
+// this is the same forward pass (same GGUF tensors, same ops in the same
+// order: RMSNorm -> attention with interleaved RoPE and a KV cache -> SwiGLU
+// MLP, residuals around both) with two taps at the last position of every
+// call:
+//
+//   layer_means  mean |hidden state| after each block (22 values for TinyLlama)
+//   slice        the middle block's hidden state, mean-pooled to HIDDEN_SLICE
+//
+// Dense llama only: a mixture-of-experts GGUF is refused here and CandleLlama
+// falls back to candle's own model (logits as the Glass Brain, as before).
+// A test against the GGUF in models.toml compares this pass's logits with candle's.
+
+use anyhow::{bail, Result};
+use candle_core::quantized::{gguf_file, QMatMul};
+use candle_core::{DType, Device, IndexOp, Module, Tensor};
+use candle_nn::Embedding;
+use candle_transformers::quantized_nn::RmsNorm;
+use candle_transformers::utils::repeat_kv;
+use std::collections::HashMap;
+use std::io::{Read, Seek};
+
+use crate::cortex::backend::{HiddenSummary, HIDDEN_SLICE};
+
+/// RoPE table length (positions), as candle's quantized llama.
+const MAX_SEQ_LEN: usize = 4096;
+
+struct Block {
+    attention_wq: QMatMul,
+    attention_wk: QMatMul,
+    attention_wv: QMatMul,
+    attention_wo: QMatMul,
+    attention_norm: RmsNorm,
+    feed_forward_w1: QMatMul,
+    feed_forward_w2: QMatMul,
+    feed_forward_w3: QMatMul,
+    ffn_norm: RmsNorm,
+    n_head: usize,
+    n_kv_head: usize,
+    head_dim: usize,
+    cos: Tensor,
+    sin: Tensor,
+    neg_inf: Tensor,
+    kv_cache: Option<(Tensor, Tensor)>,
+}
+
+impl Block {
+    fn rotary(&self, x: &Tensor, index_pos: usize) -> Result<Tensor> {
+        let (_b, _heads, seq_len, _dim) = x.dims4()?;
+        let cos = self.cos.narrow(0, index_pos, seq_len)?;
+        let sin = self.sin.narrow(0, index_pos, seq_len)?;
+        Ok(candle_nn::rotary_emb::rope_i(&x.contiguous()?, &cos, &sin)?)
+    }
+
+    fn attention(&mut self, x: &Tensor, mask: Option<&Tensor>, index_pos: usize) -> Result<Tensor> {
+        let (b, seq_len, n_embd) = x.dims3()?;
+        let q = self.attention_wq.forward(x)?
+            .reshape((b, seq_len, self.n_head, self.head_dim))?.transpose(1, 2)?;
+        let k = self.attention_wk.forward(x)?
+            .reshape((b, seq_len, self.n_kv_head, self.head_dim))?.transpose(1, 2)?;
+        let v = self.attention_wv.forward(x)?
+            .reshape((b, seq_len, self.n_kv_head, self.head_dim))?.transpose(1, 2)?
+            .contiguous()?;
+        let q = self.rotary(&q, index_pos)?;
+        let k = self.rotary(&k, index_pos)?;
+
+        let (k, v) = match &self.kv_cache {
+            Some((k_cache, v_cache)) if index_pos > 0 => (Tensor::cat(&[k_cache, &k], 2)?, Tensor::cat(&[v_cache, &v], 2)?),
+            _ => (k, v),
+        };
+        self.kv_cache = Some((k.clone(), v.clone()));
+
+        let k = repeat_kv(k, self.n_head / self.n_kv_head)?;
+        let v = repeat_kv(v, self.n_head / self.n_kv_head)?;
+        let att = (q.matmul(&k.t()?)? / (self.head_dim as f64).sqrt())?;
+        let att = match mask {
+            Some(mask) => {
+                let mask = mask.broadcast_as(att.shape())?;
+                mask.where_cond(&self.neg_inf.broadcast_as(att.shape().dims())?, &att)?
+            },
+            None => att,
+        };
+        let att = candle_nn::ops::softmax_last_dim(&att)?;
+        let y = att.matmul(&v.contiguous()?)?;
+        let y = y.transpose(1, 2)?.reshape(&[b, seq_len, n_embd])?;
+        Ok(self.attention_wo.forward(&y)?)
+    }
+
+    fn mlp(&self, x: &Tensor) -> Result<Tensor> {
+        let w1 = self.feed_forward_w1.forward(x)?;
+        let w3 = self.feed_forward_w3.forward(x)?;
+        Ok(self.feed_forward_w2.forward(&(candle_nn::ops::silu(&w1)? * w3)?)?)
+    }
+}
+
+pub struct ProbedLlama {
+    tok_embeddings: Embedding,
+    blocks: Vec<Block>,
+    norm: RmsNorm,
+    output: QMatMul,
+    masks: HashMap<usize, Tensor>,
+    last: Option<HiddenSummary>,
+}
+
+impl ProbedLlama {
+    pub fn from_gguf<R: Read + Seek>(ct: gguf_file::Content, reader: &mut R, device: &Device) -> Result<Self> {
+        let md = |key: &str| match ct.metadata.get(key) {
+            Some(value) => Ok(value),
+            None => bail!("{} missing from the GGUF metadata", key),
+        };
+        let experts = md("llama.expert_count").and_then(|v| Ok(v.to_u32()?)).unwrap_or(0);
+        if experts > 1 {
+            bail!("mixture of experts ({} experts): no hidden probe", experts);
+        }
+        let n_head = md("llama.attention.head_count")?.to_u32()? as usize;
+        let n_kv_head = md("llama.attention.head_count_kv")?.to_u32()? as usize;
+        let block_count = md("llama.block_count")?.to_u32()? as usize;
+        let embedding_length = md("llama.embedding_length")?.to_u32()? as usize;
+        let rope_dim = md("llama.rope.dimension_count")?.to_u32()? as usize;
+        let eps = md("llama.attention.layer_norm_rms_epsilon")?.to_f32()? as f64;
+        let rope_base = md("llama.rope.freq_base").and_then(|v| Ok(v.to_f32()?)).unwrap_or(10000.0);
+
+        let (cos, sin) = rope_tables(rope_dim, rope_base, device)?;
+        let neg_inf = Tensor::new(f32::NEG_INFINITY, device)?;
+        let tok_embeddings_q = ct.tensor(reader, "token_embd.weight", device)?;
+        let tok_embeddings = tok_embeddings_q.dequantize(device)?;
+        let norm = RmsNorm::from_qtensor(ct.tensor(reader, "output_norm.weight", device)?, eps)?;
+        let output = match ct.tensor(reader, "output.weight", device) {
+            Ok(tensor) => tensor,
+            Err(_) => tok_embeddings_q, // Tied embeddings
+        };
+
+        let mut blocks = Vec::with_capacity(block_count);
+        for i in 0..block_count {
+            let mut q = |name: &str| -> Result<QMatMul> {
+                Ok(QMatMul::from_qtensor(ct.tensor(reader, &format!("blk.{}.{}.weight", i, name), device)?)?)
+            };
+            let (attention_wq, attention_wk, attention_wv, attention_wo) = (q("attn_q")?, q("attn_k")?, q("attn_v")?, q("attn_output")?);
+            let (feed_forward_w1, feed_forward_w2, feed_forward_w3) = (q("ffn_gate")?, q("ffn_down")?, q("ffn_up")?);
+            let attention_norm = RmsNorm::from_qtensor(ct.tensor(reader, &format!("blk.{}.attn_norm.weight", i), device)?, eps)?;
+            let ffn_norm = RmsNorm::from_qtensor(ct.tensor(reader, &format!("blk.{}.ffn_norm.weight", i), device)?, eps)?;
+            blocks.push(Block {
+                attention_wq, attention_wk, attention_wv, attention_wo, attention_norm,
+                feed_forward_w1, feed_forward_w2, feed_forward_w3, ffn_norm,
+                n_head,
+                n_kv_head,
+                head_dim: embedding_length / n_head,
+                cos: cos.clone(),
+                sin: sin.clone(),
+                neg_inf: neg_inf.clone(),
+                kv_cache: None,
+            });
+        }
+
+        Ok(Self {
+            tok_embeddings: Embedding::new(tok_embeddings, embedding_length),
+            blocks,
+            norm,
+            output: QMatMul::from_qtensor(output)?,
+            masks: HashMap::new(),
+            last: None,
+        })
+    }
+
+    /// Transformer blocks (llama.block_count).
+    pub fn block_count(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// What the last `forward` saw inside.
+    pub fn hidden(&self) -> Option<&HiddenSummary> {
+        self.last.as_ref()
+    }
+
+    fn mask(&mut self, t: usize, device: &Device) -> Result<Tensor> {
+        if let Some(mask) = self.masks.get(&t) {
+            return Ok(mask.clone());
+        }
+        let mask: Vec<u8> = (0..t).flat_map(|i| (0..t).map(move |j| u8::from(j > i))).collect();
+        let mask = Tensor::from_slice(&mask, (t, t), device)?;
+        self.masks.insert(t, mask.clone());
+        Ok(mask)
+    }
+
+    /// Logits of the last position (as candle's ModelWeights::forward), taps recorded.
+    pub fn forward(&mut self, x: &Tensor, index_pos: usize) -> Result<Tensor> {
+        let (_b, seq_len) = x.dims2()?;
+        let mask = if seq_len == 1 { None } else { Some(self.mask(seq_len, x.device())?) };
+        let slice_layer = self.blocks.len() / 2;
+        let mut means = Vec::with_capacity(self.blocks.len());
+        let mut slice = Vec::new();
+        let mut hidden = self.tok_embeddings.forward(x)?;
+        for (i, block) in self.blocks.iter_mut().enumerate() {
+            let residual = &hidden;
+            let attn = block.attention(&block.attention_norm.forward(&hidden)?, mask.as_ref(), index_pos)?;
+            let x = (attn + residual)?;
+            let residual = &x;
+            let mlp = block.mlp(&block.ffn_norm.forward(&x)?)?;
+            hidden = (mlp + residual)?;
+
+            // TAPS: the newest position only (what this call added to the stream)
+            let last = hidden.i((.., seq_len - 1, ..))?;
+            means.push(last.abs()?.mean_all()?);
+            if i == slice_layer {
+                slice = pool_mean(&last.flatten_all()?.to_dtype(DType::F32)?.to_vec1::<f32>()?, HIDDEN_SLICE);
+            }
+        }
+        let layer_means = Tensor::stack(&means, 0)?.to_dtype(DType::F32)?.to_vec1::<f32>()?;
+        self.last = Some(HiddenSummary { layer_means, slice_layer, slice });
+
+        let x = self.norm.forward(&hidden)?;
+        let x = x.i((.., seq_len - 1, ..))?;
+        Ok(self.output.forward(&x)?)
+    }
+}
+
+fn rope_tables(head_dim: usize, base: f32, device: &Device) -> Result<(Tensor, Tensor)> {
+    let theta: Vec<f32> = (0..head_dim).step_by(2)
+        .map(|i| 1.0 / base.powf(i as f32 / head_dim as f32))
+        .collect();
+    let theta = Tensor::new(theta.as_slice(), device)?;
+    let idx_theta = Tensor::arange(0, MAX_SEQ_LEN as u32, device)?
+        .to_dtype(DType::F32)?
+        .reshape((MAX_SEQ_LEN, 1))?
+        .matmul(&theta.reshape((1, theta.elem_count()))?)?;
+    Ok((idx_theta.cos()?, idx_theta.sin()?))
+}
+
+/// Mean of `n` near-equal contiguous chunks (fewer values than `n`: returned as they are).
+pub fn pool_mean(values: &[f32], n: usize) -> Vec<f32> {
+    if values.len() <= n || n == 0 {
+        return values.to_vec();
+    }
+    (0..n).map(|k| {
+        let chunk = &values[k * values.len() / n..(k + 1) * values.len() / n];
+        chunk.iter().sum::<f32>() / chunk.len().max(1) as f32
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_transformers::models::quantized_llama::ModelWeights;
+
+    /// Against a real GGUF (CPU): the probe has as many blocks as llama.block_count says,
+    /// one finite layer mean per block and a finite HIDDEN_SLICE slice of the middle one,
+    /// and its logits match candle's own ModelWeights on the same tokens (prefill, then
+    /// one cached step).
+    #[test]
+    #[ignore = "needs the GGUF in models.toml"]
+    fn the_probe_matches_candles_forward_pass_and_sees_every_block() -> Result<()> {
+        let model_file = crate::core::config::ModelsConfig::load().model_path;
+        let device = Device::Cpu;
+        let mut file = std::fs::File::open(&model_file)?;
+        let content = gguf_file::Content::read(&mut file)?;
+        let declared = match content.metadata.get("llama.block_count") {
+            Some(count) => count.to_u32()? as usize,
+            None => bail!("llama.block_count missing from the GGUF metadata"),
+        };
+        let mut probe = ProbedLlama::from_gguf(content, &mut file, &device)?;
+        file.rewind()?;
+        let content = gguf_file::Content::read(&mut file)?;
+        let mut reference = ModelWeights::from_gguf(content, &mut file, &device)?;
+        assert_eq!(probe.block_count(), declared);
+
+        let prompt = Tensor::new(&[1u32, 450, 4799, 338], &device)?.unsqueeze(0)?;
+        let step = Tensor::new(&[263u32], &device)?.unsqueeze(0)?;
+        for (tokens, pos) in [(&prompt, 0), (&step, 4)] {
+            let ours = probe.forward(tokens, pos)?.squeeze(0)?.to_dtype(DType::F32)?.to_vec1::<f32>()?;
+            let theirs = reference.forward(tokens, pos)?.squeeze(0)?.to_dtype(DType::F32)?.to_vec1::<f32>()?;
+            // Same ops, same order: only float noise apart
+            let worst = ours.iter().zip(&theirs).fold(0.0f32, |w, (a, b)| w.max((a - b).abs()));
+            assert!(worst <= 1e-2, "pos {}: logits differ from candle's ModelWeights by up to {:.4}", pos, worst);
+
+            let hidden = probe.hidden().expect("no hidden summary after forward");
+            assert_eq!(hidden.layer_means.len(), declared, "pos {}", pos);
+            assert!(!hidden.slice.is_empty() && hidden.slice.len() <= HIDDEN_SLICE, "pos {}: {} slice values", pos, hidden.slice.len());
+            assert!(hidden.layer_means.iter().chain(&hidden.slice).all(|v| v.is_finite()), "pos {}: non-finite hidden activations", pos);
+        }
+        Ok(())
+    }
+}
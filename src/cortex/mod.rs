@@ -1 +1,10 @@
 pub mod planet;
+pub mod queue;
+pub mod cortexd;
+pub mod backend;
+pub mod llama_probe;
+pub mod sampler;
+pub mod ollama;
+pub mod context;
+pub mod echo;
+pub mod replay;
@@ -0,0 +1,308 @@
+// src/cortex/ollama.rs
+// THE REMOTE SUBSTRATE: Cortex over HTTP (Ollama / llama.cpp server)
+//
+// For bodies that can't hold a GGUF next to Whisper and the reservoir.
+// The server never hands us raw logits, only the top-k log-probabilities of
+// each generated token. Those are expanded into a SPARSE neural echo over a
+// local, hash-interned vocabulary so `inject_logits` and the Semantic Field
+// keep working (blurrier, but honest).
+//
+// Network failure is not death: requests fail fast, the Planet falls back to
+// the resonance-less path, and a System thought marks the transition.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::mpsc::Sender;
+use std::time::{Duration, Instant};
+
+use crate::core::config::OllamaConfig;
+use crate::core::thought::{Thought, MindVoice};
+use crate::cortex::backend::{CortexBackend, GenerationParams};
+
+/// Size of the interned vocabulary (matches TinyLlama so downstream physics sees the same scale).
+const VOCAB_SLOTS: usize = 32000;
+/// log-prob -> pseudo-logit offset (prob 1.0 ≈ logit 10, like a confident local model).
+const ECHO_OFFSET: f32 = 10.0;
+
+pub struct OllamaCortex {
+    config: OllamaConfig,
+    host: String,
+    port: u16,
+    thought_tx: Sender<Thought>,
+    context: Vec<u32>,
+    vocab: RefCell<HashMap<u32, String>>, // Interned pieces (tokenize is &self)
+    // Reachability (for transition thoughts + fast-fail backoff)
+    online: bool,
+    retry_at: Option<Instant>,
+}
+
+impl OllamaCortex {
+    pub fn new(config: OllamaConfig, thought_tx: Sender<Thought>) -> Result<Self> {
+        let (host, port) = parse_base_url(&config.base_url)?;
+        let mut vocab = HashMap::new();
+        vocab.insert(1, "<s>".to_string());
+        vocab.insert(2, "</s>".to_string());
+        let _ = thought_tx.send(Thought::new(MindVoice::System,
+            format!("🌐 Neocortex: Remote ({} @ {})", config.model, config.base_url)));
+        Ok(Self {
+            config,
+            host,
+            port,
+            thought_tx,
+            context: Vec::new(),
+            vocab: RefCell::new(vocab),
+            online: true,
+            retry_at: None,
+        })
+    }
+
+    fn intern(&self, token: &str) -> u32 {
+        let mut hasher = DefaultHasher::new();
+        token.hash(&mut hasher);
+        let id = 3 + (hasher.finish() % (VOCAB_SLOTS as u64 - 3)) as u32;
+        self.vocab.borrow_mut().insert(id, token.to_string()); // Collisions: last writer wins
+        id
+    }
+
+    /// POST /api/generate. `on_chunk` receives every JSON object of the (possibly streamed) reply
+    /// and returns false to hang up early.
+    fn request(&mut self, body: Value, on_chunk: &mut dyn FnMut(&Value) -> bool) -> Result<()> {
+        // BACKOFF: While the server is down, don't pay a connect timeout on every 5Hz pulse
+        if let Some(at) = self.retry_at {
+            if Instant::now() < at {
+                return Err(anyhow!("Ollama offline (backoff)"));
+            }
+        }
+
+        let result = self.request_inner(&body, on_chunk);
+        match &result {
+            Ok(_) => {
+                if !self.online {
+                    let _ = self.thought_tx.send(Thought::new(MindVoice::System, "🌐 Ollama: Link restored.".to_string()));
+                }
+                self.online = true;
+                self.retry_at = None;
+            },
+            Err(e) => {
+                if self.online {
+                    let _ = self.thought_tx.send(Thought::new(MindVoice::System,
+                        format!("🌐 Ollama unreachable ({}). Thinking without resonance.", e)));
+                }
+                self.online = false;
+                self.retry_at = Some(Instant::now() + Duration::from_secs(self.config.retry_secs));
+            }
+        }
+        result
+    }
+
+    fn request_inner(&self, body: &Value, on_chunk: &mut dyn FnMut(&Value) -> bool) -> Result<()> {
+        let addr = (self.host.as_str(), self.port).to_socket_addrs()?
+            .next()
+            .ok_or_else(|| anyhow!("cannot resolve {}", self.host))?;
+        let mut stream = TcpStream::connect_timeout(&addr, Duration::from_millis(self.config.connect_timeout_ms))?;
+        stream.set_read_timeout(Some(Duration::from_millis(self.config.read_timeout_ms)))?;
+
+        let payload = body.to_string();
+        let request = format!(
+            "POST /api/generate HTTP/1.1\r\nHost: {}:{}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.host, self.port, payload.len(), payload
+        );
+        stream.write_all(request.as_bytes())?;
+
+        let mut reader = BufReader::new(stream);
+
+        // Status line
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        if !line.contains(" 200 ") {
+            return Err(anyhow!("HTTP {}", line.trim()));
+        }
+
+        // Headers
+        let mut chunked = false;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 { break; }
+            let l = line.trim();
+            if l.is_empty() { break; }
+            if l.to_ascii_lowercase().starts_with("transfer-encoding:") && l.to_ascii_lowercase().contains("chunked") {
+                chunked = true;
+            }
+        }
+
+        // Body: NDJSON (one object per line), optionally chunk-encoded
+        // (Bytes, not String: a chunk boundary may split a multi-byte character)
+        let mut pending: Vec<u8> = Vec::new();
+        let mut emit = |pending: &mut Vec<u8>| -> bool {
+            while let Some(nl) = pending.iter().position(|&b| b == b'\n') {
+                let obj: Vec<u8> = pending.drain(..=nl).collect();
+                if let Ok(v) = serde_json::from_slice::<Value>(&obj) {
+                    if !on_chunk(&v) { return false; }
+                }
+            }
+            true
+        };
+
+        if chunked {
+            loop {
+                line.clear();
+                if reader.read_line(&mut line)? == 0 { break; }
+                let size = usize::from_str_radix(line.trim().split(';').next().unwrap_or("0"), 16)?;
+                if size == 0 { break; }
+                let mut buf = vec![0u8; size];
+                reader.read_exact(&mut buf)?;
+                let mut crlf = [0u8; 2];
+                reader.read_exact(&mut crlf)?;
+                pending.extend_from_slice(&buf);
+                if !emit(&mut pending) { return Ok(()); }
+            }
+        } else {
+            loop {
+                line.clear();
+                if reader.read_line(&mut line)? == 0 { break; }
+                pending.extend_from_slice(line.as_bytes());
+                if !emit(&mut pending) { return Ok(()); }
+            }
+        }
+        // Last object may lack a trailing newline
+        pending.push(b'\n');
+        emit(&mut pending);
+        Ok(())
+    }
+
+    /// Expand one `logprobs` entry into the sparse echo.
+    fn scatter_logprobs(&self, entry: &Value, echo: &mut [f32]) {
+        let mut pairs: Vec<(String, f32)> = Vec::new();
+        if let Some(top) = entry.get("top_logprobs").and_then(|v| v.as_array()) {
+            for t in top {
+                if let (Some(tok), Some(lp)) = (t.get("token").and_then(|v| v.as_str()), t.get("logprob").and_then(|v| v.as_f64())) {
+                    pairs.push((tok.to_string(), lp as f32));
+                }
+            }
+        }
+        if let (Some(tok), Some(lp)) = (entry.get("token").and_then(|v| v.as_str()), entry.get("logprob").and_then(|v| v.as_f64())) {
+            pairs.push((tok.to_string(), lp as f32));
+        }
+        for (tok, lp) in pairs {
+            let id = self.intern(&tok) as usize;
+            echo[id] = echo[id].max((ECHO_OFFSET + lp).max(0.0));
+        }
+    }
+}
+
+impl CortexBackend for OllamaCortex {
+    /// One-token probe: the server's top-k for the next token becomes a sparse echo.
+    fn forward_logits(&mut self, tokens: &[u32], pos: usize) -> Result<Vec<f32>> {
+        if pos == 0 {
+            self.context.clear();
+        }
+        self.context.extend_from_slice(tokens);
+        let prompt = self.decode(&self.context.clone(), true)?;
+
+        let body = json!({
+            "model": self.config.model,
+            "prompt": prompt,
+            "raw": true,
+            "stream": false,
+            "logprobs": true,
+            "top_logprobs": self.config.top_logprobs,
+            "options": { "num_predict": 1 }
+        });
+
+        let mut replies: Vec<Value> = Vec::new();
+        self.request(body, &mut |v| { replies.push(v.clone()); true })?;
+
+        let mut echo = vec![0.0f32; VOCAB_SLOTS];
+        for reply in &replies {
+            if let Some(entries) = reply.get("logprobs").and_then(|v| v.as_array()) {
+                for entry in entries {
+                    self.scatter_logprobs(entry, &mut echo);
+                }
+            } else if let Some(text) = reply.get("response").and_then(|v| v.as_str()) {
+                // Server without logprobs: at least the chosen token lights up
+                if !text.is_empty() {
+                    let id = self.intern(text) as usize;
+                    echo[id] = ECHO_OFFSET;
+                }
+            }
+        }
+        Ok(echo)
+    }
+
+    /// Whitespace-attached pieces ("hola mundo" -> ["hola", " mundo"]), BOS first.
+    fn tokenize(&self, text: &str) -> Result<Vec<u32>> {
+        let mut ids = vec![1];
+        let mut piece = String::new();
+        for c in text.chars() {
+            if c.is_whitespace() && !piece.is_empty() && !piece.chars().all(char::is_whitespace) {
+                ids.push(self.intern(&piece));
+                piece.clear();
+            }
+            piece.push(c);
+        }
+        if !piece.is_empty() {
+            ids.push(self.intern(&piece));
+        }
+        Ok(ids)
+    }
+
+    fn decode(&self, tokens: &[u32], skip_special: bool) -> Result<String> {
+        let mut out = String::new();
+        let vocab = self.vocab.borrow();
+        for t in tokens {
+            if *t < 3 && skip_special { continue; }
+            if let Some(s) = vocab.get(t) {
+                out.push_str(s);
+            }
+        }
+        Ok(out)
+    }
+
+    fn vocab_size(&self) -> usize {
+        VOCAB_SLOTS
+    }
+
+    /// Remote generation streams text; chemistry can't bend the server's logits,
+    /// so only temperature/top-p (already chemical) and the seed travel with the request.
+    fn generate_text(&mut self, prompt: &str, params: &GenerationParams, on_fragment: &mut dyn FnMut(&str) -> bool) -> Option<Result<String>> {
+        let body = json!({
+            "model": self.config.model,
+            "prompt": prompt,
+            "raw": true,
+            "stream": true,
+            "options": {
+                "num_predict": params.max_tokens,
+                "temperature": params.temperature,
+                "top_p": params.top_p,
+                "stop": params.stop,
+                "seed": params.seed as u32, // The server's sampler takes 32 bits
+            }
+        });
+
+        let mut full = String::new();
+        let result = self.request(body, &mut |v| {
+            if let Some(text) = v.get("response").and_then(|r| r.as_str()) {
+                full.push_str(text);
+                if !on_fragment(text) { return false; }
+            }
+            !v.get("done").and_then(|d| d.as_bool()).unwrap_or(false)
+        });
+        Some(result.map(|_| full))
+    }
+}
+
+fn parse_base_url(url: &str) -> Result<(String, u16)> {
+    let rest = url.strip_prefix("http://")
+        .ok_or_else(|| anyhow!("Only http:// base URLs are supported ({})", url))?;
+    let authority = rest.split('/').next().unwrap_or(rest);
+    match authority.rsplit_once(':') {
+        Some((host, port)) => Ok((host.to_string(), port.parse()?)),
+        None => Ok((authority.to_string(), 80)),
+    }
+}
@@ -1,24 +1,39 @@
-use anyhow::{Error as E, Result};
-use candle_core::{Tensor, Device, DType, IndexOp};
-use candle_transformers::models::quantized_llama::ModelWeights as Llama;
-use candle_transformers::generation::LogitsProcessor;
-use tokenizers::Tokenizer;
+use anyhow::Result;
 use crate::core::thought::{Thought, MindVoice};
+use crate::core::config::{BioBudgetConfig, CortexConfig, ModelsConfig, FieldConfig};
+use crate::core::field::SemanticField;
+use crate::core::lexicon::Lexicon;
+use crate::core::components::{self, ComponentStatus, Components};
+use crate::cortex::context::{clamp_bio, same_bio, ContextWindow, SegmentKind, TokenSplit};
+use crate::core::memory_vector::MemoryRecord;
+use crate::core::selftest;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::sync::mpsc::{Sender, Receiver, channel};
 use std::thread;
-
-const MODEL_FILE: &str = "models/tinyllama-1.1b-chat-v1.0.Q4_K_M.gguf"; 
-const TOKENIZER_FILE: &str = "models/tokenizer_tinyllama.json"; 
+use std::time::{Duration, Instant};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use crate::cortex::queue::{cortex_queue, CortexSender, CortexDelivery, CortexCommand};
+use crate::cortex::backend::{ActivationLayout, CortexBackend, CandleLlama, MockCortex, GenerationParams};
+use crate::cortex::ollama::OllamaCortex;
+use crate::cortex::sampler::{inference_seed, Sampler};
+use crate::cortex::replay::{InferenceLog, InferenceRecord};
+use crate::cortex::echo::NeuralEcho;
 
 // AXIOMS REMOVED: ALEPH is born naked. No instructions, only physics. 
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+// Only structural breaks that would confuse the prompt loop.
+// Removed: "System:", "Instructions:", "You are", "Qualia:", "Context:", "Response:"
+const STOP_SEQUENCES: &[&str] = &["<|", "USER:", "EVENTO:", "A:", "D:", "C:", "[", "COLMENA", "Respuestabreve", "</s>"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum CortexMode {
     Listen, // Passive Perception (Activations Only)
     Think,  // Active Generation (Text + Activations)
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CortexInput {
     pub mode: CortexMode, // NEW: Control Friction
     pub text: String,
@@ -37,47 +52,240 @@ pub struct CortexInput {
     pub temperature_clamp: Option<f32>, // Firefighter Protocol override
 }
 
+/// What the genome makes of the Planet (Genome::phenotype): how cold a thought may
+/// get, and how hard the threatening lexicon pulls (CortexCommand::Temperament).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Temperament {
+    pub temperature_floor: f32,
+    pub threat_gravity: f32, // Logit offset on the first token of each threat term
+}
+
+impl Default for Temperament {
+    fn default() -> Self {
+        Self { temperature_floor: 0.1, threat_gravity: 0.0 }
+    }
+}
+
+/// Token-class name of the threatening lexicon in the Semantic Field.
+const THREAT_CLASS: &str = "threat";
+
+/// How often an idle Planet looks at its residency when nothing arrives.
+const RESIDENCY_POLL: Duration = Duration::from_secs(5);
+
+/// Adenosine owed for loading weights: grogginess grows with the load time.
+fn load_cost(secs: f32) -> f32 {
+    (secs * 0.02).min(0.2)
+}
+
+/// Generation budget: the more tired, the shorter the thought.
+fn available_tokens(adenosine: f32) -> usize {
+    if adenosine > 0.8 { 30 } else if adenosine > 0.5 { 60 } else { 120 }
+}
+
+/// Which model served an output (two-tier mode).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CortexTier {
+    Fast, // Listen / perception (or the only model)
+    Deep, // Think (large model, lazily loaded)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CortexOutput {
     pub _text: String,
-    pub neural_echo: Vec<f32>, // Neural Echo (Logits)
+    pub neural_echo: NeuralEcho, // Neural Echo (Logits, pooled at the reservoir's size)
+    pub seed: u64, // Sampler seed of this inference (`aleph replay-inference --seed`)
     pub synthesized_thought: Option<String>, // Resonant Word (from Semantic Field)
     pub top_tokens: Vec<(String, f32)>, // Top active tokens for visualization
     pub inference_latency_ms: u64,
-    pub activations: Vec<f32>, // Downsampled "Glass Brain" data (e.g. 512 nodes)
+    pub interrupted: bool, // Generation cancelled by higher-priority input (latency is partial)
+    pub tier: CortexTier,
+    pub warmup_cost: f32, // Adenosine owed for (re)loading the deep model
+    pub activations: Vec<f32>, // "Glass Brain" data: hidden-layer summary, or pooled logits (see layout)
+    pub activation_layout: ActivationLayout, // Header of `activations`
+    pub prompt_split: Option<TokenSplit>, // Think only: where the prompt's tokens went
+    pub bio_dropped: usize, // Bio fields cut to fit cortex.bio.max_tokens
+    pub bio_deduped: bool,  // Bio unchanged (within tolerance): not re-sent
+}
+
+/// A model plus the fields built with its own vocabulary (tiers don't share tokenizers).
+struct Tier {
+    backend: Box<dyn CortexBackend>,
+    docs_field: SemanticField,
+    semantic_field: SemanticField,
+}
+
+/// The large Think model. While Think runs it is swapped into the Planet's active
+/// slot and the fast tier waits here; otherwise the deep tier waits (or is evicted).
+struct DeepSlot {
+    model_path: String,
+    tokenizer_path: String,
+    parked: Option<Tier>,
+    last_used: Instant,
+    idle_evict: Duration,
+}
+
+/// Lifecycle of the fast (or only) model's weights (models.idle_unload_secs).
+/// Only Think keeps them resident: Listen pulses are skipped while they are out.
+struct Residency {
+    idle_unload: Option<Duration>, // None = always resident
+    min_resident: Duration,
+    loaded_at: Instant,
+    last_think: Instant,
+    suspended: u64, // Listen pulses skipped since the unload
+}
+
+impl Residency {
+    fn always() -> Self {
+        Self { idle_unload: None, min_resident: Duration::ZERO, loaded_at: Instant::now(), last_think: Instant::now(), suspended: 0 }
+    }
 }
 
 pub struct Planet {
-    model: Llama,
-    tokenizer: Tokenizer,
-    device: Device,
-    logits_processor: LogitsProcessor,
+    backend: Box<dyn CortexBackend>,
+    sampler: Sampler,
     #[allow(dead_code)]
     thought_tx: Sender<Thought>,
-    // FIFO BUFFER (Consciousness Stream)
-    history: String, 
+    // FIFO BUFFER (Consciousness Stream): tagged segments, assembled within a token budget
+    history: ContextWindow,
+    context_tokens: usize, // The model's context window (prompt + generation)
+    history_cap: Option<usize>, // Memory pressure: prompt budget ceiling (core/pressure.rs)
     // SPEECH GATING
     is_internal_monologue: bool,
     // BIAS MATRIX
     semantic_field: crate::core::field::SemanticField,
+    docs_field: crate::core::field::SemanticField, // Static author-provided gravity
+    field_config: FieldConfig,
+    // INTERRUPTION (Raised by the queue when a user speaks)
+    cancel: Arc<AtomicBool>,
+    interrupted: bool,
+    // TWO-TIER CORTEX (None = single model, as always)
+    deep: Option<DeepSlot>,
+    last_memories: Vec<MemoryRecord>, // So a freshly loaded deep tier feels past sleeps too
+    residency: Residency,
+    // REPRODUCIBILITY: per-inference seeds from a master seed, and the tape of utterances
+    master_seed: u64,
+    inferences: u64,
+    last_prompt: String, // What the model saw in the last inference
+    field_id: Option<String>, // Saved memories of the active field (None = docs only)
+    log: InferenceLog,
+    // BODY BUDGET: bio_context clamped and not repeated while unchanged
+    bio_budget: BioBudgetConfig,
+    last_split: Option<TokenSplit>,
+    bio_dropped: usize,
+    bio_deduped: bool,
+    activation_layout: ActivationLayout, // Of the last perceive
+    // GENOME: temperature floor and threat gravity, and the terms that gravity pulls
+    temperament: Temperament,
+    threat_terms: Vec<String>,
 }
 
 impl Planet {
-    pub fn spawn(thought_tx: Sender<Thought>) -> Result<(Sender<CortexInput>, Receiver<CortexOutput>)> {
-        let (input_tx, input_rx) = channel::<CortexInput>();
+    /// `reservoir_size` is published by the daemon (neurogenesis): the echo is pooled at it.
+    pub fn spawn(thought_tx: Sender<Thought>, config: CortexConfig, models: ModelsConfig, reservoir_size: Arc<AtomicUsize>, components: Components) -> Result<(CortexSender, Receiver<CortexOutput>)> {
+        let (input_tx, input_rx) = cortex_queue(Duration::from_secs_f32(config.staleness_secs));
         let (output_tx, output_rx) = channel::<CortexOutput>();
         let thread_thought_tx = thought_tx.clone();
+        let cancel = input_rx.cancel_flag();
 
         thread::spawn(move || {
-            match Self::new(thread_thought_tx.clone()) {
+            // SUBSTRATE SELECTION (Loaded inside the thread: GGUF load is slow)
+            let backend = Self::load_backend(&thread_thought_tx, &models);
+
+            match backend.and_then(|b| Self::new(thread_thought_tx.clone(), cancel, b, config.field.clone(), config.context_tokens)) {
                 Ok(mut core) => {
+                    components.report(components::CORTEX, ComponentStatus::Ready);
+                    components.report(components::SEMANTIC_FIELD, if core.docs_field.strength() > 0.0 {
+                        ComponentStatus::Ready
+                    } else {
+                        ComponentStatus::Degraded("no docs/: zero gravity".to_string())
+                    });
+                    core.master_seed = models.inference_seed.unwrap_or_else(|| rand::rng().random());
+                    core.log = InferenceLog::new(&models.inference_log);
+                    core.bio_budget = config.bio.clone();
+                    let _ = thread_thought_tx.send(Thought::new(MindVoice::System, match models.inference_seed {
+                        Some(seed) => format!("🎲 Inference seeds: master {} (seeded run)", seed),
+                        None => format!("🎲 Inference seeds: master {} (set models.inference_seed to repeat this run)", core.master_seed),
+                    }));
+                    if let Some(think_model) = &models.think_model_path {
+                        let _ = thread_thought_tx.send(Thought::new(MindVoice::System,
+                            format!("🧠 Two-tier Cortex: Think -> {} (lazy, evict after {}s idle)", think_model, models.think_idle_evict_secs)));
+                        core.deep = Some(DeepSlot {
+                            model_path: think_model.clone(),
+                            tokenizer_path: models.think_tokenizer_path.clone().unwrap_or_else(|| models.tokenizer_path.clone()),
+                            parked: None,
+                            last_used: Instant::now(),
+                            idle_evict: Duration::from_secs(models.think_idle_evict_secs),
+                        });
+                    }
+                    if models.idle_unload_secs > 0 {
+                        core.residency.idle_unload = Some(Duration::from_secs(models.idle_unload_secs));
+                        core.residency.min_resident = Duration::from_secs(models.min_resident_secs);
+                    }
                     let _ = thread_thought_tx.send(Thought::new(MindVoice::System, "🪐 Planet (Narrative Engine): ONLINE (Stream Mode)".to_string()));
+                    // WARM-UP: the first real inference doesn't pay for the mmap and the GPU context
+                    if config.warmup {
+                        core.warmup(&thread_thought_tx);
+                    }
                     
                     loop {
-                        let msg = match input_rx.recv() {
-                            Ok(m) => m,
-                            Err(_) => break,
+                        let poll = core.residency.idle_unload.map(|_| Instant::now() + RESIDENCY_POLL);
+                        let delivery = input_rx.recv_by(poll);
+                        // Free the weights if Think has been quiet for a while (Listen doesn't count;
+                        // a Think arriving now is served by them instead)
+                        if !matches!(&delivery, Some(CortexDelivery::Input(m, _)) if m.mode == CortexMode::Think) {
+                            core.unload_if_idle(Instant::now());
+                        }
+                        let msg = match delivery {
+                            Some(CortexDelivery::Input(m, _)) => m,
+                            Some(CortexDelivery::Quiet) => continue,
+                            Some(CortexDelivery::Stale { count, oldest_ms }) => {
+                                let _ = thread_thought_tx.send(Thought::new(MindVoice::System,
+                                    format!("⌛ Planet: Dropped {} stale input(s) (oldest {:.1}s). Too late to answer.", count, oldest_ms as f32 / 1000.0)));
+                                continue;
+                            },
+                            Some(CortexDelivery::RebuildField(memories)) => {
+                                core.rebuild_field(&memories);
+                                continue;
+                            },
+                            Some(CortexDelivery::Command(CortexCommand::DumpHistory { reply_tx })) => {
+                                let _ = reply_tx.send(core.history.segments());
+                                continue;
+                            },
+                            Some(CortexDelivery::Command(CortexCommand::HistoryBudget(cap))) => {
+                                core.history_cap = cap;
+                                continue;
+                            },
+                            Some(CortexDelivery::Command(CortexCommand::Temperament(temperament))) => {
+                                core.set_temperament(temperament);
+                                continue;
+                            },
+                            None => break,
                         };
 
+                        // Free the big model if Think has been quiet for a while
+                        core.evict_idle_deep();
+
+                        // LAZY RELOAD: Think brings the weights back (and is groggy for it);
+                        // Listen pulses are skipped until then instead of waking them
+                        let mut reload_cost = 0.0;
+                        if msg.mode == CortexMode::Think {
+                            core.residency.last_think = Instant::now();
+                            match core.ensure_resident() {
+                                Ok((cost, took)) => {
+                                    reload_cost = cost;
+                                    input_rx.postpone(took); // What queued up meanwhile isn't stale for it
+                                },
+                                Err(e) => {
+                                    let _ = thread_thought_tx.send(Thought::new(MindVoice::Error,
+                                        format!("⚠️ Cortex reload failed: {}. This thought is lost.", e)));
+                                    continue;
+                                }
+                            }
+                        } else if !core.backend.is_loaded() {
+                            core.residency.suspended += 1;
+                            continue;
+                        }
+
                         // 1. NEURO-MODULATION (Physics of Thought)
                         
                         // TEMPERATURE (Creativity/Chaos) -> Driven by RESERVOIR ENTROPY
@@ -92,7 +300,8 @@ impl Planet {
                             base_temp += (msg.cortisol - 0.6);
                         }
 
-                        base_temp = base_temp.clamp(0.1, 2.0);
+                        // CURIOSITY (genome): a curious mind never gets entirely cold
+                        base_temp = base_temp.clamp(core.temperament.temperature_floor.clamp(0.1, 2.0), 2.0);
 
                         // Firefighter Protocol: Clamp temperature
                         if let Some(clamp) = msg.temperature_clamp {
@@ -104,13 +313,23 @@ impl Planet {
                         // Range: 0.95 (Aden 0) -> 0.4 (Aden 1)
                         let base_top_p = (0.95 - (msg.adenosine * 0.55)).max(0.1); 
 
-                        core.logits_processor = LogitsProcessor::new(
-                            rand::thread_rng().gen(),
-                            Some(base_temp as f64),
-                            Some(base_top_p as f64)
-                        );
+                        // SEED: the run's master seed + this inference's index (reproducible, never repeated)
+                        let inference = core.inferences;
+                        let seed = core.next_seed();
+                        core.sampler = Sampler::new(seed, base_temp, base_top_p);
+                        core.last_prompt = msg.text.clone(); // Think replaces it with the assembled prompt
+                        (core.last_split, core.bio_dropped, core.bio_deduped) = (None, 0, false);
+                        core.activation_layout = ActivationLayout::default();
                          
                         let start = std::time::Instant::now();
+                        core.interrupted = false;
+
+                        // TIER SELECTION: Think goes to the deep model (if configured)
+                        let (tier, warmup_cost) = if msg.mode == CortexMode::Think {
+                            core.enter_deep()
+                        } else {
+                            (CortexTier::Fast, 0.0)
+                        };
                          
                             // 2. FIFO STREAM LOGIC
                             let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -125,8 +344,7 @@ impl Planet {
                                  },
                                  CortexMode::Think => {
                                      // ACTIVE THOUGHT (Text Generation)
-                                      let available_tokens = if msg.adenosine > 0.8 { 30 } else if msg.adenosine > 0.5 { 60 } else { 120 };
-                                      core.think_stream(&msg.text, &msg.bio_state, msg._long_term_memory.as_deref(), available_tokens, &msg)
+                                      core.think_stream(&msg.text, &msg.bio_state, msg._long_term_memory.as_deref(), available_tokens(msg.adenosine), &msg)
                                  }
                              }
                         }));
@@ -160,7 +378,36 @@ impl Planet {
                             Some(text_response.clone())
                         };
                          
+                        if tier == CortexTier::Deep {
+                            core.leave_deep();
+                        }
+
                         let latency_ms = start.elapsed().as_millis() as u64;
+                        let interrupted = core.interrupted;
+
+                        // THE TAPE: utterances can be regenerated from their record
+                        if synthesized.is_some() {
+                            let record = InferenceRecord {
+                                at: std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+                                inference,
+                                seed,
+                                mode: if msg.mode == CortexMode::Think { "think" } else { "listen" }.to_string(),
+                                tier: if tier == CortexTier::Deep { "deep" } else { "fast" }.to_string(),
+                                prompt: core.last_prompt.clone(),
+                                max_tokens: available_tokens(msg.adenosine),
+                                temperature: base_temp,
+                                top_p: base_top_p,
+                                cortisol: msg.cortisol,
+                                adenosine: msg.adenosine,
+                                dopamine: msg.dopamine,
+                                field: core.field_id.clone(),
+                                interrupted,
+                                output: text_response.clone(),
+                            };
+                            if let Err(e) = core.log.append(&record) {
+                                let _ = thread_thought_tx.send(Thought::new(MindVoice::Error, format!("⚠️ Inference log: {}", e)));
+                            }
+                        }
                         
                         // DEBUG: Trace send
                         /*
@@ -171,16 +418,27 @@ impl Planet {
 
                         let _ = output_tx.send(CortexOutput { 
                             _text: text_response, // Still send as text for legacy logging
-                            neural_echo: echo, 
+                            neural_echo: NeuralEcho::from_logits(&echo, reservoir_size.load(Ordering::Relaxed), config.full_echo),
+                            seed,
                             synthesized_thought: synthesized,
                             top_tokens,
                             inference_latency_ms: latency_ms,
+                            interrupted,
+                            tier,
+                            warmup_cost: warmup_cost + reload_cost,
                             activations,
+                            activation_layout: core.activation_layout,
+                            prompt_split: core.last_split,
+                            bio_dropped: core.bio_dropped,
+                            bio_deduped: core.bio_deduped,
                         });
                     }
                 }
                 Err(e) => {
-                    let _ = thread_thought_tx.send(Thought::new(MindVoice::System, format!("FATAL: Cortex Init Failed: {}", e)));
+                    // PRE-VERBAL: the daemon sees this and stops feeding the cortex
+                    components.report(components::CORTEX, ComponentStatus::Offline(e.to_string()));
+                    components.report(components::SEMANTIC_FIELD, ComponentStatus::Offline("no cortex".to_string()));
+                    let _ = thread_thought_tx.send(Thought::new(MindVoice::Error, format!("FATAL: Cortex Init Failed: {}", e)));
                 }
             }
         });
@@ -188,61 +446,292 @@ impl Planet {
         Ok((input_tx, output_rx))
     }
 
-    fn new(tx: Sender<Thought>) -> Result<Self> {
-        // Attempt CUDA first
-        let (device, model) = match Device::new_cuda(0) {
-            Ok(cuda_device) => {
-                let _ = tx.send(Thought::new(MindVoice::System, "🚀 Neocortex: Using CUDA (GPU Accelerator)".to_string()));
-                match Self::load_model(&cuda_device) {
-                    Ok(m) => (cuda_device, m),
-                    Err(e) => {
-                        let _ = tx.send(Thought::new(MindVoice::System, format!("⚠️ CUDA OOM during Load: {}. Falling back to CPU.", e)));
-                        let cpu_device = Device::Cpu;
-                        let m = Self::load_model(&cpu_device)?;
-                        (cpu_device, m)
-                    }
-                }
+    pub fn new(tx: Sender<Thought>, cancel: Arc<AtomicBool>, backend: Box<dyn CortexBackend>, field_config: FieldConfig, context_tokens: usize) -> Result<Self> {
+        let docs_field = Self::load_docs_field(&tx, backend.as_ref())?;
+        let semantic_field = docs_field.clone(); // Until the first sleep, only the docs pull
+
+        Ok(Self {
+            backend,
+            sampler: Sampler::new(0, 0.85, 0.95), // Reseeded before every inference
+            thought_tx: tx,
+            history: ContextWindow::new(), // Starts tabula rasa
+            context_tokens,
+            history_cap: None,
+            is_internal_monologue: false,
+            semantic_field,
+            docs_field,
+            field_config,
+            cancel,
+            interrupted: false,
+            deep: None,
+            last_memories: Vec::new(),
+            residency: Residency::always(),
+            master_seed: 0,
+            inferences: 0,
+            last_prompt: String::new(),
+            field_id: None,
+            log: InferenceLog::new(""),
+            bio_budget: BioBudgetConfig::default(),
+            last_split: None,
+            bio_dropped: 0,
+            bio_deduped: false,
+            activation_layout: ActivationLayout::default(),
+            temperament: Temperament::default(),
+            threat_terms: Vec::new(),
+        })
+    }
+
+    /// The genome spoke: keep the temperature floor, and bend every field (active
+    /// and parked tier, each with its own vocabulary) toward or away from the
+    /// threatening lexicon.
+    fn set_temperament(&mut self, temperament: Temperament) {
+        if temperament.threat_gravity != 0.0 && self.threat_terms.is_empty() {
+            self.threat_terms = Lexicon::load().threat_terms();
+        }
+        self.temperament = temperament;
+        let gravity = temperament.threat_gravity;
+        Self::tune_field(&mut self.docs_field, self.backend.as_ref(), &self.threat_terms, gravity);
+        Self::tune_field(&mut self.semantic_field, self.backend.as_ref(), &self.threat_terms, gravity);
+        if let Some(tier) = self.deep.as_mut().and_then(|s| s.parked.as_mut()) {
+            Self::tune_field(&mut tier.docs_field, tier.backend.as_ref(), &self.threat_terms, gravity);
+            Self::tune_field(&mut tier.semantic_field, tier.backend.as_ref(), &self.threat_terms, gravity);
+        }
+        let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!(
+            "🧬 Temperament: temperature floor {:.2}, threat gravity {:+.2} ({} terms)",
+            temperament.temperature_floor, gravity, self.threat_terms.len())));
+    }
+
+    /// Threat gravity on the token each threat term starts with (as it appears mid-sentence,
+    /// after whatever the tokenizer prepends to any text, e.g. BOS).
+    fn tune_field(field: &mut SemanticField, backend: &dyn CortexBackend, terms: &[String], gravity: f32) {
+        let specials = backend.tokenize("").map_or(0, |ids| ids.len());
+        let tokens: Vec<u32> = terms.iter()
+            .filter_map(|term| backend.tokenize(&format!(" {}", term)).ok()?.get(specials).copied())
+            .collect();
+        field.set_class_gravity(THREAT_CLASS, &tokens, gravity);
+    }
+
+    /// The fast (or only) substrate named in models.toml.
+    fn load_backend(tx: &Sender<Thought>, models: &ModelsConfig) -> Result<Box<dyn CortexBackend>> {
+        match models.backend.as_str() {
+            "mock" => {
+                let _ = tx.send(Thought::new(MindVoice::System, format!("🧪 Neocortex: MockCortex (seed {})", models.mock_seed)));
+                Ok(Box::new(MockCortex::new(models.mock_seed)))
             },
-            Err(e) => {
-                let _ = tx.send(Thought::new(MindVoice::System, format!("🐌 Neocortex: CPU Fallback (CUDA Init error: {})", e)));
-                let cpu_device = Device::Cpu;
-                let m = Self::load_model(&cpu_device)?;
-                (cpu_device, m)
-            }
+            "ollama" => OllamaCortex::new(models.ollama.clone(), tx.clone()).map(|b| Box::new(b) as Box<dyn CortexBackend>),
+            _ => CandleLlama::load(tx, &models.model_path, &models.tokenizer_path).map(|b| Box::new(b) as Box<dyn CortexBackend>),
+        }
+    }
+
+    /// Seed for the next inference (and count it).
+    /// Startup warm-up (core/selftest.rs): one throwaway Listen and one tiny Think on the
+    /// fast tier, each reported as a selftest thought. Nothing goes out as a CortexOutput
+    /// (no latency bill, no adenosine), the seed counter is untouched (seeded runs repeat)
+    /// and the history starts empty again.
+    fn warmup(&mut self, tx: &Sender<Thought>) {
+        let mut input = CortexInput {
+            mode: CortexMode::Listen,
+            text: selftest::WARMUP_TEXT.to_string(),
+            bio_state: String::new(),
+            bio_context: String::new(),
+            _somatic_state: String::new(),
+            _long_term_memory: None,
+            _cpu_load: 0.0,
+            _ram_pressure: 0.0,
+            _cognitive_impairment: 0.0,
+            entropy: 0.5,
+            adenosine: 0.0,
+            dopamine: 0.3,
+            cortisol: 0.0,
+            _oxytocin: 0.0,
+            temperature_clamp: None,
         };
-        
-        let tokenizer = Tokenizer::from_file(TOKENIZER_FILE).map_err(|e| E::msg(format!("Error cargando tokenizador en {}: {}", TOKENIZER_FILE, e)))?;
-        
-        // LOAD SEMANTIC FIELD (Gravity Well)
+        let start = Instant::now();
+        let listen = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.perceive(&input.text, &input)))
+            .map_err(|_| "panicked".to_string())
+            .and_then(|r| r.map(|(echo, ..)| format!("{} logits", echo.len())).map_err(|e| e.to_string()));
+        let _ = tx.send(selftest::stage_thought(selftest::CORTEX_LISTEN, start.elapsed(), listen));
+
+        input.mode = CortexMode::Think;
+        self.sampler = Sampler::new(0, 0.7, 0.9);
+        let start = Instant::now();
+        let think = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.think_stream(&input.text, "", None, selftest::WARMUP_TOKENS, &input)))
+            .map(|(_, text, ..)| format!("{} chars", text.chars().count()))
+            .map_err(|_| "panicked".to_string());
+        let _ = tx.send(selftest::stage_thought(selftest::CORTEX_THINK, start.elapsed(), think));
+
+        self.history = ContextWindow::new();
+        self.last_prompt.clear();
+        (self.last_split, self.bio_dropped, self.bio_deduped) = (None, 0, false);
+        self.is_internal_monologue = false;
+        self.interrupted = false;
+    }
+
+    fn next_seed(&mut self) -> u64 {
+        let seed = inference_seed(self.master_seed, self.inferences);
+        self.inferences += 1;
+        seed
+    }
+
+    // LOAD SEMANTIC FIELD (Gravity Well)
+    fn load_docs_field(tx: &Sender<Thought>, backend: &dyn CortexBackend) -> Result<SemanticField> {
         let _ = tx.send(Thought::new(MindVoice::System, "📚 Semantic Field: Initializing...".to_string()));
-        let semantic_field = match crate::core::field::SemanticField::from_directory(std::path::Path::new("docs/"), &tokenizer, &device, 1.0) {
+        match SemanticField::from_directory(std::path::Path::new("docs/"), backend, 1.0) {
             Ok(field) => {
                  let _ = tx.send(Thought::new(MindVoice::System, "✅ Semantic Field: Online (Gravity: 1.0)".to_string()));
-                 field
+                 Ok(field)
             },
             Err(e) => {
-                 let _ = tx.send(Thought::new(MindVoice::System, format!("⚠️ Semantic Field Error: {}. Running with zero gravity.", e)));
-                 crate::core::field::SemanticField::from_directory(std::path::Path::new("docs/"), &tokenizer, &device, 0.0)?
+                 let _ = tx.send(Thought::new(MindVoice::Error, format!("⚠️ Semantic Field Error: {}. Running with zero gravity.", e)));
+                 SemanticField::from_directory(std::path::Path::new("docs/"), backend, 0.0)
             }
+        }
+    }
+
+    /// Swap the deep tier into the active slot (loading it if needed).
+    /// Returns the tier that will serve, and the warm-up cost of a (re)load.
+    fn enter_deep(&mut self) -> (CortexTier, f32) {
+        let Some(slot) = self.deep.as_mut() else {
+            return (CortexTier::Fast, 0.0);
         };
 
-        Ok(Self {
-            model,
-            tokenizer,
-            device,
-            logits_processor: LogitsProcessor::new(rand::thread_rng().gen(), Some(0.85), Some(0.95)),
-            thought_tx: tx,
-            history: String::new(), // Starts tabula rasa
-            is_internal_monologue: false,
-            semantic_field,
-        })
+        let mut warmup_cost = 0.0;
+        let mut tier = match slot.parked.take() {
+            Some(t) => t,
+            None => {
+                // WARM-UP: Loading the big model is real work. It costs.
+                let _ = self.thought_tx.send(Thought::new(MindVoice::System, "🧠 Deep Cortex: Waking up (loading Think model)...".to_string()));
+                let t0 = Instant::now();
+                let loaded = CandleLlama::load(&self.thought_tx, &slot.model_path, &slot.tokenizer_path)
+                    .and_then(|b| {
+                        let backend: Box<dyn CortexBackend> = Box::new(b);
+                        let mut docs_field = Self::load_docs_field(&self.thought_tx, backend.as_ref())?;
+                        Self::tune_field(&mut docs_field, backend.as_ref(), &self.threat_terms, self.temperament.threat_gravity);
+                        let semantic_field = Self::blended_field(&docs_field, &self.last_memories, backend.as_ref(), &self.field_config)
+                            .unwrap_or_else(|_| docs_field.clone());
+                        Ok(Tier { backend, docs_field, semantic_field })
+                    });
+                match loaded {
+                    Ok(t) => {
+                        let secs = t0.elapsed().as_secs_f32();
+                        warmup_cost = load_cost(secs);
+                        let _ = self.thought_tx.send(Thought::new(MindVoice::System,
+                            format!("🧠 Deep Cortex: Online in {:.1}s (warm-up cost +{:.2} adenosine)", secs, warmup_cost)));
+                        t
+                    },
+                    Err(e) => {
+                        let _ = self.thought_tx.send(Thought::new(MindVoice::System,
+                            format!("⚠️ Deep Cortex load failed: {}. Thinking with the fast model.", e)));
+                        return (CortexTier::Fast, 0.0);
+                    }
+                }
+            }
+        };
+
+        std::mem::swap(&mut self.backend, &mut tier.backend);
+        std::mem::swap(&mut self.docs_field, &mut tier.docs_field);
+        std::mem::swap(&mut self.semantic_field, &mut tier.semantic_field);
+        slot.parked = Some(tier); // Fast tier waits here while Think runs
+        (CortexTier::Deep, warmup_cost)
+    }
+
+    /// Undo `enter_deep`: fast tier back in the active slot, deep tier parked.
+    fn leave_deep(&mut self) {
+        let Some(slot) = self.deep.as_mut() else { return; };
+        if let Some(mut tier) = slot.parked.take() {
+            std::mem::swap(&mut self.backend, &mut tier.backend);
+            std::mem::swap(&mut self.docs_field, &mut tier.docs_field);
+            std::mem::swap(&mut self.semantic_field, &mut tier.semantic_field);
+            slot.parked = Some(tier);
+        }
+        slot.last_used = Instant::now();
+    }
+
+    fn evict_idle_deep(&mut self) {
+        let Some(slot) = self.deep.as_mut() else { return; };
+        if slot.parked.is_some() && slot.last_used.elapsed() > slot.idle_evict {
+            slot.parked = None; // Drop = weights leave (V)RAM
+            let _ = self.thought_tx.send(Thought::new(MindVoice::System,
+                format!("💤 Deep Cortex evicted after {}s without Think (VRAM freed).", slot.idle_evict.as_secs())));
+        }
+    }
+
+    /// Drop the fast model's weights after idle_unload without a Think, once it has been
+    /// resident for min_resident. Tokenizer, history and fields stay. Returns true on unload.
+    fn unload_if_idle(&mut self, now: Instant) -> bool {
+        let Some(idle) = self.residency.idle_unload else { return false; };
+        let quiet_for = now.saturating_duration_since(self.residency.last_think);
+        if quiet_for < idle || now.saturating_duration_since(self.residency.loaded_at) < self.residency.min_resident {
+            return false;
+        }
+        if !self.backend.unload() {
+            return false; // Remote, or already out
+        }
+        self.residency.suspended = 0;
+        let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!(
+            "💤 Cortex: weights released after {}s without Think (tokenizer, history and field kept). Listen suspended.",
+            quiet_for.as_secs())));
+        true
     }
 
-    fn load_model(device: &Device) -> Result<Llama> {
-        let mut file = std::fs::File::open(MODEL_FILE).map_err(|e| E::msg(format!("No encuentro {}: {}", MODEL_FILE, e)))?;
-        let content = candle_core::quantized::gguf_file::Content::read(&mut file)?;
-        let model = Llama::from_gguf(content, &mut file, device)?;
-        Ok(model)
+    /// Reload the weights if they were released. Returns the grogginess (adenosine)
+    /// and how long the reload took (zero if they were resident).
+    fn ensure_resident(&mut self) -> Result<(f32, Duration)> {
+        if self.backend.is_loaded() {
+            return Ok((0.0, Duration::ZERO));
+        }
+        let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!(
+            "☕ Cortex: waking the weights ({} Listen pulses skipped)...", self.residency.suspended)));
+        let t0 = Instant::now();
+        self.backend.reload()?;
+        let took = t0.elapsed();
+        let cost = load_cost(took.as_secs_f32());
+        self.residency.loaded_at = Instant::now();
+        let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!(
+            "☕ Cortex: weights back in {:.1}s (grogginess +{:.2} adenosine)", took.as_secs_f32(), cost)));
+        Ok((cost, took))
+    }
+
+    /// field = docs * docs_weight + memories * memory_weight (memories empty = docs only)
+    fn blended_field(docs_field: &SemanticField, memories: &[MemoryRecord], backend: &dyn CortexBackend, config: &FieldConfig) -> Result<SemanticField> {
+        if memories.is_empty() {
+            return Ok(docs_field.clone());
+        }
+        let memory_field = SemanticField::from_memories(memories, backend, 1.0)?;
+        Ok(SemanticField::blend(docs_field, config.docs_weight, &memory_field, config.memory_weight))
+    }
+
+    /// SLEEP REWIRING: Bend the probability landscape toward lived experience.
+    /// field = docs * docs_weight + memories * memory_weight
+    fn rebuild_field(&mut self, memories: &[MemoryRecord]) {
+        self.last_memories = memories.to_vec();
+
+        let new_field = match Self::blended_field(&self.docs_field, memories, self.backend.as_ref(), &self.field_config) {
+            Ok(f) => f,
+            Err(e) => {
+                let _ = self.thought_tx.send(Thought::new(MindVoice::Error, format!("⚠️ Semantic Field rebuild failed: {}", e)));
+                return;
+            }
+        };
+        let drift = new_field.divergence_from(&self.semantic_field);
+        self.semantic_field = new_field;
+        self.field_id = match self.log.save_field(memories) {
+            Ok(id) => id,
+            Err(e) => {
+                let _ = self.thought_tx.send(Thought::new(MindVoice::Error, format!("⚠️ Inference log: field not saved ({}). Replays after this sleep won't be exact.", e)));
+                Some("unsaved".to_string())
+            }
+        };
+
+        // The parked deep tier (own vocabulary) rewires too
+        if let Some(tier) = self.deep.as_mut().and_then(|s| s.parked.as_mut()) {
+            if let Ok(f) = Self::blended_field(&tier.docs_field, memories, tier.backend.as_ref(), &self.field_config) {
+                tier.semantic_field = f;
+            }
+        }
+
+        let _ = self.thought_tx.send(Thought::new(MindVoice::System,
+            format!("🧭 Semantic Field rebuilt from {} memories. Drift (KL): {:.4}", memories.len(), drift)));
     }
 
     fn think_stream(&mut self, input: &str, _bio_desc: &str, memory: Option<&str>, max_tokens: usize, chem: &CortexInput) -> (Vec<f32>, String, Vec<(String, f32)>, Vec<f32>) {
@@ -251,42 +740,53 @@ impl Planet {
             self.is_internal_monologue = true;
         }
 
-        // Memory Injection
-        // Memory Injection
-        let mem_str = if let Some(m) = memory {
-            format!("{}\n", m)
-        } else {
-            String::new()
-        };
-        
-        // Rolling Context
-        if self.history.len() > 3000 {
-            let split_idx = self.history.len().saturating_sub(500);
-            self.history = self.history[split_idx..].to_string();
-        }
-        
         // INJECTION (Stream of Consciousness)
         // No labels. No instructions. Just the flow of experience.
-        let injection = if !input.is_empty() {
-             format!("{}\n{}\n{}\n", mem_str, chem.bio_context, input)
-        } else {
-             // Passive existence 
-             format!("{}\n{}\n", mem_str, chem.bio_context)
-        };
-        
-        // DEBUG: Log the prompt to see what breaks it
-        // let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!("🐛 PROMPT: {:?}", injection)));
-        
-        self.history.push_str(&injection);
-
-        let prompt = self.history.clone();
-        
+        self.history.next_turn();
+        let backend = self.backend.as_ref();
+        let count = |text: &str| backend.tokenize(text).map(|t| t.len()).unwrap_or(text.len()); // Bytes >= tokens
+        if let Some(m) = memory {
+            self.history.push(SegmentKind::Memory, m);
+        }
+        // BODY BUDGET: least important fields go first; an unchanged body is not repeated
+        let (bio, dropped) = clamp_bio(&chem.bio_context, &self.bio_budget, &count);
+        self.bio_dropped = dropped;
+        self.bio_deduped = self.history.last_of(SegmentKind::Bio)
+            .is_some_and(|last| same_bio(last, &bio, self.bio_budget.dedup_tolerance));
+        if !self.bio_deduped {
+            self.history.push(SegmentKind::Bio, &bio);
+        }
+        self.history.push(SegmentKind::Percept, input); // Empty = passive existence
+
+        // Rolling Context: newest-first, whole segments, room left for the generation
+        let budget = self.context_tokens.saturating_sub(max_tokens);
+        let budget = self.history_cap.map_or(budget, |cap| budget.min(cap));
+        let assembled = self.history.assemble(budget, &count);
+        let split = assembled.split;
+        let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!(
+            "📏 Prompt: {}/{} tokens ({} segments, {} own) | bio {} mem {} hist {} input {}{}",
+            assembled.tokens, budget, assembled.segments, assembled.own,
+            split.bio, split.memory, split.history, split.input,
+            if self.bio_deduped { " (bio unchanged)" } else { "" })));
+        self.last_split = Some(split);
+        let prompt = assembled.text;
+        self.last_prompt = prompt.clone();
+
+        let (neural_echo, text_out, top_tokens, activations) = self.respond(&prompt, max_tokens, chem);
+        self.history.push(SegmentKind::Output, &text_out);
+
+        (neural_echo, text_out, top_tokens, activations)
+    }
+
+    /// Perceive the prompt, then speak: the resonant word if the field collapsed
+    /// into one, else a generated stream. (History is the caller's business.)
+    fn respond(&mut self, prompt: &str, max_tokens: usize, chem: &CortexInput) -> (Vec<f32>, String, Vec<(String, f32)>, Vec<f32>) {
         // LOBOTOMY PROTCOL: 
         // 1. Perception (Physics)
-        let (neural_echo, resonant_word, top_tokens, activations) = match self.perceive(&prompt, chem) {
+        let (neural_echo, resonant_word, top_tokens, activations) = match self.perceive(prompt, chem) {
             Ok(res) => res,
             Err(e) => {
-                let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!("❌ Neural Echo Failed: {}", e)));
+                let _ = self.thought_tx.send(Thought::new(MindVoice::Error, format!("❌ Neural Echo Failed: {}", e)));
                 (Vec::new(), None, Vec::new(), Vec::new())
             }
         };
@@ -298,7 +798,7 @@ impl Planet {
             burst
         } else {
             // Generate standard response
-            match self.generate(&prompt, max_tokens, chem) {
+            match self.generate(prompt, max_tokens, chem) {
                 Ok(s) => {
                     // DEBUG: See what the raw output is
                     if s.trim().is_empty() {
@@ -313,115 +813,94 @@ impl Planet {
         (neural_echo, text_out, top_tokens, activations)
     }
 
-    // 🔹 BIOLOGICAL TENSOR OPERATIONS 🔹
-    fn apply_semantic_matrix(&self, logits: Tensor, chem: &CortexInput) -> Result<Tensor> {
-        let mut distorted_logits = logits.clone();
-        
+    // 🔹 BIOLOGICAL LOGIT OPERATIONS 🔹
+    // Backend-agnostic: operates on the raw logit vector, whatever produced it.
+    fn apply_semantic_matrix(&mut self, logits: &mut [f32], chem: &CortexInput) {
         // 1. CORTISOL: Anxiety / Tremor (Noise Injection)
         // If stress is high, we inject Gaussian noise into the decision surface.
         // This simulates "shaking" or "racing thoughts".
+        // (Drawn from the inference's seeded stream, so a replay shakes the same way.)
         if chem.cortisol > 0.4 {
-            let noise_scale = (chem.cortisol - 0.4) * 0.5; 
-            let noise = Tensor::randn(0.0, noise_scale as f64, distorted_logits.shape(), &self.device)?
-                        .to_dtype(distorted_logits.dtype())?; // Fix: Cast to match Logits (F32)
-            distorted_logits = (distorted_logits + noise)?;
+            let noise_scale = (chem.cortisol - 0.4) * 0.5;
+            self.sampler.jitter(logits, noise_scale);
         }
-        
-        // 2. ADENOSINE: Brain Fog (Global Inhibition)
-        // If fatigued, we dampen the peaks. Lowers confidence.
+
         // 2. ADENOSINE: Brain Fog (Global Inhibition)
         // If fatigued, we dampen the peaks. Lowers confidence.
         if chem.adenosine > 0.5 {
-            let dampening = 1.0 - ((chem.adenosine - 0.5)); // 1.0 -> 0.5
-            distorted_logits = (distorted_logits * dampening as f64)?;
+            let dampening = 1.0 - (chem.adenosine - 0.5); // 1.0 -> 0.5
+            for l in logits.iter_mut() {
+                *l *= dampening;
+            }
         }
 
         // 3. SEMANTIC GRAVITY (The Bias Matrix)
         // Pull thoughts towards the documentation's probability space.
-        distorted_logits = self.semantic_field.apply(distorted_logits)?;
-        
-
-        
-        Ok(distorted_logits)
+        self.semantic_field.apply(logits);
     }
 
     /// LOBOTOMY MODE: Process input, return probability cloud (Neural Echo) AND Resonant Word.
     /// Does NOT generate text.
     // PASSIVE PERCEPTION (Physics of Information)
     fn perceive(&mut self, input_text: &str, chem: &CortexInput) -> Result<(Vec<f32>, Option<String>, Vec<(String, f32)>, Vec<f32>)> {
-        let tokens = self.tokenizer.encode(input_text, true).map_err(E::msg)?;
-        let token_ids = tokens.get_ids().to_vec();
-        
-        // DEBUG: Print token info if idle
-        /*
-        if input_text == "." || input_text == "scan" {
-             let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!("🔍 PERCEIVE: '{}' -> {} tokens", input_text, token_ids.len())));
-        }
-        */
+        let token_ids = self.backend.tokenize(input_text)?;
 
         if token_ids.is_empty() { return Ok((Vec::new(), None, Vec::new(), Vec::new())); }
 
-        let input_tensor = Tensor::new(token_ids.as_slice(), &self.device)?.unsqueeze(0)?;
-        
         // Forward pass
-        let logits = self.model.forward(&input_tensor, 0)?;
-        let mut logits = logits.squeeze(0)?.to_dtype(DType::F32)?;
-        
-        if logits.rank() == 2 {
-            let seq_len = logits.dim(0)?;
-            logits = logits.i(seq_len - 1)?;
-        }
-        
-        let echo = logits.to_vec1::<f32>()?;
+        let echo = self.backend.forward_logits(&token_ids, 0)?;
 
         // 🔹 APPLY SEMANTIC MATRIX (Field Bias) 🔹
-        let logits_biased = self.apply_semantic_matrix(logits.clone(), chem)?;
-        
+        let mut logits_biased = echo.clone();
+        self.apply_semantic_matrix(&mut logits_biased, chem);
+
         // CHECK RESONANCE
-        let mut resonance = self.semantic_field.find_resonance(&logits_biased).unwrap_or(None);
-        
+        let mut resonance = self.semantic_field.find_resonance(&logits_biased, self.backend.as_ref()).unwrap_or(None);
+
         // Extract Top Tokens
         let top_tokens = Vec::new();
         // (Simplified top 5 extraction for visualization)
         // Note: Real implementation would sort logits
-        
+
         // MANIC OVERRIDE: If High Dopamine (> 0.6) and NO resonance, force a word.
         if resonance.is_none() && chem.dopamine > 0.6 {
              // Force generate a short burst (1-5 tokens)
              // We need to ensure we sample a REAL token, not whitespace.
              let mut burst = String::new();
-             
+
              // Attempt up to 3 times to find a non-empty token
              for _ in 0..3 {
-                 if let Ok(token) = self.logits_processor.sample(&logits_biased) {
-                     if let Ok(fragment) = self.tokenizer.decode(&[token], true) {
-                         if !fragment.trim().is_empty() {
-                             burst.push_str(&fragment);
-                             // If we got a valid token, maybe just stop to be concise/glitchy
-                             break;
-                         }
+                 let token = self.sampler.sample(&logits_biased);
+                 if let Ok(fragment) = self.backend.decode(&[token], true) {
+                     if !fragment.trim().is_empty() {
+                         burst.push_str(&fragment);
+                         // If we got a valid token, maybe just stop to be concise/glitchy
+                         break;
                      }
                  }
              }
-             
+
              if !burst.trim().is_empty() && burst.chars().any(|c| c.is_alphabetic()) {
                  resonance = Some(burst.trim().to_string());
              }
         }
-        
-        // Create "Glass Brain" Activations (Downsample 32k -> 512)
-        // We use a max-pooling approach to catch spikes
-        let activation_size = 512;
-        let chunk_size = echo.len() / activation_size;
-        let activations_vis: Vec<f32> = echo.chunks(chunk_size)
-            .map(|chunk| chunk.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b)))
-            .map(|v| (v + 5.0).max(0.0) / 10.0) // Normalize roughly 0-1 from logits
-            .collect();
-        /*
-        if input_text == "scan" {
-             let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!("🔍 PERCEIVE RESULT: Echo={} Activations={}", echo.len(), activations_vis.len())));
-        }
-        */
+
+        // Create "Glass Brain" Activations
+        // Inside view when the backend has one: layer means, then the mid-layer slice
+        let activations_vis: Vec<f32> = if let Some(hidden) = self.backend.hidden_summary() {
+            self.activation_layout = ActivationLayout::hidden(&hidden);
+            hidden.layer_means.into_iter().chain(hidden.slice).collect()
+        } else {
+            // Logits only (Downsample 32k -> 512): max-pooling to catch spikes
+            let activation_size = 512;
+            let chunk_size = (echo.len() / activation_size).max(1);
+            let pooled: Vec<f32> = echo.chunks(chunk_size)
+                .map(|chunk| chunk.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b)))
+                .map(|v| (v + 5.0).max(0.0) / 10.0) // Normalize roughly 0-1 from logits
+                .collect();
+            self.activation_layout = ActivationLayout { logits: pooled.len(), ..ActivationLayout::default() };
+            pooled
+        };
 
         Ok((echo, resonance, top_tokens, activations_vis))
     }
@@ -429,31 +908,55 @@ impl Planet {
 
     fn generate(&mut self, prompt: &str, max_tokens: usize, chem: &CortexInput) -> Result<String> {
         // Normalize prompt? No, raw stream.
-        
-        let tokens = self.tokenizer.encode(prompt, true).map_err(E::msg)?;
-        let mut token_ids = tokens.get_ids().to_vec();
+
+        // REMOTE SUBSTRATE: The server runs the token loop; we only stream and gate.
+        let params = GenerationParams {
+            max_tokens,
+            temperature: self.sampler.temperature(),
+            top_p: self.sampler.top_p(),
+            stop: STOP_SEQUENCES,
+            seed: self.sampler.seed(),
+        };
+        let cancel = self.cancel.clone();
+        let tx = self.thought_tx.clone();
+        let mut phrase = String::new();
+        let mut interrupted = false;
+        let remote = self.backend.generate_text(prompt, &params, &mut |fragment| {
+            if cancel.load(Ordering::SeqCst) {
+                interrupted = true;
+                return false;
+            }
+            phrase.push_str(fragment);
+            let has_punctuation = fragment.contains(['.', '!', '?', '\n', ',']);
+            if has_punctuation || phrase.len() > 50 {
+                let _ = tx.send(Thought::new(MindVoice::Cortex, phrase.clone()));
+                phrase.clear();
+            }
+            true
+        });
+        if let Some(result) = remote {
+            self.interrupted = interrupted;
+            if !phrase.trim().is_empty() || interrupted {
+                let tail = if interrupted { format!("{}— [INTERRUPTED]", phrase) } else { phrase };
+                let _ = self.thought_tx.send(Thought::new(MindVoice::Cortex, tail));
+            }
+            return result.map(|s| s.trim().to_string());
+        }
+
+        let mut token_ids = self.backend.tokenize(prompt)?;
         if token_ids.is_empty() { return Ok(String::new()); }
 
         let mut pos = 0;
-        
-        let input_tensor = Tensor::new(token_ids.as_slice(), &self.device)?.unsqueeze(0)?;
-        // input_tensor...
-        // let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!("[INFO] LLM Initial forward pass ({} tokens)...", token_ids.len())));
-        let logits = self.model.forward(&input_tensor, pos)?;
-        let mut logits = logits.squeeze(0)?.to_dtype(DType::F32)?;
-        
-        if logits.rank() == 2 {
-            let seq_len = logits.dim(0)?;
-            logits = logits.i(seq_len - 1)?;
-        }
-        
+
+        let mut logits = self.backend.forward_logits(&token_ids, pos)?;
+
         // 🔹 APPLY SEMANTIC MATRIX (Initial) 🔹
-        logits = self.apply_semantic_matrix(logits, chem)?;
-        
+        self.apply_semantic_matrix(&mut logits, chem);
+
         pos += token_ids.len();
 
         let mut gen_tokens = Vec::new();
-        let mut next_token = self.logits_processor.sample(&logits)?;
+        let mut next_token = self.sampler.sample(&logits);
         token_ids.push(next_token);
         gen_tokens.push(next_token);
 
@@ -461,7 +964,13 @@ impl Planet {
 
         for i in 0..max_tokens {
             // STOP ON EOS
-            if next_token == 1 || next_token == 2 { break; }
+            if self.backend.is_eos(next_token) { break; }
+
+            // 0. INTERRUPTION (Someone spoke. Stop at this token boundary.)
+            if self.cancel.load(Ordering::SeqCst) {
+                self.interrupted = true;
+                break;
+            }
 
             // 1. HANDBRAKE (Organic Sequence Repeat Detection)
             if gen_tokens.len() >= 10 {
@@ -471,26 +980,16 @@ impl Planet {
                     break;
                 }
             }
-            if i % 50 == 0 && i > 0 { 
+            if i % 50 == 0 && i > 0 {
                 let _ = self.thought_tx.send(Thought::new(MindVoice::System, format!("[LLM: {}/{} tokens]", i, max_tokens)));
             }
-            // STOP ON EOS
-            if next_token == 2 { break; }
-
-            let input_tensor = Tensor::new(&[next_token], &self.device)?.unsqueeze(0)?;
-            let logits_raw = self.model.forward(&input_tensor, pos)?;
-            let logits_raw = logits_raw.squeeze(0)?.to_dtype(DType::F32)?;
-            let mut logits = if logits_raw.rank() == 2 {
-                let seq_len = logits_raw.dim(0)?;
-                logits_raw.i(seq_len - 1)?
-            } else {
-                logits_raw
-            };
+
+            let mut logits = self.backend.forward_logits(&[next_token], pos)?;
 
             // 🔹 APPLY SEMANTIC MATRIX (Loop) 🔹
-            logits = self.apply_semantic_matrix(logits, chem)?;
+            self.apply_semantic_matrix(&mut logits, chem);
 
-            next_token = self.logits_processor.sample(&logits)?;
+            next_token = self.sampler.sample(&logits);
             token_ids.push(next_token);
             gen_tokens.push(next_token);
             pos += 1;
@@ -500,28 +999,26 @@ impl Planet {
             // We accumulate TOKENS now, not just strings, to preserve spacing.
             let mut pending_chk = current_word_tokens.clone();
             pending_chk.push(next_token);
-            
-            if let Ok(fragment) = self.tokenizer.decode(&pending_chk, false) {
+
+            if let Ok(fragment) = self.backend.decode(&pending_chk, false) {
                   // STOP SEQUENCE DETECTION
                   // User Feedback: Don't cut off the flow! Allow hallucinations.
                   // Only stop on structural breaks that would confuse the prompt loop.
-                  let stop_sequences = ["<|", "USER:", "EVENTO:", "A:", "D:", "C:", "[", "COLMENA", "Respuestabreve", "</s>"];
-                  // Removed: "System:", "Instructions:", "You are", "Qualia:", "Context:", "Response:"
                   let mut should_stop = false;
-                  for stop in stop_sequences {
+                  for stop in STOP_SEQUENCES {
                       if fragment.contains(stop) {
                           should_stop = true;
                           break;
                       }
                   }
                   if should_stop { break; }
-                  
+
                   // PHRASE BOUNDARY detection
-                  let has_punctuation = fragment.contains('.') || fragment.contains('!') || 
+                  let has_punctuation = fragment.contains('.') || fragment.contains('!') ||
                                         fragment.contains('?') || fragment.contains('\n') || fragment.contains(',');
-                                        
+
                   // If we have a punctuation or it's getting long, flush.
-                  if has_punctuation || fragment.len() > 50 { 
+                  if has_punctuation || fragment.len() > 50 {
                        // FORCE INTERNAL: The Daemon decides if this becomes vocal.
                        // All raw stream is just "Cortex" activity.
                        let _ = self.thought_tx.send(Thought::new(MindVoice::Cortex, fragment.clone()));
@@ -534,15 +1031,285 @@ impl Planet {
 
         // Send remaining buffer
         if !current_word_tokens.is_empty() {
-             if let Ok(fragment) = self.tokenizer.decode(&current_word_tokens, false) {
+             if let Ok(fragment) = self.backend.decode(&current_word_tokens, false) {
                  if !fragment.trim().is_empty() {
                      // Force Internal
+                     let fragment = if self.interrupted { format!("{}— [INTERRUPTED]", fragment) } else { fragment };
                      let _ = self.thought_tx.send(Thought::new(MindVoice::Cortex, fragment));
                  }
              }
+        } else if self.interrupted {
+             let _ = self.thought_tx.send(Thought::new(MindVoice::Cortex, "— [INTERRUPTED]".to_string()));
         }
-        
-        let full_text = self.tokenizer.decode(&gen_tokens, true).map_err(E::msg)?;
+
+        let full_text = self.backend.decode(&gen_tokens, true)?;
         Ok(full_text.trim().to_string())
     }
+
+    // 🔹 REPLAY (`aleph replay-inference`) 🔹
+
+    /// Run a recorded inference again: same model tier, field, seed, knobs and prompt.
+    pub fn replay(record: &InferenceRecord, models: &ModelsConfig, config: &CortexConfig) -> Result<String> {
+        let (tx, _thoughts) = channel();
+        let backend: Box<dyn CortexBackend> = if record.tier == "deep" {
+            let model = models.think_model_path.as_deref().ok_or_else(|| anyhow::anyhow!("recorded on the deep tier, but models.think_model_path is not set"))?;
+            let tokenizer = models.think_tokenizer_path.as_deref().unwrap_or(&models.tokenizer_path);
+            Box::new(CandleLlama::load(&tx, model, tokenizer)?)
+        } else {
+            Self::load_backend(&tx, models)?
+        };
+        let mut core = Self::new(tx, Arc::new(AtomicBool::new(false)), backend, config.field.clone(), config.context_tokens)?;
+        core.log = InferenceLog::new(&models.inference_log);
+        if let Some(id) = &record.field {
+            let memories = core.log.load_field(id)?;
+            core.rebuild_field(&memories);
+        }
+        core.rerun(record)
+    }
+
+    /// The inference of `record` on this Planet's model and field.
+    fn rerun(&mut self, record: &InferenceRecord) -> Result<String> {
+        let chem = CortexInput {
+            mode: if record.mode == "think" { CortexMode::Think } else { CortexMode::Listen },
+            text: record.prompt.clone(),
+            bio_state: String::new(),
+            bio_context: String::new(),
+            _somatic_state: String::new(),
+            _long_term_memory: None,
+            _cpu_load: 0.0,
+            _ram_pressure: 0.0,
+            _cognitive_impairment: 0.0,
+            entropy: 0.0,
+            adenosine: record.adenosine,
+            dopamine: record.dopamine,
+            cortisol: record.cortisol,
+            _oxytocin: 0.0,
+            temperature_clamp: None,
+        };
+        self.sampler = Sampler::new(record.seed, record.temperature, record.top_p);
+        self.interrupted = false;
+        Ok(match chem.mode {
+            CortexMode::Think => self.respond(&record.prompt, record.max_tokens, &chem).1,
+            CortexMode::Listen => self.perceive(&record.prompt, &chem)?.1.unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cortex::backend::{HIDDEN_SLICE, MOCK_LAYERS};
+    use crate::cortex::queue::CortexPriority;
+
+    const MASTER: u64 = 7;
+    const PLANET_FAILED: &str = "MockCortex Planet failed to start (run from the repo root: it reads docs/)";
+
+    fn mock_planet(backend: Box<dyn CortexBackend>) -> Planet {
+        let (tx, _thoughts) = channel();
+        Planet::new(tx, Arc::new(AtomicBool::new(false)), backend, FieldConfig::default(), 2048).expect(PLANET_FAILED)
+    }
+
+    /// A MockCortex Planet living through `inputs` the way the spawn loop drives it
+    /// (next seed, fresh sampler, think), recording every inference.
+    fn mock_run(master: u64, mock_seed: u64, inputs: &[&str]) -> Result<Vec<InferenceRecord>> {
+        let (tx, _thoughts) = channel();
+        let mut core = Planet::new(tx, Arc::new(AtomicBool::new(false)), Box::new(MockCortex::new(mock_seed)), FieldConfig::default(), 2048)?;
+        core.master_seed = master;
+        let mut records = Vec::new();
+        for (i, input) in inputs.iter().enumerate() {
+            let chem = CortexInput {
+                mode: CortexMode::Think,
+                text: input.to_string(),
+                bio_state: String::new(),
+                bio_context: format!("pulso {}", 60 + i),
+                _somatic_state: String::new(),
+                _long_term_memory: None,
+                _cpu_load: 0.0,
+                _ram_pressure: 0.0,
+                _cognitive_impairment: 0.0,
+                entropy: 0.5,
+                adenosine: 0.3,
+                dopamine: 0.4,
+                cortisol: 0.7, // Above 0.4: the tremor draws from the seeded stream too
+                _oxytocin: 0.0,
+                temperature_clamp: None,
+            };
+            let inference = core.inferences;
+            let seed = core.next_seed();
+            core.sampler = Sampler::new(seed, 0.9, 0.9);
+            let max_tokens = 24;
+            let (_, output, _, _) = core.think_stream(input, "", None, max_tokens, &chem);
+            records.push(InferenceRecord {
+                at: 0, inference, seed,
+                mode: "think".to_string(), tier: "fast".to_string(),
+                prompt: core.last_prompt.clone(), max_tokens,
+                temperature: 0.9, top_p: 0.9,
+                cortisol: chem.cortisol, adenosine: chem.adenosine, dopamine: chem.dopamine,
+                field: None, interrupted: false, output,
+            });
+        }
+        Ok(records)
+    }
+
+    fn probe_input(mode: CortexMode, text: &str) -> CortexInput {
+        CortexInput {
+            mode,
+            text: text.to_string(),
+            bio_state: String::new(),
+            bio_context: "pulso 60".to_string(),
+            _somatic_state: String::new(),
+            _long_term_memory: None,
+            _cpu_load: 0.0,
+            _ram_pressure: 0.0,
+            _cognitive_impairment: 0.0,
+            entropy: 0.5,
+            adenosine: 0.3,
+            dopamine: 0.4,
+            cortisol: 0.2,
+            _oxytocin: 0.0,
+            temperature_clamp: None,
+        }
+    }
+
+    /// MockCortex with its inside view switched off: a logits-only substrate.
+    struct LogitsOnly(MockCortex);
+
+    impl CortexBackend for LogitsOnly {
+        fn forward_logits(&mut self, tokens: &[u32], pos: usize) -> Result<Vec<f32>> {
+            self.0.forward_logits(tokens, pos)
+        }
+        fn tokenize(&self, text: &str) -> Result<Vec<u32>> {
+            self.0.tokenize(text)
+        }
+        fn decode(&self, tokens: &[u32], skip_special: bool) -> Result<String> {
+            self.0.decode(tokens, skip_special)
+        }
+        fn vocab_size(&self) -> usize {
+            self.0.vocab_size()
+        }
+    }
+
+    #[test]
+    fn the_master_seed_makes_a_run_repeatable_and_every_inference_replays() {
+        let inputs = ["hola", "¿quién está ahí?", "", "el mar suena como estática", "hola"];
+        let first = mock_run(MASTER, 42, &inputs).expect(PLANET_FAILED);
+        let second = mock_run(MASTER, 42, &inputs).expect(PLANET_FAILED);
+        let other = mock_run(MASTER ^ 1, 42, &inputs).expect(PLANET_FAILED);
+
+        let diverged = first.iter().zip(&second).position(|(a, b)| a != b);
+        assert!(first == second, "same master seed: runs diverge at inference {:?}", diverged);
+        let mut seeds: Vec<u64> = first.iter().map(|r| r.seed).collect();
+        seeds.sort_unstable();
+        seeds.dedup();
+        assert_eq!(seeds.len(), inputs.len(), "successive inferences share seeds");
+        assert!(first.iter().zip(&other).all(|(a, b)| a.seed != b.seed), "another master seed gave the same seeds");
+        assert!(first.iter().zip(&other).any(|(a, b)| a.output != b.output), "another master seed gave the same outputs");
+
+        // What `replay-inference` does: a fresh Planet regenerates the exact output
+        for record in &first {
+            let text = mock_planet(Box::new(MockCortex::new(42))).rerun(record).unwrap();
+            assert_eq!(text, record.output, "inference {} (seed {})", record.inference, record.seed);
+        }
+    }
+
+    #[test]
+    fn weights_unload_only_when_idle_and_resident_long_enough_and_reload_with_the_history() {
+        let mut core = mock_planet(Box::new(MockCortex::new(42)));
+        let t0 = Instant::now();
+        core.residency = Residency {
+            idle_unload: Some(Duration::from_secs(60)),
+            min_resident: Duration::from_secs(120),
+            loaded_at: t0,
+            last_think: t0,
+            suspended: 0,
+        };
+        let chem = probe_input(CortexMode::Think, "hola");
+        core.think_stream("hola", "", None, 12, &chem);
+        let count = |text: &str| text.split_whitespace().count();
+        let history = core.history.assemble(4096, &count).text;
+
+        // Anti-thrashing: idle long enough, but not resident long enough
+        assert!(!core.unload_if_idle(t0 + Duration::from_secs(90)), "unloaded before min_resident_secs");
+        assert!(core.backend.is_loaded());
+        // A recent Think keeps the weights
+        core.residency.last_think = t0 + Duration::from_secs(100);
+        assert!(!core.unload_if_idle(t0 + Duration::from_secs(130)), "unloaded 30s after a Think (idle 60s)");
+        // Idle and resident long enough: out, history kept
+        assert!(core.unload_if_idle(t0 + Duration::from_secs(200)));
+        assert!(!core.backend.is_loaded());
+        assert_eq!(core.history.assemble(4096, &count).text, history);
+
+        // Back on demand: reload time charged, history intact, thinking works
+        let (cost, took) = core.ensure_resident().unwrap();
+        assert!(cost > 0.0 && took > Duration::ZERO, "reload: cost {:.3}, took {:?}", cost, took);
+        assert!(core.backend.is_loaded());
+        assert_eq!(core.history.assemble(4096, &count).text, history);
+        let (echo, _, _, _) = core.think_stream("¿sigues ahí?", "", None, 12, &chem);
+        assert!(!echo.is_empty(), "no echo after the reload");
+    }
+
+    #[test]
+    fn a_spawned_planet_skips_listen_while_unloaded_and_queues_thinks_through_the_reload() {
+        let (tx, thoughts) = channel();
+        let config = CortexConfig { staleness_secs: 0.2, warmup: false, ..CortexConfig::default() }; // Shorter than a mock reload
+        let models = ModelsConfig {
+            backend: "mock".to_string(),
+            idle_unload_secs: 1,
+            min_resident_secs: 1,
+            inference_log: String::new(),
+            ..ModelsConfig::default()
+        };
+        let (cortex, outputs) = Planet::spawn(tx, config, models, Arc::new(AtomicUsize::new(256)), Components::default()).expect(PLANET_FAILED);
+        let deadline = Instant::now() + RESIDENCY_POLL * 3;
+        let mut released = false;
+        while !released {
+            match thoughts.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(thought) => released = thought.text.starts_with("💤 Cortex: weights released"),
+                Err(_) => break,
+            }
+        }
+        assert!(released, "the idle weights were never released");
+        cortex.send(probe_input(CortexMode::Listen, "scan"), CortexPriority::Idle);
+        thread::sleep(Duration::from_millis(500));
+        assert_eq!(outputs.try_iter().count(), 0, "a Listen was answered while unloaded");
+
+        // Thinks from several threads during the reload: none stale, none failed
+        let senders: Vec<_> = (0..4).map(|i| {
+            let cortex = cortex.clone();
+            thread::spawn(move || cortex.send(probe_input(CortexMode::Think, &format!("pregunta {}", i)), CortexPriority::Agency))
+        }).collect();
+        for sender in senders {
+            sender.join().unwrap();
+        }
+        let answered: Vec<CortexOutput> = (0..4).filter_map(|_| outputs.recv_timeout(Duration::from_secs(10)).ok()).collect();
+        assert_eq!(answered.len(), 4);
+        let trouble: Vec<String> = thoughts.try_iter()
+            .filter(|t| t.voice == MindVoice::Error || t.text.starts_with("⌛"))
+            .map(|t| t.text)
+            .collect();
+        assert!(trouble.is_empty(), "{:?}", trouble);
+        assert!(answered.iter().map(|o| o.warmup_cost).sum::<f32>() > 0.0, "the reload was not charged");
+    }
+
+    #[test]
+    fn perception_ships_the_mock_layers_then_the_hidden_slice() {
+        let chem = probe_input(CortexMode::Listen, "hola silencio");
+        let mut core = mock_planet(Box::new(MockCortex::new(42)));
+        let (_, _, _, activations) = core.perceive(&chem.text, &chem).unwrap();
+        let layout = core.activation_layout;
+        assert_eq!(layout, ActivationLayout { layers: MOCK_LAYERS, slice: HIDDEN_SLICE, slice_layer: MOCK_LAYERS / 2, logits: 0 });
+        assert_eq!(activations.len(), layout.layers + layout.slice);
+        assert!(activations.iter().all(|v| v.is_finite()));
+    }
+
+    #[test]
+    fn a_backend_without_an_inside_view_ships_pooled_logits() {
+        let chem = probe_input(CortexMode::Listen, "hola silencio");
+        let mut plain = mock_planet(Box::new(LogitsOnly(MockCortex::new(42))));
+        let pooled = plain.perceive(&chem.text, &chem).unwrap().3.len();
+        let layout = plain.activation_layout;
+        assert!(!layout.is_hidden());
+        assert!(layout.logits > 0);
+        assert_eq!(layout.logits, pooled);
+    }
 }
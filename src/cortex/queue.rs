@@ -0,0 +1,323 @@
+// src/cortex/queue.rs
+// THE ANTECHAMBER: Prioritized input queue in front of the Planet
+//
+// The Planet thinks slower than the Star beats. A raw mpsc backs up with idle
+// "scan" pulses and old stimuli, so ALEPH ends up answering something said a
+// minute ago. This queue keeps only what is still worth thinking about:
+//   User > Agency > Idle, FIFO inside each class.
+//   A new Idle pulse replaces the queued one instead of piling up.
+//   Anything older than `staleness` is dropped before inference.
+//   A User input raises the cancel flag: the generation in flight stops at the
+//   next token boundary so the fresh utterance is heard now, not in 5 seconds.
+//   Time the worker spends reloading its weights doesn't count toward
+//   staleness (`postpone`): what arrived during a reload waits, it isn't lost.
+//   Control commands (`command`) travel on a lane of their own: never stale,
+//   never superseded, never cancelling a generation, and served before any input.
+
+use crate::cortex::planet::{CortexInput, Temperament};
+use crate::core::memory_vector::MemoryRecord;
+use crate::cortex::context::Segment;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Who is asking the Planet to think.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CortexPriority {
+    Idle,   // Listen pulses that keep the Neural Echo alive
+    Agency, // Spontaneous speech / rumination
+    User,   // External stimulus (text, voice)
+}
+
+/// Questions for the Planet thread itself (answered between inferences).
+pub enum CortexCommand {
+    /// The history ring, oldest segment first (GET /consciousness, shutdown dump)
+    DumpHistory { reply_tx: Sender<Vec<Segment>> },
+    /// Memory pressure: cap the history the prompt is assembled from (None = the whole window)
+    HistoryBudget(Option<usize>),
+    /// The genome's knobs: temperature floor and threat-lexicon gravity (Genome::phenotype)
+    Temperament(Temperament),
+}
+
+struct Queued {
+    input: CortexInput,
+    priority: CortexPriority,
+    enqueued: Instant,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CortexQueueStats {
+    pub depth: usize,
+    pub superseded: u64,    // Idle pulses replaced by a newer one
+    pub stale_dropped: u64, // Items that waited longer than the staleness window
+}
+
+struct QueueState {
+    items: VecDeque<Queued>,
+    stats: CortexQueueStats,
+    // Pending Semantic Field rebuild (latest consolidation wins)
+    field_rebuild: Option<Vec<MemoryRecord>>,
+    // Control lane (FIFO, outside priorities and staleness)
+    commands: VecDeque<CortexCommand>,
+}
+
+struct Shared {
+    state: Mutex<QueueState>,
+    signal: Condvar,
+    cancel: Arc<AtomicBool>,
+}
+
+/// Sending half. Cheap to clone; the Planet worker stops once every sender is gone.
+#[derive(Clone)]
+pub struct CortexSender {
+    shared: Arc<Shared>,
+}
+
+/// Receiving half, owned by the Planet worker.
+pub struct CortexReceiver {
+    shared: Arc<Shared>,
+    staleness: Duration,
+}
+
+pub fn cortex_queue(staleness: Duration) -> (CortexSender, CortexReceiver) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(QueueState {
+            items: VecDeque::new(),
+            stats: CortexQueueStats::default(),
+            field_rebuild: None,
+            commands: VecDeque::new(),
+        }),
+        signal: Condvar::new(),
+        cancel: Arc::new(AtomicBool::new(false)),
+    });
+    (
+        CortexSender { shared: shared.clone() },
+        CortexReceiver { shared, staleness },
+    )
+}
+
+impl CortexSender {
+    pub fn send(&self, input: CortexInput, priority: CortexPriority) {
+        let mut state = self.shared.state.lock().unwrap();
+
+        // Idle pulses don't accumulate: the freshest one replaces the queued one
+        if priority == CortexPriority::Idle {
+            let before = state.items.len();
+            state.items.retain(|q| q.priority != CortexPriority::Idle);
+            state.stats.superseded += (before - state.items.len()) as u64;
+        }
+
+        // Interrupt whatever the Planet is saying right now. Raised under the lock and
+        // before the push: `recv_by` lowers it when it pops this item, so it can never
+        // be left up against the very input that raised it.
+        if priority == CortexPriority::User {
+            self.shared.cancel.store(true, Ordering::SeqCst);
+        }
+        state.items.push_back(Queued { input, priority, enqueued: Instant::now() });
+        state.stats.depth = state.items.len();
+        drop(state);
+        self.shared.signal.notify_one();
+    }
+
+    /// Ask the Planet to rebuild its Semantic Field from freshly consolidated memories.
+    pub fn rebuild_field(&self, memories: Vec<MemoryRecord>) {
+        self.shared.state.lock().unwrap().field_rebuild = Some(memories);
+        self.shared.signal.notify_one();
+    }
+
+    /// Send a control command (answered once the inference in flight, if any, is done).
+    pub fn command(&self, command: CortexCommand) {
+        self.shared.state.lock().unwrap().commands.push_back(command);
+        self.shared.signal.notify_one();
+    }
+
+    pub fn stats(&self) -> CortexQueueStats {
+        self.shared.state.lock().unwrap().stats
+    }
+}
+
+/// What the worker gets back from `recv`.
+pub enum CortexDelivery {
+    /// Something to think about, and who asked (a cortexd link passes it on)
+    Input(CortexInput, CortexPriority),
+    /// Items dropped for staleness since the last delivery (oldest age in ms)
+    Stale { count: usize, oldest_ms: u64 },
+    /// Rebuild the Semantic Field from the semantic store (after sleep)
+    RebuildField(Vec<MemoryRecord>),
+    /// Nothing arrived before the deadline (`recv_by`)
+    Quiet,
+    /// A control command (`CortexSender::command`)
+    Command(CortexCommand),
+}
+
+impl CortexReceiver {
+    /// Flag polled by the generation loop between tokens.
+    pub fn cancel_flag(&self) -> Arc<AtomicBool> {
+        self.shared.cancel.clone()
+    }
+
+    /// Don't count `by` against the staleness of what is waiting (the worker was busy reloading).
+    pub fn postpone(&self, by: Duration) {
+        let mut state = self.shared.state.lock().unwrap();
+        for item in state.items.iter_mut() {
+            item.enqueued += by;
+        }
+    }
+
+    /// Block until there is something to think about, or until `deadline` (`Quiet`).
+    /// Returns None once all senders are dropped and the queue is empty.
+    pub fn recv_by(&self, deadline: Option<Instant>) -> Option<CortexDelivery> {
+        let mut state = self.shared.state.lock().unwrap();
+        loop {
+            // 0. Control and structural work first (cheap, and every later thought should feel it)
+            if let Some(command) = state.commands.pop_front() {
+                return Some(CortexDelivery::Command(command));
+            }
+            if let Some(memories) = state.field_rebuild.take() {
+                return Some(CortexDelivery::RebuildField(memories));
+            }
+
+            // 1. Drop what is no longer worth answering
+            let now = Instant::now();
+            let mut dropped = 0;
+            let mut oldest = Duration::ZERO;
+            state.items.retain(|q| {
+                let age = now.duration_since(q.enqueued);
+                if age > self.staleness {
+                    dropped += 1;
+                    oldest = oldest.max(age);
+                    false
+                } else {
+                    true
+                }
+            });
+            if dropped > 0 {
+                state.stats.stale_dropped += dropped as u64;
+                state.stats.depth = state.items.len();
+                return Some(CortexDelivery::Stale { count: dropped, oldest_ms: oldest.as_millis() as u64 });
+            }
+
+            // 2. Highest priority first, FIFO within the class
+            let best = state.items.iter().enumerate()
+                .max_by(|(ia, a), (ib, b)| a.priority.cmp(&b.priority).then(ib.cmp(ia)))
+                .map(|(i, _)| i);
+            if let Some(i) = best {
+                let item = state.items.remove(i).unwrap();
+                state.stats.depth = state.items.len();
+                // Whatever raised the flag is being served now (or was already)
+                self.shared.cancel.store(false, Ordering::SeqCst);
+                return Some(CortexDelivery::Input(item.input, item.priority));
+            }
+
+            // 3. Empty: stop if nobody can send anymore, otherwise wait
+            if Arc::strong_count(&self.shared) <= 1 {
+                return None;
+            }
+            let mut wait = Duration::from_millis(500);
+            if let Some(deadline) = deadline {
+                if now >= deadline {
+                    return Some(CortexDelivery::Quiet);
+                }
+                wait = wait.min(deadline - now);
+            }
+            state = self.shared.signal.wait_timeout(state, wait).unwrap().0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cortex::planet::CortexMode;
+    use std::thread;
+
+    fn input(text: &str) -> CortexInput {
+        CortexInput {
+            mode: CortexMode::Think,
+            text: text.to_string(),
+            bio_state: String::new(),
+            bio_context: String::new(),
+            _somatic_state: String::new(),
+            _long_term_memory: None,
+            _cpu_load: 0.0,
+            _ram_pressure: 0.0,
+            _cognitive_impairment: 0.0,
+            entropy: 0.5,
+            adenosine: 0.1,
+            dopamine: 0.3,
+            cortisol: 0.1,
+            _oxytocin: 0.0,
+            temperature_clamp: None,
+        }
+    }
+
+    fn next_input(rx: &CortexReceiver) -> (CortexInput, CortexPriority) {
+        match rx.recv_by(Some(Instant::now() + Duration::from_secs(5))) {
+            Some(CortexDelivery::Input(input, priority)) => (input, priority),
+            _ => panic!("expected an input"),
+        }
+    }
+
+    #[test]
+    fn user_first_then_agency_then_freshest_idle() {
+        let (tx, rx) = cortex_queue(Duration::from_secs(60));
+        tx.send(input("idle 1"), CortexPriority::Idle);
+        tx.send(input("agency"), CortexPriority::Agency);
+        tx.send(input("idle 2"), CortexPriority::Idle);
+        tx.send(input("user 1"), CortexPriority::User);
+        tx.send(input("user 2"), CortexPriority::User);
+
+        let order: Vec<String> = (0..4).map(|_| next_input(&rx).0.text).collect();
+        assert_eq!(order, ["user 1", "user 2", "agency", "idle 2"]);
+        assert_eq!(tx.stats().superseded, 1);
+        assert_eq!(tx.stats().depth, 0);
+    }
+
+    #[test]
+    fn stale_items_are_dropped_and_commands_jump_the_line() {
+        let (tx, rx) = cortex_queue(Duration::from_millis(20));
+        tx.send(input("old"), CortexPriority::User);
+        thread::sleep(Duration::from_millis(40));
+        tx.command(CortexCommand::HistoryBudget(Some(4)));
+        assert!(matches!(rx.recv_by(None), Some(CortexDelivery::Command(CortexCommand::HistoryBudget(Some(4))))));
+        assert!(matches!(rx.recv_by(None), Some(CortexDelivery::Stale { count: 1, .. })));
+        assert!(matches!(rx.recv_by(Some(Instant::now())), Some(CortexDelivery::Quiet)));
+        assert_eq!(tx.stats().stale_dropped, 1);
+    }
+
+    /// A mocked slow model (one token every STEP, polling the flag between tokens
+    /// like `Planet::generate`) stops within one token step of a user input.
+    #[test]
+    fn cancellation_latency_is_one_token_step() {
+        const STEP: Duration = Duration::from_millis(25);
+        let (tx, rx) = cortex_queue(Duration::from_secs(60));
+        let cancel = rx.cancel_flag();
+        let (started_tx, started_rx) = std::sync::mpsc::channel();
+        let worker = thread::spawn(move || {
+            let (_, priority) = next_input(&rx);
+            assert_eq!(priority, CortexPriority::Agency);
+            started_tx.send(()).unwrap();
+            for _ in 0..400 {
+                if cancel.load(Ordering::SeqCst) {
+                    return (Instant::now(), next_input(&rx));
+                }
+                thread::sleep(STEP); // One token
+            }
+            panic!("generation was never interrupted");
+        });
+
+        tx.send(input("rumination"), CortexPriority::Agency);
+        started_rx.recv().unwrap();
+        thread::sleep(STEP * 3);
+        let spoken = Instant::now();
+        tx.send(input("hola"), CortexPriority::User);
+        let (stopped, (heard, priority)) = worker.join().unwrap();
+
+        let latency = stopped.duration_since(spoken);
+        assert!(latency <= STEP + Duration::from_millis(15), "stopped {:?} after the user spoke", latency);
+        assert_eq!((heard.text.as_str(), priority), ("hola", CortexPriority::User));
+    }
+}
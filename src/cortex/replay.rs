@@ -0,0 +1,107 @@
+// src/cortex/replay.rs
+// THE TAPE: Every utterance, with what it takes to say it again
+//
+// The Planet appends one NDJSON line per inference that produced text to
+// `models.inference_log`: its seed, the exact prompt the model saw, the sampling
+// knobs, the chemistry that bent the logits and which Semantic Field was active.
+// A field blended from memories is written once per sleep to
+// `<log dir>/fields/<id>.json` (the docs-only field is rebuilt from docs/).
+//
+//   aleph replay-inference [--line N | --seed S] [--log logs/inferences.ndjson]
+//
+// loads the model, the field and the record and runs the same inference again
+// (`Planet::replay`). An interrupted original stops early; its replay runs on.
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use crate::core::memory_vector::MemoryRecord;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InferenceRecord {
+    pub at: u64,        // Unix seconds
+    pub inference: u64, // Index within the run (seed = inference_seed(master, inference))
+    pub seed: u64,
+    pub mode: String, // "listen" | "think"
+    pub tier: String, // "fast" | "deep"
+    pub prompt: String,
+    pub max_tokens: usize,
+    pub temperature: f32,
+    pub top_p: f32,
+    pub cortisol: f32,
+    pub adenosine: f32,
+    pub dopamine: f32,
+    pub field: Option<String>, // None = docs only
+    pub interrupted: bool,
+    pub output: String,
+}
+
+/// Where the records (and the memory fields they point to) are written. Empty path = off.
+pub struct InferenceLog {
+    path: Option<PathBuf>,
+}
+
+impl InferenceLog {
+    pub fn new(path: &str) -> Self {
+        Self { path: (!path.is_empty()).then(|| PathBuf::from(path)) }
+    }
+
+    fn fields_dir(&self) -> Option<PathBuf> {
+        let path = self.path.as_ref()?;
+        Some(path.parent().unwrap_or(Path::new(".")).join("fields"))
+    }
+
+    pub fn append(&self, record: &InferenceRecord) -> Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+        Ok(())
+    }
+
+    /// Save the memories a field was blended from (once: the id is their content hash).
+    pub fn save_field(&self, memories: &[MemoryRecord]) -> Result<Option<String>> {
+        let Some(dir) = self.fields_dir() else { return Ok(None) };
+        if memories.is_empty() {
+            return Ok(None);
+        }
+        let json = serde_json::to_string(memories)?;
+        let id = format!("{:016x}", fnv1a(json.as_bytes()));
+        let file = dir.join(format!("{}.json", id));
+        if !file.exists() {
+            fs::create_dir_all(&dir)?;
+            fs::write(&file, json)?;
+        }
+        Ok(Some(id))
+    }
+
+    pub fn load_field(&self, id: &str) -> Result<Vec<MemoryRecord>> {
+        let file = self.fields_dir().ok_or_else(|| anyhow!("inference log is off"))?.join(format!("{}.json", id));
+        let json = fs::read_to_string(&file).with_context(|| format!("field {} ({})", id, file.display()))?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// A record by line (1-based) or by seed; neither = the last one.
+    pub fn find(&self, line: Option<usize>, seed: Option<u64>) -> Result<InferenceRecord> {
+        let path = self.path.as_ref().ok_or_else(|| anyhow!("inference log is off (models.inference_log is empty)"))?;
+        let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let mut records: Vec<(usize, InferenceRecord)> = text.lines().enumerate()
+            .filter_map(|(i, l)| serde_json::from_str(l).ok().map(|r| (i + 1, r)))
+            .collect();
+        let found = match (line, seed) {
+            (Some(n), _) => records.into_iter().find(|(i, _)| *i == n),
+            (None, Some(s)) => records.into_iter().rev().find(|(_, r)| r.seed == s),
+            (None, None) => records.pop(),
+        };
+        found.map(|(_, r)| r).ok_or_else(|| anyhow!("no such inference in {}", path.display()))
+    }
+}
+
+/// 64-bit FNV-1a (stable across builds, unlike the std hasher).
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |h, &b| (h ^ b as u64).wrapping_mul(0x100000001b3))
+}
@@ -0,0 +1,163 @@
+// src/cortex/sampler.rs
+// THE DICE: Backend-agnostic token sampling (temperature + nucleus)
+//
+// Works on plain logit vectors so any CortexBackend can be sampled the same way.
+//
+// Each inference gets a fresh Sampler seeded with `inference_seed(master, n)`:
+// the same master seed replays a whole run draw for draw, successive inferences
+// still differ, and the seed of any single one is enough to regenerate it
+// (`aleph replay-inference`). The cortisol tremor draws from the same stream.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, Normal};
+
+pub struct Sampler {
+    rng: StdRng,
+    seed: u64,
+    temperature: f32,
+    top_p: f32,
+}
+
+impl Sampler {
+    pub fn new(seed: u64, temperature: f32, top_p: f32) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            seed,
+            temperature,
+            top_p,
+        }
+    }
+
+    pub fn temperature(&self) -> f32 {
+        self.temperature
+    }
+
+    pub fn top_p(&self) -> f32 {
+        self.top_p
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Draw a token id. Temperature ~0 collapses to argmax.
+    pub fn sample(&mut self, logits: &[f32]) -> u32 {
+        if logits.is_empty() {
+            return 0;
+        }
+        if self.temperature < 1e-3 {
+            return argmax(logits).0 as u32;
+        }
+
+        let scaled: Vec<f32> = logits.iter().map(|l| l / self.temperature).collect();
+        let probs = softmax(&scaled);
+
+        // Nucleus: keep the smallest set of tokens whose mass reaches top_p
+        let mut order: Vec<usize> = (0..probs.len()).collect();
+        order.sort_unstable_by(|&a, &b| probs[b].total_cmp(&probs[a]));
+        let mut mass = 0.0;
+        let mut cut = order.len();
+        for (rank, &i) in order.iter().enumerate() {
+            mass += probs[i];
+            if mass >= self.top_p {
+                cut = rank + 1;
+                break;
+            }
+        }
+
+        let mut r = self.rng.random::<f32>() * mass;
+        for &i in &order[..cut] {
+            r -= probs[i];
+            if r <= 0.0 {
+                return i as u32;
+            }
+        }
+        order[cut - 1] as u32
+    }
+
+    /// Add Gaussian noise (std `scale`) to every logit, from the sampler's own stream.
+    pub fn jitter(&mut self, logits: &mut [f32], scale: f32) {
+        if let Ok(normal) = Normal::new(0.0, scale) {
+            for l in logits.iter_mut() {
+                *l += normal.sample(&mut self.rng);
+            }
+        }
+    }
+}
+
+/// Seed of the `n`th inference of a run started with `master` (splitmix64 of both).
+pub fn inference_seed(master: u64, n: u64) -> u64 {
+    let mut x = master ^ n.wrapping_mul(0x9E3779B97F4A7C15);
+    x = x.wrapping_add(0x9E3779B97F4A7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^ (x >> 31)
+}
+
+pub fn softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let exps: Vec<f32> = logits.iter().map(|l| (l - max).exp()).collect();
+    let sum: f32 = exps.iter().sum();
+    exps.into_iter().map(|e| e / sum.max(f32::MIN_POSITIVE)).collect()
+}
+
+/// (index, value) of the largest logit.
+pub fn argmax(logits: &[f32]) -> (usize, f32) {
+    logits.iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(i, v)| (i, *v))
+        .unwrap_or((0, 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOGITS: [f32; 6] = [0.5, 3.0, -1.0, 2.5, 0.0, 1.0];
+
+    #[test]
+    fn cold_or_narrow_sampling_is_argmax() {
+        assert_eq!(argmax(&LOGITS), (1, 3.0));
+        assert_eq!(Sampler::new(7, 0.0, 0.9).sample(&LOGITS), 1);
+        let mut narrow = Sampler::new(7, 1.0, 1e-6);
+        assert!((0..50).all(|_| narrow.sample(&LOGITS) == 1));
+        assert_eq!(Sampler::new(7, 1.0, 0.9).sample(&[]), 0);
+    }
+
+    #[test]
+    fn same_seed_same_draws() {
+        let draws = |seed| {
+            let mut sampler = Sampler::new(seed, 1.2, 0.95);
+            (0..64).map(|_| sampler.sample(&LOGITS)).collect::<Vec<_>>()
+        };
+        assert_eq!(draws(inference_seed(42, 3)), draws(inference_seed(42, 3)));
+        assert_ne!(draws(inference_seed(42, 3)), draws(inference_seed(42, 4)));
+    }
+
+    #[test]
+    fn nucleus_never_draws_outside_the_top_mass() {
+        // softmax(LOGITS): tokens 1 and 3 hold ~0.85 of the mass
+        let mut sampler = Sampler::new(11, 1.0, 0.8);
+        for _ in 0..500 {
+            assert!(matches!(sampler.sample(&LOGITS), 1 | 3), "drew outside the nucleus");
+        }
+    }
+
+    #[test]
+    fn softmax_is_a_distribution() {
+        let probs = softmax(&[1000.0, 1000.0, -1000.0]);
+        assert!((probs.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!((probs[0] - 0.5).abs() < 1e-6 && probs[2] == 0.0);
+    }
+
+    #[test]
+    fn jitter_is_seeded() {
+        let (mut a, mut b) = (LOGITS, LOGITS);
+        Sampler::new(5, 1.0, 1.0).jitter(&mut a, 0.3);
+        Sampler::new(5, 1.0, 1.0).jitter(&mut b, 0.3);
+        assert_eq!(a, b);
+        assert_ne!(a, LOGITS);
+    }
+}
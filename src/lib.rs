@@ -0,0 +1,9 @@
+// src/lib.rs
+// The organism as a library: the `aleph_zero` binary (main.rs) and the
+// benches (benches/*.rs) link against the same modules.
+
+pub mod core;
+pub mod senses;
+pub mod tui;
+pub mod actuators;
+pub mod cortex;
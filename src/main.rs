@@ -1,10 +1,6 @@
 #![allow(deprecated)]
 
-mod core;
-mod senses;
-mod tui;
-mod actuators;
-mod cortex;
+use aleph_zero::{core, cortex, senses, tui};
 
 use anyhow::Result;
 
@@ -17,21 +13,219 @@ async fn main() -> Result<(), anyhow::Error> {
     match mode {
         "daemon" | "start" | "--headless" | "headless" => {
             // THE STAR (Headless Body)
-            let listen_path = args.iter().position(|r| r == "--listen")
-                .and_then(|i| args.get(i + 1).cloned());
+            // aleph start [--headless | --listen <file> | --synthetic <script|"tone 440hz 0.5 2s; ...">] [--speed 10x] [--profile local|container]
+            //             [--log-gate gate.csv] [--log-membrane membrane.csv] [--soak-interlocutor 2h]
+            let flag = |name: &str| args.iter().position(|a| a == name).and_then(|i| args.get(i + 1).cloned());
+            if let Some(name) = flag("--profile") {
+                let profile = core::profile::Profile::parse(&name)?;
+                std::env::set_var(core::profile::PROFILE_VAR, if profile == core::profile::Profile::Container { "container" } else { "local" });
+            }
+            // Decision CSVs are [decisions] keys: the flags set them like the environment would
+            if let Some(path) = flag("--log-gate") {
+                std::env::set_var("ALEPH_DECISIONS__GATE_CSV", path);
+            }
+            if let Some(path) = flag("--log-membrane") {
+                std::env::set_var("ALEPH_DECISIONS__MEMBRANE_CSV", path);
+            }
+            if let Some(span) = flag("--soak-interlocutor") {
+                let secs = core::interlocutor::parse_span(&span)?.as_secs().max(1);
+                std::env::set_var("ALEPH_INTERLOCUTOR__SOAK_SECS", secs.to_string());
+            }
+            let speed = flag("--speed").map(|s| senses::synthetic::parse_speed(&s)).transpose()?;
 
             let headless = args.iter().any(|a| a == "--headless" || a == "headless");
+            let audio = if headless {
+                Some(senses::ears::SensoryMode::Headless)
+            } else if let Some(path) = flag("--listen") {
+                Some(senses::ears::SensoryMode::File { path, speed: speed.unwrap_or(1.0) })
+            } else if let Some(source) = flag("--synthetic") {
+                let mut script = senses::synthetic::Script::load(&source)?;
+                script.speed = speed.unwrap_or(script.speed);
+                Some(senses::ears::SensoryMode::Synthetic(script))
+            } else {
+                None // Mic, or the browser if there is none
+            };
 
-            core::daemon::run(listen_path, headless)?;
+            if let Some(dir) = core::profile::enter_data_dir(core::profile::Profile::current())? {
+                println!("📦 Data dir: {}", dir.display());
+            }
+            core::daemon::run(audio)?;
+        },
+        "cortexd" => {
+            // THE CORPUS CALLOSUM (Planet + ears for a daemon in [cortexd] split mode; it launches this itself)
+            // aleph cortexd --socket <path> [--clock-origin <unix ms>] [--headless | --audio mic|websocket | --listen <file> | --synthetic <script>] [--speed 10x]
+            let flag = |name: &str| args.iter().position(|a| a == name).and_then(|i| args.get(i + 1).cloned());
+            let socket = flag("--socket").ok_or_else(|| anyhow::anyhow!("Usage: aleph cortexd --socket <path> [audio flags]"))?;
+            if let Some(origin) = flag("--clock-origin") {
+                core::pacing::adopt_clock_origin(origin.parse()?);
+            }
+            let speed = flag("--speed").map(|s| senses::synthetic::parse_speed(&s)).transpose()?.unwrap_or(1.0);
+            let audio = if args.iter().any(|a| a == "--headless") {
+                senses::ears::SensoryMode::Headless
+            } else if let Some(path) = flag("--listen") {
+                senses::ears::SensoryMode::File { path, speed }
+            } else if let Some(source) = flag("--synthetic") {
+                senses::ears::SensoryMode::Synthetic(senses::synthetic::Script::parse(&source)?)
+            } else if flag("--audio").as_deref() == Some("websocket") {
+                senses::ears::SensoryMode::WebSocket
+            } else {
+                senses::ears::SensoryMode::Mic
+            };
+            cortex::cortexd::run_child(&socket, audio)?;
+        },
+        "ctl" => {
+            // THE WORKBENCH (Admin commands over the IPC socket; no command = REPL)
+            // aleph ctl [--socket <path>] [--secret <s>] [<command> [args...]]   (aleph ctl help)
+            let config = core::config::AlephConfig::load();
+            let mut socket = config.runtime.ipc_socket.clone();
+            let mut secret = config.admin.secret.clone();
+            let mut words = Vec::new();
+            let mut rest = args.iter().skip(2);
+            while let Some(arg) = rest.next() {
+                match arg.as_str() {
+                    "--socket" => socket = rest.next().cloned().ok_or_else(|| anyhow::anyhow!("--socket needs a path"))?,
+                    "--secret" => secret = rest.next().cloned().ok_or_else(|| anyhow::anyhow!("--secret needs a value"))?,
+                    _ => words.push(arg.clone()),
+                }
+            }
+            let mut ctl = core::admin::Ctl::connect(std::path::Path::new(&socket), &secret)?;
+            match words.split_first() {
+                Some((command, command_args)) => {
+                    let (ok, reply) = ctl.call(command, command_args)?;
+                    println!("{}", serde_json::to_string_pretty(&reply)?);
+                    if !ok {
+                        std::process::exit(1);
+                    }
+                },
+                None => ctl.repl(std::io::stdin().lock(), &mut std::io::stdout())?,
+            }
         },
         "view" | "tui" => {
             // THE TELESCOPE (Visualizer)
             println!("🔭 Connecting to ALEPH Star System...");
             tui::client::run()?;  
         },
+        "export-graph" => {
+            // THE CARTOGRAPHER (Offline connectome export, no daemon needed)
+            // aleph export-graph <out.graphml|out.json> [--threshold 0.1] [--brain reservoir.json]
+            let flag = |name: &str| args.iter().position(|a| a == name).and_then(|i| args.get(i + 1).cloned());
+            let out = args.get(2).filter(|a| !a.starts_with("--")).cloned()
+                .ok_or_else(|| anyhow::anyhow!("Usage: aleph export-graph <out.graphml|out.json> [--threshold 0.1] [--brain reservoir.json]"))?;
+            let threshold: f32 = flag("--threshold").map(|t| t.parse()).transpose()?.unwrap_or(0.1);
+            let brain_path = flag("--brain").unwrap_or_else(|| "reservoir.json".to_string());
+
+            let brain = core::reservoir::FractalReservoir::read_from_disk(&brain_path)?;
+            let edges = brain.export_graph(&out, threshold)?;
+            let expected = brain.count_edges(threshold);
+            if edges != expected {
+                anyhow::bail!("export wrote {} edges but {} synapses exceed {}", edges, expected, threshold);
+            }
+            println!("🗺️  {} neurons, {} edges (|w| > {}) -> {}", brain.current_size(), edges, threshold, out);
+        },
+        "diff-brain" => {
+            // THE ARCHAEOLOGIST (What learning happened between two snapshots)
+            // aleph diff-brain <old.bin|old.json> <new.bin|new.json> [--top 10] [--json]
+            let usage = "Usage: aleph diff-brain <old.bin> <new.bin> [--top 10] [--json]";
+            let old_path = args.get(2).ok_or_else(|| anyhow::anyhow!(usage))?;
+            let new_path = args.get(3).ok_or_else(|| anyhow::anyhow!(usage))?;
+            let top_k: usize = args.iter().position(|a| a == "--top")
+                .and_then(|i| args.get(i + 1))
+                .map(|k| k.parse()).transpose()?.unwrap_or(10);
+
+            let old = core::brain_diff::load_brain(old_path)?;
+            let new = core::brain_diff::load_brain(new_path)?;
+            let report = core::brain_diff::diff(&old, &new, top_k);
+            if args.iter().any(|a| a == "--json") {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print!("{}", report);
+            }
+        },
+        "events" => {
+            // THE LEDGER (Per-day counts and intervals of the research event log)
+            // aleph events summarize [<events.ndjson>...] [--json | --openmetrics]
+            let usage = "Usage: aleph events summarize [<events.ndjson>...] [--json | --openmetrics]";
+            if args.get(2).map(String::as_str) != Some("summarize") {
+                anyhow::bail!(usage);
+            }
+            let mut paths: Vec<std::path::PathBuf> = args.iter().skip(3)
+                .filter(|a| !a.starts_with("--"))
+                .map(std::path::PathBuf::from)
+                .collect();
+            if paths.is_empty() {
+                let config = core::config::AlephConfig::load().ledger;
+                paths = core::ledger::files(&config.path, config.keep); // With its rotated files, oldest first
+            }
+            if paths.is_empty() {
+                anyhow::bail!("no ledger found ({})", usage);
+            }
+            let summary = core::ledger::summarize_files(&paths)?;
+            if args.iter().any(|a| a == "--json") {
+                println!("{}", serde_json::to_string_pretty(&summary)?);
+            } else if args.iter().any(|a| a == "--openmetrics") {
+                print!("{}", summary.to_openmetrics());
+            } else {
+                print!("{}", summary.to_text());
+            }
+        },
+        "replay-inference" => {
+            // THE TAPE (Say a recorded utterance again: same model, field, seed and prompt)
+            // aleph replay-inference [--line N | --seed S] [--log logs/inferences.ndjson]
+            let flag = |name: &str| args.iter().position(|a| a == name).and_then(|i| args.get(i + 1).cloned());
+            let line: Option<usize> = flag("--line").map(|s| s.parse()).transpose()?;
+            let seed: Option<u64> = flag("--seed").map(|s| s.parse()).transpose()?;
+            let mut models = core::config::ModelsConfig::load();
+            if let Some(log) = flag("--log") {
+                models.inference_log = log;
+            }
+
+            let record = cortex::replay::InferenceLog::new(&models.inference_log).find(line, seed)?;
+            println!("🎞️  Inference {} (seed {}, {} on the {} tier, temp {:.2}, top-p {:.2}{})", record.inference, record.seed,
+                record.mode, record.tier, record.temperature, record.top_p, if record.interrupted { ", interrupted" } else { "" });
+            println!("   original: {}", record.output);
+            let replayed = cortex::planet::Planet::replay(&record, &models, &core::config::AlephConfig::load().cortex)?;
+            println!("   replayed: {}", replayed);
+            let same = if record.interrupted { replayed.starts_with(&record.output) } else { replayed == record.output };
+            println!("{}", if same { "✅ Identical" } else { "❌ Different (another model file or backend?)" });
+            if !same {
+                std::process::exit(1);
+            }
+        },
+        "soak" => {
+            // THE LONG NIGHT (Days of simulated life, invariants every simulated minute)
+            // aleph soak [--hours 72] [--seed 7] [--size 200] [--max-size 400] [--hz 20] [--rss-mb 64] [--snapshot soak-failure.json] [--json]
+            let flag = |name: &str| args.iter().position(|a| a == name).and_then(|i| args.get(i + 1).cloned());
+            let defaults = core::soak::SoakOptions::default();
+            let options = core::soak::SoakOptions {
+                hours: flag("--hours").map(|s| s.parse()).transpose()?.unwrap_or(defaults.hours),
+                seed: flag("--seed").map(|s| s.parse()).transpose()?.unwrap_or(defaults.seed),
+                size: flag("--size").map(|s| s.parse()).transpose()?.unwrap_or(defaults.size),
+                max_size: flag("--max-size").map(|s| s.parse()).transpose()?.unwrap_or(defaults.max_size),
+                hz: flag("--hz").map(|s| s.parse()).transpose()?.unwrap_or(defaults.hz),
+                rss_growth_mb: flag("--rss-mb").map(|s| s.parse()).transpose()?.unwrap_or(defaults.rss_growth_mb),
+                snapshot: flag("--snapshot").unwrap_or(defaults.snapshot.clone()),
+                ..defaults
+            };
+
+            let report = core::soak::run(&options);
+            if args.iter().any(|a| a == "--json") {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                for v in &report.violations {
+                    println!("❌ minute {} ({:.1}h) {}: {}", v.minute, v.minute as f32 / 60.0, v.invariant, v.detail);
+                }
+                if let Some(path) = &report.snapshot {
+                    println!("📸 Snapshot: {}", path);
+                }
+                println!("{}", report.line());
+            }
+            if !report.passed() {
+                std::process::exit(1);
+            }
+        },
         _ => {
             eprintln!("Unknown mode: {}", mode);
-            eprintln!("Usage: aleph [start|view]");
+            eprintln!("Usage: aleph [start|cortexd|ctl|view|export-graph|diff-brain|events|replay-inference|soak]");
         }
     }
 
@@ -0,0 +1,149 @@
+// src/senses/agc.rs
+// THE EARDRUM: Every voice at the level the ears were tuned for
+//
+// The gates downstream (attention 0.001, Whisper peak 0.05, startle on the
+// bands) were tuned on a local CPAL microphone. Browser microphones arrive over
+// the WebSocket anywhere from 20dB quieter (ALEPH never hears them) to hot
+// enough to keep it startled. Before the cochlea:
+//
+//   1. `sanitize`: NaN/Inf and absurd samples (|x| > ABSURD) become silence,
+//      so one broken Float32Array can't poison the FFT
+//   2. `Agc::process`: a slow RMS estimate (in log, window_secs, only fed by chunks
+//      above the noise floor) sets a gain toward target_rms, clamped to
+//      min..max_gain; a peak limiter lowers it for any chunk that would clip
+//
+// The applied gain travels with every spectrum frame (`AudioSpectrum.input_gain`)
+// so the dashboard can tell "quiet user" from "dead mic".
+
+use crate::core::config::AgcConfig;
+
+/// Samples beyond this are not audio (Float32 PCM lives in -1..1, some clipping allowed).
+pub const ABSURD: f32 = 16.0;
+
+/// Silence every non-finite or absurd sample. Returns how many there were.
+pub fn sanitize(samples: &mut [f32]) -> usize {
+    let mut fixed = 0;
+    for sample in samples.iter_mut() {
+        if !sample.is_finite() || sample.abs() > ABSURD {
+            *sample = 0.0;
+            fixed += 1;
+        }
+    }
+    fixed
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt()
+}
+
+#[derive(Debug, Clone)]
+pub struct Agc {
+    config: AgcConfig,
+    sample_rate: u32,
+    level: Option<f32>, // Slow ln(RMS) of what was heard above the floor (None = nothing yet)
+    gain: f32,          // Applied to the last chunk
+}
+
+impl Agc {
+    pub fn new(config: AgcConfig, sample_rate: u32) -> Self {
+        Self { config, sample_rate: sample_rate.max(1), level: None, gain: 1.0 }
+    }
+
+    /// Gain applied to the last chunk (1.0 before the first).
+    pub fn gain(&self) -> f32 {
+        self.gain
+    }
+
+    /// Normalize `chunk` in place. Returns the gain applied.
+    pub fn process(&mut self, chunk: &mut [f32]) -> f32 {
+        if chunk.is_empty() {
+            return self.gain;
+        }
+        let c = &self.config;
+        let loudness = rms(chunk);
+        if loudness > c.noise_floor {
+            let alpha = (chunk.len() as f32 / (c.window_secs.max(0.01) * self.sample_rate as f32)).min(1.0);
+            // In log: a voice getting 10x quieter is caught as fast as one getting 10x louder
+            let heard = loudness.ln();
+            self.level = Some(self.level.map_or(heard, |level| level + (heard - level) * alpha));
+        }
+        let wanted = self.level.map_or(1.0, |level| (c.target_rms / level.exp()).clamp(c.min_gain, c.max_gain));
+
+        // LIMITER: a loud onset rides the old (high) gain until the level catches up
+        let peak = chunk.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+        self.gain = if peak * wanted > c.ceiling { c.ceiling / peak } else { wanted };
+        for sample in chunk.iter_mut() {
+            *sample *= self.gain;
+        }
+        self.gain
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: u32 = 44_100;
+
+    /// A sine at `amplitude` (RMS = amplitude/√2), `secs` long, in 1024-sample chunks.
+    fn tone(amplitude: f32, secs: f32, phase: &mut usize) -> Vec<Vec<f32>> {
+        let n = (secs * SAMPLE_RATE as f32) as usize;
+        let samples: Vec<f32> = (0..n).map(|i| {
+            let t = (*phase + i) as f32 / SAMPLE_RATE as f32;
+            amplitude * (std::f32::consts::TAU * 220.0 * t).sin()
+        }).collect();
+        *phase += n;
+        samples.chunks(1024).map(|c| c.to_vec()).collect()
+    }
+
+    /// Seconds for ~99% of a level step (in log).
+    fn settle(config: &AgcConfig) -> f32 {
+        config.window_secs.max(0.5) * 5.0
+    }
+
+    #[test]
+    fn quiet_and_loud_voices_converge_to_target_under_the_ceiling() {
+        let config = AgcConfig::default();
+        // RMS ~0.007 and ~0.5, one after the other and in both orders
+        for (name, amplitudes) in [("quiet -> loud", [0.01, 0.7]), ("loud -> quiet", [0.7, 0.01])] {
+            let mut agc = Agc::new(config.clone(), SAMPLE_RATE);
+            let mut phase = 0;
+            let mut peak = 0.0f32;
+            for amplitude in amplitudes {
+                let chunks = tone(amplitude, settle(&config), &mut phase);
+                let last_second = (SAMPLE_RATE as usize / 1024).max(1);
+                let mut tail = Vec::new();
+                for (i, mut chunk) in chunks.iter().cloned().enumerate() {
+                    agc.process(&mut chunk);
+                    peak = peak.max(chunk.iter().fold(0.0f32, |m, s| m.max(s.abs())));
+                    if i + last_second >= chunks.len() {
+                        tail.extend(chunk);
+                    }
+                }
+                let out = rms(&tail);
+                assert!((out - config.target_rms).abs() <= config.target_rms * 0.1,
+                    "{}: input RMS {:.4} settled at {:.4} (target {:.3}, gain {:.2})",
+                    name, amplitude / 2f32.sqrt(), out, config.target_rms, agc.gain());
+            }
+            assert!(peak <= config.ceiling + 1e-4, "{}: a sample left at {:.3} (ceiling {:.2})", name, peak, config.ceiling);
+        }
+    }
+
+    #[test]
+    fn room_noise_below_the_floor_leaves_the_gain_alone() {
+        let config = AgcConfig::default();
+        let mut agc = Agc::new(config.clone(), SAMPLE_RATE);
+        let mut phase = 0;
+        for mut chunk in tone(config.noise_floor, settle(&config), &mut phase) {
+            agc.process(&mut chunk);
+        }
+        assert!((agc.gain() - 1.0).abs() <= 1e-6, "noise below the floor moved the gain to {:.2}", agc.gain());
+    }
+
+    #[test]
+    fn sanitize_silences_nan_inf_and_absurd_samples() {
+        let mut broken = vec![0.1, f32::NAN, -0.2, f32::INFINITY, f32::NEG_INFINITY, 1e30, -ABSURD, 1.5];
+        assert_eq!(sanitize(&mut broken), 4);
+        assert_eq!(broken, [0.1, 0.0, -0.2, 0.0, 0.0, 0.0, -ABSURD, 1.5]);
+    }
+}
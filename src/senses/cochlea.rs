@@ -0,0 +1,280 @@
+// src/senses/cochlea.rs
+// THE COCHLEA: Samples -> frequency bands, in Hertz
+//
+// The ears used to FFT whatever chunk the device handed over (first 1024
+// samples, rectangular window) and read fixed bin indices: "bin 6" was 94 Hz at
+// 16kHz and 281 Hz at 48kHz. Here every band is defined in Hz and converted to
+// bins with the real sample rate:
+//
+//   bass 20-250 Hz | mids 250-2000 Hz | highs 2000-20000 Hz (capped at Nyquist)
+//   embedding: BANDS linear bands of BAND_HZ over 0-8 kHz (the Nyquist of the
+//   slowest rate we meet), so band i is the same sound at 16k, 44.1k or 48k
+//
+// Samples are buffered and analyzed every `hop` samples over the last
+// `fft_size` (Hann window, so overlapping frames don't smear a tone across
+// bands). Magnitudes are normalized by the window sum, which keeps the old
+// scale: a full-scale sine reads ~the same as it did unwindowed.
+
+use rustfft::{Fft, FftPlanner, num_complex::Complex};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Embedding resolution (the Auditory region's input width).
+pub const BANDS: usize = 64;
+/// Embedding coverage: 0..BANDS*BAND_HZ = 0..8 kHz.
+pub const BAND_HZ: f32 = 125.0;
+
+const BASS: (f32, f32) = (20.0, 250.0);
+const MIDS: (f32, f32) = (250.0, 2000.0);
+const HIGHS: (f32, f32) = (2000.0, 20000.0);
+/// Same visual gain as the original analyzer.
+const GAIN: f32 = 100.0;
+
+/// What the ears publish per frame (bands plus voice detection), shared with the
+/// daemon and every telemetry client.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AudioSpectrum {
+    pub rms: f32,
+    pub bass: f32, // 20-250 Hz
+    pub mids: f32, // 250-2000 Hz
+    pub highs: f32, // 2000-20000 Hz
+    pub is_voice: bool, 
+    // Direct Sensory Projection (64-band spectrogram)
+    pub frequency_embedding: Vec<f32>,
+    /// Gain the AGC applied before analysis (1 = untouched, see senses/agc.rs).
+    #[serde(default)]
+    pub input_gain: f32,
+    /// When the frame's last sample was captured, on the daemon's monotonic clock
+    /// (µs, core/pacing.rs; 0 = unknown). Origin + this vs now = end-to-end latency.
+    #[serde(default)]
+    pub captured_us: u64,
+    /// When the ears handed it to the channel, and when the daemon took it off
+    /// the channel (same clock; 0 = unknown). Injection is the last stamp (core/latency.rs).
+    #[serde(default)]
+    pub sent_us: u64,
+    #[serde(default)]
+    pub received_us: u64,
+}
+
+/// One analyzed frame.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub rms: f32,
+    pub bass: f32,
+    pub mids: f32,
+    pub highs: f32,
+    pub bands: Vec<f32>, // BANDS values, 0..1
+    pub lag_samples: usize, // Samples of the pushed chunk that came after this frame
+}
+
+pub struct Cochlea {
+    fft: Arc<dyn Fft<f32>>,
+    fft_size: usize,
+    hop: usize,
+    stride: usize, // Hops per analyzed frame (1 = every hop; more = latency shedding)
+    sample_rate: u32,
+    window: Vec<f32>,
+    window_sum: f32,
+    samples: VecDeque<f32>,
+    pending: usize,              // New samples since the last frame
+    coarse: [(usize, usize); 3], // Bin ranges of bass / mids / highs
+    band_bins: Vec<(usize, usize)>,
+}
+
+impl Cochlea {
+    /// `fft_size` is rounded up to a power of two; `hop` is clamped to 1..=fft_size.
+    pub fn new(sample_rate: u32, fft_size: usize, hop: usize) -> Self {
+        let fft_size = fft_size.clamp(64, 16384).next_power_of_two();
+        let window: Vec<f32> = (0..fft_size)
+            .map(|i| 0.5 - 0.5 * (std::f32::consts::TAU * i as f32 / fft_size as f32).cos())
+            .collect();
+        let window_sum = window.iter().sum();
+        let mut cochlea = Self {
+            fft: FftPlanner::new().plan_fft_forward(fft_size),
+            fft_size,
+            hop: hop.clamp(1, fft_size),
+            stride: 1,
+            sample_rate: 0,
+            window,
+            window_sum,
+            samples: VecDeque::with_capacity(fft_size),
+            pending: 0,
+            coarse: [(0, 0); 3],
+            band_bins: Vec::new(),
+        };
+        cochlea.set_sample_rate(sample_rate);
+        cochlea
+    }
+
+    /// Re-derive the bin ranges (files carry their own rate).
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        if sample_rate == self.sample_rate || sample_rate == 0 {
+            return;
+        }
+        self.sample_rate = sample_rate;
+        self.coarse = [self.bins(BASS.0, BASS.1), self.bins(MIDS.0, MIDS.1), self.bins(HIGHS.0, HIGHS.1)];
+        self.band_bins = (0..BANDS)
+            .map(|i| self.bins(i as f32 * BAND_HZ, (i + 1) as f32 * BAND_HZ))
+            .collect();
+        self.samples.clear();
+        self.pending = 0;
+    }
+
+    pub fn fft_size(&self) -> usize {
+        self.fft_size
+    }
+
+    /// Analyze one frame every `stride` hops (1 = all of them). Fewer FFTs when
+    /// the audio path runs over its latency budget.
+    pub fn set_stride(&mut self, stride: usize) {
+        self.stride = stride.max(1);
+    }
+
+    fn bin_hz(&self) -> f32 {
+        self.sample_rate as f32 / self.fft_size as f32
+    }
+
+    /// Bins whose center falls in [lo, hi), capped at Nyquist. Never empty: a band
+    /// narrower than one bin reads the bin nearest to its center.
+    fn bins(&self, lo: f32, hi: f32) -> (usize, usize) {
+        let nyquist_bin = self.fft_size / 2;
+        let bin_hz = self.bin_hz();
+        let start = ((lo / bin_hz).ceil() as usize).min(nyquist_bin);
+        let end = ((hi / bin_hz).ceil() as usize).min(nyquist_bin + 1);
+        if end > start {
+            (start, end)
+        } else {
+            let center = (((lo + hi) / 2.0 / bin_hz).round() as usize).min(nyquist_bin);
+            (center, center + 1)
+        }
+    }
+
+    /// Feed samples; returns one frame per completed hop (zero, one or several).
+    pub fn push(&mut self, data: &[f32]) -> Vec<Frame> {
+        let mut frames = Vec::new();
+        for (i, &s) in data.iter().enumerate() {
+            if self.samples.len() == self.fft_size {
+                self.samples.pop_front();
+            }
+            self.samples.push_back(s);
+            self.pending += 1;
+            if self.pending >= self.hop * self.stride && self.samples.len() == self.fft_size {
+                self.pending = 0;
+                frames.push(Frame { lag_samples: data.len() - 1 - i, ..self.analyze() });
+            }
+        }
+        frames
+    }
+
+    fn analyze(&self) -> Frame {
+        let rms = (self.samples.iter().map(|s| s * s).sum::<f32>() / self.fft_size as f32).sqrt();
+        let mut buffer: Vec<Complex<f32>> = self.samples.iter().zip(&self.window)
+            .map(|(&s, &w)| Complex::new(s * w, 0.0))
+            .collect();
+        self.fft.process(&mut buffer);
+
+        let scale = GAIN / self.window_sum;
+        let level = |(start, end): (usize, usize)| -> f32 {
+            buffer[start..end].iter().map(|c| c.norm()).sum::<f32>() / (end - start) as f32 * scale
+        };
+        Frame {
+            rms,
+            bass: level(self.coarse[0]).clamp(0.0, 1.0),
+            mids: level(self.coarse[1]).clamp(0.0, 1.0),
+            highs: level(self.coarse[2]).clamp(0.0, 1.0),
+            bands: self.band_bins.iter().map(|&b| (level(b) * 2.0).clamp(0.0, 1.0)).collect(),
+            lag_samples: 0,
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+}
+
+/// Capture stamps for frames: a chunk arrives when its last sample does, so a
+/// frame that ended `lag_samples` earlier was captured that much before. Stamps
+/// never go backwards (a callback that runs late, then early, can't reorder them).
+#[derive(Debug, Default)]
+pub struct CaptureClock {
+    last_us: u64,
+}
+
+impl CaptureClock {
+    pub fn stamp(&mut self, arrival_us: u64, lag_samples: usize, sample_rate: u32) -> u64 {
+        let lag_us = lag_samples as u64 * 1_000_000 / sample_rate.max(1) as u64;
+        self.last_us = arrival_us.saturating_sub(lag_us).max(self.last_us);
+        self.last_us
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The [ears] defaults.
+    const FFT_SIZE: usize = 1024;
+    const HOP: usize = 512;
+
+    #[test]
+    fn pure_tones_peak_in_their_own_band() {
+        // Tones sit inside their band, not on an edge (where half a bin of leakage decides)
+        const TONES: [f32; 6] = [100.0, 440.0, 1060.0, 2560.0, 3190.0, 6060.0];
+        const AMPLITUDE: f32 = 0.01; // Quiet enough that no band clips at 1.0
+        for rate in [16_000u32, 48_000] {
+            for tone in TONES {
+                let mut cochlea = Cochlea::new(rate, FFT_SIZE, HOP);
+                let n = cochlea.fft_size() * 4;
+                let signal: Vec<f32> = (0..n)
+                    .map(|i| AMPLITUDE * (std::f32::consts::TAU * tone * i as f32 / rate as f32).sin())
+                    .collect();
+                let frame = cochlea.push(&signal).pop().expect("no frame after four windows");
+
+                let expected = (tone / BAND_HZ) as usize;
+                let peak = frame.bands.iter().enumerate()
+                    .max_by(|a, b| a.1.total_cmp(b.1))
+                    .map(|(i, _)| i)
+                    .unwrap();
+                assert_eq!(peak, expected, "{} Hz @ {} Hz: peak in band {} ({}-{} Hz)",
+                    tone, rate, peak, peak as f32 * BAND_HZ, (peak + 1) as f32 * BAND_HZ);
+
+                let coarse = [("bass", frame.bass, BASS), ("mids", frame.mids, MIDS), ("highs", frame.highs, HIGHS)];
+                let loudest = coarse.iter().max_by(|a, b| a.1.total_cmp(&b.1)).map(|c| c.0).unwrap();
+                let owner = coarse.iter().find(|c| tone >= c.2 .0 && tone < c.2 .1).map(|c| c.0).unwrap();
+                assert_eq!(loudest, owner, "{} Hz @ {} Hz: loudest coarse band (bass {:.3} mids {:.3} highs {:.3})",
+                    tone, rate, frame.bass, frame.mids, frame.highs);
+            }
+        }
+    }
+
+    #[test]
+    fn jittery_chunks_get_monotonic_stamps_inside_their_chunk() {
+        // Chunks of uneven size, one arriving late and the next on time
+        const RATE: u32 = 48_000;
+        let mut cochlea = Cochlea::new(RATE, FFT_SIZE, HOP);
+        let mut clock = CaptureClock::default();
+        let mut arrival_us = 0u64;
+        let mut stamps = Vec::new();
+        for (i, chunk) in [480usize, 4096, 1024, 333, 8192, 2048, 960, 4800].iter().enumerate() {
+            let chunk_us = *chunk as u64 * 1_000_000 / RATE as u64;
+            let jitter = if i == 3 { 15_000 } else { 0 }; // Chunk 3 arrives late, chunk 4 on time
+            arrival_us += chunk_us;
+            let samples: Vec<f32> = (0..*chunk).map(|n| 0.1 * (n as f32 * 0.05).sin()).collect();
+            for frame in cochlea.push(&samples) {
+                let stamp = clock.stamp(arrival_us + jitter, frame.lag_samples, RATE);
+                assert!(stamp + chunk_us >= arrival_us && stamp <= arrival_us + jitter, "chunk {}: stamp {} outside its chunk", i, stamp);
+                stamps.push(stamp);
+            }
+        }
+        assert!(stamps.len() >= 8, "only {} frames from the chunks", stamps.len());
+        assert!(stamps.windows(2).all(|w| w[1] >= w[0]), "stamps go backwards: {:?}", stamps);
+
+        // A stamped spectrum survives JSON; an old one without the stamp reads 0
+        let spectrum = AudioSpectrum { rms: 0.1, captured_us: *stamps.last().unwrap(), ..Default::default() };
+        let back: AudioSpectrum = serde_json::from_str(&serde_json::to_string(&spectrum).unwrap()).unwrap();
+        assert_eq!(back.captured_us, spectrum.captured_us);
+        let old: AudioSpectrum = serde_json::from_str(r#"{"rms":0.1,"bass":0,"mids":0,"highs":0,"speaker_id":null,"is_voice":false,"frequency_embedding":[]}"#).unwrap();
+        assert_eq!(old.captured_us, 0);
+    }
+}
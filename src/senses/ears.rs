@@ -1,10 +1,22 @@
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
 use std::sync::mpsc::{Sender, Receiver};
 use std::sync::{Arc, Mutex};
-use serde::{Serialize, Deserialize};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::collections::VecDeque;
+use std::time::Instant;
 use whisper_rs::{FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters};
 use crate::core::thought::{Thought, MindVoice};
-use rustfft::{FftPlanner, num_complex::Complex};
+use crate::core::metabolism::Metabolism;
+use crate::core::pacing;
+use crate::core::config::EarsConfig;
+use crate::core::components::ComponentStatus;
+use crate::core::selftest;
+use crate::senses::whisper_model;
+use crate::senses::prosody::{self, Prosody};
+use crate::senses::cochlea::{CaptureClock, Cochlea};
+use crate::senses::agc::{self, Agc};
+use crate::senses::synthetic::Script;
+use crate::senses::sanity::SanityScorer;
 
 // Symphonia (File Decoding)
 use symphonia::core::io::MediaSourceStream;
@@ -15,17 +27,112 @@ use symphonia::core::meta::MetadataOptions;
 use symphonia::core::audio::SampleBuffer;
 use std::fs::File;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct AudioSpectrum {
-    pub rms: f32,
-    pub bass: f32, // 20-250 Hz
-    pub mids: f32, // 250-2000 Hz
-    pub highs: f32, // 2000-20000 Hz
-    #[allow(dead_code)]
-    pub speaker_id: Option<String>,
-    pub is_voice: bool, 
-    // Direct Sensory Projection (64-band spectrogram)
-    pub frequency_embedding: Vec<f32>,
+pub use crate::senses::cochlea::AudioSpectrum;
+
+/// Feed recorded samples in device-sized chunks, paced at `speed` x real time.
+/// Chunk size (and so FFT hops and the silence counter) don't depend on the speed.
+fn play(samples: &[f32], sample_rate: u32, speed: f32, processor: &impl Fn(&[f32])) {
+    for chunk in samples.chunks(1024) {
+        processor(chunk);
+        let secs = chunk.len() as f32 / sample_rate as f32 / speed.max(0.01);
+        std::thread::sleep(std::time::Duration::from_secs_f32(secs));
+    }
+}
+
+/// The model selected in [ears] (and its path, for the log), or why there is none.
+fn load_whisper(config: &EarsConfig, thought_tx: &Sender<Thought>) -> Result<(WhisperContext, String), String> {
+    let model_path = whisper_model::resolve(config, thought_tx).map_err(|e| e.to_string())?;
+    let model_str = model_path.to_string_lossy().to_string();
+    let ctx = {
+        let _log_gag = gag::Gag::stderr().ok();
+        WhisperContext::new_with_params(&model_str, WhisperContextParameters::default())
+    };
+    ctx.map(|ctx| (ctx, model_str.clone()))
+        .map_err(|e| format!("failed to load {} ({:?})", model_str, e))
+}
+
+/// Startup warm-up (core/selftest.rs): transcribe the built-in half-second sample once,
+/// so the first utterance doesn't pay for Whisper's first pass. Not billed.
+fn warm_whisper(whisper: &Mutex<WhisperContext>, language: &str) -> Result<String, String> {
+    let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
+    params.set_language(Some(language));
+    params.set_print_special(false);
+    params.set_print_progress(false);
+    params.set_print_realtime(false);
+    params.set_print_timestamps(false);
+    let _print_gag = gag::Gag::stdout().ok();
+    let _err_gag = gag::Gag::stderr().ok();
+    let ctx = whisper.lock().map_err(|_| "whisper lock poisoned".to_string())?;
+    let mut session = ctx.create_state().map_err(|e| format!("{:?}", e))?;
+    session.full(params, &selftest::sample(16000)).map_err(|e| format!("{:?}", e))?;
+    Ok(format!("{} segment(s)", session.full_n_segments().unwrap_or(0)))
+}
+
+/// Whisper worker health, read by the daemon for telemetry.
+#[derive(Debug, Default)]
+pub struct EarStats {
+    pub queue_depth: AtomicUsize,   // Utterances waiting for Whisper
+    pub last_latency_ms: AtomicU64, // End of speech -> transcript (queue wait + inference)
+    pub dropped: AtomicU64,         // Utterances forgotten because we fell behind
+    pub rejected_chunks: AtomicU64, // Browser PCM chunks refused (empty or over agc.max_chunk_samples)
+    pub absurd_samples: AtomicU64,  // Browser samples silenced (NaN/Inf/absurd)
+    pub hallucinations_blocked: AtomicU64, // Transcripts the sanity scorer kept from ALEPH
+}
+
+/// A plain copy of EarStats (what a cortexd child reports to its parent).
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EarVitals {
+    pub queue_depth: usize,
+    pub last_latency_ms: u64,
+    pub dropped: u64,
+    pub rejected_chunks: u64,
+    pub absurd_samples: u64,
+    pub hallucinations_blocked: u64,
+}
+
+impl EarStats {
+    pub fn vitals(&self) -> EarVitals {
+        EarVitals {
+            queue_depth: self.queue_depth.load(Ordering::Relaxed),
+            last_latency_ms: self.last_latency_ms.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            rejected_chunks: self.rejected_chunks.load(Ordering::Relaxed),
+            absurd_samples: self.absurd_samples.load(Ordering::Relaxed),
+            hallucinations_blocked: self.hallucinations_blocked.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Mirror a child's counters (split mode: the parent's dashboard reads these).
+    pub fn store(&self, vitals: &EarVitals) {
+        self.queue_depth.store(vitals.queue_depth, Ordering::Relaxed);
+        self.last_latency_ms.store(vitals.last_latency_ms, Ordering::Relaxed);
+        self.dropped.store(vitals.dropped, Ordering::Relaxed);
+        self.rejected_chunks.store(vitals.rejected_chunks, Ordering::Relaxed);
+        self.absurd_samples.store(vitals.absurd_samples, Ordering::Relaxed);
+        self.hallucinations_blocked.store(vitals.hallucinations_blocked, Ordering::Relaxed);
+    }
+}
+
+/// One utterance (or a 15s slice of a monologue) waiting for transcription.
+struct SpeechJob {
+    samples: Vec<f32>,
+    peak_rms: f32, // Loudest chunk while recording (the sanity scorer's voice floor)
+    enqueued: Instant,
+}
+
+fn enqueue_speech(tx: &Sender<SpeechJob>, stats: &EarStats, samples: Vec<f32>, peak_rms: f32) {
+    stats.queue_depth.fetch_add(1, Ordering::Relaxed);
+    if tx.send(SpeechJob { samples, peak_rms, enqueued: Instant::now() }).is_err() {
+        stats.queue_depth.fetch_sub(1, Ordering::Relaxed);
+        eprintln!("🔴 Worker Disconnected");
+    }
+}
+
+/// A transcription plus how it sounded.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Utterance {
+    pub text: String,
+    pub prosody: Prosody,
 }
 
 /// Sensory input mode — determines where audio comes from
@@ -33,8 +140,10 @@ pub struct AudioSpectrum {
 pub enum SensoryMode {
     /// Local microphone via CPAL
     Mic,
-    /// Audio file via Symphonia decoder
-    File(String),
+    /// Audio file via Symphonia decoder, `speed` x real time
+    File { path: String, speed: f32 },
+    /// Generated test audio (senses/synthetic.rs), no hardware needed
+    Synthetic(Script),
     /// Receive PCM from browser via WebSocket (channel-based)
     WebSocket,
     /// No audio input — text-only perturbation
@@ -49,16 +158,22 @@ pub struct AudioListener {
     
     #[allow(dead_code)]
     attention_threshold: Arc<Mutex<f32>>, 
+    cochlea: Option<Arc<Mutex<Cochlea>>>, // None = headless
+    stats: Arc<EarStats>,
+    status: ComponentStatus,
 }
 
 impl AudioListener {
     pub fn new(
         thought_tx: Sender<Thought>, 
-        ears_tx: Sender<String>, 
+        ears_tx: Sender<Utterance>, 
         spectrum_tx: Sender<AudioSpectrum>,
         word_embedding_tx: Sender<Vec<f32>>,
         mode: SensoryMode,
         ws_audio_rx: Option<Receiver<Vec<f32>>>,
+        config: EarsConfig,
+        privacy: Arc<AtomicBool>, // Do-Not-Disturb: owned by the daemon, survives ear reinits
+        metabolism: Metabolism, // Whisper time is billed here
     ) -> Result<Self, anyhow::Error> {
 
         // ============================
@@ -66,29 +181,33 @@ impl AudioListener {
         // ============================
         if matches!(mode, SensoryMode::Headless) {
             let _ = thought_tx.send(Thought::new(MindVoice::System, "Audio: Headless Mode (No Ears)".to_string()));
-            return Ok(Self {
-                _stream: None,
-                _file_thread: None,
-                _ws_thread: None,
-                attention_threshold: Arc::new(Mutex::new(0.001)),
-            });
+            return Ok(Self::headless());
         }
 
         // ============================
         // SHARED SETUP: Whisper + FFT
         // ============================
 
-        // 1. Setup Whisper
-        let ctx = {
-            let _log_gag = gag::Gag::stderr().ok();
-            WhisperContext::new_with_params(
-                "models/ggml-base.bin", 
-                WhisperContextParameters::default()
-            ).expect("failed to load ggml-base.bin")
+        // 1. Setup Whisper (no model = no ears, but ALEPH still lives)
+        let ctx = match load_whisper(&config, &thought_tx) {
+            Ok((ctx, model_str)) => {
+                let _ = thought_tx.send(Thought::new(MindVoice::System, format!(
+                    "🎧 Whisper: {} | language '{}' | queue max {} | chunks of {:.0}s",
+                    model_str, config.language, config.max_queue, config.chunk_secs)));
+                Some(ctx)
+            },
+            // No model = spectrum-only hearing: startle, bands and AGC still work, words don't
+            Err(e) => {
+                let _ = thought_tx.send(Thought::new(MindVoice::System, format!("🔇 EARS: {}. Spectrum-only hearing (no words).", e)));
+                None
+            }
         };
-        
-        let state = Arc::new(Mutex::new(ctx));
-        let is_muted = Arc::new(Mutex::new(false));
+        let status = match &ctx {
+            Some(_) => ComponentStatus::Ready,
+            None => ComponentStatus::Degraded("no Whisper model: spectrum only".to_string()),
+        };
+
+        let state = ctx.map(|ctx| Arc::new(Mutex::new(ctx)));
         let whisper_rms_threshold = Arc::new(Mutex::new(0.05));
         let attention_threshold = Arc::new(Mutex::new(0.001));
 
@@ -96,22 +215,30 @@ impl AudioListener {
         let sample_rate: u32 = match &mode {
             SensoryMode::Mic => {
                 let host = cpal::default_host();
-                let device = host.default_input_device().expect("no input device available");
+                let device = host.default_input_device().ok_or_else(|| anyhow::anyhow!("no input device available"))?;
                 let config = device.default_input_config()?;
                 config.sample_rate().0
             },
             SensoryMode::WebSocket => 44100, // Browser default
-            SensoryMode::File(_) => 44100,   // Will be overridden per-file
+            SensoryMode::File { .. } => 44100, // Overridden per-file (cochlea.set_sample_rate)
+            SensoryMode::Synthetic(script) => script.sample_rate,
             SensoryMode::Headless => unreachable!(),
         };
 
         let _ = thought_tx.send(Thought::new(MindVoice::System, format!("Audio: Init at {}Hz ({:?})", sample_rate, mode)));
 
-        // 2. FFT Config
-        let fft_len = 1024;
-        let mut planner = FftPlanner::new();
-        let fft = planner.plan_fft_forward(fft_len);
-        let fft_arc = Arc::new(fft); 
+        // 2. FFT Config (size/hop from [ears]; the file thread corrects the rate per file)
+        let cochlea = Arc::new(Mutex::new(Cochlea::new(sample_rate, config.fft_size, config.fft_hop)));
+        let fft_len = cochlea.lock().map(|c| c.fft_size()).unwrap_or(1024);
+        let listener_cochlea = cochlea.clone(); // The file thread takes `cochlea`
+
+        // THE EARDRUM: browser (and optionally mic) levels normalized before the cochlea
+        let agc_enabled = match &mode {
+            SensoryMode::WebSocket => config.agc.websocket,
+            SensoryMode::Mic => config.agc.mic,
+            _ => false, // Files and scripts are what they are
+        };
+        let agc = agc_enabled.then(|| Arc::new(Mutex::new(Agc::new(config.agc.clone(), sample_rate))));
 
         let audio_buffer = Arc::new(Mutex::new(Vec::new()));
         let is_recording = Arc::new(Mutex::new(false));
@@ -119,16 +246,61 @@ impl AudioListener {
         let peak_rms_during_recording = Arc::new(Mutex::new(0.0f32));
 
         // WHISPER WORKER THREAD
-        let (audio_work_tx, audio_work_rx) = std::sync::mpsc::channel::<Vec<f32>>();
+        let (audio_work_tx, audio_work_rx) = std::sync::mpsc::channel::<SpeechJob>();
+        let stats = Arc::new(EarStats::default());
+        let worker_stats = stats.clone();
+        let worker_privacy = privacy.clone();
+        let max_queue = config.max_queue.max(1);
+        let language = config.language.clone();
+        let auto_language = language == "auto";
         let worker_state = state.clone();
         let worker_ears_tx = ears_tx.clone();
         let worker_thought_tx = thought_tx.clone();
         let worker_word_embed_tx = word_embedding_tx.clone();
+        let mut sanity = SanityScorer::new(config.sanity.clone());
+        let warmup = config.warmup;
 
         std::thread::spawn(move || {
-             while let Ok(samples) = audio_work_rx.recv() {
+             // WARM-UP: before the first utterance, on the worker's own time
+             if let Some(whisper) = worker_state.as_ref().filter(|_| warmup) {
+                  let start = Instant::now();
+                  let result = warm_whisper(whisper, &language);
+                  let _ = worker_thought_tx.send(selftest::stage_thought(selftest::WHISPER, start.elapsed(), result));
+             }
+             let mut pending: VecDeque<SpeechJob> = VecDeque::new();
+             loop {
+                  // Take everything that queued up while Whisper was busy
+                  if pending.is_empty() {
+                      match audio_work_rx.recv() {
+                          Ok(job) => pending.push_back(job),
+                          Err(_) => break,
+                      }
+                  }
+                  pending.extend(audio_work_rx.try_iter());
+
+                  // DROP POLICY: answering what was said a minute ago is worse than not answering.
+                  // Too far behind -> forget the oldest speech, keep the freshest.
+                  if pending.len() > max_queue {
+                      let missed = pending.len() - max_queue;
+                      pending.drain(..missed);
+                      worker_stats.queue_depth.fetch_sub(missed, Ordering::Relaxed);
+                      worker_stats.dropped.fetch_add(missed as u64, Ordering::Relaxed);
+                      let _ = worker_thought_tx.send(Thought::new(MindVoice::Sensory,
+                          format!("🎧 …missed some speech… ({} fragments lost, ears fell behind)", missed)));
+                  }
+
+                  let Some(job) = pending.pop_front() else { continue };
+                  worker_stats.queue_depth.fetch_sub(1, Ordering::Relaxed);
+                  // DND switched on while this was queued: it is never transcribed
+                  if worker_privacy.load(Ordering::Relaxed) { continue; }
+                  // No model (synthetic audio only): nothing to transcribe with
+                  let Some(whisper) = &worker_state else { continue; };
+                  let samples = job.samples;
+                  // HOW it was said (independent of the words)
+                  let tone = prosody::analyze(&samples, sample_rate);
+
                   let mut params = FullParams::new(SamplingStrategy::Greedy { best_of: 1 });
-                  params.set_language(Some("es"));
+                  params.set_language(Some(&language));
                   params.set_print_special(false);
                   params.set_print_progress(false);
                   params.set_print_realtime(false);
@@ -147,9 +319,12 @@ impl AudioListener {
                   let _print_gag = gag::Gag::stdout().ok();
                   let _err_gag = gag::Gag::stderr().ok();
 
-                  let state = worker_state.lock().unwrap();
+                  let state = whisper.lock().unwrap();
                   if let Ok(mut state_session) = state.create_state() {
-                        if let Ok(_) = state_session.full(params, &resampled[..]) {
+                        let whisper_start = std::time::Instant::now();
+                        let transcribed = state_session.full(params, &resampled[..]);
+                        metabolism.report_whisper(whisper_start.elapsed());
+                        if let Ok(_) = transcribed {
                             drop(_print_gag); 
                             drop(_err_gag);
 
@@ -161,16 +336,26 @@ impl AudioListener {
                                 }
                             }
                             text = text.trim().to_string();
-                            
-                            let triggers = [
-                                "[BLANK_AUDIO]", "Subtítulos", "Amara.org", 
-                                "...", "??"
-                            ];
-                            
-                            let is_hallucination = text.len() < 2 
-                                || triggers.iter().any(|&t| text.contains(t) || text.to_lowercase().contains(&t.to_lowercase()));
-
-                            if !text.is_empty() && !is_hallucination {
+
+                            // Auto-detect: which language it was (mixed-language households)
+                            let detected = if auto_language {
+                                state_session.full_lang_id_from_state().ok().and_then(whisper_rs::get_lang_str)
+                            } else {
+                                None
+                            };
+
+                            // THE SKEPTIC: blacklisted, looping, implausible or too quiet -> logged, not heard
+                            let verdict = (text.len() >= 2).then(|| {
+                                let audio_secs = samples.len() as f32 / sample_rate as f32;
+                                sanity.score(&text, detected.unwrap_or(language.as_str()), audio_secs, job.peak_rms)
+                            });
+                            if let Some(verdict) = verdict.as_ref().filter(|v| v.blocked) {
+                                worker_stats.hallucinations_blocked.fetch_add(1, Ordering::Relaxed);
+                                let _ = worker_thought_tx.send(Thought::new(MindVoice::System, format!(
+                                    "🚫 Hallucination blocked (score {:.2}: {}): '{}'", verdict.score, verdict.reasons.join(", "), text)));
+                            }
+
+                            if verdict.is_some_and(|v| !v.blocked) {
                                 // === WORD EMBEDDING PATHWAY ===
                                 // Convert transcribed words into a hash-based 64-dim vector
                                 // This hits the Semantic region ~50-200ms after sound
@@ -178,11 +363,13 @@ impl AudioListener {
                                 let embedding = text_to_word_embedding(&text, 64);
                                 let _ = worker_word_embed_tx.send(embedding);
                                 
-                                let _ = worker_thought_tx.send(Thought::new(MindVoice::Sensory, format!("🎧 SEMANTIC ECHO: '{}'", text)));
-                                let _ = worker_ears_tx.send(text);
+                                let heard_as = detected.map(|lang| format!(" [{}]", lang)).unwrap_or_default();
+                                let _ = worker_thought_tx.send(Thought::new(MindVoice::Sensory, format!("🎧 SEMANTIC ECHO{}: '{}'", heard_as, text)));
+                                let _ = worker_ears_tx.send(Utterance { text, prosody: tone });
                             }
                         }
                   }
+                  worker_stats.last_latency_ms.store(job.enqueued.elapsed().as_millis() as u64, Ordering::Relaxed);
              }
         });
 
@@ -195,83 +382,71 @@ impl AudioListener {
             let recording_limit = is_recording.clone();
             let silence_counter = silence_frames.clone();
             let threshold_clone = attention_threshold.clone();
-            let muted_clone = is_muted.clone();
+            let privacy_clone = privacy.clone();
             let whisper_threshold_clone = whisper_rms_threshold.clone();
             let peak_rms_clone = peak_rms_during_recording.clone();
-            let fft_clone = fft_arc.clone();
+            let cochlea_clone = cochlea.clone();
             let thought_tx_debug = thought_tx.clone();
             let audio_work_tx_clone = audio_work_tx.clone();
             let spectrum_tx_clone = spectrum_tx.clone();
+            let stats_clone = stats.clone();
+            let chunk_samples = ((sample_rate as f32 * config.chunk_secs.max(1.0)) as usize).max(fft_len);
+            let agc_clone = agc.clone();
+            let capture_clock = Arc::new(Mutex::new(CaptureClock::default()));
 
             move |data: &[f32]| {
+                let arrival_us = pacing::monotonic_us(); // The chunk's last sample is now
+                // 0. AGC (everything below hears the normalized signal)
+                let mut normalized = Vec::new();
+                let mut input_gain = 1.0;
+                let data = match agc_clone.as_ref().and_then(|agc| agc.lock().ok()) {
+                    Some(mut agc) => {
+                        normalized.extend_from_slice(data);
+                        input_gain = agc.process(&mut normalized);
+                        &normalized[..]
+                    },
+                    None => data,
+                };
+
                 // A. RMS
                 let rms = (data.iter().map(|s| s * s).sum::<f32>() / data.len() as f32).sqrt();
                 
-                // B. FFT Analysis
-                let mut spectrum_buffer: Vec<Complex<f32>> = data.iter()
-                    .take(fft_len)
-                    .map(|&s| Complex::new(s, 0.0))
-                    .collect();
-                
-                if spectrum_buffer.len() < fft_len {
-                    spectrum_buffer.resize(fft_len, Complex::new(0.0, 0.0));
-                }
-
-                fft_clone.process(&mut spectrum_buffer);
-
-                let get_magnitude = |buf: &[Complex<f32>], start: usize, end: usize| -> f32 {
-                        if start >= buf.len() || end > buf.len() { return 0.0; }
-                        buf[start..end].iter()
-                        .map(|c| c.norm())
-                        .sum::<f32>() / (end - start).max(1) as f32
+                // B. Spectrum: one frame per hop (bands in Hz, see cochlea.rs)
+                let (frames, rate) = match cochlea_clone.lock() {
+                    Ok(mut cochlea) => (cochlea.push(data), cochlea.sample_rate()),
+                    Err(_) => (Vec::new(), 0),
                 };
-
-                let raw_bass = get_magnitude(&spectrum_buffer, 1, 6);
-                let raw_mids = get_magnitude(&spectrum_buffer, 6, 46);
-                let raw_highs = get_magnitude(&spectrum_buffer, 46, 200);
-                
-                let scale = fft_len as f32; 
-                let gain = 100.0; 
-                let (bass, mids, highs) = (
-                    (raw_bass / scale * gain).clamp(0.0, 1.0), 
-                    (raw_mids / scale * gain).clamp(0.0, 1.0),
-                    (raw_highs / scale * gain).clamp(0.0, 1.0)
-                );
-
-                // C. Direct Sensory Embedding (64 bands)
-                // Map FFT (512 bins) -> 64 bands (Logarithmic scaling would be better, but linear for now)
-                let mut embedding = Vec::with_capacity(64);
-                let bin_size = spectrum_buffer.len() / 2 / 64; // ~4 bins per band
-                
-                for i in 0..64 {
-                    let start = i * bin_size;
-                    let end = start + bin_size;
-                    let mag = get_magnitude(&spectrum_buffer, start, end);
-                    // Normalize generally
-                    embedding.push((mag / scale * gain * 2.0).clamp(0.0, 1.0)); 
-                }
-
-                // Voice Detection
                 let gate = threshold_clone.try_lock().map(|t| *t).unwrap_or(0.01);
-                let is_loud_enough = rms > gate;
-                let voice_profile = mids > highs && mids > bass * 0.5;
-                let is_voice = is_loud_enough && voice_profile;
-
-                let spectrum = AudioSpectrum { 
-                    rms, 
-                    bass, 
-                    mids, 
-                    highs, 
-                    speaker_id: None, 
-                    is_voice,
-                    frequency_embedding: embedding 
-                };
-                let _ = spectrum_tx_clone.send(spectrum);
+                for frame in frames {
+                    // Voice Detection
+                    let is_loud_enough = frame.rms > gate;
+                    let voice_profile = frame.mids > frame.highs && frame.mids > frame.bass * 0.5;
+
+                    let spectrum = AudioSpectrum {
+                        rms: frame.rms,
+                        bass: frame.bass,
+                        mids: frame.mids,
+                        highs: frame.highs,
+                        is_voice: is_loud_enough && voice_profile,
+                        frequency_embedding: frame.bands,
+                        input_gain,
+                        captured_us: capture_clock.lock().map(|mut clock| clock.stamp(arrival_us, frame.lag_samples, rate)).unwrap_or(arrival_us),
+                        sent_us: pacing::monotonic_us(),
+                        received_us: 0, // The daemon's bridge stamps it
+                    };
+                    let _ = spectrum_tx_clone.send(spectrum);
+                }
 
                 // Gating
                 let threshold = threshold_clone.try_lock().map(|t| *t).unwrap_or(0.00001);
-                let muted = muted_clone.try_lock().map(|m| *m).unwrap_or(false);
-                if muted { return; }
+                // DO NOT DISTURB: the body still feels the sound (spectrum above), but no words are kept
+                if privacy_clone.load(Ordering::Relaxed) {
+                    if let (Ok(mut recording), Ok(mut buffer)) = (recording_limit.try_lock(), buffer_clone.try_lock()) {
+                        *recording = false;
+                        buffer.clear();
+                    }
+                    return;
+                }
 
                 // Recording Logic
                 let Ok(mut recording) = recording_limit.try_lock() else { return; };
@@ -294,6 +469,16 @@ impl AudioListener {
 
                 if *recording {
                     buffer.extend_from_slice(data);
+
+                    // MONOLOGUE SPLIT: hand Whisper slices, not a 2-minute speech at once
+                    if buffer.len() >= chunk_samples && *silence <= 45 {
+                        let whisper_threshold = whisper_threshold_clone.try_lock().map(|t| *t).unwrap_or(0.3);
+                        if *peak_rms > whisper_threshold {
+                            enqueue_speech(&audio_work_tx_clone, &stats_clone, std::mem::take(&mut *buffer), *peak_rms);
+                        } else {
+                            buffer.clear();
+                        }
+                    }
                     
                     if *silence > 45 {
                         *recording = false;
@@ -301,10 +486,7 @@ impl AudioListener {
                         
                         if *peak_rms > whisper_threshold {
                              let _ = thought_tx_debug.send(Thought::new(MindVoice::System, format!("🧠 GATE OPEN (Peak: {:.4})", *peak_rms)));
-                             let samples = buffer.clone();
-                             if let Err(_) = audio_work_tx_clone.send(samples) {
-                                  eprintln!("🔴 Worker Disconnected");
-                             }
+                             enqueue_speech(&audio_work_tx_clone, &stats_clone, buffer.clone(), *peak_rms);
                         }
                         buffer.clear();
                     }
@@ -316,9 +498,8 @@ impl AudioListener {
         // 4. MODE-SPECIFIC INPUT SOURCE
         // ============================
         match mode {
-            SensoryMode::File(path_str) => {
+            SensoryMode::File { path, speed } => {
                 // --- FILE MODE ---
-                let path = path_str.clone();
                 let _ = thought_tx.send(Thought::new(MindVoice::System, format!("📂 Opening Audio File: {}", path)));
 
                 let file_thread = std::thread::spawn(move || {
@@ -338,6 +519,9 @@ impl AudioListener {
 
                     let track_id = track.id;
                     let file_sample_rate = track.codec_params.sample_rate.unwrap_or(44100);
+                    if let Ok(mut cochlea) = cochlea.lock() {
+                        cochlea.set_sample_rate(file_sample_rate);
+                    }
                     
                     loop {
                         let packet = match format.next_packet() {
@@ -366,11 +550,7 @@ impl AudioListener {
                                     .map(|chunk: &[f32]| chunk.iter().sum::<f32>() / channels as f32)
                                     .collect();
 
-                                for chunk in mono_samples.chunks(1024) {
-                                    processor(chunk); 
-                                    let sleep_micros = (chunk.len() as f32 / file_sample_rate as f32 * 1_000_000.0) as u64;
-                                    std::thread::sleep(std::time::Duration::from_micros(sleep_micros));
-                                }
+                                play(&mono_samples, file_sample_rate, speed, &processor);
                             },
                             Err(e) => eprintln!("Error decoding: {}", e),
                         }
@@ -383,6 +563,32 @@ impl AudioListener {
                     _file_thread: Some(file_thread),
                     _ws_thread: None,
                     attention_threshold,
+                    cochlea: Some(listener_cochlea.clone()),
+                    stats,
+                    status,
+                })
+            },
+
+            SensoryMode::Synthetic(script) => {
+                // --- SYNTHETIC MODE ---
+                let samples = script.render()?;
+                let _ = thought_tx.send(Thought::new(MindVoice::System, format!(
+                    "🎼 Synthetic Audio: {} segments, {:.1}s at {}Hz, {}x speed",
+                    script.segments.len(), samples.len() as f32 / script.sample_rate as f32, script.sample_rate, script.speed)));
+
+                let synth_thread = std::thread::spawn(move || {
+                    play(&samples, script.sample_rate, script.speed, &processor);
+                    println!("🎼 Synthetic Audio Finished.");
+                });
+
+                Ok(Self {
+                    _stream: None,
+                    _file_thread: Some(synth_thread),
+                    _ws_thread: None,
+                    attention_threshold,
+                    cochlea: Some(listener_cochlea.clone()),
+                    stats,
+                    status,
                 })
             },
 
@@ -392,9 +598,18 @@ impl AudioListener {
                 let _ = thought_tx.send(Thought::new(MindVoice::System, "🌐 Audio: WebSocket Mode (Browser Ears)".to_string()));
 
                 let rx = ws_audio_rx.expect("WebSocket mode requires ws_audio_rx channel");
+                let ws_stats = stats.clone();
+                let max_chunk = config.agc.max_chunk_samples.max(1);
                 
                 let ws_thread = std::thread::spawn(move || {
-                    while let Ok(samples) = rx.recv() {
+                    while let Ok(mut samples) = rx.recv() {
+                        // A browser can send anything: refuse absurd chunks, silence absurd samples
+                        if samples.is_empty() || samples.len() > max_chunk {
+                            ws_stats.rejected_chunks.fetch_add(1, Ordering::Relaxed);
+                            continue;
+                        }
+                        let fixed = agc::sanitize(&mut samples);
+                        ws_stats.absurd_samples.fetch_add(fixed as u64, Ordering::Relaxed);
                         // Feed browser audio into the same processor pipeline
                         for chunk in samples.chunks(1024) {
                             processor(chunk);
@@ -408,13 +623,16 @@ impl AudioListener {
                     _file_thread: None,
                     _ws_thread: Some(ws_thread),
                     attention_threshold,
+                    cochlea: Some(listener_cochlea.clone()),
+                    stats,
+                    status,
                 })
             },
 
             SensoryMode::Mic => {
                 // --- MIC MODE ---
                 let host = cpal::default_host();
-                let device = host.default_input_device().expect("no input device available");
+                let device = host.default_input_device().ok_or_else(|| anyhow::anyhow!("no input device available"))?;
                 let config = device.default_input_config()?;
 
                 let stream = device.build_input_stream(
@@ -433,12 +651,43 @@ impl AudioListener {
                     _file_thread: None,
                     _ws_thread: None,
                     attention_threshold,
+                    cochlea: Some(listener_cochlea.clone()),
+                    stats,
+                    status,
                 })
             },
 
             SensoryMode::Headless => unreachable!(), // Handled above
         }
     }
+
+    pub fn headless() -> Self {
+        Self {
+            _stream: None,
+            _file_thread: None,
+            _ws_thread: None,
+            attention_threshold: Arc::new(Mutex::new(0.001)),
+            cochlea: None,
+            stats: Arc::new(EarStats::default()),
+            status: ComponentStatus::Offline("headless".to_string()),
+        }
+    }
+
+    pub fn stats(&self) -> Arc<EarStats> {
+        self.stats.clone()
+    }
+
+    /// Analyze one frame every `stride` FFT hops (latency shedding; 1 = all of them).
+    pub fn set_fft_stride(&self, stride: usize) {
+        if let Some(mut cochlea) = self.cochlea.as_ref().and_then(|c| c.lock().ok()) {
+            cochlea.set_stride(stride);
+        }
+    }
+
+    /// Ready (Whisper + spectrum), Degraded (spectrum only) or Offline (headless).
+    pub fn status(&self) -> ComponentStatus {
+        self.status.clone()
+    }
 }
 
 /// Convert text into a hash-based word embedding vector.
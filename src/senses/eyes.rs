@@ -39,8 +39,6 @@ impl Eyes {
                     }
                     println!("👁️  VISUAL CORTEX: Online (Real Webcam)");
                     
-                    let mut last_frame_gray: Option<Vec<u8>> = None;
-                    
                     loop {
                         thread::sleep(Duration::from_millis(200)); // 5Hz Capture Rate
                         match camera.frame() {
@@ -58,49 +56,10 @@ impl Eyes {
                                     }
                                 }
                                 
-                                // 2. Calculate Motion (Frame Diff) + Embedding
-                                // We want a 64x64 Grid (4096 points) for both Embedding and Visualization
-                                let grid_w = 64;
-                                let grid_size = grid_w * grid_w;
-                                let mut visual_grid = vec![0.0; grid_size];
-                                
-                                if let Some(last) = &last_frame_gray {
-                                    if last.len() == gray.len() {
-                                        // Map 320x240 -> 64x64
-                                        let step_x = width as usize / grid_w;
-                                        let step_y = height as usize / grid_w;
-                                        
-                                        for y in 0..grid_w {
-                                            for x in 0..grid_w {
-                                                // Sample center pixel of the block (fastest)
-                                                // Averaging would be better but slower
-                                                let src_x = x * step_x;
-                                                let src_y = y * step_y;
-                                                let idx = src_y * width as usize + src_x;
-                                                
-                                                if idx < gray.len() {
-                                                    let val = gray[idx];
-                                                    let last_val = last[idx];
-                                                    
-                                                    let diff = (val as i16 - last_val as i16).abs() as f32;
-                                                    let bright = val as f32;
-                                                    
-                                                    // Combined signal: Motion (High) + Brightness (Low baseline)
-                                                    let signal = (diff / 30.0) + (bright / 255.0 * 0.1);
-                                                    visual_grid[y * grid_w + x] = signal.min(1.0);
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                                
-                                last_frame_gray = Some(gray);
-                                
-                                // Send Grid (Daemon will downsample for embedding if needed, or we use grid as embedding)
-                                // Current Reservoir expects 64-float embedding?
-                                // Let's send the FULL 4096 grid. The Reservoir can sample it or we project it.
-                                // Wait, the channel is Sender<Vec<f32>>.
-                                // We should send the full grid.
+                                // 2. Luminance grid: average every block of the frame (GRID x GRID)
+                                // Motion and pooling happen downstream (Retina), on every frame
+                                let visual_grid = luminance_grid(&gray, width as usize, height as usize);
+
                                 if let Err(_) = tx.send(visual_grid) {
                                     break;
                                 }
@@ -123,21 +82,196 @@ impl Eyes {
         });
     }
     
+    /// No camera: a bright square wandering over a dim room (same grid as the webcam).
     fn run_simulation(tx: Sender<Vec<f32>>) {
-         let mut rng = rand::thread_rng();
+         let mut rng = rand::rng();
          println!("👁️  VISUAL CORTEX: Simulation Mode Active");
+         let mut t = 0;
          loop {
-             let sleep_ms = rng.gen_range(200..800);
-             thread::sleep(Duration::from_millis(sleep_ms));
-             let dim = 64; 
-             let mut embedding = Vec::with_capacity(dim);
-             let mode = rng.gen_range(0.0..1.0);
-             if mode > 0.8 {
-                 for _ in 0..dim { embedding.push(rng.gen::<f32>() * 2.0 - 1.0); }
-             } else {
-                 for _ in 0..dim { embedding.push(rng.gen::<f32>() * 0.2 - 0.1); }
+             thread::sleep(Duration::from_millis(200)); // 5Hz, like the camera
+             let mut grid = moving_square(t);
+             for px in grid.iter_mut() {
+                 *px = (*px + rng.random_range(-0.02..0.02)).clamp(0.0, 1.0); // Sensor noise
              }
-             if let Err(_) = tx.send(embedding) { break; }
+             t += 1;
+             if let Err(_) = tx.send(grid) { break; }
          }
     }
 }
+
+// --- RETINA: grid -> reservoir input ---
+//
+// The daemon used to feed the reservoir every 64th value of the grid (one
+// pixel per row), so the embedding was whatever column the stride hit. Now:
+//
+//   form   = 8x8 average pooling of luminance, contrast-normalized per frame
+//            (mean 0, unit spread, so a dim room and a bright one look alike)
+//   motion = 8x8 average pooling of |frame - previous frame|
+//
+// Both go in one embedding, [form (64) | motion x MOTION_GAIN (64)], so they
+// use different input projections. Motion weighs more: change matters more
+// than static light.
+
+/// Side of the grid the eyes send (GRID x GRID luminance, 0..1).
+pub const GRID: usize = 64;
+/// Side of the pooled map (POOLED x POOLED cells per channel).
+pub const POOLED: usize = 8;
+pub const MOTION_GAIN: f32 = 2.0;
+
+/// Block-average a camera frame (gray, row-major) down to GRID x GRID, 0..1.
+pub fn luminance_grid(gray: &[u8], width: usize, height: usize) -> Vec<f32> {
+    let mut sums = vec![0.0f32; GRID * GRID];
+    let mut counts = vec![0u32; GRID * GRID];
+    if width == 0 || height == 0 {
+        return sums;
+    }
+    for (i, &px) in gray.iter().enumerate().take(width * height) {
+        let (x, y) = (i % width, i / width);
+        let cell = (y * GRID / height) * GRID + x * GRID / width;
+        sums[cell] += px as f32;
+        counts[cell] += 1;
+    }
+    for (sum, &n) in sums.iter_mut().zip(&counts) {
+        *sum = if n > 0 { *sum / n as f32 / 255.0 } else { 0.0 };
+    }
+    sums
+}
+
+/// Average `side x side` (row-major) down to `out x out`. `side` must be a multiple of `out`.
+pub fn average_pool(grid: &[f32], side: usize, out: usize) -> Vec<f32> {
+    let block = side / out;
+    let mut pooled = vec![0.0f32; out * out];
+    for y in 0..side {
+        for x in 0..side {
+            pooled[(y / block) * out + x / block] += grid[y * side + x];
+        }
+    }
+    let n = (block * block) as f32;
+    pooled.iter_mut().for_each(|v| *v /= n);
+    pooled
+}
+
+/// Per-frame contrast normalization: zero mean, unit standard deviation, squashed
+/// into [-1, 1]. A flat frame stays flat (the spread has a floor) instead of
+/// amplifying sensor noise into structure.
+pub fn contrast_normalize(values: &mut [f32]) {
+    if values.is_empty() {
+        return;
+    }
+    let n = values.len() as f32;
+    let mean = values.iter().sum::<f32>() / n;
+    let std = (values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n).sqrt().max(0.05);
+    values.iter_mut().for_each(|v| *v = ((*v - mean) / std * 0.5).clamp(-1.0, 1.0));
+}
+
+/// What one frame does to the visual region.
+pub struct Percept {
+    pub form: Vec<f32>,   // POOLED^2, contrast-normalized luminance
+    pub motion: Vec<f32>, // POOLED^2, pooled |frame - previous| (0 on the first frame)
+}
+
+impl Percept {
+    /// [form | motion x MOTION_GAIN], for `inject_embedding`.
+    pub fn embedding(&self) -> Vec<f32> {
+        self.form.iter().copied()
+            .chain(self.motion.iter().map(|m| (m * MOTION_GAIN).min(1.0)))
+            .collect()
+    }
+}
+
+/// Remembers the previous grid for the motion channel.
+#[derive(Default)]
+pub struct Retina {
+    previous: Option<Vec<f32>>,
+}
+
+impl Retina {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// None if the grid isn't GRID x GRID.
+    pub fn see(&mut self, grid: &[f32]) -> Option<Percept> {
+        if grid.len() != GRID * GRID {
+            return None;
+        }
+        let mut form = average_pool(grid, GRID, POOLED);
+        contrast_normalize(&mut form);
+        let motion = match &self.previous {
+            Some(prev) => {
+                let diff: Vec<f32> = grid.iter().zip(prev).map(|(a, b)| (a - b).abs()).collect();
+                average_pool(&diff, GRID, POOLED)
+            }
+            None => vec![0.0; POOLED * POOLED],
+        };
+        self.previous = Some(grid.to_vec());
+        Some(Percept { form, motion })
+    }
+}
+
+/// Synthetic scene: a bright SQUARE x SQUARE block on a dim background, sliding
+/// SPEED pixels per frame and bouncing off the edges (diagonally, at different rates).
+pub fn moving_square(t: usize) -> Vec<f32> {
+    const SQUARE: usize = 12;
+    const SPEED: usize = 3;
+    let bounce = |pos: usize| {
+        let span = GRID - SQUARE;
+        let p = pos % (2 * span);
+        if p < span { p } else { 2 * span - p }
+    };
+    let (x0, y0) = (bounce(t * SPEED), bounce(t * SPEED / 2 + 20));
+    let mut grid = vec![0.1f32; GRID * GRID];
+    for y in y0..y0 + SQUARE {
+        for x in x0..x0 + SQUARE {
+            grid[y * GRID + x] = 0.9;
+        }
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pooled cell (x, y) containing the center of the square in frame `t`.
+    fn square_cell(t: usize) -> (usize, usize) {
+        let grid = moving_square(t);
+        let (mut sx, mut sy, mut n) = (0, 0, 0);
+        for (i, &v) in grid.iter().enumerate() {
+            if v > 0.5 {
+                sx += i % GRID;
+                sy += i / GRID;
+                n += 1;
+            }
+        }
+        let block = GRID / POOLED;
+        (sx / n.max(1) / block, sy / n.max(1) / block)
+    }
+
+    #[test]
+    fn a_moving_square_drags_form_and_motion_along() {
+        let mut retina = Retina::new();
+        let mut visited = std::collections::HashSet::new();
+        let argmax = |v: &[f32]| v.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).map(|(i, _)| (i % POOLED, i / POOLED)).unwrap();
+        let near = |a: (usize, usize), b: (usize, usize)| a.0.abs_diff(b.0) <= 1 && a.1.abs_diff(b.1) <= 1;
+
+        retina.see(&moving_square(0));
+        for t in 1..=200 {
+            let Some(percept) = retina.see(&moving_square(t)) else { continue };
+            let here = square_cell(t);
+            visited.insert(here);
+
+            let form_peak = argmax(&percept.form);
+            assert!(near(form_peak, here), "frame {}: form peaks at cell {:?}, square is at {:?}", t, form_peak, here);
+            let motion_peak = argmax(&percept.motion);
+            assert!(near(motion_peak, here), "frame {}: motion peaks at cell {:?}, square is at {:?}", t, motion_peak, here);
+            let stray = percept.motion.iter().enumerate()
+                .filter(|(i, _)| { let c = (i % POOLED, i / POOLED); c.0.abs_diff(here.0) > 2 || c.1.abs_diff(here.1) > 2 })
+                .map(|(_, &m)| m)
+                .fold(0.0f32, f32::max);
+            assert!(stray <= 1e-3, "frame {}: motion {:.3} far from the square", t, stray);
+        }
+        // The activation changes place over the run
+        assert!(visited.len() >= 4, "the square only visited {} cells", visited.len());
+    }
+}
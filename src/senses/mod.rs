@@ -1,6 +1,13 @@
+pub mod agc;
 pub mod audio;
+pub mod cochlea;
 pub mod ears;
 pub mod eyes;
 pub mod proprioception;
+pub mod prosody;
+pub mod sanity;
+pub mod synthetic;
 pub mod tactile;
+pub mod transcript;
+pub mod whisper_model;
 
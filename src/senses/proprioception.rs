@@ -3,6 +3,7 @@ use std::thread;
 use std::time::Duration;
 use std::sync::mpsc::Sender;
 
+#[derive(Clone)]
 pub struct BodyStatus {
     pub cpu_usage: f32, // 0.0 - 100.0 (Global load)
     pub ram_usage: f32, // Used / Total ratio (0.0 - 1.0)
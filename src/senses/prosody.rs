@@ -0,0 +1,235 @@
+// src/senses/prosody.rs
+// THE TONE: How something was said, independent of what was said
+//
+// Computed per utterance (or per 15s slice) on the Whisper worker, off the
+// audio callback, from the same buffer that gets transcribed:
+//   - pitch: median f0 of voiced frames (normalized autocorrelation, 70-400 Hz)
+//   - pitch variance: spread of f0 relative to its mean (flat vs. lively)
+//   - syllable rate: peaks of the loudness envelope per second of speech
+//   - loudness + dynamics: mean frame RMS and its relative spread
+// The daemon turns these into chemistry (see `Neurotransmitters::apply_prosody`).
+
+use serde::{Deserialize, Serialize};
+
+/// Analysis rate: voice f0 lives far below 5 kHz, so we decimate first.
+const ANALYSIS_RATE: f32 = 11025.0;
+const FRAME_SECS: f32 = 0.04; // 40ms: at least 2 periods of a 70 Hz voice
+const HOP_SECS: f32 = 0.02;
+const MIN_PITCH: f32 = 70.0;
+const MAX_PITCH: f32 = 400.0;
+const VOICING_THRESHOLD: f32 = 0.3; // Normalized autocorrelation needed to call a frame voiced
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Prosody {
+    pub pitch_hz: f32,          // 0 = nothing voiced
+    pub pitch_variance: f32,    // Std dev / mean of f0
+    pub syllable_rate: f32,     // Envelope peaks per second of speech
+    pub loudness: f32,          // Mean frame RMS
+    pub loudness_dynamics: f32, // Std dev / mean of frame RMS
+}
+
+/// Pitch of one frame by normalized autocorrelation. None if unvoiced.
+pub fn estimate_pitch(frame: &[f32], sample_rate: f32) -> Option<f32> {
+    let min_lag = (sample_rate / MAX_PITCH).floor() as usize;
+    let max_lag = ((sample_rate / MIN_PITCH).ceil() as usize).min(frame.len().saturating_sub(1));
+    if min_lag == 0 || min_lag >= max_lag {
+        return None;
+    }
+
+    let energy: f32 = frame.iter().map(|s| s * s).sum();
+    if energy <= f32::EPSILON {
+        return None;
+    }
+
+    // Normalize by the overlap so long lags aren't penalized
+    let corrs: Vec<f32> = (min_lag..=max_lag)
+        .map(|lag| {
+            let corr: f32 = frame.iter().zip(&frame[lag..]).map(|(a, b)| a * b).sum();
+            corr / energy * frame.len() as f32 / (frame.len() - lag) as f32
+        })
+        .collect();
+    let best_corr = corrs.iter().cloned().fold(0.0, f32::max);
+    if best_corr < VOICING_THRESHOLD {
+        return None;
+    }
+
+    // Multiples of the period correlate just as well: take the first peak
+    // that is nearly as good as the best one (avoids octave-down errors).
+    let lag = (1..corrs.len().saturating_sub(1))
+        .find(|&i| corrs[i] >= 0.9 * best_corr && corrs[i] >= corrs[i - 1] && corrs[i] >= corrs[i + 1])?;
+    Some(sample_rate / (min_lag + lag) as f32)
+}
+
+/// Prosodic features of a mono recording.
+pub fn analyze(samples: &[f32], sample_rate: u32) -> Prosody {
+    // 1. Decimate (box filter) to ~11 kHz
+    let step = ((sample_rate as f32 / ANALYSIS_RATE).round() as usize).max(1);
+    let rate = sample_rate as f32 / step as f32;
+    let signal: Vec<f32> = samples.chunks(step)
+        .map(|c| c.iter().sum::<f32>() / c.len() as f32)
+        .collect();
+
+    let frame_len = (rate * FRAME_SECS) as usize;
+    let hop = (rate * HOP_SECS).max(1.0) as usize;
+    if frame_len == 0 || signal.len() < frame_len {
+        return Prosody::default();
+    }
+
+    // 2. Frame loudness envelope
+    let frames: Vec<&[f32]> = (0..=(signal.len() - frame_len) / hop)
+        .map(|i| &signal[i * hop..i * hop + frame_len])
+        .collect();
+    let envelope: Vec<f32> = frames.iter()
+        .map(|f| (f.iter().map(|s| s * s).sum::<f32>() / f.len() as f32).sqrt())
+        .collect();
+    let (loudness, loudness_sd) = mean_sd(&envelope);
+
+    // 3. Pitch over the frames that actually carry sound
+    let gate = loudness * 0.5;
+    let pitches: Vec<f32> = frames.iter().zip(&envelope)
+        .filter(|(_, &e)| e > gate)
+        .filter_map(|(f, _)| estimate_pitch(f, rate))
+        .collect();
+    let (pitch_mean, pitch_sd) = mean_sd(&pitches);
+    let pitch_hz = median(&pitches);
+
+    // 4. Syllables ~ clear local maxima of the envelope (10% over its mean), >= 100ms apart
+    let min_gap = (0.1 / HOP_SECS).ceil() as usize;
+    let prominence = loudness * 1.1;
+    let mut syllables = 0;
+    let mut last_peak: Option<usize> = None;
+    for i in 1..envelope.len().saturating_sub(1) {
+        let is_peak = envelope[i] > prominence && envelope[i] >= envelope[i - 1] && envelope[i] > envelope[i + 1];
+        if is_peak && last_peak.map_or(true, |p| i - p >= min_gap) {
+            syllables += 1;
+            last_peak = Some(i);
+        }
+    }
+    // Speaking time = first to last loud frame (leading/trailing silence excluded)
+    let first = envelope.iter().position(|&e| e > gate);
+    let last = envelope.iter().rposition(|&e| e > gate);
+    let voiced_secs = match (first, last) {
+        (Some(a), Some(b)) => (b - a + 1) as f32 * HOP_SECS,
+        _ => 0.0,
+    };
+
+    Prosody {
+        pitch_hz,
+        pitch_variance: if pitch_mean > 0.0 { pitch_sd / pitch_mean } else { 0.0 },
+        syllable_rate: if voiced_secs > 0.0 { syllables as f32 / voiced_secs } else { 0.0 },
+        loudness,
+        loudness_dynamics: if loudness > 0.0 { loudness_sd / loudness } else { 0.0 },
+    }
+}
+
+/// Running memory of the usual speaker's pitch. "Familiar" = close to it.
+#[derive(Debug, Clone, Default)]
+pub struct VoiceProfile {
+    mean_pitch: f32,
+    heard: u32,
+}
+
+impl VoiceProfile {
+    /// Returns whether the voice sounded familiar, then learns from it.
+    pub fn observe(&mut self, pitch_hz: f32) -> bool {
+        if pitch_hz <= 0.0 {
+            return false;
+        }
+        let familiar = self.heard >= 3 && (pitch_hz - self.mean_pitch).abs() / self.mean_pitch < 0.15;
+        self.heard += 1;
+        let alpha = 1.0 / self.heard.min(20) as f32;
+        self.mean_pitch += (pitch_hz - self.mean_pitch) * alpha;
+        familiar
+    }
+}
+
+fn mean_sd(values: &[f32]) -> (f32, f32) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = values.len() as f32;
+    let mean = values.iter().sum::<f32>() / n;
+    let var = values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n;
+    (mean, var.sqrt())
+}
+
+fn median(values: &[f32]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    sorted[sorted.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::TAU;
+
+    /// `secs` of a tone at `hz` (with two weaker harmonics, like a voice), 48 kHz.
+    fn tone(hz: f32, secs: f32, amplitude: f32) -> Vec<f32> {
+        (0..(48_000.0 * secs) as usize)
+            .map(|i| {
+                let t = i as f32 / 48_000.0;
+                amplitude * ((TAU * hz * t).sin() + 0.5 * (TAU * 2.0 * hz * t).sin() + 0.25 * (TAU * 3.0 * hz * t).sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pitch_of_synthesized_tones_within_3_percent() {
+        for hz in [80.0, 110.0, 150.0, 220.0, 300.0, 380.0] {
+            let frame: Vec<f32> = tone(hz, 0.04, 0.3).iter().step_by(4).copied().collect(); // 12 kHz
+            let estimated = estimate_pitch(&frame, 12_000.0).unwrap_or_else(|| panic!("{}Hz not voiced", hz));
+            assert!((estimated - hz).abs() / hz < 0.03, "{}Hz estimated as {:.1}Hz", hz, estimated);
+
+            let whole = analyze(&tone(hz, 1.0, 0.3), 48_000);
+            assert!((whole.pitch_hz - hz).abs() / hz < 0.03, "{}Hz analyzed as {:.1}Hz", hz, whole.pitch_hz);
+            assert!(whole.pitch_variance < 0.05, "a steady tone is flat ({})", whole.pitch_variance);
+        }
+    }
+
+    #[test]
+    fn silence_and_noise_are_unvoiced() {
+        assert_eq!(estimate_pitch(&[0.0; 480], 12_000.0), None);
+        let mut state = 0x2545F491u32;
+        let noise: Vec<f32> = (0..480).map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as f32 / u32::MAX as f32 - 0.5
+        }).collect();
+        assert_eq!(estimate_pitch(&noise, 12_000.0), None);
+        assert_eq!(analyze(&[0.0; 48_000], 48_000).pitch_hz, 0.0);
+    }
+
+    #[test]
+    fn syllables_and_loudness_follow_the_envelope() {
+        // 4 bursts per second of a 200 Hz voice, 150ms on / 100ms off, for 2s
+        let voice = tone(200.0, 2.0, 0.3);
+        let pulsed: Vec<f32> = voice.iter().enumerate()
+            .map(|(i, s)| if (i % 12_000) < 7_200 { *s } else { 0.0 })
+            .collect();
+        let heard = analyze(&pulsed, 48_000);
+        // 8 bursts over the speaking span (the last pause doesn't count): ~4-6 per second
+        assert!((3.5..=6.0).contains(&heard.syllable_rate), "{} syllables/s", heard.syllable_rate);
+        assert!(heard.loudness_dynamics > 0.5, "bursts are dynamic ({})", heard.loudness_dynamics);
+        assert!((heard.pitch_hz - 200.0).abs() < 6.0);
+
+        let loud = analyze(&tone(150.0, 0.5, 0.6), 48_000).loudness;
+        let soft = analyze(&tone(150.0, 0.5, 0.1), 48_000).loudness;
+        assert!(loud > 5.0 * soft);
+    }
+
+    #[test]
+    fn familiar_after_a_few_similar_utterances() {
+        let mut profile = VoiceProfile::default();
+        assert!(!profile.observe(0.0));
+        for _ in 0..3 {
+            assert!(!profile.observe(180.0));
+        }
+        assert!(profile.observe(190.0));
+        assert!(!profile.observe(300.0), "a different voice");
+    }
+}
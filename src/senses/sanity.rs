@@ -0,0 +1,172 @@
+// src/senses/sanity.rs
+// THE SKEPTIC: Not everything Whisper hears was said
+//
+// On silence and room noise Whisper still writes something, and it likes to
+// write the same thing ("Gracias por ver el video", "Subtítulos por..."). That
+// reached ALEPH as novel input: a dopamine spike and a memory of words nobody
+// said. Before a transcript leaves the worker it is scored, starting from 1:
+//
+//   blacklist   a phrase listed for its language (or "*") -> 0, never speech
+//   repeated    -repeat_penalty per verbatim appearance in the last `history`
+//               transcripts (one repeat is a person; a loop is Whisper)
+//   rate        characters per second of audio outside min..max_chars_per_sec
+//               (a paragraph from half a second, one word from fifteen)
+//   quiet       the source audio never peaked above voice_floor_rms
+//
+// Below min_score the transcript is logged and dropped: no word embedding, no
+// ears_tx, nothing for memory. Every transcript, dropped or not, enters the
+// repeat history, so a loop stays caught. The tests feed canned ones.
+
+use std::collections::VecDeque;
+
+use crate::core::config::TranscriptSanityConfig;
+
+/// How believable a transcript is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Verdict {
+    pub score: f32,
+    pub reasons: Vec<&'static str>, // blacklist / repeated / rate / quiet
+    pub blocked: bool,
+}
+
+pub struct SanityScorer {
+    config: TranscriptSanityConfig,
+    recent: VecDeque<String>,
+}
+
+/// Lowercase words without punctuation: "¡Gracias,  por ver!" == "gracias por ver".
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl SanityScorer {
+    pub fn new(config: TranscriptSanityConfig) -> Self {
+        Self { config, recent: VecDeque::new() }
+    }
+
+    /// Score `text` heard in `language` from `audio_secs` of audio peaking at `peak_rms`.
+    pub fn score(&mut self, text: &str, language: &str, audio_secs: f32, peak_rms: f32) -> Verdict {
+        let mut score = 1.0f32;
+        let mut reasons = Vec::new();
+
+        let lower = text.to_lowercase();
+        let listed = ["*", language].iter()
+            .filter_map(|key| self.config.blacklist.get(*key))
+            .flatten()
+            .any(|phrase| !phrase.is_empty() && lower.contains(&phrase.to_lowercase()));
+        if listed {
+            score = 0.0;
+            reasons.push("blacklist");
+        }
+
+        let normalized = normalize(text);
+        let repeats = self.recent.iter().filter(|r| **r == normalized).count();
+        if repeats > 0 {
+            score -= self.config.repeat_penalty * repeats as f32;
+            reasons.push("repeated");
+        }
+
+        let chars = text.chars().filter(|c| !c.is_whitespace()).count() as f32;
+        if audio_secs > 0.0 {
+            let rate = chars / audio_secs;
+            let too_slow = audio_secs >= self.config.slow_min_secs && rate < self.config.min_chars_per_sec;
+            if rate > self.config.max_chars_per_sec || too_slow {
+                score -= self.config.rate_penalty;
+                reasons.push("rate");
+            }
+        }
+
+        if peak_rms < self.config.voice_floor_rms {
+            score -= self.config.quiet_penalty;
+            reasons.push("quiet");
+        }
+
+        if self.config.history > 0 {
+            if self.recent.len() >= self.config.history {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(normalized);
+        }
+        let score = score.max(0.0);
+        Verdict { score, reasons, blocked: score < self.config.min_score }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voice(config: &TranscriptSanityConfig) -> f32 {
+        config.voice_floor_rms * 3.0
+    }
+
+    #[test]
+    fn a_loop_on_silence_is_blocked_from_the_third_time_on() {
+        let config = TranscriptSanityConfig::default();
+        let mut scorer = SanityScorer::new(config.clone());
+        let looped: Vec<bool> = (0..6).map(|_| scorer.score("Y eso es todo por hoy.", "es", 1.5, voice(&config)).blocked).collect();
+        assert_eq!(looped, [false, false, true, true, true, true]);
+    }
+
+    #[test]
+    fn blacklists_apply_per_language_and_star_everywhere() {
+        let config = TranscriptSanityConfig::default();
+        let mut scorer = SanityScorer::new(config.clone());
+        let cases = [
+            ("¡Gracias por ver el video!", "es", true),
+            ("Thanks for watching!", "en", true),
+            ("Thanks for watching!", "es", false),
+            ("[BLANK_AUDIO]", "de", true),
+        ];
+        for (text, language, blocked) in cases {
+            let verdict = scorer.score(text, language, 1.5, voice(&config));
+            assert_eq!(verdict.blocked, blocked, "'{}' [{}]: {:?}", text, language, verdict);
+        }
+    }
+
+    #[test]
+    fn implausible_speaking_rates_are_blocked() {
+        let config = TranscriptSanityConfig::default();
+        let mut scorer = SanityScorer::new(config.clone());
+        let paragraph = "hola ".repeat(40);
+        let fast = scorer.score(&paragraph, "es", 0.5, voice(&config));
+        assert!(fast.blocked, "200 chars in 0.5s: {:?}", fast);
+        let slow = scorer.score("Gracias.", "es", 15.0, voice(&config));
+        assert!(slow.blocked, "'Gracias.' in 15s: {:?}", slow);
+        let short = scorer.score("Sí.", "es", 0.4, voice(&config));
+        assert!(!short.blocked, "'Sí.' in 0.4s: {:?}", short);
+    }
+
+    #[test]
+    fn quiet_audio_is_a_doubt_alone_and_blocked_with_a_repeat() {
+        let config = TranscriptSanityConfig::default();
+        let mut scorer = SanityScorer::new(config.clone());
+        let quiet = config.voice_floor_rms * 0.5;
+        let first = scorer.score("¿Hay alguien ahí?", "es", 1.5, quiet);
+        assert!(!first.blocked, "first: {:?}", first);
+        let again = scorer.score("¿Hay alguien ahí?", "es", 1.5, quiet);
+        assert!(again.blocked && again.reasons.contains(&"quiet"), "repeated: {:?}", again);
+    }
+
+    #[test]
+    fn real_conversation_passes() {
+        let config = TranscriptSanityConfig::default();
+        let mut scorer = SanityScorer::new(config.clone());
+        let sentences = [
+            "Hola, ¿cómo estás?", "Hoy llueve mucho en la ciudad.", "Me llamo Ana.",
+            "¿Qué estás pensando?", "El café está frío.", "Mañana vamos al mar.",
+            "No entiendo lo que dices.", "Escucha esta canción.", "Ya es tarde.", "Buenas noches, Aleph.",
+        ];
+        let blocked: Vec<&str> = sentences.iter()
+            .filter(|s| scorer.score(s, "es", s.chars().count() as f32 / 14.0, voice(&config)).blocked)
+            .copied()
+            .collect();
+        assert!(blocked.is_empty(), "plausible speech blocked: {:?}", blocked);
+    }
+}
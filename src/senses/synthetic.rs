@@ -0,0 +1,234 @@
+// src/senses/synthetic.rs
+// THE TUNING FORK: Deterministic test audio, no hardware needed
+//
+// Sensory code used to need a microphone, or a file played in real time. A
+// Script describes a sound as a list of segments and renders it to mono
+// samples that go through the same processor as the mic (SensoryMode::Synthetic),
+// optionally faster than real time. Same script + same seed = same samples.
+//
+// DSL: one statement per line or `;`-separated, `#` starts a comment:
+//
+//   rate 16000            sample rate (default 16000)
+//   speed 10x             playback speed multiplier (default 1x)
+//   seed 7                noise seed (default 7)
+//   tone 440hz 0.5 2s     sine: frequency, amplitude, duration
+//   noise 0.8 500ms       white noise burst: amplitude, duration
+//   silence 1s
+//   impulse 0.9           a one-sample click: a marker whose arrival can be timed
+//   wav voice.wav         a recording (resampled to `rate`, mixed to mono)
+//
+// or in code: Script::new(16000).silence(1.0).noise(0.8, 0.5).tone(440.0, 0.3, 2.0)
+
+use anyhow::{anyhow, bail, Context, Result};
+use std::fs::File;
+use std::path::Path;
+
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+#[derive(Debug, Clone)]
+pub enum Segment {
+    Tone { hz: f32, amplitude: f32, secs: f32 },
+    Noise { amplitude: f32, secs: f32 },
+    Silence { secs: f32 },
+    Impulse { amplitude: f32 },
+    Wav(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Script {
+    pub sample_rate: u32,
+    pub speed: f32,
+    pub seed: u64,
+    pub segments: Vec<Segment>,
+}
+
+impl Script {
+    pub fn new(sample_rate: u32) -> Self {
+        Self { sample_rate, speed: 1.0, seed: 7, segments: Vec::new() }
+    }
+
+    pub fn tone(mut self, hz: f32, amplitude: f32, secs: f32) -> Self {
+        self.segments.push(Segment::Tone { hz, amplitude, secs });
+        self
+    }
+
+    pub fn noise(mut self, amplitude: f32, secs: f32) -> Self {
+        self.segments.push(Segment::Noise { amplitude, secs });
+        self
+    }
+
+    pub fn silence(mut self, secs: f32) -> Self {
+        self.segments.push(Segment::Silence { secs });
+        self
+    }
+
+    pub fn impulse(mut self, amplitude: f32) -> Self {
+        self.segments.push(Segment::Impulse { amplitude });
+        self
+    }
+
+    pub fn wav(mut self, path: &str) -> Self {
+        self.segments.push(Segment::Wav(path.to_string()));
+        self
+    }
+
+    /// A script file, or the script text itself (`--synthetic "noise 0.8 1s; silence 2s"`).
+    pub fn load(arg: &str) -> Result<Self> {
+        if Path::new(arg).is_file() {
+            let text = std::fs::read_to_string(arg).with_context(|| format!("reading {}", arg))?;
+            Self::parse(&text)
+        } else {
+            Self::parse(arg)
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut script = Script::new(16000);
+        let statements = text.lines()
+            .map(|line| line.split('#').next().unwrap_or(""))
+            .flat_map(|line| line.split(';'))
+            .map(str::trim)
+            .filter(|s| !s.is_empty());
+        for statement in statements {
+            let words: Vec<&str> = statement.split_whitespace().collect();
+            let arg = |i: usize| words.get(i).copied().ok_or_else(|| anyhow!("'{}': missing argument {}", statement, i));
+            let number = |i: usize| -> Result<f32> {
+                let w = arg(i)?;
+                w.parse().map_err(|_| anyhow!("'{}': '{}' is not a number", statement, w))
+            };
+            match words[0] {
+                "rate" => script.sample_rate = number(1)? as u32,
+                "speed" => script.speed = parse_speed(arg(1)?)?,
+                "seed" => script.seed = arg(1)?.parse().map_err(|_| anyhow!("'{}': bad seed", statement))?,
+                "tone" => {
+                    let hz = arg(1)?.trim_end_matches("hz").trim_end_matches("Hz");
+                    let hz = hz.parse().map_err(|_| anyhow!("'{}': bad frequency", statement))?;
+                    script = script.tone(hz, number(2)?, parse_duration(arg(3)?)?);
+                },
+                "noise" => script = script.noise(number(1)?, parse_duration(arg(2)?)?),
+                "silence" => script = script.silence(parse_duration(arg(1)?)?),
+                "impulse" => script = script.impulse(number(1)?),
+                "wav" => script = script.wav(arg(1)?),
+                other => bail!("unknown statement '{}' (rate, speed, seed, tone, noise, silence, impulse, wav)", other),
+            }
+        }
+        if script.sample_rate < 8000 {
+            bail!("rate {} is too low (min 8000)", script.sample_rate);
+        }
+        Ok(script)
+    }
+
+    /// The script as DSL text (`parse` gives it back; the cortexd child gets it on its command line).
+    pub fn to_dsl(&self) -> String {
+        let mut statements = vec![format!("rate {}", self.sample_rate), format!("speed {}x", self.speed), format!("seed {}", self.seed)];
+        statements.extend(self.segments.iter().map(|segment| match segment {
+            Segment::Tone { hz, amplitude, secs } => format!("tone {}hz {} {}s", hz, amplitude, secs),
+            Segment::Noise { amplitude, secs } => format!("noise {} {}s", amplitude, secs),
+            Segment::Silence { secs } => format!("silence {}s", secs),
+            Segment::Impulse { amplitude } => format!("impulse {}", amplitude),
+            Segment::Wav(path) => format!("wav {}", path),
+        }));
+        statements.join("; ")
+    }
+
+    /// Mono samples at `sample_rate`. Deterministic: noise comes from `seed`.
+    pub fn render(&self) -> Result<Vec<f32>> {
+        let rate = self.sample_rate as f32;
+        let mut rng = self.seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1; // xorshift state, never 0
+        let mut out = Vec::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Tone { hz, amplitude, secs } => {
+                    let n = (secs * rate) as usize;
+                    out.extend((0..n).map(|i| amplitude * (std::f32::consts::TAU * hz * i as f32 / rate).sin()));
+                },
+                Segment::Noise { amplitude, secs } => {
+                    let n = (secs * rate) as usize;
+                    out.extend((0..n).map(|_| {
+                        rng ^= rng << 13;
+                        rng ^= rng >> 7;
+                        rng ^= rng << 17;
+                        amplitude * ((rng >> 40) as f32 / (1u64 << 24) as f32 * 2.0 - 1.0)
+                    }));
+                },
+                Segment::Silence { secs } => out.extend(std::iter::repeat(0.0).take((secs * rate) as usize)),
+                Segment::Impulse { amplitude } => out.push(*amplitude),
+                Segment::Wav(path) => {
+                    let (file_rate, samples) = decode_mono(path)?;
+                    out.extend(resample(&samples, file_rate, self.sample_rate));
+                },
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// "10x", "10" or "0.5x". Must be > 0.
+pub fn parse_speed(s: &str) -> Result<f32> {
+    let speed: f32 = s.trim_end_matches(['x', 'X']).parse()
+        .map_err(|_| anyhow!("bad speed '{}' (expected e.g. 10x)", s))?;
+    if !(speed > 0.0 && speed.is_finite()) {
+        bail!("speed must be > 0 (got {})", s);
+    }
+    Ok(speed)
+}
+
+/// "2s", "500ms" or a bare number of seconds.
+fn parse_duration(s: &str) -> Result<f32> {
+    let secs = if let Some(ms) = s.strip_suffix("ms") {
+        ms.parse::<f32>().map(|v| v / 1000.0)
+    } else {
+        s.trim_end_matches('s').parse::<f32>()
+    };
+    secs.ok().filter(|v| *v >= 0.0).ok_or_else(|| anyhow!("bad duration '{}' (expected e.g. 2s or 500ms)", s))
+}
+
+/// Whole file, mixed down to mono.
+fn decode_mono(path: &str) -> Result<(u32, Vec<f32>)> {
+    let src = File::open(path).with_context(|| format!("opening {}", path))?;
+    let mss = MediaSourceStream::new(Box::new(src), Default::default());
+    let probed = symphonia::default::get_probe()
+        .format(&Hint::new(), mss, &FormatOptions::default(), &MetadataOptions::default())
+        .with_context(|| format!("unsupported format: {}", path))?;
+    let mut format = probed.format;
+    let track = format.tracks().iter().find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| anyhow!("no audio track in {}", path))?;
+    let track_id = track.id;
+    let rate = track.codec_params.sample_rate.unwrap_or(44100);
+    let mut decoder = symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())
+        .with_context(|| format!("unsupported codec: {}", path))?;
+
+    let mut mono = Vec::new();
+    while let Ok(packet) = format.next_packet() {
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let Ok(decoded) = decoder.decode(&packet) else { continue };
+        let spec = *decoded.spec();
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        let channels = spec.channels.count().max(1);
+        mono.extend(buffer.samples().chunks(channels).map(|frame| frame.iter().sum::<f32>() / channels as f32));
+    }
+    Ok((rate, mono))
+}
+
+/// Linear interpolation (test audio, not mastering).
+fn resample(samples: &[f32], from: u32, to: u32) -> Vec<f32> {
+    if from == to || samples.is_empty() {
+        return samples.to_vec();
+    }
+    let ratio = from as f32 / to as f32;
+    let n = (samples.len() as f32 / ratio) as usize;
+    (0..n).map(|i| {
+        let pos = i as f32 * ratio;
+        let (idx, frac) = (pos as usize, pos.fract());
+        let next = samples.get(idx + 1).copied().unwrap_or(samples[idx.min(samples.len() - 1)]);
+        samples[idx.min(samples.len() - 1)] * (1.0 - frac) + next * frac
+    }).collect()
+}
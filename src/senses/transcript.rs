@@ -0,0 +1,174 @@
+// src/senses/transcript.rs
+// THE PAUSES: One transcript, several things said
+//
+// Whisper often hands back a whole turn at once ("Hola. ¿Cómo estás? Yo bien.").
+// Stored as one engram and hashed into the sensory vector in one go, it made
+// retrieval coarse and the sensory projection a smear. The daemon now:
+//
+//   1. splits the transcript into sentences (`split_sentences`: . ! ? … and line
+//      breaks end one; ¿ ¡ open one; decimals and "..." mid-word don't cut),
+//   2. stores each sentence as its own engram, all sharing an utterance id so the
+//      hippocampus can put the turn back together when one of them is recalled,
+//   3. hashes each sentence's words into the sensory channels a few ticks after
+//      the previous one (`WordEchoes`), as they were heard, not all at once,
+//   4. hands only the last sentence to the Planet as the percept.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+
+/// Characters that end a sentence.
+const TERMINATORS: [char; 4] = ['.', '!', '?', '…'];
+/// Characters that may trail a terminator and still belong to its sentence.
+const CLOSERS: [char; 6] = ['"', '\'', '”', '’', '»', ')'];
+
+/// The sentences of a transcript, trimmed, in order (empty ones dropped).
+/// A terminator only ends a sentence before whitespace or the end of the text.
+pub fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\n' || c == '\r' {
+            push_sentence(&mut sentences, &mut current);
+            continue;
+        }
+        // ¿ ¡ after a finished clause open a new one ("bien ¿y tú?" stays whole)
+        if (c == '¿' || c == '¡') && current.trim_end().ends_with(TERMINATORS) {
+            push_sentence(&mut sentences, &mut current);
+        }
+        current.push(c);
+        if TERMINATORS.contains(&c) {
+            while let Some(&next) = chars.peek() {
+                if TERMINATORS.contains(&next) || CLOSERS.contains(&next) {
+                    current.push(next);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            if chars.peek().is_none_or(|n| n.is_whitespace()) {
+                push_sentence(&mut sentences, &mut current);
+            }
+        }
+    }
+    push_sentence(&mut sentences, &mut current);
+    sentences
+}
+
+fn push_sentence(sentences: &mut Vec<String>, current: &mut String) {
+    let sentence = current.trim();
+    if !sentence.is_empty() && sentence.chars().any(|c| c.is_alphanumeric()) {
+        sentences.push(sentence.to_string());
+    }
+    current.clear();
+}
+
+/// Sensory channel a word lands on (of `channels`).
+pub fn word_channel(word: &str, channels: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    word.hash(&mut hasher);
+    (hasher.finish() % channels.max(1) as u64) as usize
+}
+
+/// Sentences waiting to be felt: each one's words hit the sensory vector at its tick.
+#[derive(Debug, Default)]
+pub struct WordEchoes {
+    queue: VecDeque<(u64, String)>,
+}
+
+impl WordEchoes {
+    /// Sentence i of this utterance is due `i * offset_ticks` after `now`, and never
+    /// before the end of what is already queued (utterances don't interleave).
+    pub fn schedule(&mut self, sentences: &[String], now: u64, offset_ticks: u64) {
+        let start = self.queue.back().map_or(now, |(tick, _)| (*tick + offset_ticks).max(now));
+        for (i, sentence) in sentences.iter().enumerate() {
+            self.queue.push_back((start + i as u64 * offset_ticks, sentence.clone()));
+        }
+    }
+
+    /// The sentences due by `now`, in order.
+    pub fn due(&mut self, now: u64) -> Vec<String> {
+        let mut due = Vec::new();
+        while self.queue.front().is_some_and(|(tick, _)| *tick <= now) {
+            if let Some((_, sentence)) = self.queue.pop_front() {
+                due.push(sentence);
+            }
+        }
+        due
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Forget what hasn't been felt yet (Do-Not-Disturb).
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn sentences_split_on_spanish_punctuation() {
+        let cases: [(&str, &[&str]); 10] = [
+            ("Hola. ¿Cómo estás? Yo bien.", &["Hola.", "¿Cómo estás?", "Yo bien."]),
+            ("¡Qué día!¿Viste el mar?", &["¡Qué día!", "¿Viste el mar?"]),
+            ("bien, ¿y tú?", &["bien, ¿y tú?"]),
+            ("Pesa 3.5 kilos. Nada más", &["Pesa 3.5 kilos.", "Nada más"]),
+            ("Bueno... no sé. Quizás", &["Bueno...", "no sé.", "Quizás"]),
+            ("Dijo \"basta.\" Y se fue.", &["Dijo \"basta.\"", "Y se fue."]),
+            ("primera línea\nsegunda línea", &["primera línea", "segunda línea"]),
+            ("¿Sí?! Claro…  Vale", &["¿Sí?!", "Claro…", "Vale"]),
+            ("   ", &[]),
+            ("... . ¿?", &[]),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(split_sentences(text), expected, "split {:?}", text);
+        }
+    }
+
+    #[test]
+    fn echoes_keep_their_offsets_and_never_interleave() {
+        // A second utterance queues behind the first
+        let mut echoes = WordEchoes::default();
+        let (first, second) = (words(&["a", "b", "c"]), words(&["d", "e"]));
+        echoes.schedule(&first, 100, 6);
+        echoes.schedule(&second, 101, 6);
+        let mut felt = Vec::new();
+        for tick in 100..=140 {
+            for sentence in echoes.due(tick) {
+                felt.push((tick, sentence));
+            }
+        }
+        let expected: Vec<(u64, String)> = [(100, "a"), (106, "b"), (112, "c"), (118, "d"), (124, "e")]
+            .iter().map(|(t, s)| (*t, s.to_string())).collect();
+        assert_eq!(felt, expected);
+        assert!(echoes.is_empty(), "{} left", echoes.len());
+
+        // A late poll gets everything due at once, still in order; offset 0 = all together
+        echoes.schedule(&first, 200, 0);
+        echoes.schedule(&second, 300, 6);
+        assert_eq!(echoes.due(200), first);
+        assert_eq!(echoes.due(400), second);
+    }
+
+    #[test]
+    fn word_channels_stay_in_range() {
+        for word in ["hola", "¿cómo", "", "🙂"] {
+            assert!(word_channel(word, 500) < 500, "{:?}", word);
+        }
+        assert_eq!(word_channel("x", 0), 0);
+    }
+}
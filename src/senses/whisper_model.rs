@@ -0,0 +1,101 @@
+// src/senses/whisper_model.rs
+// THE COCHLEA: Which Whisper ALEPH hears with, and where it comes from
+//
+// `[ears]` in aleph.toml picks a size (tiny/base/small) or an explicit path.
+// If the file is missing and `auto_download` is on, the official ggml build is
+// fetched from the whisper.cpp repository on Hugging Face, verified against its
+// published SHA-1 and only then moved into the models dir. Any failure is an
+// Err, never a panic: the ears fall back to Headless instead.
+
+use anyhow::{anyhow, bail, Result};
+use sha1::{Digest, Sha1};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::Sender;
+
+use crate::core::config::EarsConfig;
+use crate::core::thought::{Thought, MindVoice};
+
+const BASE_URL: &str = "https://huggingface.co/ggerganov/whisper.cpp/resolve/main";
+
+/// (size, sha1) as published by whisper.cpp (models/README.md).
+const KNOWN_MODELS: [(&str, &str); 3] = [
+    ("tiny", "bd577a113a864445d4c299885e0cb97d4ba92b5f"),
+    ("base", "465707469ff3a37a2b9b8d8f89f2f99de7299dac"),
+    ("small", "55356645c2b361a969dfd0ef2c5a50d530afd8d5"),
+];
+
+/// Path of a usable model file, downloading it first if allowed.
+pub fn resolve(config: &EarsConfig, thoughts: &Sender<Thought>) -> Result<PathBuf> {
+    if let Some(path) = &config.model_path {
+        let path = PathBuf::from(path);
+        if path.exists() {
+            return Ok(path);
+        }
+        bail!("model_path '{}' does not exist", path.display());
+    }
+
+    let (size, sha1) = KNOWN_MODELS.iter()
+        .find(|(size, _)| *size == config.model_size)
+        .ok_or_else(|| anyhow!("unknown Whisper size '{}' (tiny, base or small)", config.model_size))?;
+
+    let file_name = format!("ggml-{}.bin", size);
+    let path = PathBuf::from(&config.models_dir).join(&file_name);
+    if path.exists() {
+        return Ok(path);
+    }
+    if !config.auto_download {
+        bail!("{} not found (set ears.auto_download = true or place it there)", path.display());
+    }
+
+    download(&format!("{}/{}", BASE_URL, file_name), &path, sha1, thoughts)?;
+    Ok(path)
+}
+
+fn download(url: &str, dest: &PathBuf, expected_sha1: &str, thoughts: &Sender<Thought>) -> Result<()> {
+    let _ = thoughts.send(Thought::new(MindVoice::System, format!("⬇️ EARS: Downloading {}...", url)));
+
+    let response = ureq::get(url).call()?;
+    let total: Option<u64> = response.header("Content-Length").and_then(|v| v.parse().ok());
+
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let partial = dest.with_extension("bin.part");
+    let mut file = File::create(&partial)?;
+    let mut reader = response.into_reader();
+    let mut hasher = Sha1::new();
+    let mut buf = vec![0u8; 1 << 20];
+    let mut received: u64 = 0;
+    let mut last_decile = 0;
+
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 { break; }
+        file.write_all(&buf[..n])?;
+        hasher.update(&buf[..n]);
+        received += n as u64;
+
+        if let Some(total) = total.filter(|t| *t > 0) {
+            let decile = received * 10 / total;
+            if decile > last_decile {
+                last_decile = decile;
+                let _ = thoughts.send(Thought::new(MindVoice::System,
+                    format!("⬇️ EARS: {}% ({} / {} MB)", decile * 10, received >> 20, total >> 20)));
+            }
+        }
+    }
+    file.sync_all()?;
+    drop(file);
+
+    let actual: String = hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect();
+    if actual != expected_sha1 {
+        let _ = fs::remove_file(&partial);
+        bail!("checksum mismatch for {} (got {}, expected {})", url, actual, expected_sha1);
+    }
+
+    fs::rename(&partial, dest)?;
+    let _ = thoughts.send(Thought::new(MindVoice::System, format!("✅ EARS: {} verified and cached.", dest.display())));
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use anyhow::Result;
-use std::io::{self, Read, Write};
+use std::io::{self, Write};
 use rand::Rng; // For Glitching
 use std::os::unix::net::UnixStream;
 use std::time::Duration;
-use crate::core::ipc::AlephPacket;
+use crate::core::feeding::{Feeding, Food};
+use crate::core::ipc::{self, AlephPacket, EventKind, LineBuffer, MAX_TELEMETRY_LINE};
+use crate::core::textutil;
 use crate::senses::ears::AudioSpectrum;
 use crate::tui::avatar::{self};
 use crossterm::{
@@ -21,6 +23,9 @@ use ratatui::{
 };
 use std::thread;
 
+/// Amount of one F3/F4/F5 feed.
+const TUI_FEED: f32 = 0.2;
+
 pub fn run() -> Result<()> {
     // 1. Setup Terminal
     enable_raw_mode()?;
@@ -45,12 +50,12 @@ pub fn run() -> Result<()> {
     let _window_width = 60.0; // 60 seconds of history
 
     // 2. Connect to Nervous System
-    let socket_path = "/tmp/aleph.sock";
+    let socket_path = crate::core::config::AlephConfig::load().runtime.ipc_socket;
     println!("🔌 Connecting to Nervous System at {}...", socket_path);
     
     // Retry loop for connection
     let mut stream = loop {
-        match UnixStream::connect(socket_path) {
+        match UnixStream::connect(&socket_path) {
             Ok(s) => break s,
             Err(_) => {
                 thread::sleep(Duration::from_millis(500));
@@ -81,78 +86,95 @@ pub fn run() -> Result<()> {
         reservoir_size: 500,
         visual_cortex: Vec::new(),
         neuron_positions: Vec::new(),
+        privacy_mode: false,
+        satellite_passed: 0,
+        satellite_hardened: 0,
+        satellite_ontological_errors: 0,
+        satellite_attention: 0.0,
+        gate_attempts: 0,
+        gate_spoken: 0,
+        gate_suppressed_by_reason: Default::default(),
     };
     
     // Input Buffer
     let mut input_buffer = String::new();
+
+    // Events (epiphanies, sleep stages, restarts...): each arrives once, newest last
+    let mut event_log: Vec<String> = Vec::new();
+
+    // The daemon's clock origin (Hello): spectrum capture stamps -> end-to-end latency
+    let mut clock_origin_ms: Option<u64> = None;
     
-    // Packet Receiver Thread logic is implicit effectively by polling non-blocking stream
-    // Actually, reading from stream is stream-based, so we might get partial JSON.
-    // For specific "lines", we need a BufReader, but that blocks. 
-    // Let's use a simpler approach: Read to buffer, split by newline.
-    let mut net_buffer = Vec::new();
+    // The socket is a stream: packets arrive split across reads, or several per
+    // read. LineBuffer reassembles them and every packet is dispatched, in order.
+    let mut net_buffer = LineBuffer::new(MAX_TELEMETRY_LINE);
 
     // 4. Main Event Loop
     loop {
         // A. Network Read (Non-blocking)
-        let mut chunk = [0u8; 16384]; // 16KB Buffer for Neural State
-        if let Ok(n) = stream.read(&mut chunk) {
-            if n > 0 {
-                net_buffer.extend_from_slice(&chunk[..n]);
-                // Try to parse lines using lossy to avoid UTF-8 crash on boundary
-                let s = String::from_utf8_lossy(&net_buffer).to_string();
-                if let Some(pos) = s.rfind('\n') {
-                    let lines: Vec<&str> = s[..pos].split('\n').collect();
-                    if let Some(last_line) = lines.last() {
-                        if !last_line.is_empty() {
-                            match serde_json::from_str::<AlephPacket>(last_line) {
-                                Ok(packet) => {
-                                    last_packet = packet;
-                                    // Update Entropy History
-                                    if let AlephPacket::Telemetry { entropy, .. } = &last_packet {
-                                        entropy_history.push((last_tick.elapsed().as_secs_f64(), *entropy as f64)); // Use ELAPSED time not absolute
-                                        // Keep roughly last 100 points or based on time?
-                                        if entropy_history.len() > 200 {
-                                             entropy_history.remove(0);
-                                        }
-                                    }
-                                },
-                                Err(e) => {
-                                    // Inject error into state for visibility
-                                    if let AlephPacket::Telemetry { adenosine, cortisol, dopamine, oxytocin, audio_spectrum, heart_rate, lucidity, reservoir_activity, short_term_memory, loop_frequency, cpu_usage, reservoir_size, .. } = &last_packet {
-                                         last_packet = AlephPacket::Telemetry {
-                                            adenosine: *adenosine,
-                                            cortisol: *cortisol,
-                                            dopamine: *dopamine,
-                                            oxytocin: *oxytocin,
-                                            audio_spectrum: audio_spectrum.clone(),
-                                            heart_rate: *heart_rate,
-                                            lucidity: *lucidity,
-                                            reservoir_activity: reservoir_activity.clone(),
-                                            short_term_memory: short_term_memory.clone(),
-                                            current_state: "Decoding Error".to_string(),
-                                            entropy: 0.0, // Placeholder
-                                            loop_frequency: *loop_frequency,
-                                            cpu_usage: *cpu_usage,
-                                            activations: Vec::new(),
-                                            region_map: Vec::new(),
-                                            reservoir_size: *reservoir_size,
-                                            visual_cortex: Vec::new(),
-                                            neuron_positions: Vec::new(),
-                                        };
-                                    }
-                                }
+        if let Ok(Some(packets)) = ipc::read_packets(&mut stream, &mut net_buffer) {
+            for packet in packets {
+                match packet {
+                    Ok(AlephPacket::Stimulus { .. }) => {
+                        // Echo of a stimulus: not a frame, the screen keeps the last telemetry
+                    },
+                    Ok(AlephPacket::Hello { clock_origin_ms: origin, .. }) => {
+                        clock_origin_ms = Some(origin);
+                    },
+                    Ok(AlephPacket::Event { kind, timestamp, detail }) => {
+                        event_log.push(event_line(kind, timestamp, &detail));
+                        if event_log.len() > EVENT_LOG_LEN {
+                            event_log.remove(0);
+                        }
+                    },
+                    Ok(packet @ AlephPacket::Telemetry { .. }) => {
+                        // Update Entropy History (every frame, not just the last of the read)
+                        if let AlephPacket::Telemetry { entropy, .. } = &packet {
+                            entropy_history.push((last_tick.elapsed().as_secs_f64(), *entropy as f64)); // Use ELAPSED time not absolute
+                            // Keep roughly last 100 points or based on time?
+                            if entropy_history.len() > 200 {
+                                 entropy_history.remove(0);
                             }
                         }
+                        last_packet = packet;
+                    },
+                    Ok(_) => {},
+                    Err(_) => {
+                        // Inject error into state for visibility
+                        if let AlephPacket::Telemetry { adenosine, cortisol, dopamine, oxytocin, audio_spectrum, heart_rate, lucidity, reservoir_activity, short_term_memory, loop_frequency, cpu_usage, reservoir_size, privacy_mode, .. } = &last_packet {
+                             last_packet = AlephPacket::Telemetry {
+                                adenosine: *adenosine,
+                                cortisol: *cortisol,
+                                dopamine: *dopamine,
+                                oxytocin: *oxytocin,
+                                audio_spectrum: audio_spectrum.clone(),
+                                heart_rate: *heart_rate,
+                                lucidity: *lucidity,
+                                reservoir_activity: reservoir_activity.clone(),
+                                short_term_memory: short_term_memory.clone(),
+                                current_state: "Decoding Error".to_string(),
+                                entropy: 0.0, // Placeholder
+                                loop_frequency: *loop_frequency,
+                                cpu_usage: *cpu_usage,
+                                activations: Vec::new(),
+                                region_map: Vec::new(),
+                                reservoir_size: *reservoir_size,
+                                visual_cortex: Vec::new(),
+                                neuron_positions: Vec::new(),
+                                privacy_mode: *privacy_mode,
+                                satellite_passed: 0,
+                                satellite_hardened: 0,
+                                satellite_ontological_errors: 0,
+                                satellite_attention: 0.0,
+                                gate_attempts: 0,
+                                gate_spoken: 0,
+                                gate_suppressed_by_reason: Default::default(),
+                            };
+                        }
                     }
-                    // Clear processed part
-                    net_buffer = s[pos+1..].as_bytes().to_vec();
-                } else if net_buffer.len() > 65536 {
-                     // Safety drain if no newline found for a long time
-                     net_buffer.clear();
-                }
                 }
             }
+        }
 
 
         // Calculate FPS
@@ -177,13 +199,13 @@ pub fn run() -> Result<()> {
                     ]
                     .as_ref(),
                 )
-                .split(f.size());
+                .split(f.area());
 
             // 0. Extract Data First
-            let (aden, cort, dopa, oxy, spec, neurons, current_hz) = match &last_packet {
-                AlephPacket::Telemetry { adenosine, cortisol, dopamine, oxytocin, audio_spectrum, reservoir_activity, loop_frequency, .. } => 
-                    (*adenosine, *cortisol, *dopamine, *oxytocin, audio_spectrum.clone(), reservoir_activity.clone(), *loop_frequency),
-                _ => (0.0, 0.0, 0.0, 0.0, AudioSpectrum::default(), vec![], 60.0),
+            let (aden, cort, dopa, oxy, spec, neurons, current_hz, dnd) = match &last_packet {
+                AlephPacket::Telemetry { adenosine, cortisol, dopamine, oxytocin, audio_spectrum, reservoir_activity, loop_frequency, privacy_mode, .. } => 
+                    (*adenosine, *cortisol, *dopamine, *oxytocin, audio_spectrum.clone(), reservoir_activity.clone(), *loop_frequency, *privacy_mode),
+                _ => (0.0, 0.0, 0.0, 0.0, AudioSpectrum::default(), vec![], 60.0, false),
             };
             
             // Audio Logic
@@ -191,7 +213,7 @@ pub fn run() -> Result<()> {
             let val_mids = (spec.mids * 500.0).clamp(0.0, 1.0);
             let val_highs = (spec.highs * 500.0).clamp(0.0, 1.0);
             let val_rms = (spec.rms * 1000.0).clamp(0.0, 1.0); // Sensitive scaling (was * 10.0)
-            let hearing_status = if spec.rms > 0.001 { "LISTENING" } else { "SILENT" };
+            let hearing_status = if dnd { "🔒 DND (PRIVATE)" } else if spec.rms > 0.001 { "LISTENING" } else { "SILENT" };
 
              let make_bar_audio = |value: f32, label: &str| -> Line {
                 let bar_width = 10;
@@ -212,13 +234,17 @@ pub fn run() -> Result<()> {
             
             // Truncate status to avoid breaking layout
             let status_trimmed = if packet_data.chars().count() > 25 { 
-                format!("{}...", packet_data.chars().take(24).collect::<String>()) 
+                format!("{}...", textutil::truncate_chars(&packet_data, 24))
             } else { 
                 packet_data.clone() 
             };
             
-            let title_text = format!("ALEPH v2.0 | {} Hz | ST: {} | EAR: {} ({:.4})", 
-                current_hz as u32, status_trimmed, hearing_status, spec.rms);
+            // What the bars show was heard this long ago (capture -> daemon -> socket -> screen)
+            let ear_lag = clock_origin_ms.filter(|_| spec.captured_us > 0)
+                .map(|origin| format!(" {}ms", crate::core::timeline::unix_millis(std::time::SystemTime::now()).saturating_sub(origin + spec.captured_us / 1000)))
+                .unwrap_or_default();
+            let title_text = format!("ALEPH v2.0 | {} Hz | ST: {} | EAR: {} ({:.4}){}", 
+                current_hz as u32, status_trimmed, hearing_status, spec.rms, ear_lag);
             
             // Generate Avatar
             // We need to construct a temp struct for the helper or update the helper.
@@ -244,8 +270,8 @@ pub fn run() -> Result<()> {
                 make_bar_audio(val_mids, "MIDS"),
                 make_bar_audio(val_highs, "HIGH"),
             ])
-            .style(Style::default().fg(if val_rms > 0.1 { Color::Green } else { Color::Cyan }).add_modifier(Modifier::BOLD))
-            .block(Block::default().borders(Borders::ALL));
+            .style(Style::default().fg(if dnd { Color::Magenta } else if val_rms > 0.1 { Color::Green } else { Color::Cyan }).add_modifier(Modifier::BOLD))
+            .block(Block::default().borders(Borders::ALL).title(if dnd { " 🔒 PRIVACY MODE — not transcribing (F2) " } else { "" }));
             f.render_widget(title_paragraph, header_layout[0]);
             
             let face_widget = Paragraph::new(face.ascii)
@@ -293,7 +319,7 @@ pub fn run() -> Result<()> {
             // Narrative Stream (Chat Style: Oldest Top, Newest Bottom)
             let messages: Vec<ListItem> = match &last_packet {
                 AlephPacket::Telemetry { short_term_memory, adenosine, cortisol, .. } => {
-                    let mut rng = rand::thread_rng();
+                    let mut rng = rand::rng();
                     short_term_memory.iter().enumerate().map(|(i, m)| {
                          // 1. FADING (Adenosine)
                          // Older messages fade more? Or global fatigue fades everything?
@@ -308,23 +334,29 @@ pub fn run() -> Result<()> {
                          let color_val = (255.0 * brightness) as u8;
                          let base_color = Color::Rgb(color_val, color_val, color_val);
                          
+                         // Timestamp stays legible (dim, never glitched)
+                         let (stamp, m) = split_clock(m);
+
                          // 2. GLITCHING (Cortisol / Structural Tremor)
                          // If cortisol is high, text characters might "shift".
-                         let display_text = if *cortisol > 0.3 && rng.gen::<f32>() < (*cortisol - 0.3) {
+                         let display_text = if *cortisol > 0.3 && rng.random::<f32>() < (*cortisol - 0.3) {
                              // Glitch: Replace random chars
                              m.chars().map(|c| {
-                                 if rng.gen::<f32>() < 0.1 {
+                                 if rng.random::<f32>() < 0.1 {
                                      let glitches = ['!', '?', '#', '@', '%', '&', '*', '0', '1'];
-                                     glitches[rng.gen_range(0..glitches.len())]
+                                     glitches[rng.random_range(0..glitches.len())]
                                  } else {
                                      c
                                  }
                              }).collect::<String>()
                          } else {
-                             m.clone()
+                             m.to_string()
                          };
 
-                         ListItem::new(Line::from(vec![Span::styled(display_text, Style::default().fg(base_color))]))
+                         ListItem::new(Line::from(vec![
+                             Span::styled(stamp, Style::default().fg(Color::DarkGray)),
+                             Span::styled(display_text, Style::default().fg(base_color)),
+                         ]))
                     }).collect()
                 },
                 _ => vec![],
@@ -375,14 +407,24 @@ pub fn run() -> Result<()> {
             }
             if !current_line.is_empty() { neuron_lines.push(Line::from(current_line)); }
             
+            let right_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(EVENT_LOG_LEN as u16 + 2)].as_ref())
+                .split(body_chunks[1]);
             let cortex_widget = Paragraph::new(neuron_lines)
                 .block(Block::default().title("Neocortex (Reservoir)").borders(Borders::ALL));
-            f.render_widget(cortex_widget, body_chunks[1]);
+            f.render_widget(cortex_widget, right_chunks[0]);
+
+            let event_items: Vec<ListItem> = event_log.iter().map(|line| ListItem::new(line.as_str())).collect();
+            let events_widget = List::new(event_items)
+                .style(Style::default().fg(Color::Yellow))
+                .block(Block::default().title("Events").borders(Borders::ALL));
+            f.render_widget(events_widget, right_chunks[1]);
 
             // 4. Input
             let input = Paragraph::new(format!("> {}", input_buffer))
                 .style(Style::default().fg(Color::White))
-                .block(Block::default().borders(Borders::ALL).title("Perturb System"));
+                .block(Block::default().borders(Borders::ALL).title("Perturb System (F2 DND · F3 attention · F4 data · F5 rest)"));
             f.render_widget(input, chunks[3]);
 
         })?;
@@ -392,6 +434,24 @@ pub fn run() -> Result<()> {
             if let Event::Key(key) = event::read()? {
                 match key.code {
                     KeyCode::Esc => break,
+                    KeyCode::F(2) => {
+                        // Toggle Do-Not-Disturb (daemon owns the state; telemetry tells us the current one)
+                        let dnd = matches!(&last_packet, AlephPacket::Telemetry { privacy_mode: true, .. });
+                        let command = if dnd { "SYS:DND_OFF" } else { "SYS:DND_ON" };
+                        let packet = AlephPacket::Stimulus { text: command.to_string(), force: 1.0 };
+                        if let Ok(json) = serde_json::to_string(&packet) {
+                            let _ = stream.write_all(format!("{}\n", json).as_bytes());
+                        }
+                    },
+                    KeyCode::F(n @ 3..=5) => {
+                        // Feed: F3 attention, F4 data, F5 rest (the daemon's loop eats it)
+                        let kind = [Food::Attention, Food::Data, Food::Rest][n as usize - 3];
+                        let feed = Feeding { kind, amount: TUI_FEED, text: None };
+                        let packet = AlephPacket::Stimulus { text: feed.command(), force: 1.0 };
+                        if let Ok(json) = serde_json::to_string(&packet) {
+                            let _ = stream.write_all(format!("{}\n", json).as_bytes());
+                        }
+                    },
                     KeyCode::Char(c) => input_buffer.push(c),
                     KeyCode::Backspace => { input_buffer.pop(); },
                     KeyCode::Enter => {
@@ -420,3 +480,30 @@ pub fn run() -> Result<()> {
 
     Ok(())
 }
+
+/// "HH:MM:SS rest" -> ("HH:MM:SS ", "rest"); lines without a clock are all text.
+fn split_clock(line: &str) -> (&str, &str) {
+    let stamped = line.len() > 9
+        && line.as_bytes()[..8].iter().enumerate().all(|(i, b)| if i == 2 || i == 5 { *b == b':' } else { b.is_ascii_digit() })
+        && line.as_bytes()[8] == b' ';
+    if stamped { line.split_at(9) } else { ("", line) }
+}
+
+/// Events kept on screen.
+const EVENT_LOG_LEN: usize = 5;
+
+/// "HH:MM:SS Kind key=value ..." (local time of the event, not of its arrival).
+fn event_line(kind: EventKind, timestamp: u64, detail: &serde_json::Value) -> String {
+    let clock = chrono::DateTime::<chrono::Utc>::from_timestamp_millis(timestamp as i64)
+        .map(|t| t.with_timezone(&chrono::Local).format("%H:%M:%S").to_string())
+        .unwrap_or_default();
+    let fields = detail.as_object().map(|fields| fields.iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::String(text) => format!("{}={}", key, text),
+            other => format!("{}={}", key, other),
+        })
+        .collect::<Vec<_>>()
+        .join(" "))
+        .unwrap_or_default();
+    format!("{} {:?} {}", clock, kind, fields)
+}
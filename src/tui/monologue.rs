@@ -3,7 +3,7 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem},
     text::{Line, Span},
 };
-use crate::core::thought::{Thought, MindVoice};
+use crate::core::thought::{self, Thought, MindVoice};
 
 #[allow(dead_code)]
 pub fn render_monologue<'a>(thoughts: &'a [Thought], insight_intensity: f32) -> List<'a> {
@@ -19,10 +19,15 @@ pub fn render_monologue<'a>(thoughts: &'a [Thought], insight_intensity: f32) ->
                 MindVoice::Chem => ("[CHEM]   ", Color::Magenta),
                 MindVoice::System => ("[SYSTEM] ", Color::DarkGray),
                 MindVoice::Vocal => ("[VOCAL]  ", Color::White),
+                MindVoice::Dream => ("[DREAM]  ", Color::Indexed(99)),
+                MindVoice::Memory => ("[MEMORY] ", Color::Indexed(215)),
+                MindVoice::Error => ("[ERROR]  ", Color::Red),
+                MindVoice::Agency => ("[AGENCY] ", Color::Yellow),
             };
 
             let line = Line::from(vec![
-                Span::styled("> ", Style::default().fg(Color::DarkGray)),
+                Span::styled(thought::clock(t.timestamp), Style::default().fg(Color::DarkGray)),
+                Span::styled(" > ", Style::default().fg(Color::DarkGray)),
                 Span::styled(prefix_text, Style::default().fg(color)),
                 Span::raw(" "),
                 Span::raw(&t.text), // Ensure this field exists
@@ -2,6 +2,7 @@ import React from 'react';
 import { useAlephSocket } from './hooks/useAlephSocket';
 import { Header } from './components/Header';
 import { TraumaBanner } from './components/TraumaBanner';
+import { PrivacyBanner } from './components/PrivacyBanner';
 import { ReservoirView } from './components/ReservoirView';
 import { VisualCortex } from './components/VisualCortex';
 import { ChemistryPanel } from './components/ChemistryPanel';
@@ -16,6 +17,7 @@ function App() {
     <div className="app-container">
       <Header isConnected={isConnected} telemetry={telemetry} />
       <TraumaBanner telemetry={telemetry} />
+      <PrivacyBanner telemetry={telemetry} />
       
       {/* DEBUG OVERLAY */}
       <div style={{ 
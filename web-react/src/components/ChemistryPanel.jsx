@@ -24,6 +24,45 @@ const ChemRow = ({ label, value, color }) => (
   </div>
 );
 
+// Spotlight: which pathway drives the reservoir right now (shares sum to 1)
+const PATHWAY_COLORS = {
+  Input: '#888888', Auditory: '#00ffcc', Visual: '#ff8800', Semantic: '#44aaff',
+  Echo: '#00ff88', Memory: '#aa66ff', Chemistry: '#ff3366',
+};
+
+const AttentionBar = ({ breakdown, focus, shifts }) => {
+  const parts = Object.entries(breakdown || {}).filter(([, share]) => share > 0.005);
+  return (
+    <div style={{ marginBottom: '14px' }}>
+      <div style={{
+        fontSize: '11px', fontWeight: 500, color: 'var(--text-secondary)',
+        textTransform: 'uppercase', letterSpacing: '1px', marginBottom: '6px'
+      }}>
+        Attention
+        {focus && (
+          <span style={{ marginLeft: '8px', textTransform: 'none', color: PATHWAY_COLORS[focus] || 'var(--text-dim)' }}>
+            👁️ {focus.toLowerCase()} <span style={{ color: 'var(--text-dim)' }}>({shifts ?? 0} shifts)</span>
+          </span>
+        )}
+      </div>
+      <div style={{
+        display: 'flex', height: '8px', background: 'rgba(255,255,255,0.04)', borderRadius: '4px', overflow: 'hidden'
+      }}>
+        {parts.map(([pathway, share]) => (
+          <div key={pathway} title={`${pathway} ${(share * 100).toFixed(0)}%`} style={{
+            width: `${share * 100}%`, background: PATHWAY_COLORS[pathway] || '#666', transition: 'width 0.3s ease'
+          }}></div>
+        ))}
+      </div>
+      <div style={{ display: 'flex', flexWrap: 'wrap', gap: '8px', marginTop: '4px', fontSize: '9px', color: 'var(--text-dim)' }}>
+        {parts.map(([pathway, share]) => (
+          <span key={pathway} style={{ color: PATHWAY_COLORS[pathway] || '#666' }}>{pathway} {(share * 100).toFixed(0)}%</span>
+        ))}
+      </div>
+    </div>
+  );
+};
+
 function getChemState(t) {
     if (!t) return "OFFLINE";
     if (t.cortisol > 0.7) return "⚠️ HIGH STRESS (Defense Mode)";
@@ -71,6 +110,12 @@ export function ChemistryPanel({ telemetry, history }) {
         <ChemRow label="Oxytocin" value={oxytocin || 0} color="linear-gradient(90deg, #ff00aa, #ff44cc)" />
         <ChemRow label="Serotonin" value={serotonin || 0} color="linear-gradient(90deg, #00ff88, #00cc66)" />
         
+        <AttentionBar
+          breakdown={telemetry?.attention_breakdown}
+          focus={telemetry?.attention_focus}
+          shifts={telemetry?.attention_shifts}
+        />
+
         <div className="sparkline-container" style={{ marginTop: '8px', paddingTop: '14px', borderTop: '1px solid var(--border-glass)' }}>
             <Sparklines history={history} />
         </div>
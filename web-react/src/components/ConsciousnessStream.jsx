@@ -1,7 +1,18 @@
 import React, { useEffect, useRef, useMemo } from 'react';
 
+// Lines arrive as "HH:MM:SS [F₂] text" (older daemons: no clock)
+const splitClock = (text) => {
+  const m = /^(\d\d:\d\d:\d\d) (.*)$/s.exec(text);
+  return m ? { stamp: m[1], body: m[2] } : { stamp: null, body: text };
+};
+
 export function ConsciousnessStream({ telemetry }) {
   const thoughts = useMemo(() => telemetry?.thoughts || [], [telemetry?.thoughts]);
+  // Voice label -> color, from the daemon's routing table (new voices need no client release)
+  const voiceColors = useMemo(
+    () => Object.fromEntries((telemetry?.voices || []).map(v => [v.label, v.color])),
+    [telemetry?.voices]
+  );
   const bottomRef = useRef(null);
   const streamRef = useRef(null);
 
@@ -20,6 +31,10 @@ export function ConsciousnessStream({ telemetry }) {
   }, [thoughts]);
 
   const getStyle = (text) => {
+    const label = /^\[([^\]]+)\]/.exec(text)?.[1];
+    if (label && voiceColors[label]) {
+      return { color: voiceColors[label] };
+    }
     if (text.includes('🧠') || text.includes('🔥') || text.includes('🛡️') || text.includes('⚙️')) {
       return { color: 'var(--accent-cyan)' };
     } else if (text.includes('💬') || text.includes('🗣️')) {
@@ -51,16 +66,20 @@ export function ConsciousnessStream({ telemetry }) {
            scrollbarWidth: 'thin',
            scrollbarColor: 'var(--border-glass) transparent'
         }}>
-          {thoughts.map((text, i) => (
-            <div key={i} className="stream-entry" style={{
-                padding: '4px 0',
-                borderBottom: '1px solid rgba(255,255,255,0.02)',
-                animation: 'fadeIn 0.3s ease forwards',
-                ...getStyle(text)
-            }}>
-              {text}
-            </div>
-          ))}
+          {thoughts.map((text, i) => {
+            const { stamp, body } = splitClock(text);
+            return (
+              <div key={i} className="stream-entry" style={{
+                  padding: '4px 0',
+                  borderBottom: '1px solid rgba(255,255,255,0.02)',
+                  animation: 'fadeIn 0.3s ease forwards',
+                  ...getStyle(body)
+              }}>
+                {stamp && <span style={{ color: 'var(--text-dim)', marginRight: '8px' }}>{stamp}</span>}
+                {body}
+              </div>
+            );
+          })}
           <div ref={bottomRef} />
         </div>
       </div>
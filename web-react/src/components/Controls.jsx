@@ -10,7 +10,7 @@ export function Controls({ sendAction, sendStimulus, telemetry }) {
       setInputObj('');
   };
 
-  const { loop_frequency, reservoir_size, entropy, trauma_state } = telemetry || {};
+  const { loop_frequency, reservoir_size, entropy, trauma_state, privacy_mode } = telemetry || {};
 
   return (
     <div className="panel controls-panel" style={{ gridColumn: 2, gridRow: 2 }}>
@@ -22,6 +22,7 @@ export function Controls({ sendAction, sendStimulus, telemetry }) {
           <button className="btn" onClick={() => sendAction('poke')}>👆 Poke</button>
           <button className="btn" onClick={() => sendAction('sleep')}>😴 Sleep</button>
           <button className="btn" onClick={() => sendAction('dream')}>🌙 Dream</button>
+          <button className="btn" onClick={() => sendAction(privacy_mode ? 'dnd_off' : 'dnd_on')}>{privacy_mode ? '🔓 Resume Ears' : '🔒 Do Not Disturb'}</button>
           <button className="btn btn-danger" onClick={() => window.alert('Stress Test initiated on backend via low-level signal (Simulated for safety)')}>⚡ Stress Test</button>
         </div>
         
@@ -0,0 +1,21 @@
+import React from 'react';
+
+export function PrivacyBanner({ telemetry }) {
+  const { privacy_mode } = telemetry || {};
+
+  if (!privacy_mode) return null;
+
+  return (
+    <div className="privacy-banner" style={{
+        padding: '8px 24px',
+        background: 'linear-gradient(90deg, rgba(170,0,255,0.18), rgba(0,170,255,0.1))',
+        borderBottom: '1px solid rgba(170,0,255,0.4)',
+        fontSize: '13px',
+        fontWeight: 600,
+        color: '#c77dff',
+        textAlign: 'center'
+    }}>
+      🔒 PRIVACY MODE — Do Not Disturb — nothing is being transcribed, stored or sent to the Planet
+    </div>
+  );
+}
@@ -171,7 +171,7 @@ function Connectome({ activity, regionMap, neuronPositions }) {
 }
 
 // FRONTAL LOBE RENDERER
-// Data source: activations[] (512-dim downsampled LLM logits via max-pooling in perceive())
+// Data source: activations[] (hidden-layer summary, or 512 max-pooled logits; see activation_layout)
 // NO fake pulse. Color = pure activation value.
 function FrontalLobe({ positions, initialColors, activations }) {
     const pointsRef = useRef();
@@ -590,9 +590,19 @@ function BrainScene({ reservoirActivity, activations, size, telemetry }) {
     );
 }
 
+// Hidden-layer activations come raw (layer means, then a mid-layer slice): rescale to 0-1
+// like the pooled logits already are.
+function glassBrain(activations, layout) {
+  if (!layout || !layout.layers || activations.length === 0) return activations;
+  const lo = Math.min(...activations);
+  const hi = Math.max(...activations);
+  const span = hi - lo || 1;
+  return activations.map(v => (v - lo) / span);
+}
+
 export function ReservoirView({ telemetry }) {
   const reservoirActivity = telemetry?.reservoir_activity || []; 
-  const activations = telemetry?.activations || []; 
+  const activations = glassBrain(telemetry?.activations || [], telemetry?.activation_layout);
   const rawSize = telemetry?.reservoir_size || 0; 
   const displaySize = rawSize > 100 ? rawSize : Math.floor(MAX_NEURONS * 0.8);
 
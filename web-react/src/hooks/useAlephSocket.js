@@ -40,11 +40,8 @@ export function useAlephSocket() {
       try {
         let raw = lastMessage.data;
         
-        // Handle Blob (binary) data if received
+        // Binary frames are activity raster columns ("RSTR", see src/core/raster.rs), not telemetry
         if (raw instanceof Blob) {
-            setDebugInfo(`WARN: Received Blob (${raw.size}b). Expecting Text.`);
-            // You might need to text() it if it's actually text data sent as binary
-            // raw.text().then(...) 
             return; 
         }
 
@@ -53,7 +50,13 @@ export function useAlephSocket() {
         const data = parsed.Telemetry || parsed; 
         
         if (data && Object.keys(data).length > 0) {
-            setTelemetry(data);
+            // region_map / neuron_positions / neuron_ids only come when topology_version changes: keep the last ones
+            setTelemetry(prev => (data.region_map || !prev) ? data : {
+                ...data,
+                region_map: prev.region_map,
+                neuron_positions: prev.neuron_positions,
+                neuron_ids: prev.neuron_ids,
+            });
             updateHistory(data);
             setDebugInfo(`OK: ${Object.keys(data).length} keys. Size: ${raw.length}`);
         } else {